@@ -25,12 +25,16 @@ impl<'a> ElemPtr<'a> for AccPtr<'a> {
             Array::Default { .. } => 0,
             Array::Alloced { indexes, .. } => indexes.len() - 1,
         };
-        let default_proof = TokenTree::<TokenTreeNodes, Tokens>::default_leaf_node();
+        let default_proof = TokenTree::<TokenTreeNodes, Tokens>::default_leaf_node(
+            self.file.params.token_tree_height,
+        );
         let token_tree = TokenTree {
             nodes,
             actual_level,
             tokens: leaves,
             default_proof,
+            deferred: true,
+            dirty_leaf_ids: vec![],
         };
         Ok(Account {
             l2_addr,
@@ -69,7 +73,11 @@ impl<'a> ElemPtr<'a> for AccPtr<'a> {
                 nodes: TokenTreeNodes::Default { file },
                 actual_level: 0,
                 tokens: Tokens::Default { file },
-                default_proof: TokenTree::<TokenTreeNodes, Tokens>::default_leaf_node(),
+                default_proof: TokenTree::<TokenTreeNodes, Tokens>::default_leaf_node(
+                    file.params.token_tree_height,
+                ),
+                deferred: true,
+                dirty_leaf_ids: vec![],
             },
         }
     }
@@ -0,0 +1,43 @@
+use super::{key_index, TsFile, L1_ADDRS_SLOT, L1_REQ_SYNCED_SLOT, L2_ADDRS_SLOT};
+use ark_bn254::Fr;
+use num_bigint::BigUint;
+
+// the last L1 block whose L1 requests have been scanned for registrations
+pub fn load_l1_req_synced_block_id(file: &TsFile) -> Result<u64, String> {
+    file.load_slot(L1_REQ_SYNCED_SLOT)
+}
+
+pub fn set_l1_req_synced_block_id(file: &TsFile, l1_block_id: u64) -> Result<(), String> {
+    file.write_slot(L1_REQ_SYNCED_SLOT, l1_block_id)
+}
+
+fn to_l1_key(l1_addr: [u8; 20]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[..20].copy_from_slice(&l1_addr);
+    key
+}
+
+pub fn push_l1_addr(file: &TsFile, acc_id: usize, l1_addr: [u8; 20]) -> Result<(), String> {
+    key_index::insert(file, L1_ADDRS_SLOT, to_l1_key(l1_addr), acc_id as u64)
+}
+
+pub fn find_acc_id_by_l1_addr(file: &TsFile, l1_addr: [u8; 20]) -> Result<Option<usize>, String> {
+    Ok(key_index::find(file, L1_ADDRS_SLOT, to_l1_key(l1_addr))?.map(|acc_id| acc_id as usize))
+}
+
+// the L2 address (hashed public key) of the registration, by its little-endian bytes
+fn to_l2_key(l2_addr: Fr) -> [u8; 32] {
+    let biguint: BigUint = l2_addr.into();
+    let mut key = [0u8; 32];
+    let bytes = biguint.to_bytes_le();
+    key[..bytes.len()].copy_from_slice(&bytes);
+    key
+}
+
+pub fn push_l2_addr(file: &TsFile, acc_id: usize, l2_addr: Fr) -> Result<(), String> {
+    key_index::insert(file, L2_ADDRS_SLOT, to_l2_key(l2_addr), acc_id as u64)
+}
+
+pub fn find_acc_id_by_l2_addr(file: &TsFile, l2_addr: Fr) -> Result<Option<usize>, String> {
+    Ok(key_index::find(file, L2_ADDRS_SLOT, to_l2_key(l2_addr))?.map(|acc_id| acc_id as usize))
+}
@@ -0,0 +1,59 @@
+use super::{list::ArrayPtr, node_ptr::NodePtr, Array, TsFile, EVACUATED_SLOT};
+use ark_bn254::Fr;
+use ts_state::Array as ArrayTrait;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EvacuatedEntry {
+    pub acc_id: usize,
+    pub token_id: u64,
+    pub amount: Fr,
+}
+
+// the evacuated amounts by account id then token id, none for the tokens not evacuated
+type Evacuated<'a> = Array<'a, ArrayPtr<'a, NodePtr<'a>>>;
+
+// an (account, token) pair can only be evacuated once, later requests for it are ignored
+pub fn push_evacuated(
+    file: &TsFile,
+    acc_id: usize,
+    token_id: u64,
+    amount: Fr,
+) -> Result<(), String> {
+    let mut evacuated = Evacuated::load(file, file.load_slot(EVACUATED_SLOT)? as usize)?;
+    let mut amounts = evacuated.get(acc_id)?;
+    if amounts.get(token_id as usize)?.is_some() {
+        return Ok(());
+    }
+    amounts.set(token_id as usize, &Some(amount))?;
+    evacuated.set(acc_id, &amounts)
+}
+
+pub fn load_evacuated(file: &TsFile, acc_id: usize) -> Result<Vec<EvacuatedEntry>, String> {
+    let amounts = Evacuated::load(file, file.load_slot(EVACUATED_SLOT)? as usize)?.get(acc_id)?;
+    let mut evacuated = vec![];
+    for token_id in 0..amounts.capacity() {
+        if let Some(amount) = amounts.get(token_id)? {
+            evacuated.push(EvacuatedEntry {
+                acc_id,
+                token_id: token_id as u64,
+                amount,
+            });
+        }
+    }
+    Ok(evacuated)
+}
+
+pub fn find_evacuated(
+    file: &TsFile,
+    acc_id: usize,
+    token_id: u64,
+) -> Result<Option<EvacuatedEntry>, String> {
+    let amounts = Evacuated::load(file, file.load_slot(EVACUATED_SLOT)? as usize)?.get(acc_id)?;
+    Ok(amounts
+        .get(token_id as usize)?
+        .map(|amount| EvacuatedEntry {
+            acc_id,
+            token_id,
+            amount,
+        }))
+}
@@ -0,0 +1,108 @@
+use super::wal::checksum;
+use ts_state::constants::Params;
+
+pub const MAGIC: [u8; 8] = *b"TSEVACU\0";
+// to bump together with a step in `TsFile::migrate` whenever the layout changes
+pub const FORMAT_VERSION: u64 = 4;
+// the header is followed by the slots of the indexes, then by the data of the state
+pub const HEADER_SIZE: usize = 128;
+// the checksum covers the header alone, the index slots and the data after it are not checked
+const CHECKSUM_OFFSET: usize = HEADER_SIZE - 8;
+
+// the deployment a state file is synced from, recorded with the genesis anchor when the file is
+// created and checked whenever it is opened
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub params: Params,
+    pub contract_addr: [u8; 20],
+    pub chain_id: u64,
+}
+impl Header {
+    pub fn encode(&self, l2_genesis_l1_anchor_id: u64) -> [u8; HEADER_SIZE] {
+        let mut bytes = [0u8; HEADER_SIZE];
+        bytes[..8].copy_from_slice(&MAGIC);
+        for (i, field) in [
+            FORMAT_VERSION,
+            self.params.account_tree_height as u64,
+            self.params.token_tree_height as u64,
+            self.params.tx_count_per_block as u64,
+            self.chain_id,
+            l2_genesis_l1_anchor_id,
+        ]
+        .iter()
+        .enumerate()
+        {
+            bytes[8 + i * 8..16 + i * 8].copy_from_slice(&field.to_le_bytes());
+        }
+        bytes[56..76].copy_from_slice(&self.contract_addr);
+        let sum = checksum(&bytes[..CHECKSUM_OFFSET]);
+        bytes[CHECKSUM_OFFSET..].copy_from_slice(&sum.to_le_bytes());
+        bytes
+    }
+    // the format version, the header and the genesis anchor, or none if the bytes do not start with the magic number
+    pub fn decode(bytes: &[u8; HEADER_SIZE]) -> Result<Option<(u64, Self, u64)>, String> {
+        if bytes[..8] != MAGIC {
+            return Ok(None);
+        }
+        if checksum(&bytes[..CHECKSUM_OFFSET]).to_le_bytes() != bytes[CHECKSUM_OFFSET..] {
+            return Err("the file header is corrupted".to_string());
+        }
+        let field = |i: usize| u64::from_le_bytes(bytes[8 + i * 8..16 + i * 8].try_into().unwrap());
+        let header = Self {
+            params: Params {
+                account_tree_height: field(1) as usize,
+                token_tree_height: field(2) as usize,
+                tx_count_per_block: field(3) as usize,
+            },
+            contract_addr: bytes[56..76].try_into().unwrap(),
+            chain_id: field(4),
+        };
+        Ok(Some((field(0), header, field(5))))
+    }
+    // the anchor is only checked when it is known
+    pub fn check(
+        &self,
+        l2_genesis_l1_anchor_id: u64,
+        expected: &Self,
+        expected_l2_genesis_l1_anchor_id: Option<u64>,
+    ) -> Result<(), String> {
+        for (name, value, expected_value) in [
+            (
+                "account_tree_height",
+                self.params.account_tree_height as u64,
+                expected.params.account_tree_height as u64,
+            ),
+            (
+                "token_tree_height",
+                self.params.token_tree_height as u64,
+                expected.params.token_tree_height as u64,
+            ),
+            (
+                "tx_count_per_block",
+                self.params.tx_count_per_block as u64,
+                expected.params.tx_count_per_block as u64,
+            ),
+            ("chain_id", self.chain_id, expected.chain_id),
+            (
+                "l2_genesis_l1_anchor_id",
+                l2_genesis_l1_anchor_id,
+                expected_l2_genesis_l1_anchor_id.unwrap_or(l2_genesis_l1_anchor_id),
+            ),
+        ] {
+            if value != expected_value {
+                return Err(format!(
+                    "the state file was synced with {} {}, not {}",
+                    name, value, expected_value
+                ));
+            }
+        }
+        if self.contract_addr != expected.contract_addr {
+            return Err(format!(
+                "the state file was synced with ts_contract_addr 0x{}, not 0x{}",
+                hex::encode(self.contract_addr),
+                hex::encode(expected.contract_addr)
+            ));
+        }
+        Ok(())
+    }
+}
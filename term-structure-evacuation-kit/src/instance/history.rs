@@ -0,0 +1,137 @@
+use super::{
+    list::ListPtr, load_fr, load_u64, write_fr, write_u64, Array, ElemPtr, TsFile,
+    BLOCK_ROOTS_SLOT, HISTORIES_SLOT,
+};
+use ark_bn254::Fr;
+use num_traits::Zero;
+use ts_state::{Array as ArrayTrait, BalanceChange};
+
+#[derive(Clone, Copy, Debug)]
+pub struct HistoryEntry {
+    pub tx_id: usize,
+    pub token_id: u64,
+    pub avl_amt: Fr,
+    pub locked_amt: Fr,
+}
+
+pub struct HistoryPtr<'a> {
+    file: &'a TsFile,
+    index: usize,
+}
+impl<'a> ElemPtr<'a> for HistoryPtr<'a> {
+    const SIZE: usize = 80;
+    type Elem = HistoryEntry;
+    fn new(file: &'a TsFile, index: usize) -> Self {
+        Self { file, index }
+    }
+    fn read(&self) -> Result<Self::Elem, String> {
+        let mut index = self.index;
+        Ok(HistoryEntry {
+            tx_id: load_u64(self.file, &mut index)? as usize,
+            token_id: load_u64(self.file, &mut index)?,
+            avl_amt: load_fr(self.file, &mut index)?,
+            locked_amt: load_fr(self.file, &mut index)?,
+        })
+    }
+    fn write(&self, elem: &Self::Elem) -> Result<(), String> {
+        let mut index = self.index;
+        write_u64(self.file, &mut index, elem.tx_id as u64)?;
+        write_u64(self.file, &mut index, elem.token_id)?;
+        write_fr(self.file, &mut index, elem.avl_amt)?;
+        write_fr(self.file, &mut index, elem.locked_amt)
+    }
+    fn default(_: &'a TsFile) -> Self::Elem {
+        HistoryEntry {
+            tx_id: 0,
+            token_id: 0,
+            avl_amt: Fr::zero(),
+            locked_amt: Fr::zero(),
+        }
+    }
+}
+
+// the history of each account, by account id
+type Histories<'a> = Array<'a, ListPtr<'a, HistoryPtr<'a>>>;
+
+pub fn push_history(file: &TsFile, tx_id: usize, change: &BalanceChange) -> Result<(), String> {
+    let mut histories = Histories::load(file, file.load_slot(HISTORIES_SLOT)? as usize)?;
+    let acc_id = change.acc_id as usize;
+    let mut history = histories.get(acc_id)?;
+    history.push(&HistoryEntry {
+        tx_id,
+        token_id: change.token_id,
+        avl_amt: change.avl_amt,
+        locked_amt: change.locked_amt,
+    })?;
+    histories.set(acc_id, &history)
+}
+
+pub fn load_history(file: &TsFile, acc_id: usize) -> Result<Vec<HistoryEntry>, String> {
+    Histories::load(file, file.load_slot(HISTORIES_SLOT)? as usize)?
+        .get(acc_id)?
+        .to_vec()
+}
+
+// the roots recorded after a block was executed, with the time of the block
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockRoot {
+    pub ts_root: Fr,
+    pub state_root: Fr,
+    pub timestamp: u64,
+}
+
+pub struct BlockRootPtr<'a> {
+    file: &'a TsFile,
+    index: usize,
+}
+impl<'a> ElemPtr<'a> for BlockRootPtr<'a> {
+    // a flag set once the block is recorded, then the timestamp and the roots
+    const SIZE: usize = 80;
+    type Elem = Option<BlockRoot>;
+    fn new(file: &'a TsFile, index: usize) -> Self {
+        Self { file, index }
+    }
+    fn read(&self) -> Result<Self::Elem, String> {
+        let mut index = self.index;
+        if load_u64(self.file, &mut index)? == 0 {
+            return Ok(None);
+        }
+        let timestamp = load_u64(self.file, &mut index)?;
+        Ok(Some(BlockRoot {
+            ts_root: load_fr(self.file, &mut index)?,
+            state_root: load_fr(self.file, &mut index)?,
+            timestamp,
+        }))
+    }
+    fn write(&self, elem: &Self::Elem) -> Result<(), String> {
+        let mut index = self.index;
+        match elem {
+            Some(block_root) => {
+                write_u64(self.file, &mut index, 1)?;
+                write_u64(self.file, &mut index, block_root.timestamp)?;
+                write_fr(self.file, &mut index, block_root.ts_root)?;
+                write_fr(self.file, &mut index, block_root.state_root)
+            }
+            None => self.file.write(index, &[0u8; 80], Self::SIZE),
+        }
+    }
+    fn default(_: &'a TsFile) -> Self::Elem {
+        None
+    }
+}
+
+// by block id
+type BlockRoots<'a> = Array<'a, BlockRootPtr<'a>>;
+
+pub fn push_block_root(
+    file: &TsFile,
+    block_id: usize,
+    block_root: BlockRoot,
+) -> Result<(), String> {
+    BlockRoots::load(file, file.load_slot(BLOCK_ROOTS_SLOT)? as usize)?
+        .set(block_id, &Some(block_root))
+}
+
+pub fn load_block_root(file: &TsFile, block_id: usize) -> Result<Option<BlockRoot>, String> {
+    BlockRoots::load(file, file.load_slot(BLOCK_ROOTS_SLOT)? as usize)?.get(block_id)
+}
@@ -0,0 +1,61 @@
+use super::{list::ListPtr, load_u64, write_u64, Array, ElemPtr, TsFile};
+use ts_state::Array as ArrayTrait;
+
+// a hash index from 32 byte keys to ids, whose array head is kept in a slot of the file: the keys
+// are spread over the buckets by their first bytes, each bucket being a list of (key, id) records
+const BUCKET_MASK: usize = (1 << 12) - 1;
+
+pub struct KeyPtr<'a> {
+    file: &'a TsFile,
+    index: usize,
+}
+impl<'a> ElemPtr<'a> for KeyPtr<'a> {
+    const SIZE: usize = 40;
+    type Elem = ([u8; 32], u64);
+    fn new(file: &'a TsFile, index: usize) -> Self {
+        Self { file, index }
+    }
+    fn read(&self) -> Result<Self::Elem, String> {
+        let key = self.file.read::<32>(self.index)?;
+        let id = load_u64(self.file, &mut (self.index + 32))?;
+        Ok((key, id))
+    }
+    fn write(&self, elem: &Self::Elem) -> Result<(), String> {
+        self.file.write(self.index, &elem.0, 32)?;
+        write_u64(self.file, &mut (self.index + 32), elem.1)
+    }
+    fn default(_: &'a TsFile) -> Self::Elem {
+        ([0u8; 32], 0)
+    }
+}
+
+type Buckets<'a> = Array<'a, ListPtr<'a, KeyPtr<'a>>>;
+
+fn bucket(key: &[u8; 32]) -> usize {
+    u16::from_le_bytes([key[0], key[1]]) as usize & BUCKET_MASK
+}
+
+// a key inserted again is given the new id
+pub fn insert(file: &TsFile, slot: usize, key: [u8; 32], id: u64) -> Result<(), String> {
+    let mut buckets = Buckets::load(file, file.load_slot(slot)? as usize)?;
+    let mut list = buckets.get(bucket(&key))?;
+    for i in 0..list.len {
+        if list.items.get(i)?.0 == key {
+            return list.items.set(i, &(key, id));
+        }
+    }
+    list.push(&(key, id))?;
+    buckets.set(bucket(&key), &list)
+}
+
+pub fn find(file: &TsFile, slot: usize, key: [u8; 32]) -> Result<Option<u64>, String> {
+    let buckets = Buckets::load(file, file.load_slot(slot)? as usize)?;
+    let list = buckets.get(bucket(&key))?;
+    for i in 0..list.len {
+        let (item_key, id) = list.items.get(i)?;
+        if item_key == key {
+            return Ok(Some(id));
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,125 @@
+use super::{
+    address, evacuation,
+    history::{self, BlockRoot},
+    load_fr, load_u64, TsFile,
+};
+use ts_state::{BalanceChange, Map, TSBInfo};
+use ts_tx::Tx;
+
+// up to the format version 1 the indexes were linked lists, newest record first, whose heads were
+// kept in the spare bytes of the account records: the history of an account in its own record,
+// the other lists in the record of account 0
+const HISTORY_HEAD_OFFSET: usize = 80;
+const BLOCK_ROOT_HEAD_OFFSET: usize = 88;
+const L1_ADDR_HEAD_OFFSET: usize = 96;
+const L1_REQ_SYNCED_OFFSET: usize = 104;
+const EVACUATED_HEAD_OFFSET: usize = 112;
+
+// the records of a list in the order they were pushed, each given from the cursor after its link
+fn load_list<T>(
+    file: &TsFile,
+    head_index: usize,
+    mut f: impl FnMut(&mut usize) -> Result<T, String>,
+) -> Result<Vec<T>, String> {
+    let mut records = vec![];
+    let mut index = load_u64(file, &mut head_index.clone())? as usize;
+    while index != 0 {
+        let mut cursor = index;
+        index = load_u64(file, &mut cursor)? as usize;
+        records.push(f(&mut cursor)?);
+    }
+    records.reverse();
+    Ok(records)
+}
+
+// rebuilds the indexes of a migrated file, whose slots are allocated, from its lists
+pub fn migrate_indexes(file: &TsFile) -> Result<(), String> {
+    let state = file.to_state()?;
+    let accounts = &state.accounts.accounts;
+    let acc_index = match accounts.locate(0)? {
+        Some(acc_index) => acc_index,
+        None => return Ok(()),
+    };
+    let mut acc_id = 0;
+    while let Some(index) = accounts.locate(acc_id)? {
+        let history = load_list(file, index + HISTORY_HEAD_OFFSET, |cursor| {
+            let tx_id = load_u64(file, cursor)? as usize;
+            let change = BalanceChange {
+                acc_id: acc_id as u64,
+                token_id: load_u64(file, cursor)?,
+                avl_amt: load_fr(file, cursor)?,
+                locked_amt: load_fr(file, cursor)?,
+            };
+            Ok((tx_id, change))
+        })?;
+        for (tx_id, change) in history {
+            history::push_history(file, tx_id, &change)?;
+        }
+        acc_id += 1;
+    }
+    // the time of the blocks was not recorded
+    let block_roots = load_list(file, acc_index + BLOCK_ROOT_HEAD_OFFSET, |cursor| {
+        let block_id = load_u64(file, cursor)? as usize;
+        let block_root = BlockRoot {
+            ts_root: load_fr(file, cursor)?,
+            state_root: load_fr(file, cursor)?,
+            timestamp: 0,
+        };
+        Ok((block_id, block_root))
+    })?;
+    for (block_id, block_root) in block_roots {
+        history::push_block_root(file, block_id, block_root)?;
+    }
+    let l1_addrs = load_list(file, acc_index + L1_ADDR_HEAD_OFFSET, |cursor| {
+        let acc_id = load_u64(file, cursor)? as usize;
+        Ok((acc_id, file.read::<20>(*cursor)?))
+    })?;
+    for (acc_id, l1_addr) in l1_addrs {
+        address::push_l1_addr(file, acc_id, l1_addr)?;
+    }
+    address::set_l1_req_synced_block_id(
+        file,
+        load_u64(file, &mut (acc_index + L1_REQ_SYNCED_OFFSET))?,
+    )?;
+    let evacuated = load_list(file, acc_index + EVACUATED_HEAD_OFFSET, |cursor| {
+        let acc_id = load_u64(file, cursor)? as usize;
+        let token_id = load_u64(file, cursor)?;
+        Ok((acc_id, token_id, load_fr(file, cursor)?))
+    })?;
+    for (acc_id, token_id, amount) in evacuated {
+        evacuation::push_evacuated(file, acc_id, token_id, amount)?;
+    }
+    Ok(())
+}
+
+// indexes the L2 addresses of the registrations synced so far, which the format version 2 did not
+pub fn index_l2_addrs(file: &TsFile) -> Result<(), String> {
+    let state = file.to_state()?;
+    state.for_each_tx(file.block_count as usize, |_, tx| {
+        if let Tx::TxRegister(tx) = tx.raw_tx {
+            address::push_l2_addr(file, tx.account_id as usize, tx.hashed_pub_key)?;
+        }
+        Ok(())
+    })
+}
+
+// indexes the bond tokens created so far by their base token and maturity, which the format
+// version 3 did not
+pub fn index_bond_tokens(file: &TsFile) -> Result<(), String> {
+    let mut state = file.to_state()?;
+    let mut created = vec![];
+    state.for_each_tx(file.block_count as usize, |_, tx| {
+        if let Tx::TxCreateTsbBondToken(tx) = tx.raw_tx {
+            created.push(tx);
+        }
+        Ok(())
+    })?;
+    for tx in created {
+        let tsb_info = TSBInfo {
+            base_token_id: tx.base_token_id as usize,
+            maturity: tx.maturity,
+        };
+        state.bond_token_ids.set(&tsb_info, &tx.bond_token_id)?;
+    }
+    Ok(())
+}
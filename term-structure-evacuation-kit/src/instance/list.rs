@@ -0,0 +1,82 @@
+use super::{load_u64, write_u64, Array, ElemPtr, TsFile};
+use ts_state::Array as ArrayTrait;
+
+// an array stored in an element of another array, by the head of its first chunk
+pub struct ArrayPtr<'a, Ptr: ElemPtr<'a>> {
+    file: &'a TsFile,
+    index: usize,
+    _phantom: std::marker::PhantomData<Ptr>,
+}
+impl<'a, Ptr: ElemPtr<'a>> ElemPtr<'a> for ArrayPtr<'a, Ptr> {
+    const SIZE: usize = 8;
+    type Elem = Array<'a, Ptr>;
+    fn new(file: &'a TsFile, index: usize) -> Self {
+        Self {
+            file,
+            index,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+    fn read(&self) -> Result<Self::Elem, String> {
+        Array::load(
+            self.file,
+            load_u64(self.file, &mut self.index.clone())? as usize,
+        )
+    }
+    fn write(&self, elem: &Self::Elem) -> Result<(), String> {
+        write_u64(self.file, &mut self.index.clone(), elem.head() as u64)
+    }
+    fn default(file: &'a TsFile) -> Self::Elem {
+        Array::Default { file }
+    }
+}
+
+// the records are appended to the array of a list, which keeps their count
+pub struct List<'a, Ptr: ElemPtr<'a>> {
+    pub len: usize,
+    pub items: Array<'a, Ptr>,
+}
+impl<'a, Ptr: ElemPtr<'a>> List<'a, Ptr> {
+    pub fn push(&mut self, elem: &Ptr::Elem) -> Result<(), String> {
+        self.items.set(self.len, elem)?;
+        self.len += 1;
+        Ok(())
+    }
+    pub fn to_vec(&self) -> Result<Vec<Ptr::Elem>, String> {
+        (0..self.len).map(|i| self.items.get(i)).collect()
+    }
+}
+
+pub struct ListPtr<'a, Ptr: ElemPtr<'a>> {
+    file: &'a TsFile,
+    index: usize,
+    _phantom: std::marker::PhantomData<Ptr>,
+}
+impl<'a, Ptr: ElemPtr<'a>> ElemPtr<'a> for ListPtr<'a, Ptr> {
+    const SIZE: usize = 16;
+    type Elem = List<'a, Ptr>;
+    fn new(file: &'a TsFile, index: usize) -> Self {
+        Self {
+            file,
+            index,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+    fn read(&self) -> Result<Self::Elem, String> {
+        let mut index = self.index;
+        let len = load_u64(self.file, &mut index)? as usize;
+        let items = Array::load(self.file, load_u64(self.file, &mut index)? as usize)?;
+        Ok(List { len, items })
+    }
+    fn write(&self, elem: &Self::Elem) -> Result<(), String> {
+        let mut index = self.index;
+        write_u64(self.file, &mut index, elem.len as u64)?;
+        write_u64(self.file, &mut index, elem.items.head() as u64)
+    }
+    fn default(file: &'a TsFile) -> Self::Elem {
+        List {
+            len: 0,
+            items: Array::Default { file },
+        }
+    }
+}
@@ -1,10 +1,28 @@
 mod acc_ptr;
+pub mod address;
+pub mod evacuation;
+mod header;
+pub mod history;
+mod key_index;
+mod legacy;
+mod list;
 mod node_ptr;
 mod token_ptr;
 mod tsbinfo_ptr;
 mod tx_ptr;
+mod wal;
+pub use self::header::{Header, FORMAT_VERSION};
 use self::{
-    acc_ptr::AccPtr, node_ptr::NodePtr, token_ptr::TokenPtr, tsbinfo_ptr::TSBInfoPtr, tx_ptr::TxPtr,
+    acc_ptr::AccPtr,
+    header::HEADER_SIZE,
+    history::{BlockRootPtr, HistoryPtr},
+    key_index::KeyPtr,
+    list::{ArrayPtr, ListPtr},
+    node_ptr::NodePtr,
+    token_ptr::TokenPtr,
+    tsbinfo_ptr::{BondTokenIds, TSBInfoPtr},
+    tx_ptr::TxPtr,
+    wal::Wal,
 };
 use ark_bn254::Fr;
 use ark_ff::PrimeField;
@@ -14,45 +32,114 @@ use std::{
     fs::{File, OpenOptions},
     io::{Read, Seek, SeekFrom, Write},
     sync::{Arc, Mutex},
-    usize,
 };
 use ts_poseidon::poseidon;
 use ts_state::{
-    constants::{ACCOUNT_TREE_HEIGHT, TOKEN_TREE_HEIGHT},
-    AccountTree, Array as ArrayTrait, State, TokenTree, Value as ValueTrait,
+    constants::Params, AccountTree, Array as ArrayTrait, State, TokenTree, Value as ValueTrait,
 };
 
+// the layout of the data: the counters written by `sync`, the ts root, then the first chunks of the arrays
+const TS_ROOT_OFFSET: usize = std::mem::size_of::<u64>() * 3;
+const ACCOUNT_TREE_NODES_OFFSET: usize = TS_ROOT_OFFSET + std::mem::size_of::<Fr>();
+const ACCOUNTS_OFFSET: usize =
+    ACCOUNT_TREE_NODES_OFFSET + std::mem::size_of::<u64>() + <NodePtr as ElemPtr>::SIZE;
+const TSB_INFOS_OFFSET: usize =
+    ACCOUNTS_OFFSET + std::mem::size_of::<u64>() + <AccPtr as ElemPtr>::SIZE;
+const TXS_OFFSET: usize =
+    TSB_INFOS_OFFSET + std::mem::size_of::<u64>() + <TSBInfoPtr as ElemPtr>::SIZE;
+
+// the indexes of the kit, which are not part of the state, have their slots between the header and
+// the data: the heads of arrays allocated with the file, and the L1 request sync point
+const INDEXES_SIZE: usize = 64;
+const BLOCK_ROOTS_SLOT: usize = 0;
+const HISTORIES_SLOT: usize = 8;
+const L1_ADDRS_SLOT: usize = 16;
+const EVACUATED_SLOT: usize = 24;
+const L1_REQ_SYNCED_SLOT: usize = 32;
+const L2_ADDRS_SLOT: usize = 40;
+const BOND_TOKENS_SLOT: usize = 48;
+const DATA_OFFSET: usize = HEADER_SIZE + INDEXES_SIZE;
+
+struct Storage {
+    file: File,
+    // none for the files that are only renamed or removed once complete, which are written in place
+    wal: Option<Wal>,
+}
+
 pub struct TsFile {
-    file: Arc<Mutex<File>>,
+    file: Arc<Mutex<Storage>>,
     pub latest_l1_block_id: u64,
     pub block_count: u64,
     pub tx_count: u64,
+    pub params: Params,
 }
 impl TsFile {
-    pub fn open(filename: &str, l2_genesis_l1_anchor_id: Option<u64>) -> Result<Self, String> {
-        let file = OpenOptions::new()
+    // the writes only reach the file at `sync`, all together, so that an interrupted block is rolled back
+    pub fn open(
+        filename: &str,
+        l2_genesis_l1_anchor_id: Option<u64>,
+        header: Header,
+    ) -> Result<Self, String> {
+        Self::open_with(filename, l2_genesis_l1_anchor_id, header, true)
+    }
+    fn open_with(
+        filename: &str,
+        l2_genesis_l1_anchor_id: Option<u64>,
+        header: Header,
+        journaled: bool,
+    ) -> Result<Self, String> {
+        let mut file = OpenOptions::new()
             .read(true)
             .write(true)
             .create(true)
+            .truncate(false)
             .open(filename)
             .map_err(|e| e.to_string())?;
+        let wal = match journaled {
+            true => Some(Wal::open(filename, &mut file)?),
+            false => None,
+        };
         let mut ts_file = Self {
-            file: Arc::new(Mutex::new(file)),
-            latest_l1_block_id: l2_genesis_l1_anchor_id
-                .ok_or("cfg.l2_genesis_l1_anchor_id is required")?
-                as u64,
+            file: Arc::new(Mutex::new(Storage { file, wal })),
+            latest_l1_block_id: 0,
             block_count: 1,
             tx_count: 0,
+            params: header.params,
         };
         if ts_file.is_empty()? {
+            let l2_genesis_l1_anchor_id =
+                l2_genesis_l1_anchor_id.ok_or("cfg.l2_genesis_l1_anchor_id is required")?;
+            ts_file.latest_l1_block_id = l2_genesis_l1_anchor_id.saturating_sub(1);
+            ts_file.write_raw(0, &header.encode(l2_genesis_l1_anchor_id))?;
+            ts_file.write_raw(HEADER_SIZE, &[0u8; INDEXES_SIZE])?;
             ts_file.alloc_val(std::mem::size_of::<u64>() * 3)?;
-            ts_file.sync()?;
             let _ = Value::alloc(&ts_file)?;
             Array::<NodePtr>::load(&ts_file, 0)?.alloc()?;
             Array::<AccPtr>::load(&ts_file, 0)?.alloc()?;
             Array::<TSBInfoPtr>::load(&ts_file, 0)?.alloc()?;
             Array::<TxPtr>::load(&ts_file, 0)?.alloc()?;
+            ts_file.alloc_indexes()?;
+            ts_file.sync()?;
         } else {
+            let mut bytes = [0u8; HEADER_SIZE];
+            ts_file.read_raw(0, &mut bytes)?;
+            match Header::decode(&bytes).map_err(|e| format!("{}: {}", filename, e))? {
+                Some((FORMAT_VERSION, file_header, anchor_id)) => {
+                    file_header.check(anchor_id, &header, l2_genesis_l1_anchor_id)?
+                }
+                Some((version, _, _)) if version > FORMAT_VERSION => {
+                    return Err(format!(
+                        "{} has the format version {}, which this version of the kit does not read",
+                        filename, version
+                    ))
+                }
+                _ => {
+                    return Err(format!(
+                        "{} is not a state file of this format version, run `ts-evacu migrate` if it was synced by an older version",
+                        filename
+                    ))
+                }
+            }
             let mut index = 0;
             ts_file.latest_l1_block_id = load_u64(&ts_file, &mut index)?;
             ts_file.block_count = load_u64(&ts_file, &mut index)?;
@@ -62,38 +149,65 @@ impl TsFile {
     }
     pub fn sync(&self) -> Result<(), String> {
         let mut index = 0;
-        write_u64(&self, &mut index, self.latest_l1_block_id)?;
-        write_u64(&self, &mut index, self.block_count)?;
-        write_u64(&self, &mut index, self.tx_count)?;
-        self.file
-            .lock()
-            .map_err(|e| e.to_string())?
-            .sync_all()
-            .map_err(|e| e.to_string())?;
-        Ok(())
+        write_u64(self, &mut index, self.latest_l1_block_id)?;
+        write_u64(self, &mut index, self.block_count)?;
+        write_u64(self, &mut index, self.tx_count)?;
+        let mut storage = self.file.lock().map_err(|e| e.to_string())?;
+        let Storage { file, wal } = &mut *storage;
+        match wal {
+            Some(wal) => wal.commit(file),
+            None => file.sync_all().map_err(|e| e.to_string()),
+        }
     }
     pub fn close(self) -> Result<(), String> {
         self.sync()
     }
-    pub fn to_state(&self) -> Result<StateInstance, String> {
+    // allocates the arrays of the empty slots
+    fn alloc_indexes(&self) -> Result<(), String> {
+        fn alloc<'a, Ptr: ElemPtr<'a>>(file: &'a TsFile, slot: usize) -> Result<(), String> {
+            if file.load_slot(slot)? != 0 {
+                return Ok(());
+            }
+            let mut arr = Array::<Ptr>::load(file, 0)?;
+            arr.alloc()?;
+            file.write_slot(slot, arr.head() as u64)
+        }
+        alloc::<BlockRootPtr>(self, BLOCK_ROOTS_SLOT)?;
+        alloc::<ListPtr<HistoryPtr>>(self, HISTORIES_SLOT)?;
+        alloc::<ListPtr<KeyPtr>>(self, L1_ADDRS_SLOT)?;
+        alloc::<ArrayPtr<NodePtr>>(self, EVACUATED_SLOT)?;
+        alloc::<ListPtr<KeyPtr>>(self, L2_ADDRS_SLOT)?;
+        alloc::<ListPtr<KeyPtr>>(self, BOND_TOKENS_SLOT)?;
+        Ok(())
+    }
+    fn load_slot(&self, slot: usize) -> Result<u64, String> {
+        let mut buf = [0u8; 8];
+        self.read_raw(HEADER_SIZE + slot, &mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+    fn write_slot(&self, slot: usize, value: u64) -> Result<(), String> {
+        self.write_raw(HEADER_SIZE + slot, &value.to_le_bytes())
+    }
+    pub fn to_state(&self) -> Result<StateInstance<'_>, String> {
         let (ts_root, account_tree_nodes, accounts, tsb_infos, txs) = if !self.is_empty()? {
-            let ts_root: Value = Value::load(self, 24)?;
-            let account_tree_nodes: Array<NodePtr> = Array::load(&self, 56)?;
-            let accounts: Array<AccPtr> = Array::load(&self, 96)?;
-            let tsb_infos: Array<TSBInfoPtr> = Array::load(&self, 280)?;
-            let txs: Array<TxPtr> = Array::load(&self, 352)?;
+            let ts_root: Value = Value::load(self, TS_ROOT_OFFSET)?;
+            let account_tree_nodes: Array<NodePtr> = Array::load(self, ACCOUNT_TREE_NODES_OFFSET)?;
+            let accounts: Array<AccPtr> = Array::load(self, ACCOUNTS_OFFSET)?;
+            let tsb_infos: Array<TSBInfoPtr> = Array::load(self, TSB_INFOS_OFFSET)?;
+            let txs: Array<TxPtr> = Array::load(self, TXS_OFFSET)?;
             (ts_root, account_tree_nodes, accounts, tsb_infos, txs)
         } else {
             return Err("unreachable".to_string());
         };
 
-        let token_default_leaf_node = TokenTree::<TokenTreeNodes, Tokens>::default_leaf_node();
+        let token_default_leaf_node =
+            TokenTree::<TokenTreeNodes, Tokens>::default_leaf_node(self.params.token_tree_height);
         let default_token_root =
-            poseidon::<3>(&[token_default_leaf_node[TOKEN_TREE_HEIGHT - 1]; 2]);
+            poseidon::<3>(&[token_default_leaf_node[self.params.token_tree_height - 1]; 2]);
         let default_leaf_node =
             poseidon::<5>(&[Fr::from(3u64), Fr::zero(), Fr::zero(), default_token_root]);
         let mut default_proof = vec![default_leaf_node];
-        for i in 1..ACCOUNT_TREE_HEIGHT {
+        for i in 1..self.params.account_tree_height {
             default_proof.push(poseidon::<3>(&[default_proof[i - 1], default_proof[i - 1]]));
         }
         let actual_level = match &accounts {
@@ -106,70 +220,194 @@ impl TsFile {
             accounts,
             actual_level,
             default_proof,
+            token_tree_height: self.params.token_tree_height,
+            deferred: false,
+            dirty_leaf_ids: vec![],
+            journal: None,
             _phantom: std::marker::PhantomData,
         };
 
         let state = StateInstance {
+            params: self.params,
             ts_root,
             accounts: account_tree,
             tsb_infos,
+            bond_token_ids: BondTokenIds::new(self),
             txs,
         };
 
         Ok(state)
     }
+    // the length of the whole file, header included
+    fn raw_len(&self) -> Result<usize, String> {
+        let storage = self.file.lock().map_err(|e| e.to_string())?;
+        Ok(match &storage.wal {
+            Some(wal) => wal.len() as usize,
+            None => storage.file.metadata().map_err(|e| e.to_string())?.len() as usize,
+        })
+    }
+    fn read_raw(&self, index: usize, buf: &mut [u8]) -> Result<(), String> {
+        let mut storage = self.file.lock().map_err(|e| e.to_string())?;
+        let Storage { file, wal } = &mut *storage;
+        match wal {
+            Some(wal) => wal.read(file, index as u64, buf)?,
+            None => {
+                file.seek(SeekFrom::Start(index as u64))
+                    .map_err(|e| e.to_string())?;
+                file.read(buf).map_err(|e| e.to_string())?;
+            }
+        }
+        Ok(())
+    }
+    fn write_raw(&self, index: usize, buf: &[u8]) -> Result<(), String> {
+        let mut storage = self.file.lock().map_err(|e| e.to_string())?;
+        let Storage { file, wal } = &mut *storage;
+        match wal {
+            Some(wal) => wal.write(file, index as u64, buf)?,
+            None => {
+                file.seek(SeekFrom::Start(index as u64))
+                    .map_err(|e| e.to_string())?;
+                file.write(buf).map_err(|e| e.to_string())?;
+            }
+        }
+        Ok(())
+    }
+    // the data, which the offsets below are relative to, starts after the header and the index slots
+    fn len(&self) -> Result<usize, String> {
+        Ok(self.raw_len()?.saturating_sub(DATA_OFFSET))
+    }
     fn is_empty(&self) -> Result<bool, String> {
-        let file = self.file.lock().map_err(|e| e.to_string())?;
-        let metadata = file.metadata().map_err(|e| e.to_string())?;
-        Ok(metadata.len() == 0)
+        Ok(self.raw_len()? == 0)
     }
     fn read<const LEN: usize>(&self, index: usize) -> Result<[u8; LEN], String> {
-        let mut file = self.file.lock().map_err(|e| e.to_string())?;
-        file.seek(SeekFrom::Start(index as u64))
-            .map_err(|e| e.to_string())?;
         let mut buf = [0u8; LEN];
-        file.read(&mut buf).map_err(|e| e.to_string())?;
+        self.read_raw(DATA_OFFSET + index, &mut buf)?;
         Ok(buf)
     }
     fn write(&self, index: usize, buf: &[u8], len: usize) -> Result<(), String> {
-        let mut file = self.file.lock().map_err(|e| e.to_string())?;
-        file.seek(SeekFrom::Start(index as u64))
-            .map_err(|e| e.to_string())?;
         let mut buf = buf.to_vec();
         buf.resize(len, 0);
-        file.write(&buf).map_err(|e| e.to_string())?;
+        self.write_raw(DATA_OFFSET + index, &buf)
+    }
+    fn set_len(&self, len: usize) -> Result<(), String> {
+        let len = DATA_OFFSET + len;
+        let mut storage = self.file.lock().map_err(|e| e.to_string())?;
+        match &mut storage.wal {
+            Some(wal) => wal.set_len(len as u64),
+            None => storage
+                .file
+                .set_len(len as u64)
+                .map_err(|e| e.to_string())?,
+        }
         Ok(())
     }
     fn alloc_val(&self, size: usize) -> Result<usize, String> {
-        let mut file = self.file.lock().map_err(|e| e.to_string())?;
-        let index = file.seek(SeekFrom::End(0)).map_err(|e| e.to_string())? as usize;
-        file.set_len((index + size) as u64)
-            .map_err(|e| e.to_string())?;
+        let index = self.len()?;
+        self.set_len(index + size)?;
         Ok(index)
     }
     fn alloc_arr(&self, size: usize, len: usize) -> Result<usize, String> {
-        let mut file = self.file.lock().map_err(|e| e.to_string())?;
-        let index = file.seek(SeekFrom::End(0)).map_err(|e| e.to_string())? as usize;
-        file.set_len((index + std::mem::size_of::<u64>() + size * len) as u64)
-            .map_err(|e| e.to_string())?;
+        let index = self.len()?;
+        self.set_len(index + std::mem::size_of::<u64>() + size * len)?;
         Ok(index)
     }
     pub fn perform_with_file(
         filename: &str,
         l2_genesis_l1_anchor_id: Option<u64>,
+        header: Header,
+        callbackfn: impl FnMut(&mut Self) -> Result<(), String>,
+    ) -> Result<(), String> {
+        Self::perform_with(filename, l2_genesis_l1_anchor_id, header, true, callbackfn)
+    }
+    // for the temporary files of replays, which are written in place
+    pub fn perform_with_tmp_file(
+        filename: &str,
+        l2_genesis_l1_anchor_id: Option<u64>,
+        header: Header,
+        callbackfn: impl FnMut(&mut Self) -> Result<(), String>,
+    ) -> Result<(), String> {
+        Self::perform_with(filename, l2_genesis_l1_anchor_id, header, false, callbackfn)
+    }
+    fn perform_with(
+        filename: &str,
+        l2_genesis_l1_anchor_id: Option<u64>,
+        header: Header,
+        journaled: bool,
         mut callbackfn: impl FnMut(&mut Self) -> Result<(), String>,
     ) -> Result<(), String> {
-        let mut ts_file = Self::open(
-            filename,
-            match l2_genesis_l1_anchor_id {
-                Some(id) => Some(id - 1),
-                None => None,
-            },
-        )?;
+        let mut ts_file = Self::open_with(filename, l2_genesis_l1_anchor_id, header, journaled)?;
         callbackfn(&mut ts_file)?;
         ts_file.close()?;
         Ok(())
     }
+    // brings a state file to the current format version, returns the version it had if it changed.
+    // the files written before the header was introduced have no magic number and count as version 0,
+    // their header is taken from the configuration, which must be the one they were synced with
+    pub fn migrate(
+        filename: &str,
+        l2_genesis_l1_anchor_id: u64,
+        header: Header,
+    ) -> Result<Option<u64>, String> {
+        let mut file = File::open(filename).map_err(|e| e.to_string())?;
+        let len = file.metadata().map_err(|e| e.to_string())?.len() as usize;
+        let mut bytes = [0u8; HEADER_SIZE];
+        file.read(&mut bytes).map_err(|e| e.to_string())?;
+        let version = match Header::decode(&bytes)? {
+            Some((version, file_header, anchor_id)) => {
+                file_header.check(anchor_id, &header, Some(l2_genesis_l1_anchor_id))?;
+                version
+            }
+            None => 0,
+        };
+        // where the data starts, which keeps its layout, and so its offsets, in the new file
+        let data_offset = match version {
+            FORMAT_VERSION => return Ok(None),
+            0 => 0,
+            1 => HEADER_SIZE,
+            2 | 3 => DATA_OFFSET,
+            version => {
+                return Err(format!(
+                    "{} has the format version {}, which this version of the kit does not read",
+                    filename, version
+                ))
+            }
+        };
+        if len < data_offset + TXS_OFFSET + std::mem::size_of::<u64>() {
+            return Err(format!("{} is not a state file", filename));
+        }
+        let tmp_filename = format!("{}.migrate.tmp", filename);
+        let mut tmp = File::create(&tmp_filename).map_err(|e| e.to_string())?;
+        tmp.write_all(&header.encode(l2_genesis_l1_anchor_id))
+            .map_err(|e| e.to_string())?;
+        // the slots of the indexes are kept from the version 2
+        let slots_offset = match version {
+            2 | 3 => HEADER_SIZE,
+            _ => data_offset,
+        };
+        let mut slots = vec![0u8; data_offset - slots_offset];
+        file.seek(SeekFrom::Start(slots_offset as u64))
+            .map_err(|e| e.to_string())?;
+        file.read_exact(&mut slots).map_err(|e| e.to_string())?;
+        slots.resize(INDEXES_SIZE, 0);
+        tmp.write_all(&slots).map_err(|e| e.to_string())?;
+        std::io::copy(&mut file, &mut tmp).map_err(|e| e.to_string())?;
+        drop(tmp);
+        let ts_file = Self::open_with(&tmp_filename, Some(l2_genesis_l1_anchor_id), header, false)?;
+        ts_file.alloc_indexes()?;
+        // the indexes were lists kept in the account records, they are rebuilt in their slots
+        if version < 2 {
+            legacy::migrate_indexes(&ts_file)?;
+        }
+        // the L2 addresses were not indexed
+        if version < 3 {
+            legacy::index_l2_addrs(&ts_file)?;
+        }
+        // nor were the bond tokens
+        legacy::index_bond_tokens(&ts_file)?;
+        ts_file.close()?;
+        std::fs::rename(&tmp_filename, filename).map_err(|e| e.to_string())?;
+        Ok(Some(version))
+    }
 }
 
 pub trait ElemPtr<'a> {
@@ -199,7 +437,7 @@ impl<'a, Ptr: ElemPtr<'a>> Array<'a, Ptr> {
         let mut indexes = vec![];
         let mut index = head;
         while index != 0 {
-            indexes.push(index as usize);
+            indexes.push(index);
             index = u64::from_le_bytes(file.read(index)?) as usize;
         }
         Ok(Self::Alloced {
@@ -210,7 +448,7 @@ impl<'a, Ptr: ElemPtr<'a>> Array<'a, Ptr> {
         })
     }
     fn alloc(&mut self) -> Result<(), String> {
-        Ok(match self {
+        match self {
             Self::Default { file } => {
                 let head = file.alloc_arr(Ptr::SIZE, 1)?;
                 let indexes = vec![head];
@@ -228,46 +466,66 @@ impl<'a, Ptr: ElemPtr<'a>> Array<'a, Ptr> {
                 file.write(*last_idx, &index.to_le_bytes(), std::mem::size_of::<u64>())?;
                 indexes.push(index as usize);
             }
-        })
+        }
+        Ok(())
+    }
+}
+impl<'a, Ptr: ElemPtr<'a>> Array<'a, Ptr> {
+    fn head(&self) -> usize {
+        match self {
+            Self::Default { .. } => 0,
+            Self::Alloced { head, .. } => *head,
+        }
+    }
+    // the number of elements of the allocated chunks
+    fn capacity(&self) -> usize {
+        match self {
+            Self::Default { .. } => 0,
+            Self::Alloced { indexes, .. } => 1 << (indexes.len() - 1),
+        }
+    }
+    pub fn locate(&self, index: usize) -> Result<Option<usize>, String> {
+        match self {
+            Self::Default { .. } => Ok(None),
+            Self::Alloced { indexes, .. } => Ok(Some(if index == 0 {
+                *indexes.first().ok_or("invalid array struct")? + std::mem::size_of::<u64>()
+            } else {
+                let index = index as u64;
+                let leading_one_idx =
+                    std::mem::size_of::<u64>() * 8 - 1 - index.leading_zeros() as usize;
+                let tmp = if leading_one_idx + 1 < indexes.len() {
+                    indexes.get(leading_one_idx + 1).ok_or("unreachable")?
+                } else {
+                    return Ok(None);
+                };
+                tmp + std::mem::size_of::<u64>()
+                    + (index - (1 << leading_one_idx)) as usize * Ptr::SIZE
+            })),
+        }
     }
 }
 impl<'a, Ptr: ElemPtr<'a>> ArrayTrait<Ptr::Elem> for Array<'a, Ptr> {
     fn get(&self, index: usize) -> Result<Ptr::Elem, String> {
-        match self {
-            Self::Default { file } => Ok(Ptr::default(file)),
-            Self::Alloced { file, indexes, .. } => {
-                let ptr = Ptr::new(
-                    file,
-                    if index == 0 {
-                        *indexes.get(0).ok_or("invalid array struct")? + std::mem::size_of::<u64>()
-                    } else {
-                        let index = index as u64;
-                        let leading_one_idx =
-                            std::mem::size_of::<u64>() * 8 - 1 - index.leading_zeros() as usize;
-                        let tmp = if leading_one_idx + 1 < indexes.len() {
-                            indexes.get(leading_one_idx + 1).ok_or("unreachable")?
-                        } else {
-                            return Ok(Ptr::default(file));
-                        };
-                        tmp + std::mem::size_of::<u64>()
-                            + (index - (1 << leading_one_idx)) as usize * Ptr::SIZE
-                    },
-                );
-                ptr.read()
-            }
+        let file = match self {
+            Self::Default { file } => file,
+            Self::Alloced { file, .. } => file,
+        };
+        match self.locate(index)? {
+            Some(index) => Ptr::new(file, index).read(),
+            None => Ok(Ptr::default(file)),
         }
     }
     fn set(&mut self, index: usize, elem: &Ptr::Elem) -> Result<(), String> {
         match self {
             Self::Default { .. } => {
                 self.alloc()?;
-                return self.set(index, elem);
+                self.set(index, elem)
             }
             Self::Alloced { file, indexes, .. } => {
                 let ptr = Ptr::new(
                     file,
                     if index == 0 {
-                        *indexes.get(0).ok_or("invalid array struct")? + std::mem::size_of::<u64>()
+                        *indexes.first().ok_or("invalid array struct")? + std::mem::size_of::<u64>()
                     } else {
                         let index = index as u64;
                         let leading_one_idx =
@@ -351,5 +609,6 @@ pub type StateInstance<'a> = State<
     TokenTreeNodes<'a>,
     Tokens<'a>,
     TSBInfos<'a>,
+    BondTokenIds<'a>,
     Txs<'a>,
 >;
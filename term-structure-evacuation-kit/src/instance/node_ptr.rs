@@ -25,7 +25,7 @@ impl<'a> ElemPtr<'a> for NodePtr<'a> {
     }
     fn write(&self, node: &Option<Fr>) -> Result<(), String> {
         let mut index = self.index;
-        Ok(match node {
+        match node {
             Some(node) => {
                 if node == &Fr::zero() {
                     self.file.write(index, &[255u8; 32], Self::SIZE)?;
@@ -36,7 +36,8 @@ impl<'a> ElemPtr<'a> for NodePtr<'a> {
             None => {
                 self.file.write(index, &[0u8; 32], Self::SIZE)?;
             }
-        })
+        }
+        Ok(())
     }
     fn default(_: &'a TsFile) -> Self::Elem {
         Self::Elem::default()
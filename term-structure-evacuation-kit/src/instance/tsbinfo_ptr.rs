@@ -1,5 +1,8 @@
-use super::{load_fr, load_u64, write_fr, write_u64, ElemPtr, TsFile};
-use ts_state::TSBInfo;
+use super::{key_index, load_fr, load_u64, write_fr, write_u64, ElemPtr, TsFile, BOND_TOKENS_SLOT};
+use ark_bn254::Fr;
+use num_bigint::BigUint;
+use ts_poseidon::poseidon;
+use ts_state::{Map, TSBInfo};
 
 pub struct TSBInfoPtr<'a> {
     file: &'a TsFile,
@@ -30,3 +33,29 @@ impl<'a> ElemPtr<'a> for TSBInfoPtr<'a> {
         Self::Elem::default()
     }
 }
+
+// the bond tokens by their base token and maturity, in a hash index keyed by the hash of both
+pub struct BondTokenIds<'a> {
+    file: &'a TsFile,
+}
+impl<'a> BondTokenIds<'a> {
+    pub fn new(file: &'a TsFile) -> Self {
+        Self { file }
+    }
+}
+fn to_key(tsb_info: &TSBInfo) -> [u8; 32] {
+    let hash = poseidon::<3>(&[Fr::from(tsb_info.base_token_id as u64), tsb_info.maturity]);
+    let biguint: BigUint = hash.into();
+    let mut key = [0u8; 32];
+    let bytes = biguint.to_bytes_le();
+    key[..bytes.len()].copy_from_slice(&bytes);
+    key
+}
+impl<'a> Map<TSBInfo, u64> for BondTokenIds<'a> {
+    fn get(&self, key: &TSBInfo) -> Result<Option<u64>, String> {
+        key_index::find(self.file, BOND_TOKENS_SLOT, to_key(key))
+    }
+    fn set(&mut self, key: &TSBInfo, elem: &u64) -> Result<(), String> {
+        key_index::insert(self.file, BOND_TOKENS_SLOT, to_key(key), *elem)
+    }
+}
@@ -13,7 +13,7 @@ impl<'a> ElemPtr<'a> for TxPtr<'a> {
     }
     fn read(&self) -> Result<Self::Elem, String> {
         let bytes: [u8; 512] = self.file.read(self.index)?;
-        let elem = unsafe { std::mem::transmute(bytes) };
+        let elem = unsafe { std::mem::transmute::<[u8; 512], Tx>(bytes) };
         Ok(elem)
     }
     fn write(&self, elem: &Self::Elem) -> Result<(), String> {
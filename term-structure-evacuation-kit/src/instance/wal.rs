@@ -0,0 +1,180 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{ErrorKind, Read, Seek, SeekFrom, Write},
+};
+
+const PAGE_SIZE: usize = 256;
+
+// the writes since the last commit are kept in memory by pages of the ts file. a commit first
+// writes them all to `<ts_filename>.wal` with a checksum, then applies them to the ts file and
+// removes the log, so that a crash leaves either a complete log to apply again on reopen, or an
+// incomplete one to drop with the ts file still as it was at the previous commit
+pub struct Wal {
+    filename: String,
+    pages: BTreeMap<u64, Vec<u8>>,
+    // the length of the ts file with the pending writes, and the one on disk
+    len: u64,
+    file_len: u64,
+}
+impl Wal {
+    pub fn open(ts_filename: &str, file: &mut File) -> Result<Self, String> {
+        let filename = format!("{}.wal", ts_filename);
+        match std::fs::read(&filename) {
+            Ok(log) => {
+                if let Some((len, pages)) = parse(&log) {
+                    apply(file, len, &pages)?;
+                }
+                std::fs::remove_file(&filename).map_err(|e| e.to_string())?;
+                sync_dir(&filename)?;
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(e.to_string()),
+        }
+        let file_len = file.metadata().map_err(|e| e.to_string())?.len();
+        Ok(Self {
+            filename,
+            pages: BTreeMap::new(),
+            len: file_len,
+            file_len,
+        })
+    }
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+    pub fn set_len(&mut self, len: u64) {
+        self.len = self.len.max(len);
+    }
+    pub fn read(&self, file: &mut File, index: u64, buf: &mut [u8]) -> Result<(), String> {
+        read_file(file, self.file_len, index, buf)?;
+        if buf.is_empty() {
+            return Ok(());
+        }
+        let end = index + buf.len() as u64;
+        for (page_id, page) in self
+            .pages
+            .range(index / PAGE_SIZE as u64..=(end - 1) / PAGE_SIZE as u64)
+        {
+            let page_start = page_id * PAGE_SIZE as u64;
+            let start = index.max(page_start);
+            let stop = end.min(page_start + PAGE_SIZE as u64);
+            buf[(start - index) as usize..(stop - index) as usize].copy_from_slice(
+                &page[(start - page_start) as usize..(stop - page_start) as usize],
+            );
+        }
+        Ok(())
+    }
+    pub fn write(&mut self, file: &mut File, index: u64, buf: &[u8]) -> Result<(), String> {
+        if buf.is_empty() {
+            return Ok(());
+        }
+        let end = index + buf.len() as u64;
+        for page_id in index / PAGE_SIZE as u64..=(end - 1) / PAGE_SIZE as u64 {
+            let page_start = page_id * PAGE_SIZE as u64;
+            let start = index.max(page_start);
+            let stop = end.min(page_start + PAGE_SIZE as u64);
+            let src = &buf[(start - index) as usize..(stop - index) as usize];
+            let range = (start - page_start) as usize..(stop - page_start) as usize;
+            match self.pages.get_mut(&page_id) {
+                Some(page) => page[range].copy_from_slice(src),
+                None => {
+                    let mut page = vec![0u8; PAGE_SIZE];
+                    read_file(file, self.file_len, page_start, &mut page)?;
+                    // rewriting the same bytes, like the header of an unchanged file, logs nothing
+                    if page[range.clone()] != *src {
+                        page[range].copy_from_slice(src);
+                        self.pages.insert(page_id, page);
+                    }
+                }
+            }
+        }
+        self.set_len(end);
+        Ok(())
+    }
+    pub fn commit(&mut self, file: &mut File) -> Result<(), String> {
+        if self.pages.is_empty() && self.len == self.file_len {
+            return Ok(());
+        }
+        let mut log = self.len.to_le_bytes().to_vec();
+        for (page_id, page) in self.pages.iter() {
+            log.extend(page_id.to_le_bytes());
+            log.extend(page);
+        }
+        log.extend(checksum(&log).to_le_bytes());
+        let mut wal_file = File::create(&self.filename).map_err(|e| e.to_string())?;
+        wal_file.write_all(&log).map_err(|e| e.to_string())?;
+        wal_file.sync_all().map_err(|e| e.to_string())?;
+        sync_dir(&self.filename)?;
+
+        let pages = std::mem::take(&mut self.pages);
+        apply(file, self.len, &pages)?;
+        self.file_len = self.len;
+        std::fs::remove_file(&self.filename).map_err(|e| e.to_string())?;
+        sync_dir(&self.filename)
+    }
+}
+
+// FNV-1a, only to tell a complete log from a torn one
+pub fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn parse(log: &[u8]) -> Option<(u64, BTreeMap<u64, Vec<u8>>)> {
+    if log.len() < 16 || !(log.len() - 16).is_multiple_of(8 + PAGE_SIZE) {
+        return None;
+    }
+    let (body, sum) = log.split_at(log.len() - 8);
+    if checksum(body).to_le_bytes() != sum {
+        return None;
+    }
+    let len = u64::from_le_bytes(body[..8].try_into().ok()?);
+    let pages = body[8..]
+        .chunks_exact(8 + PAGE_SIZE)
+        .map(|record| {
+            let (page_id, page) = record.split_at(8);
+            (
+                u64::from_le_bytes(page_id.try_into().unwrap()),
+                page.to_vec(),
+            )
+        })
+        .collect();
+    Some((len, pages))
+}
+
+fn apply(file: &mut File, len: u64, pages: &BTreeMap<u64, Vec<u8>>) -> Result<(), String> {
+    file.set_len(len).map_err(|e| e.to_string())?;
+    for (page_id, page) in pages.iter() {
+        let page_start = page_id * PAGE_SIZE as u64;
+        let page_len = (len - page_start).min(PAGE_SIZE as u64) as usize;
+        file.seek(SeekFrom::Start(page_start))
+            .map_err(|e| e.to_string())?;
+        file.write_all(&page[..page_len])
+            .map_err(|e| e.to_string())?;
+    }
+    file.sync_all().map_err(|e| e.to_string())
+}
+
+// the creation and removal of the log only last once the directory holding it is synced
+fn sync_dir(filename: &str) -> Result<(), String> {
+    let dir = match std::path::Path::new(filename).parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => std::path::Path::new("."),
+    };
+    File::open(dir)
+        .and_then(|dir| dir.sync_all())
+        .map_err(|e| e.to_string())
+}
+
+// the part beyond the end of the file reads as zeros, like the space allocated by `set_len`
+fn read_file(file: &mut File, file_len: u64, index: u64, buf: &mut [u8]) -> Result<(), String> {
+    buf.fill(0);
+    if index >= file_len {
+        return Ok(());
+    }
+    let len = ((file_len - index) as usize).min(buf.len());
+    file.seek(SeekFrom::Start(index))
+        .map_err(|e| e.to_string())?;
+    file.read_exact(&mut buf[..len]).map_err(|e| e.to_string())
+}
@@ -1,11 +1,17 @@
-use instance::TsFile;
+use instance::{address, evacuation, history, Header, StateInstance, TsFile};
 use num_bigint::BigUint;
+use num_traits::Zero;
 use serde::{Deserialize, Serialize};
-use ts_merkle_tree::{MerkleTree, MerkleTreeWithLeaves};
+use std::collections::BTreeMap;
+use ts_merkle_tree::{
+    diff_leaf_ids, verify_merkle_prf, verify_multi_prf, MerkleLeaf, MerkleTree,
+    MerkleTreeWithLeaves,
+};
 use ts_retriever::{
-    get_remaining_l1_req_count, retrieve, retrieve_consume_data, retrieve_last_excuted_block,
+    get_latest_l1_block_id, get_remaining_l1_req_count, retrieve, retrieve_consume_data,
+    retrieve_l1_requests, retrieve_last_excuted_block,
 };
-use ts_state::{constants::TX_COUNT_PER_BLOCK, Array, Value};
+use ts_state::{constants::Params, Array, Ledger, OrderType, Value};
 use ts_tx::{parser::Schema, Tx};
 
 pub mod instance;
@@ -19,17 +25,56 @@ pub struct Config {
     l2_genesis_l1_anchor_id: u64,
     max_parallel_calls: u64,
     filter_batch_size: u64,
+    account_tree_height: Option<usize>,
+    token_tree_height: Option<usize>,
+    tx_count_per_block: Option<usize>,
+    chain_id: u64,
+}
+impl Config {
+    // the tree heights and block size default to the mainnet ones
+    fn params(&self) -> Params {
+        let default = Params::default();
+        Params {
+            account_tree_height: self
+                .account_tree_height
+                .unwrap_or(default.account_tree_height),
+            token_tree_height: self.token_tree_height.unwrap_or(default.token_tree_height),
+            tx_count_per_block: self
+                .tx_count_per_block
+                .unwrap_or(default.tx_count_per_block),
+        }
+    }
+    // what the state file is checked against when it is opened. the chain id has no default, so that
+    // a file synced from a test network is never recorded or opened as a mainnet one
+    fn header(&self) -> Result<Header, String> {
+        let contract_addr = hex::decode(self.ts_contract_addr.trim_start_matches("0x"))
+            .map_err(|e| format!("invalid ts_contract_addr: {}", e))?
+            .try_into()
+            .map_err(|_| "invalid ts_contract_addr: expected 20 bytes".to_string())?;
+        Ok(Header {
+            params: self.params(),
+            contract_addr,
+            chain_id: self.chain_id,
+        })
+    }
 }
 
-pub fn update_state(cfg: Config, end_block_id: Option<usize>) -> Result<(), String> {
+// with `threads`, the txs of a block that update independent accounts are applied in parallel
+pub fn update_state(
+    cfg: Config,
+    end_block_id: Option<usize>,
+    threads: Option<usize>,
+) -> Result<(), String> {
     let rt = tokio::runtime::Runtime::new().map_err(|e| e.to_string())?;
     TsFile::perform_with_file(
         cfg.ts_filename.as_str(),
         Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
         |ts_file| {
-            let sechma: Schema = serde_json::from_str(&include_str!("../ZkTrueUp_Tx_Schema.json"))
+            let sechma: Schema = serde_json::from_str(include_str!("../ZkTrueUp_Tx_Schema.json"))
                 .map_err(|e| e.to_string())?;
             let start_block_id = ts_file.latest_l1_block_id + 1;
+            let tx_count_per_block = ts_file.params.tx_count_per_block;
 
             println!("[ts_file loaded]");
             println!("    latest_l1_block_id: {}", ts_file.latest_l1_block_id);
@@ -47,6 +92,8 @@ pub fn update_state(cfg: Config, end_block_id: Option<usize>) -> Result<(), Stri
                 cfg.filter_batch_size as usize,
                 |l1_anchor_id, block| {
                     let mut state = ts_file.to_state()?;
+                    state.set_deferred(true)?;
+                    state.set_journaling(true)?;
                     let block_id = ts_file.block_count as usize;
                     if block.block_number.as_usize() < block_id {
                         return Ok(()); // skip
@@ -56,7 +103,7 @@ pub fn update_state(cfg: Config, end_block_id: Option<usize>) -> Result<(), Stri
                     let tmp = block.public_data.clone();
                     let mut tmp = tmp.as_slice();
                     let mut tx_id_offset = 0;
-                    while tmp.len() != 0 {
+                    while !tmp.is_empty() {
                         let res = match sechma.parse(&mut tmp) {
                             Ok(res) => {
                                 // println!("    tx_id_offset {:>3}: {:#?}", tx_id_offset, res);
@@ -72,7 +119,7 @@ pub fn update_state(cfg: Config, end_block_id: Option<usize>) -> Result<(), Stri
                         if let Tx::TxNoop(_) = res {
                             break;
                         }
-                        match state.push_tx((block_id - 1) * TX_COUNT_PER_BLOCK + tx_id_offset, res)
+                        match state.push_tx((block_id - 1) * tx_count_per_block + tx_id_offset, res)
                         {
                             Ok(_) => {}
                             Err(e) => {
@@ -84,27 +131,49 @@ pub fn update_state(cfg: Config, end_block_id: Option<usize>) -> Result<(), Stri
                         }
                         tx_id_offset += 1;
                     }
-                    for j in 0..tx_id_offset {
-                        match state.update((block_id - 1) * TX_COUNT_PER_BLOCK + j) {
+                    if let Some(threads) = threads {
+                        let first_tx_id = (block_id - 1) * tx_count_per_block;
+                        let tx_ids = first_tx_id..first_tx_id + tx_id_offset;
+                        let balance_changes = match state.update_parallel(tx_ids, threads) {
+                            Ok(balance_changes) => balance_changes,
                             Err(e) => {
                                 println!("# ===================== #");
                                 println!("    block: {}", block_id);
-                                println!("    tx_id: {}", (block_id - 1) * TX_COUNT_PER_BLOCK + j);
-                                println!(
-                                    "res: {:#?}",
-                                    state
-                                        .txs
-                                        .get((block_id - 1) * TX_COUNT_PER_BLOCK + j)?
-                                        .raw_tx
-                                );
-                                return Err(e.to_string());
+                                return Err(e);
                             }
-                            Ok(_) => {
-                                // println!(
-                                //     "    exec tx {:>3}: {}",
-                                //     (block_id - 1) * TX_COUNT_PER_BLOCK + j,
-                                //     ts_merkle_tree::MerkleTree::get_root(&state.accounts)?
-                                // );
+                        };
+                        for (tx_id, balance_changes) in balance_changes {
+                            index_tx(ts_file, &state, tx_id, balance_changes)?;
+                        }
+                    } else {
+                        for j in 0..tx_id_offset {
+                            match state.update((block_id - 1) * tx_count_per_block + j) {
+                                Err(e) => {
+                                    println!("# ===================== #");
+                                    println!("    block: {}", block_id);
+                                    println!(
+                                        "    tx_id: {}",
+                                        (block_id - 1) * tx_count_per_block + j
+                                    );
+                                    println!(
+                                        "res: {:#?}",
+                                        state
+                                            .txs
+                                            .get((block_id - 1) * tx_count_per_block + j)?
+                                            .raw_tx
+                                    );
+                                    return Err(e.to_string());
+                                }
+                                Ok(_) => {
+                                    let tx_id = (block_id - 1) * tx_count_per_block + j;
+                                    let balance_changes = state.take_balance_changes()?;
+                                    index_tx(ts_file, &state, tx_id, balance_changes)?;
+                                    // println!(
+                                    //     "    exec tx {:>3}: {}",
+                                    //     (block_id - 1) * tx_count_per_block + j,
+                                    //     ts_merkle_tree::MerkleTree::get_root(&state.accounts)?
+                                    // );
+                                }
                             }
                         }
                     }
@@ -112,6 +181,7 @@ pub fn update_state(cfg: Config, end_block_id: Option<usize>) -> Result<(), Stri
                         BigUint::from_bytes_be(&block.new_ts_root).into();
                     let new_state_root: ark_bn254::Fr =
                         BigUint::from_bytes_be(&block.new_state_root).into();
+                    state.commit()?;
                     state.set_ts_root(new_ts_root)?;
                     if state.get_root()? != new_state_root {
                         println!("# ===================== #");
@@ -120,18 +190,362 @@ pub fn update_state(cfg: Config, end_block_id: Option<usize>) -> Result<(), Stri
                         println!("    new_state_root: {}", new_state_root);
                         return Err("state root mismatch".to_string());
                     }
+                    history::push_block_root(
+                        ts_file,
+                        block_id,
+                        history::BlockRoot {
+                            ts_root: new_ts_root,
+                            state_root: new_state_root,
+                            timestamp: block.timestamp.as_u64(),
+                        },
+                    )?;
                     ts_file.block_count += 1;
-                    ts_file.latest_l1_block_id = l1_anchor_id as u64;
+                    ts_file.latest_l1_block_id = l1_anchor_id;
                     ts_file.sync()?;
                     Ok(())
                 },
             ))
             .map_err(|e| e.to_string())?;
+
+            // index the registrations and evacuations of the L1 requests emitted so far.
+            // evacuations are requested once no more blocks are executed, so the scan goes up to
+            // the latest L1 block rather than the latest synced one
+            let l1_req_start_block_id = std::cmp::max(
+                address::load_l1_req_synced_block_id(ts_file)? + 1,
+                cfg.l2_genesis_l1_anchor_id,
+            );
+            let l1_req_end_block_id = match end_block_id {
+                Some(end_block_id) => end_block_id as u64,
+                None => rt.block_on(get_latest_l1_block_id(
+                    cfg.api_link.as_str(),
+                    cfg.api_key.as_str(),
+                ))?,
+            };
+            if l1_req_start_block_id <= l1_req_end_block_id {
+                println!("    indexing L1 requests ...");
+                let l1_requests = rt.block_on(retrieve_l1_requests(
+                    cfg.api_link.as_str(),
+                    cfg.api_key.as_str(),
+                    cfg.ts_contract_addr.as_str(),
+                    l1_req_start_block_id,
+                    l1_req_end_block_id,
+                    cfg.filter_batch_size as usize,
+                ))?;
+                for (sender, pub_data) in l1_requests {
+                    match sechma.parse(&mut pub_data.as_slice())? {
+                        Tx::TxRegister(tx) => {
+                            address::push_l1_addr(ts_file, tx.account_id as usize, sender)?;
+                        }
+                        Tx::TxEvacuation(tx) => {
+                            evacuation::push_evacuated(
+                                ts_file,
+                                tx.sender_id as usize,
+                                tx.token_id,
+                                tx.amount,
+                            )?;
+                        }
+                        _ => {}
+                    }
+                }
+                address::set_l1_req_synced_block_id(ts_file, l1_req_end_block_id)?;
+            }
             Ok(())
         },
     )
 }
 
+// records what the history, the evacuation status and the address lookup need from an applied tx
+fn index_tx(
+    ts_file: &TsFile,
+    state: &StateInstance,
+    tx_id: usize,
+    balance_changes: Vec<ts_state::BalanceChange>,
+) -> Result<(), String> {
+    match state.txs.get(tx_id)?.raw_tx {
+        Tx::TxRegister(tx) => {
+            address::push_l2_addr(ts_file, tx.account_id as usize, tx.hashed_pub_key)?;
+        }
+        Tx::TxEvacuation(tx) => {
+            evacuation::push_evacuated(ts_file, tx.sender_id as usize, tx.token_id, tx.amount)?;
+        }
+        _ => {}
+    }
+    for change in balance_changes {
+        if change.avl_amt.is_zero() && change.locked_amt.is_zero() {
+            continue;
+        }
+        history::push_history(ts_file, tx_id, &change)?;
+    }
+    Ok(())
+}
+
+// accepts either the L1 address of the registration or the L2 address (hashed public key)
+pub fn find_acc_id(cfg: Config, addr: &str) -> Result<usize, String> {
+    let bytes = hex::decode(addr.trim_start_matches("0x")).map_err(|e| e.to_string())?;
+    let addr: [u8; 20] = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| "address must be 20 bytes".to_string())?;
+    let mut acc_id = None;
+    TsFile::perform_with_file(
+        cfg.ts_filename.as_str(),
+        Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
+        |ts_file| {
+            acc_id = address::find_acc_id_by_l1_addr(ts_file, addr)?;
+            if acc_id.is_none() {
+                let l2_addr: ark_bn254::Fr = BigUint::from_bytes_be(&addr).into();
+                acc_id = address::find_acc_id_by_l2_addr(ts_file, l2_addr)?;
+            }
+            Ok(())
+        },
+    )?;
+    acc_id.ok_or("no account is registered with this address".to_string())
+}
+
+// the snapshots cached next to the ts file, the least recently used ones beyond this count are removed
+const MAX_SNAPSHOT_COUNT: usize = 4;
+
+// runs the callback on the latest state, or on a snapshot of the state right after the given block.
+// the snapshot is cached next to the ts file as `<ts_filename>.<block_id>` and replayed from the txs in the ts file,
+// starting from the nearest earlier snapshot. a cached snapshot is only used while its state root is the one
+// the ts file records for its block, the ones of blocks dropped by a reorg are removed and replayed again
+fn perform_at_block(
+    cfg: &Config,
+    block_id: Option<usize>,
+    callbackfn: impl FnMut(&mut TsFile) -> Result<(), String>,
+) -> Result<(), String> {
+    let block_id = match block_id {
+        Some(block_id) => block_id,
+        None => {
+            return TsFile::perform_with_file(
+                cfg.ts_filename.as_str(),
+                Some(cfg.l2_genesis_l1_anchor_id),
+                cfg.header()?,
+                callbackfn,
+            )
+        }
+    };
+    let snapshot_filename = format!("{}.{}", cfg.ts_filename, block_id);
+    TsFile::perform_with_file(
+        cfg.ts_filename.as_str(),
+        Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
+        |ts_file| {
+            if block_id == 0 || block_id >= ts_file.block_count as usize {
+                return Err(format!("block {} has not been synced", block_id));
+            }
+            let history::BlockRoot {
+                ts_root,
+                state_root,
+                ..
+            } = history::load_block_root(ts_file, block_id)?
+                .ok_or(format!("no state root is recorded for block {}", block_id))?;
+            let mut base = None;
+            for (cached_block_id, filename) in snapshot_filenames(cfg)?.into_iter().rev() {
+                if cached_block_id > block_id {
+                    continue;
+                }
+                let recorded_root = history::load_block_root(ts_file, cached_block_id)?
+                    .map(|block_root| block_root.state_root);
+                if recorded_root.is_some() && load_snapshot_root(cfg, &filename) == recorded_root {
+                    base = Some((cached_block_id, filename));
+                    break;
+                }
+                remove_snapshot(&filename)?;
+            }
+            if let Some((cached_block_id, _)) = base {
+                if cached_block_id == block_id {
+                    return Ok(());
+                }
+            }
+
+            let tmp_filename = format!("{}.tmp", snapshot_filename);
+            if std::path::Path::new(&tmp_filename).exists() {
+                std::fs::remove_file(&tmp_filename).map_err(|e| e.to_string())?;
+            }
+            let base_block_id = match &base {
+                Some((base_block_id, base_filename)) => {
+                    std::fs::copy(base_filename, &tmp_filename).map_err(|e| e.to_string())?;
+                    *base_block_id
+                }
+                None => 0,
+            };
+            let state = ts_file.to_state()?;
+            TsFile::perform_with_tmp_file(
+                tmp_filename.as_str(),
+                Some(cfg.l2_genesis_l1_anchor_id),
+                cfg.header()?,
+                |snapshot| {
+                    {
+                        let mut snapshot_state = snapshot.to_state()?;
+                        snapshot_state.set_deferred(true)?;
+                        for replayed_block_id in base_block_id + 1..=block_id {
+                            for tx_id in
+                                push_block_txs(&state, &mut snapshot_state, replayed_block_id)?
+                            {
+                                snapshot_state.update(tx_id)?;
+                            }
+                        }
+                        snapshot_state.commit()?;
+                        snapshot_state.set_ts_root(ts_root)?;
+                        if snapshot_state.get_root()? != state_root {
+                            return Err("state root mismatch".to_string());
+                        }
+                    }
+                    snapshot.block_count = block_id as u64 + 1;
+                    Ok(())
+                },
+            )?;
+            std::fs::rename(&tmp_filename, &snapshot_filename).map_err(|e| e.to_string())
+        },
+    )?;
+    evict_snapshots(cfg, &snapshot_filename)?;
+    TsFile::perform_with_file(
+        snapshot_filename.as_str(),
+        Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
+        callbackfn,
+    )
+}
+
+// the snapshots cached as `<ts_filename>.<block_id>`, by block id
+fn snapshot_filenames(cfg: &Config) -> Result<Vec<(usize, String)>, String> {
+    let path = std::path::Path::new(&cfg.ts_filename);
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => std::path::Path::new("."),
+    };
+    let prefix = format!(
+        "{}.",
+        path.file_name()
+            .ok_or("invalid ts_filename")?
+            .to_string_lossy()
+    );
+    let mut filenames = vec![];
+    for entry in std::fs::read_dir(dir).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if let Some(block_id) = name.strip_prefix(&prefix) {
+            if !block_id.is_empty() && block_id.chars().all(|c| c.is_ascii_digit()) {
+                if let Ok(block_id) = block_id.parse::<usize>() {
+                    filenames.push((block_id, format!("{}.{}", cfg.ts_filename, block_id)));
+                }
+            }
+        }
+    }
+    filenames.sort();
+    Ok(filenames)
+}
+
+// the state root of a cached snapshot, none when it cannot be opened
+fn load_snapshot_root(cfg: &Config, filename: &str) -> Option<ark_bn254::Fr> {
+    let mut root = None;
+    TsFile::perform_with_file(
+        filename,
+        Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header().ok()?,
+        |snapshot| {
+            root = Some(snapshot.to_state()?.get_root()?);
+            Ok(())
+        },
+    )
+    .ok()?;
+    root
+}
+
+fn remove_snapshot(filename: &str) -> Result<(), String> {
+    for filename in [filename.to_string(), format!("{}.wal", filename)] {
+        if std::path::Path::new(&filename).exists() {
+            std::fs::remove_file(&filename).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+// marks the snapshot as used, and removes the least recently used ones beyond `MAX_SNAPSHOT_COUNT`
+fn evict_snapshots(cfg: &Config, used_filename: &str) -> Result<(), String> {
+    std::fs::File::options()
+        .write(true)
+        .open(used_filename)
+        .and_then(|file| file.set_modified(std::time::SystemTime::now()))
+        .map_err(|e| e.to_string())?;
+    let mut snapshots = vec![];
+    for (_, filename) in snapshot_filenames(cfg)? {
+        let used_at = std::fs::metadata(&filename)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|e| e.to_string())?;
+        snapshots.push((used_at, filename));
+    }
+    snapshots.sort();
+    snapshots.reverse();
+    for (_, filename) in snapshots.iter().skip(MAX_SNAPSHOT_COUNT) {
+        if filename != used_filename {
+            remove_snapshot(filename)?;
+        }
+    }
+    Ok(())
+}
+
+// pushes the txs of a synced block into a state being replayed, returns the ids of the pushed txs
+fn push_block_txs(
+    state: &StateInstance,
+    replayed_state: &mut StateInstance,
+    block_id: usize,
+) -> Result<std::ops::Range<usize>, String> {
+    let first_tx_id = (block_id - 1) * state.params.tx_count_per_block;
+    let mut tx_count = 0;
+    while tx_count < state.params.tx_count_per_block {
+        let raw_tx = state.txs.get(first_tx_id + tx_count)?.raw_tx;
+        if let Tx::TxNoop(_) = raw_tx {
+            break;
+        }
+        replayed_state.push_tx(first_tx_id + tx_count, raw_tx)?;
+        tx_count += 1;
+    }
+    Ok(first_tx_id..first_tx_id + tx_count)
+}
+
+// replays the synced blocks into `<ts_filename>.<name>.tmp`, which is removed afterwards.
+// the callback gets the synced state, the empty deferred state to replay into and the last block to replay
+fn perform_replay(
+    cfg: &Config,
+    name: &str,
+    end_block_id: Option<usize>,
+    mut callbackfn: impl FnMut(&StateInstance, &mut StateInstance, usize) -> Result<(), String>,
+) -> Result<(), String> {
+    let replay_filename = format!("{}.{}.tmp", cfg.ts_filename, name);
+    if std::path::Path::new(&replay_filename).exists() {
+        std::fs::remove_file(&replay_filename).map_err(|e| e.to_string())?;
+    }
+    TsFile::perform_with_file(
+        cfg.ts_filename.as_str(),
+        Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
+        |ts_file| {
+            let state = ts_file.to_state()?;
+            let end_block_id = match end_block_id {
+                Some(end_block_id) if end_block_id >= ts_file.block_count as usize => {
+                    return Err(format!("block {} has not been synced", end_block_id));
+                }
+                Some(end_block_id) => end_block_id,
+                None => ts_file.block_count as usize - 1,
+            };
+            TsFile::perform_with_tmp_file(
+                replay_filename.as_str(),
+                Some(cfg.l2_genesis_l1_anchor_id),
+                cfg.header()?,
+                |replayed| {
+                    let mut replayed_state = replayed.to_state()?;
+                    replayed_state.set_deferred(true)?;
+                    callbackfn(&state, &mut replayed_state, end_block_id)
+                },
+            )
+        },
+    )?;
+    std::fs::remove_file(&replay_filename).map_err(|e| e.to_string())
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct EvacuProof {
     #[serde(rename = "currentTime")]
@@ -161,42 +575,171 @@ pub struct EvacuProof {
     #[serde(rename = "tokenMkPrf")]
     token_mk_prf: Vec<String>,
 }
-pub fn get_evacu_prf(cfg: Config, acc_id: usize, token_id: usize) -> Result<EvacuProof, String> {
+pub fn get_evacu_prf(
+    cfg: Config,
+    acc_id: usize,
+    token_id: usize,
+    block_id: Option<usize>,
+) -> Result<EvacuProof, String> {
+    TsFile::perform_with_file(
+        cfg.ts_filename.as_str(),
+        Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
+        |ts_file| match evacuation::find_evacuated(ts_file, acc_id, token_id as u64)? {
+            Some(entry) => Err(format!(
+                "token {} of account {} has already been evacuated (amount: {})",
+                token_id, acc_id, entry.amount
+            )),
+            None => Ok(()),
+        },
+    )?;
     let mut evacu_proof = EvacuProof::default();
+    perform_at_block(&cfg, block_id, |ts_file| {
+        let state = ts_file.to_state()?;
+        let acc_prf = state.accounts.verify_leaf(acc_id)?;
+        let account = state.accounts.leaf_at(acc_id)?;
+        let token_prf = account.tokens.verify_leaf(token_id)?;
+
+        // the proofs are checked on their own before they are handed out
+        let hash = |left: ark_bn254::Fr, right| Ok(ts_poseidon::poseidon::<3>(&[left, right]));
+        verify_merkle_prf(hash, &token_prf.merkle_prf)?;
+        verify_merkle_prf(hash, &acc_prf.merkle_prf)?;
+        if token_prf.leaf.digest()? != token_prf.merkle_prf.ori_leaf_node
+            || account.digest()? != acc_prf.merkle_prf.ori_leaf_node
+            || acc_prf.merkle_prf.ori_root != state.accounts.get_root()?
+        {
+            return Err("the evacuation proof does not match the state".to_string());
+        }
+
+        evacu_proof.state_root = state.get_root()?.to_string();
+        evacu_proof.ts_root = state.ts_root.get()?.to_string();
+        evacu_proof.acc_root = state.accounts.get_root()?.to_string();
+        evacu_proof.acc_id = acc_id.to_string();
+        evacu_proof.nonce = account.nonce.to_string();
+        evacu_proof.ts_addr = account.l2_addr.to_string();
+        evacu_proof.token_root = account.tokens.get_root()?.to_string();
+        evacu_proof.token_id = token_id.to_string();
+        evacu_proof.avl_amt = token_prf.leaf.avl_amt.to_string();
+        evacu_proof.locked_amt = token_prf.leaf.locked_amt.to_string();
+        evacu_proof.acc_mk_prf = acc_prf
+            .merkle_prf
+            .proof
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        evacu_proof.token_mk_prf = token_prf
+            .merkle_prf
+            .proof
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        Ok(())
+    })?;
+    evacu_proof.current_time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_secs()
+        .to_string();
+    Ok(evacu_proof)
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MultiEvacuProof {
+    #[serde(rename = "currentTime")]
+    current_time: String,
+    #[serde(rename = "stateRoot")]
+    state_root: String,
+    #[serde(rename = "tsRoot")]
+    ts_root: String,
+    #[serde(rename = "accRoot")]
+    acc_root: String,
+    #[serde(rename = "accId")]
+    acc_id: String,
+    #[serde(rename = "nonce")]
+    nonce: String,
+    #[serde(rename = "tsAddr")]
+    ts_addr: String,
+    #[serde(rename = "tokenRoot")]
+    token_root: String,
+    #[serde(rename = "tokenIds")]
+    token_ids: Vec<String>,
+    #[serde(rename = "avlAmts")]
+    avl_amts: Vec<String>,
+    #[serde(rename = "lockedAmts")]
+    locked_amts: Vec<String>,
+    #[serde(rename = "accMkPrf")]
+    acc_mk_prf: Vec<String>,
+    // the siblings of the token multi-proof, level by level from the leaves
+    #[serde(rename = "tokenMultiPrf")]
+    token_multi_prf: Vec<String>,
+}
+// like `get_evacu_prf`, for all the tokens of the account that hold funds and were not evacuated
+pub fn get_multi_evacu_prf(
+    cfg: Config,
+    acc_id: usize,
+    block_id: Option<usize>,
+) -> Result<MultiEvacuProof, String> {
+    let mut evacuated_token_ids = vec![];
     TsFile::perform_with_file(
         cfg.ts_filename.as_str(),
         Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
         |ts_file| {
-            let state = ts_file.to_state()?;
-            let acc_prf = state.accounts.verify_leaf(acc_id)?;
-            let account = state.accounts.leaf_at(acc_id)?;
-            let token_prf = account.tokens.verify_leaf(token_id)?;
-
-            evacu_proof.state_root = state.get_root()?.to_string();
-            evacu_proof.ts_root = state.ts_root.get()?.to_string();
-            evacu_proof.acc_root = state.accounts.get_root()?.to_string();
-            evacu_proof.acc_id = acc_id.to_string();
-            evacu_proof.nonce = account.nonce.to_string();
-            evacu_proof.ts_addr = account.l2_addr.to_string();
-            evacu_proof.token_root = account.tokens.get_root()?.to_string();
-            evacu_proof.token_id = token_id.to_string();
-            evacu_proof.avl_amt = token_prf.leaf.avl_amt.to_string();
-            evacu_proof.locked_amt = token_prf.leaf.locked_amt.to_string();
-            evacu_proof.acc_mk_prf = acc_prf
-                .merkle_prf
-                .proof
-                .iter()
-                .map(|x| x.to_string())
-                .collect();
-            evacu_proof.token_mk_prf = token_prf
-                .merkle_prf
-                .proof
+            evacuated_token_ids = evacuation::load_evacuated(ts_file, acc_id)?
                 .iter()
-                .map(|x| x.to_string())
+                .map(|entry| entry.token_id as usize)
                 .collect();
             Ok(())
         },
     )?;
+    let mut evacu_proof = MultiEvacuProof::default();
+    perform_at_block(&cfg, block_id, |ts_file| {
+        let state = ts_file.to_state()?;
+        let acc_prf = state.accounts.verify_leaf(acc_id)?;
+        let account = state.accounts.leaf_at(acc_id)?;
+        let mut token_ids = vec![];
+        for token_id in account.tokens.leaf_ids() {
+            let token_id = token_id?;
+            if !evacuated_token_ids.contains(&token_id) {
+                token_ids.push(token_id);
+            }
+        }
+        if token_ids.is_empty() {
+            return Err(format!("account {} has no funds to evacuate", acc_id));
+        }
+        let token_prf = account.tokens.verify_leaf_nodes(&token_ids)?;
+
+        let hash = |left: ark_bn254::Fr, right| Ok(ts_poseidon::poseidon::<3>(&[left, right]));
+        verify_multi_prf(hash, &token_prf)?;
+        verify_merkle_prf(hash, &acc_prf.merkle_prf)?;
+        if account.digest()? != acc_prf.merkle_prf.ori_leaf_node
+            || acc_prf.merkle_prf.ori_root != state.accounts.get_root()?
+        {
+            return Err("the evacuation proof does not match the state".to_string());
+        }
+
+        evacu_proof.state_root = state.get_root()?.to_string();
+        evacu_proof.ts_root = state.ts_root.get()?.to_string();
+        evacu_proof.acc_root = state.accounts.get_root()?.to_string();
+        evacu_proof.acc_id = acc_id.to_string();
+        evacu_proof.nonce = account.nonce.to_string();
+        evacu_proof.ts_addr = account.l2_addr.to_string();
+        evacu_proof.token_root = account.tokens.get_root()?.to_string();
+        for token_id in token_ids {
+            let token = account.tokens.leaf_at(token_id)?;
+            evacu_proof.token_ids.push(token_id.to_string());
+            evacu_proof.avl_amts.push(token.avl_amt.to_string());
+            evacu_proof.locked_amts.push(token.locked_amt.to_string());
+        }
+        evacu_proof.acc_mk_prf = acc_prf
+            .merkle_prf
+            .proof
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        evacu_proof.token_multi_prf = token_prf.proof.iter().map(|x| x.to_string()).collect();
+        Ok(())
+    })?;
     evacu_proof.current_time = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map_err(|e| e.to_string())?
@@ -212,23 +755,794 @@ pub struct Funds {
     avl_amt: String,
     locked_amt: String,
 }
-pub fn query_funds(cfg: Config, acc_id: usize, token_id: usize) -> Result<Funds, String> {
+pub fn query_funds(
+    cfg: Config,
+    acc_id: usize,
+    token_id: usize,
+    block_id: Option<usize>,
+) -> Result<Funds, String> {
     let mut funds = Funds::default();
+    perform_at_block(&cfg, block_id, |ts_file| {
+        let state = ts_file.to_state()?;
+        let account = state.accounts.leaf_at(acc_id)?;
+        let token = account.tokens.leaf_at(token_id)?;
+        funds.acc_id = acc_id;
+        funds.token_id = token_id;
+        funds.avl_amt = token.avl_amt.to_string();
+        funds.locked_amt = token.locked_amt.to_string();
+        Ok(())
+    })?;
+    Ok(funds)
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct EvacuatedFunds {
+    token_id: u64,
+    amount: String,
+}
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct EvacuStatus {
+    acc_id: usize,
+    evacuable: Vec<Funds>,
+    evacuated: Vec<EvacuatedFunds>,
+}
+// lists the tokens of an account that still hold funds and have not been evacuated yet
+pub fn get_evacu_status(cfg: Config, acc_id: usize) -> Result<EvacuStatus, String> {
+    let mut status = EvacuStatus {
+        acc_id,
+        ..Default::default()
+    };
     TsFile::perform_with_file(
         cfg.ts_filename.as_str(),
         Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
         |ts_file| {
             let state = ts_file.to_state()?;
+            let evacuated = evacuation::load_evacuated(ts_file, acc_id)?;
             let account = state.accounts.leaf_at(acc_id)?;
-            let token = account.tokens.leaf_at(token_id)?;
-            funds.acc_id = acc_id;
-            funds.token_id = token_id;
-            funds.avl_amt = token.avl_amt.to_string();
-            funds.locked_amt = token.locked_amt.to_string();
+            for token_id in account.tokens.leaf_ids() {
+                let token_id = token_id?;
+                let token = account.tokens.leaf_at(token_id)?;
+                if !evacuated
+                    .iter()
+                    .any(|entry| entry.token_id == token_id as u64)
+                {
+                    status.evacuable.push(Funds {
+                        acc_id,
+                        token_id,
+                        avl_amt: token.avl_amt.to_string(),
+                        locked_amt: token.locked_amt.to_string(),
+                    });
+                }
+            }
+            status.evacuated = evacuated
+                .iter()
+                .map(|entry| EvacuatedFunds {
+                    token_id: entry.token_id,
+                    amount: entry.amount.to_string(),
+                })
+                .collect();
             Ok(())
         },
     )?;
-    Ok(funds)
+    Ok(status)
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AccountInfo {
+    acc_id: usize,
+    l2_addr: String,
+    nonce: String,
+    token_ids: Vec<usize>,
+}
+// the accounts and tokens are walked from the tree nodes, skipping the empty subtrees
+pub fn get_accounts(cfg: Config) -> Result<Vec<AccountInfo>, String> {
+    let mut accounts = vec![];
+    TsFile::perform_with_file(
+        cfg.ts_filename.as_str(),
+        Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
+        |ts_file| {
+            let state = ts_file.to_state()?;
+            for acc_id in state.accounts.leaf_ids() {
+                let acc_id = acc_id?;
+                let account = state.accounts.leaf_at(acc_id)?;
+                accounts.push(AccountInfo {
+                    acc_id,
+                    l2_addr: account.l2_addr.to_string(),
+                    nonce: account.nonce.to_string(),
+                    token_ids: account.tokens.leaf_ids().collect::<Result<_, _>>()?,
+                });
+            }
+            Ok(())
+        },
+    )?;
+    Ok(accounts)
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TokenDiff {
+    token_id: usize,
+    avl_amt_a: String,
+    avl_amt_b: String,
+    locked_amt_a: String,
+    locked_amt_b: String,
+}
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AccountDiff {
+    acc_id: usize,
+    l2_addr_a: String,
+    l2_addr_b: String,
+    nonce_a: String,
+    nonce_b: String,
+    tokens: Vec<TokenDiff>,
+    first_diverging_tx_id: Option<usize>,
+}
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StateDiff {
+    state_root_a: String,
+    state_root_b: String,
+    accounts: Vec<AccountDiff>,
+}
+// compares the states of two ts files, or of the same one, each at its latest block or right after the given one.
+// the account and token trees are walked from the roots, only into the subtrees that differ
+pub fn diff_states(
+    cfg: Config,
+    ts_filename_a: Option<String>,
+    block_id_a: Option<usize>,
+    ts_filename_b: Option<String>,
+    block_id_b: Option<usize>,
+) -> Result<StateDiff, String> {
+    let cfg_a = Config {
+        ts_filename: ts_filename_a.unwrap_or(cfg.ts_filename.clone()),
+        ..cfg.clone()
+    };
+    let cfg_b = Config {
+        ts_filename: ts_filename_b.unwrap_or(cfg.ts_filename.clone()),
+        ..cfg
+    };
+    let mut diff = StateDiff::default();
+    perform_at_block(&cfg_a, block_id_a, |file_a| {
+        let state_a = file_a.to_state()?;
+        perform_at_block(&cfg_b, block_id_b, |file_b| {
+            let state_b = file_b.to_state()?;
+            diff.state_root_a = state_a.get_root()?.to_string();
+            diff.state_root_b = state_b.get_root()?.to_string();
+            for acc_id in diff_leaf_ids(&state_a.accounts, &state_b.accounts)? {
+                let account_a = state_a.accounts.leaf_at(acc_id)?;
+                let account_b = state_b.accounts.leaf_at(acc_id)?;
+                let mut tokens = vec![];
+                for token_id in diff_leaf_ids(&account_a.tokens, &account_b.tokens)? {
+                    let token_a = account_a.tokens.leaf_at(token_id)?;
+                    let token_b = account_b.tokens.leaf_at(token_id)?;
+                    tokens.push(TokenDiff {
+                        token_id,
+                        avl_amt_a: token_a.avl_amt.to_string(),
+                        avl_amt_b: token_b.avl_amt.to_string(),
+                        locked_amt_a: token_a.locked_amt.to_string(),
+                        locked_amt_b: token_b.locked_amt.to_string(),
+                    });
+                }
+                diff.accounts.push(AccountDiff {
+                    acc_id,
+                    l2_addr_a: account_a.l2_addr.to_string(),
+                    l2_addr_b: account_b.l2_addr.to_string(),
+                    nonce_a: account_a.nonce.to_string(),
+                    nonce_b: account_b.nonce.to_string(),
+                    tokens,
+                    first_diverging_tx_id: None,
+                });
+            }
+            Ok(())
+        })
+    })?;
+    // the snapshots of past blocks have no history, it is read from the synced files up to these blocks
+    let acc_ids: Vec<usize> = diff.accounts.iter().map(|account| account.acc_id).collect();
+    let histories_a = load_histories(&cfg_a, block_id_a, &acc_ids)?;
+    let histories_b = load_histories(&cfg_b, block_id_b, &acc_ids)?;
+    for (account, (history_a, history_b)) in diff
+        .accounts
+        .iter_mut()
+        .zip(histories_a.iter().zip(histories_b.iter()))
+    {
+        let same = |a: &history::HistoryEntry, b: &history::HistoryEntry| {
+            a.tx_id == b.tx_id
+                && a.token_id == b.token_id
+                && a.avl_amt == b.avl_amt
+                && a.locked_amt == b.locked_amt
+        };
+        let common = history_a
+            .iter()
+            .zip(history_b.iter())
+            .take_while(|(a, b)| same(a, b))
+            .count();
+        account.first_diverging_tx_id = match (history_a.get(common), history_b.get(common)) {
+            (Some(a), Some(b)) => Some(a.tx_id.min(b.tx_id)),
+            (Some(a), None) => Some(a.tx_id),
+            (None, Some(b)) => Some(b.tx_id),
+            (None, None) => None,
+        };
+    }
+    Ok(diff)
+}
+fn load_histories(
+    cfg: &Config,
+    block_id: Option<usize>,
+    acc_ids: &[usize],
+) -> Result<Vec<Vec<history::HistoryEntry>>, String> {
+    let mut histories = vec![];
+    TsFile::perform_with_file(
+        cfg.ts_filename.as_str(),
+        Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
+        |ts_file| {
+            let state = ts_file.to_state()?;
+            for acc_id in acc_ids {
+                let mut history = history::load_history(ts_file, *acc_id)?;
+                if let Some(block_id) = block_id {
+                    history
+                        .retain(|entry| entry.tx_id < block_id * state.params.tx_count_per_block);
+                }
+                histories.push(history);
+            }
+            Ok(())
+        },
+    )?;
+    Ok(histories)
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct OpenOrder {
+    tx_id: usize,
+    order_type: String,
+    acc_id: u64,
+    deducted_token_id: u64,
+    remaining_deducted_amt: String,
+    target_token_id: Option<u64>,
+    remaining_target_amt: Option<String>,
+    cum_deducted_amt: String,
+    cum_target_amt: String,
+    locked_amt: String,
+    status: String,
+}
+// the time of the last synced block, which the orders are expired against rather than the time of
+// the machine. unknown when no block is synced or it was synced before the times were recorded
+fn last_block_time(ts_file: &TsFile) -> Result<Option<u64>, String> {
+    let block_root = history::load_block_root(ts_file, ts_file.block_count as usize - 1)?;
+    Ok(block_root
+        .map(|block_root| block_root.timestamp)
+        .filter(|timestamp| *timestamp != 0))
+}
+
+pub fn get_open_orders(cfg: Config, acc_id: Option<usize>) -> Result<Vec<OpenOrder>, String> {
+    let mut open_orders = vec![];
+    TsFile::perform_with_file(
+        cfg.ts_filename.as_str(),
+        Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
+        |ts_file| {
+            let state = ts_file.to_state()?;
+            let current_time = last_block_time(ts_file)?;
+            for order in state.get_open_orders(ts_file.block_count as usize)? {
+                if acc_id.is_some_and(|acc_id| acc_id as u64 != order.acc_id) {
+                    continue;
+                }
+                open_orders.push(OpenOrder {
+                    tx_id: order.tx_id,
+                    order_type: match order.order_type {
+                        OrderType::AucLend => "auction_lend",
+                        OrderType::AucBorrow => "auction_borrow",
+                        OrderType::SecLimit => "second_limit_order",
+                    }
+                    .to_string(),
+                    acc_id: order.acc_id,
+                    deducted_token_id: order.deducted_token_id,
+                    remaining_deducted_amt: order.remaining_deducted_amt().to_string(),
+                    target_token_id: order.target_token_id,
+                    remaining_target_amt: order.remaining_target_amt().map(|x| x.to_string()),
+                    cum_deducted_amt: order.cum_deducted_amt.to_string(),
+                    cum_target_amt: order.cum_target_amt.to_string(),
+                    locked_amt: order.locked_amt.to_string(),
+                    status: if current_time
+                        .is_some_and(|current_time| order.is_expired(current_time.into()))
+                    {
+                        "expired"
+                    } else if order.is_partially_matched() {
+                        "partially_matched"
+                    } else {
+                        "unmatched"
+                    }
+                    .to_string(),
+                });
+            }
+            Ok(())
+        },
+    )?;
+    Ok(open_orders)
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BondPositionInfo {
+    bond_token_id: u64,
+    base_token_id: u64,
+    maturity: String,
+    maturity_date: String,
+    is_matured: bool,
+    avl_amt: String,
+    locked_amt: String,
+    redeemable_amt: String,
+}
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct LoanPositionInfo {
+    collateral_token_id: u64,
+    matched_collateral_amt: String,
+    debt_token_id: u64,
+    matched_debt_amt: String,
+    maturity: String,
+    maturity_date: String,
+    is_matured: bool,
+}
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Positions {
+    acc_id: usize,
+    bonds: Vec<BondPositionInfo>,
+    loans: Vec<LoanPositionInfo>,
+}
+// the positions mature against the time of the last synced block, as the orders expire
+pub fn get_positions(cfg: Config, acc_id: usize) -> Result<Positions, String> {
+    let mut positions = Positions {
+        acc_id,
+        ..Default::default()
+    };
+    TsFile::perform_with_file(
+        cfg.ts_filename.as_str(),
+        Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
+        |ts_file| {
+            let state = ts_file.to_state()?;
+            let end_block_id = ts_file.block_count as usize;
+            let current_time = last_block_time(ts_file)?.map(ark_bn254::Fr::from);
+            positions.bonds = state
+                .get_bond_positions(end_block_id, acc_id)?
+                .iter()
+                .map(|bond| BondPositionInfo {
+                    bond_token_id: bond.bond_token_id,
+                    base_token_id: bond.base_token_id,
+                    maturity: bond.maturity.to_string(),
+                    maturity_date: to_date(bond.maturity),
+                    is_matured: current_time
+                        .is_some_and(|current_time| bond.is_matured(current_time)),
+                    avl_amt: bond.avl_amt.to_string(),
+                    locked_amt: bond.locked_amt.to_string(),
+                    redeemable_amt: current_time
+                        .map_or(ark_bn254::Fr::zero(), |current_time| {
+                            bond.redeemable_amt(current_time)
+                        })
+                        .to_string(),
+                })
+                .collect();
+            positions.loans = state
+                .get_loan_positions(end_block_id, acc_id)?
+                .iter()
+                .map(|loan| LoanPositionInfo {
+                    collateral_token_id: loan.collateral_token_id,
+                    matched_collateral_amt: loan.matched_collateral_amt.to_string(),
+                    debt_token_id: loan.debt_token_id,
+                    matched_debt_amt: loan.matched_debt_amt.to_string(),
+                    maturity: loan.maturity.to_string(),
+                    maturity_date: to_date(loan.maturity),
+                    is_matured: current_time
+                        .is_some_and(|current_time| loan.is_matured(current_time)),
+                })
+                .collect();
+            Ok(())
+        },
+    )?;
+    Ok(positions)
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    tx_id: usize,
+    block_id: usize,
+    op: String,
+    token_id: u64,
+    avl_amt: String,
+    locked_amt: String,
+}
+pub fn get_history(
+    cfg: Config,
+    acc_id: usize,
+    token_id: Option<usize>,
+    start_block_id: Option<usize>,
+    end_block_id: Option<usize>,
+) -> Result<Vec<HistoryRecord>, String> {
+    let mut records = vec![];
+    TsFile::perform_with_file(
+        cfg.ts_filename.as_str(),
+        Some(cfg.l2_genesis_l1_anchor_id),
+        cfg.header()?,
+        |ts_file| {
+            let state = ts_file.to_state()?;
+            for entry in history::load_history(ts_file, acc_id)? {
+                let block_id = entry.tx_id / state.params.tx_count_per_block + 1;
+                if token_id.is_some_and(|token_id| token_id as u64 != entry.token_id)
+                    || start_block_id.is_some_and(|start_block_id| block_id < start_block_id)
+                    || end_block_id.is_some_and(|end_block_id| block_id > end_block_id)
+                {
+                    continue;
+                }
+                records.push(HistoryRecord {
+                    tx_id: entry.tx_id,
+                    block_id,
+                    op: state.txs.get(entry.tx_id)?.raw_tx.op_name().to_string(),
+                    token_id: entry.token_id,
+                    avl_amt: to_signed_string(entry.avl_amt),
+                    locked_amt: to_signed_string(entry.locked_amt),
+                });
+            }
+            Ok(())
+        },
+    )?;
+    Ok(records)
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TokenAudit {
+    token_id: u64,
+    avl_amt: String,
+    locked_amt: String,
+    order_locked_amt: String,
+    deposited_amt: String,
+    withdrawn_amt: String,
+    evacuated_amt: String,
+    fee_amt: String,
+    to_loans_amt: String,
+    from_loans_amt: String,
+    surplus_amt: String,
+}
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AuditIssue {
+    block_id: usize,
+    token_id: u64,
+    message: String,
+}
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Audit {
+    end_block_id: usize,
+    tokens: Vec<TokenAudit>,
+    issues: Vec<AuditIssue>,
+}
+// replays the synced blocks into `<ts_filename>.audit.tmp` and checks after each block that,
+// per token, the funds of all accounts are what the deposits, withdrawals, evacuations, fees and loans leave
+pub fn audit(cfg: Config, end_block_id: Option<usize>) -> Result<Audit, String> {
+    let mut audit = Audit::default();
+    perform_replay(
+        &cfg,
+        "audit",
+        end_block_id,
+        |state, replayed_state, end_block_id| {
+            audit.end_block_id = end_block_id;
+            replayed_state.set_journaling(true)?;
+            let mut ledgers: BTreeMap<u64, Ledger> = BTreeMap::new();
+            for block_id in 1..=end_block_id {
+                let prev_ledgers = ledgers.clone();
+                for tx_id in push_block_txs(state, replayed_state, block_id)? {
+                    replayed_state.update_and_audit(tx_id, &mut ledgers)?;
+                }
+                replayed_state.commit()?;
+                for (token_id, ledger) in &ledgers {
+                    let prev = prev_ledgers.get(token_id).copied().unwrap_or_default();
+                    let mut issue = |message: String| {
+                        audit.issues.push(AuditIssue {
+                            block_id,
+                            token_id: *token_id,
+                            message,
+                        })
+                    };
+                    if ledger.surplus_amt() != prev.surplus_amt() {
+                        issue(format!(
+                        "the accounts gained {} that no deposit, withdrawal, evacuation, fee or loan accounts for",
+                        to_signed_string(ledger.surplus_amt() - prev.surplus_amt())
+                    ));
+                    }
+                    for (flow, amt) in [
+                        ("fees", ledger.fee_amt - prev.fee_amt),
+                        ("flows to loans", ledger.to_loans_amt - prev.to_loans_amt),
+                        (
+                            "flows from loans",
+                            ledger.from_loans_amt - prev.from_loans_amt,
+                        ),
+                    ] {
+                        if is_negative(amt) {
+                            issue(format!("{} are negative: {}", flow, to_signed_string(amt)));
+                        }
+                    }
+                }
+            }
+
+            // whatever is still locked must be held by an open order
+            let mut order_locked_amts: BTreeMap<u64, ark_bn254::Fr> = BTreeMap::new();
+            for order in replayed_state.get_open_orders(end_block_id + 1)? {
+                *order_locked_amts
+                    .entry(order.deducted_token_id)
+                    .or_default() += order.locked_amt;
+            }
+            for (token_id, ledger) in &ledgers {
+                let order_locked_amt = order_locked_amts.get(token_id).copied().unwrap_or_default();
+                if ledger.locked_amt != order_locked_amt {
+                    audit.issues.push(AuditIssue {
+                        block_id: end_block_id,
+                        token_id: *token_id,
+                        message: format!(
+                            "the accounts lock {} but the open orders hold {}",
+                            to_signed_string(ledger.locked_amt),
+                            order_locked_amt
+                        ),
+                    });
+                }
+                audit.tokens.push(TokenAudit {
+                    token_id: *token_id,
+                    avl_amt: to_signed_string(ledger.avl_amt),
+                    locked_amt: to_signed_string(ledger.locked_amt),
+                    order_locked_amt: order_locked_amt.to_string(),
+                    deposited_amt: ledger.deposited_amt.to_string(),
+                    withdrawn_amt: ledger.withdrawn_amt.to_string(),
+                    evacuated_amt: ledger.evacuated_amt.to_string(),
+                    fee_amt: to_signed_string(ledger.fee_amt),
+                    to_loans_amt: to_signed_string(ledger.to_loans_amt),
+                    from_loans_amt: to_signed_string(ledger.from_loans_amt),
+                    surplus_amt: to_signed_string(ledger.surplus_amt()),
+                });
+            }
+            Ok(())
+        },
+    )?;
+    Ok(audit)
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MarketReport {
+    bond_token_id: u64,
+    base_token_id: u64,
+    maturity: String,
+    maturity_date: String,
+    start_block_id: usize,
+    end_block_id: usize,
+    primary_matched_amt: String,
+    primary_bond_amt: String,
+    avg_interest_rate: String,
+    primary_fee_amt: String,
+    secondary_bond_amt: String,
+    secondary_base_amt: String,
+    avg_price: String,
+    last_price: String,
+    secondary_fee_amt: String,
+}
+impl MarketReport {
+    pub fn csv_header() -> &'static str {
+        "bond_token_id,base_token_id,maturity,maturity_date,start_block_id,end_block_id,primary_matched_amt,primary_bond_amt,avg_interest_rate,primary_fee_amt,secondary_bond_amt,secondary_base_amt,avg_price,last_price,secondary_fee_amt"
+    }
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            self.bond_token_id,
+            self.base_token_id,
+            self.maturity,
+            self.maturity_date,
+            self.start_block_id,
+            self.end_block_id,
+            self.primary_matched_amt,
+            self.primary_bond_amt,
+            self.avg_interest_rate,
+            self.primary_fee_amt,
+            self.secondary_bond_amt,
+            self.secondary_base_amt,
+            self.avg_price,
+            self.last_price,
+            self.secondary_fee_amt
+        )
+    }
+}
+#[derive(Clone, Copy, Default)]
+struct MarketStats {
+    base_token_id: u64,
+    maturity: ark_bn254::Fr,
+    primary_matched_amt: ark_bn254::Fr,
+    primary_bond_amt: ark_bn254::Fr,
+    weighted_interest: ark_bn254::Fr,
+    primary_fee_amt: ark_bn254::Fr,
+    secondary_bond_amt: ark_bn254::Fr,
+    secondary_base_amt: ark_bn254::Fr,
+    last_trade: Option<(ark_bn254::Fr, ark_bn254::Fr)>,
+    secondary_fee_amt: ark_bn254::Fr,
+}
+// replays the synced blocks and reports, per bond token and range of `interval` blocks,
+// what the auctions and the secondary market matched in [start_block_id, end_block_id]
+pub fn get_markets(
+    cfg: Config,
+    start_block_id: Option<usize>,
+    end_block_id: Option<usize>,
+    interval: Option<usize>,
+) -> Result<Vec<MarketReport>, String> {
+    let start_block_id = start_block_id.unwrap_or(1);
+    if interval == Some(0) {
+        return Err("the interval must be at least 1 block".to_string());
+    }
+    let mut markets: BTreeMap<(u64, usize), MarketStats> = BTreeMap::new();
+    let mut last_block_id = 0;
+    perform_replay(
+        &cfg,
+        "markets",
+        end_block_id,
+        |state, replayed_state, end_block_id| {
+            last_block_id = end_block_id;
+            replayed_state.set_journaling(true)?;
+            for block_id in 1..=end_block_id {
+                let range_id = match interval {
+                    Some(interval) if block_id >= start_block_id => {
+                        (block_id - start_block_id) / interval
+                    }
+                    _ => 0,
+                };
+                // the market of the matching batch being replayed, its fees are what the batch
+                // takes out of the base token
+                let mut batch_market: Option<(u64, bool)> = None;
+                for tx_id in push_block_txs(state, replayed_state, block_id)? {
+                    let trade = replayed_state.update_and_trade(tx_id)?;
+                    let balance_changes = replayed_state.take_balance_changes()?;
+                    if block_id < start_block_id {
+                        continue;
+                    }
+                    if let Some(trade) = trade {
+                        let stats = markets.entry((trade.bond_token_id, range_id)).or_default();
+                        stats.base_token_id = trade.base_token_id;
+                        stats.maturity = trade.maturity;
+                        match trade.matched_interest {
+                            Some(matched_interest) => {
+                                stats.primary_matched_amt += trade.base_amt;
+                                stats.primary_bond_amt += trade.bond_amt;
+                                stats.weighted_interest += matched_interest * trade.base_amt;
+                            }
+                            None => {
+                                stats.secondary_bond_amt += trade.bond_amt;
+                                stats.secondary_base_amt += trade.base_amt;
+                                stats.last_trade = Some((trade.bond_amt, trade.base_amt));
+                            }
+                        }
+                        batch_market = Some((trade.bond_token_id, trade.is_primary));
+                    }
+                    match replayed_state.txs.get(tx_id)?.raw_tx {
+                        Tx::TxAucMatch(_)
+                        | Tx::TxAucEnd(_)
+                        | Tx::TxSecLimitExchange(_)
+                        | Tx::TxSecLimitEnd(_)
+                        | Tx::TxSecMarketExchange(_)
+                        | Tx::TxSecMarketEnd(_) => {}
+                        _ => {
+                            batch_market = None;
+                            continue;
+                        }
+                    }
+                    if let Some((bond_token_id, is_primary)) = batch_market {
+                        let stats = markets.entry((bond_token_id, range_id)).or_default();
+                        for change in balance_changes {
+                            if change.token_id != stats.base_token_id {
+                                continue;
+                            }
+                            let fee_amt = -(change.avl_amt + change.locked_amt);
+                            match is_primary {
+                                true => stats.primary_fee_amt += fee_amt,
+                                false => stats.secondary_fee_amt += fee_amt,
+                            }
+                        }
+                    }
+                }
+                replayed_state.commit()?;
+            }
+            Ok(())
+        },
+    )?;
+    let fixed_one: BigUint = 100000000u64.into();
+    Ok(markets
+        .into_iter()
+        .map(|((bond_token_id, range_id), stats)| {
+            let (range_start_block_id, range_end_block_id) = match interval {
+                Some(interval) => (
+                    start_block_id + range_id * interval,
+                    std::cmp::min(
+                        start_block_id + (range_id + 1) * interval - 1,
+                        last_block_id,
+                    ),
+                ),
+                None => (start_block_id, last_block_id),
+            };
+            let avg_interest_rate = if stats.primary_matched_amt.is_zero() {
+                String::new()
+            } else {
+                let weighted_interest: BigUint = stats.weighted_interest.into();
+                let primary_matched_amt: BigUint = stats.primary_matched_amt.into();
+                let avg_interest = weighted_interest / primary_matched_amt;
+                if avg_interest >= fixed_one {
+                    to_decimal_string(avg_interest - &fixed_one, fixed_one.clone())
+                } else {
+                    format!(
+                        "-{}",
+                        to_decimal_string(&fixed_one - avg_interest, fixed_one.clone())
+                    )
+                }
+            };
+            let avg_price = if stats.secondary_bond_amt.is_zero() {
+                String::new()
+            } else {
+                to_decimal_string(
+                    stats.secondary_base_amt.into(),
+                    stats.secondary_bond_amt.into(),
+                )
+            };
+            let last_price = match stats.last_trade {
+                Some((bond_amt, base_amt)) if !bond_amt.is_zero() => {
+                    to_decimal_string(base_amt.into(), bond_amt.into())
+                }
+                _ => String::new(),
+            };
+            MarketReport {
+                bond_token_id,
+                base_token_id: stats.base_token_id,
+                maturity: stats.maturity.to_string(),
+                maturity_date: to_date(stats.maturity),
+                start_block_id: range_start_block_id,
+                end_block_id: range_end_block_id,
+                primary_matched_amt: stats.primary_matched_amt.to_string(),
+                primary_bond_amt: stats.primary_bond_amt.to_string(),
+                avg_interest_rate,
+                primary_fee_amt: to_signed_string(stats.primary_fee_amt),
+                secondary_bond_amt: stats.secondary_bond_amt.to_string(),
+                secondary_base_amt: stats.secondary_base_amt.to_string(),
+                avg_price,
+                last_price,
+                secondary_fee_amt: to_signed_string(stats.secondary_fee_amt),
+            }
+        })
+        .collect())
+}
+
+// negative amounts are stored as field negations
+fn to_signed_string(amt: ark_bn254::Fr) -> String {
+    if -amt < amt {
+        format!("-{}", -amt)
+    } else {
+        amt.to_string()
+    }
+}
+// formats numerator / denominator with 8 decimals, rounded down
+fn to_decimal_string(numerator: BigUint, denominator: BigUint) -> String {
+    let scaled = numerator * 100000000u64 / denominator;
+    let fixed_one: BigUint = 100000000u64.into();
+    format!(
+        "{}.{:0>8}",
+        &scaled / &fixed_one,
+        (&scaled % &fixed_one).to_string()
+    )
+}
+fn is_negative(amt: ark_bn254::Fr) -> bool {
+    -amt < amt
+}
+
+// formats a unix timestamp as a UTC yyyy-mm-dd date (proleptic Gregorian calendar)
+fn to_date(timestamp: ark_bn254::Fr) -> String {
+    let timestamp: BigUint = timestamp.into();
+    let days = timestamp.to_u64_digits().first().copied().unwrap_or(0) as i64 / 86400;
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
 }
 
 pub fn get_consume_data(cfg: Config) -> Result<Vec<String>, String> {
@@ -268,3 +1582,32 @@ pub fn get_last_excuted_block(cfg: Config) -> Result<String, String> {
 
     Ok(data)
 }
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Migration {
+    pub filename: String,
+    pub from_version: u64,
+    pub to_version: u64,
+}
+
+// the snapshots cached as `<ts_filename>.<block_id>` share the layout of the ts file, so they are migrated with it
+pub fn migrate(cfg: Config) -> Result<Vec<Migration>, String> {
+    let header = cfg.header()?;
+    let mut filenames = vec![cfg.ts_filename.clone()];
+    for (_, filename) in snapshot_filenames(&cfg)? {
+        filenames.push(filename);
+    }
+
+    let mut migrations = Vec::new();
+    for filename in filenames {
+        if let Some(from_version) = TsFile::migrate(&filename, cfg.l2_genesis_l1_anchor_id, header)?
+        {
+            migrations.push(Migration {
+                filename,
+                from_version,
+                to_version: instance::FORMAT_VERSION,
+            });
+        }
+    }
+    Ok(migrations)
+}
@@ -1,7 +1,9 @@
 extern crate term_structure_evacuation_kit;
-use clap::{App, Arg, SubCommand};
+use clap::{App, Arg, ArgMatches, SubCommand};
 use term_structure_evacuation_kit::{
-    get_consume_data, get_evacu_prf, get_last_excuted_block, query_funds, update_state, Config,
+    audit, diff_states, find_acc_id, get_accounts, get_consume_data, get_evacu_prf,
+    get_evacu_status, get_history, get_last_excuted_block, get_markets, get_multi_evacu_prf,
+    get_open_orders, get_positions, migrate, query_funds, update_state, Config, MarketReport,
 };
 
 fn main() {
@@ -26,6 +28,13 @@ fn main() {
                         .long("endblock")
                         .takes_value(true)
                         .help("End block id"),
+                )
+                .arg(
+                    Arg::with_name("jobs")
+                        .short("j")
+                        .long("jobs")
+                        .takes_value(true)
+                        .help("Applies the txs of independent accounts on this many threads"),
                 ),
         )
         .subcommand(
@@ -44,16 +53,36 @@ fn main() {
                         .short("a")
                         .long("accid")
                         .takes_value(true)
-                        .required(true)
+                        .required_unless("address")
                         .help("Account ID"),
                 )
+                .arg(
+                    Arg::with_name("address")
+                        .long("address")
+                        .takes_value(true)
+                        .conflicts_with("acc_id")
+                        .help("L1 or L2 address of the account"),
+                )
                 .arg(
                     Arg::with_name("token_id")
                         .short("t")
                         .long("tokenid")
                         .takes_value(true)
-                        .required(true)
+                        .required_unless("all")
                         .help("Token ID"),
+                )
+                .arg(
+                    Arg::with_name("all")
+                        .long("all")
+                        .conflicts_with("token_id")
+                        .help("Exports one proof for all the tokens holding funds"),
+                )
+                .arg(
+                    Arg::with_name("block_id")
+                        .short("b")
+                        .long("block")
+                        .takes_value(true)
+                        .help("L2 block id, defaults to the latest synced block"),
                 ),
         )
         .subcommand(
@@ -72,9 +101,16 @@ fn main() {
                         .short("a")
                         .long("accid")
                         .takes_value(true)
-                        .required(true)
+                        .required_unless("address")
                         .help("Account ID"),
                 )
+                .arg(
+                    Arg::with_name("address")
+                        .long("address")
+                        .takes_value(true)
+                        .conflicts_with("acc_id")
+                        .help("L1 or L2 address of the account"),
+                )
                 .arg(
                     Arg::with_name("token_id")
                         .short("t")
@@ -82,6 +118,248 @@ fn main() {
                         .takes_value(true)
                         .required(true)
                         .help("Token ID"),
+                )
+                .arg(
+                    Arg::with_name("block_id")
+                        .short("b")
+                        .long("block")
+                        .takes_value(true)
+                        .help("L2 block id, defaults to the latest synced block"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("orders")
+                .about("List the open orders and their locked amounts")
+                .arg(
+                    Arg::with_name("config")
+                        .short("c")
+                        .long("config")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Sets a custom config file"),
+                )
+                .arg(
+                    Arg::with_name("acc_id")
+                        .short("a")
+                        .long("accid")
+                        .takes_value(true)
+                        .help("Account ID"),
+                )
+                .arg(
+                    Arg::with_name("address")
+                        .long("address")
+                        .takes_value(true)
+                        .conflicts_with("acc_id")
+                        .help("L1 or L2 address of the account"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("positions")
+                .about("List the bond token holdings and borrow positions of an account")
+                .arg(
+                    Arg::with_name("config")
+                        .short("c")
+                        .long("config")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Sets a custom config file"),
+                )
+                .arg(
+                    Arg::with_name("acc_id")
+                        .short("a")
+                        .long("accid")
+                        .takes_value(true)
+                        .required_unless("address")
+                        .help("Account ID"),
+                )
+                .arg(
+                    Arg::with_name("address")
+                        .long("address")
+                        .takes_value(true)
+                        .conflicts_with("acc_id")
+                        .help("L1 or L2 address of the account"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("status")
+                .about("List the tokens of an account that can still be evacuated")
+                .arg(
+                    Arg::with_name("config")
+                        .short("c")
+                        .long("config")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Sets a custom config file"),
+                )
+                .arg(
+                    Arg::with_name("acc_id")
+                        .short("a")
+                        .long("accid")
+                        .takes_value(true)
+                        .required_unless("address")
+                        .help("Account ID"),
+                )
+                .arg(
+                    Arg::with_name("address")
+                        .long("address")
+                        .takes_value(true)
+                        .conflicts_with("acc_id")
+                        .help("L1 or L2 address of the account"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("accounts")
+                .about("List the accounts with the tokens they hold")
+                .arg(
+                    Arg::with_name("config")
+                        .short("c")
+                        .long("config")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Sets a custom config file"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("diff")
+                .about("Find the accounts and tokens that differ between two states")
+                .arg(
+                    Arg::with_name("config")
+                        .short("c")
+                        .long("config")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Sets a custom config file"),
+                )
+                .arg(
+                    Arg::with_name("ts_filename_a")
+                        .index(1)
+                        .help("First ts file, defaults to the one of the config file"),
+                )
+                .arg(
+                    Arg::with_name("ts_filename_b")
+                        .index(2)
+                        .help("Second ts file, defaults to the one of the config file"),
+                )
+                .arg(
+                    Arg::with_name("block_id_a")
+                        .long("blocka")
+                        .takes_value(true)
+                        .help(
+                            "L2 block id of the first state, defaults to the latest synced block",
+                        ),
+                )
+                .arg(
+                    Arg::with_name("block_id_b")
+                        .long("blockb")
+                        .takes_value(true)
+                        .help(
+                            "L2 block id of the second state, defaults to the latest synced block",
+                        ),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("history")
+                .about("List the balance changes of an account")
+                .arg(
+                    Arg::with_name("config")
+                        .short("c")
+                        .long("config")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Sets a custom config file"),
+                )
+                .arg(
+                    Arg::with_name("acc_id")
+                        .short("a")
+                        .long("accid")
+                        .takes_value(true)
+                        .required_unless("address")
+                        .help("Account ID"),
+                )
+                .arg(
+                    Arg::with_name("address")
+                        .long("address")
+                        .takes_value(true)
+                        .conflicts_with("acc_id")
+                        .help("L1 or L2 address of the account"),
+                )
+                .arg(
+                    Arg::with_name("token_id")
+                        .short("t")
+                        .long("tokenid")
+                        .takes_value(true)
+                        .help("Token ID"),
+                )
+                .arg(
+                    Arg::with_name("start_block_id")
+                        .short("s")
+                        .long("startblock")
+                        .takes_value(true)
+                        .help("First L2 block id"),
+                )
+                .arg(
+                    Arg::with_name("end_block_id")
+                        .short("e")
+                        .long("endblock")
+                        .takes_value(true)
+                        .help("Last L2 block id"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("markets")
+                .about("Report the matched volumes, interest rates, prices and fees per bond token")
+                .arg(
+                    Arg::with_name("config")
+                        .short("c")
+                        .long("config")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Sets a custom config file"),
+                )
+                .arg(
+                    Arg::with_name("start_block_id")
+                        .short("s")
+                        .long("startblock")
+                        .takes_value(true)
+                        .help("First L2 block id"),
+                )
+                .arg(
+                    Arg::with_name("end_block_id")
+                        .short("e")
+                        .long("endblock")
+                        .takes_value(true)
+                        .help("Last L2 block id"),
+                )
+                .arg(
+                    Arg::with_name("interval")
+                        .short("i")
+                        .long("interval")
+                        .takes_value(true)
+                        .help("Number of L2 blocks per report row"),
+                )
+                .arg(
+                    Arg::with_name("csv")
+                        .long("csv")
+                        .help("Prints the report as CSV"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("audit")
+                .about("Checks that the funds of every token are conserved block by block")
+                .arg(
+                    Arg::with_name("config")
+                        .short("c")
+                        .long("config")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Sets a custom config file"),
+                )
+                .arg(
+                    Arg::with_name("end_block_id")
+                        .short("e")
+                        .long("endblock")
+                        .takes_value(true)
+                        .help("Last L2 block id"),
                 ),
         )
         .subcommand(
@@ -108,6 +386,18 @@ fn main() {
                         .help("Sets a custom config file"),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("migrate")
+                .about("Upgrades the ts file and its cached snapshots to the current file format")
+                .arg(
+                    Arg::with_name("config")
+                        .short("c")
+                        .long("config")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Sets a custom config file"),
+                ),
+        )
         .get_matches();
 
     if let Some(matches) = matches.subcommand_matches("update_state") {
@@ -131,7 +421,18 @@ fn main() {
             None => None,
         };
 
-        if let Err(e) = update_state(config, end_block_id) {
+        let threads = match matches.value_of("jobs") {
+            Some(s) => match s.parse::<usize>() {
+                Ok(num) if num > 0 => Some(num),
+                _ => {
+                    eprintln!("[Error] Invalid number of jobs");
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        if let Err(e) = update_state(config, end_block_id, threads) {
             eprintln!("[Error] Failed to update state: {}", e);
             return;
         }
@@ -147,20 +448,36 @@ fn main() {
             }
         };
 
-        let acc_id = match matches.value_of("acc_id") {
+        let acc_id = match parse_acc_id(matches, &config) {
+            Ok(acc_id) => acc_id,
+            Err(e) => {
+                eprintln!("[Error] {}", e);
+                return;
+            }
+        };
+
+        let block_id = match matches.value_of("block_id") {
             Some(num) => match num.parse::<usize>() {
-                Ok(num) => num,
+                Ok(num) => Some(num),
                 Err(_) => {
-                    eprintln!("[Error] Invalid account id");
+                    eprintln!("[Error] Invalid block id");
                     return;
                 }
             },
-            None => {
-                eprintln!("unreachable");
-                return;
-            }
+            None => None,
         };
 
+        if matches.is_present("all") {
+            match get_multi_evacu_prf(config, acc_id, block_id) {
+                Ok(evacu_proof) => match serde_json::to_string(&evacu_proof) {
+                    Ok(json) => println!("{}", json),
+                    Err(e) => eprintln!("[Error] Failed to serialize evacuation proof: {}", e),
+                },
+                Err(e) => eprintln!("[Error] Failed to get evacuation proof: {}", e),
+            }
+            return;
+        }
+
         let token_id = match matches.value_of("token_id") {
             Some(num) => match num.parse::<usize>() {
                 Ok(num) => num,
@@ -175,7 +492,7 @@ fn main() {
             }
         };
 
-        match get_evacu_prf(config, acc_id, token_id) {
+        match get_evacu_prf(config, acc_id, token_id, block_id) {
             Ok(evacu_proof) => match serde_json::to_string(&evacu_proof) {
                 Ok(json) => println!("{}", json),
                 Err(e) => eprintln!("[Error] Failed to serialize evacuation proof: {}", e),
@@ -194,11 +511,19 @@ fn main() {
             }
         };
 
-        let acc_id = match matches.value_of("acc_id") {
+        let acc_id = match parse_acc_id(matches, &config) {
+            Ok(acc_id) => acc_id,
+            Err(e) => {
+                eprintln!("[Error] {}", e);
+                return;
+            }
+        };
+
+        let token_id = match matches.value_of("token_id") {
             Some(num) => match num.parse::<usize>() {
                 Ok(num) => num,
                 Err(_) => {
-                    eprintln!("[Error] Invalid account id");
+                    eprintln!("[Error] Invalid token id");
                     return;
                 }
             },
@@ -208,26 +533,321 @@ fn main() {
             }
         };
 
+        let block_id = match matches.value_of("block_id") {
+            Some(num) => match num.parse::<usize>() {
+                Ok(num) => Some(num),
+                Err(_) => {
+                    eprintln!("[Error] Invalid block id");
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        match query_funds(config, acc_id, token_id, block_id) {
+            Ok(funds) => match serde_json::to_string(&funds) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("[Error] Failed to serialize funds: {}", e),
+            },
+            Err(e) => eprintln!("[Error] Failed to query funds: {}", e),
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("orders") {
+        let config_path = matches.value_of("config").unwrap_or("default_path");
+        let config = match load_config(config_path) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                eprintln!("[Error] Failed to load config: {}", e);
+                return;
+            }
+        };
+
+        let acc_id = match parse_optional_acc_id(matches, &config) {
+            Ok(acc_id) => acc_id,
+            Err(e) => {
+                eprintln!("[Error] {}", e);
+                return;
+            }
+        };
+
+        match get_open_orders(config, acc_id) {
+            Ok(orders) => match serde_json::to_string(&orders) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("[Error] Failed to serialize orders: {}", e),
+            },
+            Err(e) => eprintln!("[Error] Failed to get open orders: {}", e),
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("positions") {
+        let config_path = matches.value_of("config").unwrap_or("default_path");
+        let config = match load_config(config_path) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                eprintln!("[Error] Failed to load config: {}", e);
+                return;
+            }
+        };
+
+        let acc_id = match parse_acc_id(matches, &config) {
+            Ok(acc_id) => acc_id,
+            Err(e) => {
+                eprintln!("[Error] {}", e);
+                return;
+            }
+        };
+
+        match get_positions(config, acc_id) {
+            Ok(positions) => match serde_json::to_string(&positions) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("[Error] Failed to serialize positions: {}", e),
+            },
+            Err(e) => eprintln!("[Error] Failed to get positions: {}", e),
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("status") {
+        let config_path = matches.value_of("config").unwrap_or("default_path");
+        let config = match load_config(config_path) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                eprintln!("[Error] Failed to load config: {}", e);
+                return;
+            }
+        };
+
+        let acc_id = match parse_acc_id(matches, &config) {
+            Ok(acc_id) => acc_id,
+            Err(e) => {
+                eprintln!("[Error] {}", e);
+                return;
+            }
+        };
+
+        match get_evacu_status(config, acc_id) {
+            Ok(status) => match serde_json::to_string(&status) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("[Error] Failed to serialize the status: {}", e),
+            },
+            Err(e) => eprintln!("[Error] Failed to get the status: {}", e),
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("accounts") {
+        let config_path = matches.value_of("config").unwrap_or("default_path");
+        let config = match load_config(config_path) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                eprintln!("[Error] Failed to load config: {}", e);
+                return;
+            }
+        };
+
+        match get_accounts(config) {
+            Ok(accounts) => match serde_json::to_string(&accounts) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("[Error] Failed to serialize the accounts: {}", e),
+            },
+            Err(e) => eprintln!("[Error] Failed to get the accounts: {}", e),
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("diff") {
+        let config_path = matches.value_of("config").unwrap_or("default_path");
+        let config = match load_config(config_path) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                eprintln!("[Error] Failed to load config: {}", e);
+                return;
+            }
+        };
+
+        let block_id_a = match matches.value_of("block_id_a") {
+            Some(num) => match num.parse::<usize>() {
+                Ok(num) => Some(num),
+                Err(_) => {
+                    eprintln!("[Error] Invalid block id");
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        let block_id_b = match matches.value_of("block_id_b") {
+            Some(num) => match num.parse::<usize>() {
+                Ok(num) => Some(num),
+                Err(_) => {
+                    eprintln!("[Error] Invalid block id");
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        match diff_states(
+            config,
+            matches.value_of("ts_filename_a").map(|s| s.to_string()),
+            block_id_a,
+            matches.value_of("ts_filename_b").map(|s| s.to_string()),
+            block_id_b,
+        ) {
+            Ok(diff) => match serde_json::to_string(&diff) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("[Error] Failed to serialize the diff: {}", e),
+            },
+            Err(e) => eprintln!("[Error] Failed to diff the states: {}", e),
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("history") {
+        let config_path = matches.value_of("config").unwrap_or("default_path");
+        let config = match load_config(config_path) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                eprintln!("[Error] Failed to load config: {}", e);
+                return;
+            }
+        };
+
+        let acc_id = match parse_acc_id(matches, &config) {
+            Ok(acc_id) => acc_id,
+            Err(e) => {
+                eprintln!("[Error] {}", e);
+                return;
+            }
+        };
+
         let token_id = match matches.value_of("token_id") {
             Some(num) => match num.parse::<usize>() {
-                Ok(num) => num,
+                Ok(num) => Some(num),
                 Err(_) => {
                     eprintln!("[Error] Invalid token id");
                     return;
                 }
             },
-            None => {
-                eprintln!("unreachable");
+            None => None,
+        };
+
+        let start_block_id = match matches.value_of("start_block_id") {
+            Some(num) => match num.parse::<usize>() {
+                Ok(num) => Some(num),
+                Err(_) => {
+                    eprintln!("[Error] Invalid start block id");
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        let end_block_id = match matches.value_of("end_block_id") {
+            Some(num) => match num.parse::<usize>() {
+                Ok(num) => Some(num),
+                Err(_) => {
+                    eprintln!("[Error] Invalid end block id");
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        match get_history(config, acc_id, token_id, start_block_id, end_block_id) {
+            Ok(history) => match serde_json::to_string(&history) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("[Error] Failed to serialize history: {}", e),
+            },
+            Err(e) => eprintln!("[Error] Failed to get history: {}", e),
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("markets") {
+        let config_path = matches.value_of("config").unwrap_or("default_path");
+        let config = match load_config(config_path) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                eprintln!("[Error] Failed to load config: {}", e);
                 return;
             }
         };
 
-        match query_funds(config, acc_id, token_id) {
-            Ok(funds) => match serde_json::to_string(&funds) {
+        let start_block_id = match matches.value_of("start_block_id") {
+            Some(num) => match num.parse::<usize>() {
+                Ok(num) => Some(num),
+                Err(_) => {
+                    eprintln!("[Error] Invalid start block id");
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        let end_block_id = match matches.value_of("end_block_id") {
+            Some(num) => match num.parse::<usize>() {
+                Ok(num) => Some(num),
+                Err(_) => {
+                    eprintln!("[Error] Invalid end block id");
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        let interval = match matches.value_of("interval") {
+            Some(num) => match num.parse::<usize>() {
+                Ok(num) => Some(num),
+                Err(_) => {
+                    eprintln!("[Error] Invalid interval");
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        match get_markets(config, start_block_id, end_block_id, interval) {
+            Ok(markets) => {
+                if matches.is_present("csv") {
+                    println!("{}", MarketReport::csv_header());
+                    for market in markets {
+                        println!("{}", market.to_csv_row());
+                    }
+                } else {
+                    match serde_json::to_string(&markets) {
+                        Ok(json) => println!("{}", json),
+                        Err(e) => eprintln!("[Error] Failed to serialize the markets: {}", e),
+                    }
+                }
+            }
+            Err(e) => eprintln!("[Error] Failed to get the markets: {}", e),
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("audit") {
+        let config_path = matches.value_of("config").unwrap_or("default_path");
+        let config = match load_config(config_path) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                eprintln!("[Error] Failed to load config: {}", e);
+                return;
+            }
+        };
+
+        let end_block_id = match matches.value_of("end_block_id") {
+            Some(num) => match num.parse::<usize>() {
+                Ok(num) => Some(num),
+                Err(_) => {
+                    eprintln!("[Error] Invalid end block id");
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        match audit(config, end_block_id) {
+            Ok(audit) => match serde_json::to_string(&audit) {
                 Ok(json) => println!("{}", json),
-                Err(e) => eprintln!("[Error] Failed to serialize funds: {}", e),
+                Err(e) => eprintln!("[Error] Failed to serialize the audit: {}", e),
             },
-            Err(e) => eprintln!("[Error] Failed to query funds: {}", e),
+            Err(e) => eprintln!("[Error] Failed to audit the state: {}", e),
         }
     }
 
@@ -265,6 +885,25 @@ fn main() {
             Err(e) => eprintln!("[Error] Failed to export the data: {}", e),
         }
     }
+
+    if let Some(matches) = matches.subcommand_matches("migrate") {
+        let config_path = matches.value_of("config").unwrap_or("default_path");
+        let config = match load_config(config_path) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                eprintln!("[Error] Failed to load config: {}", e);
+                return;
+            }
+        };
+
+        match migrate(config) {
+            Ok(migrations) => match serde_json::to_string(&migrations) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("[Error] Failed to serialize the migrations: {}", e),
+            },
+            Err(e) => eprintln!("[Error] Failed to migrate the ts file: {}", e),
+        }
+    }
 }
 
 fn load_config(path: &str) -> Result<Config, String> {
@@ -272,3 +911,23 @@ fn load_config(path: &str) -> Result<Config, String> {
     let reader = std::io::BufReader::new(file);
     serde_json::from_reader(reader).map_err(|e| e.to_string())
 }
+
+// for the commands that require an account
+fn parse_acc_id(matches: &ArgMatches, config: &Config) -> Result<usize, String> {
+    parse_optional_acc_id(matches, config)?
+        .ok_or("An account id or address is required".to_string())
+}
+fn parse_optional_acc_id(matches: &ArgMatches, config: &Config) -> Result<Option<usize>, String> {
+    if let Some(address) = matches.value_of("address") {
+        return find_acc_id(config.clone(), address)
+            .map(Some)
+            .map_err(|e| format!("Failed to find account: {}", e));
+    }
+    match matches.value_of("acc_id") {
+        Some(num) => num
+            .parse::<usize>()
+            .map(Some)
+            .map_err(|_| "Invalid account id".to_string()),
+        None => Ok(None),
+    }
+}
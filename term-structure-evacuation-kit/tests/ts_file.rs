@@ -0,0 +1,802 @@
+use ark_bn254::Fr;
+use term_structure_evacuation_kit::{
+    audit, find_acc_id, get_multi_evacu_prf, get_positions,
+    instance::{address, evacuation, history, Header, TsFile},
+    query_funds, Config,
+};
+use ts_merkle_tree::MerkleTreeWithLeaves;
+use ts_state::{constants::Params, BalanceChange, Value};
+use ts_tx::*;
+
+struct TmpFile(String);
+impl TmpFile {
+    fn new(name: &str) -> Self {
+        let filename = std::env::temp_dir()
+            .join(format!("ts_file_{}_{}.ts", name, std::process::id()))
+            .to_string_lossy()
+            .to_string();
+        for filename in [filename.clone(), format!("{}.wal", filename)] {
+            let _ = std::fs::remove_file(filename);
+        }
+        Self(filename)
+    }
+}
+impl Drop for TmpFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+        let _ = std::fs::remove_file(format!("{}.wal", self.0));
+    }
+}
+
+// applies a block that sets the ts root and the l2 address of an account
+fn apply_block(ts_file: &mut TsFile, acc_id: u64, value: u64) -> Fr {
+    let root = {
+        let mut state = ts_file.to_state().unwrap();
+        state.set_ts_root(Fr::from(value)).unwrap();
+        state
+            .accounts
+            .update(acc_id, |account| {
+                account.l2_addr = Fr::from(value);
+                Ok(())
+            })
+            .unwrap();
+        state.get_root().unwrap()
+    };
+    ts_file.block_count += 1;
+    root
+}
+
+// spread over the buckets of the index
+fn l1_addr(acc_id: u64) -> [u8; 20] {
+    let mut l1_addr = [0x55; 20];
+    l1_addr[..8].copy_from_slice(&(acc_id * 7919).to_le_bytes());
+    l1_addr
+}
+
+fn header() -> Header {
+    Header {
+        params: Params::default(),
+        contract_addr: [0x11; 20],
+        chain_id: 1,
+    }
+}
+
+fn open(filename: &str) -> TsFile {
+    TsFile::open(filename, Some(1), header()).unwrap()
+}
+
+fn config_json(filename: &str) -> serde_json::Value {
+    serde_json::json!({
+        "ts_filename": filename,
+        "ts_contract_addr": format!("0x{}", "11".repeat(20)),
+        "api_key": "",
+        "api_link": "",
+        "l2_genesis_l1_anchor_id": 1,
+        "max_parallel_calls": 1,
+        "filter_batch_size": 1,
+        "chain_id": 1,
+    })
+}
+
+fn config(filename: &str) -> Config {
+    serde_json::from_value(config_json(filename)).unwrap()
+}
+
+#[test]
+fn interrupted_block_is_rolled_back() {
+    let tmp = TmpFile::new("rollback");
+    let mut ts_file = open(&tmp.0);
+    let root = apply_block(&mut ts_file, 2, 5);
+    ts_file.sync().unwrap();
+    // the next block, far enough to grow the account tree, is interrupted before its sync
+    apply_block(&mut ts_file, 1000, 6);
+    drop(ts_file);
+
+    let ts_file = open(&tmp.0);
+    assert_eq!(ts_file.block_count, 2);
+    let state = ts_file.to_state().unwrap();
+    assert_eq!(state.get_root().unwrap(), root);
+    assert_eq!(state.ts_root.get().unwrap(), Fr::from(5u64));
+    assert_eq!(state.accounts.leaf_at(2).unwrap().l2_addr, Fr::from(5u64));
+    assert_eq!(
+        state.accounts.leaf_at(1000).unwrap().l2_addr,
+        Fr::from(0u64)
+    );
+}
+
+#[test]
+fn synced_blocks_are_kept() {
+    let tmp = TmpFile::new("synced");
+    let mut ts_file = open(&tmp.0);
+    apply_block(&mut ts_file, 2, 5);
+    ts_file.sync().unwrap();
+    let root = apply_block(&mut ts_file, 1000, 6);
+    ts_file.close().unwrap();
+
+    let ts_file = open(&tmp.0);
+    assert_eq!(ts_file.block_count, 3);
+    assert_eq!(ts_file.to_state().unwrap().get_root().unwrap(), root);
+    assert!(!std::path::Path::new(&format!("{}.wal", tmp.0)).exists());
+}
+
+#[test]
+fn torn_log_is_dropped() {
+    let tmp = TmpFile::new("torn");
+    let mut ts_file = open(&tmp.0);
+    let root = apply_block(&mut ts_file, 2, 5);
+    ts_file.close().unwrap();
+    let len = std::fs::metadata(&tmp.0).unwrap().len();
+    // a log cut short while it was being written
+    std::fs::write(format!("{}.wal", tmp.0), [7u8; 300]).unwrap();
+
+    let ts_file = open(&tmp.0);
+    assert_eq!(std::fs::metadata(&tmp.0).unwrap().len(), len);
+    assert_eq!(ts_file.to_state().unwrap().get_root().unwrap(), root);
+    assert!(!std::path::Path::new(&format!("{}.wal", tmp.0)).exists());
+}
+
+#[test]
+fn mismatched_header_is_rejected() {
+    let tmp = TmpFile::new("mismatch");
+    open(&tmp.0).close().unwrap();
+    let len = std::fs::metadata(&tmp.0).unwrap().len();
+
+    let other_chain = Header {
+        chain_id: 5,
+        ..header()
+    };
+    let e = TsFile::open(&tmp.0, Some(1), other_chain).err().unwrap();
+    assert!(e.contains("chain_id 1, not 5"), "{}", e);
+    let other_contract = Header {
+        contract_addr: [0x22; 20],
+        ..header()
+    };
+    assert!(TsFile::open(&tmp.0, Some(1), other_contract).is_err());
+    let other_params = Header {
+        params: Params {
+            account_tree_height: 8,
+            ..Params::default()
+        },
+        ..header()
+    };
+    assert!(TsFile::open(&tmp.0, Some(1), other_params).is_err());
+    assert!(TsFile::open(&tmp.0, Some(2), header()).is_err());
+    // an existing file can be opened without knowing its anchor
+    assert!(TsFile::open(&tmp.0, None, header()).is_ok());
+    assert_eq!(std::fs::metadata(&tmp.0).unwrap().len(), len);
+
+    // the configuration must name its chain, which the commands check against the file
+    let mut cfg = config_json(&tmp.0);
+    cfg.as_object_mut().unwrap().remove("chain_id");
+    assert!(serde_json::from_value::<Config>(cfg.clone()).is_err());
+    cfg["chain_id"] = 5.into();
+    let e = query_funds(serde_json::from_value(cfg).unwrap(), 1, 1, None)
+        .err()
+        .unwrap();
+    assert!(e.contains("chain_id 1, not 5"), "{}", e);
+}
+
+#[test]
+fn foreign_file_is_rejected() {
+    let tmp = TmpFile::new("foreign");
+    std::fs::write(&tmp.0, [7u8; 1024]).unwrap();
+    assert!(TsFile::open(&tmp.0, Some(1), header()).is_err());
+    assert_eq!(std::fs::read(&tmp.0).unwrap(), [7u8; 1024]);
+
+    // a header with a flipped bit
+    std::fs::remove_file(&tmp.0).unwrap();
+    open(&tmp.0).close().unwrap();
+    let mut bytes = std::fs::read(&tmp.0).unwrap();
+    bytes[40] ^= 1;
+    std::fs::write(&tmp.0, &bytes).unwrap();
+    let e = TsFile::open(&tmp.0, Some(1), header()).err().unwrap();
+    assert!(e.contains("corrupted"), "{}", e);
+}
+
+#[test]
+fn legacy_file_is_migrated() {
+    // a version 1 file of 3 blocks whose indexes were lists in the account records: the history of
+    // accounts 2 and 3, the roots of blocks 1 and 2, the L1 addresses of accounts 2 and 3, the L1
+    // requests synced up to block 42 and the evacuation of token 1 of account 2 and token 48 of account 3
+    let fixture =
+        std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/v1.ts")).unwrap();
+    let root = "18835426297011079206191303965710377977235484560525938302872561380176709632405";
+    let tmp = TmpFile::new("legacy");
+    // the files synced before the header was introduced hold the same data without it
+    for (version, bytes) in [(1, &fixture[..]), (0, &fixture[128..])] {
+        std::fs::write(&tmp.0, bytes).unwrap();
+        assert!(TsFile::open(&tmp.0, Some(1), header()).is_err());
+        assert_eq!(TsFile::migrate(&tmp.0, 1, header()).unwrap(), Some(version));
+
+        let ts_file = open(&tmp.0);
+        assert_eq!(ts_file.block_count, 3);
+        assert_eq!(
+            ts_file.to_state().unwrap().get_root().unwrap().to_string(),
+            root
+        );
+        let history: Vec<(usize, u64)> = history::load_history(&ts_file, 2)
+            .unwrap()
+            .iter()
+            .map(|e| (e.tx_id, e.token_id))
+            .collect();
+        assert_eq!(history, vec![(7, 1), (9, 2), (9, 1)]);
+        assert_eq!(history::load_history(&ts_file, 3).unwrap().len(), 1);
+        assert_eq!(
+            history::load_block_root(&ts_file, 2).unwrap(),
+            Some(history::BlockRoot {
+                ts_root: Fr::from(2u64),
+                state_root: Fr::from(102u64),
+                timestamp: 0,
+            })
+        );
+        assert_eq!(
+            address::find_acc_id_by_l1_addr(&ts_file, [0x33; 20]).unwrap(),
+            Some(3)
+        );
+        assert_eq!(address::load_l1_req_synced_block_id(&ts_file).unwrap(), 42);
+        let evacuated: Vec<(usize, u64)> = [2, 3]
+            .iter()
+            .flat_map(|acc_id| evacuation::load_evacuated(&ts_file, *acc_id).unwrap())
+            .map(|e| (e.acc_id, e.token_id))
+            .collect();
+        assert_eq!(evacuated, vec![(2, 1), (3, 48)]);
+        drop(ts_file);
+        assert_eq!(TsFile::migrate(&tmp.0, 1, header()).unwrap(), None);
+    }
+}
+
+#[test]
+fn indexes_are_keyed() {
+    let tmp = TmpFile::new("indexes");
+    let mut ts_file = open(&tmp.0);
+    apply_block(&mut ts_file, 2, 5);
+    apply_block(&mut ts_file, 3, 6);
+    for (acc_id, tx_id, token_id, avl_amt, locked_amt) in [
+        (2, 7, 1, 10, 0),
+        (3, 8, 1, 20, 0),
+        (2, 9, 2, 0, 30),
+        (2, 9, 1, 40, 0),
+    ] {
+        let change = BalanceChange {
+            acc_id,
+            token_id,
+            avl_amt: Fr::from(avl_amt),
+            locked_amt: Fr::from(locked_amt),
+        };
+        history::push_history(&ts_file, tx_id, &change).unwrap();
+    }
+    for block_id in 1..=2 {
+        let block_root = history::BlockRoot {
+            ts_root: Fr::from(block_id as u64),
+            state_root: Fr::from(100 + block_id as u64),
+            timestamp: 1700000000 + block_id as u64,
+        };
+        history::push_block_root(&ts_file, block_id, block_root).unwrap();
+    }
+    // enough addresses to fill several buckets, one of them registered again
+    for acc_id in 0..300u64 {
+        address::push_l1_addr(&ts_file, acc_id as usize, l1_addr(acc_id)).unwrap();
+    }
+    address::push_l1_addr(&ts_file, 1000, l1_addr(0)).unwrap();
+    address::set_l1_req_synced_block_id(&ts_file, 42).unwrap();
+    evacuation::push_evacuated(&ts_file, 2, 300, Fr::from(50u64)).unwrap();
+    evacuation::push_evacuated(&ts_file, 2, 1, Fr::from(0u64)).unwrap();
+    // a pair is only evacuated once
+    evacuation::push_evacuated(&ts_file, 2, 1, Fr::from(60u64)).unwrap();
+    ts_file.close().unwrap();
+
+    let ts_file = open(&tmp.0);
+    let load = |acc_id: usize| -> Vec<(usize, u64, Fr, Fr)> {
+        history::load_history(&ts_file, acc_id)
+            .unwrap()
+            .iter()
+            .map(|e| (e.tx_id, e.token_id, e.avl_amt, e.locked_amt))
+            .collect()
+    };
+    assert_eq!(
+        load(2),
+        vec![
+            (7, 1, Fr::from(10u64), Fr::from(0u64)),
+            (9, 2, Fr::from(0u64), Fr::from(30u64)),
+            (9, 1, Fr::from(40u64), Fr::from(0u64)),
+        ]
+    );
+    assert_eq!(load(3), vec![(8, 1, Fr::from(20u64), Fr::from(0u64))]);
+    assert!(load(4).is_empty());
+    assert!(load(1 << 20).is_empty());
+    assert_eq!(
+        history::load_block_root(&ts_file, 2).unwrap(),
+        Some(history::BlockRoot {
+            ts_root: Fr::from(2u64),
+            state_root: Fr::from(102u64),
+            timestamp: 1700000002,
+        })
+    );
+    assert_eq!(history::load_block_root(&ts_file, 3).unwrap(), None);
+    for acc_id in 1..300u64 {
+        assert_eq!(
+            address::find_acc_id_by_l1_addr(&ts_file, l1_addr(acc_id)).unwrap(),
+            Some(acc_id as usize)
+        );
+    }
+    assert_eq!(
+        address::find_acc_id_by_l1_addr(&ts_file, l1_addr(0)).unwrap(),
+        Some(1000)
+    );
+    assert_eq!(
+        address::find_acc_id_by_l1_addr(&ts_file, [0x44; 20]).unwrap(),
+        None
+    );
+    assert_eq!(address::load_l1_req_synced_block_id(&ts_file).unwrap(), 42);
+    let evacuated: Vec<(u64, Fr)> = evacuation::load_evacuated(&ts_file, 2)
+        .unwrap()
+        .iter()
+        .map(|e| (e.token_id, e.amount))
+        .collect();
+    assert_eq!(evacuated, vec![(1, Fr::from(0u64)), (300, Fr::from(50u64))]);
+    assert!(evacuation::find_evacuated(&ts_file, 3, 1)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn l2_addrs_are_indexed_on_migration() {
+    // a version 2 file of one block registering accounts 2 and 3 with the L2 addresses 0x7502 and
+    // 0x7503, and account 2 with the L1 address 0x2222...
+    let tmp = TmpFile::new("legacy_v2");
+    std::fs::copy(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/v2.ts"),
+        &tmp.0,
+    )
+    .unwrap();
+    assert!(TsFile::open(&tmp.0, Some(1), header()).is_err());
+    assert_eq!(TsFile::migrate(&tmp.0, 1, header()).unwrap(), Some(2));
+
+    let ts_file = open(&tmp.0);
+    assert_eq!(ts_file.block_count, 2);
+    assert_eq!(
+        ts_file.to_state().unwrap().get_root().unwrap().to_string(),
+        "32353309508745687360104682151610859874251405463196870174686611715858851706"
+    );
+    for acc_id in [2, 3] {
+        assert_eq!(
+            address::find_acc_id_by_l2_addr(&ts_file, Fr::from(0x7500 + acc_id as u64)).unwrap(),
+            Some(acc_id)
+        );
+    }
+    assert_eq!(
+        address::find_acc_id_by_l2_addr(&ts_file, Fr::from(0x7504u64)).unwrap(),
+        None
+    );
+    assert_eq!(
+        address::find_acc_id_by_l1_addr(&ts_file, [0x22; 20]).unwrap(),
+        Some(2)
+    );
+}
+
+#[test]
+fn bond_tokens_are_indexed_on_migration() {
+    // a version 3 file of one block creating bond token 48 of token 1 and bond token 49 of token 2
+    let tmp = TmpFile::new("legacy_v3");
+    std::fs::copy(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/v3.ts"),
+        &tmp.0,
+    )
+    .unwrap();
+    assert!(TsFile::open(&tmp.0, Some(1), header()).is_err());
+    assert_eq!(TsFile::migrate(&tmp.0, 1, header()).unwrap(), Some(3));
+
+    let ts_file = open(&tmp.0);
+    let state = ts_file.to_state().unwrap();
+    assert_eq!(
+        state.get_root().unwrap().to_string(),
+        "11111136759937335548574249596774232166565402845047929052520827150573194067803"
+    );
+    assert_eq!(state.find_bond_token_id(1, Fr::from(1715552000u64)), Ok(48));
+    assert_eq!(state.find_bond_token_id(2, Fr::from(1731363200u64)), Ok(49));
+    assert!(state
+        .find_bond_token_id(2, Fr::from(1715552000u64))
+        .is_err());
+}
+
+#[test]
+fn acc_id_is_found_by_address() {
+    let tmp = TmpFile::new("find_acc_id");
+    let ts_file = open(&tmp.0);
+    for acc_id in 0..300u64 {
+        address::push_l1_addr(&ts_file, acc_id as usize, l1_addr(acc_id)).unwrap();
+        address::push_l2_addr(&ts_file, acc_id as usize, Fr::from(0x7500 + acc_id)).unwrap();
+    }
+    ts_file.close().unwrap();
+
+    let cfg = config(&tmp.0);
+    let find = |addr: String| find_acc_id(cfg.clone(), &addr);
+    assert_eq!(find(format!("0x{}", hex::encode(l1_addr(42)))), Ok(42));
+    // an L2 address is given as 20 big-endian bytes
+    assert_eq!(find(format!("0x{:040x}", 0x7500 + 43)), Ok(43));
+    assert!(find(format!("0x{:040x}", 0x7500 + 300)).is_err());
+    assert!(find("0x1234".to_string()).is_err());
+}
+
+#[test]
+fn audit_reports_the_unbalanced_block() {
+    let tmp = TmpFile::new("audit");
+    let mut ts_file = open(&tmp.0);
+    let blocks = [
+        vec![
+            ts_tx::Tx::TxRegister(TxRegister {
+                account_id: 1,
+                hashed_pub_key: Fr::from(0x7501u64),
+            }),
+            ts_tx::Tx::TxRegister(TxRegister {
+                account_id: 2,
+                hashed_pub_key: Fr::from(0x7502u64),
+            }),
+            ts_tx::Tx::TxDeposit(TxDeposit {
+                account_id: 1,
+                deposit_token_id: 1,
+                deposit_amt: Fr::from(1000u64),
+            }),
+        ],
+        vec![ts_tx::Tx::TxTransfer(TxTransfer {
+            sender_id: 1,
+            transfer_token_id: 1,
+            transfer_amt: Fr::from(300u64),
+            receiver_id: 2,
+        })],
+        // a withdrawal whose fee credits the account, which no L1 request or fee accounts for
+        vec![ts_tx::Tx::TxWithdraw(TxWithdraw {
+            account_id: 2,
+            withdraw_token_id: 1,
+            withdraw_amt: Fr::from(100u64),
+            tx_fee_token_id: 1,
+            tx_fee_amt: -Fr::from(10u64),
+        })],
+        vec![ts_tx::Tx::TxDeposit(TxDeposit {
+            account_id: 2,
+            deposit_token_id: 1,
+            deposit_amt: Fr::from(50u64),
+        })],
+    ];
+    {
+        let mut state = ts_file.to_state().unwrap();
+        for (block_index, raw_txs) in blocks.iter().enumerate() {
+            let first_tx_id = block_index * state.params.tx_count_per_block;
+            for (offset, raw_tx) in raw_txs.iter().enumerate() {
+                state.push_tx(first_tx_id + offset, *raw_tx).unwrap();
+                state.update(first_tx_id + offset).unwrap();
+            }
+        }
+    }
+    ts_file.block_count += blocks.len() as u64;
+    ts_file.close().unwrap();
+
+    let report =
+        |end_block_id| serde_json::to_value(audit(config(&tmp.0), end_block_id).unwrap()).unwrap();
+    // balanced up to the block before
+    let balanced = report(Some(2));
+    assert_eq!(balanced["issues"], serde_json::json!([]));
+    assert_eq!(balanced["tokens"][0]["avl_amt"], "1000");
+
+    let unbalanced = report(None);
+    assert_eq!(unbalanced["end_block_id"], 4);
+    assert_eq!(
+        unbalanced["issues"],
+        serde_json::json!([{
+            "block_id": 3,
+            "token_id": 1,
+            "message": "fees are negative: -10",
+        }])
+    );
+    assert_eq!(unbalanced["tokens"][0]["fee_amt"], "-10");
+    assert_eq!(unbalanced["tokens"][0]["avl_amt"], "960");
+}
+
+#[test]
+fn audit_reports_the_auction_that_credits_the_wrong_amount() {
+    let tmp = TmpFile::new("audit_auction");
+    let mut ts_file = open(&tmp.0);
+    const T0: u64 = 1700000000;
+    const MATURITY: u64 = T0 + 180 * 86400;
+    let n = Params::default().tx_count_per_block as u64;
+    let borrow = |borrowing_amt: u64| {
+        ts_tx::Tx::TxAucBorrow(TxAucBorrow {
+            sender_id: 2,
+            collateral_token_id: 2,
+            collateral_amt: Fr::from(50000000u64),
+            fee_rate: Fr::from(1000000u64),
+            borrowing_amt: Fr::from(borrowing_amt),
+            matched_time: Fr::from(T0),
+            primary_borrow_min_fee_amt: Fr::from(10000u64),
+        })
+    };
+    // an auction that opens at `start_tx_id` and matches the lend order of tx 5
+    let auction = |start_tx_id: u64, borrower_tx_id: u64, collateral_amt: u64| {
+        vec![
+            ts_tx::Tx::TxAucStart(TxAucStart {
+                borrower_tx_offset: start_tx_id - borrower_tx_id,
+                ori_matched_interest: Fr::from(105000000u64),
+            }),
+            ts_tx::Tx::TxAucMatch(TxAucMatch {
+                lender_tx_offset: start_tx_id + 1 - 5,
+            }),
+            ts_tx::Tx::TxAucEnd(TxAucEnd {
+                borrow_account: Fr::from(2u64),
+                collateral_token_id: 2,
+                collateral_amt: Fr::from(collateral_amt),
+                debt_token_id: 1,
+                debt_amt: Fr::from(0u64),
+                matched_time: Fr::from(T0),
+                maturity: Fr::from(MATURITY),
+            }),
+        ]
+    };
+    let blocks = [
+        vec![
+            ts_tx::Tx::TxCreateTsbBondToken(TxCreateTsbBondToken {
+                maturity: Fr::from(MATURITY),
+                base_token_id: 1,
+                bond_token_id: 48,
+            }),
+            ts_tx::Tx::TxRegister(TxRegister {
+                account_id: 1,
+                hashed_pub_key: Fr::from(0x7501u64),
+            }),
+            ts_tx::Tx::TxRegister(TxRegister {
+                account_id: 2,
+                hashed_pub_key: Fr::from(0x7502u64),
+            }),
+            ts_tx::Tx::TxDeposit(TxDeposit {
+                account_id: 1,
+                deposit_token_id: 1,
+                deposit_amt: Fr::from(200000000u64),
+            }),
+            ts_tx::Tx::TxDeposit(TxDeposit {
+                account_id: 2,
+                deposit_token_id: 2,
+                deposit_amt: Fr::from(100000000u64),
+            }),
+            ts_tx::Tx::TxAucLend(TxAucLend {
+                lender_id: 1,
+                lending_token_id: 1,
+                lending_amt: Fr::from(100000000u64),
+                fee_rate: Fr::from(1000000u64),
+                default_matched_interest_rate: Fr::from(5000000u64),
+                maturity_time: Fr::from(MATURITY),
+                matched_time: Fr::from(T0),
+                primary_lend_min_fee_amt: Fr::from(10000u64),
+            }),
+            borrow(60000000),
+        ],
+        auction(n, 6, 50000000),
+        // the borrower is matched in full, but the end books less collateral than it takes
+        [vec![borrow(30000000)], auction(2 * n + 1, 2 * n, 40000000)].concat(),
+    ];
+    {
+        let mut state = ts_file.to_state().unwrap();
+        for (block_index, raw_txs) in blocks.iter().enumerate() {
+            let first_tx_id = block_index * state.params.tx_count_per_block;
+            for (offset, raw_tx) in raw_txs.iter().enumerate() {
+                state.push_tx(first_tx_id + offset, *raw_tx).unwrap();
+            }
+            for offset in 0..raw_txs.len() {
+                state.update(first_tx_id + offset).unwrap();
+            }
+        }
+    }
+    ts_file.block_count += blocks.len() as u64;
+    ts_file.close().unwrap();
+
+    let report =
+        |end_block_id| serde_json::to_value(audit(config(&tmp.0), end_block_id).unwrap()).unwrap();
+    let balanced = report(Some(2));
+    assert_eq!(balanced["issues"], serde_json::json!([]));
+    assert_eq!(balanced["tokens"][1]["to_loans_amt"], "50000000");
+
+    let unbalanced = report(None);
+    assert_eq!(
+        unbalanced["issues"],
+        serde_json::json!([{
+            "block_id": 3,
+            "token_id": 2,
+            "message": "the accounts gained -10000000 that no deposit, withdrawal, evacuation, fee or loan accounts for",
+        }])
+    );
+}
+
+// syncs one deposit per block with the given amounts, recording the root of each block
+fn sync_deposits(filename: &str, amts: &[u64]) {
+    let mut ts_file = open(filename);
+    {
+        let mut state = ts_file.to_state().unwrap();
+        let n = state.params.tx_count_per_block;
+        state
+            .push_tx(
+                0,
+                ts_tx::Tx::TxRegister(TxRegister {
+                    account_id: 1,
+                    hashed_pub_key: Fr::from(0x7501u64),
+                }),
+            )
+            .unwrap();
+        for (block_index, amt) in amts.iter().enumerate() {
+            let tx_id = block_index * n + (block_index == 0) as usize;
+            state
+                .push_tx(
+                    tx_id,
+                    ts_tx::Tx::TxDeposit(TxDeposit {
+                        account_id: 1,
+                        deposit_token_id: 1,
+                        deposit_amt: Fr::from(*amt),
+                    }),
+                )
+                .unwrap();
+            for tx_id in block_index * n..=tx_id {
+                state.update(tx_id).unwrap();
+            }
+            let block_root = history::BlockRoot {
+                ts_root: state.ts_root.get().unwrap(),
+                state_root: state.get_root().unwrap(),
+                timestamp: 0,
+            };
+            history::push_block_root(&ts_file, block_index + 1, block_root).unwrap();
+        }
+    }
+    ts_file.block_count += amts.len() as u64;
+    ts_file.close().unwrap();
+}
+
+#[test]
+fn snapshots_follow_the_synced_blocks() {
+    let tmp = TmpFile::new("snapshots");
+    let cfg = config(&tmp.0);
+    let snapshots = || {
+        (1..=6)
+            .filter(|block_id| std::path::Path::new(&format!("{}.{}", tmp.0, block_id)).exists())
+            .collect::<Vec<usize>>()
+    };
+    let funds_at = |block_id| {
+        serde_json::to_value(query_funds(cfg.clone(), 1, 1, Some(block_id)).unwrap()).unwrap()
+            ["avl_amt"]
+            .clone()
+    };
+    sync_deposits(&tmp.0, &[100, 200, 300, 400, 500, 600]);
+    assert_eq!(funds_at(2), "300");
+    assert_eq!(funds_at(4), "1000");
+    assert_eq!(snapshots(), vec![2, 4]);
+
+    // a reorg syncs other blocks from block 2 on, the snapshots built from the dropped ones are replayed again
+    std::fs::remove_file(&tmp.0).unwrap();
+    sync_deposits(&tmp.0, &[100, 20, 30, 40, 50, 60]);
+    assert_eq!(funds_at(4), "190");
+    assert_eq!(funds_at(1), "100");
+    assert_eq!(funds_at(2), "120");
+
+    // the least recently used snapshots are removed beyond the cap
+    assert_eq!(funds_at(5), "240");
+    assert_eq!(funds_at(6), "300");
+    assert_eq!(snapshots(), vec![1, 2, 5, 6]);
+    for block_id in 1..=6 {
+        let _ = std::fs::remove_file(format!("{}.{}", tmp.0, block_id));
+        let _ = std::fs::remove_file(format!("{}.{}.wal", tmp.0, block_id));
+    }
+}
+
+#[test]
+fn positions_mature_at_the_time_of_the_last_synced_block() {
+    let tmp = TmpFile::new("positions");
+    const MATURITY: u64 = 1715552000;
+    let blocks = [
+        (
+            MATURITY - 86400,
+            vec![
+                ts_tx::Tx::TxCreateTsbBondToken(TxCreateTsbBondToken {
+                    maturity: Fr::from(MATURITY),
+                    base_token_id: 1,
+                    bond_token_id: 48,
+                }),
+                ts_tx::Tx::TxRegister(TxRegister {
+                    account_id: 1,
+                    hashed_pub_key: Fr::from(0x7501u64),
+                }),
+                ts_tx::Tx::TxDeposit(TxDeposit {
+                    account_id: 1,
+                    deposit_token_id: 48,
+                    deposit_amt: Fr::from(500u64),
+                }),
+            ],
+        ),
+        (
+            MATURITY,
+            vec![ts_tx::Tx::TxDeposit(TxDeposit {
+                account_id: 1,
+                deposit_token_id: 1,
+                deposit_amt: Fr::from(100u64),
+            })],
+        ),
+    ];
+    let bond = || {
+        serde_json::to_value(get_positions(config(&tmp.0), 1).unwrap()).unwrap()["bonds"][0].clone()
+    };
+    for (block_index, (timestamp, raw_txs)) in blocks.iter().enumerate() {
+        let mut ts_file = open(&tmp.0);
+        {
+            let mut state = ts_file.to_state().unwrap();
+            let first_tx_id = block_index * state.params.tx_count_per_block;
+            for (offset, raw_tx) in raw_txs.iter().enumerate() {
+                state.push_tx(first_tx_id + offset, *raw_tx).unwrap();
+                state.update(first_tx_id + offset).unwrap();
+            }
+            let block_root = history::BlockRoot {
+                ts_root: state.ts_root.get().unwrap(),
+                state_root: state.get_root().unwrap(),
+                timestamp: *timestamp,
+            };
+            history::push_block_root(&ts_file, block_index + 1, block_root).unwrap();
+        }
+        ts_file.block_count += 1;
+        ts_file.close().unwrap();
+
+        let bond = bond();
+        assert_eq!(bond["avl_amt"], "500");
+        if block_index == 0 {
+            assert_eq!(bond["is_matured"], false);
+            assert_ne!(bond["redeemable_amt"], "500");
+        } else {
+            assert_eq!(bond["is_matured"], true);
+            assert_eq!(bond["redeemable_amt"], "500");
+        }
+    }
+}
+
+#[test]
+fn multi_evacu_prf_selects_the_funded_tokens_left() {
+    let tmp = TmpFile::new("multi_evacu");
+    let mut ts_file = open(&tmp.0);
+    let deposit = |token_id, amt: u64| {
+        ts_tx::Tx::TxDeposit(TxDeposit {
+            account_id: 1,
+            deposit_token_id: token_id,
+            deposit_amt: Fr::from(amt),
+        })
+    };
+    let raw_txs = [
+        ts_tx::Tx::TxRegister(TxRegister {
+            account_id: 1,
+            hashed_pub_key: Fr::from(0x7501u64),
+        }),
+        ts_tx::Tx::TxRegister(TxRegister {
+            account_id: 2,
+            hashed_pub_key: Fr::from(0x7502u64),
+        }),
+        deposit(1, 1000),
+        deposit(3, 500),
+        deposit(5, 20),
+        deposit(300, 7),
+        // token 5 is emptied again
+        ts_tx::Tx::TxTransfer(TxTransfer {
+            sender_id: 1,
+            transfer_token_id: 5,
+            transfer_amt: Fr::from(20u64),
+            receiver_id: 2,
+        }),
+    ];
+    {
+        let mut state = ts_file.to_state().unwrap();
+        for (tx_id, raw_tx) in raw_txs.iter().enumerate() {
+            state.push_tx(tx_id, *raw_tx).unwrap();
+            state.update(tx_id).unwrap();
+        }
+    }
+    ts_file.block_count += 1;
+    // token 3 has been evacuated already
+    evacuation::push_evacuated(&ts_file, 1, 3, Fr::from(500u64)).unwrap();
+    ts_file.close().unwrap();
+
+    let prf = serde_json::to_value(get_multi_evacu_prf(config(&tmp.0), 1, None).unwrap()).unwrap();
+    assert_eq!(prf["tokenIds"], serde_json::json!(["1", "300"]));
+    assert_eq!(prf["avlAmts"], serde_json::json!(["1000", "7"]));
+    let prf = serde_json::to_value(get_multi_evacu_prf(config(&tmp.0), 2, None).unwrap()).unwrap();
+    assert_eq!(prf["tokenIds"], serde_json::json!(["5"]));
+    assert!(get_multi_evacu_prf(config(&tmp.0), 3, None).is_err());
+}
@@ -0,0 +1,134 @@
+use ark_bn254::Fr;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use std::collections::HashMap;
+use ts_merkle_tree::MerkleTree;
+use ts_poseidon::poseidon;
+
+const LEVEL: usize = 32;
+const ACCOUNT_COUNT: usize = 1024;
+const TX_COUNT_PER_BLOCK: usize = 256;
+
+#[derive(Clone)]
+struct MemTree {
+    nodes: HashMap<usize, Fr>,
+    actual_level: usize,
+    default_proof: Vec<Fr>,
+    deferred: bool,
+    dirty_leaf_ids: Vec<usize>,
+}
+impl MemTree {
+    fn new() -> Self {
+        let mut default_proof = vec![Fr::from(0u64)];
+        for i in 1..LEVEL {
+            default_proof.push(Self::hash(default_proof[i - 1], default_proof[i - 1]).unwrap());
+        }
+        Self {
+            nodes: HashMap::new(),
+            actual_level: 0,
+            default_proof,
+            deferred: false,
+            dirty_leaf_ids: vec![],
+        }
+    }
+}
+impl MerkleTree for MemTree {
+    type Node = Fr;
+    fn hash(left: Self::Node, right: Self::Node) -> Result<Self::Node, String> {
+        Ok(poseidon::<3>(&[left, right]))
+    }
+    fn idx_at(&self, idx: usize) -> Result<Option<Self::Node>, String> {
+        Ok(self.nodes.get(&idx).copied())
+    }
+    fn idx_set(&mut self, idx: usize, node: Option<Self::Node>) -> Result<(), String> {
+        match node {
+            Some(node) => self.nodes.insert(idx, node),
+            None => self.nodes.remove(&idx),
+        };
+        Ok(())
+    }
+    fn get_actual_level(&self) -> Result<usize, String> {
+        Ok(self.actual_level)
+    }
+    fn set_actual_level(&mut self, level: usize) -> Result<(), String> {
+        self.actual_level = level;
+        Ok(())
+    }
+    fn get_default_proof(&self, idx: usize) -> Result<Self::Node, String> {
+        self.default_proof
+            .get(idx)
+            .copied()
+            .ok_or(format!("default proof index out of range: {}", idx))
+    }
+    fn get_level(&self) -> Result<usize, String> {
+        Ok(LEVEL)
+    }
+    fn is_deferred(&self) -> Result<bool, String> {
+        Ok(self.deferred)
+    }
+    fn get_dirty_leaf_ids(&self) -> Result<&[usize], String> {
+        Ok(&self.dirty_leaf_ids)
+    }
+    fn push_dirty_leaf_id(&mut self, leaf_id: usize) -> Result<(), String> {
+        self.dirty_leaf_ids.push(leaf_id);
+        Ok(())
+    }
+    fn take_dirty_leaf_ids(&mut self) -> Result<Vec<usize>, String> {
+        Ok(std::mem::take(&mut self.dirty_leaf_ids))
+    }
+}
+
+// every transfer rewrites the sender and the receiver leaf
+fn block_of_transfers() -> Vec<(usize, Fr)> {
+    let mut seed: u64 = 0x5eed;
+    let mut next = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        seed >> 33
+    };
+    (0..TX_COUNT_PER_BLOCK * 2)
+        .map(|_| (next() as usize % ACCOUNT_COUNT, Fr::from(next())))
+        .collect()
+}
+
+fn apply_block(mut tree: MemTree, block: &[(usize, Fr)]) -> Fr {
+    for (leaf_id, node) in block {
+        tree.update_leaf_node_deferred(*leaf_id, *node).unwrap();
+    }
+    tree.commit().unwrap();
+    tree.get_root().unwrap()
+}
+
+fn bench_block(c: &mut Criterion) {
+    let mut genesis = MemTree::new();
+    for leaf_id in 0..ACCOUNT_COUNT {
+        genesis
+            .update_leaf_node(leaf_id, Fr::from(leaf_id as u64 + 1))
+            .unwrap();
+    }
+    let mut deferred_genesis = genesis.clone();
+    deferred_genesis.deferred = true;
+    // both give the same root, which tests/reference.rs checks
+    let block = block_of_transfers();
+
+    let mut group = c.benchmark_group("block_of_256_transfers");
+    group.sample_size(10);
+    group.bench_function("eager", |b| {
+        b.iter_batched(
+            || genesis.clone(),
+            |tree| apply_block(tree, &block),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("deferred", |b| {
+        b.iter_batched(
+            || deferred_genesis.clone(),
+            |tree| apply_block(tree, &block),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_block);
+criterion_main!(benches);
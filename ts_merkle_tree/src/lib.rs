@@ -1,3 +1,12 @@
+#![no_std]
+extern crate alloc;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use serde::{Deserialize, Serialize};
 
 fn log2_of_usize(mut n: usize) -> usize {
@@ -8,11 +17,29 @@ fn log2_of_usize(mut n: usize) -> usize {
     }
     log2
 }
-fn lv_order_idx_to_idx(level: usize, idx: usize) -> usize {
+fn check_leaf_id(leaf_id: usize, level: usize) -> Result<(), String> {
+    if level < usize::BITS as usize && leaf_id >> level != 0 {
+        return Err(format!(
+            "leaf_id {} does not fit a tree of {} levels",
+            leaf_id, level
+        ));
+    }
+    Ok(())
+}
+fn lv_order_idx_to_idx(level: usize, idx: usize) -> Result<usize, String> {
     if idx == 0 {
-        panic!("idx 0 is not a valid index");
+        return Err(format!(
+            "idx 0 is not a valid index in a tree of {} levels",
+            level
+        ));
     }
     let depth = log2_of_usize(idx);
+    if depth > level {
+        return Err(format!(
+            "idx {} is below the leaves of a tree of {} levels",
+            idx, level
+        ));
+    }
     let position = idx - (1 << depth);
 
     let n = level - depth;
@@ -21,7 +48,7 @@ fn lv_order_idx_to_idx(level: usize, idx: usize) -> usize {
     let offset = 1 << (n + 1);
 
     let new_idx = start + position * offset;
-    new_idx
+    Ok(new_idx)
 }
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MerkleVerifyPrf<Node: Clone + Copy> {
@@ -52,6 +79,15 @@ pub struct MerkleUpdatePrfWithLeafData<Node: Clone + Copy, Leaf: MerkleLeaf<Node
     pub ori_leaf: Leaf,
     pub new_leaf: Leaf,
 }
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MerkleMultiPrf<Node: Clone + Copy> {
+    pub root: Node,
+    pub level: usize,
+    pub leaf_ids: Vec<usize>,
+    pub leaf_nodes: Vec<Node>,
+    // the siblings that cannot be computed from the leaves, level by level from the bottom
+    pub proof: Vec<Node>,
+}
 pub trait MerkleTree {
     type Node: Clone + Copy;
     fn hash(left: Self::Node, right: Self::Node) -> Result<Self::Node, String>;
@@ -61,8 +97,24 @@ pub trait MerkleTree {
     fn set_actual_level(&mut self, level: usize) -> Result<(), String>;
     fn get_default_proof(&self, idx: usize) -> Result<Self::Node, String>;
     fn get_level(&self) -> Result<usize, String>;
+    fn is_deferred(&self) -> Result<bool, String> {
+        Ok(false)
+    }
+    // the leaves updated since the last commit, possibly repeated
+    fn get_dirty_leaf_ids(&self) -> Result<&[usize], String> {
+        Ok(&[])
+    }
+    fn push_dirty_leaf_id(&mut self, _leaf_id: usize) -> Result<(), String> {
+        Err("deferred update is not supported".to_string())
+    }
+    fn take_dirty_leaf_ids(&mut self) -> Result<Vec<usize>, String> {
+        Ok(vec![])
+    }
 
     fn get_root(&self) -> Result<Self::Node, String> {
+        if !self.get_dirty_leaf_ids()?.is_empty() {
+            return Err("merkle tree has uncommitted leaves".to_string());
+        }
         let mut root = self.lv_order_at(1 << (self.get_level()? - self.get_actual_level()?))?;
         for i in self.get_actual_level()?..self.get_level()? {
             root = Self::hash(root, self.get_default_proof(i)?)?;
@@ -70,13 +122,31 @@ pub trait MerkleTree {
         Ok(root)
     }
     fn lv_order_at(&self, idx: usize) -> Result<Self::Node, String> {
-        match self.idx_at(lv_order_idx_to_idx(self.get_level()?, idx))? {
+        match self.idx_at(lv_order_idx_to_idx(self.get_level()?, idx)?)? {
             Some(node) => Ok(node),
             None => self.get_default_proof(self.get_level()? - log2_of_usize(idx)),
         }
     }
+    // unlike `lv_order_at`, also gives the nodes above the actual level,
+    // where only the ones on the left edge differ from the default ones
+    fn lv_order_node_at(&self, idx: usize) -> Result<Self::Node, String> {
+        let level = self.get_level()?;
+        let actual_level = self.get_actual_level()?;
+        let height = level - log2_of_usize(idx);
+        if height <= actual_level {
+            return self.lv_order_at(idx);
+        }
+        if idx != 1 << (level - height) {
+            return self.get_default_proof(height);
+        }
+        let mut node = self.lv_order_at(1 << (level - actual_level))?;
+        for i in actual_level..height {
+            node = Self::hash(node, self.get_default_proof(i)?)?;
+        }
+        Ok(node)
+    }
     fn lv_order_set(&mut self, idx: usize, node: Self::Node) -> Result<(), String> {
-        self.idx_set(lv_order_idx_to_idx(self.get_level()?, idx), Some(node))?;
+        self.idx_set(lv_order_idx_to_idx(self.get_level()?, idx)?, Some(node))?;
         Ok(())
     }
     fn leaf_id_at(&self, leaf_id: usize) -> Result<Self::Node, String> {
@@ -90,17 +160,11 @@ pub trait MerkleTree {
         Ok(())
     }
     fn verify_leaf_node(&self, leaf_id: usize) -> Result<MerkleVerifyPrf<Self::Node>, String> {
+        check_leaf_id(leaf_id, self.get_level()?)?;
         let mut proof = vec![];
         let mut idx = leaf_id + (1 << self.get_level()?);
-        for _ in 0..self.get_actual_level()? {
-            proof.push(match idx & 1 == 0 {
-                true => self.lv_order_at(idx + 1)?,
-                false => self.lv_order_at(idx - 1)?,
-            });
-            idx >>= 1;
-        }
-        for i in self.get_actual_level()?..self.get_level()? {
-            proof.push(self.get_default_proof(i)?);
+        for _ in 0..self.get_level()? {
+            proof.push(self.lv_order_node_at(idx ^ 1)?);
             idx >>= 1;
         }
         Ok(MerkleVerifyPrf {
@@ -112,14 +176,77 @@ pub trait MerkleTree {
             proof,
         })
     }
+    // a proof that the leaf still holds the default leaf node
+    fn verify_empty_leaf_node(&self, leaf_id: usize) -> Result<MerkleVerifyPrf<Self::Node>, String>
+    where
+        Self::Node: PartialEq,
+    {
+        let prf = self.verify_leaf_node(leaf_id)?;
+        if prf.ori_leaf_node != self.get_default_proof(0)? {
+            return Err(format!("leaf {} is not empty", leaf_id));
+        }
+        Ok(prf)
+    }
+    // the ids of the leaves that differ from the default one, in ascending order
+    fn leaf_ids(&self) -> LeafIds<'_, Self>
+    where
+        Self: Sized,
+        Self::Node: PartialEq,
+    {
+        LeafIds {
+            tree: self,
+            stack: vec![],
+            started: false,
+        }
+    }
+    // one proof for several leaves, the siblings shared by their paths are only given once
+    fn verify_leaf_nodes(&self, leaf_ids: &[usize]) -> Result<MerkleMultiPrf<Self::Node>, String> {
+        let level = self.get_level()?;
+        let mut leaf_ids = leaf_ids.to_vec();
+        leaf_ids.sort_unstable();
+        leaf_ids.dedup();
+        if leaf_ids.is_empty() {
+            return Err("no leaf to prove".to_string());
+        }
+        let mut leaf_nodes = vec![];
+        for leaf_id in leaf_ids.iter() {
+            check_leaf_id(*leaf_id, level)?;
+            leaf_nodes.push(self.leaf_id_at(*leaf_id)?);
+        }
+        let mut idxes: Vec<usize> = leaf_ids
+            .iter()
+            .map(|leaf_id| leaf_id + (1 << level))
+            .collect();
+        let mut proof = vec![];
+        for _ in 0..level {
+            for (j, idx) in idxes.iter().enumerate() {
+                let has_brother = match idx & 1 == 0 {
+                    true => idxes.get(j + 1) == Some(&(idx + 1)),
+                    false => j > 0 && idxes[j - 1] == idx - 1,
+                };
+                if has_brother {
+                    continue;
+                }
+                proof.push(self.lv_order_node_at(idx ^ 1)?);
+            }
+            idxes = idxes.iter().map(|idx| idx >> 1).collect();
+            idxes.dedup();
+        }
+        Ok(MerkleMultiPrf {
+            root: self.get_root()?,
+            level,
+            leaf_ids,
+            leaf_nodes,
+            proof,
+        })
+    }
     fn update_leaf_node(
         &mut self,
         leaf_id: usize,
         node: Self::Node,
     ) -> Result<MerkleUpdatePrf<Self::Node>, String> {
-        if log2_of_usize(leaf_id) > self.get_level()? {
-            panic!("leaf_id is too large");
-        }
+        self.commit()?;
+        check_leaf_id(leaf_id, self.get_level()?)?;
         if log2_of_usize(leaf_id) + 1 > self.get_actual_level()? {
             let new_actual_level = log2_of_usize(leaf_id) + 1;
             for i in self.get_actual_level()?..new_actual_level {
@@ -153,7 +280,7 @@ pub trait MerkleTree {
             }
             idx >>= 1;
         }
-        let mut node = self.lv_order_at(idx).unwrap();
+        let mut node = self.lv_order_at(idx)?;
         for i in self.get_actual_level()?..self.get_level()? {
             let brother = self.get_default_proof(i)?;
             match idx & 1 == 0 {
@@ -172,9 +299,113 @@ pub trait MerkleTree {
             proof,
         })
     }
+    fn update_leaf_node_deferred(
+        &mut self,
+        leaf_id: usize,
+        node: Self::Node,
+    ) -> Result<(), String> {
+        if !self.is_deferred()? {
+            self.update_leaf_node(leaf_id, node)?;
+            return Ok(());
+        }
+        check_leaf_id(leaf_id, self.get_level()?)?;
+        if log2_of_usize(leaf_id) + 1 > self.get_actual_level()? {
+            // the left edge above the old actual level is rebuilt along the path of leaf 0
+            self.set_actual_level(log2_of_usize(leaf_id) + 1)?;
+            self.push_dirty_leaf_id(0)?;
+        }
+        self.lv_order_set(leaf_id + (1 << self.get_level()?), node)?;
+        self.push_dirty_leaf_id(leaf_id)
+    }
+    fn commit(&mut self) -> Result<(), String> {
+        let dirty_leaf_ids = self.take_dirty_leaf_ids()?;
+        if dirty_leaf_ids.is_empty() {
+            return Ok(());
+        }
+        let level = self.get_level()?;
+        let mut idxes: Vec<usize> = dirty_leaf_ids
+            .iter()
+            .map(|leaf_id| leaf_id + (1 << level))
+            .collect();
+        idxes.sort_unstable();
+        idxes.dedup();
+        for _ in 0..self.get_actual_level()? {
+            idxes = idxes.iter().map(|idx| idx >> 1).collect();
+            idxes.dedup();
+            for idx in idxes.iter() {
+                let node = Self::hash(
+                    self.lv_order_at(idx << 1)?,
+                    self.lv_order_at((idx << 1) | 1)?,
+                )?;
+                self.lv_order_set(*idx, node)?;
+            }
+        }
+        Ok(())
+    }
+}
+// walks down the subtrees that hold a node other than the default one of their height
+pub struct LeafIds<'t, T: MerkleTree> {
+    tree: &'t T,
+    stack: Vec<usize>,
+    started: bool,
+}
+impl<'t, T: MerkleTree> LeafIds<'t, T>
+where
+    T::Node: PartialEq,
+{
+    fn is_empty(&self, idx: usize) -> Result<bool, String> {
+        let level = self.tree.get_level()?;
+        let height = level - log2_of_usize(idx);
+        Ok(match self.tree.idx_at(lv_order_idx_to_idx(level, idx)?)? {
+            // the default proof stops below the root
+            Some(_) if height == level => false,
+            Some(node) => node == self.tree.get_default_proof(height)?,
+            None => true,
+        })
+    }
+    fn next_leaf_id(&mut self) -> Result<Option<usize>, String> {
+        let level = self.tree.get_level()?;
+        if !self.started {
+            self.started = true;
+            if !self.tree.get_dirty_leaf_ids()?.is_empty() {
+                return Err("merkle tree has uncommitted leaves".to_string());
+            }
+            self.stack
+                .push(1 << (level - self.tree.get_actual_level()?));
+        }
+        while let Some(idx) = self.stack.pop() {
+            if self.is_empty(idx)? {
+                continue;
+            }
+            if idx >= 1 << level {
+                return Ok(Some(idx - (1 << level)));
+            }
+            self.stack.push((idx << 1) | 1);
+            self.stack.push(idx << 1);
+        }
+        Ok(None)
+    }
+}
+impl<'t, T: MerkleTree> Iterator for LeafIds<'t, T>
+where
+    T::Node: PartialEq,
+{
+    type Item = Result<usize, String>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_leaf_id() {
+            Ok(leaf_id) => leaf_id.map(Ok),
+            Err(e) => {
+                self.stack.clear();
+                Some(Err(e))
+            }
+        }
+    }
 }
 pub trait MerkleLeaf<Node: Clone + Copy> {
     fn digest(&self) -> Result<Node, String>;
+    fn commit(&mut self) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 pub trait MerkleTreeWithLeaves: MerkleTree {
@@ -192,8 +423,9 @@ pub trait MerkleTreeWithLeaves: MerkleTree {
     fn update_leaf(
         &mut self,
         idx: usize,
-        leaf: Self::Leaf,
+        mut leaf: Self::Leaf,
     ) -> Result<MerkleUpdatePrfWithLeafData<Self::Node, Self::Leaf>, String> {
+        leaf.commit()?;
         let digest = leaf.digest();
         let ori_leaf = self.leaf_at(idx)?;
         let merkle_prf = self.update_leaf_node(idx, digest?)?;
@@ -215,4 +447,174 @@ pub trait MerkleTreeWithLeaves: MerkleTree {
         f(&mut leaf)?;
         self.update_leaf(idx as usize, leaf)
     }
+    fn update_leaf_deferred(&mut self, idx: usize, mut leaf: Self::Leaf) -> Result<(), String> {
+        leaf.commit()?;
+        let digest = leaf.digest()?;
+        self.update_leaf_node_deferred(idx, digest)?;
+        self.leaf_set(idx, leaf)
+    }
+    fn update_deferred(
+        &mut self,
+        idx: u64,
+        f: impl FnOnce(&mut Self::Leaf) -> Result<(), String>,
+    ) -> Result<(), String> {
+        let mut leaf = self.leaf_at(idx as usize)?;
+        f(&mut leaf)?;
+        self.update_leaf_deferred(idx as usize, leaf)
+    }
+}
+
+// recomputes the root from a leaf node and the siblings along its path, from the leaf up
+pub fn calc_root<Node: Clone + Copy>(
+    hash: impl Fn(Node, Node) -> Result<Node, String>,
+    leaf_id: usize,
+    leaf_node: Node,
+    proof: &[Node],
+) -> Result<Node, String> {
+    check_leaf_id(leaf_id, proof.len())?;
+    let mut node = leaf_node;
+    for (i, brother) in proof.iter().enumerate() {
+        node = match (leaf_id >> i) & 1 == 0 {
+            true => hash(node, *brother)?,
+            false => hash(*brother, node)?,
+        };
+    }
+    Ok(node)
+}
+// checks that the leaf node is in the tree of the root, without the tree
+pub fn verify_merkle_prf<Node: Clone + Copy + PartialEq>(
+    hash: impl Fn(Node, Node) -> Result<Node, String>,
+    prf: &MerkleVerifyPrf<Node>,
+) -> Result<(), String> {
+    if prf.ori_root != prf.new_root || prf.ori_leaf_node != prf.new_leaf_node {
+        return Err("a membership proof must not change the tree".to_string());
+    }
+    if calc_root(hash, prf.leaf_id, prf.ori_leaf_node, &prf.proof)? != prf.ori_root {
+        return Err(format!(
+            "leaf {} is not in the tree of the root",
+            prf.leaf_id
+        ));
+    }
+    Ok(())
+}
+// checks that replacing the original leaf node with the new one turns the original root
+// into the new root, the siblings being the same before and after
+pub fn verify_update_prf<Node: Clone + Copy + PartialEq>(
+    hash: impl Fn(Node, Node) -> Result<Node, String>,
+    prf: &MerkleUpdatePrf<Node>,
+) -> Result<(), String> {
+    if calc_root(&hash, prf.leaf_id, prf.ori_leaf_node, &prf.proof)? != prf.ori_root {
+        return Err(format!(
+            "original leaf {} is not in the tree of the original root",
+            prf.leaf_id
+        ));
+    }
+    if calc_root(&hash, prf.leaf_id, prf.new_leaf_node, &prf.proof)? != prf.new_root {
+        return Err(format!(
+            "new leaf {} is not in the tree of the new root",
+            prf.leaf_id
+        ));
+    }
+    Ok(())
+}
+// checks that all the leaf nodes are in the tree of the root, the siblings being taken in the
+// order `verify_leaf_nodes` gives them
+pub fn verify_multi_prf<Node: Clone + Copy + PartialEq>(
+    hash: impl Fn(Node, Node) -> Result<Node, String>,
+    prf: &MerkleMultiPrf<Node>,
+) -> Result<(), String> {
+    if prf.leaf_ids.is_empty() || prf.leaf_ids.len() != prf.leaf_nodes.len() {
+        return Err("the proof needs one leaf node per leaf id".to_string());
+    }
+    if prf.leaf_ids.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err("the leaf ids must be sorted and distinct".to_string());
+    }
+    if prf.level >= usize::BITS as usize {
+        return Err(format!("a tree of {} levels is too deep", prf.level));
+    }
+    for leaf_id in prf.leaf_ids.iter() {
+        check_leaf_id(*leaf_id, prf.level)?;
+    }
+    let mut nodes: Vec<(usize, Node)> = prf
+        .leaf_ids
+        .iter()
+        .map(|leaf_id| leaf_id + (1 << prf.level))
+        .zip(prf.leaf_nodes.iter().copied())
+        .collect();
+    let mut proof = prf.proof.iter();
+    for _ in 0..prf.level {
+        let mut parents = vec![];
+        let mut j = 0;
+        while j < nodes.len() {
+            let (idx, node) = nodes[j];
+            let parent = match nodes.get(j + 1) {
+                Some((brother_idx, brother)) if idx & 1 == 0 && *brother_idx == idx + 1 => {
+                    j += 1;
+                    hash(node, *brother)?
+                }
+                _ => {
+                    let brother = *proof.next().ok_or("the proof is too short")?;
+                    match idx & 1 == 0 {
+                        true => hash(node, brother)?,
+                        false => hash(brother, node)?,
+                    }
+                }
+            };
+            parents.push((idx >> 1, parent));
+            j += 1;
+        }
+        nodes = parents;
+    }
+    if proof.next().is_some() {
+        return Err("the proof is too long".to_string());
+    }
+    if nodes[0].1 != prf.root {
+        return Err("the leaves are not in the tree of the root".to_string());
+    }
+    Ok(())
+}
+// checks that the leaf is empty in the tree of the root
+pub fn verify_non_membership_prf<Node: Clone + Copy + PartialEq>(
+    hash: impl Fn(Node, Node) -> Result<Node, String>,
+    default_leaf_node: Node,
+    prf: &MerkleVerifyPrf<Node>,
+) -> Result<(), String> {
+    if prf.ori_leaf_node != default_leaf_node {
+        return Err(format!("leaf {} is not empty", prf.leaf_id));
+    }
+    verify_merkle_prf(hash, prf)
+}
+// the ids of the leaves whose nodes differ between two trees of the same height, in ascending order.
+// both trees are walked from the root, only into the subtrees that differ
+pub fn diff_leaf_ids<A, B>(a: &A, b: &B) -> Result<Vec<usize>, String>
+where
+    A: MerkleTree,
+    B: MerkleTree<Node = A::Node>,
+    A::Node: PartialEq,
+{
+    let level = a.get_level()?;
+    if b.get_level()? != level {
+        return Err(format!(
+            "a tree of {} levels cannot be compared with one of {} levels",
+            level,
+            b.get_level()?
+        ));
+    }
+    if !a.get_dirty_leaf_ids()?.is_empty() || !b.get_dirty_leaf_ids()?.is_empty() {
+        return Err("merkle tree has uncommitted leaves".to_string());
+    }
+    let mut leaf_ids = vec![];
+    let mut stack = vec![1];
+    while let Some(idx) = stack.pop() {
+        if a.lv_order_node_at(idx)? == b.lv_order_node_at(idx)? {
+            continue;
+        }
+        if idx >> level == 1 {
+            leaf_ids.push(idx - (1 << level));
+        } else {
+            stack.push(idx * 2 + 1);
+            stack.push(idx * 2);
+        }
+    }
+    Ok(leaf_ids)
 }
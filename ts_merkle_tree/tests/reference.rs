@@ -0,0 +1,392 @@
+use proptest::prelude::*;
+use std::collections::HashMap;
+use ts_merkle_tree::{
+    diff_leaf_ids, verify_merkle_prf, verify_multi_prf, verify_non_membership_prf,
+    verify_update_prf, MerkleTree,
+};
+
+const LEVEL: usize = 6;
+
+// not a cryptographic hash, only one where the order of the children matters
+fn mix(left: u64, right: u64) -> u64 {
+    (left ^ 0x9e37_79b9_7f4a_7c15)
+        .wrapping_mul(0xbf58_476d_1ce4_e5b9)
+        .rotate_left(29)
+        ^ right.wrapping_mul(0x94d0_49bb_1331_11eb)
+}
+
+struct SparseTree {
+    nodes: HashMap<usize, u64>,
+    actual_level: usize,
+    default_proof: Vec<u64>,
+    deferred: bool,
+    dirty_leaf_ids: Vec<usize>,
+}
+impl SparseTree {
+    fn new(deferred: bool) -> Self {
+        let mut default_proof = vec![0];
+        for i in 1..LEVEL {
+            default_proof.push(mix(default_proof[i - 1], default_proof[i - 1]));
+        }
+        Self {
+            nodes: HashMap::new(),
+            actual_level: 0,
+            default_proof,
+            deferred,
+            dirty_leaf_ids: vec![],
+        }
+    }
+}
+impl MerkleTree for SparseTree {
+    type Node = u64;
+    fn hash(left: Self::Node, right: Self::Node) -> Result<Self::Node, String> {
+        Ok(mix(left, right))
+    }
+    fn idx_at(&self, idx: usize) -> Result<Option<Self::Node>, String> {
+        Ok(self.nodes.get(&idx).copied())
+    }
+    fn idx_set(&mut self, idx: usize, node: Option<Self::Node>) -> Result<(), String> {
+        match node {
+            Some(node) => self.nodes.insert(idx, node),
+            None => self.nodes.remove(&idx),
+        };
+        Ok(())
+    }
+    fn get_actual_level(&self) -> Result<usize, String> {
+        Ok(self.actual_level)
+    }
+    fn set_actual_level(&mut self, level: usize) -> Result<(), String> {
+        self.actual_level = level;
+        Ok(())
+    }
+    fn get_default_proof(&self, idx: usize) -> Result<Self::Node, String> {
+        self.default_proof
+            .get(idx)
+            .copied()
+            .ok_or(format!("default proof index out of range: {}", idx))
+    }
+    fn get_level(&self) -> Result<usize, String> {
+        Ok(LEVEL)
+    }
+    fn is_deferred(&self) -> Result<bool, String> {
+        Ok(self.deferred)
+    }
+    fn get_dirty_leaf_ids(&self) -> Result<&[usize], String> {
+        Ok(&self.dirty_leaf_ids)
+    }
+    fn push_dirty_leaf_id(&mut self, leaf_id: usize) -> Result<(), String> {
+        self.dirty_leaf_ids.push(leaf_id);
+        Ok(())
+    }
+    fn take_dirty_leaf_ids(&mut self) -> Result<Vec<usize>, String> {
+        Ok(std::mem::take(&mut self.dirty_leaf_ids))
+    }
+}
+
+// every leaf is stored and every level is hashed in full
+struct DenseTree {
+    leaves: Vec<u64>,
+}
+impl DenseTree {
+    fn new() -> Self {
+        Self {
+            leaves: vec![0; 1 << LEVEL],
+        }
+    }
+    fn levels(&self) -> Vec<Vec<u64>> {
+        let mut levels = vec![self.leaves.clone()];
+        while levels.last().unwrap().len() > 1 {
+            let below = levels.last().unwrap();
+            levels.push(below.chunks(2).map(|pair| mix(pair[0], pair[1])).collect());
+        }
+        levels
+    }
+    fn root(&self) -> u64 {
+        self.levels()[LEVEL][0]
+    }
+    fn proof(&self, leaf_id: usize) -> Vec<u64> {
+        let levels = self.levels();
+        (0..LEVEL).map(|i| levels[i][(leaf_id >> i) ^ 1]).collect()
+    }
+}
+
+fn updates() -> impl Strategy<Value = Vec<(usize, u64)>> {
+    prop::collection::vec((0..1usize << LEVEL, any::<u64>()), 0..40)
+}
+
+// updates that often stay in the first leaves, so the tree does not grow to its full height,
+// and often reset a leaf to the default node
+fn sparse_updates() -> impl Strategy<Value = Vec<(usize, u64)>> {
+    prop::collection::vec(
+        (
+            prop_oneof![0..4usize, 0..1usize << LEVEL],
+            prop_oneof![Just(0u64), any::<u64>()],
+        ),
+        0..20,
+    )
+}
+
+proptest! {
+    #[test]
+    fn update_leaf_node_matches_dense_tree(updates in updates()) {
+        let mut tree = SparseTree::new(false);
+        let mut dense = DenseTree::new();
+        prop_assert_eq!(tree.get_root().unwrap(), dense.root());
+        for (leaf_id, node) in updates {
+            let ori_root = dense.root();
+            let ori_leaf_node = dense.leaves[leaf_id];
+            dense.leaves[leaf_id] = node;
+            let prf = tree.update_leaf_node(leaf_id, node).unwrap();
+            prop_assert_eq!(prf.ori_root, ori_root);
+            prop_assert_eq!(prf.new_root, dense.root());
+            prop_assert_eq!(prf.ori_leaf_node, ori_leaf_node);
+            prop_assert_eq!(prf.new_leaf_node, node);
+            prop_assert_eq!(prf.proof, dense.proof(leaf_id));
+            prop_assert_eq!(tree.get_root().unwrap(), dense.root());
+        }
+    }
+
+    #[test]
+    fn verify_leaf_node_matches_dense_tree(updates in updates()) {
+        let mut tree = SparseTree::new(false);
+        let mut dense = DenseTree::new();
+        for (leaf_id, node) in updates {
+            tree.update_leaf_node(leaf_id, node).unwrap();
+            dense.leaves[leaf_id] = node;
+        }
+        for leaf_id in 0..1 << LEVEL {
+            let prf = tree.verify_leaf_node(leaf_id).unwrap();
+            prop_assert_eq!(prf.ori_root, dense.root());
+            prop_assert_eq!(prf.ori_leaf_node, dense.leaves[leaf_id]);
+            prop_assert_eq!(prf.proof, dense.proof(leaf_id));
+        }
+    }
+
+    // a proof verifies, and no longer does once its leaf, a sibling, its leaf id or a root is changed
+    #[test]
+    fn tampered_proofs_are_rejected(
+        updates in updates(),
+        leaf_id in 0..1usize << LEVEL,
+        node in any::<u64>(),
+        i in 0..LEVEL,
+        delta in 1..u64::MAX,
+    ) {
+        let hash = |left, right| Ok(mix(left, right));
+        let mut tree = SparseTree::new(false);
+        let mut dense = DenseTree::new();
+        for (leaf_id, node) in updates {
+            tree.update_leaf_node(leaf_id, node).unwrap();
+            dense.leaves[leaf_id] = node;
+        }
+        // moving the leaf to the other side of the i-th sibling only changes the root if they differ
+        let swaps_differ = |dense: &DenseTree| {
+            dense.levels()[i][leaf_id >> i] != dense.proof(leaf_id)[i]
+        };
+
+        let prf = tree.verify_leaf_node(leaf_id).unwrap();
+        prop_assert!(verify_merkle_prf(hash, &prf).is_ok());
+        let mut tampered = prf.clone();
+        tampered.ori_leaf_node = tampered.ori_leaf_node.wrapping_add(delta);
+        tampered.new_leaf_node = tampered.ori_leaf_node;
+        prop_assert!(verify_merkle_prf(hash, &tampered).is_err());
+        let mut tampered = prf.clone();
+        tampered.proof[i] = tampered.proof[i].wrapping_add(delta);
+        prop_assert!(verify_merkle_prf(hash, &tampered).is_err());
+        if swaps_differ(&dense) {
+            let mut tampered = prf.clone();
+            tampered.leaf_id ^= 1 << i;
+            prop_assert!(verify_merkle_prf(hash, &tampered).is_err());
+        }
+        let mut tampered = prf.clone();
+        tampered.ori_root = tampered.ori_root.wrapping_add(delta);
+        tampered.new_root = tampered.ori_root;
+        prop_assert!(verify_merkle_prf(hash, &tampered).is_err());
+        let mut tampered = prf.clone();
+        tampered.new_leaf_node = tampered.new_leaf_node.wrapping_add(delta);
+        prop_assert!(verify_merkle_prf(hash, &tampered).is_err());
+
+        let ori_dense_swaps_differ = swaps_differ(&dense);
+        dense.leaves[leaf_id] = node;
+        let prf = tree.update_leaf_node(leaf_id, node).unwrap();
+        prop_assert!(verify_update_prf(hash, &prf).is_ok());
+        let mut tampered = prf.clone();
+        tampered.ori_leaf_node = tampered.ori_leaf_node.wrapping_add(delta);
+        prop_assert!(verify_update_prf(hash, &tampered).is_err());
+        let mut tampered = prf.clone();
+        tampered.new_leaf_node = tampered.new_leaf_node.wrapping_add(delta);
+        prop_assert!(verify_update_prf(hash, &tampered).is_err());
+        let mut tampered = prf.clone();
+        tampered.proof[i] = tampered.proof[i].wrapping_add(delta);
+        prop_assert!(verify_update_prf(hash, &tampered).is_err());
+        if ori_dense_swaps_differ || swaps_differ(&dense) {
+            let mut tampered = prf.clone();
+            tampered.leaf_id ^= 1 << i;
+            prop_assert!(verify_update_prf(hash, &tampered).is_err());
+        }
+        let mut tampered = prf.clone();
+        tampered.ori_root = tampered.ori_root.wrapping_add(delta);
+        prop_assert!(verify_update_prf(hash, &tampered).is_err());
+        let mut tampered = prf.clone();
+        tampered.new_root = tampered.new_root.wrapping_add(delta);
+        prop_assert!(verify_update_prf(hash, &tampered).is_err());
+    }
+
+    #[test]
+    fn multi_proofs_match_dense_tree(
+        updates in updates(),
+        leaf_ids in prop::collection::btree_set(0..1usize << LEVEL, 1..8),
+    ) {
+        let mut tree = SparseTree::new(false);
+        let mut dense = DenseTree::new();
+        for (leaf_id, node) in updates {
+            tree.update_leaf_node(leaf_id, node).unwrap();
+            dense.leaves[leaf_id] = node;
+        }
+        let prf = tree.verify_leaf_nodes(&leaf_ids.into_iter().collect::<Vec<_>>()).unwrap();
+        prop_assert_eq!(prf.root, dense.root());
+        for (leaf_id, leaf_node) in prf.leaf_ids.iter().zip(prf.leaf_nodes.iter()) {
+            prop_assert_eq!(*leaf_node, dense.leaves[*leaf_id]);
+        }
+        prop_assert!(verify_multi_prf(|left, right| Ok(mix(left, right)), &prf).is_ok());
+    }
+
+    #[test]
+    fn tampered_multi_proofs_are_rejected(
+        updates in updates(),
+        leaf_ids in prop::collection::btree_set(0..1usize << LEVEL, 2..8),
+        delta in 1..u64::MAX,
+    ) {
+        let hash = |left, right| Ok(mix(left, right));
+        let mut tree = SparseTree::new(false);
+        for (leaf_id, node) in updates {
+            tree.update_leaf_node(leaf_id, node).unwrap();
+        }
+        let prf = tree.verify_leaf_nodes(&leaf_ids.into_iter().collect::<Vec<_>>()).unwrap();
+        prop_assert!(verify_multi_prf(hash, &prf).is_ok());
+        let mut tampered = prf.clone();
+        tampered.root = tampered.root.wrapping_add(delta);
+        prop_assert!(verify_multi_prf(hash, &tampered).is_err());
+        let mut tampered = prf.clone();
+        tampered.leaf_nodes[0] = tampered.leaf_nodes[0].wrapping_add(delta);
+        prop_assert!(verify_multi_prf(hash, &tampered).is_err());
+        // a few leaves out of the whole tree always need siblings
+        let mut tampered = prf.clone();
+        tampered.proof.pop();
+        prop_assert!(verify_multi_prf(hash, &tampered).is_err());
+        let mut tampered = prf.clone();
+        tampered.proof.push(delta);
+        prop_assert!(verify_multi_prf(hash, &tampered).is_err());
+        let mut tampered = prf.clone();
+        tampered.leaf_ids.swap(0, 1);
+        tampered.leaf_nodes.swap(0, 1);
+        prop_assert!(verify_multi_prf(hash, &tampered).is_err());
+        let mut tampered = prf.clone();
+        tampered.leaf_ids.insert(0, prf.leaf_ids[0]);
+        tampered.leaf_nodes.insert(0, prf.leaf_nodes[0]);
+        prop_assert!(verify_multi_prf(hash, &tampered).is_err());
+    }
+
+    #[test]
+    fn leaf_ids_are_the_non_default_leaves(updates in sparse_updates()) {
+        let mut tree = SparseTree::new(false);
+        let mut dense = DenseTree::new();
+        for (leaf_id, node) in updates {
+            tree.update_leaf_node(leaf_id, node).unwrap();
+            dense.leaves[leaf_id] = node;
+        }
+        let expected: Vec<usize> = (0..1 << LEVEL)
+            .filter(|leaf_id| dense.leaves[*leaf_id] != 0)
+            .collect();
+        let leaf_ids: Result<Vec<usize>, String> = tree.leaf_ids().collect();
+        prop_assert_eq!(leaf_ids.unwrap(), expected);
+    }
+
+    // every leaf id is checked, those beyond the grown part of the tree too
+    #[test]
+    fn non_membership_proofs_match_dense_tree(updates in sparse_updates()) {
+        let hash = |left, right| Ok(mix(left, right));
+        let mut tree = SparseTree::new(false);
+        let mut dense = DenseTree::new();
+        for (leaf_id, node) in updates {
+            tree.update_leaf_node(leaf_id, node).unwrap();
+            dense.leaves[leaf_id] = node;
+        }
+        for leaf_id in 0..1 << LEVEL {
+            let is_empty = dense.leaves[leaf_id] == 0;
+            let prf = tree.verify_leaf_node(leaf_id).unwrap();
+            prop_assert_eq!(prf.ori_root, dense.root());
+            prop_assert_eq!(verify_non_membership_prf(hash, 0, &prf).is_ok(), is_empty);
+            prop_assert_eq!(tree.verify_empty_leaf_node(leaf_id).is_ok(), is_empty);
+            // a non-empty leaf cannot be passed off as the default one
+            let mut forged = prf.clone();
+            forged.ori_leaf_node = 0;
+            forged.new_leaf_node = 0;
+            prop_assert_eq!(verify_non_membership_prf(hash, 0, &forged).is_ok(), is_empty);
+        }
+    }
+
+    #[test]
+    fn diff_matches_dense_trees(updates_a in updates(), updates_b in updates()) {
+        let mut trees = vec![];
+        for updates in [updates_a, updates_b] {
+            let mut tree = SparseTree::new(false);
+            let mut dense = DenseTree::new();
+            for (leaf_id, node) in updates {
+                tree.update_leaf_node(leaf_id, node).unwrap();
+                dense.leaves[leaf_id] = node;
+            }
+            trees.push((tree, dense));
+        }
+        let expected: Vec<usize> = (0..1 << LEVEL)
+            .filter(|leaf_id| trees[0].1.leaves[*leaf_id] != trees[1].1.leaves[*leaf_id])
+            .collect();
+        prop_assert_eq!(diff_leaf_ids(&trees[0].0, &trees[1].0).unwrap(), expected);
+        prop_assert!(diff_leaf_ids(&trees[0].0, &trees[0].0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn deferred_updates_match_dense_tree(updates in updates()) {
+        let mut tree = SparseTree::new(true);
+        let mut dense = DenseTree::new();
+        for (leaf_id, node) in updates {
+            tree.update_leaf_node_deferred(leaf_id, node).unwrap();
+            dense.leaves[leaf_id] = node;
+        }
+        tree.commit().unwrap();
+        prop_assert_eq!(tree.get_root().unwrap(), dense.root());
+    }
+
+    // blocks of updates committed once each give the roots and proofs of updates applied one by one
+    #[test]
+    fn deferred_updates_match_eager_updates(blocks in prop::collection::vec(updates(), 1..4)) {
+        let mut eager = SparseTree::new(false);
+        let mut deferred = SparseTree::new(true);
+        for block in blocks {
+            for (leaf_id, node) in block {
+                eager.update_leaf_node(leaf_id, node).unwrap();
+                deferred.update_leaf_node_deferred(leaf_id, node).unwrap();
+            }
+            prop_assert_eq!(deferred.get_root().is_err(), !deferred.get_dirty_leaf_ids().unwrap().is_empty());
+            deferred.commit().unwrap();
+            prop_assert!(deferred.get_dirty_leaf_ids().unwrap().is_empty());
+            prop_assert_eq!(deferred.get_root().unwrap(), eager.get_root().unwrap());
+            prop_assert_eq!(deferred.get_actual_level().unwrap(), eager.get_actual_level().unwrap());
+        }
+        for leaf_id in 0..1 << LEVEL {
+            prop_assert_eq!(
+                deferred.verify_leaf_node(leaf_id).unwrap().proof,
+                eager.verify_leaf_node(leaf_id).unwrap().proof
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range_leaf_ids_are_errors(leaf_id in (1usize << LEVEL)..usize::MAX, node in any::<u64>()) {
+        let mut tree = SparseTree::new(false);
+        prop_assert!(tree.update_leaf_node(leaf_id, node).is_err());
+        prop_assert!(tree.verify_leaf_node(leaf_id).is_err());
+        let mut tree = SparseTree::new(true);
+        prop_assert!(tree.update_leaf_node_deferred(leaf_id, node).is_err());
+        prop_assert!(tree.lv_order_at(0).is_err());
+    }
+}
@@ -0,0 +1,30 @@
+use ark_bn254::Fr;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ts_poseidon::{poseidon, poseidon_generic};
+
+fn bench_width<const INPUT_LEN_PLUS_ONE: usize>(c: &mut Criterion) {
+    let input: Vec<Fr> = (1..INPUT_LEN_PLUS_ONE as u64).map(Fr::from).collect();
+    assert_eq!(
+        poseidon::<INPUT_LEN_PLUS_ONE>(&input),
+        poseidon_generic::<INPUT_LEN_PLUS_ONE>(&input),
+        "the fast path must produce the same hash as the generic rounds"
+    );
+
+    let mut group = c.benchmark_group(format!("poseidon_{}", INPUT_LEN_PLUS_ONE));
+    group.bench_function("fast", |b| {
+        b.iter(|| poseidon::<INPUT_LEN_PLUS_ONE>(black_box(&input)))
+    });
+    group.bench_function("generic", |b| {
+        b.iter(|| poseidon_generic::<INPUT_LEN_PLUS_ONE>(black_box(&input)))
+    });
+    group.finish();
+}
+
+fn bench_poseidon(c: &mut Criterion) {
+    bench_width::<3>(c);
+    bench_width::<4>(c);
+    bench_width::<5>(c);
+}
+
+criterion_group!(benches, bench_poseidon);
+criterion_main!(benches);
@@ -0,0 +1,270 @@
+// generates the constants of circomlib's optimized Poseidon for the widths 2 to 17:
+// the round constants and MDS matrices come from the Grain LFSR of the Poseidon paper
+// (generate_parameters_grain.sage 1 0 254 t 8 R_P p), then the constants of the partial rounds
+// are folded and their matrices factorized into sparse ones, as circomlib's poseidon_constants_opt.
+// the elements are written in Montgomery form to constants.bin, with the statics reading them in constants.rs
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, Field, PrimeField, Zero};
+use std::fmt::Write;
+
+const N_ROUNDS_F: usize = 8;
+const N_ROUNDS_PS: [usize; 16] = [
+    56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68,
+];
+
+struct Grain {
+    bits: Vec<bool>,
+}
+impl Grain {
+    fn new(t: usize, n_rounds_p: usize) -> Self {
+        let mut bits = vec![];
+        // field: prime (1), s-box: x^alpha (0), field size, width and numbers of rounds
+        for (value, len) in [
+            (1, 2),
+            (0, 4),
+            (254, 12),
+            (t, 12),
+            (N_ROUNDS_F, 10),
+            (n_rounds_p, 10),
+        ] {
+            for i in (0..len).rev() {
+                bits.push(value >> i & 1 == 1);
+            }
+        }
+        bits.extend([true; 30]);
+        let mut grain = Self { bits };
+        for _ in 0..160 {
+            grain.step();
+        }
+        grain
+    }
+    fn step(&mut self) -> bool {
+        let b = &self.bits;
+        let new_bit = b[62] ^ b[51] ^ b[38] ^ b[23] ^ b[13] ^ b[0];
+        self.bits.remove(0);
+        self.bits.push(new_bit);
+        new_bit
+    }
+    // the output is filtered: a 1 keeps the next bit, a 0 discards it
+    fn next_bit(&mut self) -> bool {
+        loop {
+            if self.step() {
+                return self.step();
+            }
+            self.step();
+        }
+    }
+    fn next_bigint(&mut self) -> <Fr as PrimeField>::BigInt {
+        let bits: Vec<bool> = (0..254).map(|_| self.next_bit()).collect();
+        <Fr as PrimeField>::BigInt::from_bits_be(&bits)
+    }
+    fn next_field_element(&mut self) -> Fr {
+        loop {
+            let bigint = self.next_bigint();
+            if bigint < Fr::MODULUS {
+                return Fr::from_bigint(bigint).unwrap();
+            }
+        }
+    }
+    fn next_field_element_mod(&mut self) -> Fr {
+        Fr::from_le_bytes_mod_order(&self.next_bigint().to_bytes_le())
+    }
+}
+
+type Matrix = Vec<Vec<Fr>>;
+
+fn transpose(m: &Matrix) -> Matrix {
+    (0..m[0].len())
+        .map(|j| m.iter().map(|row| row[j]).collect())
+        .collect()
+}
+fn mul(a: &Matrix, b: &Matrix) -> Matrix {
+    a.iter()
+        .map(|row| {
+            (0..b[0].len())
+                .map(|j| row.iter().zip(b).map(|(x, b_row)| *x * b_row[j]).sum())
+                .collect()
+        })
+        .collect()
+}
+fn row_mul(v: &[Fr], m: &Matrix) -> Vec<Fr> {
+    mul(&vec![v.to_vec()], m).remove(0)
+}
+fn inverse(m: &Matrix) -> Matrix {
+    let n = m.len();
+    let mut a: Matrix = m
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut row = row.clone();
+            row.extend((0..n).map(|j| Fr::from((i == j) as u64)));
+            row
+        })
+        .collect();
+    for col in 0..n {
+        let pivot = (col..n)
+            .find(|row| !a[*row][col].is_zero())
+            .expect("singular matrix");
+        a.swap(col, pivot);
+        let inv = a[col][col].inverse().unwrap();
+        a[col].iter_mut().for_each(|x| *x *= inv);
+        for row in 0..n {
+            if row != col && !a[row][col].is_zero() {
+                let factor = a[row][col];
+                let pivot_row = a[col].clone();
+                a[row]
+                    .iter_mut()
+                    .zip(pivot_row)
+                    .for_each(|(x, y)| *x -= factor * y);
+            }
+        }
+    }
+    a.into_iter().map(|row| row[n..].to_vec()).collect()
+}
+
+struct Constants {
+    c: Vec<Fr>,
+    m: Matrix,
+    p: Matrix,
+    s: Vec<Fr>,
+}
+
+fn generate(t: usize) -> Constants {
+    let n_rounds_p = N_ROUNDS_PS[t - 2];
+    let n_rounds = N_ROUNDS_F + n_rounds_p;
+    let half = N_ROUNDS_F / 2;
+    let mut grain = Grain::new(t, n_rounds_p);
+    let round_constants: Vec<Vec<Fr>> = (0..n_rounds)
+        .map(|_| (0..t).map(|_| grain.next_field_element()).collect())
+        .collect();
+    let xs: Vec<Fr> = (0..2 * t).map(|_| grain.next_field_element_mod()).collect();
+    let mds: Matrix = (0..t)
+        .map(|i| {
+            (0..t)
+                .map(|j| (xs[i] + xs[t + j]).inverse().unwrap())
+                .collect()
+        })
+        .collect();
+    // circomlib mixes with y[i] = sum(m[j][i] * x[j]), its matrices are transposed
+    let m = transpose(&mds);
+    let m_inv = inverse(&m);
+
+    // the constants of each partial round but the first one are moved back through the previous mix,
+    // only their first element, which goes through the s-box, stays in that round
+    let mut c = round_constants;
+    for i in (half..half + n_rounds_p).rev() {
+        let moved = row_mul(&c[i + 1], &m_inv);
+        for j in 1..t {
+            c[i][j] += moved[j];
+        }
+        c[i + 1] = vec![moved[0]];
+    }
+
+    // the mix of the partial rounds is factorized into a dense matrix applied once before them,
+    // and for each round a sparse one given by its first row and first column
+    let mut m_mul = m.clone();
+    let mut sparse = vec![];
+    for _ in 0..n_rounds_p {
+        let m_hat: Matrix = m_mul[1..].iter().map(|row| row[1..].to_vec()).collect();
+        let w: Matrix = m_mul[1..].iter().map(|row| vec![row[0]]).collect();
+        let v = m_mul[0][1..].to_vec();
+        let w_hat: Vec<Fr> = mul(&inverse(&m_hat), &w)
+            .into_iter()
+            .map(|row| row[0])
+            .collect();
+        sparse.push((w_hat, v));
+        let m_i: Matrix = (0..t)
+            .map(|i| {
+                (0..t)
+                    .map(|j| match (i, j) {
+                        (0, 0) => Fr::from(1u64),
+                        (0, _) | (_, 0) => Fr::from(0u64),
+                        _ => m_hat[i - 1][j - 1],
+                    })
+                    .collect()
+            })
+            .collect();
+        m_mul = mul(&m, &m_i);
+    }
+    let p = m_mul;
+
+    // the constants added after an s-box and before a mix are premultiplied by the inverse of the mix
+    let mut flat = c[0].clone();
+    for round in c.iter().take(half + 1).skip(1) {
+        flat.extend(row_mul(round, &m_inv));
+    }
+    for round in c.iter().skip(half + 1).take(n_rounds_p) {
+        flat.push(round[0]);
+    }
+    for round in c.iter().skip(half + n_rounds_p + 1) {
+        flat.extend(row_mul(round, &m_inv));
+    }
+    let mut s = vec![];
+    for (w_hat, v) in sparse.iter().rev() {
+        s.push(mds[0][0]);
+        s.extend(w_hat);
+        s.extend(v);
+    }
+    Constants { c: flat, m, p, s }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    let mut blob = vec![];
+    let mut push = |elems: &[Fr]| {
+        let start = blob.len() / 32;
+        for elem in elems {
+            for limb in elem.0 .0 {
+                blob.extend(limb.to_le_bytes());
+            }
+        }
+        start
+    };
+    let (mut c, mut m, mut p, mut s) = (String::new(), String::new(), String::new(), String::new());
+    for t in 2..=17 {
+        let constants = generate(t);
+        let start = push(&constants.c);
+        writeln!(
+            c,
+            "static C{t}: [Fr; {}] = load({start});",
+            constants.c.len()
+        )
+        .unwrap();
+        let start = push(&constants.s);
+        writeln!(
+            s,
+            "static S{t}: [Fr; {}] = load({start});",
+            constants.s.len()
+        )
+        .unwrap();
+        for (name, matrix, out) in [("M", &constants.m, &mut m), ("P", &constants.p, &mut p)] {
+            let mut rows = vec![];
+            for (i, row) in matrix.iter().enumerate() {
+                let start = push(row);
+                writeln!(out, "static {name}{t}_{i}: [Fr; {t}] = load({start});").unwrap();
+                rows.push(format!("&{name}{t}_{i}"));
+            }
+            writeln!(
+                out,
+                "static {name}{t}: [&[Fr]; {t}] = [{}];",
+                rows.join(", ")
+            )
+            .unwrap();
+        }
+    }
+    let tables = |name: &str, elem: &str| {
+        let widths: Vec<String> = (2..=17).map(|t| format!("&{name}{t}")).collect();
+        format!("pub static {name}: [{elem}; 16] = [{}];", widths.join(", "))
+    };
+    let glue = format!(
+        "pub mod c {{\n    use super::*;\n{c}{}\n}}\npub mod m {{\n    use super::*;\n{m}{}\n}}\n\
+         pub mod p {{\n    use super::*;\n{p}{}\n}}\npub mod s {{\n    use super::*;\n{s}{}\n}}\n",
+        tables("C", "&[Fr]"),
+        tables("M", "&[&[Fr]]"),
+        tables("P", "&[&[Fr]]"),
+        tables("S", "&[Fr]"),
+    );
+    std::fs::write(out_dir.join("constants.bin"), blob).unwrap();
+    std::fs::write(out_dir.join("constants.rs"), glue).unwrap();
+}
@@ -1,19929 +0,0 @@
-use std::marker::PhantomData;
-
-use ark_bn254::{Fr, FrConfig};
-use ark_ff::{BigInt, Fp, MontBackend};
-
-const C2: [Fr; 72] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12208208821504100967,
-            8352786714756315675,
-            13931969323734133734,
-            169165012173077633,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11069586816774575342,
-            4040526433733796225,
-            17421914956921628469,
-            2101459233408232249,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12766053330654783918,
-            16643839629377730151,
-            4012665962279950708,
-            2086130275545629270,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3586557175677148003,
-            10872314720934014902,
-            9754473654558481042,
-            1865023305627933910,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14413583321636337406,
-            7095181836983148034,
-            15001642643801876291,
-            1997491806272931643,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6154314502183953735,
-            17683196639434117526,
-            10947843084748012015,
-            2411419451542533881,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18414594751487074470,
-            10316386320149317861,
-            4488263702677423005,
-            684806996355821575,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2234801819131824512,
-            17207848346161607387,
-            1511140588101635294,
-            1713871916469305506,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8289253811895532697,
-            11120428254070618917,
-            6670487778405927298,
-            1354143864870207567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4328670421244506275,
-            9350800335122047289,
-            9701921232174959710,
-            2250545351039125297,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16428793343421336798,
-            15236417049800286405,
-            17904667110805967116,
-            802493901717841939,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1761558419076508672,
-            4757094154726828996,
-            418442826439203393,
-            3107867305267404916,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6262607331491469714,
-            4700347158737284159,
-            13017577231821608996,
-            237587729484555330,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4871698294754502770,
-            15841789406562735779,
-            16949480552189092328,
-            1118026333522167528,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8102645972899888282,
-            14373075884951126863,
-            8869561264152752400,
-            24224480603560787,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8684438983685302515,
-            1782283389035384922,
-            16124225981537516212,
-            1912334942714850293,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16805186942981098420,
-            2194487662956150793,
-            13183494615973739759,
-            160545703437353441,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15143353708283306013,
-            12854860426862242284,
-            3076196797198229197,
-            2082315239265328681,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2080649324033414802,
-            10777560809478459569,
-            8438101781377727444,
-            2797190598356738705,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13822527793666007790,
-            7090209303354682812,
-            3120667244265242151,
-            451632782891118800,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9595001005295256724,
-            4659090381251588400,
-            15397147079548631301,
-            2248397299189655721,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16281987559701898720,
-            12747057220871654530,
-            3744341218563647487,
-            912048380579074856,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11321490168788657484,
-            7522268364079140250,
-            4159411502099695792,
-            3114004282641270944,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9123660912227912108,
-            14354453660620997559,
-            10916376191361122180,
-            2921762990744413878,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1170008115820378873,
-            17677440366128188833,
-            5697710174959202836,
-            444855665121891120,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5873548347495067149,
-            18339724658455402483,
-            15568987937919786595,
-            958285504359505853,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17936217071116294047,
-            1742855532064182977,
-            11634452171429081499,
-            663711211354886963,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16959244447509705186,
-            15785111639017693866,
-            11951770305262105877,
-            1369444795523666574,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10513403112219730335,
-            18169598687187283028,
-            8762804827343601154,
-            3363991994964145937,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8961330631780537423,
-            10410813559130625294,
-            12107154523245478289,
-            716890937454556082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            274327582811675024,
-            17123374511144127020,
-            13306660552748072097,
-            1962660516714857504,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            488208969634654926,
-            2949411539120355126,
-            11272986495875608659,
-            2027477501910143443,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11248935418426539004,
-            13945555823175316496,
-            18141034603054617830,
-            3073544306514865301,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17973294301246878982,
-            12655704478189066235,
-            16070186504911764824,
-            1344462161091270810,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7869670497565922388,
-            14943702408899516691,
-            17796686304302864221,
-            2957747369010733424,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8127387569228704527,
-            10926488831329407996,
-            11029519894403388933,
-            267137628154113376,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6788871961834302129,
-            10967880005555788349,
-            792839015950338324,
-            1824899847119639046,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7221578734870102317,
-            11618754723968096654,
-            8071863078250919731,
-            785062089819149998,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9423860775633903298,
-            17371181746095646713,
-            6370141527905375116,
-            135078490756252294,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11925419238376309137,
-            3470709748240737671,
-            3325231779283484295,
-            3264957309813684985,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8576149378960896375,
-            18347075319556276360,
-            17942363274805413678,
-            1108208892037007827,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13174153183919862810,
-            4151916953188884114,
-            17127248808580608926,
-            748116038765003246,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14045412623109942053,
-            16284153227101806099,
-            8328621817835157486,
-            667116862942553145,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3087169435918328222,
-            16892309340220881731,
-            226188842016211881,
-            2737018684733115199,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5997950996172944975,
-            5946738362077038468,
-            62488095989717741,
-            925451353016610845,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17725487822682505140,
-            12792483017001240083,
-            4188205197074696797,
-            1208536479970712440,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3324724902328108041,
-            15425948040505746178,
-            14400671436290595870,
-            1462477983036897482,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6685713592897655115,
-            1271590864800581936,
-            2881863525412932492,
-            2983182800142279927,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3419732375086340320,
-            7416500960763716669,
-            10920842721657206948,
-            756981144948604484,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10810660359527910491,
-            15593074510722228877,
-            2855390816011171455,
-            2967926130333920398,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17902507623555135759,
-            6793704743206090791,
-            3114049452440290227,
-            738127478421147528,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1453796050685662183,
-            15630824697249706497,
-            14346311468927459475,
-            239446468872134126,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13344675868605218326,
-            16094816476164389904,
-            8222497334502512575,
-            1899435037231596510,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1413994985972888745,
-            1397512774119230891,
-            17036951850413210534,
-            2137757082105588702,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11043727859523051066,
-            17864926483911093335,
-            1130472930397353989,
-            2795243000109897840,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16185681221810749523,
-            7510307467243852025,
-            9753216551836794112,
-            1001387367880402037,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            665478482165876976,
-            14934143212888003961,
-            10213622089484812151,
-            105824138997444532,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8210679477696516024,
-            8056493284361459908,
-            377970183003015988,
-            2263094587906135730,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5153831861317178807,
-            9296647523054930272,
-            5540861027059681002,
-            1213416530530124085,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8165093019417808113,
-            13097599419787561067,
-            7097367031753555406,
-            2699230676889981802,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3670179589033936584,
-            2193949913826116805,
-            16599040054991804021,
-            2032505007222575607,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6130504608434465102,
-            8397160090210762132,
-            10555002173205611223,
-            1572145486437759371,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11834783482347663991,
-            6367172399872564761,
-            17982768695891024666,
-            3444079221778820024,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1802671662012639296,
-            16199005879681076085,
-            6461725285269389685,
-            2361019091432215638,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4725513312119149004,
-            1735383155590575333,
-            2368993179451396815,
-            2390943410921618981,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1001415959169101540,
-            13200966806511614137,
-            15933149297555667100,
-            1553979416896242419,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14658277211163004781,
-            17541004062970050303,
-            12848289942217578474,
-            1547894436314275601,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12872837508179936287,
-            8061857517086798361,
-            10621835802045883805,
-            1849751626650545599,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            650007220322277700,
-            10088381251576513794,
-            10535539944491277771,
-            2229362322590030687,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1141045076550901132,
-            13131153927032820629,
-            15761133438527607784,
-            2907061461133833642,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10834425341195866026,
-            11122994973558955501,
-            9087798639316717459,
-            692634131822708955,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4810721918253380614,
-            3012193515994546452,
-            9133215826260323133,
-            1664802788176312209,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C3: [Fr; 81] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9492385987992791128,
-            680432315601848839,
-            2855872623209058572,
-            1601239780691047490,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6785711768592540442,
-            6246566044944926558,
-            125043130933818449,
-            1711334188322332030,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11889214640683519369,
-            12676343762103092894,
-            15630842739907632185,
-            1747563154250758156,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13506027956329300467,
-            15331083346134167976,
-            11613889283946828733,
-            1247050861621736340,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6442988432475529691,
-            15202244007452922466,
-            8787739328604199378,
-            1490821887038336200,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6943356232144313212,
-            1427074057338393871,
-            13944159090723529251,
-            2257800230943507356,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6600026491391297492,
-            3188301552115198424,
-            12741819202621425149,
-            74747750791547745,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2611446023448483696,
-            11333315420533924245,
-            10337113331971735699,
-            250024772955876343,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8808417520926418298,
-            4413598916229908469,
-            15648125179671430924,
-            2487440544278834173,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2750340975874954618,
-            4433845518952389360,
-            13532624336410666905,
-            1555776158287069557,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15942790038310559370,
-            8669190371851152889,
-            6433258443839470636,
-            267719819123716780,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13840942538990415663,
-            8901278454789865149,
-            7872762305511320284,
-            2955396005300404914,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7053901515597605562,
-            2271498200996908407,
-            15000145701145268037,
-            2212572997940132842,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11974609149456622713,
-            655897895624345755,
-            12859988879839059216,
-            620879680933991330,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13608080401037694534,
-            5340120708081449144,
-            4302594803263205712,
-            891120073914244929,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6769296120431061057,
-            11034010129666572126,
-            9206252108936199585,
-            1820306798641245889,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11543950538428294216,
-            11502098338258604457,
-            16708693458881631297,
-            2208939173907866007,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            921867890307527771,
-            10506159457999721468,
-            6835619483716492855,
-            579828482976078876,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9921690879423737909,
-            163666215351633849,
-            3613241278685048668,
-            1240667930133917387,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13974696534898282358,
-            17318512095524262426,
-            4625514675745696825,
-            994585764971971808,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14848714340610051949,
-            4414113675900673397,
-            2005214120542695316,
-            1246343576637327906,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2905464273488127269,
-            2390067034961618931,
-            10386198558205960819,
-            2534676614711584307,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            610616071285097039,
-            8299723724241615842,
-            5527557628084616648,
-            1524250182065926155,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12176167341950881467,
-            2244060289928932484,
-            1771818518110565158,
-            1635071189379319905,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14994004011673647151,
-            7443120581062397980,
-            11673508968663069831,
-            3095255876353662404,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11291957266918928477,
-            15912229884286587591,
-            7086348247166771864,
-            1776336993228650657,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3965713700532412887,
-            7002441295600695168,
-            17643110418491760032,
-            2799353090378427095,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2114402989038810339,
-            8892516681607279854,
-            13055454336872876112,
-            217005202101803875,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5610801504455137016,
-            8304286707549454946,
-            13050992117822782841,
-            5497750686485388,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4337676085250611684,
-            13956499661109674885,
-            17507142931871700412,
-            2510258608490129992,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2328615485185744089,
-            9006438765595615709,
-            15562722982339759982,
-            2676892069473926222,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12608283408120294796,
-            12650206308118125043,
-            3201134665702741260,
-            1181544231110483858,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16491376654016766340,
-            632823049977711990,
-            9795865893695253523,
-            2831143956753268919,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8251200567439494862,
-            2289170045441229648,
-            9972662640960434545,
-            2570777688296186767,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3684829533645487003,
-            13318204440403844862,
-            17196621827919684909,
-            1367946618920982899,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13958975289103326856,
-            3606843498950014798,
-            7988678602146501554,
-            2396231015711283576,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3120148394124052823,
-            14247155658705515332,
-            15127529323965515849,
-            2256802107677475413,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8249435902880556479,
-            18162358266621224597,
-            12031901597475275928,
-            739146259690597946,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14082305619806694411,
-            63289737400377907,
-            593789116798260651,
-            2097628430968736355,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11631415120536933650,
-            2759276009125151079,
-            13128576332523719122,
-            1411664645902159628,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16380720204384911594,
-            13454872019561993593,
-            16881740600888769291,
-            271118930493962013,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17602221825384128796,
-            5062287544195781406,
-            1302950651532675193,
-            924864769831062446,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13914857475579963318,
-            15056177476902908020,
-            5063334439861428689,
-            1815630909634864670,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5611651197794371876,
-            6214761339967386187,
-            4689770337719836550,
-            1354642462717437643,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            504220946233756895,
-            1773953290675022952,
-            2009433478138153195,
-            7224804751859126,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3304829113416123851,
-            13188250572312313520,
-            1248164290661202262,
-            2258300980125205324,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9951204240419538925,
-            13259090460964877083,
-            8248745627891804916,
-            126950284125270375,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2879945410669035950,
-            8288666566524821710,
-            17042416133594398537,
-            1856223614358571405,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14399247201254845806,
-            8543341728128823159,
-            8216557508667648351,
-            2647658667583245795,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17126963627470917901,
-            12629358951751213149,
-            6123614374338895750,
-            2506008492023756636,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9255176378792486767,
-            2017961095388881346,
-            9152834144932085456,
-            553430730502782592,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6379521576417826364,
-            9407957425335554407,
-            3237255810462544291,
-            2978244674014353228,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13864378021946364181,
-            1873806658749497644,
-            939078391141061175,
-            294602613244500660,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17284200412741348197,
-            9534480070836397362,
-            9479885196144083293,
-            3219254249251640376,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18277466068632375321,
-            16304971130965245294,
-            7080036773828579687,
-            1474087847596814293,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13572609815254379855,
-            4387301577106900641,
-            7480627542151720630,
-            374613497903999174,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15903435790888097617,
-            2981588593930423752,
-            8087720382414995895,
-            2870573731468321569,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10836351713664606080,
-            13973007301688817023,
-            10936961300918173112,
-            661722034595128824,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1874714756417772459,
-            12917829764079534972,
-            12225973095887150766,
-            2372946563892191871,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11167992354004667271,
-            8433651087263475217,
-            115814098232866865,
-            2736350663626714104,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14653561491838401289,
-            4423257641721419653,
-            11935934027587545056,
-            3443638288608585858,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14949128926026615945,
-            18039927068889295793,
-            2987745143980739098,
-            2773959087372027527,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1377170198421337161,
-            14877129212942900168,
-            8089863191506256172,
-            2060268149680350867,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15560817636343866833,
-            13208634895889674533,
-            17523971602888532280,
-            1202383130263953045,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4968221273259993947,
-            18346812606199631772,
-            11728832904658874440,
-            624629248346937600,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4397184223950168530,
-            6664084202553077633,
-            7324117844508193317,
-            148184901055015592,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8324437806248182491,
-            3355319497409862012,
-            10571562275568643346,
-            1974051954639143287,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13388204882878927013,
-            209118106315153934,
-            10278860475346442455,
-            3311840827639461766,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7131348497375860553,
-            16525815548176463509,
-            16771741948514674365,
-            1673374239352371682,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5815494156823350935,
-            13529715659913792488,
-            16505085945181960156,
-            3315095112774854167,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15300781618102339215,
-            16524107545886352236,
-            15882890245856321751,
-            9527889056114043,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18026131427976070597,
-            15771592187054708167,
-            9619697752179820432,
-            3412754369699496728,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4700945918944377263,
-            5342416886015193248,
-            17195285205795078213,
-            1923248467812623363,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            156957574584734297,
-            3069657944983465617,
-            5898886159247710262,
-            3485543786391995672,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3506166593857940276,
-            10626913205375617272,
-            6352845225416465995,
-            799028128009849979,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1524524397500007663,
-            6134696769977837079,
-            6962373043912634442,
-            1538817541171093507,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12756233417353711222,
-            9845297974915560514,
-            11165857326185746606,
-            2187189991325818473,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18102456052216928517,
-            378909019158676033,
-            7335914298267453273,
-            291488801377795541,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5403582179284843246,
-            8510610079525684390,
-            6438735726629478572,
-            637403071997158173,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17119589778491351782,
-            11447937118360973529,
-            15499356810338911896,
-            2686178563828189713,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8620418667391728518,
-            16618059174106923643,
-            2625230872609891796,
-            1632790802203991076,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C4: [Fr; 88] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4675466866566341926,
-            8698088188063495740,
-            10423897452055043101,
-            2502043820367835167,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15970049863210008936,
-            14618335249704749279,
-            14646544523058636394,
-            1351614311336215653,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16352076952239603906,
-            14382136972075372730,
-            12787003642804505031,
-            1547800992895504952,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10180112135564254806,
-            2653683156474060987,
-            2586519615287325201,
-            910593268905864060,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10113196007420375184,
-            8980559018729651579,
-            10714556409377481072,
-            2242697184846815576,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11566907834683053689,
-            4085981393292982713,
-            7568348763900035067,
-            2128446640669347817,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10799714066083573848,
-            2111398519204950495,
-            14547076614661173892,
-            328081250566091006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12893923898858212876,
-            1177904110114567066,
-            5665878133609448253,
-            2988319756967463330,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15057200283971154828,
-            15891833513656205042,
-            13720521067236005102,
-            1134228196451931399,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15335969832897328921,
-            10357023459165514263,
-            4897183680833807607,
-            2085834841110569183,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17286002592982486335,
-            6716470467931476289,
-            15358083161432777745,
-            2173626767742586517,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4426601383377479335,
-            45248594275662934,
-            3172587139635504343,
-            1083273249638834009,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4978555712389798109,
-            936699683399967554,
-            6794579702702906562,
-            3337805505737650441,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4027782675085317834,
-            4544392956553339261,
-            3817053260037726030,
-            2191578892677100737,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5987252586185855909,
-            8945112411788135652,
-            9542342140557283085,
-            961008785196434930,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15267024591002325304,
-            5020756376878077104,
-            9663359389405448425,
-            54123278946183915,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5049776823574024183,
-            18310016962143082968,
-            7891201822054344856,
-            442447157906988799,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12750903828073622682,
-            7676129822403322792,
-            13574722756389524901,
-            1323740845197425871,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15243429617060043016,
-            11846394512793906144,
-            7568676489977883761,
-            464081599160928317,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17923868725921947424,
-            3488110643815658125,
-            13803217970556810673,
-            1599310840872097622,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            424326196888195565,
-            15285398429995735644,
-            17336684395293712779,
-            323591051627657099,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17497470078400019057,
-            709524128609346652,
-            10899702379559982443,
-            2426487603053991049,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17769299080398953777,
-            7765410253127611107,
-            10213668878700104170,
-            1239689057880174639,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11756356421929024347,
-            10292412875156467278,
-            13927505214870367022,
-            2886646744020692593,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15547751046961111918,
-            2490031848595465896,
-            10563523125871397733,
-            2384049469920839594,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7994781722407053366,
-            5612763233064491662,
-            10443171639015283580,
-            227399182075855127,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14448361716011258725,
-            2699925451991024297,
-            11563819336424821905,
-            3388190505074113483,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15432029795380933783,
-            4959235194385997918,
-            18388871317344358856,
-            3375425622218140936,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16131638428248812864,
-            18202920749949301283,
-            7516878244345503443,
-            636317206083922895,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9870812760652424340,
-            9064778729415010811,
-            14911400023609856396,
-            453506090041081644,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14232024925384743770,
-            5070358371053440467,
-            6334604511216250571,
-            2388679093825733504,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8617172867039385619,
-            1158610975309071385,
-            10249677333744038154,
-            2595994877688439476,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16630663906395884065,
-            4659730245973731537,
-            10099583864380939074,
-            2369361392847689791,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9131566470510949115,
-            5498447360965196931,
-            7753797406882318595,
-            2341904985925502396,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1828598793578519389,
-            17414352550412548256,
-            16462747320680034166,
-            1294599412498594373,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11808604377546138980,
-            5657070438194051028,
-            2969285012624492277,
-            1377715680211220118,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8633472487915637702,
-            7957069432431476705,
-            1424887374026469764,
-            258753955633814089,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13222924943969312515,
-            11423521121092776258,
-            5951742377564570495,
-            2006717358687331624,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2390737914423351970,
-            14011741240250668571,
-            17491754746138176862,
-            248931597167137384,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7172631949555405513,
-            13814502938617819404,
-            15700458112074986795,
-            2094528813873145894,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9791906829792136654,
-            7040374370255077193,
-            2792675658374034650,
-            1881338506295524039,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6411344464099310129,
-            9163888272472147816,
-            6590507628728738389,
-            1517550500401137197,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4569200757815344271,
-            16983651834096568675,
-            7166500147855647295,
-            3247482557037656348,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3970812528224067535,
-            15099920029157644890,
-            3200525007041439445,
-            1384233021966856877,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6142372745591325457,
-            12354753496533110901,
-            12775336295840216307,
-            56638132917895495,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7472176960433453860,
-            784425089206913598,
-            11150031060492448235,
-            1739223978921165784,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5281168208022496670,
-            1591227657090598448,
-            15672355918235766295,
-            899867268652316779,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5370840576118759068,
-            9790004384894601052,
-            6520001674118342066,
-            1642176404125508187,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12364543509327604117,
-            16236543311233318486,
-            17896766596622631510,
-            174614932135155908,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1544274241577107494,
-            16315327287913035515,
-            10231733334212258326,
-            3218155015226653282,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            812055945902313345,
-            2154619617192857971,
-            1376005025936869935,
-            1376569493499933247,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10131545185968746500,
-            16718438506852751912,
-            729997919043171476,
-            902718933580603316,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4189153635707462049,
-            17393979137414719457,
-            12965160664208130233,
-            710942514556604184,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13828214816315198798,
-            13548052588855087439,
-            2362641293795055450,
-            454836937674174304,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16400084386479352461,
-            5578837255756896640,
-            16509884923886326569,
-            801389038285432378,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            66238506757035503,
-            14777886366550308946,
-            17747949279991505943,
-            2640586512647735691,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3819797708214776021,
-            14761455349877307104,
-            4277294950906455673,
-            2915438493827629624,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18066622606344453847,
-            1926144033997934550,
-            3441384111986382926,
-            1395733014599705091,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5314315168545672255,
-            12338537465356683319,
-            11314868909673434385,
-            918766560126336529,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2573409445685402899,
-            376419360033396022,
-            12999641892624767226,
-            2641899485703656140,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13182909775647895261,
-            4832339922475981099,
-            14033593715297901260,
-            399158740200699231,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4467756966332023893,
-            18324858481881302976,
-            6071851237704157214,
-            2017054341653522889,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10218729784111553925,
-            3083061969054832778,
-            8627112953727763026,
-            2352415130394531305,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6671671234149826231,
-            17488606314412111728,
-            8635205905974812754,
-            963754810787343209,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4808071155100687437,
-            17871450871193518385,
-            14861943343246593713,
-            776730581339604011,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11124112621961298312,
-            14527320664671485795,
-            7871276703103474211,
-            1775384557347710375,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17258106156700143498,
-            2695737193365341649,
-            4736677955745322048,
-            1010425623961355479,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10645762173190960028,
-            1930859162879480692,
-            10951308745670373808,
-            789707649946545642,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6424357576883637579,
-            14873008354476825150,
-            5282116551999363068,
-            1838103375849329168,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11177733919560044400,
-            3621264317589099247,
-            10050273620898803879,
-            2365645673643841509,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10475576827417582782,
-            3580255738886843696,
-            12303987222629627012,
-            2895525698481841585,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            65923335647489215,
-            10699343801321569732,
-            15656716551789752481,
-            246605208513807059,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7896567457929300369,
-            14246826729904359016,
-            6118800737474664500,
-            1303195573636291263,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10058220146864138260,
-            8269588964388040340,
-            18320613920444148752,
-            1612751287401934067,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10550564094774527971,
-            14363702057622587561,
-            14947838774929550084,
-            997084006955445423,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7001497113545645083,
-            5134215122010499067,
-            2429864842611882702,
-            197251644376648988,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3161804961126894712,
-            11374632645711403510,
-            7704479287801189080,
-            1022672266697700288,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17693961770745888139,
-            9619467438259596598,
-            1385607481178629836,
-            2842379914168459260,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            699077178268837253,
-            11540368878155573307,
-            14318865123698225846,
-            2868410718029167275,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17672097630561466957,
-            2700298088987838540,
-            6528372873328968468,
-            2139133080919067133,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11707711793347919809,
-            3325505011551430193,
-            13190653943172517311,
-            2180210769360670309,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17994273878895949502,
-            695367483632064290,
-            4634035566995516288,
-            2285230734450476499,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            948878121286441904,
-            12933556725030859838,
-            16710334342320079408,
-            1308273468308377841,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13648854641695325522,
-            5770771860608398540,
-            12037269522995579142,
-            1994300471315114477,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8512120006591770730,
-            12750337124069338718,
-            7048869938151442444,
-            927294701644773029,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3775539831460626410,
-            12001789860904438673,
-            7386001711633191874,
-            2991166401623919782,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2399044237123297963,
-            16025389768126201481,
-            12999682159140946065,
-            1679928103656696425,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6198081871704449344,
-            2175026444526288186,
-            8727665708955732354,
-            1907898425838617324,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C5: [Fr; 100] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9766783020970580196,
-            5062578843211540303,
-            17469806143909327044,
-            619466276259455793,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15483030801440226748,
-            7091654316604608295,
-            8189264523146366142,
-            1390997227385649831,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14001010660849757324,
-            11310216157784436223,
-            1323926446991923697,
-            402309630710447480,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14053193385316348717,
-            6564119810107864016,
-            12729951405035517725,
-            383156293311992929,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9561988481685942256,
-            6471728879109999948,
-            14657523534955018101,
-            1549879836438390786,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15333533597948564754,
-            13870486306044912085,
-            8735037633840213202,
-            3103887915785710657,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4519274763769251149,
-            1792155126514112483,
-            10969671509620578501,
-            1487555819212568313,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3563211135490778908,
-            10087022302865036290,
-            9315653494035434012,
-            105723849330769835,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10853278946410272641,
-            7901201179127389394,
-            17399550418980969270,
-            2658944614115012380,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10862227374807046833,
-            5032284629057403311,
-            5869165954981132161,
-            1692151379863651020,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8533353023705986851,
-            14076696897264742765,
-            2074976499605269178,
-            983524178802743405,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5824444808905739490,
-            9904511175509162614,
-            1967099959681518226,
-            2795660913088914643,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18196517162650352336,
-            7297143732286003478,
-            4481443933835650446,
-            948447589217781083,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5804827968684128381,
-            10761286419444024570,
-            1516600722932302482,
-            2045376255573379547,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            719529683451334282,
-            16363092211357693391,
-            4410420130355699732,
-            1827140833333225441,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13632112507066889159,
-            3121921192713146059,
-            5769730888716360883,
-            1608888418324943154,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8607840229415265778,
-            1518615384290980374,
-            1644387807005733867,
-            559240781953721353,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5522498377579518062,
-            8789478773359922598,
-            6668135062130069277,
-            3110314049922120082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16639916093066144397,
-            2804326717724257340,
-            12042255885120355440,
-            872614443037644116,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4772075581989706778,
-            10186869960648563771,
-            3441263404924658696,
-            1994542110757224590,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6516094706520965031,
-            15354603807554750215,
-            1729799562217626662,
-            706295679975688245,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            987247562498157510,
-            8965402514286290667,
-            12361460139603115279,
-            514474522985105508,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18002983029042155830,
-            16749331290537121660,
-            6374624403505114258,
-            2552894185694529164,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1842521632616909342,
-            17418855709047567465,
-            8813555946207176193,
-            323164055789604250,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8634057746890831646,
-            2034815045178525769,
-            17374401654379068855,
-            2615633101915956472,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6123910321700461134,
-            2878321750240541851,
-            3407068314322795542,
-            1999226050290135792,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6833048047018830998,
-            5403861023040195560,
-            14006582377166956961,
-            439882507512090580,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            178224354451358312,
-            5043557669073777846,
-            16958880042995716450,
-            2320977430149860497,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4423306579395239103,
-            5980888949963988096,
-            16966963664310026110,
-            1870516628629125316,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10010103897133467545,
-            11107822424290646474,
-            7926318868548840988,
-            2708117913165467908,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14653489667892591744,
-            13131408379036025871,
-            158937366900448190,
-            459284552224722371,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13243488961152076554,
-            10037169984966988952,
-            9881788285703036213,
-            1708587465226488090,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2445186930872676531,
-            4027801156424413450,
-            13409765213311562224,
-            647495334549944650,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2634828309662293520,
-            3721462760480482742,
-            16499275603505245969,
-            2905421814356887649,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12717526562641951835,
-            16724880878508108784,
-            3060585740948083557,
-            3477535489156256314,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10673112274831272713,
-            3884391937637432303,
-            14907576200632078620,
-            54157577094665858,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7421675442288695018,
-            14661693795912843228,
-            8006879591484952409,
-            2012285272793336799,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8133242105697164531,
-            10887951735119285668,
-            345371930421634979,
-            1164950341461559633,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18113948933405686710,
-            12719652987594926315,
-            16983409012774888413,
-            1348332670815148562,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4162996686030168311,
-            4084326251677126924,
-            14555640243919702156,
-            1233077305849493701,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7877197060430417182,
-            17608902948062427440,
-            9493207602678577005,
-            3429667068085255349,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11087115516250691839,
-            9008900262916659727,
-            7288270219847054821,
-            3351142040233854909,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1882434332380653321,
-            13802090848310049309,
-            8134350082802564540,
-            529148936949919228,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12591426187624592486,
-            10771160300325894543,
-            16896342918394818449,
-            2796035496721546966,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4851341553010133969,
-            6916026084748599923,
-            2526536256497982674,
-            951419245640339506,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9764863921479931174,
-            11881216365879392432,
-            3050437998104087097,
-            3297899507882391179,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            36465193579166472,
-            16052302593021803,
-            15148714755931100246,
-            2696087732708021159,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1503062068407016929,
-            6763114818456169346,
-            1686549902412123460,
-            1120417601560445775,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9724154863572940876,
-            15983464116919745410,
-            17693398336590942801,
-            1069296084645469552,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15326486553038653150,
-            6236150399223152784,
-            15800188557491984846,
-            1864573611130871223,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12633393876538264578,
-            5946684936753819811,
-            5571111042866626818,
-            1204139246986662798,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14409905157114491981,
-            16192784258208885086,
-            17974290808559877125,
-            2443915637184312454,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16944758089787133797,
-            16905273837397745441,
-            17818184651380542210,
-            2813166295484104203,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            146958091804133757,
-            7565118266497375883,
-            6356878117805408688,
-            508689976240173497,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11932155460308424474,
-            6133614574693372072,
-            1107695872568872171,
-            3423709696850143476,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14390920556188404053,
-            17206253015476634722,
-            17751919205243628665,
-            2148041763248355184,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12752615144952713033,
-            5211583448067424977,
-            12914057247323704372,
-            751129743339042260,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1308313567688288420,
-            7303496080944299897,
-            2779222684623660904,
-            2243863833121314181,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15214177336328699546,
-            14208537930043810640,
-            6642852869310236261,
-            3398282605489892089,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            507278331766046572,
-            18282466629349869636,
-            10819877873184296554,
-            2429376513482042864,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11066289324318683518,
-            11223397081699116515,
-            1018246686450982338,
-            856732779779236919,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5023719848943273364,
-            12873209201811400400,
-            18008735537984131673,
-            736173219458060775,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8081314042146577476,
-            2655578829413398220,
-            7450871333843599559,
-            1134691596973378753,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10197007103437606761,
-            65165250643821318,
-            13347522257532230532,
-            1924850485540787044,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5134442572317958121,
-            4856723671256407591,
-            13808588427975078799,
-            1737844737887381572,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12897538845649669302,
-            547669151679185034,
-            5734155924446624968,
-            1759319649896204894,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11993203148555968629,
-            11664160158091748315,
-            103321924893867003,
-            2413764373692513716,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9516143166715619479,
-            2506449044510266733,
-            17711645544402629004,
-            351011365402768357,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6750033170231989340,
-            14067903397129257431,
-            4214775419927551151,
-            1998528493194972207,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            281530499909686779,
-            11405840820964463986,
-            8855787934196878072,
-            2722848987801352959,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7012752004561410974,
-            18221660959517242100,
-            2942691346919775295,
-            3178218624108743561,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9869627583268851344,
-            1887319500668598510,
-            4696514433352307509,
-            3141547007652591568,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8376990821796472769,
-            14264003421835629156,
-            18118710850355836435,
-            2872241031268351091,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2042187970237022043,
-            1502135672363637177,
-            18438422119741696367,
-            891315531723892130,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12620934042475711463,
-            12144544189903252661,
-            5255340919467040944,
-            1712580195470276208,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1539067531549567855,
-            11924025913461541451,
-            6749468146632013521,
-            190457678305326118,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1322817929103705382,
-            8214157977286176236,
-            12321153710523311563,
-            1841750248850318464,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            409509588394090932,
-            17648723276265212569,
-            2959897013984023854,
-            1779336991831675789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6852343258121298072,
-            11577194781523171371,
-            14774396788049600020,
-            3021807389221056367,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8063427738689269491,
-            10015579695170528224,
-            11515012802802828489,
-            425028066817068526,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12128696045560176078,
-            545520442069613189,
-            6205546304474588086,
-            968420895815224150,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13285411658610860374,
-            75746444992145861,
-            16432554557385137714,
-            2675899687183109337,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8740331308396340112,
-            14786303146293818145,
-            11375160080344462566,
-            1269899673151778874,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3496538531052513567,
-            11642094808038593135,
-            2453783104119900109,
-            163100674894284962,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13540523000472230872,
-            3368195210264678925,
-            14850502960161350079,
-            991230494234794774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7144530933647699571,
-            8858403388316637224,
-            14078434639304341954,
-            11303773163394927,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9822522907537446822,
-            1083367324436643546,
-            4372987572739399756,
-            2897502318690887775,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4133899308660948620,
-            7301090435928214467,
-            4845026733187027126,
-            2648639711748969593,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17959128924346181072,
-            16474366619770326167,
-            1582623573879326655,
-            1111062067302481206,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14673226232748806728,
-            16446196274182065363,
-            249405482261596101,
-            1434788153389048491,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15195811414096702331,
-            14221572756738646860,
-            14285383972055201982,
-            1353419285878037348,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            782177492934477189,
-            7675817981835733469,
-            15720315072541297314,
-            130256524112841098,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2002534465895545812,
-            11989291066272767316,
-            12333000770945158128,
-            371108200539817476,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            952067859788331951,
-            4523444588799876984,
-            17934994071954513567,
-            792491971530690034,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12751545083727311000,
-            11199535119219958639,
-            760032908040712962,
-            3456379476899748852,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3108365730633195777,
-            1987210510175901260,
-            16194454253270091969,
-            214092300573647609,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17815572515301066354,
-            952684015151333998,
-            3523181349422517840,
-            2122192718769203634,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12651662136739389496,
-            5822451487628288167,
-            14948679705272675842,
-            3280349582736556450,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13398236399427102428,
-            14102763963843226581,
-            11625428694488336912,
-            1126701836745770997,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15599865819904864023,
-            1343827185834066014,
-            1683906180769626737,
-            341443821285123534,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C6: [Fr; 108] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4136121326596288272,
-            7477741677087793109,
-            4180429253245751888,
-            974319611754123799,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13565302252692332460,
-            8807070238532936264,
-            13047762826405960409,
-            1223712377431904374,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7862521222039882976,
-            10181099298447837411,
-            11821742319051996466,
-            3297063981834099689,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1776725562516874039,
-            12720088003543467295,
-            11298963478001327407,
-            3339826109276616849,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            629411262895419351,
-            3612788917907312826,
-            12321520613937861212,
-            2158977807175977596,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8820035604051965560,
-            5176756870847161452,
-            15685999430158155927,
-            1631454621363558580,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6300735033958227459,
-            13275906516129068549,
-            13636226512517434434,
-            3458286981255975760,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18336575278024783768,
-            12035440239557285947,
-            15087705324571484867,
-            265071015262110574,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14344909142320831349,
-            13111754640972436531,
-            1408038626271593040,
-            1486187508400604342,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5560261334312409585,
-            16746965846770074857,
-            13204335875285881639,
-            28541139602924995,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1908060855873146769,
-            11981033964439003241,
-            14937536275041312160,
-            2725225152268198855,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17876964203333105506,
-            8504333694482881953,
-            1334731806650189626,
-            1403305460372753182,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14408217058050962321,
-            9389636897439975228,
-            17361866966188704224,
-            3329190462739219933,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9458846362991330495,
-            7872169383842055892,
-            9717204888460432861,
-            222811146878371461,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6788668173618623731,
-            10642928191027312862,
-            152760331359772687,
-            1499399811938744472,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5984791759323212881,
-            15862918261138234151,
-            6875991187627374351,
-            2645305947886879000,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12536791252202159122,
-            5786455638522720064,
-            11213907514923297317,
-            1047134365189740265,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3445971500179918411,
-            8499514091196893699,
-            2532433503171489915,
-            363644031174455690,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10737003264148319444,
-            16719082331986245390,
-            13898113794931192838,
-            1120245518986137307,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10792678433856556434,
-            11400386731240454636,
-            15272806278475988812,
-            2022959007256288258,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7569235350853112917,
-            17621877683859882342,
-            12004160064332697796,
-            559758258547823366,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            734185851403619774,
-            11666203576973868738,
-            11145512935904231800,
-            214426954686625078,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7573162439116495884,
-            2033993802284068655,
-            12343352573257684712,
-            469085330694438412,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            793712623310750282,
-            473140204007997216,
-            9769318403749774619,
-            3152344643245425803,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1095826851671736751,
-            8237111279371235575,
-            6659378424256855579,
-            1453356015748860526,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11229760917312803725,
-            18179763620926501546,
-            3866941997529246250,
-            1044512395830253447,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10110397016586740474,
-            629637892568875031,
-            17329828023628623210,
-            3342139782751428844,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1414300357174250734,
-            13956433740161505409,
-            6815912252109862800,
-            1581706721069789950,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2793789718822443296,
-            18184538368079657941,
-            3573633932961652691,
-            1830811030650528803,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8477177395382742177,
-            17575559215276789854,
-            5990473020708655143,
-            2785779082635530236,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7611565116730620393,
-            3591495932041195600,
-            6138941770043091167,
-            1891729465213329815,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6897584477554813576,
-            2668698376776687690,
-            10827400403206396184,
-            2076108997602188051,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11804545000143578515,
-            15915167811675690832,
-            16794813929520125423,
-            2821438382843551199,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            507898666747800094,
-            8448604121143416519,
-            3682351727563595408,
-            1782513734302867428,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5223638590143435165,
-            11903096251272276995,
-            6982296445657211788,
-            1540735648481115621,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2248173188320524116,
-            7534634480152277138,
-            4439393106236000282,
-            712398347655302598,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7186722626954990509,
-            13428822316214623292,
-            3539886001718218570,
-            661378798093815644,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1861578520976382728,
-            1628315199185467321,
-            17502628424457571001,
-            270748711852966688,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            690471204936622405,
-            11768733789744708800,
-            14490258451080928012,
-            1143806722777658219,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3230603575544194471,
-            17929416895613089262,
-            10106749476600898675,
-            2685125125125093940,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11261960556451833607,
-            6993227017204613752,
-            5369768747865908119,
-            1575808972094545773,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5516870175544177592,
-            7453597484730566600,
-            14223911296119663991,
-            1301272958698131127,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1825576494814200549,
-            9371740986957306186,
-            8228787921246696980,
-            311961051913247669,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9267198718158071042,
-            13120782387080181706,
-            4827492548220051526,
-            1540348074505869438,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11600598443855881041,
-            14459603229454183087,
-            8810794538223635576,
-            1241117094258180482,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17445883676428698268,
-            6400699923861806615,
-            6864022280494395086,
-            2801640517482242065,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2154643875888759072,
-            3940596230870639389,
-            13298534293225116174,
-            1947749037266173611,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5111060241027574630,
-            4224702177346726979,
-            9680735139700237039,
-            1922948633797601518,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8482753783294901677,
-            4307541061376612729,
-            17783701468351568259,
-            1206051692641237577,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15037979929531400286,
-            4448460616591123581,
-            299547671551709319,
-            716056338780933115,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4466481707406766492,
-            8448576692377598634,
-            16223974784287356064,
-            911537644923104033,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14154940650685226939,
-            2293752032090967111,
-            14284854732449211625,
-            592822662873140311,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14547711354359012304,
-            10512629959887965440,
-            5626588289606446136,
-            1020204522581845469,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7245315049640614761,
-            15541062382270459042,
-            14316569866268880529,
-            2231739867366979477,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7867746533745092087,
-            11899217907562660425,
-            11664669889778729191,
-            3398609407669765031,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9954360155833934062,
-            12912135163028294697,
-            7680142172727520965,
-            2343172453334712525,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13711300384318067619,
-            1243860733810165502,
-            9514152781443825503,
-            1409346681110480268,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            93687623060737734,
-            581885231948469730,
-            1199026875834666832,
-            1044867178173387635,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8291930873237615809,
-            3102496421247693265,
-            3681722317900331163,
-            2101241289294473496,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17730818876552935632,
-            7496118617757371142,
-            16458476735641587854,
-            1992735127556512692,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5591274967655052217,
-            11714549072042047397,
-            10158892102327225072,
-            797978743558161905,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12108987309154349849,
-            2951150666259422984,
-            13308328959557542099,
-            1096156340294258881,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3908532343011140891,
-            386919393389940209,
-            7595722483739198479,
-            3201439809422624908,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11911846562046005817,
-            3495296111798702175,
-            7175161959811853684,
-            828731746328042028,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16956683906501996111,
-            1746970513535262941,
-            664171877746823511,
-            2291759758997238414,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7029977550282427378,
-            8164793003158600196,
-            1585188778851603237,
-            280092721100439210,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13960153631409625607,
-            9691761029366646213,
-            5738434015514311371,
-            1646163830889852363,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            29295777503951786,
-            11506282777963372252,
-            3742648040815770084,
-            3399258612334152413,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4573319640559548986,
-            10275877903358748938,
-            4705876675792891070,
-            2754718730576048041,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            726310111155522692,
-            410472147731159039,
-            8657940859060302872,
-            1666564452823643437,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2645737927645825666,
-            2120317391464926959,
-            10539526248383166724,
-            2288869348745428865,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17173419761512123928,
-            10620933789061824312,
-            9144796262968926906,
-            506450258262428437,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8352431239512399188,
-            11341044235452049256,
-            10272561010340502325,
-            738351668083408631,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4067085228811191017,
-            7179378388247686322,
-            8936986566032687876,
-            273437824565446331,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9420121898790274920,
-            6860095758978462879,
-            7373596435102210455,
-            1526853889614709963,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15356201882274093162,
-            8120622726302774372,
-            1469638829522615386,
-            827418129308275494,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13442810586449863822,
-            7422961599281331981,
-            5271772376450742532,
-            2485140559444450009,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5451350921709529028,
-            9443658562798382960,
-            15339061306698262404,
-            1273636259146973723,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3498068700936164717,
-            14099731163483392068,
-            6042850110339223810,
-            302612404349879730,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17542709574397541429,
-            13323228147476240922,
-            1952828964757205662,
-            2722572223074693197,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8853676647151136501,
-            5592338507347354480,
-            11555850999260429369,
-            1983349919411454514,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15520231234115793146,
-            7210184029537275783,
-            12399601660509592536,
-            2368005896568197133,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15434471422740573831,
-            9260935400660409321,
-            17024086705687950695,
-            3310648084843412902,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12618454322832238405,
-            7302329577904361988,
-            7681736173241570605,
-            1247434628880160493,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11713472722900291678,
-            17732213836652423428,
-            18259669879591762283,
-            1782189127561835480,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10492440887361977452,
-            5518236687114825984,
-            4449501463378478548,
-            315255452597476118,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6955426566867943151,
-            11949739322179806403,
-            14714587237435741887,
-            2773255168202540501,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11990792385088144513,
-            10880771818888466753,
-            15536490384941081316,
-            451554883157179804,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14866486142544970980,
-            13940591572841540336,
-            6741514130365107514,
-            545630875663245379,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13232393895280713759,
-            5976650796555289339,
-            15400328458082442310,
-            906338074083155399,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1339749845233086930,
-            6211263245989988267,
-            11382124660041341247,
-            674713196770099804,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4344014090006129332,
-            610985981722891719,
-            10118986869582420721,
-            2682748264258643010,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10832271313364371700,
-            12657091580232899289,
-            5920064323423156882,
-            3147059733281689847,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6527775858739045090,
-            3935873251966884610,
-            15502270950804434759,
-            1375131475826120680,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12309217156552320771,
-            6350863281621791511,
-            8044317118760639685,
-            2553996636513689920,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10486803491010505414,
-            15987940228839516952,
-            16697694167429411714,
-            713053731678452125,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1868339054288260263,
-            9383877273476963975,
-            1453920718680162849,
-            834970394452319027,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3494167093323976838,
-            4590673153450871601,
-            9231057041063544679,
-            1502152528008432092,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18394728581020744016,
-            9583468639896272543,
-            17372151877092486510,
-            1620115446607001430,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16943986112910403781,
-            12911364501798284951,
-            11014467580795428733,
-            2364299343519950576,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14639759284191734358,
-            9157326608714531551,
-            4311808546196840505,
-            329056757307475687,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1951797079751585265,
-            11035269936978229141,
-            14582566978933370909,
-            3200509423019001128,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5513412423950626710,
-            11366954810041838454,
-            1339551868138220993,
-            3225742204567335214,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16165367552861792277,
-            6408021522132134884,
-            5869754307958086537,
-            2204508308571854898,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10821315423803107133,
-            4670240397345519594,
-            4947156676285247988,
-            1392451687905329434,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            52162701524679313,
-            12139981056548457188,
-            809581454716365082,
-            862429136613334448,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6139688041239628362,
-            13968569510206705333,
-            2706210029721171523,
-            3132005422304348615,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7543032442701922901,
-            1364157168911194653,
-            5718657229268279649,
-            864803771908236335,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C7: [Fr; 119] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2422842082066892290,
-            11129452256653609321,
-            1231658512798252939,
-            55157752915815565,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14807736788850543295,
-            17396176498963340617,
-            11518618938932941868,
-            1115139299732083529,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12291600532285894073,
-            6093890626109279071,
-            14950835735114400716,
-            1642030011128785454,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1240405783326414076,
-            17758051458371896550,
-            4059996409620736509,
-            2653439970382220829,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5318490474285819684,
-            18378615396112471831,
-            7171551292196767786,
-            1012254866965465809,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9561133659518310779,
-            5657385570596057235,
-            14085954870763006027,
-            57768305246529175,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10327176486007246481,
-            4750969070383160197,
-            6809267999670399374,
-            578961515100606804,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17128146120680200254,
-            6074511336349766305,
-            17599643367856645221,
-            1748689954241210713,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12430989753935601861,
-            2044035058629877197,
-            10105473270032750859,
-            581371340876099372,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10639927640600972962,
-            756696746491748824,
-            8692293701361864880,
-            2215224343865982927,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8162978363946484233,
-            13475008643830324505,
-            4490713697568935998,
-            1944168488799229138,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9621436795767093518,
-            15074366394049571977,
-            2679845786736406086,
-            542861096081806058,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6071705798319790530,
-            1104285410982387441,
-            1967577194334915856,
-            1702472135058004422,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6641696063934027312,
-            3700918936408003919,
-            103791604904399797,
-            1378965959743019834,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7653413001094765639,
-            16916588282219063040,
-            16450335496680291337,
-            2467049826323688170,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7572512420942600367,
-            4914032667246662100,
-            16341641624842287368,
-            2035569006264944398,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1227947791207040849,
-            4368339651688248480,
-            2368248879313115443,
-            1342568059212071958,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7689169649849419293,
-            18270888405782399065,
-            17188471971022083139,
-            3178125642954211012,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1081343786456491593,
-            6703555613876314447,
-            1110244581474143622,
-            1849631857502773516,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3210020718814027257,
-            13871415658215142645,
-            15314146245151396333,
-            890019320452799704,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9821816033262307471,
-            431728157814263772,
-            15141026314884020913,
-            3234050196714928147,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11810949108165799967,
-            17418484515180000777,
-            120820136876845856,
-            2835878078997667807,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6833791763661588826,
-            777872223893762667,
-            5102968741965259591,
-            1554018103794210067,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6297692841459180881,
-            11715257412338385503,
-            11592621818938099985,
-            1878784798902326583,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16973421606842947801,
-            14578198857534337567,
-            13766454904944181328,
-            3155207983317155640,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14548024799503416135,
-            8590050971423548053,
-            7255351139064240566,
-            2148438195153495058,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10674155638523416112,
-            9187399482265163673,
-            4008910686701461746,
-            1286536629788084109,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6038974581004375099,
-            12831182146774045240,
-            2215128580381259224,
-            797896824492440051,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9925583776991233549,
-            3462289569170188726,
-            414330320867547759,
-            2770224713879219681,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6846554189236077284,
-            12003964510226794669,
-            2417300789125803426,
-            3290165244841631415,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14116314648082854555,
-            10706623973967424176,
-            9748315897479807362,
-            925495963028767771,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8316145879400032684,
-            13082188034914532593,
-            18236974255702220904,
-            1987184884378573554,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16701708510188626280,
-            7251628882590498215,
-            7295152704474589988,
-            2796159748445523758,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18327235846776420178,
-            8707047742329893648,
-            1190420561845722265,
-            369933521902685216,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13499161910607853252,
-            7967792047193195467,
-            8339644265121006261,
-            873790946336298421,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9291587266098808876,
-            9642345817664352007,
-            396526701459875481,
-            2547135262192059037,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6666353141284615770,
-            8555944154370528976,
-            8341830625607999891,
-            3138334904353867577,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16762789180274807801,
-            2578761785677268505,
-            11537275439329830244,
-            1834423395966393597,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17385579674665814629,
-            11190064508817609430,
-            12422336837556681685,
-            1506220893905173639,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7034086848737403376,
-            12021216229411975748,
-            8764724207380343606,
-            3328456959113876174,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10353608068285622988,
-            12491360364644408192,
-            10628474923278916333,
-            827173206100584830,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            660920323662782376,
-            9046363871740051831,
-            14222307729284904198,
-            2963940443967367893,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1358019072208163164,
-            16336756284143301363,
-            9811014272904736622,
-            2082437218241625421,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10673394059357249147,
-            17688064592652321192,
-            15213358736173765657,
-            187750809592187695,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16050994373834574750,
-            472854237147874695,
-            1423221286600603017,
-            254738583718228880,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18428501599595003864,
-            6445235952273396072,
-            15595871815083689566,
-            2328656521975864932,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12181020612659610160,
-            9722101759630941428,
-            18211793757261605994,
-            772044423301761231,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6950215981018507948,
-            279808161809225253,
-            498587905477022807,
-            2110396387485426562,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7400091065807520932,
-            6991306423967502356,
-            14417153566047364427,
-            472499400723436500,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16816127701796947798,
-            12491152705455933988,
-            15366937982445033745,
-            1785329438708084199,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7305540131644440575,
-            12571111389134648205,
-            1950152394966614249,
-            3119894309650145078,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5166278601289835601,
-            17953183880083285252,
-            1428024768200991429,
-            3025820210679242879,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16447770158719302064,
-            13925046609229341417,
-            6490285782800046953,
-            1774186709968305694,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2315673643966966886,
-            12871774159223740952,
-            8148281155070684578,
-            155654435096461617,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2430598202612355429,
-            10777567765639985578,
-            2650276139105758466,
-            1300340100433210013,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11793155380464977315,
-            2942240434772105084,
-            14558901077010731350,
-            1481670129592169676,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11787468325022047454,
-            7846834089863950586,
-            13437026134885049883,
-            1164664580559202283,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1845345815813287165,
-            11745358532294057730,
-            13170461815338830374,
-            2431666998535699228,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12628702032450979582,
-            245067808863924130,
-            12401288182601215631,
-            2617210429437009549,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11201280776751963890,
-            16917996618646078071,
-            9184351189367170306,
-            2701158982243630620,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14811842461026984526,
-            15786231662356139741,
-            5602132892502980607,
-            960329149197840266,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6216133191866201573,
-            5357788277434648123,
-            12204225575846146479,
-            717693422002933466,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            383942051783308610,
-            1959458440154017339,
-            3359088745194821021,
-            2804949053410334983,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1365111766291239414,
-            4712059028943927790,
-            14544448568656406031,
-            3116312004272874464,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8501383844151705217,
-            2807101990630992981,
-            12175036726257558559,
-            1832248934205092502,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12301941668805244354,
-            5596686209501734703,
-            14579724802954009001,
-            1299667294148505331,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7846398803465525986,
-            6534297471582987218,
-            2824024193165403374,
-            2641969729222596774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13721886903661019689,
-            11958488011289712673,
-            12169623010132176625,
-            2336127765154702772,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14461082249580818424,
-            9530354336449163345,
-            8186831599301286714,
-            2013327332306218804,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5718025696266187998,
-            13587884344996519743,
-            1433463684286366310,
-            3213066812712717504,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14523457530692483409,
-            15173418531369385994,
-            10078590076430180809,
-            10486395605635331,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2355385820931361273,
-            6248677509538044401,
-            14530824633337463417,
-            3077343795261137407,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7530940300512642721,
-            15338189967729116513,
-            12862507828240225083,
-            1231172793427727827,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3684703672356368470,
-            11943415817471617601,
-            3238101262098911478,
-            3233739783561707180,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4012636570621415850,
-            7108549575510993281,
-            12672847368686399530,
-            871920220055932728,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1102900613436984849,
-            14150045829074461995,
-            1833362795300090278,
-            1967133322774211937,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9385488147150547284,
-            10254640146212670428,
-            8056536629790912457,
-            1230659438838686724,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16436950886231703734,
-            5444263174385607080,
-            14092784795563607810,
-            2147683576216730106,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5160470647978816692,
-            6674866498213887132,
-            13503629983720971284,
-            531454767842663190,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12500834204871384870,
-            6758865957865864357,
-            17514795423775288335,
-            700496667620211047,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7600998851553607382,
-            8677741389700440260,
-            2767693058971859969,
-            3007325534166505537,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10574672383630852902,
-            6605484900581587116,
-            13280035206114887107,
-            1701578408335317321,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11133163478751403094,
-            12888832117076188911,
-            3532086777194386402,
-            3029116055381288252,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13715498298379999467,
-            8205690188698391989,
-            12078788031604370203,
-            615573823739022131,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15436751295838038484,
-            14672109227499579064,
-            16480940798038685215,
-            1174001458917266022,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4521362101551200040,
-            2365725662640517445,
-            17873056348050478746,
-            698595891558620208,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8049293643718376178,
-            12975585549143468982,
-            8160263594370425528,
-            3080268604147184595,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15045263690241348641,
-            2582209563227962726,
-            913663358227478813,
-            1759549960687875364,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16012088288199120166,
-            11001439761758406627,
-            5134712196029869162,
-            490460790581103050,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9976048732292892765,
-            13819886535984570944,
-            10425555427759019164,
-            2968064269639476220,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8955694714631448467,
-            13204799446188505492,
-            5172148025661109033,
-            661450163520754263,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14005326903604274498,
-            925226052538081999,
-            17185245333739198639,
-            1698870194664437081,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6224619466311792767,
-            13960422918019614983,
-            7972114868703628759,
-            3009259385632521029,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14402450347184898007,
-            16565988670795395193,
-            11204071507940492242,
-            642253858283397724,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15814053952175605421,
-            1848698185290976414,
-            12805330841648688389,
-            2177946226291455291,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17735385720032578366,
-            14287545481526633855,
-            11558396810058167010,
-            2576987117421999069,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8789207155019902421,
-            17711787718847051161,
-            3172145751533496206,
-            2062439486833781697,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18257156131979932723,
-            10153580550740632440,
-            13304007518155384345,
-            1485007109578952870,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            469884913068914867,
-            4494070695399126160,
-            12200979672125607387,
-            3427373752523859804,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14976286816173409380,
-            6685416036728172517,
-            15881806926717623597,
-            2196203317859326176,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3029174270152529789,
-            15237244992583844696,
-            9730698045666093900,
-            2390308196233309292,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17062662038147591357,
-            9610476273674335993,
-            17568392812307074761,
-            1032292336539855789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18385107097987736159,
-            4713156081257893792,
-            1662947051113308160,
-            3017346375411162894,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10009213566617800562,
-            7774517231835122166,
-            12898832618800779714,
-            1366548375311191178,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3181907419210405793,
-            9165069640463975943,
-            8693784620929862088,
-            2713802280474278078,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17099026962860088837,
-            13382025345535443532,
-            5744613249465985875,
-            693736621673732763,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1602025307604565348,
-            7663235919023148367,
-            9280691645702218528,
-            1886935017151898927,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6945965424378792161,
-            345665750910690073,
-            5110884813815411631,
-            2005783491614753761,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6638911700306952184,
-            2180770580893385265,
-            11806950910406269737,
-            723017272313253659,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15859210998667836437,
-            18270827006651660737,
-            7071915145655070014,
-            1686880157100669581,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6733310399075573230,
-            3151728745878325862,
-            8391055283062855126,
-            302142654039236678,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7852463805327642212,
-            7227016040486001166,
-            3170305083648936433,
-            2049052174427424200,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11107732056703744354,
-            2202715284885786451,
-            1329611878287709565,
-            237766340689017653,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1090947552172820999,
-            3779561901281356355,
-            1986142891194017776,
-            2116756834452400734,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9456410854389745534,
-            8854268408997600275,
-            4076794827897356540,
-            2149006974602933648,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6225444103895364106,
-            11232801675690221911,
-            6544577056539406621,
-            1743547284588745457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11267336442745637721,
-            17950435029600898401,
-            9621231610792023167,
-            2389967878583211728,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15687641732386765268,
-            4352396784984068370,
-            10304093448805805616,
-            907680300085908416,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4384130995035032261,
-            17010046979405935114,
-            16644000553810529560,
-            529721510993613501,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C8: [Fr; 128] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13390729921617038900,
-            15108385685131297160,
-            12162790736524965480,
-            2615045645203195376,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4444480144545589188,
-            14200754348540853767,
-            10994718010267606475,
-            1713309788233488884,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11130743312017591914,
-            5385510268392821405,
-            13072004033386566221,
-            2423289725567992108,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1882319567213510150,
-            695930426649623502,
-            12872134206820618729,
-            206990379299070990,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17403375277281802696,
-            7928545813428495099,
-            17694760294483268843,
-            1986376573760023370,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14690432623879462236,
-            3095449192349791035,
-            12036949665615035235,
-            1614411224628606357,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16256641337710403172,
-            13282134059068794159,
-            15156581610646558289,
-            2608438918011501277,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17362533997434455061,
-            12706744647781317775,
-            10736017219657328406,
-            929633690151490862,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4196667270664417021,
-            7094145307095251181,
-            101557795937663990,
-            2513946899955155443,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4592121579742504033,
-            7998616868292172898,
-            936820512952673646,
-            1698321932484587919,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5101758912652632551,
-            17264732324315336833,
-            6955662072554466503,
-            1827535413486603168,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14222568836224529249,
-            2463820946267510922,
-            16194106180566228520,
-            3121639860605404435,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10107242432525345177,
-            13359598991392654639,
-            11430059898725644308,
-            1364241201490834420,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3268383102654147668,
-            13771033372253473220,
-            10196758158960068346,
-            2659566299131793742,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2067836611936855879,
-            7500202454007821095,
-            11075879256065073260,
-            3415216532396268255,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4056447848568121684,
-            10028824181599898557,
-            10402413039359474108,
-            2606762726440267725,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10945443841717796761,
-            4116545537115802701,
-            5880654094878257386,
-            694402609441566054,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8790208782641225838,
-            12356507439003811811,
-            239199899672450332,
-            1596449827842570789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12440743331141862426,
-            3001347880535469864,
-            15525063119666734206,
-            2065653613529432370,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5043263689605011573,
-            14903984533412463189,
-            13387619883115042000,
-            863038127296878748,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7905344696967267879,
-            860216696279353277,
-            4576433402145895236,
-            1670456071570081895,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2102481974799892693,
-            2342113663372406228,
-            5025263514692541829,
-            228386184378480423,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15725526070627149013,
-            1057360205854065786,
-            3829152123950394934,
-            933265039151543784,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3609022054436438808,
-            12375048590525896824,
-            4556338133149770318,
-            1872152475818522211,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5563944044528487221,
-            763607006266317265,
-            9032785559265197180,
-            615677397303958009,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            480048513411259069,
-            14804159231431214329,
-            9454528309998683977,
-            2176029210233645697,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3905803134264699916,
-            3980007693728515937,
-            14174438272819083228,
-            410837603776089003,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8938136903169768509,
-            4568047017506427259,
-            12930894520039181099,
-            822833255589035089,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3834556596297439706,
-            5746476752879690979,
-            11073703167341906479,
-            1395577510126423855,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11079208682295591840,
-            11058906126239972721,
-            6576828089859785827,
-            1435371668774463182,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5995016346424823379,
-            16602761183922774783,
-            15780724268584458650,
-            2269098724088993950,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7013522141806916122,
-            8033692279828221593,
-            5830969371274981013,
-            474697899012036711,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1803544625247917854,
-            6011747540865040946,
-            1361801276498129991,
-            2739222311922764139,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1523161309469583113,
-            12784874551931533396,
-            16692546945325817630,
-            1731736629269010769,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3648011157862700481,
-            15459808635740683796,
-            2360187670121031497,
-            473985228648533155,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8875048474778436554,
-            885337291971580927,
-            1810509209739639187,
-            1189507410868679971,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14729510187399863457,
-            8720371839178870394,
-            17971594475330979369,
-            204718814995070014,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4974637818349214847,
-            4478087754308953058,
-            16730152352957413786,
-            905511840246383462,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14593815661893699295,
-            11227422147213790120,
-            5486529172781630381,
-            3076156280134477583,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4786501245941461481,
-            4899897368625273852,
-            3873467785147228055,
-            2110831067683665590,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12214532256199654183,
-            9613458750815600846,
-            3505659137059552612,
-            2219260396722828528,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7283722720072251808,
-            12935944743518391063,
-            3826675870546868343,
-            1538506416448546100,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2964377024786883815,
-            12280851932282108217,
-            9591166412082972313,
-            3126675628489974092,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14894445513486131926,
-            2617609556887591743,
-            8160842299873257720,
-            1720388814488611457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7683550751949805169,
-            355767462710035537,
-            5922614044974856167,
-            1730704860644221107,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6353122385458461560,
-            17979351712080825273,
-            12391614611406639803,
-            2628851222904067995,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3230155468311596430,
-            2513440982092432956,
-            9645759228473367302,
-            393484210648749377,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14977696437865907278,
-            3385782814561511708,
-            18314486016626614591,
-            1781994049849337516,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5460317490824742419,
-            1528837443746420311,
-            10632644021462705347,
-            1758394133706111162,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4149187767036596025,
-            9993799442894099614,
-            14710925331469219055,
-            477262959817982307,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5511599425970732457,
-            1741185789472621178,
-            7766446771391147343,
-            2149653274385979026,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16898602415900822689,
-            6570557319662184362,
-            9473546504805030184,
-            3316815866240760886,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16200449589725530483,
-            10815116547925711937,
-            6199597855085436928,
-            3067912474442354439,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7688449718712527999,
-            844928714475825883,
-            13360061677127224643,
-            2081372506392161674,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            192383380158811389,
-            8116140465533842285,
-            12915898688231071932,
-            2330172924786600163,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1945346312250879962,
-            10570374927348187409,
-            7080772484505225387,
-            429317727798444088,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17853933705243086728,
-            16601643051239274977,
-            2115253047020398355,
-            513691764512905986,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16489160648109615934,
-            7370692115310380742,
-            11916427965508297714,
-            749634928943356248,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            707903737895565132,
-            4255844153106193962,
-            1470180055501520620,
-            2126856080934093017,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2233154904174052271,
-            5865105822741308143,
-            9790687798575899764,
-            2042611889748423892,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1123280258297175142,
-            14860283255093023562,
-            15596791232314021908,
-            3456795686064240493,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2292811637528430465,
-            8967045406064737207,
-            14980447411197247519,
-            2026387098916803110,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1453625689924643103,
-            9810498026305565368,
-            12270767138608202214,
-            1025589090211135862,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5587672522764432514,
-            15573779257384236452,
-            16211090543337009232,
-            2250478317021594407,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4876162433342628910,
-            14182115626037620609,
-            18347684568828634206,
-            1004779607310924752,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6459949522272550503,
-            16909498680567253612,
-            7351929200220598458,
-            2387182150771673930,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5831889676705615666,
-            7052551749483073890,
-            6594003254747804208,
-            2357857569862523149,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            902343863768715547,
-            5630756305061782324,
-            10812404685024041674,
-            3398442942575426453,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16209078567680374005,
-            1270939322546624807,
-            9453743535562369115,
-            1378926890929420753,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13731359940679873334,
-            14692644228257837180,
-            13320183969170490147,
-            2335317069969598172,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3504650288153684228,
-            16826250830040672630,
-            13145604528653467883,
-            1755287764923436856,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3154518749271982883,
-            12847022974091571276,
-            5351624561891864540,
-            3399657433819388775,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8317770771538004940,
-            10863610186112125951,
-            14966372822944859161,
-            2302044394669689059,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7505586315948914406,
-            15859260035524698745,
-            6885121243642611000,
-            2259589565983668032,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18326880227900643174,
-            4716896927594674796,
-            7545977245481303085,
-            1877409488646279137,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1399238422662027646,
-            17296636622803269438,
-            7062245954335625518,
-            3090954293486236839,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13482968813542682227,
-            5295422626431213481,
-            17738432265516156199,
-            2461584038959137006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12883532006851535816,
-            9467214069609677749,
-            10933213015006400392,
-            1572933316903433119,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            135780898404273585,
-            13486889602804127675,
-            694619775789069688,
-            2688900087677949834,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18158960889958636489,
-            11360137873200880158,
-            11378110740234019743,
-            832527277023647179,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13867074977634343477,
-            9746873406679434210,
-            5318129086131210359,
-            2716933948381419265,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11900245397013608496,
-            3419479245671187062,
-            16944210488632751394,
-            602802106169030835,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3002240177160519509,
-            12852963192021508565,
-            10877735834394343063,
-            517537108811684158,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4463882063948140424,
-            1178992791863626993,
-            784641719627174093,
-            1310789990887447732,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10885618122635542706,
-            1289353185970110745,
-            17780935397402275451,
-            1900051481767279821,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9053899814240164345,
-            7220095052826066517,
-            1525937251148585677,
-            1682174785367452674,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2285169016131071175,
-            10097481888521619719,
-            8801606657786556961,
-            1418086970833766624,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4159704156520029459,
-            7342206986842622656,
-            1801626909393344424,
-            1159304767506882506,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18097933784977810318,
-            12829890151897325525,
-            13473279135790557373,
-            1541245117438187055,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14245298301989958329,
-            17788658065718141469,
-            15717739548781328454,
-            665564753082838239,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11803645819580861961,
-            16479001110948708267,
-            18149959877538088691,
-            3299765040193616129,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16354668051271612900,
-            3451004251240749386,
-            1795766913596405306,
-            728613094678679158,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1980335732804848061,
-            11808432241099591437,
-            7848411264750296058,
-            3433359737716348932,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10189163675152485076,
-            16223013189889273528,
-            16887738812040369739,
-            2691195594928642095,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17726008078832651298,
-            9260643584134229107,
-            759134250678490782,
-            3017688923397893495,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17088193127415026535,
-            16029588724372624923,
-            15403709170103199578,
-            465348745473149485,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16087404371224626718,
-            5889289563528779984,
-            5392261633820765312,
-            3417170359470917873,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6865657763720460069,
-            9728663993647978467,
-            8551101927074455876,
-            442848852640925547,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16109845970895860792,
-            6413722132961383848,
-            10179373505886552568,
-            3404144533561728339,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6064361679349157159,
-            9012917962709765486,
-            3012466032689942705,
-            2562850707578640561,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4505008106502305387,
-            886165406356665251,
-            11373096505846845348,
-            1870277869206423686,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16915473505884157668,
-            8699356594240440735,
-            1259804055110293147,
-            1634717250668258261,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13926061061763255900,
-            853422202488646437,
-            8464395905477180261,
-            615423350988576330,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16710098719059397799,
-            15814136740810243513,
-            6456823846221098159,
-            1663736850857373569,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13179703474205122772,
-            13284433075555241983,
-            9982521362924532272,
-            706657988868986840,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6607803768782711420,
-            12629639854711812205,
-            4146281664728035549,
-            2499734242677496785,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13658717075836397941,
-            9248432895783699345,
-            14558604766357784852,
-            86491477456851706,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12750311338395278778,
-            1219087759598913171,
-            10356246271202652867,
-            855326973405455695,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7323585386688429816,
-            7522316189380616043,
-            9013205650954616951,
-            3212643252584092837,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4170019650866224725,
-            14365404467143951116,
-            6561979961451352791,
-            1851212638053703944,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4516578975248243447,
-            12191459626546538683,
-            4950195710648376168,
-            2606687800747073942,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3294479874815864894,
-            1654007590563393672,
-            16650190985704957501,
-            2250896868523622441,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17449789529924901999,
-            9243285618240640265,
-            17320636822005545458,
-            637217627232815634,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13985850761800618560,
-            1263894452239692960,
-            17847685028205743288,
-            2271202752332103054,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14829019616052476250,
-            4841758133783269587,
-            8084424203729840800,
-            113924172470244389,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2096110243184439107,
-            13087634417845800356,
-            16186271427889366651,
-            1767684469633907673,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6571661800695672637,
-            14251908970933117426,
-            17867780357228789315,
-            2430570134371442821,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10886327830338400244,
-            15199791155140721152,
-            3714718760979985831,
-            3196824605975098908,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15233735374610423777,
-            5747768086793808872,
-            145990107392028557,
-            371724887335813810,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1456080936317758632,
-            1979886014484351010,
-            4341792879449040909,
-            548030872661778438,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11918836557402926720,
-            3940353112459396768,
-            11279649377130851697,
-            832797533614689036,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15506040388376662827,
-            16691820075835206147,
-            8175882987506665792,
-            212529201668870651,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9304833966662411861,
-            5156522362725060811,
-            5108348915689749461,
-            2790980042733352228,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2306141960966882490,
-            10691665598040728990,
-            8282334163110840862,
-            2255260154046795643,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13676854154249288441,
-            1754719202061595501,
-            16358785880226046238,
-            1256453843843149984,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12103160041593032880,
-            7698141424525653695,
-            10930312264524637562,
-            1897753944537527410,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8646925595724796414,
-            6818471284664122132,
-            5487176599162363277,
-            2534534590540661297,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13438739278132518112,
-            100832356750989077,
-            4609921998490130996,
-            2802192079234832115,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C9: [Fr; 135] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14756263493439734378,
-            1335347729475951650,
-            3944856928138225974,
-            2343967830996978935,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11536017154648838212,
-            4019015476920212449,
-            4737360234794256021,
-            1126491172170473452,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10556002761875661632,
-            15114693613850020567,
-            6132127435896335400,
-            2523926629000584141,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12220606147790816248,
-            10370731517755052841,
-            7610896288128688469,
-            2018407509409126384,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16174433204691987449,
-            5475566384968096693,
-            8559253653812294757,
-            2135365808257121214,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5471991674622205970,
-            1844488262793314291,
-            179214094070121798,
-            2143572368218901285,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16210487241313285255,
-            2156657513140717880,
-            9174211718727555710,
-            2393519162240709742,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17268218836184206022,
-            7655621159045199994,
-            17459969059447326584,
-            1237727799948235472,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17901173890396294474,
-            7966336029567700430,
-            4476260852630648118,
-            1166234003882017081,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4677768516697890106,
-            10413950175285718181,
-            16880725084185835852,
-            2093592881763221852,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16456801169394825358,
-            4105039159314717213,
-            1049676790677950962,
-            2055957575038065752,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14106777585042101729,
-            2681731626555204004,
-            11615839419234731008,
-            3422129855468864819,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11861636859267051309,
-            8285397287272173291,
-            16367690724832971582,
-            77955640341682889,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9917094772290333213,
-            7847204291178720109,
-            17046124105105906973,
-            160518231998480028,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10351946788736111381,
-            2404775638480651128,
-            2044079860280932603,
-            1041476332819699473,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            688124709583452186,
-            951171543717540861,
-            9175294380454113059,
-            1796386478041934699,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10086395686303937269,
-            3741943379911451860,
-            17726084454760435825,
-            3257741251842203190,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8698883430412365117,
-            10425806745217454203,
-            2814899730043898152,
-            2005555078983194279,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8455646894327041586,
-            6659257057122768799,
-            16992091571204503357,
-            243645385929803172,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9222938822572887204,
-            398070755805061712,
-            10725911839398818426,
-            2469622556630921143,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5190549270032605445,
-            6181127447466606359,
-            4324549921060323166,
-            1345018958330269983,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9073862673248918254,
-            898934516633006076,
-            17860388945899591012,
-            161294483833403274,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2167228481238929237,
-            18163923748317500847,
-            7282268380168529027,
-            393957100656501739,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13868708659489736944,
-            18063586377067604759,
-            11362806161255268396,
-            1741773525224519168,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18124011358924121593,
-            7942315626275709378,
-            1123536142977776918,
-            1469696417664702116,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7023341082789563327,
-            24577284422629409,
-            10841367861857114023,
-            1034374971743366226,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11853432096478947335,
-            5228328930211897415,
-            11918780079269187260,
-            771069223642393684,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16344818762093080051,
-            259074156614902080,
-            1942908662290771970,
-            1449836828304380028,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15127686864848829728,
-            13884354997536698370,
-            10984879478497411449,
-            3360104359344805441,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10844214941816117719,
-            8063913837461566973,
-            8270512391949805977,
-            2011301492668164153,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8671189338119365229,
-            12544261005615294978,
-            6434005555842886124,
-            2797343778094475884,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1188646099823550224,
-            15203074381317163231,
-            13501159604427665370,
-            2477385751623681143,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16442565787932101827,
-            6437277867283960993,
-            17646174220731532382,
-            3055419509926296491,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8475795911274626004,
-            15660142916494901482,
-            10771639470984228104,
-            3380517110974118607,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11514138475585280495,
-            15938877118960529997,
-            8559021017253054349,
-            378064195204716131,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5714839140277587662,
-            9581503372622870245,
-            978288424695091925,
-            100083655855255542,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14451747613734094545,
-            12941706036010555886,
-            7961795562983031597,
-            3351249635544654602,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8423719346141686958,
-            12851394124185497982,
-            11293110509538225851,
-            1965030753239136350,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1385161235892833809,
-            12114770908244332253,
-            1068189530516142362,
-            2146197276834417267,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3150652520997400278,
-            7575667597485085958,
-            6197436099064381509,
-            2308854031897390495,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11667210418359912889,
-            9345709163793499902,
-            5039961231054063554,
-            82524194770671847,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3952097859427517678,
-            3146411964291221464,
-            17852156243252792169,
-            460297739123581304,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2338262970755444711,
-            7180662817624486287,
-            6930676302236243983,
-            1890048089457424600,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16614486120301265482,
-            15906975793876371964,
-            6815788015875209219,
-            500427933383894405,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7147065287497131468,
-            11733402146438975287,
-            7266046679011500151,
-            925719797169261439,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11500698417245691663,
-            13159297314334153342,
-            18216065705614141749,
-            297683408805425204,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9086736667856961118,
-            384959455247883180,
-            7187924984170554087,
-            1210057082703024716,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6407465178859003921,
-            5347955408529623647,
-            21688000034807894,
-            2227506465440655020,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11892688833734077801,
-            8237084180726131945,
-            848459104076581575,
-            2626559916606402757,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7239203755684683271,
-            14921262896988275471,
-            3225127284197168937,
-            519523118644740044,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16833541907259862216,
-            13735882836379616785,
-            1558354208089579514,
-            2180060009721454546,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7946784460025972202,
-            10799529418837279797,
-            8271297557420983136,
-            1845792988216182332,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11909477855469155134,
-            9733753155427468520,
-            12864926288445759553,
-            601657381789299274,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3156911303525143846,
-            8031517068333797709,
-            13394812474335187048,
-            268164999559237696,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13871784566808907845,
-            3604161122525592475,
-            5417384084977135662,
-            1680272463879737338,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3013081723168677410,
-            1821460861996561124,
-            2297910499869020814,
-            567045273448478807,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11896333603187929426,
-            4127092087772553348,
-            5487877193925994850,
-            2271047951860174014,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            699604844096847844,
-            6281675611617450080,
-            12580007604236399574,
-            962481882581199828,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10562308643908675280,
-            11980505680269753195,
-            5535281347920055368,
-            2576664032131941899,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8920292212140572104,
-            18396718173015748255,
-            15637382681672815896,
-            866044238679339880,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9936594115635758397,
-            17938775368289200926,
-            11588517304804529843,
-            1690131590261184532,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2699659695588086392,
-            8553731926565527104,
-            15302755643344903947,
-            3328624139562812620,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7738787988361916587,
-            8055001497769252488,
-            3620129451903638365,
-            1526070653420612774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3369480726757021374,
-            12558239423034570637,
-            8179408951615863909,
-            364649926364034429,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17901191570498492677,
-            5722364527727837185,
-            16884354183276150778,
-            3165035390614491441,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5547396600197174789,
-            10242008825259440836,
-            8399887328180402774,
-            2525189271762333193,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11447203433992352809,
-            10524313091958805924,
-            1450921579567137796,
-            2486952090018136799,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15482600827886331739,
-            15365036109624626834,
-            3898975889843273027,
-            127842419832837516,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6175204098067937888,
-            10718513046729590160,
-            754753868975989322,
-            2389128693668643970,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17651779997246480016,
-            3576782541223699590,
-            1037082832285963194,
-            686078603011442565,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11848219372403446,
-            3134786796808201966,
-            3805053980670503194,
-            2799224461193508622,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17706264660846816465,
-            6200605163455556386,
-            8504047641598703924,
-            2392464985554678585,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13659482373314005246,
-            11737351824624037087,
-            6657846537956374127,
-            639158747024890570,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            166146559813675534,
-            8696312900374501103,
-            6071632895127047816,
-            2415113146958340394,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7102715423462641920,
-            7069953455502322224,
-            9414312515846460352,
-            2420750460816591808,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8899710223743086060,
-            4656441073715554693,
-            17367028043042797032,
-            1378216816264844846,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14039552833558490233,
-            9641421412868456599,
-            9916168285392914074,
-            2120505732478511246,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5648210638490159275,
-            11250117045495008559,
-            10088588717400950552,
-            3106827919823331731,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4026668066776165597,
-            2645086940162913711,
-            7239652829722981805,
-            3158701508005082639,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12800662684229532399,
-            3526132257529547168,
-            2248156360654346011,
-            2388949342885668095,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18156843586890840491,
-            5257632150306160349,
-            5168205332026558184,
-            1468261465343808519,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12620977656900190192,
-            13989910301341708635,
-            11631267130579856595,
-            1765865537865802950,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14156100944568740094,
-            7585708734057722449,
-            12234621935651854522,
-            3175463503321696276,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14268253049114856269,
-            9097724067294510701,
-            843896150010967979,
-            2758362251847566139,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3958487499838611561,
-            3009029113026436551,
-            4579337222949131236,
-            3030127146324109186,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7686085829750912730,
-            10561331000819256772,
-            12247720929572889535,
-            418509858487877438,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10489622284270606728,
-            4588522477566121097,
-            1694658025286968899,
-            597460724231021596,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            397807251800332065,
-            11116039746261112307,
-            10033399786248994661,
-            504807006906199829,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7009074175728083496,
-            12731862872124751110,
-            17056308826095942559,
-            744290999920209362,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15502899009580829868,
-            15759640226066282855,
-            15480900575988215006,
-            3061281358185413576,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11117577392424588380,
-            7417086153229730362,
-            3393907574995798512,
-            88203942892129619,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12700969951993885151,
-            11944635689791658380,
-            14735475352100327241,
-            2799969750070163679,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7891569131650276882,
-            8562512178643291227,
-            5860264026116032321,
-            3108608294676904395,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15756732645333249187,
-            12982416104382578488,
-            13976140019115881814,
-            932299288351279553,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17067145403509220609,
-            15631608179314486834,
-            9767862076049140046,
-            3151988109538473006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14368982117049223449,
-            10850963613712116464,
-            840547961043921790,
-            3134208618913472814,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4390438966242038055,
-            6712239146741521763,
-            1259191578190320644,
-            1735457016662149020,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15581606617916884552,
-            17918326062015948136,
-            1429270583500604350,
-            1538734476573872705,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1622415495972318058,
-            553500008184278620,
-            11039314199614873001,
-            1894806412463382589,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5360468328474336855,
-            4964763749215418306,
-            2981877081238244756,
-            505242646389178097,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            138897318268366676,
-            14363008379923775242,
-            14468484508500908716,
-            2177852903254202033,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2732498820762848434,
-            3451050131406116792,
-            5778708945893474774,
-            20942839392378487,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3881118631017293602,
-            9128724132611335228,
-            2122415045326864829,
-            1857509176536656647,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2492538235998429834,
-            1422246104026584490,
-            324989065334694988,
-            290263107865418745,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6017626599797485920,
-            2382557026814886087,
-            2337853099478307638,
-            680062741023134301,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14509472921490094656,
-            15521300827669053591,
-            13895414894949763444,
-            2706909739212887220,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8150589749083277176,
-            6877635273041244706,
-            14740149490156519909,
-            2660112311805329263,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12678661689756994211,
-            11508870169542990223,
-            3529072603761260513,
-            1776175562196358892,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8370382715867169927,
-            14475129615668090635,
-            11958247429341417759,
-            3424753794776593652,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6325200772066655884,
-            14534959324345887400,
-            11965413874856752690,
-            3451641499862746028,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1676440589296929703,
-            16760923831569818822,
-            14842258807527963330,
-            2372912422270020193,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2602720444898030847,
-            15866527047167566674,
-            10464519192171422536,
-            3160428108790385640,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7007946061736081366,
-            16524791079254898186,
-            17978031526437971161,
-            2661013783897571441,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3650950998671969414,
-            7400818251306634751,
-            13470215682835036272,
-            54715127944411818,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4588472467465496933,
-            6686986774996405635,
-            1434357996689069453,
-            29063871776703085,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14019096367291731355,
-            349301615001577629,
-            13445202589768384275,
-            555424737580969682,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7425858252404792036,
-            15127799978163462251,
-            6167346048570514373,
-            1607687134250589102,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2052831543919153274,
-            2991907515912010127,
-            12453188828027832784,
-            2843103763892678035,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5932098543403616949,
-            9335741132384012490,
-            2043988785462119804,
-            1033568788452192943,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9418961274880440452,
-            11009343236009431415,
-            3146258117757851382,
-            2338085733957750412,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16373175934927135981,
-            6214931633518027984,
-            17239777844835691060,
-            2481417189795655751,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8789674606613601867,
-            8255286251182201217,
-            26487767542501500,
-            3323498002027878421,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1784199918647665790,
-            17063488364303382416,
-            4509744108608343942,
-            998825405254862142,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9527816611989933933,
-            12327720975276153747,
-            13218153112588524207,
-            1758454134757420362,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18424691245295539289,
-            10861048293651987206,
-            10142984440974293830,
-            1721204190838601593,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4087976444161402028,
-            5538474464816540693,
-            10098119485711053772,
-            3471863296030658692,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12281372917242419892,
-            15914122100623115829,
-            13078529788778899870,
-            460990267680615707,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17562233559078489779,
-            11791710419716369432,
-            10771313507658923714,
-            99884396482735379,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            549936230040919657,
-            7648951588444816362,
-            13743258083946167201,
-            3353154462967462404,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14760176403417977992,
-            5070530124510266313,
-            2482831512647557767,
-            1051637366516650531,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11807680203163388591,
-            17002104070984413183,
-            15838758946552963830,
-            2473319666610128912,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14012955157779589214,
-            7778259733460806568,
-            13503618269031595333,
-            1917203534232280706,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3795553744658075530,
-            17836826309557006835,
-            13496775473785850183,
-            160891759488686570,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13562455025237358524,
-            13748190989271010331,
-            18290851038398706425,
-            2806948264518474218,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4317186286092296031,
-            9599798667023135340,
-            11481081629149979037,
-            817425376437424269,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C10: [Fr; 140] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14978048375866871174,
-            8994719917210448194,
-            6045036728888843796,
-            2706155614395525311,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3932188892185367630,
-            17382452098372250282,
-            16514782098803454040,
-            790091925860944580,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14139656585567431003,
-            5114860549934676839,
-            543635010184233856,
-            688053082683493375,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10261188854949007112,
-            10656423860069688104,
-            12518393070841215018,
-            1147288752906007034,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1831761223997414513,
-            3684945668933837543,
-            11310326699763331904,
-            1766826753897057308,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11527329438212178236,
-            14017816040825628527,
-            12030897670666620474,
-            407839845526326222,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3059210533557505587,
-            5466432651119599879,
-            13620539214329497810,
-            932625543022822038,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14245243179536414052,
-            17360558259297805216,
-            7756825247199850201,
-            843863790385507254,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14563501750122381068,
-            14788109853581917837,
-            6207882655323435481,
-            1608269613144932570,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17371871690582829053,
-            1213584095436768126,
-            16945967291401136423,
-            841816847453853917,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3523024492481763526,
-            198189201266280161,
-            15026215186256156013,
-            2190517245058009307,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17243417383171123844,
-            4990916011094969649,
-            14847187481082323257,
-            914830825851187373,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            557554302505830014,
-            1080244929909962278,
-            3326472582710483866,
-            971742458943545689,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17785021194894025721,
-            17361399403641878412,
-            6454155105713046485,
-            1278414537475769578,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15850479723182276037,
-            14264284978850555656,
-            11480601631788880124,
-            2533923620655269371,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14748274587887204226,
-            13996486586088499706,
-            4829885829482952389,
-            2999943847956431275,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1845132225006323094,
-            3882217237608239141,
-            13545056783936600620,
-            58007472593985387,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8540480456359400928,
-            1655131686897391492,
-            11784934668814974349,
-            141749207300619611,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2517635413004409526,
-            7461300173529718329,
-            5725413774325332879,
-            3335431641590246490,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11765759182927710939,
-            3271466628430584281,
-            10270173403402455470,
-            601853398988758246,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12123581268305791292,
-            736917640093421691,
-            13079301644961641889,
-            2824804972884944002,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4448418382759798077,
-            140846054169051302,
-            8851618892408895586,
-            853854921890767938,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9532619337272226402,
-            18098179072659119756,
-            12789702904125874612,
-            3191802389092837902,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3233155514467000827,
-            6432502302889316475,
-            3419884898147665080,
-            2145680915034823396,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5972890019848001763,
-            5094505925680591711,
-            11504915645055667723,
-            10599448613387014,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10056792627792993500,
-            2270565167446208720,
-            13144572053000505009,
-            2835016436936607862,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1688702562482603888,
-            10558145630578722144,
-            5238193566541878081,
-            168362703432951031,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13830206436064307082,
-            9558314239602801001,
-            1582862203413276615,
-            2215852332940764273,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            216951279425788472,
-            9619820620785114518,
-            4934419316649779167,
-            3106112632430773928,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11569215426260039829,
-            16202177526683683853,
-            4432068283910798828,
-            575541125060410066,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2137886927827736344,
-            17604681722611670888,
-            5877654441984359333,
-            741927616833167541,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8172340927620587613,
-            4577327208719604650,
-            2123037090170477125,
-            1022625006077390894,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5490293923072081336,
-            18442050282184382886,
-            2010517908690667112,
-            2034875222808519854,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            59907807407248508,
-            15802773213927670142,
-            12906960343011819687,
-            1943042061346610753,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6414109689369929428,
-            1641273437505684988,
-            2200756382693274981,
-            1184230679661510528,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7051591278656007152,
-            7892771700924634265,
-            9283328545454083967,
-            728732576577224415,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6584177196058111160,
-            8655998278015704739,
-            1587078642351414274,
-            2255589832354591057,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1947201634817438954,
-            1696136706163774038,
-            14805578555590713855,
-            1246251968027510953,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13193884825343478074,
-            10255997522599341547,
-            1757455973860216460,
-            1442166976806103387,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18167916080741016242,
-            5258782456560115583,
-            6735034063796783426,
-            992628347306771661,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10466287009376718954,
-            17447515225838848419,
-            10109573903461193942,
-            851741243313700399,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14255489608013459763,
-            10876748424662362952,
-            10464094744721991206,
-            1984920300491271746,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5962181432873652743,
-            14880253707008424734,
-            2927551115367048314,
-            2314549170742515276,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17382714272742482201,
-            4213434390595671988,
-            4375164598182536497,
-            615127806463962757,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10740444151439953101,
-            15449968632837571825,
-            9937119560789526295,
-            2650489229468161704,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1597767926209938518,
-            7892202228822076424,
-            2669233615328894925,
-            2393831473663186940,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16005520622717258218,
-            14201960523728373892,
-            1007776478386769429,
-            1258041364257635986,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1316820245411143424,
-            12008138872297417625,
-            5073956618827641739,
-            1802686173748795873,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13979217016881059579,
-            4693835180870543383,
-            12940813660815248995,
-            2314952330971262096,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9353145983697212302,
-            13857768548058580765,
-            13735640352920769466,
-            77855101338889,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4528322562001633831,
-            7667093458712328595,
-            8620614460519825824,
-            385389294943578503,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13958918891125747398,
-            14833304206207660019,
-            1583622509899528164,
-            2076129725237059220,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9157557899519561758,
-            2924745881212004466,
-            419009603386564747,
-            2621067453672364919,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3495193792772065612,
-            13531546805584498567,
-            15112059822440473278,
-            2960636124100491944,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5234306006645084971,
-            5811280994986898194,
-            3540458005588363146,
-            2208860469910758536,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7451980324710593361,
-            2043465659641681440,
-            11750114978910055370,
-            2250956821960388527,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13199991147236132896,
-            4255597822763281163,
-            9138798633251249985,
-            1154779950695301437,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14055521439295852661,
-            8879676987205529863,
-            14837701017807296668,
-            1940751191500842064,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            916255927973649695,
-            13841332407158769804,
-            334886181800146666,
-            3151117506356790726,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13274577305166232394,
-            5685155184528452011,
-            14062069094086856082,
-            523155373486076489,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            510137529481782676,
-            4661578098819415479,
-            6817623090086106546,
-            754177480615759786,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3481248723493343966,
-            5332126529496588895,
-            12778786942353516268,
-            2527817803600521399,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18044827466425420658,
-            2523689055658297051,
-            6577017812453259480,
-            1793372152942419741,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17925171262831085766,
-            16963236483583521633,
-            11721894891180849110,
-            2557654435763632398,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7289416323051857613,
-            10252105726214571671,
-            11220595233411871828,
-            127633228264714790,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12462754242181884071,
-            12535786310420451736,
-            16951400157565249223,
-            2602024769490293143,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14755329212560432980,
-            5874935080379046001,
-            3540493539852412220,
-            2934226722252885368,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1215173099205580642,
-            11764635896549110702,
-            18044584027873284971,
-            1386953840890849826,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3118943892977027611,
-            5116855230617660394,
-            3655670421729573942,
-            2372669772585074211,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16557496567963639099,
-            1918635623120239172,
-            15405076106672253610,
-            2777213044016314903,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2924630010936879397,
-            17606701605792358355,
-            16562050557312894376,
-            978307831279981758,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4693475305914089119,
-            8682333752135243797,
-            15957028592708230519,
-            2600767941917938636,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12356826325507192666,
-            6889391586979497996,
-            8530482751238632744,
-            2930996649606307650,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4774494186855381536,
-            17418746073773634486,
-            11859185312915505160,
-            2590578327039783171,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16957852642939480530,
-            1529855756059129385,
-            12202520412568697605,
-            1427235309438949431,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5649224607079933551,
-            15374859329715940765,
-            7940970413423474060,
-            578556468385620026,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2588708911879609088,
-            7811844715366480858,
-            14565135357638041799,
-            1505759728592188839,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14745446644863383389,
-            7402488192645653291,
-            2256646968588180383,
-            3009758256823086873,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11095204829625724347,
-            8013316749093056247,
-            7177878889644037731,
-            1674418803518424605,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2415173348743024922,
-            1383971665244036804,
-            18239811259123227169,
-            835938544380644967,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3228194998765333083,
-            9667509668623953491,
-            7001977265857554669,
-            2037940451799311817,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8496459917574490695,
-            14234951701419630811,
-            14977029605711106976,
-            3020176209858745935,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14697428911455102273,
-            10093743493363672090,
-            6494308059577779678,
-            3052096411806752818,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3157752632996079787,
-            15116400081388323221,
-            8730859878898862500,
-            1488640766475343848,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3824265321759314523,
-            12542075341353348721,
-            1641119275818327537,
-            815442229512359956,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16418737271338266254,
-            9275805975458366589,
-            16551113803389826945,
-            3026103488293191277,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2735814371587259739,
-            4921547990384376970,
-            10654715897797218032,
-            3129906899436957329,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3207119531429824166,
-            3322205085696694450,
-            17387237710624391849,
-            1793333380520520713,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1409192505916182742,
-            2415213515078463432,
-            14927903791122349230,
-            429638243769217343,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2827965879122411931,
-            12100818604632530062,
-            11164107969636574781,
-            649180664738188013,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3249381824182146704,
-            16400627867349927301,
-            2705930556235848925,
-            1604181679925323097,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3599685168274500028,
-            13026780963898186603,
-            16009867784345482911,
-            2109927126931835855,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12802177425176528955,
-            6230205602174532989,
-            8916009726512577248,
-            306099635080366540,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            341035302508384505,
-            7516608598098578953,
-            13559962810119527013,
-            2783172253680371030,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4408987848917649142,
-            2132201350643592949,
-            8019151390339595953,
-            1931451745857440495,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18228192957038948906,
-            10191200386491830283,
-            7256290037039488985,
-            552102321905219885,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12435368552636666991,
-            1692171793678524855,
-            1327687922966778682,
-            1682934053302271878,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5283736507370868724,
-            11566732889996425068,
-            6536231320060612661,
-            3076688157220006598,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16474846484154415512,
-            5093875573035178892,
-            7680534114296205785,
-            2565709935419875159,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16769374617311332333,
-            3505019568802025369,
-            6083130048402105376,
-            3067625937714724377,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16178843409574017756,
-            589028092846433466,
-            6365368507634435641,
-            181206396376452115,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3232497081119994415,
-            6918736311663970759,
-            2658465845237719933,
-            3452149083232674365,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6923513835233473469,
-            13415724350277388510,
-            16652540161875536106,
-            68695825446557781,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12074989706253461835,
-            7878229268377982795,
-            2040585054594087766,
-            3448704214258037496,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13143861965930990414,
-            15646258132148801544,
-            6038895336651967454,
-            1428517218015303421,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11282244257387656157,
-            6624266690703136363,
-            8897516338886761691,
-            3022599413883743083,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8323278023848639728,
-            18004492349444292018,
-            7653209437823624067,
-            1237653739265036287,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14192429672278551371,
-            16812504409208308814,
-            16156396315790684938,
-            1705251087676250482,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12387904693120920904,
-            11309375807661043279,
-            14847425279601071229,
-            2822766551151492312,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1936544417557227086,
-            12875354267990609051,
-            15889991487234554766,
-            2554502643334557513,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            813012676685985655,
-            15265223580205869931,
-            11649716684438369811,
-            712013769418678947,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4127637615330024907,
-            17038168989900901389,
-            4989050655972186168,
-            1636443684477513410,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12600771402406648314,
-            7540254406806755459,
-            3752774856764457456,
-            2771044504666848442,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7267452342646943735,
-            3878030979078658768,
-            8263270323237584486,
-            1789082058342706058,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            853115416754388505,
-            10664672918638371897,
-            5314610720886910385,
-            1416232603590965255,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4762452322052841572,
-            13539941937546323269,
-            11076844694847705785,
-            545678830279585455,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12535726255728713919,
-            15830832905583896566,
-            12712699480620309364,
-            1649249391566411511,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15790083626003858762,
-            15183251640957727333,
-            725762479048250549,
-            2969048254929137177,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11580830009831691461,
-            16413319090765086642,
-            14756500632147479665,
-            1656788451941172607,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            650701813558973191,
-            6312646867352257070,
-            16278904265055798410,
-            3287767577159513409,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9839278980467591654,
-            6544574106951675651,
-            18431848786680479546,
-            741028409578459635,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10862850162087876783,
-            2427648095264323831,
-            13328060553777083271,
-            3135864770795245388,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1994462430033252445,
-            229432475425591674,
-            14438927925170759286,
-            2435173447447449926,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1395831260211611198,
-            14953867459192968486,
-            7670238954815479158,
-            1695136996854172981,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12589358651128695847,
-            9038227798324626143,
-            530612108203096504,
-            451790942821366263,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13228400507685116232,
-            3878095621712900469,
-            15179926107956303180,
-            2804023316595648542,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3765297377108829180,
-            8112642439141370438,
-            2344986533365901019,
-            2610031108503761609,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5132706042935229965,
-            12372398451153823347,
-            10185509515080922806,
-            1632887736497698955,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5315161369057851477,
-            16693090241846291358,
-            14927866881152498718,
-            1350597762592327882,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12053851596463282695,
-            10161268113646467614,
-            6819746711644208037,
-            50912940580198196,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5228103859521765277,
-            15559240242791141472,
-            7677352874110709570,
-            3124223547641396460,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2748861064521635643,
-            5699274185856862131,
-            10984289987439510626,
-            2768337219962402415,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1946792593250249824,
-            8082687326204380811,
-            13379446590447862253,
-            1550541668983690133,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18032437856505812560,
-            2795338114983863911,
-            13222225752168515771,
-            1835148798451150563,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9643527065185230013,
-            4839846105446691105,
-            5371809298515782923,
-            2823451495576518988,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4487360413351324100,
-            10642899608079673674,
-            11691056640711980904,
-            2420460495513128035,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17546441944444489062,
-            16176271535028696308,
-            3643644887047164645,
-            3119314295984763853,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2888741099992413548,
-            12023359398940439601,
-            1480833659539617119,
-            3176786412190720374,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16847337235701290251,
-            10061982797224252686,
-            2912985792129519497,
-            1400349798831815457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4818014799557524731,
-            1733767732851066125,
-            13638147261980584435,
-            737657370764565145,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C11: [Fr; 154] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14917508564848407841,
-            12792940423292355920,
-            7291862180330698,
-            1730510740658380578,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1776375469563893600,
-            16577636683437407237,
-            3293678395999950211,
-            3218359221462288563,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10926885817543201913,
-            1980128066062032540,
-            17400307222657437561,
-            3444265211465193011,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8176083168460352036,
-            5366329782893630116,
-            12175492559724936495,
-            1797565808503556712,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            321179563157977580,
-            12579825903608950850,
-            6666157817621288126,
-            2402054865285679114,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10832946206613337122,
-            11843650695081933802,
-            9538513034517818604,
-            945260759100632554,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10322183512005968964,
-            1251870420573781721,
-            1644627326644297582,
-            3369197386474444846,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            854600053878099931,
-            4211662736575632376,
-            10849490601046618847,
-            2853457030707700183,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12654703571964845610,
-            2410436526852643016,
-            10521600274688862074,
-            58347624736583862,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2011822738017561885,
-            12734801092568389542,
-            1538889050927661696,
-            1045953470831969615,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9523984074294087005,
-            17527415271566323941,
-            18072446184228004254,
-            1909008580761168634,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6727609114321805476,
-            4710560501563084235,
-            4809474093694530621,
-            185420160481452687,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11328829779431917754,
-            7471446145171925950,
-            3979747002081451482,
-            2993655239002811168,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14775906491426692565,
-            1881004135730230605,
-            3982761999108858337,
-            46278820375624519,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15600846276372029124,
-            444735868425937003,
-            15861693339901174224,
-            1038659000624625075,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8324211953549605937,
-            7873188263684355708,
-            12087769098048023459,
-            2789635272666008013,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1875380604480990177,
-            5492726761432466934,
-            15323426811230721291,
-            204598760003348526,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15766610314453514714,
-            10331723543070031515,
-            6960922858587954704,
-            2754477441225523161,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13627639237703486691,
-            8647160080321417687,
-            10235621041540133169,
-            2272340231447718519,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8747320178108017339,
-            593188556678825014,
-            16228324665202410097,
-            501293891719054308,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13693957400761277600,
-            11773260888968766055,
-            3050242084409484256,
-            2298378298519238079,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3521780762275569044,
-            15026438858600609636,
-            14038656034542328912,
-            2222144433743785476,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9305553056162992065,
-            8997709594205587668,
-            33304184141803065,
-            2377131593989032427,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16959119701785389860,
-            2182697421191152225,
-            7865461062408265921,
-            1178916132548692821,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3014610813728558801,
-            16117448684584658139,
-            15306692072279122094,
-            596173895824359346,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13100224280370925153,
-            5522502549497121389,
-            16035917483038316518,
-            195530757711891556,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4855697628720246658,
-            16859715534726228417,
-            12118391832336396525,
-            1638646544136050046,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10914387960538721076,
-            1270360183406304563,
-            10310152113786081145,
-            2190019289437131493,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2171908760950664732,
-            8387202903474002353,
-            16771155737916306182,
-            458118796931153215,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3670874813229992765,
-            9803629641506519802,
-            15617613017211894949,
-            1549581732686799748,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4562696216384979405,
-            5157389929938558197,
-            13804582254210640263,
-            930081947598461870,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1088681697995507342,
-            10536353975969477926,
-            10275978286444950273,
-            1773555917288846032,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16316054634315387667,
-            10969210030801084361,
-            2352829536195180848,
-            1401938018049899379,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            382109140812508477,
-            3681181498503561893,
-            5122031547070633811,
-            161927299802417466,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12437291399789085415,
-            4496085692799913443,
-            10004379394161406082,
-            137612259255694489,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3869439682548394947,
-            511406292585948067,
-            15886789462572853313,
-            2444814562480852125,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11914781937144956702,
-            6969686481822140519,
-            7986819504801682611,
-            2283271608849698985,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            990333661945137240,
-            5809102541661259860,
-            2440104438820292838,
-            1246467196904906851,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5151178199716800250,
-            14691227821759833025,
-            9967794246007955189,
-            2366484728743105952,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14660098563845666515,
-            13367239178806811262,
-            10881777055502304041,
-            1438103350370970431,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            804292028704888401,
-            13026135986618202718,
-            12370110650495610193,
-            2416593190927336036,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14649698812571996941,
-            14256795583066397751,
-            3378439953204142358,
-            2337641877842116809,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3932307218094490049,
-            1896688642566647699,
-            4388088577565198053,
-            3419738750428067095,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14034039769935928418,
-            12927102128305829470,
-            12037824284984162530,
-            1322772645234679854,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1101172753516656721,
-            5347778065372851556,
-            6214891089640629915,
-            820478802537929119,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12129765213741564644,
-            17327878931977402138,
-            4418115740394437203,
-            622617124615343372,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16747806554708682483,
-            14499074848387223893,
-            11796633332607579751,
-            1114685778235145957,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8930414756028090024,
-            14486069318242400433,
-            6668979055805840747,
-            1573313036912361342,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2279155970397990000,
-            11840096714828270990,
-            18102802936683620885,
-            1105421471075953455,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8722455892132157506,
-            10955665166803673029,
-            11557874579649884878,
-            1240948686222986334,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9260665003256441688,
-            16944593131520986565,
-            13100323876416103861,
-            534434894743984461,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12895776882058147649,
-            3743147351800556852,
-            7473056498139353195,
-            2485137947366217749,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16781510864082812569,
-            12754566155446817966,
-            15313699342695651639,
-            2416841336139848763,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3916229263765867790,
-            14519455004496736524,
-            9572293549405862359,
-            2963411845935689976,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15448858248350930953,
-            10449509170858277075,
-            16128142175417648126,
-            719938419686315048,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5359958357126429744,
-            12410848528790788769,
-            13961590235152326036,
-            1591513346071389706,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18235107019623307986,
-            9808882822869719194,
-            16260648972478682985,
-            2889435358614347699,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11685437698325928171,
-            2759569023105336340,
-            17925280805945291105,
-            1370684562596889538,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17007995034501689805,
-            14346979416121524926,
-            12154765583797728633,
-            3080567998222193576,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18352793665100098329,
-            17848291613077461655,
-            12458714404097286467,
-            2431525303786767957,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12839395754097856011,
-            10893909843144640129,
-            9169521593332686206,
-            805570061138700695,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16672096468345824918,
-            10677742840784593197,
-            13740531549118348219,
-            2289954881776725738,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1059182469348612897,
-            11980352586763557215,
-            9438400612766178988,
-            1323275177783774310,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6625518424327698235,
-            7390043472665863501,
-            5471368993615022355,
-            2835941856304223862,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15948955231061114407,
-            13917113556096168590,
-            15488361705641957797,
-            2308019753402695285,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10163471007338954284,
-            17221479825731135613,
-            12685785946735683864,
-            795489349160008088,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10598436412263450806,
-            14995835322916859306,
-            14036224764444764801,
-            251976069201621838,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6375715296699601787,
-            14834662983313803814,
-            9066192678999854620,
-            999435910877111613,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12569234509350033608,
-            17812205007580420909,
-            13463671273015296112,
-            3043693350074562651,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8552156740772186460,
-            16102865695703245898,
-            9007961690837184367,
-            273387587020253008,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15868275490038036283,
-            16159871922076443293,
-            7577643726732107154,
-            540408074542938614,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            258311612616167196,
-            8600441005195867000,
-            18038702422188320812,
-            1258371362229608893,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10510577297937382167,
-            18315465604035412275,
-            7076828017547643997,
-            2212671086095064515,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11164020210284646586,
-            10282340641781739314,
-            9771839111332302438,
-            895748009865995603,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14529246590852779197,
-            16917058533045275496,
-            12023814088396548461,
-            2435024590219216854,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15271019601399969331,
-            8564252052048143871,
-            8537233806545669308,
-            3343980269194174664,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9397185584609261523,
-            1262650438295212928,
-            17128920072347118747,
-            2152266635141144570,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9142519748237360090,
-            11240040033139369579,
-            2432250066455685040,
-            1522754067114797302,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9073185862187755840,
-            17007584591821970211,
-            704768609590967884,
-            2819478643152886789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3622892144923653405,
-            349577860731852078,
-            1870413804513176744,
-            2539019243837358985,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7308383825869923757,
-            45627875905796048,
-            3482209817285717964,
-            2986377247620243910,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7770479175212068866,
-            16319628173107096173,
-            11258935162586930975,
-            1390613127336750952,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3672189556726847776,
-            13599582895317559606,
-            1829566913852617099,
-            1531257296244859317,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2338746889146220090,
-            8753087972701640895,
-            16525763870011719277,
-            1062168749304155401,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13470628517890274525,
-            5531874992229380685,
-            11547946256235364974,
-            1511044079482340720,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12997662220021579569,
-            10513193357019606578,
-            12393918735116173867,
-            1226711833369628135,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12829093540600278681,
-            8787055677176222811,
-            4395814929087030531,
-            2780241965838664686,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            439548184603370909,
-            8828532435241160952,
-            8423278192589444575,
-            3099678400848873381,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            179573797696178526,
-            15520990422388317917,
-            17487008990247916486,
-            3239531779061841188,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17511595654156177954,
-            13498053699182361220,
-            18003436153606566592,
-            824355502463368264,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2902473519149535501,
-            214515365396905574,
-            10664568243610158940,
-            1719179411892516356,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4448620654871760143,
-            5708074439556608751,
-            4272393043410702176,
-            169239052967394731,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12007868294320423424,
-            15774611445298303713,
-            15598581144272039266,
-            765970630747180085,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5842008111505823310,
-            12129612343669325254,
-            1342695627875917780,
-            2109371358348178121,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15329006015142990866,
-            12286476398534468784,
-            9066639316968341701,
-            2219217824828702334,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3803357846464446567,
-            5037515184408560125,
-            11366727662109048753,
-            79180947210804629,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7921948768823872904,
-            1414539048223296671,
-            8577879518956413330,
-            2439754441063473591,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9122426070234594301,
-            11865469219470087213,
-            17428398009287453984,
-            69434355943768210,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8798682608609695537,
-            12713780670515897728,
-            555885482183004457,
-            1232944239163965424,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7985157876587389684,
-            1683595952474848407,
-            5606782148537236015,
-            788518147955660442,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4280242024093528381,
-            12411719619313661343,
-            5410362451252717816,
-            1140286898023649321,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8453399054975179886,
-            3629566126616155619,
-            4170420887007968922,
-            3224352574837350803,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11373507359224167916,
-            8083523250378971584,
-            131797684505013151,
-            3389498298941054937,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3490195139010920107,
-            8336672634957096458,
-            15926509303434345858,
-            969242114500919575,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1293594268403053795,
-            4476829622135370358,
-            9473604964367496339,
-            713232159446272937,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12868674455844209906,
-            3248295066153251401,
-            4608266489087008070,
-            2575442853544596309,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14272071993784233901,
-            16284684504658047350,
-            17112629857275483657,
-            494013700781632946,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5965026586118562974,
-            10725026229155230555,
-            10791730964727150706,
-            2672207274535072166,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7415007938970227684,
-            7704711554684898675,
-            2678692780974762711,
-            2849551374305081942,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17556015962245461369,
-            94906294442159946,
-            1349813441978897889,
-            577644751983308555,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13841849648523317042,
-            16090406602512182980,
-            12842508567641396538,
-            2844506216153598843,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            823951026641126043,
-            9200892655254601885,
-            6651886873073322224,
-            2169262556608302411,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3783443116796075842,
-            10818770396455085645,
-            14875910381475249572,
-            3407466061276103978,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7451652460760058803,
-            13804530865998590367,
-            12937244229033318437,
-            1174358679926520069,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            363866944925760749,
-            3191644394823477841,
-            9481214884030138693,
-            2578185627224709412,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9732714948914213397,
-            656498556476596408,
-            8028988874977059579,
-            1084248072814481966,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18053649903528962709,
-            7647051829008815105,
-            17408453199137595318,
-            1540505689051464913,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17709123976300441076,
-            12835102631285035703,
-            17340794213676736741,
-            527292106845503948,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14607779919889534211,
-            2358969578838802385,
-            15249262588062550910,
-            3429487905681171805,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14003802000784369043,
-            16481470230544889446,
-            9365853632338457815,
-            546413313480387265,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11488332070802264823,
-            4095236073189250117,
-            3191838645348508253,
-            2024187959640894329,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2479784538341511265,
-            15956978480767931,
-            18439394471814164861,
-            2160906811876258075,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11652026696061505922,
-            9781894091467068636,
-            10709160400444160756,
-            1234096275350784258,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4238128941688457437,
-            558050445968806223,
-            4456125207143392076,
-            2260629147234234296,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            937202100962631287,
-            15152826195475176761,
-            1014737735256976632,
-            1091094618232113928,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8563142300632835313,
-            12715729559982909374,
-            5710365532598318776,
-            2740139302502854904,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15074937001423408387,
-            14832973063300433783,
-            18083581055011824897,
-            327635988132598118,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11945748342168378171,
-            15213150090553323376,
-            12215406090998905056,
-            2212188514370095353,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14033219742418668529,
-            9395986071079186455,
-            11597922040878800826,
-            474907150699628228,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7651305406661405212,
-            8746629792912123492,
-            4597012470793340735,
-            486182239013618490,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11723066486750823937,
-            13068071156471189025,
-            4821964894379185799,
-            995266922361108070,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7679284992162530681,
-            14639456461350803010,
-            1442927115103337826,
-            689238891483117023,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            198266046576821733,
-            12977670365099951431,
-            12585742278376797189,
-            3055359138868029268,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17833661341568098079,
-            11816662629122724401,
-            10671880768264609347,
-            3225760406809553414,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18440660929469701645,
-            3583613686990497815,
-            7342600739864413446,
-            1208408433336328049,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2012160418256787871,
-            16944388421795059703,
-            6000402858324559891,
-            2184706059652186047,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7391839699882915864,
-            13715032523107701286,
-            1461112402423506938,
-            324204905699285324,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1153586858555891489,
-            18088872810723157411,
-            17716907460262043719,
-            1316158532914179175,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12327298175729293162,
-            1985027564001123875,
-            10594251181732945702,
-            2820297373870053178,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9006202264358028847,
-            18117958719676932257,
-            6997987826809044654,
-            1754064160351741125,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7269991043392589518,
-            17134834007168253207,
-            14990655396983528344,
-            487241596391653168,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3092901884424397734,
-            687491955885544824,
-            8721515658589981067,
-            1901097628667853807,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9746467803569890069,
-            9374569296751503200,
-            9493766322100135469,
-            733290959402142986,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12835933081621828661,
-            12515385806698688213,
-            5645759765569356224,
-            2754855009527281928,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16250129583421689198,
-            14932601185657988190,
-            5206910625201805412,
-            1846246950031243334,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8051474334012486178,
-            16792238944798348691,
-            12848477177325211543,
-            1996463063848218801,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13375606022987998682,
-            2700023766653058755,
-            550159102250551998,
-            1410662335576965102,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13630764252936226331,
-            5456742141078013641,
-            1243454886396383595,
-            81768231552576986,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13014135700373259698,
-            13474831610560634109,
-            5094099861829276873,
-            2387327477681862968,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7000277812092720767,
-            8658167524695809425,
-            888550797677007653,
-            496474063585955259,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11294872533952918533,
-            10934691761560193749,
-            6083005566453421635,
-            1173299851161796646,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12868778870195286490,
-            15191125469521307945,
-            14423819945409313631,
-            1482921053695591920,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6128214060908799101,
-            2861455069842857487,
-            4851996623188978307,
-            2594245582113200296,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13422855205697752048,
-            900542482531065370,
-            1512556301331618544,
-            2867609123410161896,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C12: [Fr; 156] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13981050502672652780,
-            7591814228325986568,
-            12505430781992165858,
-            249593578680164044,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2900866585810079239,
-            10057133929441900868,
-            2123204971335613161,
-            1159313787782861760,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17050722517905489351,
-            1956020721703908099,
-            14420897372419147568,
-            1133089014253164033,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6934760843544612222,
-            8247150444617025101,
-            5393924072326095176,
-            359161652056505942,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10766347453726401718,
-            17560837278554124058,
-            7015097493991293111,
-            3136127029176044789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13788846859889624645,
-            13541903690523530517,
-            5372470627008135773,
-            2615610886897803666,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            946890469278073381,
-            6412133429056289228,
-            14988368404940164506,
-            1829487395599611628,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11447260558088510732,
-            13191131161994669141,
-            3837574796806840293,
-            2542333405424276352,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1780671081695077187,
-            17126399680332639650,
-            2319240563753144826,
-            3170370656425360945,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13057083388279380370,
-            10346674685796554472,
-            15244919245071102587,
-            1361347322853267425,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3033947674170921811,
-            12150222224073942710,
-            4405950850675813437,
-            1736972031126090674,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11058119578442952640,
-            13207580398504377421,
-            2265640444768320168,
-            322832197522592744,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8164379055631146944,
-            11921320910951560962,
-            11579584173649088053,
-            2348014623914054808,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6948140395611135764,
-            17730966081149923958,
-            7244622713006755699,
-            2001272198516510890,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16239808819342970633,
-            14113801986308176980,
-            12178322995502622443,
-            2996649966451917639,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16689068844774182619,
-            15773047333302813340,
-            88137317437524250,
-            672220929547981088,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14214556203390930331,
-            2430188676450377486,
-            5239927007715816297,
-            220496672732075363,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1172803129050768724,
-            10392208104981903494,
-            10067234281672249332,
-            1109866242758717484,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14125989770983306008,
-            15711543930578576123,
-            16234783589006233984,
-            1700251608717289903,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2791400297430871932,
-            3855467542075124678,
-            1183232822245794371,
-            2477314235445018175,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8579932370992900018,
-            14216808690994439534,
-            2444205009260142440,
-            597819656959162062,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17646951754591177071,
-            16490854104032746769,
-            12218497209925390169,
-            2653800313011499001,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11711646926812736438,
-            6603533940203217246,
-            6570257730769339316,
-            720551680353405118,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7739032075653864278,
-            4096930383022241384,
-            16006354984897407752,
-            1389629085138886308,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11068568902929016741,
-            1619514093625596231,
-            1456586206230970973,
-            2575330798075808622,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            507866794787552269,
-            3049744972565766480,
-            9804752135256304281,
-            2067181146465501468,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13423211646473550300,
-            14978045707274908918,
-            486563522340419248,
-            2679216839303652449,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8224961110513340082,
-            5363377912980413715,
-            2315180800211655378,
-            2320059874055357208,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6338899266918310569,
-            16416698473719589519,
-            9349839940137894736,
-            2429599428317561480,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9585949041239348488,
-            2942014536168450220,
-            5876786707638211191,
-            2652020540271250016,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1557428701041140911,
-            7035516160600749246,
-            5745053666330043758,
-            3449386883344968700,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14142314793453769394,
-            10280599413505464116,
-            1215274351199509424,
-            2189076159156907594,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13024857230486187569,
-            13095534651940856693,
-            6496905946531788055,
-            946181411403771015,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14177637691482513120,
-            10553393729937251317,
-            9599716893034391289,
-            577671466496949306,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4867962264231687663,
-            17409833689675676770,
-            17642067467832003506,
-            2124701948926462566,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            405959071598761678,
-            1995916875641992592,
-            14856571171058095437,
-            2908848534615132316,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9479223749296061192,
-            3529738728573920205,
-            7668159265544529575,
-            999922084890148134,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16607675877734305180,
-            1377503953022282543,
-            5965139353665710091,
-            3361746457198937711,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1686930628089491162,
-            12652268191683354742,
-            12343542947720417166,
-            325855174501774571,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12991340418004765351,
-            12209687285842000590,
-            7698872507799879977,
-            2264292791578264364,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2871633025280398572,
-            16942436038336290725,
-            17542053987187824956,
-            2985957520806810623,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12605955624486376397,
-            15220910800718656521,
-            6042547730385337348,
-            183191228990163281,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10486925411250405146,
-            13849562340627351941,
-            14606480608893582393,
-            2148804174657933060,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1265232158717898342,
-            17152708879180063657,
-            14457623587722699113,
-            2758809539381468618,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13021079138641493838,
-            15165890919137396426,
-            14033693351688064191,
-            648406895785783129,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16565038547583814659,
-            3923940098935312035,
-            17961289316108147047,
-            2803476056124107525,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11689224385926035250,
-            12868879941263998914,
-            14113973853176082072,
-            1285295321353822261,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13515414693984049238,
-            18385212710282537474,
-            5785767331993077328,
-            2838942063270410074,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3635774926425381377,
-            10639607226415952023,
-            17345057706469783442,
-            429146040606653350,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13826570077214759793,
-            17690744568371916899,
-            11528053065323538722,
-            460331745201536886,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4739202412758022192,
-            7917528177358791255,
-            14725211049341844620,
-            1595147545521736974,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17839871085558072752,
-            13935916925075991674,
-            16794010530346150559,
-            1291662027414244522,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11981220251135156748,
-            925753798717958957,
-            3914382785612858938,
-            1029063974802268359,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7291795526759630205,
-            16906458683808194586,
-            7732561284754887585,
-            515875911983122449,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            458100978782740522,
-            342967149287654472,
-            6288416013575821778,
-            2582986120655127938,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17932768235794972751,
-            16369186394460305009,
-            16559260530379023953,
-            2111514337068275708,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11001259093249515970,
-            7133077748997137007,
-            15363372813109994938,
-            648725050262777810,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13505888186439163665,
-            1482091050936359459,
-            13646147801753510587,
-            1554843787037963576,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4461816873214607624,
-            14641018255990672395,
-            13188343234708035951,
-            2863422552734979277,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2311859867687459808,
-            1169514440182008128,
-            17008885615961924432,
-            2396541960928844595,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15970943197959945304,
-            5667484076596046962,
-            330876834052054932,
-            2036572630069286810,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10530309435409489495,
-            5712314498310563227,
-            6630321458428286115,
-            825945412654045393,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15702868117282154936,
-            8246528409250845865,
-            753204812106033708,
-            1411493946723626119,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2828830495575828302,
-            16605640263091652688,
-            15178485671049344343,
-            3026385451636159725,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16345519148581338414,
-            5530945612313527554,
-            18170186678747597760,
-            561284447749819600,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4500783372380408955,
-            15271054583548275737,
-            12635239263626028094,
-            1625278026004214001,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13489851589579218331,
-            14425402404900569914,
-            17783677046651342464,
-            1008438459721680700,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11316325729146827432,
-            12683461716897924037,
-            14498224551427189360,
-            1153578026057650991,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5772080598937658035,
-            7983318404931207012,
-            4268196476208136372,
-            3402635611304295680,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2856158906201910693,
-            11728406004400331877,
-            5390963956398906277,
-            2835110822267723059,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4780603250893367767,
-            7454445810153157367,
-            1036777639934683471,
-            113031374773682547,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12918208460849248013,
-            4855174340321509007,
-            2255225685075861953,
-            2042160947529965497,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6576027339577312275,
-            2980000184668543349,
-            6418873061280777271,
-            3196733635133501660,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9908509536578380997,
-            11929938614901511392,
-            2778704072814042227,
-            455794970713978513,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11748385923924298742,
-            17485429947089048494,
-            6806534368621082153,
-            2966998146746699716,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1083848228349726322,
-            18241605883173343053,
-            14186841618539585946,
-            827486572604336212,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15989187379608323754,
-            9953293660822343014,
-            4628692898349887587,
-            873656943403765223,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11199842469664820831,
-            18358232135247172461,
-            463271448437660397,
-            980893753046023637,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6770022575018075584,
-            3866358401372127314,
-            7791496043253302417,
-            364067235933133232,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14048348736337409550,
-            1516658461359478964,
-            1357803299297234712,
-            2668801446736175904,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6580993166565083083,
-            12170056004644239525,
-            1734428176704403214,
-            959004285076895474,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5816961825040907846,
-            13119087416875290739,
-            3130387507316876264,
-            515633044195966421,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13677889479988229037,
-            17595874899558769486,
-            4310849915186574550,
-            495641212783397599,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12722161849253846063,
-            9372241694493085477,
-            6847937795900539245,
-            3139867469312801086,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11705121701968839040,
-            735344886525051633,
-            17623119060737415703,
-            2471019042786339222,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12749656610588795275,
-            8284501923823629172,
-            3206844632345407586,
-            2820156647098854444,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14622999176607934006,
-            15407464608939113437,
-            17986092289163175812,
-            2139767862003967341,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15901873767860305945,
-            574961835969154230,
-            12241219290975073018,
-            857094602119584110,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13356833802958464275,
-            2784982004191566453,
-            16420662567555399082,
-            170459171309612282,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16985201819249583293,
-            5587153191917665294,
-            6420816949941983600,
-            2252968456389205683,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16986754448569947137,
-            2521613531947739130,
-            14001504300210252340,
-            2865265766477368006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10854809533803971633,
-            17948514111577677531,
-            12795883971859739677,
-            2750905456024554157,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3245824159946579689,
-            7204180634637488836,
-            5780134187792127863,
-            222841881270107897,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16006317477020201111,
-            397569558672825219,
-            11132364724939451991,
-            2447438288952784245,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13349895784696681566,
-            4197953232837743925,
-            10244152753786734216,
-            3168801467849403987,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16556754808925715174,
-            14686748597336087406,
-            3240374771153157977,
-            1532668233443106066,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14283340731121175452,
-            10880059823640727985,
-            9913855660558825507,
-            73811407382657460,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9960698250301346514,
-            6312411694974098306,
-            10757616859678013554,
-            2135055076066083289,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10948259176045589530,
-            13657471617878732568,
-            5528374229257550211,
-            99465928690782318,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15199656465805158535,
-            2548431442139452334,
-            11834852571982719251,
-            3248711132358292770,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17577458642924874412,
-            5692077344463238523,
-            7319015011878653373,
-            2203772518979785695,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6465349236042940034,
-            11681253987325319851,
-            6026083811810243907,
-            1384876073218407891,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13488506234961285992,
-            7061601439576064649,
-            14754907407176996391,
-            516300484172593909,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13796315181707289012,
-            10134986562167545405,
-            14486772055590774767,
-            3457940009496268554,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17712733678093035933,
-            3203985213620213123,
-            5602802869969644631,
-            3100685492339603703,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12540381209172604984,
-            16644703570152067229,
-            4374067184955103215,
-            1774700536398995997,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2687619111429652535,
-            15349564217805525292,
-            14636792895688755940,
-            652748506248810195,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4288934916685978075,
-            15792219395915417008,
-            7543904409346364182,
-            1961675793601574489,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2484168204817280416,
-            17108161037936565685,
-            17672112562439744574,
-            1482968449691737552,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1651295080593844880,
-            18278576478911590943,
-            14180020506592856440,
-            577137117857053749,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2313979304819926819,
-            6339683448028170491,
-            16220619398782590422,
-            1743814827607899825,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            150773919833578686,
-            7648094319452285785,
-            11653710166922421046,
-            1184359936851854926,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12267250925941903086,
-            1583899699718210584,
-            9645038396629496886,
-            2692766108975803595,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18055701149688221468,
-            17578275857781658931,
-            2882122087106754151,
-            455839220361259972,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13611467114830615325,
-            18099864587017141778,
-            11654574366955483820,
-            2328312772793903294,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14780730170629166486,
-            7672148556873270838,
-            11901640061886293442,
-            1165103631696638140,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2766970413293059764,
-            13122433157643459552,
-            3661341751914388659,
-            198938963047150215,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9576719367730364002,
-            10827263488284617398,
-            9554923549995938651,
-            2510095790129716006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            56849439318707645,
-            14978216344088941211,
-            10901832751537887355,
-            507803238209514055,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6940058670580554298,
-            3171670710967278997,
-            4129346515970526202,
-            2866323386176238391,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7136066101214758374,
-            5603396170384976897,
-            8776832161684548096,
-            3171327056989655321,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3275139158996910661,
-            8907789754596157063,
-            8144905586940463758,
-            251582947406953567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3174823371724002691,
-            10904251397662122298,
-            10730093463603737337,
-            2680366199151157698,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14236204996971461200,
-            18215835048369452748,
-            923449890138255540,
-            2834912303954783316,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8803657972531070598,
-            12708576503602566717,
-            15943281251483824991,
-            1268912400585110897,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12265862192164624412,
-            17007480039288295548,
-            2555741524105408585,
-            1114209251270429397,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6474783674603091968,
-            2218976230395777039,
-            15518672679430195128,
-            1166828491102639042,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2969518776029160418,
-            14434693749197992040,
-            8251844132043701314,
-            506664434610708221,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2235927047531005700,
-            949665277098961952,
-            8559110697285751298,
-            1426577459538569604,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8534920814618104627,
-            8308126496216912606,
-            16508680150289880868,
-            724927009635475464,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4014763016329059917,
-            9305417199505537727,
-            17633058607765147899,
-            984057308773472568,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12278580322036527917,
-            18172454951682326996,
-            9054168322417736047,
-            1504552344430135856,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3563898209521792915,
-            18132380737354997999,
-            2607094739299132313,
-            1203902173773059889,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2515912406334003738,
-            839560919741519226,
-            3887413076206017410,
-            372647100455374948,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11394086004413489894,
-            5672774173482954475,
-            11442858867867235254,
-            2374206363158454635,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4249072844696740967,
-            7229336535617830071,
-            8249563207899252650,
-            1626459359469284580,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11574031038484838295,
-            16458969326922341871,
-            1512942763213835156,
-            26921466159922758,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18067348535291113547,
-            230311252714953280,
-            13571592229554845617,
-            2949658767262323358,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15238323632820189381,
-            7014368675473400772,
-            17408495094682856030,
-            756490998022674640,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11119904670944427047,
-            7817487404813459230,
-            5163093814997706824,
-            2779101731088642902,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1349355438355885106,
-            2937251829673570687,
-            13098373178513464386,
-            213695157060063671,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5157254819455294512,
-            14785047983089164953,
-            6264765287011004889,
-            2355498066995962827,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            101276070954545241,
-            6934777483361431928,
-            15004084374368443211,
-            1983786551277878305,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2433663064416285880,
-            6137090524826924234,
-            13162558866711883580,
-            1455127840320729459,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14243615200372155942,
-            17288799118939915295,
-            13976750648812833667,
-            1158777088606740224,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6599912373870693207,
-            4010416257799198533,
-            6018960426766855684,
-            2239005260502285781,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14052523629614774045,
-            12406638768959008136,
-            525731026753152473,
-            2877951589391764926,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14029741626757108508,
-            13387622146318019637,
-            14883328699042482544,
-            1086855009092245942,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16704501706457846389,
-            13492490048133232009,
-            15811710982746256542,
-            3090938109668745218,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4530250222406069200,
-            17712444988342517534,
-            7945936798628624822,
-            649245736336213837,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12903160226985044067,
-            14833596402214828321,
-            3502642462034506066,
-            383645656291310322,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2879628304430260764,
-            12302343809209900767,
-            15064777694495645062,
-            1379661871325434943,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18403379082334470069,
-            12384270139354036042,
-            16801620416514842173,
-            1085857153103827931,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8846250939177430344,
-            10487826990473915884,
-            16251052329380435721,
-            1857356905092464710,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            372162409027460034,
-            4263027693754510682,
-            15234021386444852006,
-            1252596973621723535,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1361972755075939081,
-            12221734635967990359,
-            10838455937310829332,
-            1457890103667543380,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C13: [Fr; 169] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13421199679270775944,
-            11034380207312194643,
-            10895257302477596451,
-            402450625496745656,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10726294660778964028,
-            12923618955761448761,
-            11128262942886343578,
-            204755409796694246,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3401875470100735902,
-            16029426252723083986,
-            11235755233436736600,
-            2056912676053778673,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18164143008600166139,
-            8939373258007848946,
-            5346705301960788205,
-            2089060358602503557,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17620630907312873380,
-            6206634067424926595,
-            3092246359983005121,
-            586720594197800970,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2128278450963284332,
-            11471557298416384067,
-            6159034021951946537,
-            2682560629659849880,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17101745493577844124,
-            2169776531141975232,
-            7727695143625291905,
-            2302981380064951448,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12525889751823613774,
-            11545412998145486525,
-            13519548439293703025,
-            852720436711654519,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6111010770319036172,
-            11235169394665717157,
-            1089084872597194618,
-            886383453647548273,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5044104847644504472,
-            4739208843771402775,
-            10496123843579345490,
-            2953279144530287953,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4137400959014494103,
-            8460636270806095729,
-            11000204453945286838,
-            1700378865104062483,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4043481188625330994,
-            2177767274530828571,
-            3240773182103584943,
-            1705796518772783481,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2839433951508565108,
-            9238314278449274364,
-            5079872543003891245,
-            1495891668572372902,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15188913937893274420,
-            15475124080234328611,
-            3679204194854771297,
-            640705244107618721,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1326248470427804847,
-            13927607075174281575,
-            5102840078396771347,
-            1839859376796301870,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2415923591001319193,
-            96565592140958070,
-            11694807130847594848,
-            3259376434787714253,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8820069101482478531,
-            2389723760690826943,
-            10014322681669165095,
-            943607566411746463,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1014492117326697577,
-            18191147072441779854,
-            7646441027934532172,
-            1425140786230993404,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15338921279725927489,
-            10353827919576190673,
-            4267185027849034561,
-            2933535880350481352,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13531704659984520704,
-            5876046764086324811,
-            15397274796485285735,
-            543095807917386492,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12592285661389793155,
-            540607516782861344,
-            3075071048569436717,
-            2634557856081289389,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10233096277844949855,
-            11989034609670243990,
-            18372247925685997460,
-            2897423014065669491,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17150223290261843364,
-            14820361022567735841,
-            1593459811809513002,
-            1566093616500412498,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12170908082937779659,
-            10360584225431947286,
-            2039067968424738924,
-            3475034381239380513,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12683873519616054870,
-            3699344846716662211,
-            13228005156999859597,
-            803233542486502056,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3483498901289320031,
-            4392285268417156884,
-            14371347447760353468,
-            1649806315402079426,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4310288652834933492,
-            9951030242060615904,
-            5870849014134592031,
-            125639300479003265,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14407777785985181958,
-            3072721715995497801,
-            9473529030872585890,
-            3093734266284635698,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12131475829709474013,
-            11783795333409436299,
-            6865691944586105539,
-            1541263756646416166,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2355095003930945707,
-            8361298277658568847,
-            15054717732608352352,
-            1101496106520199734,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4906283599463922348,
-            13000146526733241235,
-            15023785300216304131,
-            1710942201121810259,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7421987426606599353,
-            14075996156256079755,
-            15869830208529471528,
-            3201405112124095488,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14614593417275693852,
-            7186286572266456233,
-            6605477632045382886,
-            461285976736941888,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9496481851467648121,
-            15695643936049670553,
-            16953185863763658157,
-            2426638677053227829,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6194013343442031067,
-            2987039461340047722,
-            6496448678917065865,
-            2914614035962341092,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5222007691177894030,
-            5649889330541788434,
-            5234277339981409864,
-            1819557443064202864,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15687961961971501548,
-            14439626832132866879,
-            1440545225360254293,
-            2723375430168232460,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11721506017040163163,
-            5955652327092261696,
-            7869092072491808430,
-            3010637456432741660,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10061517086339783608,
-            10430318078994253118,
-            12512648857082800636,
-            709357818880066792,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16247167947809472023,
-            3207611833288829877,
-            6828125131066173485,
-            508754970795690843,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            948861381899875111,
-            1268444577468371349,
-            17896701715522435643,
-            2685057584875974213,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10979586293126103729,
-            14924311576318502346,
-            17472558469500216436,
-            1519314774968821829,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16488664790931593553,
-            10864989026557319423,
-            8239804794351445840,
-            2277905065742977363,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8724422378053862499,
-            17320749745421814332,
-            46735485069303008,
-            2433944192393132172,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4855026889246383658,
-            6506678852335059459,
-            12310016556998740740,
-            1320836733899452640,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2943084446411636561,
-            17778003710557164430,
-            16408031121498500969,
-            2706288303300986803,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8851637640588071951,
-            780657974426799723,
-            3846201119699582475,
-            2098764632486842162,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12430700489378514641,
-            13622435007733315542,
-            17546922435563195212,
-            3191044913856866252,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14279181430467437095,
-            10278959096863864539,
-            9178898718772640788,
-            2142809842964106859,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6374778000968417569,
-            3947898445249469033,
-            15122890020207321348,
-            2627207997786765202,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7788685194133267090,
-            13986669277728279571,
-            7330870372372238485,
-            2326836583808258615,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            667697996263388816,
-            10244277348637103587,
-            4289433238737836358,
-            2897600348332115312,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12767967322887796155,
-            9292229016223382329,
-            5046920313945616723,
-            611817971948288489,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8791111507827299487,
-            569587054190928124,
-            15626271986012685646,
-            444417854800641720,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17075756656697633656,
-            13793966788892483303,
-            10870188178404996624,
-            3050549002341027323,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4027452856645947177,
-            16796811760158223189,
-            710583387353006947,
-            1560290177759416944,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5552755155537404231,
-            5503227450986959493,
-            16672287458580568598,
-            3187153696315555234,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9254350223374809914,
-            16855548418985229911,
-            12682531640533523282,
-            2625451234244932109,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4381879768877445418,
-            18422756364585617656,
-            16444047120045219970,
-            2223854998769612007,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6558636582996810298,
-            11238313283401156257,
-            11059362074227636964,
-            2871308198528531526,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6555826440255199634,
-            7710161995045327375,
-            5205528706366978482,
-            1022183394063137314,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3259798700995209946,
-            11674116064119475165,
-            6935163556138188378,
-            1319190913980828289,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8871860822702163416,
-            5332347289548042314,
-            1453138479412726017,
-            2305599179320927190,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14743888068655436568,
-            9295018601003304752,
-            6602947793151389235,
-            1725842526556573888,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14358084897439979364,
-            556520794061078244,
-            2047149914897906156,
-            803138556263248213,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11704052380959159987,
-            9083905907545062690,
-            2726482425306657282,
-            2812128955412339156,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18153593734944009551,
-            18435994155423044694,
-            16554046639040772686,
-            1747991018517807763,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17794322387242162786,
-            16701638755569456994,
-            7133726461702514760,
-            388077556734088010,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4709777402463489410,
-            14627813901184379474,
-            3716597583796438883,
-            37601989669871744,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5766922702462993645,
-            3511543807789958445,
-            6830084304386810427,
-            2355616450167636421,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2230229021990389335,
-            12006180098476188681,
-            7329904025869843165,
-            3008011362863574831,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11975977501925527889,
-            5361023556483217455,
-            12108624725833623409,
-            830426099045865638,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14674094180972113611,
-            17986869505560862814,
-            13991921861196357392,
-            1454478957415002203,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15728412303718799899,
-            12495647870392602087,
-            15759717920437950031,
-            1723803464240810546,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15076050427249646375,
-            15839713449216305505,
-            11662658682644609228,
-            888182029607303071,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6346729698319636148,
-            14354861879439639158,
-            16888375997849490976,
-            2583540536234387488,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5247839110554043428,
-            4306354091450139656,
-            3132401541015072116,
-            2337990018496000622,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14013149824737683847,
-            5096479033598609141,
-            8203816424460693587,
-            2666803195159665814,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8334122020042116716,
-            4873954605925109973,
-            741830926577916146,
-            2992362202272981544,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4977998443530306694,
-            3171308319131929924,
-            6248477395941467508,
-            1733330448717975634,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13914683206327989220,
-            327425696808263920,
-            2875389005005022174,
-            2577819434941841961,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15059629900396443739,
-            18193734134704189148,
-            9716310867889578223,
-            3208448612579487727,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5591578384212065587,
-            8561097155005291381,
-            13250238338627355234,
-            2422924612406745868,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17696654565846910916,
-            5559827157495848995,
-            4015246570590735485,
-            2788350753896322373,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5662328084201894658,
-            3989617374815847487,
-            15491701236965191919,
-            946329361746827207,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3024159453931952107,
-            6878905374409660861,
-            5654235585936991702,
-            996688663012163929,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10640678107550450978,
-            6931360100926189010,
-            15667304403474053412,
-            394866340182368637,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15995756590254296006,
-            4151052182146660761,
-            18092420043490984891,
-            362178905222936432,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10323545380117992236,
-            587219029478680920,
-            4438957889655243474,
-            2840150318753727130,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15693320063535522365,
-            16674097734253571156,
-            923840275628124377,
-            161296327156238297,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11253320471303032924,
-            13927452811024866991,
-            5708135593203498848,
-            318109162754393036,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            555790000881206164,
-            10998400151681537750,
-            7089918371600468976,
-            449374768111407355,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6533568138145784029,
-            2566667809026544130,
-            7751521927495664757,
-            3170239209883647276,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17526761175166193552,
-            17843256696937112755,
-            11488216299221845477,
-            2545896823392326457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10265550771373533692,
-            11426826204618974350,
-            13860308057197737249,
-            2825919527702394103,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7804963332352905506,
-            335979466414765472,
-            10320661803028040357,
-            3334566373186617814,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7498307908972038790,
-            4360277882140951046,
-            2463645095188342105,
-            33541169314241852,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2406323732641366491,
-            15400783571467762413,
-            3965919301341000198,
-            2625336727366597727,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5163133762123053855,
-            14216860963161832825,
-            553866570613693326,
-            2242931890780291886,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1633858836374952838,
-            2610270280447916442,
-            4951322360418965609,
-            2442130226997181496,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11310320054848543503,
-            14397670031540834666,
-            6896643773349702227,
-            3308515955645104028,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10651896270978578952,
-            4143579874065759138,
-            1455656175838621249,
-            3350940630821302748,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16104062525145874585,
-            5130766419796822408,
-            1190604333367489327,
-            543969447205438510,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4118060826244708297,
-            3373051226619721254,
-            8333996571137665738,
-            2165524569165846230,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3430555380244397395,
-            11585668017318086574,
-            18165573952995337207,
-            317695525780557790,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17861622998482189476,
-            2878198171339026175,
-            13873557262856810154,
-            615194157179859762,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11913883517639340040,
-            14795201718051254037,
-            13421048000742426512,
-            241693584574902748,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7999647341180703137,
-            15229829034115617229,
-            10731143371414202225,
-            2174072385376012699,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3762477751611894711,
-            13033887488637406087,
-            9623204619284205111,
-            969782719880201116,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3547219243445937796,
-            7130588171704377713,
-            13404523518179026609,
-            1685246304868945661,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11153262845141719869,
-            4759975476412466633,
-            6865008114906618510,
-            1361774865723762876,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15704589521379366464,
-            6967791219773779990,
-            5588925105200678659,
-            2168690921424509814,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3735028546953448925,
-            16094150873959931189,
-            4340538810660480014,
-            2429768681668276896,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17069951138330637333,
-            9226973232886965602,
-            10056000989910916779,
-            1749478281309489801,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3581898098405146985,
-            999636443720713606,
-            4181772322851854025,
-            2868976670459071925,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4659910643697490733,
-            11650766617741128332,
-            4232346185398971476,
-            1235355473089774548,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4070674842402064278,
-            14095315944403453979,
-            5520732347563615585,
-            596708366089721396,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17010681660374988929,
-            13520376285952246042,
-            13001694121715201824,
-            2921344327753462729,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9991779182292358318,
-            9224030944296374719,
-            2709882999558421515,
-            1587217181538994595,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4044139675203018160,
-            12658764867604147235,
-            5584117623221088559,
-            890365621205508542,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14835340731613613305,
-            17737561916182677861,
-            1185179863203354921,
-            2298236420855346230,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            707406786842477059,
-            3181812506749752181,
-            1894113875333662428,
-            3261060941617577756,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1767013837010625620,
-            15126150978058285519,
-            17401547487983636504,
-            3157071739198191103,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8599913356694062753,
-            15901021922188747458,
-            3226774401336022444,
-            2528468921599941188,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11867033852148582742,
-            984637973148180437,
-            11216645707598302288,
-            494875440948213622,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1097393102334143267,
-            736370263119754143,
-            7788679928873177729,
-            1126540873750329383,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8500383966302371116,
-            79208145225334281,
-            7209341930950090648,
-            753171528744521027,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15088903388294573993,
-            3933859035808602867,
-            1458349127184254653,
-            1470153686808836115,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6765663974116992053,
-            17569531999813536492,
-            4561705037115072128,
-            1654823672013624225,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11249253739786310250,
-            17130598308277554553,
-            14305237297759985403,
-            2178177014330959783,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8354132736008956698,
-            9384291641254755523,
-            6210420566725081967,
-            2036086865577534424,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17382927954849394431,
-            733207115698711908,
-            13545487964637177198,
-            3264143386952731791,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            171250541960482211,
-            7715320649046366206,
-            6424591633195595299,
-            556231455796889937,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4281055935105582065,
-            7610586174203723731,
-            12205035407434405877,
-            668429435082785288,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9741566692298436463,
-            17621377157397456998,
-            5843321653558827208,
-            1491610894897759894,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17491085691952137204,
-            11164671829539097240,
-            7777726575579522060,
-            3251748746869852232,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12882335067167498229,
-            10986568065250697991,
-            7340628662803417856,
-            2884533645657411264,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6985242621233445807,
-            13983061726778911297,
-            13002863441613199550,
-            617847433596724421,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4335186967939158936,
-            1203826258027907928,
-            16729890628571709070,
-            2584198779285398151,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16390016185336636599,
-            11497902114357551392,
-            8807319105353150910,
-            2589946297349636364,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13455911748308135000,
-            6689730224224216902,
-            6900324084819977580,
-            2900734136473300387,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10710512468306206382,
-            9515752203799720892,
-            151098058660520137,
-            2055930402174510973,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16975471987221714014,
-            14713273143423534573,
-            6976528250525992644,
-            1383194306693201729,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4996366768617818635,
-            26721056209232090,
-            15552289679569031722,
-            1650683680461464903,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2975459445074099483,
-            17038932708836187197,
-            10937613627592153065,
-            1096480120615959787,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16449922411566935547,
-            11636748874831297031,
-            17480973402633569890,
-            2677258682781624248,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16951230997364519853,
-            1394700887642324347,
-            5806567830653542987,
-            2131721481811520616,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5013638678679328307,
-            9452865458742194868,
-            16677527393473650028,
-            1221061967948767878,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12844125153671514816,
-            7368736725016716624,
-            3999899301284984409,
-            1807030264991680814,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8861453913508419928,
-            4607491819933236310,
-            10499255533314009462,
-            1285859727114421525,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9917739081481611651,
-            17227747920630554356,
-            11757408429227878577,
-            479347235378737123,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9984960059663632915,
-            9287303677756690959,
-            3519087619202553877,
-            2054117471378601717,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5672063349545121935,
-            4084968687981822900,
-            9634360568484533649,
-            32439923366888052,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16105214546192373646,
-            17494491488618871047,
-            17143052268414031670,
-            487141740145239064,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4820538537308636124,
-            16476070590676228551,
-            9889820689355089434,
-            3478632329565619155,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4095493196309220766,
-            3291063582891027525,
-            3055441838410883835,
-            2482328091631144063,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4507883141038505205,
-            17502679046257394194,
-            6068166981041016086,
-            763364254889161794,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8707826735843187648,
-            1913903804504195920,
-            15476363777747536421,
-            2036450958540477809,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16625825102975407680,
-            7286405069119175398,
-            16970589281720134801,
-            3110557562583044225,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3738498412151343414,
-            14369907881247747913,
-            10872249930903279000,
-            2055609834409366340,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14626854413168044545,
-            12171436082303269556,
-            14966536949463589654,
-            1597216513542601811,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8082758488295512612,
-            1841390245367341854,
-            17989597894497914482,
-            985261668782604030,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8111487663902532659,
-            323377399932224045,
-            7656614051922983931,
-            106086538196956462,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9029397358191234161,
-            5337485547341302093,
-            11241522715148177756,
-            1822574437242472583,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9435081978964025370,
-            9669769643451853143,
-            283112665766659699,
-            2259267908198133882,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6621496429414848402,
-            16425350286569783154,
-            17000151614209377410,
-            585508040995356329,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16829190330256959139,
-            17616222893513501891,
-            17425534721805999116,
-            2681391656394451515,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9177102925729070741,
-            6089426153097389340,
-            8762994004412132531,
-            520955660156183660,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5904990853848834008,
-            18380176925154530367,
-            14182041569285687254,
-            1012775997968065238,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C14: [Fr; 182] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8132885962533294370,
-            8299329746277655281,
-            64588276546245712,
-            2386245203902079158,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15391253918306527830,
-            16098135415062692154,
-            1797463804452548419,
-            3439629596503860781,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12142453075971956945,
-            3265662358419929575,
-            12512459153227026196,
-            1320041515180582810,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15754893156040711343,
-            6000690401460915707,
-            6894247094953907429,
-            2806395622682502585,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2441515505960944680,
-            9076925521053117993,
-            2245902678578834478,
-            1442259315697833242,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            613077407381602984,
-            14626753569054260685,
-            13730404812873224513,
-            3272762913784967099,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7326016268128387732,
-            2530693953581179333,
-            7062823497544395863,
-            1756060980894232500,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4276478481198088767,
-            8749982651734405050,
-            13779201703581528019,
-            2765162807761479572,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3231605730844698248,
-            3026401357301641314,
-            8673135831716973635,
-            1945104108597679906,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5751107340471465317,
-            9167575849871810195,
-            10159949266026224032,
-            2593147013550763184,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10226534948913306493,
-            9203976103916769215,
-            15091920142960761190,
-            2951429793133233640,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16742684394771739141,
-            7006921015250880992,
-            9500165072247606398,
-            2227792786856350861,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12108663256666104827,
-            14747566959903305006,
-            17780380279312399354,
-            107109007947763165,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4739686654523863400,
-            5515028221521933938,
-            2208110677401299161,
-            1286603221920401915,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8897124745619962676,
-            9330972957921207628,
-            9741618834506412707,
-            2660433213852126135,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17218276555192017739,
-            2169238439570621682,
-            12216957703354365518,
-            2456787344883547841,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2756582588857580159,
-            551272073513521720,
-            10373785411709711154,
-            1926345417998430618,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16369831614901364365,
-            12900027699758320939,
-            5270639532054496679,
-            1198044277257384427,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16502287307025242994,
-            12340995457690463189,
-            13067412317677812389,
-            1432177793816193523,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7888419795617882621,
-            3920102314850604966,
-            5618029941879097183,
-            1774645606984833934,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16989540452276608527,
-            3905928367278270124,
-            5434162406415066653,
-            991951067175526921,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4144775015686459988,
-            9657727090738575929,
-            5005487312704765294,
-            2426318926290832727,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1114596572622094344,
-            1270745008921582448,
-            10514395097317254251,
-            2042608796190498845,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9209310940544380887,
-            2825285370040094189,
-            11196713645066387015,
-            1297957822414496485,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13829085156145304463,
-            12076480054100438282,
-            3973070349134327654,
-            1578726636997234192,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14524098755008353640,
-            1145015365821818365,
-            5948939395058624448,
-            2505254785678164079,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9740654688479172520,
-            1455935549591225867,
-            5884915030458366259,
-            592814138366343842,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3781382759694655991,
-            9125729394206175352,
-            12793154022116361037,
-            2537436692719368881,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6870567309253054337,
-            2750335970432278245,
-            12683107189638236292,
-            1678477137669813797,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18334604726995479487,
-            4680682582112544957,
-            1327919203882365048,
-            464120993415353913,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14452530999384334537,
-            9788847969104186328,
-            7873999094502119175,
-            3015741660532486598,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8972481973012548789,
-            15215519559584018723,
-            1668909663575837691,
-            3447341725327657618,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10689652360487518066,
-            7193342272668415360,
-            10419708170217559314,
-            2621507578424326619,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1292279729106410580,
-            9311648494618633075,
-            8312202729542577737,
-            797203797613194617,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4392446387482214006,
-            3983341693871682556,
-            735152701457917151,
-            25957204582371511,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14449058328742796634,
-            2708553674688802685,
-            3503739281307662227,
-            1942358102488373860,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15728283302332566033,
-            11511802674698729628,
-            12231060366827551764,
-            540530600597653612,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4962633213952274716,
-            8656806039142072665,
-            6589516629002248502,
-            2594991128943460758,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13139768434429589100,
-            12552037513952097034,
-            5220019531337058756,
-            2705302470832053774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16891329599911349154,
-            3713742735281498332,
-            18046236400127372889,
-            2798231561400587400,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14617477261713199375,
-            584485014247412212,
-            4868749990410048810,
-            1965681646285406870,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11190384086754173497,
-            3009020430555070649,
-            17564763188323435298,
-            2721472038187448892,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16220279297633309983,
-            7848126274888947277,
-            8439822603120421846,
-            1561823868765574075,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2734588135120314296,
-            14629084645614559281,
-            17112711581865041128,
-            2932406583840449018,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9393703609255138357,
-            6114927673946638312,
-            9808691407595527168,
-            676885871673844030,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16994091613323687974,
-            12942750944697603650,
-            14758366708684347785,
-            3220308185726517371,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17544094326687543165,
-            5389316341014407889,
-            9681728851501191204,
-            1509721799230453414,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7325946724564429673,
-            18344476245019987708,
-            6421854658937770175,
-            288396217100434790,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9608878883282410350,
-            4251436883995802234,
-            17979594276832171010,
-            1674842400026774596,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13143434813743676976,
-            6642536956588905921,
-            10271124194831429281,
-            2971281026169420683,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16834849954811863902,
-            11720509711842277900,
-            15162256966001136303,
-            1930059747252050400,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8903671648573380771,
-            7625109498268799786,
-            2046236849165473416,
-            379246626080408013,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9383445944838903280,
-            7428287772709788284,
-            2967433922770128090,
-            2684514324777450446,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1623852987740816606,
-            14106713055494514937,
-            15828285112395324844,
-            1524956565803123104,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14007339024896840889,
-            16144223800419615144,
-            8895469962634074395,
-            574376014251789412,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11220551283178513596,
-            10894146420430821918,
-            12055395061700947786,
-            55384600379714779,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1901928541541535833,
-            3429935956474073085,
-            15659186788288170964,
-            211740625087225675,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12460956210016470011,
-            4921209271377028268,
-            14511838697877709256,
-            1086783273691642177,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5205007504363609335,
-            2354174023112437857,
-            11529902328814804142,
-            3401182989271848291,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8753142300014896132,
-            13330777551984453022,
-            11342746775233851799,
-            406084408678545587,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6435303994522487269,
-            2591661059261589448,
-            7938983234218229468,
-            3414976884374522289,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13706779909207341559,
-            9587442221002185747,
-            4521781003007793522,
-            2216850394720190450,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17937511153114698852,
-            8161344568787917361,
-            9821600840274000806,
-            951683570997687185,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            55576972866751748,
-            7606236823132785546,
-            11077610504097211827,
-            3103614423539398298,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1771313759504555224,
-            6776793042908792447,
-            7970215252899469513,
-            171574769084879061,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16630015504034593054,
-            15749223391086160996,
-            5286428851023452283,
-            2613327426492195322,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16908920214968981466,
-            10493060269992684188,
-            5858163230853723171,
-            3297435562890650228,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16820131865161360516,
-            8329254128343335847,
-            9339244784305038738,
-            3349524624830566651,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4382445759250415516,
-            6090440750751042342,
-            8370434988908758800,
-            1370488905086148789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14564677638701749478,
-            2878770235315454270,
-            14136977323730474083,
-            1922754781467373130,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16181515716670211746,
-            10382990372179643384,
-            1937645919240346731,
-            1809286984258734167,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12529141958761523949,
-            12974039000726349820,
-            5550753868853748761,
-            310630105921196575,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9646263473228296774,
-            14329610826655998230,
-            4033450660559691331,
-            2299827551685999392,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5189039298046936416,
-            8763617433018604065,
-            8642897573073183258,
-            3322348328183336460,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17179209804806194747,
-            4839481686442296134,
-            7857077601686639284,
-            3425805644760193711,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6194100519301590486,
-            4191349764601526243,
-            17474169116891315542,
-            2730250272557869325,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15799515593693584989,
-            15388739261875855085,
-            11366507064968792905,
-            1025703282292928413,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4325579537147147330,
-            14031484899946009006,
-            10064880634348417568,
-            2579315537029603817,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6240092334165273270,
-            4000257912223920510,
-            1883812581904433847,
-            3485417722423220132,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2220287517156106285,
-            15606718486394739641,
-            12634060852027860924,
-            1007651386345968452,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6710381670961981703,
-            6534199861861639267,
-            16400023429799478509,
-            2951185237833780157,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4231643209643821519,
-            6455436182017162269,
-            2963880199976241858,
-            2547512677087177119,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8176178192732926684,
-            17856774424437647880,
-            4859644906518983524,
-            3392176728852917511,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17076491427737245436,
-            7611082530053563379,
-            14157982808684198563,
-            2494188151429373293,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14335642618034369235,
-            12631084832808824888,
-            3600632297004525807,
-            616706006669645512,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9208163357542324681,
-            11545204709080227027,
-            13404174546243965965,
-            1872124185394238397,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3824488788202919915,
-            17619043591219154162,
-            7109733762327701802,
-            3304556930927363277,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13303159232327632656,
-            7531752438577368806,
-            1382203821184452622,
-            187397782090063662,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            105785036344078054,
-            3026681806862352096,
-            850779204459418948,
-            224003685654354574,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4957659867860027878,
-            16304323616076487720,
-            2841495523709183812,
-            2712791205669896615,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15534105621657304554,
-            5828027689605389871,
-            10381142173895006640,
-            2021025067883751332,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13014100604974701047,
-            12042719141207737996,
-            12600484477327642,
-            2997937269548935885,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4328620854363941212,
-            9663946261413808997,
-            5844538109601748088,
-            252104582731223665,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1243088395050125577,
-            12754236537835495675,
-            13445141969847965815,
-            2894982200258748118,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18214158950966457911,
-            10708871476758934135,
-            10935433218386741515,
-            2543389792243491110,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17765607371915914349,
-            9391916715680641707,
-            5805501770895149507,
-            554570131102007222,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15956373721116321133,
-            6525907328308392486,
-            4004534243048469370,
-            2147321987108909581,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10442530245193674087,
-            7041477694373154722,
-            1466158445104549656,
-            385783801008578573,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1278080635772312329,
-            3840581380954195687,
-            13287454827332152080,
-            3157404894690775867,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17789695093398586019,
-            17842994677851117899,
-            9424950942765075215,
-            2153404396274923302,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17859681417795573758,
-            4416146491682795122,
-            1807973400910817463,
-            1013832086927532752,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12255396057268310147,
-            8152850068828798839,
-            8341009399061396958,
-            1169426170388625440,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6046282855490157174,
-            3117307693074605487,
-            13713981689598037762,
-            149369435910973414,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9870392578463280167,
-            6709408405672398929,
-            10547893612776351760,
-            1342072733714491042,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17316958398634415393,
-            17668265952668367278,
-            5993928402483172716,
-            636236423402090016,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12705053358677156791,
-            6060082791930597393,
-            8092231122815573283,
-            2066464737769554538,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3888155989891128333,
-            544557322235647771,
-            11728680581643961095,
-            3210167138801737775,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15062268588795156868,
-            8315383280808262265,
-            8321285703341184888,
-            1710915982014909024,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6285136866898622025,
-            7510463718922521304,
-            17037320758986157698,
-            2456750364549205694,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2184582295462352939,
-            16017089252733556528,
-            4868336725462194345,
-            556126852548065010,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1728372879288197875,
-            16315248714040953966,
-            12511700941819861869,
-            373572116679667091,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4371500865871260388,
-            13696095956652585196,
-            6426743639147373776,
-            823565254623192181,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8776882832119465373,
-            5716068635286736964,
-            13974279829159560790,
-            1653658681126654208,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16099801119338637659,
-            1293393017561217180,
-            7515011126401322765,
-            2378227858704979840,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7938564847483100311,
-            16469527312722993080,
-            4229101327923167227,
-            2902949232074730975,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1076676259841459981,
-            17970381188892454739,
-            12887475589835741053,
-            3307046386354624940,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12140178299258224064,
-            16809667312505581291,
-            12435475303007791084,
-            255565795048494977,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18000801504701544102,
-            4143382531844736678,
-            1894308005632622652,
-            3381063151386767364,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15868551909538381959,
-            15649811558879940843,
-            11299356994932360339,
-            3120759573300468474,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15013918074055716985,
-            13353316648248652550,
-            18255604396482486625,
-            1756648207718887916,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18393786349605072666,
-            811924692796156698,
-            4329448548477824033,
-            2674039522059964178,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10550515433336742907,
-            5693009192159323519,
-            1292604896479704770,
-            3365887801004378311,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13086443730061095549,
-            17752233182750122591,
-            6646347623747928859,
-            434286877472449246,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7834398965695058567,
-            7612395444584617564,
-            42852799035920852,
-            2746986318741272642,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13784274623539404549,
-            12854738745433293652,
-            10023528945915645217,
-            1721643068537634726,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14549384211783041982,
-            16663311692850795232,
-            3029095240104174500,
-            1096192288958179810,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9053827472122112844,
-            483406754233460022,
-            11649757855112034579,
-            3088351840520099337,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11907387046987210663,
-            7674380561858773368,
-            8375179694022599800,
-            1587332501551612235,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4454040052769541300,
-            4450433376730126699,
-            8254356942507501032,
-            2443543746577222475,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14382557907983090825,
-            10892114337188619077,
-            2157889005565394154,
-            2399270291426845135,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7438672794591722072,
-            3899866524128818202,
-            16374469210510660347,
-            268508797060296185,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14425558192725316527,
-            12278176324288665833,
-            11253773543063586782,
-            2420549577655820165,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4912529957046494287,
-            6503670996721294831,
-            8542897724393507097,
-            2783561613509631508,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8467208601662987137,
-            7443427364247269154,
-            1653507737439531314,
-            2319852810557053299,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11969671208070204662,
-            4161750669981863969,
-            8310086552239523063,
-            2266808514962694128,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12652960059836910918,
-            4150156545150242926,
-            15131287514167624366,
-            1430626145514335779,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2431186832693107498,
-            753089877416044104,
-            3987140933085516293,
-            666353229232499429,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13372028017976532439,
-            16463147974182574414,
-            17589420688785322089,
-            1775585819458665361,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12042436972801236868,
-            8052390276739399232,
-            18151088534617394574,
-            1787291307319819980,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16360690794240881222,
-            1828825146068838225,
-            4011875574117025269,
-            1867648273606918956,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            151903319838852100,
-            7255372707676011156,
-            12179162132097271013,
-            1402964670510220944,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4992175880742804596,
-            12085587691424822316,
-            9087236969530003635,
-            1195116013808880743,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5764950242239783486,
-            8533434868005184342,
-            4901652585221727690,
-            676616823852546953,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9040006758993956524,
-            16962862945282963245,
-            3853773934737686832,
-            3048324883956818321,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4414185422131782245,
-            7261468790505927248,
-            7071319796623008704,
-            3036908941657062085,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8807094356142198892,
-            11824347360802670818,
-            1303776675087088161,
-            3199796345716575829,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11243247079427757007,
-            1494732856711118399,
-            13980664336797546108,
-            980304516157192040,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12297687869024058124,
-            11514316882948140338,
-            5215263599232104318,
-            828758562166217054,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16495924304369233556,
-            6970342702331049085,
-            11334845680914923196,
-            375672018590320611,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18436837898302596816,
-            7613561244957539448,
-            8418427380014247746,
-            2415440899370113888,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15244451213597198671,
-            7867196132181524272,
-            16834507733949720291,
-            444964669216346053,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14509370169202923251,
-            11520985696443087925,
-            10247379655483347760,
-            1741749299798362636,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16337514702982666161,
-            12616251866568751040,
-            1970926896436842896,
-            1907302026992996644,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1467220594388663910,
-            9620738110407229934,
-            3219393125478836424,
-            3257269496274900949,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13415458375113159772,
-            6808422071671553185,
-            3338054324417702123,
-            3181568529565175284,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3023999675565259090,
-            6017891328816180023,
-            14641609683150126839,
-            1058810343018566801,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14229607910773167818,
-            12935565461615615015,
-            15623086506220508011,
-            1354617533299239736,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14095459837573962127,
-            16740719134116734508,
-            2833515729519874365,
-            3229707456441700211,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15924308229361382060,
-            6775395220268526215,
-            4584523934919309661,
-            1428862438527167540,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16142397554702489543,
-            1129326594319573646,
-            9048171540965089265,
-            3068649290540422781,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3231539712405462141,
-            1139174445030357760,
-            1559254547131820655,
-            2215752009909931424,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9465829372930873408,
-            8819307814603997832,
-            14039603350476435465,
-            140098387821665080,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10939621606104241657,
-            5914687803730275291,
-            318583762640198972,
-            3180067832608193881,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5426571399104397541,
-            14515756961916643192,
-            13166962145544138828,
-            2909433088773802440,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            322609131001893145,
-            8895052281099120465,
-            17894705099909997705,
-            998790688749756454,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4272439795733645959,
-            9611049560608266888,
-            18059828546032009934,
-            523643154513042094,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10139034468876417351,
-            17119314804340899726,
-            6079611238797920349,
-            124911263216799891,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10840676884627387704,
-            4897723356790311076,
-            9235120766691150955,
-            1417245715432528573,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13046118654829667621,
-            7598170891139192771,
-            16981712095011925081,
-            2276344266871489886,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4329772198673379520,
-            6660507200359280643,
-            17318262414049331126,
-            2377597902212544938,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1165414346782126280,
-            10857513196153864534,
-            4281146368054384987,
-            2601625389019238970,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17179841512650651536,
-            13970573319394255420,
-            8380092271136883247,
-            1799769741967348144,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4430794553227664309,
-            16614144363313985145,
-            615365737431737099,
-            2976188628774316769,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13851674990529668818,
-            10483515702588566425,
-            16350732898888216998,
-            1973139091738393487,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1043622360720433427,
-            11010134158318535194,
-            15061381622645698281,
-            2757783389830536864,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8946896849869639172,
-            16920842158771930523,
-            15823846458253492713,
-            162064630060276754,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5727282352542551815,
-            1612437773346782864,
-            13885444384112552516,
-            1525158049042436128,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3203290464462867126,
-            4902322246140843222,
-            281970590255962870,
-            1996643462103124345,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17375251261458634290,
-            288949743985566652,
-            3717168501269408566,
-            259746172453330821,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            42424573668350963,
-            16795405001008198111,
-            14090357803950557667,
-            1370884799565409186,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6556808928498237192,
-            7677970710382317600,
-            18284675285070666850,
-            1619967558987219456,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3895745500367412796,
-            17366371418839356306,
-            5568374787062869691,
-            104196331301386938,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C15: [Fr; 180] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5854491572231501385,
-            8111178952172354822,
-            2161818315748637412,
-            560792256607113773,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2771184658468088834,
-            6207609192755181832,
-            10932944442691439038,
-            720239682982155642,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16546904049500365658,
-            2502717182100929236,
-            6470841489409208966,
-            447967422960504567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            414514357077919891,
-            1849485357103292215,
-            15336436582891025989,
-            3005287553819721531,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6929582855301447062,
-            8123211500413743237,
-            6423121905906471224,
-            2877830434991712733,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14690389771597023892,
-            12747502469503799744,
-            7365456739902816975,
-            544201394993176284,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10043345802451546568,
-            16710236724428381981,
-            15916743996828402845,
-            3440606181573960809,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2081846708536021196,
-            15175570522145483221,
-            7333169106222328508,
-            1611667761813431607,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4224041573764465876,
-            1015054527620568957,
-            14472147957645787000,
-            305765081353734026,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8222852248012160041,
-            6234632679957620790,
-            5730181190927707408,
-            2762169998271338823,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9563995070332937120,
-            13923451383436329975,
-            4946359648669913210,
-            1463335787997406378,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2495373143503082203,
-            15759170316668455473,
-            8550188744935081644,
-            2566406787644650136,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1296099878083964213,
-            11441309438435130264,
-            9420967741110088588,
-            1032708313222498449,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14271967286532148463,
-            3122492544616763648,
-            17651407647017345104,
-            382365295528455739,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17675034004966472632,
-            7958145411270000743,
-            8522284508748197487,
-            2000865642588830960,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17507822056663023394,
-            13384595027385223975,
-            1791581734049713341,
-            2402975123533952684,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10374417608983490786,
-            1817063374199680280,
-            11470267580712170969,
-            3393175638540759249,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3658633299026613520,
-            3696910455989674048,
-            8873248373173699292,
-            1348583405710140162,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17470905577119892000,
-            9741325849948449512,
-            14917790935523317766,
-            1630062452247955750,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7447211403983758989,
-            2206189301821116648,
-            17685769447033455828,
-            900543653398654118,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6177764949425893447,
-            6794023092844071486,
-            16748074348167285378,
-            2659366857709395716,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1940656653504735149,
-            14219672255619262342,
-            13451676924235239287,
-            1502972216340182244,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5640444681012107304,
-            9306666814430326470,
-            10814205475370802069,
-            3216636658585475743,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5150691713787176052,
-            17120383689060144264,
-            6143545390206009635,
-            365859928030164887,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15502020682325370417,
-            12955124780253939554,
-            10856671915647090416,
-            1924959467751663501,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2112814664495765594,
-            1279902882967230516,
-            2136432823938488399,
-            3049293831539822616,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3348917149380008595,
-            8971810082390210510,
-            8951710349596379062,
-            597636932061882025,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6066937833990581042,
-            16501149597949273459,
-            2377823342742347921,
-            872072791957533091,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10093198614222710911,
-            14438662905166320873,
-            2184703174184486400,
-            2537601773647658411,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5887682120592257746,
-            4837907098356430551,
-            2909571868833577195,
-            1478669057539986981,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2383086833418621908,
-            4235668494378604903,
-            7665441946493493319,
-            549726232570240376,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6433928767975086487,
-            6114099726460888405,
-            4465933326907551511,
-            2001030679631917428,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11151396726596292413,
-            18260734238884181976,
-            1900439411746610892,
-            2860934610583205016,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10448775331921847755,
-            14001911245224596991,
-            13180378705427966078,
-            2972796084681775355,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15244092597041249405,
-            9105374859291980670,
-            7116498819226711936,
-            1610113051483837519,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5863220490292336093,
-            4087238985405759644,
-            9006210505782162672,
-            2627079492318465831,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9728353193853195078,
-            1063082964848756625,
-            8625080173880309117,
-            715089042635905596,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15667440408351274537,
-            7986820699350964742,
-            5986391124029548126,
-            1102586628819315909,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11923021351891227971,
-            15877299144218741764,
-            17220491420999651692,
-            1800526098385945845,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9298734362980897763,
-            5724178203188024842,
-            7860076657201714263,
-            2621449075515596876,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13659314314321634845,
-            18094803120239888969,
-            18326118166953545815,
-            3232049175351561044,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6004302644086191361,
-            905818925787549157,
-            9893626013089139569,
-            799237444176073570,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1395764281561828597,
-            16170167967719515142,
-            10220542568153318935,
-            2084124598091934724,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16692622265727702272,
-            11951036725658207343,
-            4840036625074397423,
-            1841931461675404093,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7487601272331289456,
-            9269761888351227712,
-            10790988380520105771,
-            3299152777861250185,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1001425268035096084,
-            613324230412881304,
-            1150862641360663846,
-            1685375480602942793,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1007908125207481195,
-            15662075115756773459,
-            16423365555468705211,
-            240539527912175011,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6541310309134878883,
-            16866744566770498550,
-            16284811804069403540,
-            380599683689923084,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16220839112312212916,
-            16882358117820744748,
-            10767469442702232287,
-            739219059651580586,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14994172281627924823,
-            2977937411167894757,
-            3270486972408383531,
-            3195201238163558408,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16932385158171399705,
-            10977246797739118616,
-            14176914812118648433,
-            1169944206308731256,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            792252112781788767,
-            6407195137044312043,
-            17093020180641414418,
-            853834409968965091,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2618112403207667279,
-            17612608886052084786,
-            14450331109381743300,
-            538212649549576463,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3365650993331491151,
-            13114904596146782311,
-            7354314733909155161,
-            2485250694583802655,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1665472777159095152,
-            13054586600394821969,
-            15783028175062207398,
-            640590881418455402,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4682443677610583336,
-            18033613946476821632,
-            15637728294102254124,
-            1977835929748731407,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3103509500651785280,
-            14555891440429619374,
-            7615864861998664675,
-            2444856155803966454,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15033035283083187811,
-            4949567275041649871,
-            4016740110194104656,
-            3155987744987256482,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10432219940481611403,
-            3535468763116259438,
-            13199129205334301068,
-            1281104525110237764,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15297315619774470936,
-            16137438788933874862,
-            8386500193967983310,
-            1342720892630538258,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9044037742932112922,
-            15216845784221355872,
-            1868164217095904191,
-            202451778883969768,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5922549067892650062,
-            1574216784734706132,
-            3773608048492684482,
-            323580231895877161,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15221415604601538454,
-            13240281712875875349,
-            13966018657841724173,
-            2065732047186280471,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5676555255880046905,
-            7073633008783803168,
-            2906157284355681895,
-            180997918751641314,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1354165458343364915,
-            6889276146317377040,
-            15247285099554839300,
-            2564211500322693525,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            914345284015480049,
-            7972758793869038197,
-            14213262591050748417,
-            2967464231008125187,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10424961761909505782,
-            8147476599722710141,
-            14338829378521069566,
-            2069520045916270622,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10000928677592467494,
-            4499975099575137390,
-            10840011940450932336,
-            837053962423089285,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8215939810980292096,
-            5596566411525165156,
-            8633627363321819137,
-            2782128451074613993,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1009498943526767687,
-            31350017711729475,
-            7882600907879063200,
-            1141595988223874393,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1429841770989977784,
-            11590883598214154551,
-            1259896457619717762,
-            836754345896200674,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13015909082346256225,
-            6082536449090887855,
-            4272906262639417776,
-            795586434523098619,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16238434530093613047,
-            8733974521256307335,
-            8209155164150750905,
-            2744382824605063306,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10338126036469928428,
-            739303104286760010,
-            7897012021569113399,
-            754521888197120805,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            341544633296896632,
-            4236293534868534818,
-            572316304301115115,
-            999433399148190801,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3378119738150240032,
-            5000090707078172088,
-            13002689464343015135,
-            3423204091189268309,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6482124747290978562,
-            13431085518112227082,
-            3238773401366834313,
-            101939908134919873,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            103395131279841993,
-            10636292840823913991,
-            9440519445923854171,
-            2080840765175108484,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10193280168802960962,
-            10200973228334237456,
-            3324289030231496458,
-            1257581342448135443,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8602987222646160928,
-            1759342417823806821,
-            3628432493813076312,
-            1330165444444117735,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            953055160948702962,
-            2444817178733809981,
-            4023261699135871284,
-            2854919293409605804,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8532677286212365771,
-            14023011005254747730,
-            10755776811264657003,
-            2178649981235717784,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10120732472889747818,
-            996964795370169503,
-            10624219297801472083,
-            2962962967207803624,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14107357002131551921,
-            14683473288855988639,
-            6085957285568562485,
-            2821270851335832297,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14177155077709504633,
-            5602769546698447803,
-            11201890349527479511,
-            3329249361455478940,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8184777530369205728,
-            9896504416534340433,
-            12302509054348846661,
-            848359713173304505,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14985566611698085067,
-            3407483747732884298,
-            8882183157857436830,
-            2500116517465468577,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2653155971428997545,
-            13571820546443936163,
-            10385530429631013330,
-            2975330782092768042,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11719921036795326160,
-            14667144436675176919,
-            16348536817650610089,
-            135489132611531543,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15952544067374472509,
-            7361471660392354595,
-            12838687657187453953,
-            1536318555215035412,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4539601891774381260,
-            4535680073968258987,
-            5449882913809490527,
-            2843204584717907958,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16262154307163312336,
-            9345592144904020062,
-            1555478695560044084,
-            843922690105693696,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7184764161094816122,
-            14219662062571807039,
-            10528936497791514992,
-            2167064175186999483,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3389491475007320589,
-            9954069946188989014,
-            16217569725090551300,
-            3054820968277768087,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9923270499190340747,
-            3529274422566932319,
-            17040952575696513539,
-            1288622082988095052,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13558343807107952125,
-            5148993978724463495,
-            8687849946507999261,
-            3078603212128740319,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5015538816552935339,
-            10845055987909979851,
-            4781450753240607523,
-            1300769916408130720,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17565235072036457931,
-            9566836988592886755,
-            7411880580609773387,
-            1072662676296289683,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7400883637231340969,
-            14279859734123949993,
-            1695060616579549263,
-            1195710083834604633,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10072953674646644441,
-            13945221671509814420,
-            87869626647008027,
-            1899826001771725851,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10634747743953432322,
-            887165435055739130,
-            2276697938686370579,
-            1067508367457223302,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5145381538203511631,
-            13877920983609180560,
-            8672470879036361444,
-            110416970997124184,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10702177708141735327,
-            16803788950732807579,
-            4222651305247784501,
-            158463003197662870,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7245366453480674773,
-            8592137481689689162,
-            15641765699908713539,
-            741507601663238349,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9816915435469190186,
-            17933782461304126011,
-            9830765235679111375,
-            2928702207254560042,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14416210315915639508,
-            13237597170083820750,
-            8731876881450811813,
-            1245409246882938393,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11073915200983486605,
-            8347786857524633554,
-            4283793717173226019,
-            1351032272341952824,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17171633892408957474,
-            17873520917170914296,
-            4117267498796139027,
-            734808596329538537,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2269869708365376814,
-            6257211614614692507,
-            13039670969097458775,
-            2630541775227729184,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9685282438972473066,
-            5948506923216886775,
-            2011676691867128377,
-            2479639094144995109,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3944779867836147303,
-            7433265067398626836,
-            14585014355411407223,
-            351794807592146326,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            728513900665682349,
-            14624802392529054553,
-            7399125086698959199,
-            1547505242331154785,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15647612401536836007,
-            15048437899065838775,
-            4045444199631618674,
-            3282783301368075544,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7673071221128459181,
-            5751439826487503948,
-            12222173643002272718,
-            1560169565264581429,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2919084804245720331,
-            6883608590583871170,
-            8895897791352472967,
-            234041189931164266,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7481534881023546703,
-            16956723516247447925,
-            2304079964314714413,
-            3233317271608319534,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4302382638416514367,
-            13583262091887603308,
-            12921099108397397980,
-            1602818128880943644,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3306204734738751892,
-            157055459717844219,
-            11562642505221034701,
-            2209297265017842412,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15119763249825074733,
-            5795127067089676676,
-            11737902928239544222,
-            616452578016121599,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4643615081680276549,
-            8221206305578464687,
-            6673377879599285357,
-            2939362197689239494,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10931082554697260757,
-            2811243125038301177,
-            13047496254342210491,
-            2639741064035365485,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13196256832461824065,
-            673323352557426385,
-            13627949423735005947,
-            1017815150436905725,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1341157353229456013,
-            8442438810274244656,
-            18263380223462977579,
-            384574551628832075,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9540571405731308685,
-            17604821700654434640,
-            16891836683459918631,
-            2158165799561961690,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            851288538394121580,
-            6309383519640086257,
-            6516783030033089922,
-            496105789982782898,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10227727871186970698,
-            12396344001627118593,
-            6432987348817221503,
-            23340409054538448,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6713950859536031806,
-            16379185906392887689,
-            8562303781176933822,
-            1070211701510065069,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17487889660218612952,
-            6874395198468597763,
-            17689001748922245035,
-            177855591503424121,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14251712839212186186,
-            9681685725445746263,
-            6713977096098456070,
-            1895134464665616349,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            273625938522617412,
-            7861224164879765721,
-            18020996107690537483,
-            242257414531491670,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12608940724544764814,
-            4166955494072154357,
-            4734511258638386080,
-            1161663295823260802,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16304806585748638430,
-            1409865110504214588,
-            11321243779991232731,
-            257184044580029712,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3903418278420965239,
-            7813783210577263450,
-            10132273160864553636,
-            2832059503229834356,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            779939522289887232,
-            901487521332639558,
-            11555501280084647221,
-            930980272581532794,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16951439085611675819,
-            12101132551848253248,
-            11037513245763706830,
-            3011204893322265844,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1057380770624688286,
-            17277838454653764320,
-            5749481724462843980,
-            337168003268671728,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16791657725746946354,
-            5426676137338566514,
-            558340978260982667,
-            2945918802009915921,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7537459079079987854,
-            18037207654468115192,
-            13762679983770510341,
-            1437456205196820883,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6863528270538910115,
-            7782102144299092122,
-            13608354277529217014,
-            1778607415900816708,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11792118142655595868,
-            11585699889276345721,
-            1415450070215240261,
-            2589303959911832046,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7466264669449715356,
-            2345134038314971424,
-            18272030490147985565,
-            1539401639550459282,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12164410434130314285,
-            8686708647095182439,
-            2373123147407544357,
-            2422328124111247106,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2866013780859417253,
-            10268503415535387542,
-            5615544731340363015,
-            1354438949935349764,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13791044887936280290,
-            10843599217652776902,
-            9141572066937314829,
-            3444792199185531617,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6804765666688603084,
-            9904343433177695409,
-            11199624045351442457,
-            2278117778249138750,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15399694833635782042,
-            5206536087520232038,
-            7077158917620850283,
-            1922086161410440642,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14743739404165479797,
-            5697770744881209856,
-            13863199771469433506,
-            2558901847426803984,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7282407718632626589,
-            10978163591438480989,
-            14954319751206228192,
-            989829188653188523,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4162070101945208575,
-            12392803036292094583,
-            6731505050327161337,
-            660314916638910669,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11378493816006683442,
-            80956725405846465,
-            14115247633558923235,
-            2730459895933421295,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7821017177959180358,
-            633193054270370559,
-            8959701345023451018,
-            551696009019615845,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16259943409002658175,
-            4403487940193975391,
-            5636784358167084081,
-            3346059967801056251,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3209433072233019407,
-            15077330027299121755,
-            7734006195293088189,
-            709080127244604434,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12425189277872844160,
-            5668995096286208897,
-            950135900330331208,
-            2845664499425245171,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10649535769869114735,
-            14266284587561077610,
-            9743802380396715144,
-            1360464666454597502,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10002234371913450885,
-            12717679289044515549,
-            8010362445061840497,
-            1938051340823668858,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4981234727525368641,
-            6158290298012514436,
-            3110923431270313760,
-            1488559649218720947,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11417522191288270989,
-            5009890624909527418,
-            15539090877135977861,
-            1860676953095250279,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4497098972153381991,
-            14546121030137330697,
-            301276336380184688,
-            1879926355064419802,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5183923646195179541,
-            15154472103222499735,
-            11879401668910735941,
-            2993660049125915476,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10696988600510915347,
-            8128084054450824654,
-            3510058870233436267,
-            2683947005803154324,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17016567890804998841,
-            6278457669508105082,
-            855730520251760026,
-            2310109191446605414,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10731552029212472366,
-            14848765194902645330,
-            11029746784350521501,
-            3269162412900351220,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10034337730347377613,
-            5160686725149378756,
-            9107231328266248084,
-            3397251571517363962,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12150641898158869214,
-            15537306845732984326,
-            15643311372491577343,
-            1377587690603111453,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8606525911317269548,
-            15850273507562200567,
-            9949843437881474459,
-            1465090236910554083,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5924056820939688155,
-            7801884799223044873,
-            15101994799299486646,
-            2577513407947837435,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4749569603256400744,
-            12693589624085980676,
-            4771236554443110863,
-            539207045155820827,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11237162061467479607,
-            7383871265786598769,
-            14526806972724202717,
-            247313901410252012,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8717480878156879187,
-            7728679755387913365,
-            12065631213288889296,
-            3376552555287984296,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17567019328673274434,
-            4818455987110256234,
-            11057780863904674965,
-            1699245768230181650,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6342257286979601131,
-            8919972639028578826,
-            2333406205629148254,
-            1410449788361925564,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17055169884240519049,
-            16705260506518094224,
-            3203002337728952720,
-            1001155843641769519,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6402479798027749379,
-            6355053432271604559,
-            5513170858030537454,
-            1959290263889527479,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6859854863053673132,
-            3098136082722529135,
-            3380158853743764730,
-            518619633153246462,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6985725552481711394,
-            9328846878649149250,
-            11738322266618474635,
-            2566798219267129767,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13514850977686632726,
-            11067332388659818196,
-            13681239554423181700,
-            409040763091861516,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            431901640410747282,
-            2589985961631359587,
-            14756291623394042189,
-            602183678801238305,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15346330918306854574,
-            4521459486353039092,
-            7833802600895429738,
-            1048239457277406023,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5077801161506036993,
-            8541401641672107842,
-            6350582663386858565,
-            309824332890372119,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C16: [Fr; 192] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16522712131664991472,
-            13374127763164559269,
-            11106908625490125323,
-            656039289375100575,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7245470467105300281,
-            12186345772755553121,
-            2917873427731388970,
-            2228764963635672990,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16581404403788476811,
-            4119187614824896943,
-            17795242823145979137,
-            2708288879198506864,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3841672636194389490,
-            9521403321523237289,
-            12734208085397692154,
-            988568106534928765,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18076829322355373775,
-            17568033674339666398,
-            4614716317348409970,
-            1009617653457787663,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6525722332513342173,
-            13180769182686407854,
-            10433292076355528631,
-            1478569942329426678,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4979370532517037860,
-            13873274533656354447,
-            14608822835972290417,
-            3046826556316437938,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3076600606575124453,
-            13077223223827794195,
-            16720062334649157551,
-            2276858435105048739,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16671226728550816049,
-            5674544732722622265,
-            5786287049292494668,
-            1457087516434934066,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            382384978103550163,
-            9213402190453426494,
-            5448711767613331095,
-            2309936388410903563,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5805561458779215047,
-            5509804759598274371,
-            7106491626261898295,
-            236392963052013107,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8762078375257664270,
-            6464523531759788021,
-            10048077568958252518,
-            399503694767358900,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3125927282038045352,
-            15403112707788821281,
-            18361346223286343736,
-            662106746592392073,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7890952353627981440,
-            11083722540800233137,
-            2591312006123639624,
-            3345930202597865090,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10134217084613626317,
-            9303438101955445618,
-            14082563348854446782,
-            2763112084976214832,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7739962496701620491,
-            9919881183423867436,
-            309394789198878846,
-            2938266663774362712,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7556159978091158448,
-            16557054361341650674,
-            15965957240002110251,
-            3089663542888607308,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13771629267839926014,
-            14190509739265258534,
-            4265188385236372352,
-            2855578841511735759,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11044711511927836726,
-            15209540450643649757,
-            14237350092789404262,
-            1538380244047399006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11280915188882184256,
-            2375784906313520825,
-            7391997090313921072,
-            2553633815498152280,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12337775748689034365,
-            1902515379972913264,
-            9727505586730245552,
-            2906722042458183732,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            783214641155921094,
-            13771236741438665504,
-            17054915346718121772,
-            3332787663265352405,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1843702448258881707,
-            18030160750566628139,
-            10142876367673955241,
-            1423824641274261431,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14187707923829081049,
-            7465640267469019747,
-            15463331092831766768,
-            98367182588410553,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6895068721740413122,
-            4341310446362261135,
-            998153960946598728,
-            55789470152801701,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7932238266265920706,
-            11990087374205475985,
-            2624851273254944595,
-            2980212655778478848,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8244823821011847872,
-            3104694925464060951,
-            9903170230537688943,
-            2646258108466242868,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8351936222484401261,
-            11613240386410219964,
-            13715256313651531694,
-            419180418218659258,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6129407896829890096,
-            3484160793530895701,
-            13388011687149460884,
-            1417905072684758082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11817091226677072828,
-            6745777646206003783,
-            13028182425581094394,
-            42098884499829361,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5639972805629926206,
-            14145403376839696193,
-            9024819116832512728,
-            1140748049910260094,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4587381904838178145,
-            504173870513905379,
-            2435290200097856454,
-            2323975591430125975,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            278335510758145964,
-            2080891792029881215,
-            10870135589037908849,
-            2943243466629957930,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            338664625305150737,
-            16921001209411369595,
-            7452590084216129081,
-            385595385955712834,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16498693884854813708,
-            16316144694160131480,
-            7412642350308113668,
-            966602659493328283,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13553149553505658500,
-            17012727162987789467,
-            12169145045685450297,
-            3364464030241713294,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14818055533541029598,
-            4323640993188508083,
-            938448120376248206,
-            715697258700569026,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9960935719124255835,
-            13873453544752005862,
-            9858813876797621373,
-            1110989651648236152,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16810636851603287465,
-            14302151135204970524,
-            5436344842382765558,
-            642701979838221213,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9976915098507091543,
-            10201646584411818220,
-            9514196714336175417,
-            211338989447992422,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10285826430008894611,
-            7523440261019257039,
-            16581685949307071562,
-            2623713048739679222,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8938099822915200630,
-            17616870742723881446,
-            275731195173679639,
-            3185572022286298493,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2426505634566701311,
-            6553969323206479864,
-            2323806587653963391,
-            1310044932806038880,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5977164548728802907,
-            4676885162970946287,
-            1574537078587701202,
-            2541436483923376329,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6982314760404691449,
-            14181145526954827551,
-            17873557631878770877,
-            2476925269764291251,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13229567464246172435,
-            15243234850105576844,
-            7373793491573917624,
-            2289096531189886308,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5688685373144096299,
-            4136164386953688078,
-            14011807279471530841,
-            2378863531948243669,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13246316095305897868,
-            6296756603116552751,
-            4385480427553350173,
-            31821280219361881,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12717420980292731506,
-            12742384772369725716,
-            4287628641031276669,
-            2506543961882722533,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1602299657512902608,
-            8850357824932995260,
-            10918923750858558114,
-            1782978259727992561,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7691971089826755703,
-            14789006854351467860,
-            6784638152538283416,
-            2528222767755580298,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3826326433520155584,
-            2815913174663817503,
-            4790476524372713606,
-            1954387827357027352,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3924501239652313314,
-            17156305007465351195,
-            13873457166816998685,
-            545960764465987336,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7010915792833440881,
-            9896708562736629053,
-            9448764104148132787,
-            864142231706984666,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5755964072176567279,
-            12967918122310004817,
-            10510171793103691494,
-            1664973881272747407,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16717078931598584797,
-            9685482865575008731,
-            4046839502975234133,
-            936395119813848600,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8726147376080887090,
-            5565286507360792901,
-            1535463299655898946,
-            1505772066628751524,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7048537409511411344,
-            9917401527356153804,
-            5807296861476249571,
-            211817735932026537,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5935034731007994231,
-            18014135024694286849,
-            4983074563997341396,
-            3301972581041114899,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9435529069201327599,
-            8847838540967137932,
-            7452450668886600562,
-            3289208524896381743,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6059197225200738208,
-            10397742105604032152,
-            5045924178297287963,
-            1284495493923233602,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13428522430969772705,
-            1248755536110849392,
-            9486201504337275908,
-            1476094124983788701,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4110652219917017262,
-            1879367768781472736,
-            6097205551638151451,
-            3376562167993004348,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5256279517618572916,
-            6241462884746301865,
-            4646381326299332274,
-            2130109784577486913,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8697312845995155851,
-            2522944745100234590,
-            10759255298250108556,
-            1230507704057501181,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12769800146364405102,
-            917529177546637679,
-            12665480083714832228,
-            3257326405531478109,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6581689447731004247,
-            3305610350579312809,
-            6744743247660867378,
-            2856778582074191700,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3458163229380934605,
-            12832658028144042219,
-            6549660014944734587,
-            2189120457028301603,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15020765488927614630,
-            7676989955223602946,
-            12605651049230085397,
-            3134222461782774794,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13695336813516084331,
-            10391486129241755799,
-            8699490719220915664,
-            3020202988012145323,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11560754158468344140,
-            1863125933825142946,
-            13171812023726867630,
-            2486321954167557391,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2937254131657288894,
-            397064424991344857,
-            2426789900370969841,
-            1498174679448131895,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15932947540512200934,
-            4461979935614250655,
-            8652071777606115876,
-            1277071842098053124,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3317537659218347232,
-            7215499776802602393,
-            3679067069393094118,
-            528905986148648434,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2267999939142360292,
-            2669951026635006128,
-            7649029265474584541,
-            675139360822056944,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2722208004462129786,
-            11846999226246125249,
-            7933952886944555679,
-            1405518631704369921,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3397513762240642248,
-            14335251281755332895,
-            10275560967250236496,
-            3279757068853852683,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10514352360055916861,
-            17759708980689340995,
-            16490062980027101337,
-            3446588192285617234,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8660280440886063796,
-            9873365818769902701,
-            15143466208339654432,
-            2204845526754048882,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17711069218078909943,
-            15840666439600117055,
-            1658157212966503075,
-            1184778010322551050,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14941043730227528624,
-            3263093522952610033,
-            6129633675185784475,
-            2509443422896914034,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13272792439702912864,
-            3943371021640880301,
-            5275056415037588006,
-            3297101152650296373,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6098286778354909423,
-            4431233020817499209,
-            9613055887906346620,
-            43741709643780763,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8090845864302862419,
-            14525276856130114545,
-            6738568616303962561,
-            524512929237193952,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15452529553417351929,
-            11452102522612820569,
-            5697480856344844180,
-            3101258006607527014,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2273155221571457048,
-            13549317320065784054,
-            4195493838151205319,
-            1022427994417806263,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16206715450879330792,
-            15041639288572589462,
-            9316573837455560138,
-            1060194457025623530,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6844574628946581753,
-            10846781395649821643,
-            12467223056623195989,
-            1244707668873267907,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2437814962385052798,
-            15925352843225256543,
-            17985242398353345444,
-            1458102824276231268,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6586905577193730754,
-            6586370080023613847,
-            8464020550830513033,
-            2068577481025721119,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17802958772552799949,
-            9002566053047470796,
-            15267462006148574861,
-            1324341109407035334,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2671975415778699225,
-            5774331087222441508,
-            1808911487214363294,
-            146471682041139733,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15485736029358479114,
-            16787259852887000512,
-            749708214457659072,
-            3334910208377980681,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14764474725962234314,
-            845438625999731635,
-            15985096771916487145,
-            3144386394376720047,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13518513361883917955,
-            4859033353769558566,
-            3306927018923510587,
-            3159632905312409376,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7879507561089942391,
-            6346596271436624361,
-            14385556725924210844,
-            1774977205276295163,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7777195488824436772,
-            1196009573253987436,
-            7899239423712116701,
-            1304482744150058824,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18002849155396914877,
-            15799798481969374790,
-            15280389078070907668,
-            2149274461279803234,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            61454554206104537,
-            7185689052959458680,
-            3153880314467235591,
-            751805185114050062,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10046168482462096821,
-            6968490438974971485,
-            8291737989350820098,
-            3067236250636562175,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8853115961797120189,
-            2182381768714057967,
-            1567432444303798131,
-            1278082654019046364,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10826101973696953172,
-            6286818169571509371,
-            13662958337111258565,
-            1890788797843073753,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4272558877471556902,
-            8869841496794535026,
-            11007306461678780999,
-            2699293784188192924,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7351788405367470838,
-            11783093717103317124,
-            6524275435243941377,
-            268879749638131059,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10380189551169112601,
-            15370542669758443186,
-            2976007844006606181,
-            1338448559449563546,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4546250900822552265,
-            18363373343826779888,
-            17554508808401026405,
-            2047990310832692705,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15297957591068658581,
-            4816618839571150084,
-            10876156854692274687,
-            2596980673859186900,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6304677229558797893,
-            9377518400923912572,
-            15641673984645263221,
-            1846810933913090766,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8043700338745584948,
-            7874203627578493401,
-            11698782210301661832,
-            2278117989749272266,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15216060043153549502,
-            1991121026111109428,
-            107148683139438215,
-            1601965139206658007,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15569111774672946102,
-            5290487504107632468,
-            4165555243471866086,
-            341401199942087627,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8163906759177253102,
-            8242924475922481750,
-            17231108575225543731,
-            877531147413773192,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3534390679635235533,
-            13794672858001793282,
-            2830626888579952986,
-            218131611587033849,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            21553607125621106,
-            5818146514196796721,
-            16421591864090098635,
-            1726580261152425329,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14167829613458681244,
-            11312318517575069982,
-            5409598036371744563,
-            1176596759444136972,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7133030037135471822,
-            10325679465730960523,
-            1918930028689074856,
-            2838226102785746600,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2067003107269279167,
-            17309708628294466716,
-            17260580121064412556,
-            2252188230411409527,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14179610407130209024,
-            10474376584462591443,
-            8817374292279742227,
-            175323740872706078,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2607914177020877925,
-            12368170573362340365,
-            8397125057555454802,
-            3371739610259592951,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14263576204445775692,
-            4287090985385916866,
-            9823898711584587848,
-            538899152798699901,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15655408815512416376,
-            3289920097887402158,
-            8605823793912399847,
-            574254501125613531,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11757239364985515708,
-            16710206734800464042,
-            14989664187747637961,
-            2925224187753554021,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1303336019215869090,
-            11233976203746869354,
-            10378561201806571210,
-            1231782542430288495,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5345853789487781293,
-            3885148282819815770,
-            4117320139557246363,
-            1381839043779267623,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14036263904849540631,
-            7050117359372486349,
-            10817701502792097875,
-            1189541838652924855,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            946722134445927183,
-            9685916972594535586,
-            12660888623201881710,
-            1958536671837367675,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14877079710371560239,
-            4002898254090883090,
-            11249419857226061102,
-            1757721357677625778,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7051719272553223685,
-            5208429203383767044,
-            15225742256258809214,
-            3204034620889928828,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11446041881928407105,
-            2905914347430710369,
-            16480505009285017279,
-            1200558159103306293,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9035272060855308645,
-            9525624573128365718,
-            14692273545569307391,
-            2167372012122250997,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12528885024207033647,
-            13847219762361586774,
-            8092437108097707398,
-            258004953913195893,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8433479820463839382,
-            17659869825006243579,
-            6730092430369086264,
-            1980153523519574619,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16542664384170964561,
-            1730877232460145044,
-            13594877389649005567,
-            3094809536609946817,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11266959633273450912,
-            3974788208120024086,
-            3027620299787920397,
-            3414969068972971378,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17766207493733276579,
-            16550230751181793503,
-            2784715261094391658,
-            1813111459837065231,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13524928513530126884,
-            2879803143365866262,
-            10685625300436736554,
-            1616668610344583595,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4290795596680254317,
-            15290569833433165695,
-            11643982190555085956,
-            676056470466068729,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            434842226099231660,
-            9345521146570892455,
-            9689614909412505566,
-            2351805367394421638,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16446405408594455787,
-            8869551576302564736,
-            3466246471656168013,
-            2306754851487388055,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4636261759790103923,
-            6781104500917523101,
-            4456060051895615488,
-            569579251262672024,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16902134215201626239,
-            13808251659375203391,
-            677713563861575456,
-            1938258692631958085,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8216349714694571482,
-            18151761839339317735,
-            2054335424864106698,
-            1652415171495561878,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8608327815701651123,
-            13286286104783728180,
-            6088450429909776115,
-            737363856564376853,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11807266287757505194,
-            2908619518779575411,
-            2614420455458829319,
-            2407844493967207330,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5266566871514801117,
-            250321065120195114,
-            6980833978852289112,
-            698261119708313146,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15726827175588787440,
-            12363592394209965166,
-            17288568103350778932,
-            1511651273024062625,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10540796802397694584,
-            12732559717259595687,
-            10983510480690081848,
-            3122494705319606584,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17079847243955045966,
-            17813816535382803370,
-            1592051988767543117,
-            1802601414669415358,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17046870320311755963,
-            11880945103679302910,
-            4581666758274787284,
-            3285992561187958024,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17556286340612200533,
-            6111093745688697021,
-            4405130676315219985,
-            2876011864074263887,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13000885431577335903,
-            12899911100261179682,
-            13148981129499734889,
-            1467727407849481435,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6551705095033017131,
-            13009725404826488133,
-            5156756769788270816,
-            785050188783618499,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1302718169169174140,
-            4694273215732703095,
-            11356856145858253193,
-            1108110305090086784,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            73353516025251816,
-            9302727839036202300,
-            17625576891206049864,
-            2527824396332670776,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            608627630839172947,
-            3441900912263686394,
-            13203390412089648466,
-            1772413087889537554,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5332914796094109203,
-            3074745148774051040,
-            12053288427793739637,
-            295385097177937717,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5028148040250706053,
-            9954837504121812798,
-            4269821292542038740,
-            1209554452998985065,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11725556436938456975,
-            9727084074996971839,
-            17035707471717110254,
-            3037956627845498633,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1023537040232643604,
-            17180562316737200575,
-            5175026028995298321,
-            2352897591096386564,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5702686284550114633,
-            8238602699060037574,
-            11016131110112258001,
-            2043754536019974774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8645966383098346106,
-            9219123577903052161,
-            14099314937043054173,
-            601352552965015984,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18098222940212801520,
-            16141581185627547295,
-            14079084266859425784,
-            363355314717514734,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18114551101040351125,
-            11353379505261419270,
-            14285932818107967238,
-            1977689599701875621,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16112357638789273597,
-            10229133153566621553,
-            13864085250274892376,
-            169509270050285197,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1292715467362284314,
-            3130407010112978976,
-            17095629587439314342,
-            95877323195391154,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9924239795240637822,
-            12325903871326445204,
-            1929446433837370239,
-            2415579219013059893,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14056105712192186790,
-            2199199266531567468,
-            5765684382628610548,
-            1035601191068842217,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14260905617370092216,
-            14269534581379276748,
-            11457520580596266677,
-            3474768196698180202,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10452522930280573804,
-            3485546538919562566,
-            7884608846805563615,
-            530321708538464228,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6493924456543468538,
-            557874445160505451,
-            12487331449389274108,
-            880380778924425111,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2320261378007629841,
-            9609299385947198445,
-            1226950351565697249,
-            2597215294091955035,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3819245518101640850,
-            11532146674215571732,
-            16157175638078863315,
-            1686795891947814973,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7082828717807021094,
-            16026655265208904100,
-            191033378957817322,
-            2636784936102212457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6895595949493975217,
-            1664744713127149697,
-            10064531715609011815,
-            2947269104996683943,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1799899399005479106,
-            14625468416981626058,
-            16676543085039096415,
-            3235486488441075799,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11037896866073747813,
-            6581876335277488824,
-            14703458403787195557,
-            2649876612285908769,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18164031170019394188,
-            5987423309258185878,
-            13456506023585113830,
-            474121558321008899,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10760496833184293497,
-            1411853243184718781,
-            11494136089154844803,
-            2023700125991712893,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17382535159090703337,
-            18205635443981187136,
-            13456999603556207758,
-            2483172054988685149,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1899057612864984524,
-            8783331788519985914,
-            2684346448119386312,
-            1083334510625744045,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10397129869536440879,
-            11467307068342897081,
-            1868547561220632430,
-            2617928134318847398,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1043308494826909969,
-            10259537656737809562,
-            9130211646221852588,
-            2108434508157205935,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9694928097005897456,
-            1563044794063174641,
-            17720964584823748272,
-            2252244371191882075,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12239131065744003872,
-            5871922251331365034,
-            15067535841536449313,
-            2618258728057473207,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12044868707268315210,
-            16683665789770918471,
-            6002947880105914810,
-            3397631354452756661,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7424298609491174622,
-            6620491828381812953,
-            3016020892639604837,
-            3162128660672440792,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            751227489114178600,
-            11713522506358337462,
-            9467561561683298703,
-            2603461678629803754,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17832312900426446516,
-            4361772503267064463,
-            16700401883127360527,
-            3390390745314979071,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6290812265889163612,
-            18060201589598922692,
-            2013167904333790774,
-            1281494915844187854,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3036345568718252044,
-            6429278933321627311,
-            17125907537598505456,
-            2386957979544819281,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8914211730769736550,
-            9784565903889636712,
-            1062650658191828924,
-            2012403828156102782,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17192583789479976782,
-            5536163974204568891,
-            11386770094621778645,
-            1098107873492870229,
-        ]),
-        PhantomData,
-    ),
-];
-
-const C17: [Fr; 204] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7292252341130389983,
-            5307504569589737372,
-            16992926528797561953,
-            627045074216603663,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9770498569893744801,
-            5413716481116539750,
-            17777728275467954339,
-            3389668535745979173,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8269777253274176070,
-            13059502912548218285,
-            13554854549968565642,
-            1739486475324856839,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11337582804795103079,
-            17589812063906577578,
-            2858126744679162956,
-            1773534909680675700,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11388266335032883601,
-            11360269055529965601,
-            17297845021712239650,
-            2569372208404294164,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12029853073329974043,
-            15638617524644031067,
-            9928368334863226495,
-            3121589277601404642,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6852481322293566177,
-            7776543627838131048,
-            7120485230351636038,
-            3400194279813797235,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4572715577394721931,
-            17734716170789849865,
-            9020362670498029448,
-            1980479513722541411,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13320397662856433256,
-            13950953995287897540,
-            6459580376623147676,
-            3481807830996773171,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2517474249183571587,
-            13800153237136793348,
-            3826842149251055826,
-            2676718073766291838,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2604814814603919277,
-            18243522117166416209,
-            9624737938959252763,
-            3068368699274049774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7197467090264733299,
-            15826058669928524697,
-            3003616954217811968,
-            863115837785574280,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10181191055863183702,
-            17975562342053343866,
-            16119412070733080299,
-            136941052012877322,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6072134979931027746,
-            11096154678023939337,
-            14653547483689710866,
-            2410528961493492657,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3966192216834001705,
-            15168275066231328008,
-            6773640371780420581,
-            403482020823269393,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9755499075961053096,
-            2671621097890226961,
-            17548493626520984104,
-            2963697780980194576,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4108934430458706476,
-            15042768900110574697,
-            5803895823809825074,
-            1655198842119708314,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18244491280952794237,
-            2054377312512235621,
-            16654084927043246496,
-            1992538323207893539,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16178345377144879547,
-            4008556655056055629,
-            11757845334912315397,
-            2906308003159805094,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11455858604417608246,
-            3564825969832427328,
-            12489548199045719144,
-            823051451334561245,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9551428809958289406,
-            13924071978993538999,
-            4709020245176860498,
-            890642299986367900,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17838642216932050289,
-            9373615754660374424,
-            9146633162440840133,
-            530576729530559618,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4122995240023341564,
-            15977049053877287394,
-            15396130067149249539,
-            1048844016092499072,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6129542526323088477,
-            7759536607396978638,
-            2058899030805421448,
-            2054497455095264166,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15009793219189864559,
-            3124312198344508213,
-            16460593336018751280,
-            706937637664015931,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15068063006479377809,
-            2642020208202655510,
-            8213057190650012392,
-            2825865862041791209,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13303535674331990724,
-            9513788275693287695,
-            14779132385210797407,
-            913090239489911630,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2255335023377663012,
-            18243077451152191377,
-            17178458803676127409,
-            577229949975746421,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7400816298570037426,
-            8867077313002394286,
-            4842001628354888934,
-            3048854939857930311,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7570822720319785325,
-            5895582739478430246,
-            13366220100988438356,
-            722554470410077551,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2313787344502880980,
-            7473394103076676574,
-            8629131665674740100,
-            2159239279098302472,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9966541004910292143,
-            2106143750011187617,
-            7841651310678694526,
-            432816683314385613,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1082126761265346023,
-            398017300702411393,
-            7097030301952068417,
-            1800912264407017226,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2089940933697178174,
-            8478686315814640058,
-            12955914098824422672,
-            2258519194892668642,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1989516332934741791,
-            12598802511425534669,
-            11390572501831742189,
-            158112320953901017,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11182311650794048379,
-            12075040518518824920,
-            2448080886413371720,
-            1280726198299447775,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2398542668203088620,
-            16298925548414468162,
-            11062457187215410824,
-            1261100051299892584,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12247517189279933052,
-            9222375959682786969,
-            7105146528951165963,
-            3055421708087342712,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3401989638107115444,
-            10367875572551578608,
-            8530152731086921958,
-            1713865112068857580,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15047944560579516214,
-            1668198045401929510,
-            15066237171015164291,
-            340914282976552547,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17994277112813363676,
-            18253792315208223128,
-            7585416075144410118,
-            1583919612399852786,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17127828387051111445,
-            17309425331195796869,
-            4935882524058023000,
-            1121803542622139006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17256526699400965508,
-            15871051068018046103,
-            17746109267452790962,
-            2424348218176018750,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11998458897651065571,
-            6857139836181942090,
-            9713084261062589137,
-            1884209809441136469,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            495483803847380168,
-            4546720857777059037,
-            13594611016138227680,
-            674865233929785432,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15769230872809829506,
-            7116911200324728231,
-            10195317957568857734,
-            864624027925042590,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11972453209862739914,
-            17470406722263589804,
-            15443517773975311477,
-            1353796166356575497,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1986057827928918867,
-            2738155224694763720,
-            10681900605572037395,
-            104618267600811968,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7662605143077396065,
-            15756452716096937225,
-            18367332039470274028,
-            2426394066282328559,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13197522258888275682,
-            11758960195574261337,
-            13406539194460253548,
-            1634259595182651968,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1380074471689215786,
-            12320505722231672692,
-            6973305802123490287,
-            1093181232686206043,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5638827889853889261,
-            6442223034640622353,
-            1987945802321263423,
-            3419274976681514278,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5235285817665750738,
-            6525658388256424416,
-            2505973966506917930,
-            2439963415958974004,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6328617277331824202,
-            4120235548248528832,
-            8160680517178006752,
-            961590336681620071,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9862943125462799425,
-            16741236534319681991,
-            2917216894794176344,
-            2218221887418467259,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2931713507299225153,
-            11411222401328564613,
-            13969372129903873358,
-            2015864333367962659,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4170841102126948456,
-            13768293921772755782,
-            10995325900084605301,
-            149663622441083965,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7583447391800214225,
-            15136973280095010525,
-            2910174099785597302,
-            3114503741420652883,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6830511942666453986,
-            11935237438389548984,
-            18193536074559552412,
-            1291155696004614780,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16454253627825692786,
-            10650722548757276624,
-            2366445191852516394,
-            383219316067529451,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10949830065248100889,
-            10246225009263494192,
-            16175425333861090769,
-            260975123207664114,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18218938102371084670,
-            14725485386079635374,
-            15210051559115197430,
-            3267168536358268950,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            459127261862268,
-            11457010791655497529,
-            15521814371183800443,
-            28112412103690305,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4860988561185076875,
-            10172166922040087221,
-            16128234527196934897,
-            3432188560629254514,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14939550873510831321,
-            16923387003008044434,
-            5179093857061247070,
-            2167355366449597411,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            167168772481926206,
-            8165944681350556009,
-            5170824106947519850,
-            1604431535156395229,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7420251246721978812,
-            9836073721448324774,
-            16229387690289386283,
-            91058365931508500,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13514510610396536283,
-            11554211646630445019,
-            4119848907593977963,
-            1531250970145361109,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10351075686457850757,
-            18139549550545383049,
-            6459608963185329751,
-            147220692295019348,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9676702037539010645,
-            15508556853297573945,
-            182343853666041127,
-            1449905033417010913,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3842034324821588045,
-            4016459504292633658,
-            8931337769810780824,
-            2615985235939530845,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10006607228540891666,
-            10973174499955939038,
-            5140061394206705022,
-            209339964932466847,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2271940316708923739,
-            452020716102694546,
-            16958239796560144912,
-            598330485139230396,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4061559571791354082,
-            14461026503946824881,
-            9231081696828346028,
-            600989704363528084,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17884969019283912266,
-            5977940506367049367,
-            3522281806519924359,
-            1737121764359938748,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            772324625997715060,
-            17588610163008374204,
-            15025767495070824491,
-            2178823503924164865,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13766899357081199968,
-            13527053829223821575,
-            8763990662099816169,
-            1603627487829489363,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6659247867632821435,
-            189164304453509125,
-            6177290123254499827,
-            1702090411598896899,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12972880783305203877,
-            911738283466839863,
-            11783222060137900477,
-            589948521376880593,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17014770697741264496,
-            14967795140547002853,
-            13511009969285928869,
-            3167887798171523078,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4342196413083629990,
-            14846056310884138599,
-            14105272652743201436,
-            2274942807040423051,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14894530976927720420,
-            2358877282007835790,
-            9397058835001009332,
-            2376312962054752699,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8722757622103452566,
-            5540846884829436454,
-            2330522313247426332,
-            110606594307505813,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3609137730544147103,
-            9001853614741952283,
-            17531626172552616621,
-            3110887702569976734,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17420622018313211371,
-            6818624812148803102,
-            17731176690431211822,
-            2168798487168012788,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12789148637225307502,
-            4484853307827302396,
-            10129991295568045837,
-            2837335110887895929,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16055966559392823722,
-            8244956887068458429,
-            12203688214101398505,
-            668946024460513584,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14482493724117225255,
-            3734936034934222357,
-            15246465105512608542,
-            14488394868662802,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4255691637106049225,
-            18423776153599250806,
-            16704167716215039149,
-            3151366679588255456,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16037749179343759555,
-            1287915480950813397,
-            12129532623196018345,
-            259096398165798653,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7335928316286732046,
-            11808906686677721330,
-            400179344179165694,
-            716162014223448731,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17530690745616574227,
-            1585271365090725235,
-            14874793762139437845,
-            591483341258255574,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13192744280057393684,
-            11807774932575826282,
-            836981929724253524,
-            959685345536127031,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3885607283411373674,
-            5909709223343714197,
-            11209688939373012278,
-            1347594380313182065,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12453168005535981197,
-            16008946012715435911,
-            3359276377544607437,
-            802632148127365460,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9423319762743501335,
-            17791015550778713629,
-            3873769753057738267,
-            3284438509244526411,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6816417857299435663,
-            2867360845532342743,
-            7032824609484507940,
-            1915943397534428860,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4211193942126862953,
-            11956834536663699835,
-            6168487429778166633,
-            2085511192567990629,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11631448928759488718,
-            3270522401912037407,
-            6321632729403222146,
-            3225282124703285607,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15876483419636982692,
-            12884062286096820170,
-            9964229246219008922,
-            1696752176266742180,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10491219481525214211,
-            12417793597863621849,
-            15192323949490931858,
-            1249510500080529924,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10884805589983294724,
-            17815610037846021409,
-            8424079723392747028,
-            1379604882482167394,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9316138203017197995,
-            2932351520579899058,
-            9380785056143438861,
-            3128113788716555479,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16299198514300951594,
-            13701778114795876230,
-            9542867707662438278,
-            1344958282160259310,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16634644913695231656,
-            2358382916893391524,
-            402863749037985119,
-            617065127169203157,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7959834163621849350,
-            13662500735739256165,
-            1128539073845459265,
-            3351648969633966643,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1489619702038293468,
-            1888160601576931325,
-            12403113263213488295,
-            2980310572121773075,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10125826471935734800,
-            13844267000837134309,
-            14046841647281787200,
-            2087211564084817078,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1208077616766374474,
-            15705632345452080401,
-            2870389956790542265,
-            2335829295144662121,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10972273158908972339,
-            4114645493094639756,
-            2355141684053919931,
-            1621507541793297340,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6235317029620937726,
-            15612009187699908313,
-            1811670623515571447,
-            1108073814586169847,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6726662198115963616,
-            768643722983965370,
-            8365501783071412462,
-            406547605941867789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10621942827497599815,
-            10977760171699927343,
-            11257746025838457224,
-            279437239624542288,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11425490160063630515,
-            10962602425169082983,
-            13602127163859842566,
-            2278065091089261215,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5486753736526358867,
-            11899795060517964927,
-            12271848137736689158,
-            183334280649231213,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5350306887625693158,
-            3729300380065202617,
-            15525999703819427314,
-            1305830519681907234,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14912128546064335303,
-            17928196592139103661,
-            14672680856938315249,
-            1305845099648494569,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8186187846810578391,
-            14195380892413208648,
-            18198336659791453583,
-            1196187094635411757,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3323853669210885309,
-            8018048018737164295,
-            12877271953664099211,
-            2667445352686398830,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11218823361210315778,
-            17900915941030707018,
-            11595822076664902321,
-            1943593742077941984,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16840121606167992997,
-            15998927003025412281,
-            10103680011339539619,
-            1525906527603331526,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14177444525021215970,
-            8809766007872784573,
-            1603621663563808666,
-            527330476389814144,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            816370547050122271,
-            11079335608152850321,
-            14759986226562782798,
-            1442277709054312915,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3687752727890389297,
-            11690044108560556029,
-            11579315340690592895,
-            23484889680755113,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5400155142930834711,
-            11215385559894337732,
-            14221983654899902663,
-            2789343181464276839,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2731569514741790041,
-            12076281682816689692,
-            14748324972908400844,
-            108783630792308838,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18109628425740079262,
-            17447934174400817234,
-            7402815880994046488,
-            82491655740887082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            361343836261399489,
-            4232121838994074244,
-            8831075767436019251,
-            639043256074286869,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4752455187477369316,
-            13943603378726609031,
-            14411167870191875145,
-            3379038524924073730,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9119156213890232190,
-            8997489159122018643,
-            865153417784461120,
-            3406992466606749872,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9284905622429120994,
-            11556933371984615494,
-            6601570080146425598,
-            945685013472921957,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12551960227409674358,
-            13122703070852107391,
-            3667806096015390041,
-            218366903181977015,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9983724033949977451,
-            17758380864165043834,
-            471310486952790478,
-            706530179741541030,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16515873983718888195,
-            2506524525808967201,
-            11720793911302483120,
-            672844911947774915,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16160318315301479600,
-            3615956919858247047,
-            7271982338111037758,
-            3052897827726312946,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6768483670794701637,
-            6194700252921471307,
-            4360679844884019663,
-            943583188547913807,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2790753797660583852,
-            10485495614664949811,
-            14762713685278515966,
-            578277360588520933,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13393601556954227014,
-            3896491510385509165,
-            7282997180773574981,
-            376173093410336179,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10427258903384796657,
-            10915469884440920913,
-            15077624736578936807,
-            3258078921171551953,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2400310784418922563,
-            5347968211712575,
-            13883047571632646847,
-            1567538653100942805,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17197363304969144143,
-            610058052933469821,
-            9550317007170852567,
-            2687768970087919987,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3968805966499399990,
-            533603933500110978,
-            423597736641380128,
-            900469029690261382,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15864506913883562103,
-            4808653173251278650,
-            133043802525363307,
-            2766557940774502063,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13325293995196781387,
-            274934807283922585,
-            3099775204673625868,
-            2879913399710757583,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13016132730375603455,
-            2270245414780200097,
-            14100579093474476285,
-            2023695662703555227,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10116289168021482674,
-            6225930481259447731,
-            15518750774846500870,
-            1492136458799939177,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8821189609536959012,
-            4559594830133378520,
-            4774614767988777329,
-            1356043435354344357,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3252066808741053731,
-            4008192357005012462,
-            17568934413552344430,
-            3215584050894023761,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15109439483692069593,
-            4968330697571860022,
-            2118841006642811053,
-            2240334349941394389,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6476890304718447114,
-            2341146452309290888,
-            4260624438351038117,
-            2458061764828008437,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            301764902536722886,
-            10205719501300605313,
-            2432480928852437248,
-            2192493849954260679,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8949594310319897744,
-            2507903618122494618,
-            3233158914527809091,
-            1847244754050138284,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14554233264693788355,
-            15082322508747217305,
-            13085855475964981209,
-            1925424672171244099,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12772402821677876256,
-            15894364638152242904,
-            16519668320046864358,
-            1485907498400122168,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17726224482022575226,
-            17304445552732670340,
-            18362078000020628530,
-            1965348329432567260,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14877294999297222887,
-            8590950539289351125,
-            17925069702916670940,
-            2314107637426840798,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            528045858718957706,
-            12714932656297916148,
-            8577183110420647419,
-            2911206703703626103,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17417106762140432659,
-            12861695667330683579,
-            18414397154352392459,
-            3455019988345246465,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5829347670088916530,
-            6738129991453836588,
-            9067698888906007013,
-            172390619282354689,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3898105325166853260,
-            11623636016380984326,
-            10726393903793131598,
-            2989159706797774208,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1789107298816589020,
-            16256050778449870837,
-            9261574161037396909,
-            2229195290715767516,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17983227735226245119,
-            7672053387672195211,
-            10279432796395301749,
-            2428493832724869898,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4203941504757403988,
-            15619095989608866741,
-            12562944521264120526,
-            1653595255714071051,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2648308887781794843,
-            16033079560153564075,
-            6082723055571400038,
-            2811580991301477331,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1484470181775256694,
-            7138499755166477610,
-            14149164226552948437,
-            841131479648292591,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11199495628688491744,
-            12313202135147463971,
-            8448429429350368019,
-            549555105772272101,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18226325040624989443,
-            6205062946992445320,
-            5676711016311442040,
-            2894689190719270797,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11111358643077931767,
-            13340480975682099260,
-            2295997164772124674,
-            1908561515417029585,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16045097259409974590,
-            7532581790709364518,
-            9862485611424107452,
-            230648085529750158,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13756301265944672439,
-            3368298917905435598,
-            15866324182674485936,
-            3424901317350322848,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18330448351949768379,
-            17354703599138918764,
-            17431013556990898934,
-            1052917935933476551,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9582964176854305963,
-            11314139604943989431,
-            12854060637948595049,
-            2469889325991405589,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7669916821843089141,
-            15906018574370509530,
-            11420418257900581235,
-            1576663783600316450,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10397348260737959347,
-            12582244843108231604,
-            17266865499233594959,
-            2923344534400366807,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4580838219963410390,
-            6998281241581831699,
-            342067656741944824,
-            1048223817817992356,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10882075961051915627,
-            13644879420509506696,
-            3451232012446288930,
-            2827230701328234787,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3869837251385538679,
-            5754357738509417038,
-            790663669744011810,
-            139587670837617949,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18435839049504169739,
-            12131636625771712146,
-            15473396980692859064,
-            3428595079950797723,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8814471086230511889,
-            15317741260672763932,
-            1007303984786674432,
-            2266449412798742348,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4575963229281236506,
-            13094255914077542062,
-            3356813886241085626,
-            981705348316913730,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1594753548766376287,
-            3689595185585046757,
-            4189202971579593322,
-            1271763295500243782,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14317818765606868728,
-            12454930824019207876,
-            9516696877536093323,
-            2226647923348313432,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17844466200298941809,
-            6394558330573875455,
-            11346195713197970279,
-            122411831335140341,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2755387617144690850,
-            15576699197694299380,
-            4742668425280869635,
-            2991593130020658430,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18004528248003116062,
-            12960866219589167047,
-            15540527351189455725,
-            902067525391485627,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9597095043228951376,
-            17440546560370346191,
-            14030942041646571962,
-            3349126755559718757,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3810477841358245938,
-            2151022708177666170,
-            9558552667955040695,
-            1056665527750128955,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14047906805656433987,
-            11745288896400257800,
-            8988221515192962803,
-            1274674088517662601,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3752553732554065601,
-            12179665721697960509,
-            4217578789457484887,
-            3474734284674827441,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18413132691114950031,
-            1863787118843162269,
-            17942450937489225243,
-            244406194154966566,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5024952801148797099,
-            13491815245615855361,
-            17524432597936120816,
-            840441844579327488,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12007271593739235064,
-            12505380686259883637,
-            10498760114513391285,
-            2927892046266857267,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8793576307611152186,
-            10502340758704656314,
-            21074051912516805,
-            1777998917983602325,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18414739952627993787,
-            1642208204131783423,
-            4614430499802411841,
-            3192559807599889976,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10215528277769992536,
-            5366806292943209798,
-            12599166827852854950,
-            1806952712837083666,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7341627092808693216,
-            14014710215334634941,
-            8062626120303876496,
-            1557918696578225042,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11919781414376003109,
-            16327088205722306518,
-            11859936422108346877,
-            1830560848588733036,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6140821268778194852,
-            9138636404050223442,
-            5636469550062506719,
-            2782845717446476972,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14761174148393954799,
-            12354470775848919361,
-            9297051259452264342,
-            1221613857032317911,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8321279017989722097,
-            10666315893350547264,
-            6386084929215118156,
-            2155148544348916079,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2666103728901711976,
-            3609619620875248489,
-            16503787667035235012,
-            1161883711312261064,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14862197427692074090,
-            8308296419611520998,
-            18381191162027722069,
-            2454537143858888017,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11425477810018555122,
-            8126687300612823438,
-            394656358108876216,
-            3477509318036557607,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5761585140562390136,
-            6465711349563255911,
-            5901127566796294624,
-            2581752206198293153,
-        ]),
-        PhantomData,
-    ),
-];
-
-pub const C: [&[Fr]; 16] = [
-    &C2, &C3, &C4, &C5, &C6, &C7, &C8, &C9, &C10, &C11, &C12, &C13, &C14, &C15, &C16, &C17,
-];
-
@@ -1,16404 +0,0 @@
-use std::marker::PhantomData;
-
-use ark_bn254::{Fr, FrConfig};
-use ark_ff::{BigInt, Fp, MontBackend};
-
-const M20: [Fr; 2] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17920654652615044516,
-            5315228629426935734,
-            16921197825591404379,
-            2189197718402122891,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6011552397623934548,
-            6038211121966084978,
-            18341914591778152024,
-            3351495707820976067,
-        ]),
-        PhantomData,
-    ),
-];
-const M21: [Fr; 2] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14492560017976159917,
-            4919046997046981090,
-            263614296871819263,
-            2759311356546844781,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16349074296086608540,
-            7458233725163740651,
-            5446347661749231797,
-            1359884895167300752,
-        ]),
-        PhantomData,
-    ),
-];
-const M2: [&[Fr]; 2] = [&M20, &M21];
-const M30: [Fr; 3] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17503398944334214103,
-            9230635622294457373,
-            15972181701675803672,
-            1035501513956951521,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7425745125907058133,
-            6258575766396631982,
-            6672317176208297581,
-            1888294593578598440,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14709430803763308375,
-            18229395017556423917,
-            14478888826236012152,
-            403155403580284040,
-        ]),
-        PhantomData,
-    ),
-];
-const M31: [Fr; 3] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3555859163746403433,
-            10741605658797293728,
-            13576548464965265343,
-            569316227345355306,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17300927987635952711,
-            6895825125235677893,
-            7535349927233173892,
-            1004824698395431955,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1681560448056422141,
-            2077804014413570479,
-            8164163776005235820,
-            19069250833956841,
-        ]),
-        PhantomData,
-    ),
-];
-const M32: [Fr; 3] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2951080920043055536,
-            4426455047811528265,
-            8768180898619925469,
-            274491192378258711,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3644467615925741357,
-            9640990985829502495,
-            7431090390984653628,
-            1714274394685692289,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5508715729952870696,
-            15229942319626936684,
-            5551071126244229286,
-            1333837310051341211,
-        ]),
-        PhantomData,
-    ),
-];
-const M3: [&[Fr]; 3] = [&M30, &M31, &M32];
-const M40: [Fr; 4] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14359397154288353683,
-            7568855942418732343,
-            6575810714121073298,
-            1603235339818113405,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14467917761785273344,
-            13691650013372381733,
-            16710733772935941799,
-            30933331812013214,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13511918451404512991,
-            11693407379192458206,
-            1210934872435280544,
-            1012602124237660672,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4023832590491479321,
-            7849041908784095335,
-            7565170275386684359,
-            844576400700517462,
-        ]),
-        PhantomData,
-    ),
-];
-const M41: [Fr; 4] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            108602273466007515,
-            16249964682517188766,
-            14716043256467990562,
-            2400229853407491193,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4862920257782450814,
-            3226053952613652889,
-            1631153030930653167,
-            1442773447642652670,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8370579706176240098,
-            2747575895192326834,
-            7476752927725609723,
-            1873900219658693905,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7004774202290679443,
-            8039918781331262335,
-            5749090311247325586,
-            539656596697807696,
-        ]),
-        PhantomData,
-    ),
-];
-const M42: [Fr; 4] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6353895381692353302,
-            4091743744793837442,
-            9077326780205011538,
-            34544310704927253,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9295621759099625059,
-            5691074610304098547,
-            15519981315894646394,
-            1805966540740592018,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            229371995065088227,
-            10773137276463677621,
-            12714674672408461909,
-            3455435845664866576,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12320988475828513141,
-            18271494081361904966,
-            8691520997971179324,
-            6932398130607138,
-        ]),
-        PhantomData,
-    ),
-];
-const M43: [Fr; 4] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11312809233593050308,
-            3529573911206627621,
-            7956373469564173497,
-            1979278223697134082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7149834460905864608,
-            18396687107781941015,
-            14544169561284541756,
-            2050595064587984231,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17260555154749277858,
-            12655030879130418755,
-            14277566629856478313,
-            2956230555600691855,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15197494995910796725,
-            5720252324164162974,
-            12162676907437121590,
-            266386469213347190,
-        ]),
-        PhantomData,
-    ),
-];
-const M4: [&[Fr]; 4] = [&M40, &M41, &M42, &M43];
-const M50: [Fr; 5] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8594639770776629194,
-            7546478767167658132,
-            13640771885987542429,
-            2983573406304057248,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7568267708875756601,
-            13400473156960276841,
-            8909009501252494912,
-            1526036498565950297,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2547844449502283135,
-            2224108112190280331,
-            5673020101247739355,
-            2345396629209515698,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15558089179312976177,
-            16167618167531458342,
-            15432044933657868742,
-            2967476811440942260,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10608174090048140849,
-            13213626376414878014,
-            13404042261614816312,
-            2721400799155958153,
-        ]),
-        PhantomData,
-    ),
-];
-const M51: [Fr; 5] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11346081397337899078,
-            3868235280280109022,
-            319557847744162712,
-            1209732933464865258,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11376777521248494128,
-            10033155562105107140,
-            13792433313831876332,
-            742813886299698787,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10050350003969446170,
-            17103769101562895865,
-            13854814582013971324,
-            1137303757160281968,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14384766868832424427,
-            15472675089588285364,
-            15494924994364919899,
-            3183826955144694209,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6270444413722244645,
-            501671752026508719,
-            9616448189204302209,
-            2598376648723208149,
-        ]),
-        PhantomData,
-    ),
-];
-const M52: [Fr; 5] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13287419000174074501,
-            13394502194651237557,
-            8243443477577732863,
-            1186060625675204365,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            774099430942415848,
-            17531786356092537825,
-            7173275843255667189,
-            929043849246395661,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1652568008606057997,
-            10894553402606003859,
-            18037866496999156598,
-            1548209455100551106,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3669441683318748640,
-            567973582796654639,
-            15779758718660083487,
-            828770557279727589,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8383217212636690614,
-            1258826470015388767,
-            5109582871684463215,
-            1202981796906886766,
-        ]),
-        PhantomData,
-    ),
-];
-const M53: [Fr; 5] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7030314969498925190,
-            1161088964787644504,
-            17064206938715911703,
-            794961854477645221,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5761901914334553971,
-            9685522708942809557,
-            15842331512316131489,
-            3032944866534977624,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2788692919946843205,
-            5105760774027740562,
-            2058134464513503103,
-            253076462901415616,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5198993834119874099,
-            16370212851241782523,
-            9508530031024693107,
-            1060192723715559555,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2045782699408783994,
-            5426962458798465580,
-            11483568061919909927,
-            123209000727701407,
-        ]),
-        PhantomData,
-    ),
-];
-const M54: [Fr; 5] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8202270750577778799,
-            1895384160460365701,
-            5193294109199544270,
-            203157780055891531,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12208013153637181259,
-            13186458422586666809,
-            5732171595128546921,
-            2796975827501539993,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16614824564905878180,
-            15260364366331942662,
-            10927524238026096875,
-            127519547071479423,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16008225481258712634,
-            14052030092861838216,
-            8711591643409463406,
-            2239868554131608592,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18353015814443867824,
-            14002165487333845958,
-            18394192751971753383,
-            1924999578119127003,
-        ]),
-        PhantomData,
-    ),
-];
-const M5: [&[Fr]; 5] = [&M50, &M51, &M52, &M53, &M54];
-const M60: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4638859865247591341,
-            12701419573696611182,
-            10927713859705290760,
-            2891083094489377082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17283216382944159232,
-            12785675483883902088,
-            3221345588442989227,
-            2583379262805975007,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4496528741566068750,
-            12951029859990105472,
-            17436902998813738947,
-            3009168287118031398,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12146716499954663704,
-            426172046362785500,
-            6713158152914667931,
-            945850408016090134,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15350602447123226377,
-            12857298404022704109,
-            5415104157501100675,
-            3105930687415033859,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11926169870823184868,
-            18408804429231627786,
-            106794418310158051,
-            3232313497447522079,
-        ]),
-        PhantomData,
-    ),
-];
-const M61: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2214059413692101491,
-            17295904315807324112,
-            2778093539175007343,
-            2782376324993009899,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10285077661630684933,
-            6063780020296212624,
-            2359426856511789438,
-            1115774118083814580,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4379082009665736419,
-            3983537824532062636,
-            8140028662269122958,
-            869279653850456957,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18320850073221173580,
-            14414716832734850219,
-            438007862716738057,
-            1729753057828074789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7191173392915360112,
-            7097085321047569378,
-            13738691375754267902,
-            317289218956017403,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4582362717460535737,
-            6367217915810087289,
-            13108746944555970705,
-            2156986887409587035,
-        ]),
-        PhantomData,
-    ),
-];
-const M62: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18145278755416295485,
-            524548675471404666,
-            16525699892622690367,
-            1722835628533022303,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2581045800423219444,
-            4193289988142647848,
-            2830010522968679119,
-            1271795704168160867,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9756742948254496333,
-            15558541812673996799,
-            11997823755237985600,
-            3216724678490440327,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6521932484404105849,
-            10829031555505758284,
-            1987854682943391613,
-            1758016003740587363,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1758488733381846652,
-            9639160368906177311,
-            1065893754149511348,
-            3065609749058878316,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13764436797397813905,
-            10686130497923712399,
-            43844932480964714,
-            3415968956758372912,
-        ]),
-        PhantomData,
-    ),
-];
-const M63: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8919748998971519079,
-            10553111342707770578,
-            9953423120640136427,
-            1819635885589578006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5209759767013746708,
-            16593707670848447867,
-            4609423532453343554,
-            2860664177642437119,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17330573047624078026,
-            3850321709524636443,
-            13291721210169349845,
-            1868038419903656256,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7856915485549279447,
-            12706984973401452164,
-            2377246448575067198,
-            1576728444073691390,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14992657940983605827,
-            10312218628588369853,
-            3140191276548286603,
-            3389557962708084372,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10655756216360734413,
-            4564680388860364352,
-            4325676119075506465,
-            2808908741151492632,
-        ]),
-        PhantomData,
-    ),
-];
-const M64: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4984721526010472226,
-            17406661742393645519,
-            17548044302249509509,
-            106017418313204681,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12951524841757792594,
-            8291894285233926973,
-            11874723583407714427,
-            2386449966227833373,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14661959023465583575,
-            11836185397591919405,
-            4258337983485861422,
-            209854786004578573,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17594328872939903264,
-            346249961561394107,
-            13508126117371595166,
-            188120850806604980,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4706935315674505056,
-            7299045132923719451,
-            10803083285237255319,
-            3454822469685879090,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13035546982297276405,
-            11701431755880720851,
-            18172618078559223709,
-            777900125851271641,
-        ]),
-        PhantomData,
-    ),
-];
-const M65: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14411489553823947513,
-            3865314241170707077,
-            13348642907516700585,
-            1047539638147959469,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16612002973603639529,
-            11931816923114202007,
-            13958955286180319413,
-            2167937634832090867,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4560623273482800812,
-            1057854953736242588,
-            16569945974343937860,
-            2154932369486393023,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            377996329530288687,
-            10092292743557633859,
-            11502930497133270711,
-            2573961855785576438,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8623515214735794042,
-            9361282133367311061,
-            8112263434035257992,
-            1540093938566634066,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2086158409009819118,
-            7965399093066884234,
-            10083485319327927108,
-            2869446179081268457,
-        ]),
-        PhantomData,
-    ),
-];
-const M6: [&[Fr]; 6] = [&M60, &M61, &M62, &M63, &M64, &M65];
-const M70: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15153710674085495083,
-            12910643420133432704,
-            2797289784435140373,
-            336388720017723838,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11972976329571950059,
-            371022540053762139,
-            9322069185617685759,
-            1342667635081676098,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12461566435888280899,
-            15085437656617753763,
-            21913818999056441,
-            425838553542950025,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8998952853701945464,
-            7973536954664772680,
-            7280797413922516523,
-            2626360389845416593,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17787563900705845092,
-            6596946242681655989,
-            18089912966101624785,
-            88569470516311657,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18239376166712241201,
-            9640059949327936303,
-            395695242325669491,
-            3388245480913994659,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3953506912772396491,
-            8481907656787123855,
-            1363713934428292130,
-            673135832251482811,
-        ]),
-        PhantomData,
-    ),
-];
-const M71: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15231257745032047235,
-            3368074060636949517,
-            12940491953008342098,
-            615955003522796962,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16146138817802010232,
-            7954643087326880845,
-            5916103508475065400,
-            3435393667225444219,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7292058091148304599,
-            16371195390110140794,
-            4038060604722507935,
-            3197895022183849391,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18246306609367967672,
-            7717013989355759800,
-            1047553556256985997,
-            2226991436387528413,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1846503849623479771,
-            6602354247593733144,
-            7054195490191063578,
-            899877873100934019,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17824038811286392381,
-            3007582624792791823,
-            11227371069140829820,
-            270084078352142893,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17181394116120442294,
-            14805749540160373387,
-            17162393938063125221,
-            2165382670867500337,
-        ]),
-        PhantomData,
-    ),
-];
-const M72: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15380619388071125957,
-            17306055439625527261,
-            18359409776286739288,
-            2712495107812141496,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7501581060754308407,
-            6051562241440345943,
-            6259030338210879999,
-            315205126804164614,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3333476736972064507,
-            14161866371129501447,
-            10882985853341732157,
-            1091253282197527209,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            484090758695808444,
-            1632064781492104047,
-            9359752622391966713,
-            853814603714452911,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10789631832222593428,
-            14193945991620520605,
-            2909786704255807771,
-            1348307090865682554,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17159780502144803077,
-            14748747789544307155,
-            12921340618858028242,
-            3202154752625411912,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6543038232019869636,
-            17142802171521904932,
-            16877445528060545057,
-            791090010111193885,
-        ]),
-        PhantomData,
-    ),
-];
-const M73: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16334896021043627986,
-            7768473698819830,
-            2855322591557026521,
-            803707183128705570,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13976137709200629848,
-            6725224039644603017,
-            4784109466002907302,
-            2379664663519771877,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11089367531142667202,
-            18079936177166895567,
-            14884836913133118455,
-            1627546644246868174,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14966906544187437744,
-            13696492693779353655,
-            7664501992605436984,
-            1214333401958244313,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17886258645461548201,
-            4514761323994214486,
-            8609942905986883386,
-            1321083986715985026,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7622889142015216264,
-            4822398275786818643,
-            18445679928390490505,
-            2724190772789325214,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            700724908813532328,
-            15931290816097729588,
-            1068217738298342967,
-            2397587266880522030,
-        ]),
-        PhantomData,
-    ),
-];
-const M74: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13080547902885263562,
-            8819773114950398207,
-            2015848004488644183,
-            2091735685277275533,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5096205942083357414,
-            4889633861347382092,
-            5483819977121266113,
-            476147451471402753,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            945221493052985493,
-            2293417977035817150,
-            8811929219467456714,
-            1597854979389331101,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16290456475513250589,
-            3248490747362579336,
-            8489440510320288138,
-            2208229633467109653,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3734943238059121493,
-            5113786676237859783,
-            18083735363423740730,
-            114988026353045855,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9484892742385135233,
-            15027370268376890928,
-            1019764075115735592,
-            923072184606340158,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12091379544557163853,
-            5086992870863319786,
-            9356529585655250410,
-            965550116392620465,
-        ]),
-        PhantomData,
-    ),
-];
-const M75: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1103037793894593011,
-            10432439615990921475,
-            408676366408273636,
-            690619635530153027,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            338796187879157787,
-            4590422126117478264,
-            13264132146164571852,
-            1700034272014686809,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            634588756552689540,
-            14612509262288776940,
-            17581714118662493727,
-            625681130087745623,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12798141042263510744,
-            7634996891896056137,
-            16968308664588799211,
-            1808845974602938301,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6831500197562191322,
-            16025482613131399041,
-            2183776491731187327,
-            1899858121342678663,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9162036728711234512,
-            5012527718204776775,
-            8455094625312164545,
-            2973721298671319596,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5846592512113055395,
-            14220618095673782396,
-            1912496591980433834,
-            2449211370048197192,
-        ]),
-        PhantomData,
-    ),
-];
-const M76: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15899329554679246838,
-            12880134623787619953,
-            15623908173414171158,
-            3339865870496991995,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10833911041392541383,
-            16356893646285478712,
-            14783870596976614099,
-            312756694244033129,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7478594237107083214,
-            3659132251006305689,
-            10445062863890901255,
-            2489525048551163673,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8700082131004252530,
-            462722613486510821,
-            17298554786910045247,
-            1921361640946991195,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10742402570912626917,
-            1312598667268358771,
-            5084413351343896334,
-            2850961198365129322,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5033621235169518140,
-            18080163549950274768,
-            17081551865292860831,
-            1383466567395944636,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6813448304966054284,
-            4196296168750082204,
-            9913129716667601372,
-            2739139277729918434,
-        ]),
-        PhantomData,
-    ),
-];
-const M7: [&[Fr]; 7] = [&M70, &M71, &M72, &M73, &M74, &M75, &M76];
-const M80: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2255668496628234545,
-            15246293562682024538,
-            15862665822765969366,
-            1243456397736102236,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16254193488763322293,
-            1533513164208426134,
-            11444334254894307337,
-            3068794511952433848,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8662207599245111094,
-            8786014275892687901,
-            14922263297622566641,
-            2112998454455766115,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2653527387116402910,
-            806244264821695581,
-            16316352272265471853,
-            1810063981454795415,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17437453649933389597,
-            298438227726495831,
-            14566389561287642520,
-            1231699219419341634,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12491372607501164237,
-            12920017754031294101,
-            11406178382388793813,
-            3265330427185718993,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10532166906874914189,
-            12091364960855445372,
-            3358882152478472325,
-            2044284482362886129,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2800182792760206347,
-            8247774128093941838,
-            15687011760647641756,
-            1102416204204783600,
-        ]),
-        PhantomData,
-    ),
-];
-const M81: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11839766509476138681,
-            15182680952613917293,
-            12636917596312885306,
-            2752711190835043578,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3479167521652508691,
-            16947501400151910789,
-            4525666756929148308,
-            2051138663884975316,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3638686653333512315,
-            393548234762867696,
-            1037358691797542596,
-            747221895727385999,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15001202976531046504,
-            17844810475171172644,
-            10147408246519795702,
-            1982156012281006857,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12068809519975788695,
-            7530228142702832958,
-            10728265213396269755,
-            2931381568329038734,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11639828332428921585,
-            2772063953636785198,
-            1800775120054476863,
-            2251967938363266964,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3138959992337660621,
-            15606812702990686187,
-            10784281102871907553,
-            740964802748796833,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3650954844299960871,
-            6397675104938520943,
-            6132290924930836966,
-            1525692250642861978,
-        ]),
-        PhantomData,
-    ),
-];
-const M82: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7227750274011365707,
-            15575317956934748098,
-            5955028652175233335,
-            3326981543517226311,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            244726005765787548,
-            1263442196663076794,
-            4473116523579748775,
-            2360916037159653637,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4029026601589582344,
-            16257666970654163238,
-            18157371025688380901,
-            2055368568444425077,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16364776769946811217,
-            8392435384377632816,
-            15492612634443542975,
-            1883628547661867214,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14461727195014350583,
-            9914840490577163548,
-            6987417683357009534,
-            1309185457053001434,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13408372423150161508,
-            1238884141274264250,
-            12327966782215889832,
-            1365052605310995345,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17161148451828057222,
-            1401647375850020173,
-            8205706537059602650,
-            869943046889911163,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4537579901045284808,
-            6475124524948511996,
-            11417496761135914773,
-            3449498215870560310,
-        ]),
-        PhantomData,
-    ),
-];
-const M83: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7403740575183550932,
-            5492933594909241847,
-            8424978560738301913,
-            2501195922569655789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8373691352551572158,
-            18281812907118486818,
-            1060666410919446181,
-            244466207255837044,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            395869971213396941,
-            572814494255117623,
-            3088417865576690550,
-            2067745341466765696,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6088221560605705409,
-            701850361942222917,
-            4151782076326797753,
-            260898601445093030,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16641315039798820857,
-            1819384504790812579,
-            6283760863374668208,
-            1179525029818500286,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11347049262318212507,
-            6540298990298500665,
-            15643179592913516040,
-            2450756118620315903,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13939703139834455318,
-            7984488369943119074,
-            1819012972440096861,
-            318939282070357759,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7022704516180014837,
-            57015810177143933,
-            6371697808397489234,
-            693774516843538333,
-        ]),
-        PhantomData,
-    ),
-];
-const M84: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12686170943952318550,
-            12350881350819275227,
-            3761503622030959427,
-            2749243505546214433,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16673947135535479479,
-            801370132127367281,
-            1511375809140931618,
-            2591054999038865781,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9306118514943060375,
-            10533802508785767705,
-            4478519753732276308,
-            569082634143603594,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2497095989441851174,
-            13062842136929996663,
-            6201211314425832868,
-            1517364916869808191,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10985863539598666595,
-            8282585320695460786,
-            5274804099406602306,
-            2838191048942409518,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7141981134193490754,
-            12249250995665134849,
-            11599690625873169509,
-            1546387837778838646,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15210380341138187176,
-            14073377472803170842,
-            17409890502761546052,
-            1999843749584697446,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16212769864113496622,
-            11081819146068091212,
-            15095838632413719042,
-            1467113415038287133,
-        ]),
-        PhantomData,
-    ),
-];
-const M85: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17050837798655321153,
-            11961364015963024192,
-            8246338996987557854,
-            396170937774182291,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2294140714063582149,
-            18014873586569803275,
-            14007724510282568745,
-            3386001132050509419,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7119590265893126766,
-            301460520994730871,
-            6481998031099773548,
-            2179619735257278400,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1959566076023465016,
-            432796852915486623,
-            8761819341679643405,
-            2929858201223893974,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            871063421526105447,
-            7385052628051317469,
-            13296967943141165370,
-            2249659682038782440,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12505987164905887541,
-            13574770131989213462,
-            17268877444945765927,
-            1248883523116576646,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1868342177156454736,
-            5816202254671397474,
-            13198872788511429418,
-            3281114375517646769,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14521913349287456436,
-            8680655065544868628,
-            18443755915043944681,
-            1732200900117628100,
-        ]),
-        PhantomData,
-    ),
-];
-const M86: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4037399471801784384,
-            9800963650356329005,
-            3279375485943748295,
-            2537229256314000185,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5703479470492908062,
-            5924379096002794205,
-            798141050265201213,
-            572050609823007645,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2549205175438774307,
-            297982251046194309,
-            13986098066176293819,
-            3448413826252920285,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3778619100368316494,
-            14286859714996134249,
-            8202033105261720912,
-            692580912614467227,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3429469000178655774,
-            16615299815410558776,
-            1330178421241372551,
-            3473947194552977374,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3575289936623463572,
-            10017265270068184560,
-            11041155754024205488,
-            495441303772336872,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8169883513122215949,
-            5417577610471981144,
-            1543667019454509214,
-            884514807666100253,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15306107955554496623,
-            5406220337748151949,
-            7063168204110225769,
-            125826083323093451,
-        ]),
-        PhantomData,
-    ),
-];
-const M87: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1673811273576141506,
-            7313553960346080301,
-            14145374863817065622,
-            1252394632090100430,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10592237289614118654,
-            15053882301889288606,
-            6250127694909659064,
-            1999747390790141203,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10477716079657411402,
-            17864755037712071185,
-            17198721660078180285,
-            554794958195656854,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2576652323279856622,
-            9417294062430276226,
-            17257408946667313823,
-            764494437533172820,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5423115746929206077,
-            9761114846425238055,
-            1955947183504845442,
-            3020567131082961054,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3798404539235065961,
-            4749669480754476544,
-            2677466985072091176,
-            1399381374044319061,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17716049539803597973,
-            7418521630427558634,
-            3812362161667919225,
-            3056209747617572885,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1380607239449817182,
-            8262792679541496354,
-            15732344911094318968,
-            2725787585785546350,
-        ]),
-        PhantomData,
-    ),
-];
-const M8: [&[Fr]; 8] = [&M80, &M81, &M82, &M83, &M84, &M85, &M86, &M87];
-const M90: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4933524686769579967,
-            2042354632144177965,
-            5885273385733824968,
-            2068533996940005711,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9110926771305576152,
-            13314873486575187834,
-            2882958788679640750,
-            2806226258125991919,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3715463980073666953,
-            5076785927406339702,
-            14526220592638695193,
-            2136213908020929893,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11227337991247801785,
-            7772718050644120808,
-            844329598009127383,
-            1884316337972287967,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2031281950090870775,
-            12352491654285243441,
-            173710349401940707,
-            2226330466078919554,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6832215675494246845,
-            12996938043462462878,
-            10073104646519412652,
-            1606940293481727399,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2339075816642727629,
-            15700761457533662474,
-            17512352349010043309,
-            1367534662767616862,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16646299738950045111,
-            10300163902214431013,
-            16149777841998515604,
-            2265189894155329612,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7267291729618880260,
-            1674036378856840607,
-            8180186895589336849,
-            1519757091481451094,
-        ]),
-        PhantomData,
-    ),
-];
-const M91: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18113196693395655642,
-            16644408848458654383,
-            14252718983598861229,
-            247103141222296861,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13661064719527002496,
-            2953046903076919906,
-            1808862765502275998,
-            1997295212464265691,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8298441807001115170,
-            15584293702156708717,
-            1405626373811520781,
-            836922532148659748,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1617326143950119759,
-            8622539939464878959,
-            7491054804629558219,
-            1853184020178621727,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14703152626302192607,
-            9676788676639405899,
-            11836869653151428137,
-            222143000140902688,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16345040887993904596,
-            12656073479733720004,
-            1419977046904238463,
-            524015294350164287,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11884978565897711864,
-            13501395136562688565,
-            13920650670279455646,
-            728935008849787790,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13702729600524705523,
-            10543330802678105558,
-            17576552816486787578,
-            2609378268654970937,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7197388607277435347,
-            10069524640848871918,
-            8776547174564825618,
-            2282346644084955302,
-        ]),
-        PhantomData,
-    ),
-];
-const M92: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12362269017651976856,
-            5756950301455108942,
-            14445563773711718799,
-            3286399355255609280,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15774048012162523575,
-            11787232362623606552,
-            10545295830910340247,
-            3427415816342249931,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12526323926841109226,
-            7088620968158193199,
-            13879409347136554883,
-            695110366924019909,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17905726221183234864,
-            3301290599282192501,
-            2811233225014116990,
-            2909304403575057730,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12166005211005947644,
-            3807623339770327659,
-            4979595026208471844,
-            1667291623252656766,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6050881613808109364,
-            8509835280696162941,
-            11778985964949291101,
-            1944569009146706513,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1196498375735490404,
-            17712569002535408357,
-            13431329185783512099,
-            455819412152481130,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7808001371057122110,
-            5336680554977467276,
-            14490134335538656248,
-            2192906397197962751,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3759465985228351603,
-            10826208702299799894,
-            16293353471471693023,
-            1287244508654221260,
-        ]),
-        PhantomData,
-    ),
-];
-const M93: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            626262873594184564,
-            8274756723172521684,
-            16049767424179592592,
-            3445206686663386779,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14794828693276836647,
-            3937959972617926259,
-            17801049079905455628,
-            700581497768482013,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1653857464145592514,
-            16300549845163362981,
-            9275748338625712081,
-            3419192354279783953,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4605040067680853484,
-            3332738705651402423,
-            12967939381116666770,
-            2096280349681199583,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5541051712233590195,
-            14888399661503760094,
-            6747624714211254120,
-            966851870222846554,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6308889057055148943,
-            7814609193674668609,
-            8115189913628117318,
-            1086892497397007831,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            97594490289226106,
-            5768339044078610323,
-            279019369892610067,
-            3004488229882653599,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4706188608262864185,
-            17301439226825114839,
-            11005037756099543882,
-            2389114301297556772,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15721988740137280230,
-            11642290674826914875,
-            16265076775746561233,
-            2641276249358925794,
-        ]),
-        PhantomData,
-    ),
-];
-const M94: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15550913694265800735,
-            3519035166657091920,
-            1123025546113934212,
-            1859106978731942962,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5934778774490222402,
-            17552618677633652935,
-            15912597850234586752,
-            320670006335610877,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4957504844832976472,
-            6965589403316173793,
-            9719037968560333084,
-            2370466008468563243,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            340470170165671838,
-            16349362949833738691,
-            9235294453785176078,
-            826377948964767160,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1752503404647218042,
-            15240327295523430533,
-            3072271275225666991,
-            3433404113611038393,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12071935159083634185,
-            14156572869409847986,
-            7901272045528318832,
-            1434525368100544007,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5549291637005953336,
-            14330863909375188652,
-            15141650071480330367,
-            242144933130139033,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5884360618563249757,
-            15118737332818417606,
-            15883900055575713238,
-            2515924625279124018,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7589080875893336869,
-            5660923844139676994,
-            5160215310962705232,
-            201113035669023913,
-        ]),
-        PhantomData,
-    ),
-];
-const M95: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17644477432127585723,
-            3587849649608642118,
-            12234412604592943610,
-            1139607490973993386,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2651850813506791897,
-            5877250730106629826,
-            4507457351184459482,
-            1213982787102225567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12770771758446843291,
-            1869384909838042618,
-            10149537654559309562,
-            2873768854202692901,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15298096569236316820,
-            814287370931892456,
-            12397476589990691508,
-            1395966955077316940,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18306507889239583586,
-            12072164257758067243,
-            18239014111506029578,
-            2978445539789845985,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15415865211751950573,
-            8733169292235386427,
-            14865812446051120821,
-            2280677166494797251,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9585170842647959610,
-            13951976096978314162,
-            11888095523450018425,
-            3136075430668106995,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6559580400412198909,
-            930628158762899789,
-            14280341422193047250,
-            24812527729556664,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8630092975538742161,
-            4419214839006486391,
-            5737581810004839928,
-            596903901777041094,
-        ]),
-        PhantomData,
-    ),
-];
-const M96: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4385687108251512356,
-            14097107248487947707,
-            1015644022092061460,
-            2048769597575854371,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13492947729927077126,
-            5437919671522489520,
-            11348673092783068392,
-            281680119968947032,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10003497923957919898,
-            14674882591907250486,
-            15019301102049123206,
-            258204130462631439,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7191568586189750968,
-            11582660781438310593,
-            6223472268983281672,
-            3219377518256410424,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6906803668904255016,
-            13321375712760215569,
-            11735223785573242498,
-            3098016535322395211,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12630874667981353223,
-            12427804616857418927,
-            12887993167407081181,
-            2887953963728362226,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5357542211480726928,
-            1079607817815793914,
-            2154673133849543793,
-            1361132838219213804,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9535806175540898757,
-            7733798642274619945,
-            4722233500947479886,
-            1979615493283919531,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6931170096481123803,
-            13845650343368236743,
-            856033166843181586,
-            977753450285692500,
-        ]),
-        PhantomData,
-    ),
-];
-const M97: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14424019300931158690,
-            16084697900623158843,
-            15916118353013959179,
-            2375240145824534749,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14703476711806312012,
-            6505573695162191820,
-            8142825132136156293,
-            2542192466548522347,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12049352306480350480,
-            8744836731686213240,
-            7441158437982065090,
-            1107854167305402455,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6204097372177202252,
-            4966959886059520670,
-            7904852308755222056,
-            794962681812918295,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12156253212101460598,
-            12763775904826790194,
-            4357208225621024979,
-            1420755654395127897,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4461408142536537380,
-            4508818584777902430,
-            12125318875462553995,
-            1692874947059877691,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4437708938784878688,
-            6554030144386687468,
-            14144019808138178285,
-            1909964896943835703,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8353669509501630615,
-            17701655750030929500,
-            6904415597608599585,
-            2510984177560333266,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1372404073165949651,
-            3245677541178738475,
-            16046788298107164450,
-            215764978601814799,
-        ]),
-        PhantomData,
-    ),
-];
-const M98: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16979536170967274882,
-            1435178713448090716,
-            16878948847624853284,
-            1097807827477989969,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8501096769680612117,
-            11655502816419821797,
-            13425545809524242627,
-            785251547393546478,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8450720837549332374,
-            16544146129006502240,
-            2830651238503925762,
-            1041668866376230538,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11094037105425329756,
-            3711441562850662161,
-            8777580957537540550,
-            598475908546022455,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16087770748433744388,
-            11374372302144905480,
-            9678044782149657648,
-            2265858475237470626,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1989952019332360208,
-            11217651772631085243,
-            8945244732093826225,
-            2481742266410783613,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13221218376517305255,
-            16875143479381326896,
-            16959140925947083838,
-            3322911404126384751,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3062596106769416994,
-            2536133372289027094,
-            8907756408460859113,
-            1019948581072320791,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16602834101757017967,
-            2219321140536930244,
-            15817410339322026869,
-            3274025324812734796,
-        ]),
-        PhantomData,
-    ),
-];
-const M9: [&[Fr]; 9] = [&M90, &M91, &M92, &M93, &M94, &M95, &M96, &M97, &M98];
-const M100: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16600631333450986702,
-            5908802470013039510,
-            15804220077681601167,
-            3110049343395948731,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3976435632853875490,
-            13037688072043572247,
-            9735559950211071495,
-            2618999804334598521,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            53052935013084240,
-            14441776718786866817,
-            14566258868976077651,
-            1950573491421037166,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13275297975292003853,
-            3346614097161879046,
-            7512385934364926256,
-            2416669538804312616,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16096905439703631418,
-            14860952147427327785,
-            4195879220113496323,
-            2901756182788214998,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14166161502514976232,
-            15373483268872821263,
-            7004678187625633464,
-            382981323496433658,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13361522531403487740,
-            6488280429578258719,
-            12209383658476651407,
-            1443289905525477197,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2729173120843782853,
-            5983807758602427015,
-            18177283346224000417,
-            373874753608163851,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10065987725896290118,
-            13224753806628961005,
-            3504060363713726325,
-            942890894357310492,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8342088140311263608,
-            5684095908548461210,
-            8269120505251634738,
-            2986423800096293366,
-        ]),
-        PhantomData,
-    ),
-];
-const M101: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15659925491892548621,
-            12755948412133458164,
-            14880799827126958747,
-            2307480687364299370,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17790595007577827185,
-            7464964962185473737,
-            5439350580162763988,
-            889389221870190817,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14166569187918130769,
-            7057425489443740175,
-            13678860357004264550,
-            868436765377819514,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8668289783770741015,
-            2979933052617813694,
-            10437066788477059684,
-            2950021581579084245,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3988028861131637986,
-            2775938453417747895,
-            9267875721356904610,
-            59772964256634482,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7540344498077578510,
-            13685175679318936794,
-            7841310156745773865,
-            1086166889824769693,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12216315874536699852,
-            16828880294049729478,
-            12052786988327053953,
-            1347147195747068035,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15334231401284058100,
-            10119961692031691544,
-            16643459752789584059,
-            1578551455980601832,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5418056898824806502,
-            2631244270563139023,
-            6638997340570804059,
-            354619059986887773,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14023367004692774232,
-            10012015278944831846,
-            10077192358124530514,
-            2089619035089380739,
-        ]),
-        PhantomData,
-    ),
-];
-const M102: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1438485312388679053,
-            16386052118196646600,
-            3771116474428455816,
-            3256267470853999235,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10430981695342641482,
-            14553522575272317269,
-            11391826212440914050,
-            117831257590097829,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11608411893036026387,
-            12324071319638484955,
-            13696202133608094588,
-            1041374414478532248,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4743414485575722204,
-            13605952984062957802,
-            7234067665640847177,
-            742729794189071701,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            844078787928720440,
-            17490463668912218489,
-            12094340018011846647,
-            1015692170515614197,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13155762934505883053,
-            4899986125948505117,
-            4943822560844606588,
-            971210858025349545,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9946156179041261577,
-            18187040176740433451,
-            11916003960680088499,
-            1715246609342519794,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12069258632602433609,
-            634261477704000764,
-            16027662476963310415,
-            403832890210945243,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14384157909078379070,
-            8933820535677823725,
-            11197888183222678802,
-            424528343614604647,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2359630046323473445,
-            10683576323129152031,
-            11520507731425013714,
-            3203928491961647160,
-        ]),
-        PhantomData,
-    ),
-];
-const M103: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16626870962278965184,
-            9494304733774770814,
-            17762321678556609763,
-            1756882893492022226,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6185120817986741354,
-            6267914349400317465,
-            9044864562083808981,
-            2922020624336660653,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15336935303484023850,
-            3071618551871998423,
-            9353032646904148842,
-            2730118934847988549,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15257706718794426409,
-            14532188322468725725,
-            16862617538998726662,
-            2219244713890075191,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7565550317127110428,
-            2464841058519068034,
-            3355471072463060219,
-            66937576932851843,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7660025444918803965,
-            16761507446927838264,
-            6786124034828719540,
-            155743650600982465,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12545382525224408446,
-            111056332331339893,
-            13081542236700263543,
-            1879260648115182903,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12813892955614617718,
-            5377880072653092713,
-            6983628624250906072,
-            2245284687877045330,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17595951651779976062,
-            11617455306336045499,
-            8984803356963060919,
-            2390910901359576407,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14915793553235578893,
-            3863277515730714993,
-            11080337484554406609,
-            2296154774967292054,
-        ]),
-        PhantomData,
-    ),
-];
-const M104: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5307370076484039596,
-            4544333001160408646,
-            9053125869235611141,
-            1249855709779837621,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9965966164937314271,
-            2727495061710830909,
-            13211191569297891068,
-            538856919113747982,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11678847988755459181,
-            2621413070760701418,
-            5743058957430835193,
-            910039283118905920,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17849805029045930979,
-            4871697365142495183,
-            7330160846903511062,
-            2515432332370739125,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7547731036293083359,
-            14201866509859023164,
-            7146737090926476523,
-            821770077924063674,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14014286758068233141,
-            12571451286297953111,
-            15020354384416456789,
-            2121995127555044057,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16449409829414282549,
-            226870850900811423,
-            799054040026793564,
-            1761854600192872497,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9911049527999768738,
-            12031729845000174162,
-            6503481892810588220,
-            3038114352410867520,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12191062729634949983,
-            4203407390290468586,
-            7944257532074676006,
-            1501189599961534878,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5631388283656620907,
-            17108479868141219188,
-            17032745121415856934,
-            527799340202825114,
-        ]),
-        PhantomData,
-    ),
-];
-const M105: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15929882565618011808,
-            1822601549970038465,
-            18067326981728163318,
-            1079972762965766792,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4031301561413460799,
-            3072777824529876979,
-            14107992018233111527,
-            1512227481669151084,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5300856650834579754,
-            4266310171550317367,
-            16260451574264572535,
-            1877186535472229944,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12051303574716421665,
-            560649757772587345,
-            5420771249996735241,
-            2776377068249960590,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7718745890996045091,
-            10314288757413190004,
-            8509944588330591292,
-            1092632598113746450,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13463077345188384718,
-            7673811335740457284,
-            4175158180439555790,
-            1194020633642390469,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14887265698468591624,
-            8211694845479130738,
-            8148895540249460110,
-            2137362506422157982,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14417924038788201527,
-            15494265119418773927,
-            12325695249776783456,
-            1309948733607347858,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17329067617340478182,
-            3809198989611461818,
-            13511693606299938991,
-            1692361526996789275,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12820237101272081631,
-            17145278972861174718,
-            5480404855161092624,
-            371435020546816581,
-        ]),
-        PhantomData,
-    ),
-];
-const M106: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3833369197749728627,
-            9485933923094013546,
-            8447385306383990634,
-            810220513984362793,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4945883752102334537,
-            6444361981016014608,
-            15149435503433462605,
-            788253920867318018,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4018369305193487539,
-            3424428617620428915,
-            384234866503749865,
-            1114845481734760787,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1232008403169643638,
-            9994747126542967912,
-            13040495873916474425,
-            519843092537017747,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14327028221235691835,
-            9935907514741495181,
-            15817109714516257360,
-            2540959249479920776,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2439873219090078605,
-            17193636509572788622,
-            12312049634687500712,
-            2658561943813130367,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13792867641045370591,
-            8995330517971501629,
-            15175449688418363301,
-            2016713962220202178,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2557294263203222762,
-            2551757595972092018,
-            10339651700046158493,
-            204140577641191567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            775524880032035828,
-            12004598551574452807,
-            11270635638030174355,
-            588931008740257168,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15231689503328859271,
-            5694442130189181487,
-            17819923456166436374,
-            1450145417343729587,
-        ]),
-        PhantomData,
-    ),
-];
-const M107: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2273751205512557607,
-            9222476303506859568,
-            16981709986265242052,
-            1271762543469840297,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15706299642573505961,
-            16188361891702183567,
-            1810980831566458992,
-            1475612059642971290,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9755593519453396310,
-            10678758794988706553,
-            11767712643696521816,
-            813575292390696607,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11128063924356351141,
-            1443178926419153149,
-            11200095447101122169,
-            862089486632240566,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11507993251239366685,
-            9051335079300444604,
-            17675073179659535006,
-            2712694834218059540,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2452654878732696004,
-            9090665254634134158,
-            17102999872670581881,
-            2071847965976004651,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2892510323073576848,
-            6266857368315366670,
-            10556861899830115851,
-            1999072988789415653,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1595916536250305038,
-            5143593114677063760,
-            9811092414533667596,
-            150081442842336809,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10971584485328355849,
-            12876064881133022650,
-            10059281731301397188,
-            1410969664106291428,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10600144846438518159,
-            14336109334452243801,
-            10421489648070688803,
-            1337499339613738812,
-        ]),
-        PhantomData,
-    ),
-];
-const M108: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            364040156088901921,
-            8353877204904088811,
-            9907468355965489706,
-            3089282291392827577,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9137200503157575999,
-            13756188819762135284,
-            18065019742637046852,
-            2883448403738419723,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18409938651368931886,
-            5102304574726525232,
-            16472975995956539775,
-            1921360643215909947,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12029360688532240355,
-            12663596885035078768,
-            10833298093451833786,
-            2380702502974671277,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7061825301644047370,
-            7118436677711251471,
-            6795417065340521350,
-            2047548906953364514,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6540193834716127908,
-            12709602210603788749,
-            3275680471562233919,
-            674581529784637460,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14753497396291011198,
-            6288401437611217242,
-            10897549598249469344,
-            3109408038488365990,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9069813000695956942,
-            3582697797255967499,
-            15466235279997285560,
-            2330003878069451030,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15455887213510081605,
-            14498297993161299135,
-            13715275847016275861,
-            1013385337175120603,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18166896593442443,
-            12277174725564001860,
-            11707991660222504676,
-            2006833508685698259,
-        ]),
-        PhantomData,
-    ),
-];
-const M109: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14689029426330664072,
-            7440797049353724228,
-            14358586592606020395,
-            2116260418670622391,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1584325250802302977,
-            7563423195511710242,
-            6195332964163900785,
-            2247406309756956399,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16299330493394222161,
-            1907551470833343010,
-            3739433196679163670,
-            1304603125041253983,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6784927143442272377,
-            9003336303695481617,
-            15082569125208945824,
-            1304946437938005876,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18116107881998644969,
-            7872850277428076340,
-            8588100564227243021,
-            1667140912403567239,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14798312970500881444,
-            5284729899815735587,
-            10875666190132637705,
-            1042250687814483922,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9093361463231202384,
-            2811999058702482089,
-            10546591153307356121,
-            3333934947634917063,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13438178439689710563,
-            18398725204891120584,
-            12525523779287449538,
-            2667683090362320067,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11692153086516432542,
-            3995028180914375452,
-            144236224597943612,
-            919940339248771986,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9988757219247304580,
-            12122829646264593708,
-            4617097472483982584,
-            381451215066769838,
-        ]),
-        PhantomData,
-    ),
-];
-const M10: [&[Fr]; 10] = [
-    &M100, &M101, &M102, &M103, &M104, &M105, &M106, &M107, &M108, &M109,
-];
-const M110: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13778150922616775651,
-            3640767472225694329,
-            6494653809889298636,
-            3092894481579731692,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3103061206369924378,
-            8535515485821698609,
-            11449990283398892748,
-            2218174344996673012,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14152856971970792201,
-            11389256117214615469,
-            2408254749350968986,
-            929947357944116885,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14396801236665369274,
-            14070462620373606443,
-            6039983994658822708,
-            3124913555106962468,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5310707121712824365,
-            13005252353661076301,
-            6281479441451277576,
-            930432253600087127,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10854852055145602471,
-            3101313483951737104,
-            1717436706522452593,
-            740800080942853408,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13707277085156624453,
-            13921823317687569744,
-            1055240429138008738,
-            3467086600378899275,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2363813552973916420,
-            8889544870876803777,
-            6563324769051233349,
-            894956374604657110,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10124423010040898192,
-            2025881884428009189,
-            11572960186569086404,
-            431521348963887080,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3781567642475832057,
-            12034665387930114285,
-            5932034955935492408,
-            695173251418122660,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18018754325867540865,
-            3933610240153982878,
-            13313585772731168901,
-            1379120289077329230,
-        ]),
-        PhantomData,
-    ),
-];
-const M111: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18205369188130108035,
-            12429775561846889765,
-            2022752181120256434,
-            3036364629608871273,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17964127357337159592,
-            9752339367371199479,
-            2098802576148783297,
-            116033286028339286,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            237559391106720579,
-            328697042493902505,
-            6648583095106517247,
-            496521842341591176,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10257346112696382854,
-            10234171037120202485,
-            2523313038731324981,
-            1694123846561338910,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5814515996681126802,
-            14438666367820298999,
-            5127954836546558060,
-            1753116753598670911,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17055411546515808656,
-            18035870341201626960,
-            1484645783506665751,
-            2427414781335178127,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11666598743926700037,
-            2078622960396866332,
-            10873900261354638012,
-            629443424457860290,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2970982600293401178,
-            11381952624900904490,
-            1395696266081440699,
-            1728463314059388753,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12402225712830701029,
-            3499440565036177997,
-            6284573220391818261,
-            497896366185435778,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10032019493085217087,
-            12532372554862216923,
-            3976751232531312516,
-            548388398793779019,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10501735057096720039,
-            665007832677217567,
-            15107632064815531828,
-            2992213691737168872,
-        ]),
-        PhantomData,
-    ),
-];
-const M112: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3342770821290973027,
-            4372971046333655103,
-            9001856182803580456,
-            2682440133780657002,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18161940403275851943,
-            12798543936748732114,
-            12175686348870185722,
-            3062827121533615330,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11890525216144096541,
-            9083006511225356570,
-            9220407049394280134,
-            733382673540752046,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10362730095892572864,
-            3443209907051679777,
-            18163001966625420038,
-            229471157002704594,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5777492187651600058,
-            11685131429628986161,
-            8996002139113781429,
-            2020652638195007244,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1935018984916349507,
-            11260550261635124769,
-            12191744448312251448,
-            1517286821766649428,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2568538675840266565,
-            6365106459048047021,
-            11489463641389538893,
-            708133779045223242,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7833855277995724058,
-            2881745101440136881,
-            6150932354969434753,
-            2736229594389159665,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2127579417903468485,
-            15444449782450777250,
-            17791076150943611162,
-            1912756356471760321,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            748213678446816379,
-            986822675624754444,
-            4743870338282828092,
-            1648932616337106659,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15836278383382788434,
-            11340908399034096235,
-            13464448041644388793,
-            682402588397464378,
-        ]),
-        PhantomData,
-    ),
-];
-const M113: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15879608713073651914,
-            9347597141258933304,
-            2814494829199330777,
-            2826776200726941747,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2326392171906922371,
-            14514690739640664056,
-            15511067974982046739,
-            376460799554709903,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15898531578461016591,
-            13953944859214577853,
-            17702566235619830815,
-            234449923477110706,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14313852747609196411,
-            4920677627894053538,
-            5855530607100354165,
-            1333319699175270454,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6743331292590951485,
-            15658414633823979093,
-            5859492364382564434,
-            2167037169966270349,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7567988910855421413,
-            8059571389114138084,
-            12599994182146362921,
-            926850075627068178,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6931503375840654352,
-            14031807719001109655,
-            18118425124677849449,
-            2873580259655024677,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7724331663409377037,
-            13239062071492448090,
-            14992501473421862044,
-            249418038683972589,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10850079506085578031,
-            7560347823865960701,
-            1368073482503991498,
-            2027227101474211314,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6184910394764903688,
-            10608331655717008781,
-            15857865339840482283,
-            1110772088086383883,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14140903017656049931,
-            8762484358882343950,
-            3162656429088899798,
-            2323804801770762963,
-        ]),
-        PhantomData,
-    ),
-];
-const M114: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2726407223817287633,
-            148341805741163469,
-            7621960538905613065,
-            762244143708248819,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13925152365137598170,
-            3922715721236761955,
-            15096160187784240756,
-            2078778637399936712,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15687466101968340110,
-            13711485881677071881,
-            18070963219761778927,
-            330117817916104382,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            517219104984633840,
-            13289652857100377596,
-            11113402786527405724,
-            2649740919341810722,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9270956895891493498,
-            5566771136112599147,
-            2688675645975529032,
-            1973982348187430009,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11009698828991460458,
-            13787655726805643321,
-            12332748484485235925,
-            2385025475489942207,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10621493643229013498,
-            4650762601025524188,
-            3551822521588894390,
-            169024244108534567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9159895919341067734,
-            14944019929257440042,
-            1769630735220184131,
-            1736335826247611276,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6928381992903458378,
-            13425430754536681504,
-            1433868631470644402,
-            2230109726258285165,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10485900582747487082,
-            15420410275714987699,
-            692506589470562171,
-            2720950907042775999,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3537216581977515028,
-            5374885683931402801,
-            3988201975843811255,
-            2581411856341963403,
-        ]),
-        PhantomData,
-    ),
-];
-const M115: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2786451271014113854,
-            12685523230279738176,
-            15694170434652168240,
-            2576163107179522534,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8270334935174831226,
-            11655326679746980550,
-            14432657578530414128,
-            1435735021654443465,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14019382112702938599,
-            564550195374504884,
-            10118618170369774521,
-            1057576672442332083,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16749680313509347977,
-            15197594135457671068,
-            3793102230853248612,
-            587145756088782609,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13597974107250896577,
-            12333716480309551963,
-            258200184833743291,
-            395827839777767110,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2758255452402044380,
-            3673734929925079732,
-            6179775957410696147,
-            1566230942152990320,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1971878515669836884,
-            13260889498887824536,
-            14108721055387995008,
-            182646287351987871,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17071250294125558658,
-            1215494062625284882,
-            5948349072212320750,
-            2157809757453975381,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13367886585339559620,
-            8211821025915106443,
-            9310486972536875137,
-            594448194870881644,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            437647846205399712,
-            10403083074283973861,
-            4374468166030333061,
-            1469569553637048398,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3728592453374443957,
-            4142815237130985825,
-            417449662858456258,
-            1821716526349495565,
-        ]),
-        PhantomData,
-    ),
-];
-const M116: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9014186571906523507,
-            5744781597470249878,
-            14882317793545267269,
-            1034744415900853100,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1883701496784470766,
-            651473967850997065,
-            1339231026772728577,
-            1429063139882626987,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4614286201959261634,
-            8093175534596697482,
-            6215840187059339204,
-            251042526465642471,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14834976775771647280,
-            17892622826978059103,
-            12525142170655978956,
-            3367795851824064154,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9430075548960684525,
-            3803596458594450500,
-            6930749452985681997,
-            1022832124050814472,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1403604054745532423,
-            2320383474365575361,
-            9514612502519383834,
-            1286196805968579835,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1611337727227420721,
-            15961877682064060084,
-            17159327230529679684,
-            886515618139274314,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12669484878558226144,
-            10416980482273447699,
-            540476849321365394,
-            2203322558689779913,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            434056104912692304,
-            7174840534297655602,
-            9189173548926963591,
-            3098171537599459736,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8459519090256979730,
-            4041515050275643218,
-            16018412829829744061,
-            1839728253090345482,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12315723202351515392,
-            12109584886075778833,
-            3960441922840715114,
-            1959173543951505085,
-        ]),
-        PhantomData,
-    ),
-];
-const M117: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10076932938606761704,
-            5830261657850587712,
-            7384782709812700069,
-            2249240416688714898,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10203178852475622347,
-            5681939700897131019,
-            9948611391477702995,
-            3230195286196557418,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3789949899627423245,
-            15675653435922066161,
-            510271332939437188,
-            161011470547644823,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4531634408981208044,
-            7722873126092440377,
-            10134359332315555178,
-            1611727334107880737,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17621080010176667035,
-            13408446783624015057,
-            17998304236624816692,
-            563246184388725370,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10883595349399029636,
-            14549279993131397692,
-            10665770660413391428,
-            175099423544497147,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            784914589108527867,
-            14605736739310714785,
-            1237841021758497703,
-            2215724244718962514,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14608534998148516384,
-            4740079591901769140,
-            10270515555589942920,
-            3183735222415451819,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12431058056090813994,
-            4329536798656626855,
-            7732708146561412189,
-            205234664316441302,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15666491199131029809,
-            17240274255237074074,
-            11122166677918638665,
-            1020725142430792589,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            991248806755629925,
-            11368536481791829944,
-            3004937940731867017,
-            2389829255149950887,
-        ]),
-        PhantomData,
-    ),
-];
-const M118: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6705619773844458074,
-            7525598502239548392,
-            3677242466006018170,
-            1011342857346480577,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12931367881030964596,
-            2067248151794402529,
-            8849808266234744202,
-            3088976913854237646,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            294324183229746729,
-            11516235402159054866,
-            14794245090233842167,
-            3233925002203213949,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15603995838360460065,
-            7057461439800651628,
-            6576428265286238924,
-            474282784360941468,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9365322812136575284,
-            3018814824994222344,
-            10352873609635432922,
-            315038561878753191,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            25901538518416950,
-            183538412695301499,
-            12219504681268927079,
-            2027494801222410079,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6194054097140638949,
-            11381076491607766290,
-            18173131677791374132,
-            618265982679183930,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3534528098508671184,
-            9097132767509918894,
-            10930404753334288951,
-            323714664149725591,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5140797009596725085,
-            14668956830052721538,
-            10738409754838016783,
-            948746403747005512,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2205438615675196742,
-            276741197944897749,
-            6274165582146087815,
-            1937182069472469625,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3597512538074882087,
-            5679672863343551899,
-            11352750389578305584,
-            1178219866018466839,
-        ]),
-        PhantomData,
-    ),
-];
-const M119: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5888561586645772112,
-            1749994041946282873,
-            10737487483307520669,
-            3442280115673048832,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9783240660263260015,
-            13156651551276765139,
-            2003329470551679443,
-            411789025146122858,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5282514891657302066,
-            7061268504774372685,
-            12042533559297921342,
-            2465996974931708166,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7623268171036766453,
-            16576553353489726385,
-            8712651404600986353,
-            3175456138459628504,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7482867802252056731,
-            9718080239284264085,
-            9736409603243320743,
-            2001223005547017441,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12801519309364492672,
-            4848679556854322314,
-            8437256662463549462,
-            1537151870092011250,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6541664378601725320,
-            18020035013404094570,
-            12189146187722801535,
-            101138530006882021,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5555760038089592735,
-            7054830138302173015,
-            7878972566900733319,
-            2672320864159327774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16078376233172581407,
-            15651099739748770733,
-            1314874370747795892,
-            381110273705669404,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16844029751401158932,
-            4362916685630713423,
-            17634649389796283885,
-            1338994644050198187,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10075706183250044587,
-            12082139834997965460,
-            17964140321711484982,
-            3424189734159136036,
-        ]),
-        PhantomData,
-    ),
-];
-const M1110: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1845149794808336814,
-            2465548328461858331,
-            5404238722193328671,
-            460700021952314018,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1142989947469879017,
-            16969087230271255954,
-            13212479391008223925,
-            2885392905740884938,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14882659429071046470,
-            12829172115504593990,
-            227769913712736451,
-            3332247406870466099,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2944169546788384254,
-            1286720978478944174,
-            6227243451857791366,
-            2631553229680925556,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2300100438220997965,
-            4908052821610413590,
-            2383950484217098208,
-            2581654957536324611,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15763572109753947705,
-            15977381827853229935,
-            3897099757363037112,
-            2857257257772897804,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10410336559862100870,
-            7792827077043656936,
-            3476382266903661078,
-            3439467532027298183,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7538380098496214324,
-            6901216267928075928,
-            12464157360477017887,
-            1843362288307307803,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            620019974726800281,
-            6354403998400171780,
-            11291814428872560247,
-            2688431475104344126,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12683498131013042046,
-            8753045996521765042,
-            1217057550178960872,
-            785229173052094089,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1667090098525637477,
-            8002488184556240950,
-            10749296471668604417,
-            275044647574059050,
-        ]),
-        PhantomData,
-    ),
-];
-const M11: [&[Fr]; 11] = [
-    &M110, &M111, &M112, &M113, &M114, &M115, &M116, &M117, &M118, &M119, &M1110,
-];
-const M120: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8926519962877962019,
-            10875404266800807564,
-            12257704583426493663,
-            2156942940422774847,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8454432170000578410,
-            2801223135977759014,
-            5758100490409665166,
-            3106333331699418091,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14545774564494594494,
-            15379994375033314838,
-            571039073634321313,
-            2276666731843185943,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2827331521392975094,
-            177916423015613060,
-            16109848895996822733,
-            1677738835597995962,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13715796287970964842,
-            6097734521873613136,
-            1521242742806952177,
-            1611550784603118162,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6467147986806695053,
-            15982494516295897835,
-            3820459113077431932,
-            2304558496222213859,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9612708007941583839,
-            14510523305329933246,
-            8719916546158019605,
-            1139028828495790426,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15442846658512608502,
-            9583600652133103491,
-            2078244258910195394,
-            1048387146376837068,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7349932806784235435,
-            14086305983039901184,
-            287012824569712548,
-            659535853564598508,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8739252018232513916,
-            15358615457272530449,
-            1240417034908717104,
-            2389139769105141547,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13959887110129561177,
-            18109241036875561457,
-            753698374235389588,
-            1965006513444669856,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7530285314832126249,
-            10424926510308975885,
-            16285857488853945104,
-            1586778683459101570,
-        ]),
-        PhantomData,
-    ),
-];
-const M121: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2088226959803177400,
-            15914062042192652357,
-            16548027596567517620,
-            2522046924298666231,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            469415144119556844,
-            15542718039444484183,
-            16013448577703201269,
-            521281891794568344,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            584850929426449728,
-            9199986702829448898,
-            6439024587803175401,
-            529129294010986521,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8583521783061483138,
-            7469169891411559276,
-            10962633128648444481,
-            2446603134956228857,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14678416782442178847,
-            2620803096511363805,
-            12645463377124604214,
-            1200238763591225939,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13893544394302941174,
-            3391376602676772833,
-            4371684688340501552,
-            1263947827214433786,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7267029618739445560,
-            15438466304812723279,
-            5561216482908615062,
-            2081059979082650023,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16541761904383330720,
-            6427683692868104238,
-            16386241467325619038,
-            3231364570894469468,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            780184076731275715,
-            5494570694075589671,
-            6743905208296738808,
-            965115946907050334,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16472282164842072674,
-            13541161011095583855,
-            819961340108455743,
-            1764176435281844889,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7079011325610915568,
-            14405546873689600148,
-            10654105716925690267,
-            1660683664618270112,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10285232989306216914,
-            10699500401607305744,
-            6713052940434243660,
-            668641515946496120,
-        ]),
-        PhantomData,
-    ),
-];
-const M122: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1219354398264404559,
-            10680776693907232385,
-            5421493332590563319,
-            1678429157596362959,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7345960860371697547,
-            8178544918393488833,
-            4180717545558019455,
-            1982427829945132908,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16105344079380465575,
-            16505888393774402289,
-            17359957051247756906,
-            3159772149788489040,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17702980765096860012,
-            11164182651292397835,
-            6744729001887857251,
-            3002859645812415329,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8487866807592342658,
-            2410636951221855201,
-            2038738666797501467,
-            680628049812168280,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9214955819401471062,
-            1531963962337779022,
-            8078708844497488298,
-            563909833236573699,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11096068879679471620,
-            9197735027732468479,
-            15052575031022269609,
-            3312565656783637158,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5143605063238880157,
-            13436880179348189640,
-            8236743949840230736,
-            3351818621360477094,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4306801064577817075,
-            18202893609570178121,
-            12903043635756465600,
-            3301468165691763833,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4151791416038039257,
-            8615220810274846680,
-            15155436388727654319,
-            285758208966925883,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6105698066976014497,
-            15525634878833503660,
-            2480331868684745653,
-            3074486757295008313,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5236779941441498290,
-            10435789768162073362,
-            2562940635176538388,
-            1092357947736749392,
-        ]),
-        PhantomData,
-    ),
-];
-const M123: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11985384012277553429,
-            15544497655061059775,
-            14371791074491801285,
-            1841358365603582896,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2003908353409035471,
-            7287649011515909995,
-            5959570931718715215,
-            2307986460842507822,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11804582785808944111,
-            7185820517460821995,
-            9727234902627816169,
-            2141138130915401429,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6501651440316548017,
-            2534749577524622286,
-            4929766546657804054,
-            2426773079324494096,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8318743077812806210,
-            13006335954152808964,
-            16360536066534671111,
-            1895870842498143293,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8668564954967762618,
-            11550089553362873072,
-            1930641530648586413,
-            818605438338128338,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1752227415148132590,
-            10532027015605476126,
-            5984641093781840875,
-            3324186486701313528,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10403394893301214892,
-            10170063415408964993,
-            788902916398134080,
-            2883828245174158928,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7932649279154719248,
-            5030671745348676256,
-            16517211359989538796,
-            3453435777255420400,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13839275113081380518,
-            17825004326298736975,
-            1956810546739738879,
-            1449116037953285876,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10800581482715360797,
-            12409963175906692200,
-            11564781530775790033,
-            2276706017157458481,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7449412037515446964,
-            15605977606508191397,
-            17546294090422777541,
-            1505399831749150800,
-        ]),
-        PhantomData,
-    ),
-];
-const M124: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2627052095341468352,
-            9949133321266115559,
-            3987402617304542932,
-            463962337783684460,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4061457261324483898,
-            3680473309285590644,
-            10053327586038468084,
-            3033320623837979450,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10192617256720180544,
-            10656755912124216468,
-            1777644573652117705,
-            782903439465847148,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15495710368464223389,
-            18313421056576381667,
-            13465784331860461187,
-            1430212512154578453,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17075350312075981183,
-            16191919091020556571,
-            3465938100085397707,
-            1349600136142751675,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18005898377698126845,
-            14765794576303073754,
-            6745325129452876631,
-            362291425660034683,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11427772047670660579,
-            11882733282495385230,
-            11107978933944770190,
-            70590259536873401,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11165182498420191579,
-            9702917780773397157,
-            12481784341106749332,
-            2889819506818406537,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15978903209889127551,
-            15932936987929779584,
-            2990878207488995796,
-            686137739837631271,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5665750471713729245,
-            6095107144273446080,
-            2846959451942596237,
-            2192549408302775476,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2218398026598889616,
-            10835592906478441951,
-            8607046895728727826,
-            1517783704252267866,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12159753531913514464,
-            1834793632472794268,
-            18279062618161204379,
-            1083632472625958206,
-        ]),
-        PhantomData,
-    ),
-];
-const M125: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15144357222501008100,
-            6486323871926291612,
-            9892303096088029811,
-            2074833145338982337,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5857615739169213950,
-            8035807920795600675,
-            16880455275089990058,
-            1424173686038053369,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4706090975551190950,
-            7493050252870820366,
-            15102200462900483986,
-            2378980888201167175,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16779716384046000860,
-            82226433629172624,
-            9532516392055723405,
-            2881352648683837259,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17147685044169472515,
-            18388305020944831767,
-            3150202264052290598,
-            370537379110421686,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14566668816957443912,
-            435209452676192425,
-            463934137257755185,
-            74218664906596293,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10895694190046433949,
-            16840287122621445463,
-            3531189330966569274,
-            2502025970944399888,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            242180675957783221,
-            11842934628133657486,
-            13931676089274205593,
-            1634781200933269948,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9865107795942227323,
-            2362142362243189441,
-            17586838982261354962,
-            1852322558833817595,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5053066160784032763,
-            6724843974295392851,
-            10418436498385769584,
-            1634441068123562992,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18426262058170404850,
-            16175155482933230826,
-            16889645449370373472,
-            1754223890378493064,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8568790498502685594,
-            16582213567231651913,
-            8144723737792043344,
-            2432783384645577628,
-        ]),
-        PhantomData,
-    ),
-];
-const M126: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3218423773660448010,
-            7956594621426332205,
-            2148632453530702201,
-            1530864573643116125,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10609113456154915989,
-            101547400364629901,
-            16128880946870895030,
-            2948175798459482831,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4649122489774425180,
-            13774789422727286000,
-            11500899174326967726,
-            313910492589435867,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9982967930768013786,
-            17726260546724292212,
-            2955462363731130279,
-            1483988011094107897,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            657393259230702766,
-            12441496540395904668,
-            17964313142705486337,
-            716139051289273437,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16244277446954473850,
-            4264972138596986471,
-            2837940315715263012,
-            1202899564899013567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10227665960323621886,
-            10908930600159005027,
-            16639401821222649193,
-            1216226615371213928,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9004401643500870880,
-            10704401890229012327,
-            10535021555053137817,
-            2295284120730482785,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16160034999221687877,
-            12806606102535649544,
-            9927403640260634981,
-            3034115520086260893,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5689392328608389874,
-            18249326974971262060,
-            16646257530274550143,
-            502757195402953208,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1444954388548443690,
-            4200930392420527948,
-            12328691226632565905,
-            2649429126763401172,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14124926567143428823,
-            17862924110978565359,
-            339615891794998868,
-            1444575286429802546,
-        ]),
-        PhantomData,
-    ),
-];
-const M127: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5185698740737854124,
-            11968858508103146087,
-            9417956892036942960,
-            1655977128131832418,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5632359122870419986,
-            11154969113882558770,
-            14990749555036768107,
-            1706091861103464665,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4623548516712683461,
-            5447784700869962389,
-            15219309348743325981,
-            2716807717598791510,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2753477841181945846,
-            17814837762793362777,
-            701098899823541619,
-            532875685646033306,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18230507973818083230,
-            5777964916423824268,
-            4978852687134566291,
-            2841273541852314004,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7190734022990382057,
-            7633258048328310229,
-            260048391963484845,
-            3296850750583210159,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16336027561015138640,
-            17586410254232540274,
-            3923556543547365995,
-            2135174549499842183,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4168850188718937590,
-            14525761886780950649,
-            16128367790725081114,
-            3185023783074761198,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2192769303991662654,
-            9117674634409611027,
-            8965221089082921749,
-            35691316332720657,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11854412087150206738,
-            1189195555712871678,
-            4432793340713366665,
-            2966151269736315481,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7326808621831288373,
-            4627384049612206360,
-            9892611896271740916,
-            596963039183705582,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17333881373070123226,
-            13075949229565193353,
-            16458555027845539773,
-            1707884417887032571,
-        ]),
-        PhantomData,
-    ),
-];
-const M128: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17367126948970087817,
-            9872709257499425483,
-            11898105081410027154,
-            3462132673578254264,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13781629210544348458,
-            3835075329104844246,
-            10581052796944185243,
-            1091375934806757139,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3275049114216610434,
-            15226091070951623862,
-            6588348425274411154,
-            2171980914811391076,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10380185783225137346,
-            1982467409014838382,
-            1895254365915066086,
-            1950476235683303759,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10871207276014119752,
-            571580278411117160,
-            2100344533912157894,
-            232361366708133843,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1160431981954734679,
-            7274761679466374132,
-            8612820918762779186,
-            2542992314173348760,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3336910788744011350,
-            12286148021699488455,
-            12030252186825949837,
-            2072348612853644075,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8163452445853776261,
-            440776943549475415,
-            9826620332031606993,
-            2186638813640541828,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14675447658988043572,
-            5843859757799198034,
-            9102558627369883488,
-            1052995991890926404,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8139131056474112196,
-            14080267416608409051,
-            622584794982339581,
-            581278189767350018,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17049157734163681231,
-            13876709841195083651,
-            13870332645871716800,
-            354087261955799887,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4709594137006714733,
-            7328034155624772696,
-            18060793962490717762,
-            36274704983455291,
-        ]),
-        PhantomData,
-    ),
-];
-const M129: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5187551440397379542,
-            2269348216233020354,
-            9054839282015136132,
-            2650825032934891481,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7036537820519288538,
-            17862771649991202974,
-            9690196107312224870,
-            3381392426509591930,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16476320810360970860,
-            10469663676135143521,
-            406540852295781756,
-            2192233152894862583,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5257361223318515526,
-            14595561383565550785,
-            12410531908865676513,
-            2878969930313968774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11650716520560729262,
-            9449596254546694037,
-            6820400417469330446,
-            254844391442531902,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6198849109010094914,
-            2020317974238838487,
-            9978027450869237661,
-            3383343325578715074,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14970537377157968597,
-            1754865046746381657,
-            18155788058413583256,
-            638784254280675413,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8916160840818495514,
-            9027183912301049504,
-            16574011015449781738,
-            3228438699923405541,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15421400849578655374,
-            16384979095259217953,
-            6245424708726549351,
-            65841272986808256,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17757110228843971705,
-            4056071795084702465,
-            12560411647547754258,
-            1054449464766696047,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11332235884499177688,
-            10327468915569947307,
-            10641299999165956334,
-            1130418871169263998,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16591724764974170343,
-            8086671162034427782,
-            7098722280103605790,
-            1410742945025678876,
-        ]),
-        PhantomData,
-    ),
-];
-const M1210: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10542828005239870543,
-            8279106558741004438,
-            7680060132917404115,
-            3180784541657195736,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14322837581633528114,
-            13119187072399298599,
-            4715956659213750497,
-            2233545875025139509,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10793795618681824478,
-            10473640128572338757,
-            1632785708471661178,
-            499474374373673771,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12525206231355529677,
-            5660030763092480879,
-            15574849229742987811,
-            2323582717980299675,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15290641378802260992,
-            16129968144122333441,
-            1908948068082661130,
-            499640586021632305,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12080656467617404602,
-            3736986492727729301,
-            1318677732548367819,
-            2329510107294573855,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10044512540844527914,
-            3819087541994330400,
-            13605725755447738250,
-            115070763010109278,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            725341222127999503,
-            1430094018440900783,
-            11701803435608569596,
-            1541937414848614658,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7839622838600315610,
-            8092245892944829287,
-            8553257543171632112,
-            233923216478624053,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13821425577676165236,
-            4988036887689358465,
-            14320095429028650520,
-            2119480309609253626,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17827711044273282781,
-            3118602987050759960,
-            15325711197399186589,
-            2752727726158826878,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15100572172953279095,
-            11457877147338361672,
-            14559580759124745142,
-            220298280572578646,
-        ]),
-        PhantomData,
-    ),
-];
-const M1211: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18086852604544126615,
-            14174304549218018876,
-            13408748386687468957,
-            525156577372591380,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14345415753493056696,
-            14084249081338960687,
-            5573637160907607491,
-            1345716917074241803,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4617906313566136489,
-            16625701761687598156,
-            7335771508723089114,
-            334655803115530539,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9685869628969141234,
-            12629236137954291843,
-            11270596362703219932,
-            3165940875539690946,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7290698205024711513,
-            5823762676344452942,
-            10425464869034477311,
-            91761888677800671,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11991895563353861042,
-            8254433444523591098,
-            5839037571530090066,
-            403947320379427070,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13704367195063911686,
-            14691860870812175284,
-            11377526924040205527,
-            897044183050177636,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11082143630774291268,
-            13490308730479641385,
-            1155857807013908179,
-            1491100111756632836,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7088506415902152401,
-            4451516689750559275,
-            5844314976425021015,
-            1754366711078204002,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12780616697749204981,
-            2202135927435206947,
-            6274963997501637949,
-            1489272922850755124,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6328740852310694853,
-            18311896189923894681,
-            10722526119059314446,
-            994404575580941155,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1144543114914404703,
-            6721443581315454729,
-            12862296482920496512,
-            786654647578029996,
-        ]),
-        PhantomData,
-    ),
-];
-const M12: [&[Fr]; 12] = [
-    &M120, &M121, &M122, &M123, &M124, &M125, &M126, &M127, &M128, &M129, &M1210, &M1211,
-];
-const M130: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2742448559636872206,
-            7595408910944052159,
-            14934450654995516201,
-            3083309976703171066,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7938782011025564058,
-            4223126473895839881,
-            7509513460315056801,
-            3313008952839282931,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17653350954103046266,
-            9779587234491823834,
-            5613164923397731587,
-            1817259034149478954,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5909109072899987037,
-            4538423053641250259,
-            3505486811537445668,
-            2855688971563021699,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5647131493833357088,
-            5616065293661910002,
-            12187825777761997063,
-            2144654322200192034,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8566121931729434594,
-            8782020041089991790,
-            5772493715533390671,
-            130244558135790785,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            184491008825683086,
-            17813405359207126237,
-            14772226379941602353,
-            1752661976023668017,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7415355527820493474,
-            11289842155648483350,
-            18437090049731453497,
-            2775954791802261308,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2618433201828952949,
-            178297478048096818,
-            17864258500708911713,
-            3326854521066623376,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15287782680427436184,
-            4160301219699845161,
-            17326586020461611778,
-            654502215104793890,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16130955224289480776,
-            11899897615706035190,
-            14410765911856962286,
-            326958494908540713,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            542240888734382415,
-            13252089220376441567,
-            14789877089062585427,
-            1595803501674169754,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17389367130314242879,
-            1992723828146885286,
-            2738389193347139129,
-            665775464829646837,
-        ]),
-        PhantomData,
-    ),
-];
-const M131: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3426007190223872498,
-            15309555839232697517,
-            12371107601806524899,
-            1165510373458662004,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16531337050665886789,
-            15730362460967200706,
-            6227986836494946362,
-            1875035053995521530,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17626142417342970057,
-            8209326661079329349,
-            1665622871575018505,
-            315379825080426894,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6544300856634740293,
-            11713444012576021857,
-            6402975029372013994,
-            2650602385142899063,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16238326279610448094,
-            10898185201419419820,
-            8031384348173450205,
-            3346916346849660963,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14466855259079839949,
-            5331127929952843836,
-            7151015889719019571,
-            1055076839244927803,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6652187768899964493,
-            4917350872984237624,
-            18228077807502845925,
-            2149181022996470327,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14528978884237622599,
-            9663975059559806458,
-            3291495262860457127,
-            406346172845394373,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3211504918940893555,
-            3255569151908709829,
-            537673625245260639,
-            356323126853706007,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12882463577847759913,
-            17806818147560354682,
-            16043290435339113526,
-            3022054111473360786,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2925907576426891618,
-            6492572271410460107,
-            14236785262990803910,
-            10570008686206703,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            75572203253809877,
-            6169313569151384554,
-            4358237785329235991,
-            2351761290143658710,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9069651211489091531,
-            1255805171196159376,
-            314543637632546544,
-            1581624117291678071,
-        ]),
-        PhantomData,
-    ),
-];
-const M132: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14181680832149344186,
-            1555103600229278137,
-            12428295873403488971,
-            229226946326984815,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16472635284280271313,
-            1765643634688974085,
-            6172832427425123814,
-            3094758610735405977,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9184512920083139536,
-            14249199334925274147,
-            9759338715026201697,
-            3157154263137684344,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16270294359177758417,
-            17902897393295997545,
-            17687459800490672118,
-            292000596839059088,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5208014112982659890,
-            11048521362052947212,
-            9054350857819886100,
-            883810963770299127,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11778880056746748868,
-            2483840116442723138,
-            15581735453845645519,
-            1929983970045453177,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6068436317322490886,
-            2377288304745793498,
-            17420638590358880576,
-            933464704494437591,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1459015981651241528,
-            13726884801498544569,
-            2700225767161467517,
-            628050273147626018,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17917663388025470510,
-            14244860862999445630,
-            11418520271070978579,
-            2183701347994222714,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1100067769584814157,
-            10443294062114979938,
-            2493694741762973609,
-            2883210041665093529,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14085819417755198934,
-            15626031365794260314,
-            6161913750522351523,
-            682782552132292103,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11588648567886351976,
-            16952664050334572173,
-            12442885270622170446,
-            583026080420182858,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15389800152294658665,
-            722162971690722444,
-            16014718174951330531,
-            1792005203475214417,
-        ]),
-        PhantomData,
-    ),
-];
-const M133: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14903753866014075473,
-            5960833768594880504,
-            9568518241557313869,
-            1531484901387058682,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5179257212491607075,
-            1899780674436771344,
-            4425878507082930213,
-            2545861789123508678,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3359003452758133920,
-            3824879371831189138,
-            5168614729467337806,
-            1526414112578422396,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16732117373935054597,
-            10688313395902385483,
-            11014141892479233145,
-            3225238754685826999,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15313377031491265311,
-            1237512154859114401,
-            16281372462022957517,
-            2794761786272434746,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7554340405534795048,
-            7960516742951562458,
-            13612363194606697184,
-            588027641319951653,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6982962366460206059,
-            11578745373222010995,
-            1671269240172937255,
-            3408804833581702106,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12680370603190904965,
-            16399386350064461826,
-            10351519688847980415,
-            743269320558511030,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14008262194565784356,
-            13819668701692694320,
-            2699800202611113093,
-            936235875350018356,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6646577105519781092,
-            7124466031267634196,
-            1208410547901602943,
-            1502737683786731322,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12252175445183534805,
-            9735133104055031331,
-            1042567230638131463,
-            2556766004536431580,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13555173275116335293,
-            6362828609944267172,
-            14367739627910520935,
-            2399464882480869422,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12307201833265608758,
-            2877323730374368502,
-            6410211575229857408,
-            664662689887447440,
-        ]),
-        PhantomData,
-    ),
-];
-const M134: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4500667523834094669,
-            7281946579724174179,
-            17824919319267382895,
-            30769782997501522,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1792730183743473768,
-            3748587705178217496,
-            6060414161914417984,
-            673108277597938748,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5092244116410673992,
-            16934761161717616938,
-            15161222384698479001,
-            3471024399337553269,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7796208832072318214,
-            17463504644465243454,
-            726894114386067466,
-            969827662328075773,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16945548000689393861,
-            5942927872414301391,
-            4916719405391798211,
-            3361048730708820070,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2328707059752128270,
-            3080913450203346489,
-            17286824075120154681,
-            563945066980781507,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7695593546312024201,
-            7282315412624274684,
-            4483434476815082058,
-            1981568257092094403,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2080003808084134771,
-            9986597206749188660,
-            1119915803271569101,
-            1608791107326653197,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10528133995661193651,
-            8721784888614581521,
-            16834671219084984821,
-            1097927798584826989,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9428760029189349282,
-            9859949766764835640,
-            17476089580336037873,
-            394920940642061448,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2623096590447288886,
-            10026313214564525132,
-            6052651671968345814,
-            3456975517744259731,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15653751724732703062,
-            4520664896833304003,
-            8274104922925687330,
-            1984341694938673660,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16503701802951858155,
-            18187861045802436427,
-            8309811234041613362,
-            788292705970061575,
-        ]),
-        PhantomData,
-    ),
-];
-const M135: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1774079332271141914,
-            2431568189127927674,
-            4468916420914885772,
-            2237630400216455013,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6910274267931070514,
-            13550992317657237070,
-            12712550968048208132,
-            1493121498274096102,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8535854013699593001,
-            17074361038206710760,
-            9982445982590361289,
-            2293384411362509663,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6115081688911572687,
-            17970894383925714549,
-            11412221912002342896,
-            893237738862845865,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11649645832936548699,
-            7637864900393883474,
-            7529236844234908290,
-            540304534773021253,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11981530842538401088,
-            6640889001869863816,
-            17886805395906744500,
-            855995541825147328,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15745871393821467143,
-            9099102763162824897,
-            8183037331192538521,
-            2115917198085335790,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5412380068719014768,
-            9378132098912073464,
-            15152322730176471556,
-            1713055623148625465,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9209353681210119935,
-            17518700789057153885,
-            16915444714426621761,
-            174797931749211778,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5453340509810403453,
-            873331714023178901,
-            5929176867660904714,
-            110657154875769528,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2121882932146025486,
-            14625551237268431694,
-            7557889645669054231,
-            2939709538844235528,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9538569099895206205,
-            9872409195342023220,
-            16079292773762704429,
-            1904958488288581729,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11215198871174584415,
-            290757393808250666,
-            15815030913813251517,
-            2661514272383433720,
-        ]),
-        PhantomData,
-    ),
-];
-const M136: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15998680308753619123,
-            4324394236339621231,
-            108066261842114206,
-            1967280461866098706,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17539497774058792043,
-            8428368911929441509,
-            14132564934431407868,
-            1148331160151859296,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9669461759451071521,
-            6190584229777880628,
-            10660107476922720174,
-            22962458810366548,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7027315106580098783,
-            1566279320739632200,
-            16232649980922461305,
-            1118012555889382839,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13518953912391877407,
-            16674503225613426457,
-            8453149522292560208,
-            3126717938946986596,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8780084225122176687,
-            12844900071368086928,
-            17057507967102560509,
-            2776099295961414503,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3123438960911212885,
-            2038266290753871050,
-            14851763275535075932,
-            594510101816934282,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2964986271040675420,
-            10916260364003035191,
-            7854671214259091910,
-            1528369999869404918,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17360414614266798053,
-            9758646781101171800,
-            3670974514599414316,
-            2177292708268125109,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9456265765895116104,
-            6602751710711897237,
-            14377793640016283548,
-            1358215798880385092,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11502798823633595507,
-            9096085647867262347,
-            11261567325862079152,
-            1070138889127399981,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15174078438255441490,
-            12720164805614341214,
-            7121464141780667018,
-            1693322370846141995,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17347145804575076824,
-            15408137070348431667,
-            11693951569552923933,
-            2742550425161424093,
-        ]),
-        PhantomData,
-    ),
-];
-const M137: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9398534831868360996,
-            1637268933425861982,
-            16924682975611432461,
-            2558724962186311248,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9013608411810216317,
-            16647432204549309531,
-            11690554585567004436,
-            1929573935875422580,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13021236814176737881,
-            3772283672104737374,
-            13699447616922599420,
-            183684630774291474,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18055847088856669375,
-            6265616997040050144,
-            6158387425672500714,
-            411124971969794934,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9472340821010058060,
-            2927450408200579506,
-            17215156334701814773,
-            1105252863622587519,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6586816955475150157,
-            5711415826637389517,
-            7815315236901808166,
-            991818753698693605,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7355808854344667122,
-            4946471168640092027,
-            8764131492934299047,
-            1232208215136167816,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7965187617224918598,
-            14097313303567384780,
-            8337992077081426049,
-            2820638995973848007,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7291033413547887416,
-            13642879958384914375,
-            1446922145681488306,
-            2738576868997502249,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            347066281950785313,
-            14718903407016311207,
-            1662255743583022126,
-            1204535615364988992,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6700612331349589281,
-            6990850275787443818,
-            14860505164743132056,
-            154353948214910680,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7265629195077376431,
-            13528815806701477710,
-            1095092228073753200,
-            1885061936048995193,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13486207787698391791,
-            738545493043479848,
-            5943007922083287732,
-            1854185416476803946,
-        ]),
-        PhantomData,
-    ),
-];
-const M138: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5059453832089784690,
-            16355827326468214812,
-            8885167606740454514,
-            2273669884490535298,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8842046532862167686,
-            7740749997281261890,
-            3160992972101797979,
-            1807241223901501592,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16707585360208590734,
-            4689321810266145115,
-            11339508089422701676,
-            2840466438180997959,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5161529847572005312,
-            10971146760942180641,
-            8851276333889875845,
-            3178809173618982582,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5574362089895784061,
-            18281145143749926501,
-            298560957778885477,
-            2906105926233482394,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18092187955977447684,
-            15776674571710854705,
-            12337637302423968158,
-            238362003513531227,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6688620956966183761,
-            5618950919461844576,
-            17215093241090196133,
-            598080826939906577,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6134645657628758299,
-            10660425734985813744,
-            13221550116010590459,
-            2377705456749212459,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17482882001253750901,
-            5257089815668749050,
-            10070124446775006596,
-            2696535801452903630,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6716309985076714765,
-            15993488441847684294,
-            8704234502394543271,
-            795974669342147393,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9108321338912306197,
-            3345326316884120485,
-            12798101983096961241,
-            2021718846443200153,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5089125614235095198,
-            15053854222601325223,
-            9932746263341700797,
-            1080699740749703051,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8182955029044361591,
-            3707318938536194939,
-            12141413662261110117,
-            3206789945434315363,
-        ]),
-        PhantomData,
-    ),
-];
-const M139: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3667263075650855579,
-            16917631444650893344,
-            9091329822772443199,
-            2163629193943377244,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12388440068284488124,
-            723177544272743068,
-            15674318242765661971,
-            435675612421223595,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14455427924942221869,
-            15851181493445196186,
-            9342268418057505194,
-            88022429674110750,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7715668820262214116,
-            467711827331996707,
-            10422988942591687413,
-            378606789902096293,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1205651893958213630,
-            31207404924149897,
-            14182912184578782483,
-            1809929563106188238,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14000084606637849564,
-            10804842743091306260,
-            13156485134272372273,
-            470495688652423278,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6830553245130000233,
-            2225166575787369379,
-            11746406068109128012,
-            1569226484180119442,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2060507534075130055,
-            17930521446811659971,
-            110966588226098450,
-            2452243624329658129,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13597574678904993384,
-            2955006997590726318,
-            4801682701504229709,
-            2648688225181244762,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11225838297387384242,
-            15892953009516253420,
-            7965225325189499597,
-            877633991796359291,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15621072995607189870,
-            9376904934720223475,
-            16960495448425447976,
-            2385008993559502201,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10408178520396619763,
-            18308914130070460566,
-            5535318661219363384,
-            1717393808782287446,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6065314279972683747,
-            16468841537673827404,
-            11052628579920408256,
-            564284681937549051,
-        ]),
-        PhantomData,
-    ),
-];
-const M1310: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            369208238599038861,
-            17056362539322206247,
-            5416553114144680712,
-            2867820401817681487,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18093555036633108640,
-            3129664266227445786,
-            4162063719917087674,
-            2484599379385322149,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12800970430053784541,
-            9558988229970485230,
-            16102839591009508392,
-            809519040178504049,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14932360612112431215,
-            17502832130017784813,
-            6973954119505357621,
-            423759693179897803,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7434993183656908294,
-            16966367180119781892,
-            9385253820215255063,
-            625533646655096750,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3223535898432683221,
-            4498173160433710731,
-            11675176988889278603,
-            3387983462404857449,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17985224294755835312,
-            12108521997555564012,
-            1059315656070184205,
-            2449380988605106494,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5239718621218113280,
-            2144563724504446983,
-            8806916376595179430,
-            2051906364973192073,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7387883396935501999,
-            16987324764673350679,
-            13491500968734584897,
-            946873014630338412,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6762376087984530256,
-            9997884307108356123,
-            12907952357708143799,
-            3348535485749055962,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15343931320581541680,
-            3868109152926953556,
-            1009349761107190161,
-            2661159218193444301,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16935203031787386634,
-            16888440557209448516,
-            11167508555540574658,
-            1662411085510188647,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3899503310883072043,
-            17506669022986560168,
-            6516390873205769602,
-            324786136411442864,
-        ]),
-        PhantomData,
-    ),
-];
-const M1311: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10851446933739997357,
-            6384797561070854624,
-            12964772522243333937,
-            51993587930106222,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18126685370497594464,
-            18056491076835791325,
-            1941434978050402571,
-            1541925155517104794,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12790194717279243677,
-            5699217866588973877,
-            11340463828079636493,
-            3022468721885400839,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11982639002749228307,
-            82938150243476038,
-            10925749877619676874,
-            2947217579990734888,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10310184544218461796,
-            9934736420685894182,
-            14033119233988693559,
-            992600523831247553,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6700343481648418820,
-            7994929157777639301,
-            11448944590472703528,
-            2419335419595010813,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            607472878044065969,
-            14421789166826552778,
-            14421891928430510804,
-            1601408374104409998,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13412996362589873307,
-            8086420352194963962,
-            5657487038643712304,
-            1744975016041064307,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4998662018611602414,
-            6404860587688970420,
-            6554529386360553552,
-            902833357290500637,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2538510426534940699,
-            1159050192010759652,
-            8629599950742558328,
-            2347598851857331016,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5946038655569764493,
-            8495166637009968460,
-            7406726036935287365,
-            1313799602090745585,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15043805767131298892,
-            11013454153286908111,
-            16914648345527986077,
-            3095644897405635213,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5977813140205181732,
-            9729224322299013290,
-            935631824460205213,
-            2054094709800099357,
-        ]),
-        PhantomData,
-    ),
-];
-const M1312: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18390167422166238926,
-            2215670558261605118,
-            2484516152997349151,
-            1650370772237204721,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12135976078811843756,
-            12726687706202132910,
-            16442930652118417972,
-            533692124922947503,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1086929947876927173,
-            1568329337900310234,
-            10884212183848544570,
-            493129211135915026,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14099909632730845831,
-            8685989488825481630,
-            6129033532774694500,
-            710376607181974124,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9779321245135903015,
-            11567558772379973234,
-            8679907752002788779,
-            1784320082606442650,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11490784503906702218,
-            5572107912216052472,
-            8172599956697736565,
-            1969164254085785373,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17923303464449863842,
-            9612344922415908516,
-            6839943175072189532,
-            1815985317703542468,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            967606566509607315,
-            3417669780421980440,
-            8751937961811054824,
-            2235042271118147562,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11007057481332476292,
-            9178213526577222222,
-            14957995282745317911,
-            105074231302446495,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9988257806105679649,
-            15184727186761509032,
-            10927728466615764033,
-            2051785147615187451,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18382095713870998769,
-            13360907148654598428,
-            16107832996256675352,
-            660436826685217011,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16346392125331168630,
-            241744753792734839,
-            6767051498447499408,
-            2055943299933031520,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18135577750747836109,
-            6335270082305271105,
-            949425835637682733,
-            1029217362933667460,
-        ]),
-        PhantomData,
-    ),
-];
-const M13: [&[Fr]; 13] = [
-    &M130, &M131, &M132, &M133, &M134, &M135, &M136, &M137, &M138, &M139, &M1310, &M1311, &M1312,
-];
-const M140: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15723090063932111907,
-            8944865148737554906,
-            13166907120804715941,
-            1923503388791086008,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7128269501927023864,
-            8061710626929122613,
-            1344001416867662716,
-            946731578768883739,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5706185985224681687,
-            7198872414098457640,
-            17508584061627532417,
-            2284756894870754035,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8805105657317281816,
-            2608466154186554382,
-            9440901516049942754,
-            1569869081893116150,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2745045567070785289,
-            14061185047834576115,
-            5014375472174591453,
-            2906015859424857626,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15231702821689821833,
-            14626859569322530334,
-            1763829654369332984,
-            2804746645161134344,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18278709433054742236,
-            13361488752408779771,
-            995342193383178750,
-            1566236562265243590,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15610728735297979917,
-            10172573351034703178,
-            6980061808350987713,
-            2428136779504056381,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5015706553756731992,
-            3659614733386427723,
-            17048018346614337115,
-            3008603172907569120,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12087519037589401593,
-            8274059398050217958,
-            4442214571871700153,
-            3145553989103373968,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18060845889380538122,
-            5623340702301007427,
-            3176741113843326578,
-            2874419826594194650,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17996032004276926488,
-            4750501164194189392,
-            6673248881091527876,
-            3474037427409495128,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17562980181912758403,
-            672009540470942852,
-            6133661910416511719,
-            446576187462267690,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3864824232037548245,
-            11722033456377338018,
-            16631834608901667620,
-            1200456793423239502,
-        ]),
-        PhantomData,
-    ),
-];
-const M141: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7188800455254685422,
-            4898751691846246812,
-            11734113685820355056,
-            1239830117564842994,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18016186239541391904,
-            3937760529827038008,
-            9362803961869212935,
-            2152957105973583186,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3265149182810371916,
-            211490777153422287,
-            11049618065994943360,
-            640303794631164991,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1559162701335453524,
-            6165767098041574164,
-            13108167173725770086,
-            3483945385228724147,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7618496528084474245,
-            2858427061292167234,
-            11294180547039428802,
-            1545339938822842120,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17067456646450246123,
-            8014910763988314884,
-            3933834416970365637,
-            2392096629813884654,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6197120093777582478,
-            14282291118842968891,
-            8313492581382326403,
-            733933607003343366,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2834877364310258897,
-            2491738450020095579,
-            10460641401702801851,
-            1493533735985718763,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16480964591290247227,
-            3305559697863006398,
-            11970652948568309451,
-            69304284786928288,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14286698384339435340,
-            16770610229019377334,
-            1566340635790650706,
-            603816611539534777,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16512221307392190220,
-            17856110787938466,
-            3308811244802484369,
-            2779486834886920548,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6946280526864646688,
-            6820256458851708211,
-            17077683117119365230,
-            350733467838114392,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14151963131829742598,
-            2509800869404094909,
-            15042531439563982486,
-            3292124668739397235,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12206279907609783937,
-            9508501963947410830,
-            2534965691229528229,
-            2111991534372668413,
-        ]),
-        PhantomData,
-    ),
-];
-const M142: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16877989963390755027,
-            18029051336705681604,
-            1183199064106197808,
-            168261955860687247,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18188309971816605108,
-            15024287518263328387,
-            15872304673351026666,
-            2130402811325576584,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7514610621040841907,
-            6394812562593629877,
-            6932575362946162814,
-            2522674282574861775,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10883370476033145914,
-            15301242982721306660,
-            6769855550741381567,
-            1203053905197486270,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5326596509387279193,
-            10810315385779786603,
-            1521565443906269660,
-            840182356913169492,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12372088151649411894,
-            3639352188487023552,
-            5715005467881160579,
-            2741133495873202704,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4366753562573594839,
-            9308973015246040430,
-            14648084026934675909,
-            1488705058285531836,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7174224772327962568,
-            7484756748543133827,
-            15201724857462344627,
-            2865292506101260928,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4776861802215438671,
-            17152409951042487795,
-            7663760761266877630,
-            3322845446713578317,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8433003720785321882,
-            17899150957789459141,
-            2390824678308683189,
-            2636880426373146255,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9056996482206725328,
-            9709148402599800922,
-            18187543229032744148,
-            3290609956160928515,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            969089586482394819,
-            11223293921018740878,
-            1542551952858326024,
-            2722298524979152828,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11901052020758661409,
-            10834221051085823632,
-            2065842757964188701,
-            1308487027551959792,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8568375837065246391,
-            8608967295786642944,
-            3941892006135474429,
-            1701565448133423396,
-        ]),
-        PhantomData,
-    ),
-];
-const M143: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10799236445289359463,
-            4472415830677530340,
-            5490304961576141579,
-            522270901332626078,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17468567191117723727,
-            8815752079971369012,
-            3816214827763322188,
-            2579711587138368774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10918399893191366791,
-            10418143301819265168,
-            8740475495813769109,
-            2529577414629233074,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1701319790772305948,
-            3056082926714016395,
-            17746979521611523506,
-            3310801661243291808,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3258100754092159069,
-            15821023703785698399,
-            9806334755380006024,
-            2938417214251742865,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15683689609094446254,
-            11604425355109051111,
-            17188543033834406530,
-            1030166685930820854,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9317310627107921685,
-            4182507186663624578,
-            11089992557547826089,
-            641429709565619445,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3535215961566547244,
-            15198979728152642526,
-            12600121797337322142,
-            1761745212001220167,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2025916552427296364,
-            9678863113808105443,
-            15654554559004904102,
-            392054388333957789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16529125175509926277,
-            13849603562199802877,
-            9922262200777419672,
-            1214742631495686868,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17637463661690352511,
-            197557774032584954,
-            11279783444708157506,
-            612088759083166892,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5309893711340427346,
-            7516814464459736283,
-            6195311413979148739,
-            878555016665598796,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13301882551243449995,
-            124430556825553543,
-            4820639055605242883,
-            24932798914243822,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10355932283845197819,
-            5673575990074253964,
-            14348073943684051611,
-            2096737018190423679,
-        ]),
-        PhantomData,
-    ),
-];
-const M144: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            810403182351634932,
-            17677013873692002550,
-            14000751300024527970,
-            2534362870039073829,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15075106776135810274,
-            7984585754379886335,
-            15233817436117675595,
-            2514386266133139282,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18109429408798603958,
-            4136555271684496779,
-            10091644908067115991,
-            1333972635223496564,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2395142288642457526,
-            18314875100152555973,
-            16159299129158717440,
-            709470138725382380,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8068064246859333113,
-            17499353096268657209,
-            10028946569702920087,
-            2606456019308061944,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3760732233000330593,
-            10067520532343680061,
-            80287585146839353,
-            136526255778773187,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1945428566400563559,
-            12564129108255985642,
-            5515941612044640270,
-            278391406905322889,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7203009029232046813,
-            587036218863786615,
-            7136559710983139079,
-            1299136591273611880,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5782333807100804249,
-            12329674574887674594,
-            15286320521022374375,
-            145343302412359362,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16656107514101234087,
-            18225709482809590508,
-            814785921389053850,
-            579070985521727526,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11727230296085804797,
-            10371078912602254258,
-            16872251852230912676,
-            1566936863555505953,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15871975014003591306,
-            16113492187140340811,
-            17541730885515931097,
-            1288104717097817034,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13030904957791264043,
-            12954762295432964280,
-            11555115472080674673,
-            1247410921070643491,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15092163378502656683,
-            2515494373754894264,
-            10204711205029761817,
-            606558049307474069,
-        ]),
-        PhantomData,
-    ),
-];
-const M145: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4661647807454158322,
-            18299519382265699018,
-            12024999099561257234,
-            259453319635904373,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9803231420622599634,
-            14378567659690072449,
-            10145739291309997794,
-            2577251243824270608,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2882594543377945838,
-            5191381206901824974,
-            10811014164072086449,
-            370503375472215212,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9856744056992749872,
-            7874984244436809117,
-            1198830064305836579,
-            1316244595835136561,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14944444934470049127,
-            4412217547666643663,
-            16182386049781035543,
-            2497066506610949193,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7368959005776066275,
-            12678122687391372274,
-            9745533383388611854,
-            2428178602238481900,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14410715894051175324,
-            16182548513875248305,
-            15225346664633489406,
-            1268163405846242322,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11668616980217993815,
-            5146535062528738693,
-            9240390544066925441,
-            2454701559058789465,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5441909851909636832,
-            16114441833413631476,
-            2889095008907781153,
-            2400386772654821585,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4824634162594789447,
-            11584052813908862659,
-            10238863602710551848,
-            3443148461501463565,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2164250649971737498,
-            17606741083490073896,
-            5250550320080009464,
-            1857651837439727922,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1499115520535795847,
-            17063514047859236980,
-            8817722380769490406,
-            2033618523633398792,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5461079160620506072,
-            884745248643885359,
-            16077833825613278569,
-            2563199659677862978,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12921046083223216064,
-            3677899098194052359,
-            9996093964477872725,
-            322498426871103280,
-        ]),
-        PhantomData,
-    ),
-];
-const M146: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17695380950040657318,
-            9817515497410599850,
-            978802562137073604,
-            1163364227073845424,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17134711782346992248,
-            1386104091317720699,
-            6770688440709428140,
-            1750583692213846684,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6177355620091288489,
-            2117192789078442029,
-            11891187894637933759,
-            640381106706220654,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4884392925598338187,
-            10510660457631149170,
-            3220024439589256416,
-            2088995716688100979,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5990445960562241306,
-            6541844874863886006,
-            9318332182090438168,
-            2490168418495996396,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11722425097389953640,
-            863397722201343196,
-            8627001133141906350,
-            3462723099642528366,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4828728723319717784,
-            14480853631547517223,
-            6100261135238605839,
-            1931060328144482280,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4165037372269179991,
-            10120761816568266752,
-            12255853684794950764,
-            1519939668342606431,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11110528640757633431,
-            522993820693353009,
-            5210812689933218225,
-            275787243796352060,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12233514255347635544,
-            8742092277574464745,
-            11639447039599086297,
-            3135344840131758458,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16695582501396361455,
-            11604152148865571503,
-            4916582470630302291,
-            1136911327899775993,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15361284788293539178,
-            4965424004603356377,
-            6336721726670059076,
-            663700992935874764,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14744101701313526480,
-            16029673025402128895,
-            83805360357917371,
-            2396056944794175429,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10651438799753668901,
-            7824491221239977019,
-            15922132466575411720,
-            124360177345588068,
-        ]),
-        PhantomData,
-    ),
-];
-const M147: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7503323828501680077,
-            991405922480170474,
-            14320640983950263873,
-            625806564775220823,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6168525921766201772,
-            13936600961183084669,
-            6376113033618971233,
-            1868731529532988106,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1512557591798811404,
-            10673707944055959520,
-            8820568112244900596,
-            2476803217721532517,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17471865497615908434,
-            10100704809452733496,
-            11686069849609137052,
-            2638991841853361177,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11580619803357745617,
-            5178637863100765716,
-            2730302747702525325,
-            1692118621652866581,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2960629412887425525,
-            8428618311505538325,
-            6127672662050694380,
-            128360553143728811,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13330708036348791949,
-            11822212512581169163,
-            15799619810107533622,
-            1448626566478299377,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1030070879774196199,
-            5803194146028027307,
-            9840280506805512618,
-            2147944987216308610,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17622283583884451552,
-            3511482537087404413,
-            15607128083068728401,
-            3087578841102957022,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7271683241457063311,
-            16586186586702994873,
-            8418406270580865658,
-            2267544333843877976,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6013970839295361516,
-            651937364099365004,
-            14433838367995367365,
-            2365611918075605249,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            137552740923587827,
-            14044418496510995720,
-            14788648696826412969,
-            3423629548594744296,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3371512221942929960,
-            5080546085497652997,
-            12128399588149251502,
-            2677144252695351771,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3334385622194098968,
-            8768288634536127370,
-            13192455748969734634,
-            2977765626555503395,
-        ]),
-        PhantomData,
-    ),
-];
-const M148: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17430530522113526419,
-            4302663412462855270,
-            4203788866906089075,
-            1336830507964808459,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4462198750596028417,
-            1834111047713096083,
-            2130556869130835479,
-            1532856788388008628,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12386490484594595483,
-            4025009953731031163,
-            3835272397345097020,
-            475676020476022928,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15298922288881535668,
-            15145392424442941689,
-            15515399036757252951,
-            3448876393010754630,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14702715753248079327,
-            15169340039891973563,
-            6679721869990070682,
-            3064032576320925621,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11860399836911164868,
-            16670873101266165351,
-            13093495757963390828,
-            353768674676118022,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8266368584936316355,
-            6290779822630942165,
-            9755294343874353411,
-            1848040482095803159,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13621995459225816206,
-            9269946393988409333,
-            10702029564419676115,
-            1664298301572646803,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6676583837299653702,
-            1685571414729850273,
-            1922134895663456798,
-            1047625670529493766,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12987803849537617354,
-            14073581080877488122,
-            3182567918828786778,
-            2516574887567787981,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3691696518485423482,
-            5679511229009722607,
-            714362944698153615,
-            115024423342642442,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13447910326577300740,
-            9452014468977022200,
-            4742121985001701949,
-            1394662285188035136,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11810199364907929711,
-            7139922055411740709,
-            7155557181271115497,
-            2276645047386927408,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12926272297459687580,
-            14360978311780720617,
-            16721113096959723170,
-            530900452778904981,
-        ]),
-        PhantomData,
-    ),
-];
-const M149: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7452627679438664116,
-            10165987087490991726,
-            14123607720567944417,
-            2624641383555210522,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13247610269232361668,
-            17334992925689530250,
-            9491413193962006056,
-            3364594193587879685,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            189418528867363759,
-            9170253151014136754,
-            6635654462889494567,
-            679178933501330705,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4204511548303548585,
-            13489448637460724507,
-            4281602277262629291,
-            2373529575252545019,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4341582856149163249,
-            17310320976281152278,
-            12488835016413939617,
-            1566488051570899695,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7438981193283136291,
-            407098390582971692,
-            15985478946150003603,
-            737128166839610844,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17627205343397093335,
-            10730661623643663512,
-            9027764288760305931,
-            1796883747482684502,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7442988325046905796,
-            8303841211677349481,
-            7930351985959298064,
-            2830215871961443447,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4626665622240516085,
-            2382308168346370579,
-            3490133456845604564,
-            1715137325647177598,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1060865510976983538,
-            14107398777460594137,
-            11655571221746168751,
-            410921609530049032,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7711437795344453635,
-            5950487951270320010,
-            3617356268304920523,
-            924678030618373244,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10724673057999316949,
-            9143532150453788227,
-            13767997231478278875,
-            1201743140334167129,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12309205341612390935,
-            16469475418995833216,
-            12550837466055130177,
-            2963525021839118911,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5203679779410372448,
-            12732711831068290335,
-            6803438093662402398,
-            3371711717497274377,
-        ]),
-        PhantomData,
-    ),
-];
-const M1410: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3869962819292722771,
-            1802440276688195700,
-            11778875007285867767,
-            1997269535327083322,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            882632853980386497,
-            14714800955829128144,
-            1986768113659782317,
-            2300230873761186464,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15159129739954776922,
-            8941160759944422751,
-            14168753762493250138,
-            1999320274536289119,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14518677992400569399,
-            1456792246220136537,
-            15357967143389405221,
-            2687933037312272490,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1316459636019330336,
-            13183929743278211100,
-            12265559106570252653,
-            561173741255185366,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14291177500713562616,
-            15132949874594211965,
-            7177903325062623614,
-            3350791084788850434,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12926776630091666294,
-            13123976616656754835,
-            15208009472620319386,
-            2338378748555303667,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12818994889667531904,
-            9434380004203693640,
-            1379459706849027505,
-            2033935619249255767,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1457061561172938858,
-            12278392630018770563,
-            401088211056468779,
-            2382124800138985011,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7571152302080948512,
-            14817335771090209366,
-            11266538360862800645,
-            7939526285225097,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7432912970189280649,
-            13876965226958335892,
-            11730632721589639328,
-            1877875943402813395,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13328904572508486740,
-            9879142973420517486,
-            8674121837409220940,
-            1676296954937676600,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12674427880834766018,
-            3362881961577622032,
-            9816751300279530992,
-            3100752135437883190,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5088249519493448744,
-            5270518309519393244,
-            12431204873816770364,
-            2530645042537691729,
-        ]),
-        PhantomData,
-    ),
-];
-const M1411: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4801551906735980375,
-            16866883861152508384,
-            5170009964177810193,
-            2201166284297156984,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            828535554548749835,
-            5918525921212259792,
-            13145422061842774097,
-            2879138671402026267,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5237062624228830049,
-            11787558106615094711,
-            6498169985358624490,
-            1160020887519724305,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12812332206637454723,
-            10422816195084554074,
-            4796420906442597504,
-            2192040696385462244,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16973208703030720220,
-            14916821533337592672,
-            15855385629493192889,
-            3322683697228695421,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4839370529746588520,
-            3862375531408522692,
-            10634916285566119805,
-            1231212786628833760,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8984814190111789940,
-            7842822080234277150,
-            16109031559308703078,
-            2370646157216382043,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4916234358966722681,
-            10597141305989704415,
-            3255256393063487493,
-            2211031137974661371,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17380999956264948340,
-            14926201341217864328,
-            3888707405743733180,
-            700432817722185223,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2856658262959952745,
-            16581298145626984479,
-            14811591264540803075,
-            20004589518648990,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13542862270677089590,
-            13095877574342593572,
-            3359977781620900163,
-            924341752239421740,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9767817366816748136,
-            16965475931257570399,
-            14434774145895312030,
-            1222234844386231961,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15234021329613043373,
-            13490686483287334737,
-            6542242759645265832,
-            1265667157481851575,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6201031427846579030,
-            17109829438762656224,
-            2992108511447112811,
-            2820353778814061494,
-        ]),
-        PhantomData,
-    ),
-];
-const M1412: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4725586929158465413,
-            6943208252376193008,
-            14705632255284541098,
-            622028241362837339,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6423373390792876818,
-            17421600494375837804,
-            15981408274170135167,
-            29529598871192342,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2706021717200808642,
-            16975691132943201826,
-            7893891771953342848,
-            3013048343574018127,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12690151079062703583,
-            7534158033751535149,
-            7890168083315562692,
-            1560263156766465332,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18191570302242209505,
-            3114870456481227725,
-            4703946645333823581,
-            696015508130919244,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8892830756812822330,
-            7312258852612811739,
-            17002895717909801537,
-            556313317819396591,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16466203847523783331,
-            12525394217575123157,
-            17659386956228232811,
-            2851528939188337290,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13210247883866637342,
-            8078702763580629370,
-            3888215652152614148,
-            428659970123326543,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6094015944784391112,
-            7623639653889647396,
-            5316853214894185984,
-            389729389011016280,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9514477829643092430,
-            10084859752288683890,
-            13184972622520703033,
-            42067127852081062,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11799866616324371170,
-            11835259834737630733,
-            10732645477456438024,
-            2220511588987012983,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3486044535640245024,
-            942559867056581075,
-            5606388090684483750,
-            711117310857700036,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6197242114437943706,
-            10344905932635669994,
-            4208113287204558843,
-            1129338337694527163,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9689030527847965548,
-            503444557125225029,
-            2953840184571331919,
-            2451124690891597208,
-        ]),
-        PhantomData,
-    ),
-];
-const M1413: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9963405283409516221,
-            2155890079759399611,
-            481425050737297965,
-            2434555641698259599,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12509276557019695138,
-            8812334114282489819,
-            7033160609788513377,
-            2220135103711149645,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11502531083992042501,
-            6571144014361079450,
-            7318222739568578575,
-            1821363130200396163,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2328581470761212087,
-            11196339663869258910,
-            6263852741685503086,
-            1772660623760324912,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5666063379303878424,
-            4832810839014807530,
-            16366065030789169812,
-            1473622725225673287,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            798279825354490462,
-            5921923814997796746,
-            10598989862995615641,
-            3407255760333292032,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3021184331851228386,
-            6814222412186609241,
-            10931313222331798425,
-            2345765264871819700,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14348508983358841809,
-            12219867297631810370,
-            17047181588408943279,
-            1165726799427824141,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5528864116154368600,
-            4688123553724488672,
-            5731455191490608204,
-            1879166798176511286,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9045804525673309488,
-            12918367774320457489,
-            8171007922543674947,
-            1854581285726132202,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5206387422653340034,
-            3118654229590967059,
-            9337639940814644894,
-            2779006832924077047,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16733945837598820247,
-            4420911342881034045,
-            9506697661718136105,
-            318011687715514426,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            260328102263374180,
-            18374896578124275613,
-            16150363701601664139,
-            2024920548751621123,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15652370196477613919,
-            16351007760007968540,
-            8944552878526541902,
-            2746140325334056241,
-        ]),
-        PhantomData,
-    ),
-];
-const M14: [&[Fr]; 14] = [
-    &M140, &M141, &M142, &M143, &M144, &M145, &M146, &M147, &M148, &M149, &M1410, &M1411, &M1412,
-    &M1413,
-];
-const M150: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1209027639078680839,
-            7943003647025095098,
-            14671381253828602591,
-            2509706271394593274,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11913237097803973711,
-            10506860615538806604,
-            9455610280541323883,
-            1668691844263439874,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9930824284270917070,
-            15576525059119323122,
-            7130390660677372583,
-            1096941406421898869,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3860688320298184564,
-            17659764991727690065,
-            17470418708856154121,
-            472493003978574883,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            294770616229210077,
-            8512721798694471724,
-            7237986969119188429,
-            2757097077822322023,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1005712891842465183,
-            15553909227949080684,
-            5118094112788040685,
-            2983941421853189163,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1372593587496172690,
-            4373909029802096584,
-            408108350155554615,
-            3123559209162303120,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12073585130281318228,
-            6867437854293081009,
-            14148265439505715822,
-            1873196721013405875,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15269363616324444205,
-            535503067739283451,
-            447972807569208134,
-            2541841168098411107,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3044260429159062801,
-            12717004410667232893,
-            9347083525214261536,
-            355824059445554528,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11510602861363473718,
-            6611360227006989407,
-            14253096669672146755,
-            1403705491240437423,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2238216637779877973,
-            2549368032129940942,
-            5708345714224448073,
-            1735294756909923605,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12078059143128281004,
-            2375070507982835758,
-            8131845058407886855,
-            2092169511664847343,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15136950226393608078,
-            10326162107714638618,
-            18066132204439221877,
-            2998044573263669735,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15467482607240442270,
-            698726484121688599,
-            14335133993932164394,
-            923894035722702126,
-        ]),
-        PhantomData,
-    ),
-];
-const M151: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12862032118729696899,
-            2747215576290068479,
-            4393731556297504968,
-            253361261830987464,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            364376348850350833,
-            4428439663543566167,
-            3592598419248480844,
-            3111248223539090895,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17844852925647605997,
-            5861049992830265210,
-            4163877882651383337,
-            870173949733566535,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1336407113150765310,
-            8330726383609928445,
-            4393190509292620927,
-            2715869065647951430,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8342914414863214768,
-            14091148142481212400,
-            12680514787545221726,
-            3301460805719922927,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17624576830977981879,
-            17791232820362987091,
-            2786530832817997557,
-            1949496316793417133,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4987827646528459184,
-            469661588178413886,
-            2297693093526870549,
-            1790622371742519275,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16448754160076133477,
-            8188111592010955594,
-            12452133810234783121,
-            2869532017153900521,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18221019123460294276,
-            7124584804424393111,
-            5804246521419602627,
-            602774928436096562,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9021834414961508960,
-            13244035998916602333,
-            9002808292873372200,
-            2870176047383840108,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5131780971983428116,
-            17357634744476411169,
-            7510504680167908483,
-            1625843971340207132,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1252565613428616488,
-            3960579899659953923,
-            7225712206308226314,
-            1107157669090521844,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2547194954642253212,
-            12460686661463923616,
-            14522906140256183039,
-            2421685268249685863,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            706300238280294047,
-            1140564986968405105,
-            10433856360352946152,
-            593228285098666475,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3765510396188424051,
-            1236939440607422123,
-            4823444851395261091,
-            644606437723344518,
-        ]),
-        PhantomData,
-    ),
-];
-const M152: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2028693044808857407,
-            8811340826958813497,
-            14259320162252242524,
-            1403023281293934358,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8122256685400512435,
-            15607441395902264372,
-            2479998652468472740,
-            130316742110489679,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8708690395514331860,
-            14309583158285968788,
-            11520380657041165944,
-            2315228094823754362,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12296441027488362448,
-            5221556012124800286,
-            4682541606959560023,
-            615436381513539144,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13858101571128832503,
-            729212068732306991,
-            8757212491414229748,
-            1479887857493679253,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15825788457833075473,
-            10427181770516707117,
-            9971800144926011299,
-            2415416091539104991,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8747291857317655528,
-            17154644536784885328,
-            2570134721046665302,
-            1978013553131129511,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3556625034446412987,
-            4947572571444982606,
-            8598556222276626387,
-            1020406301060693904,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14885264815783154293,
-            13057128974500807142,
-            9447925839285478235,
-            431862838773300786,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8344974321527155676,
-            4285454001469517206,
-            4277926828797484387,
-            1457570941360630191,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6063841675141747687,
-            10075628196371780399,
-            17198392981660556135,
-            3376453963917658440,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2462246141868663749,
-            5415074335217106752,
-            9677513500748007536,
-            3481203141758971380,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12872658905567726831,
-            3967385634345845541,
-            11197404861065102643,
-            102269097085448727,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3348507876307650166,
-            16991104592604094956,
-            6436154747628757981,
-            2305310395098847184,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9109963806751925793,
-            11502150700357414260,
-            10697116607628416741,
-            2499654005665937667,
-        ]),
-        PhantomData,
-    ),
-];
-const M153: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4038875813352131222,
-            15922334383211324003,
-            13046474496552826196,
-            3466565772212543192,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5308167243051884090,
-            4372319840593457784,
-            3309235333248708739,
-            719516807751319305,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14493035074308881532,
-            12981221280635200574,
-            7184418630730217943,
-            2975297226825403277,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9038477440471529180,
-            1378200008804707591,
-            15105980220182344004,
-            2065420212140452463,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9926937486673115723,
-            4281300951385686541,
-            1267978305021998342,
-            3176730215585758402,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            758282246376098966,
-            5262687439017851385,
-            11588717773960588640,
-            503850416687162166,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4809675920461866278,
-            6024998662255690706,
-            14065572731956920410,
-            3337896183456017195,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3180959255805446785,
-            5617760125320560445,
-            12953540264055624793,
-            682055050168722949,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13528712558982786799,
-            7701879849528092729,
-            18244590595381907692,
-            2076590683834710123,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6066166090383119527,
-            7332097826296005141,
-            953182460283866163,
-            1187437978775855841,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1601809893519271492,
-            4987269092362008695,
-            3146806590379943165,
-            455420910664438085,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15710789536380711956,
-            5157364054450716798,
-            4054562100180971579,
-            1018647870606502686,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            208675765822856503,
-            3949015121812686076,
-            17792097401581684689,
-            3006470960827921918,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8904050122643166766,
-            17201330961999737964,
-            14095948553747528136,
-            2283883294442643614,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2717609031261805321,
-            12403276616253892209,
-            1451773379470546861,
-            2460475871934710948,
-        ]),
-        PhantomData,
-    ),
-];
-const M154: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15651571925511999887,
-            15612953592165920630,
-            9859057064164881967,
-            387383520701510128,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17136864132368444157,
-            3388766909205127433,
-            9110922959084179981,
-            2018979369104840577,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12537596940908644894,
-            5389158657968648786,
-            8883467058256075397,
-            851442583554824243,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14545989120083898896,
-            9264301127981998951,
-            10709768609329788623,
-            473788060604165822,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            139734113347527218,
-            17215668669672849844,
-            10143996444786357690,
-            2372895513861490614,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1349231497095906231,
-            6163087111524437565,
-            2124562545647946080,
-            1025981556624056755,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13197435266757299065,
-            9420189604184955964,
-            9728888408344696684,
-            393580639594984312,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4135909021422069143,
-            16651978232926114876,
-            361911885928844141,
-            65611568779126231,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12185039992979789107,
-            5151836186318529976,
-            6041259032615308451,
-            2456775304621476612,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9080184604655462457,
-            167783877074677202,
-            11713175776011703172,
-            524246886767937729,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6641763956508271554,
-            12545396272519043302,
-            13962937900417807968,
-            2456366232773710584,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14923935153900630834,
-            4768142138678678034,
-            5581615761504393104,
-            2862758521369501406,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12642311208388599897,
-            295602253922632499,
-            18058982358759561873,
-            2408883394481378005,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14057892085673300259,
-            7360632471931155707,
-            7440624789888175035,
-            511599664760918498,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15496378828315420926,
-            13583442130129905721,
-            3665426254253608922,
-            1085233129951398898,
-        ]),
-        PhantomData,
-    ),
-];
-const M155: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3597594043782194828,
-            1335027886939281269,
-            7061279621972894734,
-            2133713012730339889,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16406538337127261736,
-            8950021977074432903,
-            2626481958138672168,
-            2177245383058917451,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13660481200682162058,
-            10491903090506804962,
-            9319926617057668761,
-            539515871307851370,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18104886827713525233,
-            6656113766124760704,
-            3177217505427456239,
-            3060789623779077768,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9668158481305637855,
-            2824047675029816888,
-            6158188114147053471,
-            872680004723489406,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2253221997236490128,
-            6074112064976935089,
-            12359145981476569502,
-            370796607631662537,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11597528453163684522,
-            3294985040476277101,
-            13635733522215415871,
-            2354301937356323342,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10189462672354139872,
-            16297348701276591557,
-            5938076509845181731,
-            2041044199143130703,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15925355254588284137,
-            5314003374869334965,
-            6629186441766238830,
-            1755536821939394212,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4219721511031650163,
-            1571533077621924566,
-            5901208895678572486,
-            1495921353516745469,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10024043297812982450,
-            5322311960162889329,
-            2183792439500745490,
-            43003979664899060,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10052240857687266289,
-            3796952992989974729,
-            13354886658331151355,
-            11659321459144805,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10294855593666567140,
-            14071575981817848438,
-            5262870027441397881,
-            833310500416989174,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1994860207936512416,
-            2506671863202399932,
-            8038332467022628627,
-            127819470233427393,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4192612380834219685,
-            14060925512230387813,
-            590777320844212211,
-            441891139288761832,
-        ]),
-        PhantomData,
-    ),
-];
-const M156: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7192105644864043385,
-            12240615975706685938,
-            1400482305914478409,
-            2165094519633287398,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17005316325382542314,
-            12458091187976740499,
-            17399562434277675430,
-            1195108801763340108,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12815366724152311613,
-            16631956188269077272,
-            10239523845178589248,
-            384364834825074060,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8552153779445469042,
-            12664010538537185779,
-            13872433370030842076,
-            335868341701356470,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9901867138634825521,
-            8231926908854308556,
-            2501132817850566739,
-            3196733705656918058,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17331386298165854728,
-            380560479467420696,
-            15808345973389342147,
-            1060463663783019604,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1849198240193870390,
-            16705490260438835969,
-            1796048770852705910,
-            2830062172540517140,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9531909998418093338,
-            6478286153076281109,
-            14240716345344368752,
-            1616511101386490587,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14007626841292683188,
-            12971445107078316457,
-            12838464708799972537,
-            2502359673191917558,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8930132441322578521,
-            16091059185566254850,
-            18401536967179505656,
-            1846504067889219484,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15035110466896043934,
-            13433657685238049183,
-            18373344001549121273,
-            868996871496752728,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14573127683549855963,
-            9372054428239613174,
-            16466706849188415299,
-            2043792693785156561,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15187242784788385151,
-            11240773058471275007,
-            2120134042878188233,
-            56652379962999134,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12636683761598443340,
-            9073525496183248144,
-            17190419595912494211,
-            2924769987193815512,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17849311894789510340,
-            8782104805306793982,
-            6705577824822437050,
-            684121726089891531,
-        ]),
-        PhantomData,
-    ),
-];
-const M157: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            159769155243491755,
-            1280168554713271751,
-            1656437357608858406,
-            608765644179995110,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7110253331814895656,
-            4342924521689815997,
-            17121611978478995422,
-            373165061027407520,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10505200952971678069,
-            1868802000603444418,
-            15504406470288820715,
-            3204327275105665926,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7921785103825678353,
-            13874130671330827305,
-            4745754931362053708,
-            1775104093247223143,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14107039651782758446,
-            14352942243804137079,
-            11825065080424369501,
-            2605927416395308504,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5849259887534850028,
-            16108171975697017463,
-            15313530036987553496,
-            975651477174711754,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9776751511319166448,
-            13698162875313534229,
-            18069715039334109278,
-            793766353065029072,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3811658575840268361,
-            13001535632716109501,
-            10204075230789674696,
-            573181224373988851,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15323815521491068286,
-            13062475270742257215,
-            7530090343254728440,
-            2458556512200649350,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            714053050359180181,
-            2985074899381409789,
-            11909317339666261524,
-            1599296830347699440,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7197867351174088533,
-            1109691343145648271,
-            1191295867000623727,
-            377308869132592505,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1121506744822113389,
-            7714157690981522413,
-            1944597987868633018,
-            440468187323275382,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11193716749173136448,
-            7328527334738575858,
-            8740175020499831474,
-            950887024963489743,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15255228376971307173,
-            4757091131033124297,
-            6632353927386669184,
-            3457124734227669975,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2833322763166883432,
-            8289334416129908402,
-            6685707417272767075,
-            862667935209560199,
-        ]),
-        PhantomData,
-    ),
-];
-const M158: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10068726702754304580,
-            17392849312524388748,
-            16984321195449771432,
-            3293105960672582544,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16037912278862814426,
-            12556063354769212759,
-            8254638621070691872,
-            2011647950612345853,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10696275835143143108,
-            16786033070859618465,
-            14286261667751188915,
-            1899362390296033686,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8838124165093167608,
-            14808911807121713971,
-            13013166229497037908,
-            1289669636966794741,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3756227368381825936,
-            1886488618095891051,
-            11614457307898455705,
-            3016525238079498721,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8599926477225399032,
-            7158566837448195559,
-            4223282486012207287,
-            335293741770620909,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12571338992618013065,
-            12970024271417584177,
-            6904469588371587105,
-            1637401726131078621,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1770632770805413874,
-            949011582851489938,
-            1670428114218880055,
-            2452592748355036571,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4544388326839630888,
-            8920446826600698595,
-            7435007692625158535,
-            1479109450599517404,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6979964982287844306,
-            17594162500408815655,
-            15716026278993753905,
-            801993908776426933,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14594866546656147911,
-            17564290096168581803,
-            7169408228925192284,
-            2331463915518351589,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            843772500476410841,
-            3088262760555751423,
-            2749096566605590060,
-            2809130170487990834,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16261474840206327096,
-            5934157397633465367,
-            9102671857954905503,
-            703223663035574021,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2760145988692706468,
-            578876317829904106,
-            12689715677584036551,
-            2247132866620530768,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17994889820723581819,
-            13903152685303747143,
-            1145982778056418535,
-            383588257041900967,
-        ]),
-        PhantomData,
-    ),
-];
-const M159: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7646753186603521672,
-            16780676232708815818,
-            9272988189560374340,
-            3329363870420649696,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12621530378422769909,
-            14668702369463645893,
-            11445703953824149288,
-            779522010598440398,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            174568763747443117,
-            1552900354833385526,
-            6938822623005732042,
-            2605765223089267788,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5707610491452872299,
-            1350098225825784883,
-            10095568750352847973,
-            563995181804731426,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6898712931438019177,
-            14266803741548192875,
-            11432636408378233008,
-            3139885855555446222,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2287667947826693940,
-            1856994145535895821,
-            15383184997057106135,
-            2503854430554690734,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9645217519763700514,
-            18272119342214131980,
-            4679441412526604522,
-            1441707834803420134,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17793004763858803396,
-            15033635771968139570,
-            9249002196135862667,
-            3039744398115671244,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16308942946387904283,
-            11247131595316711202,
-            3419437879893342782,
-            1947293595386933027,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8469974774152260781,
-            7198801076240796345,
-            245927921842959204,
-            2082124394036041289,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15503215406745243406,
-            7762245598891008095,
-            7772160123106326105,
-            2417204903730380119,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6335697862538436418,
-            18043954664773190967,
-            13039308626012683571,
-            2233291002378740374,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7289993750293822716,
-            257528054294557291,
-            5746325484495802684,
-            862841617100652192,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7535739847658712310,
-            13773446220579498074,
-            15692487202422999117,
-            2255134678448279362,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15997875946523366100,
-            11122936995924489244,
-            17518406321601775743,
-            1752272160119879697,
-        ]),
-        PhantomData,
-    ),
-];
-const M1510: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14730809341988418982,
-            7790659251595032907,
-            667589261129073402,
-            395543669698437749,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17503655276456482896,
-            14656728579009127671,
-            13364423984180459681,
-            3162593272947027119,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12067129251949447730,
-            3199095986600002900,
-            11994215536385456839,
-            2772214021163789072,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2894542676044942628,
-            4931069726704144869,
-            15893397252851345091,
-            2091146110494091139,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15464422976170306038,
-            3962816885304481204,
-            11955730167102127176,
-            2911630163979650309,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15493373569046075897,
-            12643662460115185164,
-            3027887065407974562,
-            123089362572887154,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6006758122019738502,
-            4969466204863761904,
-            17220092800190104501,
-            3462602174111605867,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3471374041356159762,
-            15167102329545476713,
-            15110295994889213966,
-            586850888569967018,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12668132193278923732,
-            12788636031965498532,
-            10568967888512167732,
-            1971627137487413424,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14707378379328087203,
-            18136394187651011953,
-            1907192092977444354,
-            3016496002710090406,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11140737778344136322,
-            12939984050741960966,
-            3613483772920322872,
-            240310390031339289,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            451221696988845986,
-            15813840413535702893,
-            3579796727066444391,
-            1644513849678534091,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18055776693759093990,
-            6006031852933085388,
-            3749234973939110891,
-            2116951759785049184,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4705041491235911706,
-            12552913590607766628,
-            15228707407524351413,
-            1068210680588475233,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12303530400409902448,
-            11103764893490429244,
-            5549112529510186133,
-            2887827222925481805,
-        ]),
-        PhantomData,
-    ),
-];
-const M1511: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18077257047542835989,
-            12312618281029102809,
-            15120149568996268518,
-            2894682246124604490,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9204093748024114314,
-            10878195016918001355,
-            5511117907787925177,
-            2961838599856651679,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14340949313267820616,
-            13552422089692786472,
-            3669548115236748218,
-            1621120630047006447,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            720416483999137793,
-            2020678421963200500,
-            6449597931096395008,
-            948394365944276159,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11105800109229440098,
-            8565454099914498564,
-            12179467116126481486,
-            1762061496058252304,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15165347593457557896,
-            3070875812232277078,
-            614641585437581585,
-            1279157157214743634,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14386297537615437305,
-            10080899499750222544,
-            532056233498275139,
-            2469318816017243014,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9274267890165246321,
-            3540989394680315305,
-            1680012016952691820,
-            2971786360698049080,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14473729228835027,
-            12126774739360914518,
-            16112858096067328917,
-            1393401731663854124,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2757646820137998483,
-            369478278327289209,
-            10221294515584272038,
-            1004022603588386215,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2801304238045140384,
-            13987368175262837253,
-            2382283805323453816,
-            3411097275932270540,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15731141171630546771,
-            10404406328912279238,
-            16775967228430427351,
-            2179732208181451497,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5857522541698274529,
-            1992791521205601769,
-            1703253494765264873,
-            1076813802736620884,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11039549902474565987,
-            16626273346988638977,
-            15210905237410962512,
-            422933022252116199,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13454212850518292556,
-            3004589898707000410,
-            129507552280423343,
-            2136209946091731057,
-        ]),
-        PhantomData,
-    ),
-];
-const M1512: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11591178741741020217,
-            13155081416555938315,
-            9260755981233574450,
-            1132335595701264985,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3162964645099209461,
-            18168698098351640087,
-            3496433060030578932,
-            2895769910919091616,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18202427439919811680,
-            10782445614789979464,
-            8728495298108284091,
-            2499216533559430073,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1732632573352032733,
-            471796244888332325,
-            13317867411132575058,
-            1752799637331332242,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13315815905657896245,
-            18418183119444665128,
-            11258775787939598483,
-            2235720920918838794,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14767542341460135290,
-            8536925566028386106,
-            3722150491716907062,
-            2681675084268425275,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1157889528499162378,
-            14974211406824970500,
-            296539220430875815,
-            1854319669039922631,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16741272415278337893,
-            3797923566472798987,
-            11878533918571187937,
-            2901225863513175359,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17564268545742020753,
-            5282420902715352010,
-            7643708824403714724,
-            2963980121606477805,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15111351714511699,
-            4692507871092966690,
-            7447536812506722775,
-            3061277740779792164,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4007388292221237526,
-            15013183214920360891,
-            3932340226977380309,
-            1724384323595033413,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14906479099842485361,
-            16041324812464508966,
-            17984213477135741605,
-            721571587394080427,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9945489484651154594,
-            6657803804407002325,
-            8333788465996963756,
-            2382126870056682379,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11762935401395825396,
-            16392929150797646419,
-            8840838411222397449,
-            3282612178743966031,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11749840003235280113,
-            6852276807497286629,
-            776936708510989647,
-            945946425078728269,
-        ]),
-        PhantomData,
-    ),
-];
-const M1513: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1199110810029130532,
-            3207720045255705056,
-            17295791970435141395,
-            77463058666553274,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16887966800356387382,
-            18418668484822682748,
-            7482429613228516176,
-            1847345457270274595,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7731399916932129737,
-            3675148016919221721,
-            14989467622085157708,
-            1922596780254404198,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12650924172320898939,
-            1964201516140817903,
-            17333807465130797611,
-            414516791512412031,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4648886041138226686,
-            2667636064015294607,
-            9283023071835437634,
-            537098561045252294,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5896458270160429196,
-            13010911685564402138,
-            18113110580369547439,
-            3167432207085862087,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6960331802898276724,
-            8751712552967087517,
-            4278320481882337977,
-            843702094297372457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11098576050638557092,
-            5947460979028131552,
-            5980578531492354140,
-            2565838923993967969,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            960999604116030539,
-            4219824969611964057,
-            160144288656928845,
-            2725406660977795220,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2337187983342786080,
-            250921308578323290,
-            15233088602446336110,
-            2210694005937689481,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6376829927616327262,
-            14849785515943588310,
-            1633408653347859384,
-            2217040152575086429,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6968079544308149434,
-            7773183280758464553,
-            10942900537217314281,
-            3075481664106992944,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17288203420527303359,
-            1088168942759552317,
-            15581684516082765854,
-            133766320375609290,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16577979846280838921,
-            4230341870012277397,
-            3793837224265989791,
-            287989526928691419,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10636176894465530986,
-            16454910548530583060,
-            12232381174671819910,
-            1083609524704268528,
-        ]),
-        PhantomData,
-    ),
-];
-const M1514: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12624092476274947332,
-            142610550012839915,
-            9781413665790854116,
-            2824611142818550409,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17446154940724348363,
-            8328886710223660361,
-            12683050229357918948,
-            1750432165420449571,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13571350182314000417,
-            550904950126528902,
-            4913359248948204193,
-            2536600474870707254,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15537858077884400218,
-            10435902576765585584,
-            1264614932281276909,
-            1283586644922527159,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13600933210729833063,
-            18254131011119515863,
-            9432518499865009110,
-            3365421370181574522,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            384930989886254158,
-            4200644849002003071,
-            12854238464143086517,
-            454455570396350409,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13875873468073360775,
-            16685703725598247072,
-            12712079544669038263,
-            2041234600399593256,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16709530863136313566,
-            6116095090909179155,
-            8381736550071082480,
-            2580015343859825726,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8226107384646389573,
-            13930105236903778810,
-            3088650397569379026,
-            204414840073802081,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16577344182259117919,
-            6417508331933213112,
-            15145858422313063893,
-            2153206809295956493,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13466202959137766150,
-            832479872280249934,
-            6682045317082794275,
-            2402152091201597030,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4269246351761796867,
-            17768109005558158174,
-            17931007974911416338,
-            1432423847716059118,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17543016237582774365,
-            10726315104786654705,
-            14296892006728890717,
-            1479145190926458424,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5042606093613980941,
-            13323770858792919473,
-            2574361114314583022,
-            2728971674056365831,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14966202160376729977,
-            4033156920174220473,
-            2735270669440632326,
-            866273155656168316,
-        ]),
-        PhantomData,
-    ),
-];
-const M15: [&[Fr]; 15] = [
-    &M150, &M151, &M152, &M153, &M154, &M155, &M156, &M157, &M158, &M159, &M1510, &M1511, &M1512,
-    &M1513, &M1514,
-];
-const M160: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18228707519148411994,
-            893778983143571551,
-            14930552804037119281,
-            2207320878121433072,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14915984920101043044,
-            816113521946324834,
-            11018592955494206470,
-            424476518179906275,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            607926506731203470,
-            3600790733739592806,
-            5761005876986778688,
-            2488451907383512115,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10739067275312792216,
-            3429958339365391122,
-            6631497829295153525,
-            2016806492879744632,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16444455033476966873,
-            10611414196136862193,
-            5967759046229326559,
-            2040021837389838564,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13154786119376917902,
-            7691487769048615112,
-            9013283072595648671,
-            2350419170647097742,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5092828703849254398,
-            11623392445462349439,
-            3981060681299711657,
-            30306833490129019,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8054561064143344841,
-            8375145414292143630,
-            11987374143751579326,
-            2021251016428191840,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2387176232111169079,
-            15726340038345552186,
-            9872826203021735093,
-            2512370642283111238,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11958394868592366007,
-            2929126872677673309,
-            7586279886208465992,
-            927428046914121581,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12770011415317367349,
-            2009398047615194440,
-            14295320801420210543,
-            2255716170016603767,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14080832766239084475,
-            4075919906952624981,
-            2620807745946999714,
-            2186580587622873281,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15977144455083302735,
-            6239067676773194837,
-            18235844503761539077,
-            433540672119589126,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6253455599855411766,
-            6813613734181460978,
-            17450885342212547188,
-            3323812147092917919,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10957385445350804544,
-            769586711378282955,
-            6681943873081072729,
-            1494773621299643688,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17130886529566370919,
-            6672688057550839696,
-            3271641638857312072,
-            1963546243862579729,
-        ]),
-        PhantomData,
-    ),
-];
-const M161: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4921275766052334565,
-            16229398323246323055,
-            13618351548469487584,
-            3087389341159532332,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2227417943943600728,
-            16228607402546592441,
-            3743655080469664621,
-            3025620250611166481,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9020358661081226875,
-            11701938385131725268,
-            18013120076335968404,
-            2930305776927220608,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7240707580382288412,
-            9403690277325272124,
-            9836786209220052107,
-            398102817750240768,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11348864509097150659,
-            4494816552370319216,
-            11213066441779571101,
-            187336107543035154,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6399373669761015729,
-            3194212479312991376,
-            6993849567793629347,
-            69609115908965431,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11071851454226168702,
-            16671647478200904360,
-            7763447321890396019,
-            3470073017855494343,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4577060933574410986,
-            9265763324375941420,
-            11073556673240455147,
-            804377991937016430,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9040571385183158113,
-            10424500280885023175,
-            9320897509476753647,
-            455180392676378367,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6968000032375900054,
-            8177807583236184826,
-            8157834828828777018,
-            2247180812900166842,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9717939793230273780,
-            15767965449356115677,
-            7393472305371415450,
-            1839750680570923206,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9412473859321089707,
-            14331324291090830181,
-            17277153065667492478,
-            1237064346197891232,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17342567437924850083,
-            5448166756771844468,
-            14114586660149088075,
-            52314337015261258,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16626579899020249772,
-            4069563310854770526,
-            13882188590915146668,
-            2689806758009952004,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10678915871036085807,
-            11448910186288039074,
-            11281005244033632027,
-            265219968937627624,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10119078593912275739,
-            9808103897871334474,
-            5229978523738852746,
-            616966699007973716,
-        ]),
-        PhantomData,
-    ),
-];
-const M162: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2228806465175379355,
-            15279047253652713954,
-            13490888947130133117,
-            262578630366754845,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5256743237342087593,
-            3620213021902152302,
-            797051895397535298,
-            949970627930282499,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17191759511462554447,
-            14312167106200053968,
-            15775957065052875995,
-            1298677545275537103,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2846467572852383555,
-            754891642063053773,
-            5333671024929318950,
-            2911293798277733034,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4749215688227679438,
-            17899075073887551095,
-            12083666057317800872,
-            3071844798511043864,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4995689881490808306,
-            3788345170079290991,
-            11519150022267827220,
-            2885488977224254367,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13379560131068969373,
-            12576336036303405801,
-            6161150605077030813,
-            1271706225643220446,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13511060055445257536,
-            4213366777345184483,
-            8403803915845762106,
-            972922473975792071,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6746187039419702149,
-            9026619469413920479,
-            5994883197593415178,
-            495520964904409931,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13836940370456812083,
-            15245862726853652314,
-            375333747545447298,
-            2684083900324142029,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5094190680633825269,
-            9591721725219396747,
-            10552462590917924993,
-            1316508250177183367,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8588071232191621037,
-            475493467662079474,
-            10713979042491484543,
-            586097900023487910,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6555609041300341501,
-            2944762474993646315,
-            6684423051614348057,
-            3378074499441627500,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11453517903559837691,
-            815939938661734808,
-            3882623665988027511,
-            2860211149386291494,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5273055198794465735,
-            8280268427992838052,
-            15304558718923470134,
-            3219383726937947965,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5182244501338693621,
-            922047351410106838,
-            4989782275908228813,
-            2322806413419035121,
-        ]),
-        PhantomData,
-    ),
-];
-const M163: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2508337182886194110,
-            2018288619968497968,
-            14602918790034141603,
-            177365286484458819,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12821213488914668718,
-            7392743740321847601,
-            15498167034004855920,
-            936550970521195726,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12586486362849187547,
-            4875140721628671326,
-            1968183508601214879,
-            293397641511583228,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9675722760645945825,
-            11054633176985964415,
-            17482586597120302720,
-            127167750297760799,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14190777013266374727,
-            7927777403239530523,
-            17724915022151952329,
-            2450115159152910017,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2817907611399011861,
-            11606379328232970847,
-            11914829448317493923,
-            2555994423143211521,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16671046177337731659,
-            16466473082234794002,
-            3866952164109362451,
-            1258111101921694535,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6018162258947415430,
-            5117711372299739432,
-            10338783468319381808,
-            600369871169751728,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17505267820488700150,
-            14870287033770412735,
-            9778595117502577903,
-            2943825094105687357,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1751862619629450630,
-            10566601088905760621,
-            7947165408952729394,
-            1533953238632433914,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1682816692408366709,
-            16979984794726198833,
-            18327822578217118893,
-            2178155293970198276,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15966488651376638799,
-            3000309751300961284,
-            17094233373453982533,
-            3022788952079585840,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2241890605503550976,
-            14173677546787641521,
-            8468060467733213023,
-            1578502923852558384,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3255419203232873795,
-            12391342681503065834,
-            1834455803452084881,
-            778531032328677232,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            838894845486920140,
-            14600732714528585670,
-            17160882750209328799,
-            83879739855804022,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5682081798662396862,
-            1785225016620296326,
-            45518099684656275,
-            2261486390692700938,
-        ]),
-        PhantomData,
-    ),
-];
-const M164: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13175071890525137109,
-            621839090168750356,
-            11010335299661335211,
-            350297906279918860,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1209136137119941755,
-            1585034173661092760,
-            1845187236486654097,
-            1738915025295211390,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16693376455399707365,
-            10519893388059898055,
-            17495429267206461081,
-            1196786346109468374,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13687713290892271365,
-            2717656506127073130,
-            10656629430314854878,
-            195377415642608498,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16644993697873259148,
-            5267847172139056859,
-            16050456269486731224,
-            878283471775978955,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17570200382873164287,
-            1387314327788268450,
-            3914263048205317695,
-            3227097960566198938,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            790215114683449976,
-            3855465649730716773,
-            17624205609280619912,
-            3369172308833380338,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11640881986225512105,
-            14016608544969406095,
-            2256164120611432239,
-            2969963678501168575,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17061251335200609232,
-            15250452940956189180,
-            16196039825232912867,
-            2778404226554941723,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16087488399174254507,
-            8623166469203891996,
-            1941681540898299669,
-            2317011350469237714,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7801637644203566908,
-            2705820217336604965,
-            9291047106676344709,
-            2737422601026820149,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            572674577427412860,
-            11605369090908129281,
-            6347618263651814910,
-            3438063192029763398,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10713503169936675433,
-            6078146787127828789,
-            7651401723595887080,
-            1941765559050274279,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10518031687464984380,
-            6483540658205155863,
-            12389384144097587998,
-            2638143155679821218,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2019713143537259398,
-            1947481533364363278,
-            17811083558373022087,
-            1916571319563614282,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2688539252714283307,
-            12528553006486590276,
-            3682044811910574013,
-            885464725611081925,
-        ]),
-        PhantomData,
-    ),
-];
-const M165: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            41176725757284346,
-            2707010666871171418,
-            3860291731191217272,
-            3245852095503373677,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8081541051623060251,
-            18390341082707384729,
-            13381914030217004975,
-            3102412022430754644,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18149367076560271413,
-            6646428023975761338,
-            11693445074390092565,
-            1409813963427920083,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12576995272279042646,
-            7510709431906292651,
-            2092586125120385523,
-            1447301905283387105,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1038411545047159362,
-            4963585345569154701,
-            8058448430226094507,
-            25740209459801996,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18188187239824053988,
-            11986229108123145970,
-            6219819096307069451,
-            2965463010866165775,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7302367805138741511,
-            939282080533196289,
-            11431982383364270883,
-            903619219170759232,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4823649940848543652,
-            9684375594373355506,
-            13673530285955696955,
-            1208241135558337937,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12178839987428037951,
-            9492384079824755204,
-            10077200565921983558,
-            361834302986222022,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3038751083188003934,
-            4848758214478728055,
-            15636578373139084713,
-            30093686714202811,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6504427493816650416,
-            13579467734213326070,
-            3320513450267571706,
-            3274522597333513519,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11961356205033681968,
-            5995142569287193850,
-            7159821332522096759,
-            3107265109479996201,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            246536951224743666,
-            9301022277076441443,
-            427890948324990981,
-            1000322431302907035,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13076313269294495905,
-            2949030647170487808,
-            7072978713017081960,
-            1508852794573368238,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8258966693882477053,
-            10750776632821657902,
-            14292993937340319696,
-            3478298215488813254,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14625175357229877748,
-            18318133984134304149,
-            16045190856489121098,
-            1498286592919290599,
-        ]),
-        PhantomData,
-    ),
-];
-const M166: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            134716800150417229,
-            8120178632213131186,
-            15590299010759346804,
-            1274000384574886462,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3000835823901975966,
-            13353826252975443918,
-            14369106550071036464,
-            1659392931186655818,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2200024699801068944,
-            18037605311698503360,
-            6336259543533548472,
-            2608736740607199685,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17624482822943541946,
-            4479281557053201692,
-            17526638797242145964,
-            1346074498233564196,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4568317939897876497,
-            14510627333798887372,
-            1414613208642427136,
-            2118817205994265378,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1246687407379672329,
-            5584285034287114643,
-            1278599744085190085,
-            3195632099455684279,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17211944634498620174,
-            2039425635047368755,
-            2834499482462346588,
-            2807607795796565427,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7080925837720393,
-            17886662076650278595,
-            17799301004955292613,
-            1977630025751917082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1443428500301830427,
-            1090750401249338717,
-            11657716391454495382,
-            15056631063246908,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12667726198214014189,
-            8631694810624607620,
-            14489028371845614322,
-            205174590221022546,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7492620533247650081,
-            14106100776853704668,
-            2459728919019772689,
-            778637569878288327,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3666915749554603943,
-            12223736193628018137,
-            16593917233022283727,
-            978671709093692270,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15691957746649163958,
-            15469719101620929426,
-            5460203150063426118,
-            230935743351615739,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11695955671357089509,
-            16291003987244331017,
-            423607466234859434,
-            702999945631040803,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2939435617105353242,
-            661964014435940844,
-            17206571231139682452,
-            485917743049689512,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16318808500756572403,
-            22532318190506735,
-            11064271631795975856,
-            3421798880679654000,
-        ]),
-        PhantomData,
-    ),
-];
-const M167: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7260096415536097576,
-            12888500082738983877,
-            12638880349802762827,
-            514490385951436483,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9159440837421484035,
-            18297478318569340538,
-            17943824622641666418,
-            2921577730041610893,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2733567827155333360,
-            13032122233293482312,
-            5393275346930717925,
-            3119303077898155851,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6266702086279398625,
-            11635266291321179401,
-            5995899305001790280,
-            850351534464479766,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14559547800569206548,
-            9868090953232113104,
-            3258495450089947574,
-            1393068723079054252,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5082849664254813303,
-            491003073794147737,
-            6421005279639854945,
-            649439590731586124,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17534689794500952018,
-            13776747109897349080,
-            16080562897891557079,
-            1380109821798718330,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6890583032647596489,
-            9864451628161849573,
-            15060814036870720366,
-            4604973265194469,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11936474526302659622,
-            11066395802590501119,
-            14403972201234572253,
-            3022707383337782747,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4004463632559268359,
-            8733592931799356140,
-            11792055790594858946,
-            2704357414165979997,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8471027319640475839,
-            9802777586805542866,
-            13137004019513133706,
-            141412382997821811,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7757637398223802414,
-            18024046839130027856,
-            16206102503673927975,
-            934928757852045156,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8820686740593899815,
-            15657433078154577311,
-            14582816242244595627,
-            1071757720681961850,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9668525269700564221,
-            7532124179840747575,
-            1087307698415644489,
-            1061323784972753976,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2036649918700970576,
-            7569912665377288440,
-            8102412492996696341,
-            3281036620078811852,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12499713629914699394,
-            12087544450593811343,
-            5771812299389343298,
-            2024159678711859709,
-        ]),
-        PhantomData,
-    ),
-];
-const M168: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1643938428803721675,
-            14449274104768805216,
-            9867387587888501478,
-            2975312728966379906,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13804073831390538684,
-            4436128401744003836,
-            5866060640632018436,
-            1742998246483550396,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6233630494875611405,
-            15192831520461010361,
-            14306149232844664477,
-            294388932188777402,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8093958958162985137,
-            73729709924906569,
-            12822459056155130840,
-            1476541258161474727,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17647176390562798138,
-            7204147510267994397,
-            630628431762859849,
-            79632963751564048,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            436044149189538551,
-            3848856666160581904,
-            11274439335881046568,
-            771116260485369542,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12665451255041554389,
-            10481066196469813686,
-            12597737137801183099,
-            2423346030266186595,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2048404075066821636,
-            17124735481963698559,
-            1363467418725466562,
-            1943041106168268119,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18384172084609542733,
-            4892563763852926430,
-            10864664019674815602,
-            549236794157684363,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5015788632455555185,
-            4127890148977999421,
-            14082903002418700935,
-            2362070411723994336,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2709944625362736692,
-            7675451624673364430,
-            14840344961469341008,
-            114755610848705134,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3098413088089361752,
-            8537099384421315016,
-            15771503568641484421,
-            1961715143821740872,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4456207165575625943,
-            4054318656861179214,
-            15284841540564609532,
-            991360546667966653,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18421289004605904932,
-            11897303914606921480,
-            353057643510223881,
-            36015833006202409,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14023515420730870718,
-            13183805729621328238,
-            18191119187389998205,
-            1075143520884834628,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15265796733882358335,
-            2103158375167809091,
-            7063491417265166300,
-            1428089688962182058,
-        ]),
-        PhantomData,
-    ),
-];
-const M169: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15877895169315012260,
-            10656968326840295223,
-            2026443393108993706,
-            3146152192368922550,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3002065416656965755,
-            10158432537559956512,
-            5342298291544212233,
-            2248007410970030206,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12991335523937606708,
-            15593501784450234121,
-            14441187368157171848,
-            2909409192093422796,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9878801786856477784,
-            1951439755251474147,
-            7753795666074889198,
-            1446582064723652740,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18268960201599609707,
-            128208771785368501,
-            6764481255770889165,
-            766643303365432544,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            331345686900674293,
-            7821675471751310787,
-            4430561856029794548,
-            742846897774595888,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9753508016008412305,
-            7979861264871573550,
-            5776708303940450574,
-            3280297937284436973,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5598275255204749525,
-            8041594743263998776,
-            8284295168720378934,
-            1954204096456536459,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6575324774182954876,
-            12896618365754736920,
-            8318637475766126962,
-            111875850326412387,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16779852238306517095,
-            16408317886682876310,
-            5006047518360732010,
-            2707556160446554195,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2484611239587901482,
-            3456844017009730070,
-            8724946469914646552,
-            471075107878887121,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11407637017768925338,
-            8759606548033153146,
-            12367761414174315983,
-            2720032752491767912,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11583539441494387159,
-            15818404187514975065,
-            15266785431891453229,
-            891129697167708406,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11696343471545088111,
-            191544626452840817,
-            14075662992165605506,
-            3278861533298752630,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6072386452766772487,
-            8397356344453751950,
-            13582000228033615721,
-            265590209268312871,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16641144832757913044,
-            6133591202130386710,
-            10751578253354086387,
-            147790906030861646,
-        ]),
-        PhantomData,
-    ),
-];
-const M1610: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17391679336221390105,
-            9704038897386151226,
-            6984073548022465486,
-            3429791030651873539,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18365485746592902725,
-            16009450460488925021,
-            16959317456754623162,
-            1382431454963922223,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2124510304852098229,
-            12219441842286708077,
-            751416441752858804,
-            1326043833969238464,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            112543475061790872,
-            1593811287604667224,
-            13019093470392165359,
-            644399898612803647,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5784326026483970123,
-            7564423202718139963,
-            17189677279181272049,
-            2025539762049638139,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15642904152399102672,
-            14403014595718534673,
-            6609252297188376661,
-            2020616113732384861,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1736848295075329869,
-            2711555350066419099,
-            16226484058129803824,
-            1423440703288490581,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8473725756435128149,
-            12361616592027865878,
-            1601397380083819990,
-            1758153440993609603,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5663611267051538435,
-            7788764375996307013,
-            3292646093243179314,
-            2627138469158544753,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9398302864644324011,
-            16513659842568843541,
-            5739490749122678354,
-            2644293106968427678,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3937404864800099488,
-            4143444194478572470,
-            7478377925285793965,
-            2272806554058087426,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13638123114900542496,
-            13808800737205178610,
-            3458887382614127574,
-            2637442596018739786,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14903349935532811458,
-            14294176906694657354,
-            16754432538862613071,
-            1339999149926446223,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1950604638103618893,
-            18179482417183639595,
-            9123502435127593333,
-            3237972881853807833,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17682178417701467417,
-            18107927981250446808,
-            2078939869494139785,
-            976458637444678538,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8310739426006951125,
-            3672416116882148666,
-            13795490610955949163,
-            860935389908833368,
-        ]),
-        PhantomData,
-    ),
-];
-const M1611: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6971170420757276460,
-            14894601492976858023,
-            7281867598063372161,
-            1988175743271660904,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13708392658811073367,
-            8753621224828814507,
-            12496394426964373336,
-            1915781354409957822,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2551894630948839671,
-            12245730495543929134,
-            188783473874726674,
-            2290712028681822714,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9577682176838030513,
-            6927580977538655641,
-            15060777972396279767,
-            942020788748576468,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1406836605341440139,
-            4277185158072715316,
-            3867401733215431552,
-            1387776374699275580,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6465153280423474499,
-            4795008593299716195,
-            8480379746721487381,
-            1862344065652889154,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2345270004527165786,
-            9080545798750681389,
-            18017914642286577310,
-            1082391856764712519,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8317545771748714823,
-            16547655334659011629,
-            2034723230727102222,
-            1472441368410249552,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3429231764550030018,
-            14906917290055052868,
-            7508621373647929402,
-            386421234413969176,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1247905054534013720,
-            18379709194665128402,
-            11840625483335090562,
-            3473441902170963198,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8557550048511088104,
-            12355166593998705302,
-            9493256255598732470,
-            3079664776428587932,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3319368870068394138,
-            11373648733702621169,
-            10057834390054597821,
-            1693562969066667503,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9468899282742467525,
-            1668772952099322257,
-            6840784789305408931,
-            2792282105087300889,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15047898562868839344,
-            15219952528968861969,
-            4454894801105234192,
-            1720155235155620631,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14569792751968315,
-            23530922546468147,
-            10745590240522261788,
-            3305046411694634452,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7610600843711629717,
-            13063819992263252023,
-            6820695393354013747,
-            1643279181602464901,
-        ]),
-        PhantomData,
-    ),
-];
-const M1612: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15507618132776500097,
-            15582307554375635108,
-            10888631311666312108,
-            2285483272235773866,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8926601879109276631,
-            10907461494723263001,
-            17443549240527679412,
-            2853250119451070100,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4527785016833100264,
-            12650090578720766781,
-            17090266044132973434,
-            331197579540470740,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13642304231244591069,
-            10305991003778824699,
-            2731537035514556572,
-            2014698889374620924,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7365422957276572350,
-            6249114544301681930,
-            14231507002202149310,
-            2832229494027438137,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9928607939350069033,
-            2575338748970227811,
-            12901963471258875337,
-            1131740629596469932,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            492481632931075205,
-            4481056553096646194,
-            5273743177805429088,
-            2480956051976605846,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9306972536453885383,
-            4314238971008026665,
-            9569768567388151291,
-            680754251887464563,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11832162892617783048,
-            13871830142095553855,
-            7411967540622806649,
-            1191908708442494003,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8390167630479661160,
-            14025676520041845955,
-            15981342704044731256,
-            3447151108767589400,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11488593721738572736,
-            9526903895367539396,
-            11716986714273494321,
-            1085301157514957765,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8686718236151282774,
-            1435808584112770404,
-            10061976779551003462,
-            3098239127930876445,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12649644116142120387,
-            6958432594821839213,
-            5295941621136317560,
-            574616313608180278,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6687412530803971560,
-            13570557731820174431,
-            5919672483028976097,
-            3429260062923240982,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12770556468578168759,
-            15366641839504170278,
-            574826003523971130,
-            3282002663237556329,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4378640304727934120,
-            2780783637808897795,
-            383743307386563904,
-            637326732298879149,
-        ]),
-        PhantomData,
-    ),
-];
-const M1613: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17330377730219103851,
-            62825105892344904,
-            13402640527070502655,
-            1190726090356558228,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2162821846317098905,
-            4400357419028459998,
-            9409189425611480227,
-            3257551425706864046,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4898873927156303949,
-            9844076769620169731,
-            11170385671166369899,
-            1905220300704187942,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13533124219498745870,
-            2538845802612847714,
-            2454247581393656336,
-            1966008817195399681,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            39534157812928101,
-            4955015450869350642,
-            17485969784859733050,
-            3010338903830652248,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14925269184282355364,
-            12814585475618216180,
-            10289805461171814029,
-            1832699226386011414,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12662152553594932700,
-            9989340531352474516,
-            4325254043496929767,
-            2572265190946459419,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2360241279999971055,
-            10798669967998633975,
-            11512078679931820033,
-            972699580267526767,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1585769804962533003,
-            3868783790155635046,
-            5342385549191853276,
-            2763433697681455305,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1212096130370794501,
-            3532713857165004896,
-            9356365786969776613,
-            982808153597049264,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10680659502940651171,
-            12243648021011598188,
-            8376782029506618052,
-            3369092079585269862,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            876997675745695756,
-            2553722613801643158,
-            11989487248671568227,
-            2285586125997757997,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7462114147417235513,
-            14856055666598630764,
-            17351487771077002339,
-            752361526283627882,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6231372237994091537,
-            14228559388208839978,
-            5138855363072787264,
-            2784995391710790751,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2813047768064499629,
-            2191557966802577860,
-            8997733458179082435,
-            2307128247219116607,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9509725028318866561,
-            6548806407541489784,
-            3163123152300346574,
-            1144225741092990324,
-        ]),
-        PhantomData,
-    ),
-];
-const M1614: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1986678329407437934,
-            2843700516576524578,
-            14264295306042794637,
-            1299707144116771915,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3294214301191718226,
-            6235132895579263826,
-            4982206832789357527,
-            1924436158198934913,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4407811878133793728,
-            193042666869212938,
-            17808462731646047967,
-            2642154869570460729,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17766147582518385439,
-            2630635694007314524,
-            11219280356330366567,
-            219257328812553484,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16217966390071837941,
-            13106001510499778018,
-            17822236178337925291,
-            2116962170252649634,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12661212980663913669,
-            8226146655652887548,
-            15883460536250458839,
-            3390863569350877821,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11256821965596263981,
-            2531899046690661160,
-            4887659193960664463,
-            1425002650357114441,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9787595076023777754,
-            7069926348888217458,
-            11727519368951054406,
-            2518490843691890998,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7999125102910887037,
-            9550416109583671565,
-            5025978630781223770,
-            1893301896196978134,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7526030950845722481,
-            58790921774729208,
-            6674248856343411597,
-            2933146000188850216,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18022665390417844041,
-            928587227235968624,
-            1530035752329534871,
-            157674799090934226,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14441003731208834043,
-            766782722061107298,
-            3407472503434464686,
-            1101608280332248872,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7177681195027734949,
-            7734312594159072366,
-            6606133761125151807,
-            1876562205016733643,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13119799443427580576,
-            3984660180096638818,
-            3117997131867355116,
-            1205331173836994824,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2477807983085069878,
-            8883005493478747579,
-            17596339160366459257,
-            27989893021882008,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9876328076357029401,
-            6968159459482515113,
-            13219943648018097166,
-            2601514993780965866,
-        ]),
-        PhantomData,
-    ),
-];
-const M1615: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9713225225379204389,
-            633906485844744993,
-            9651021979456474687,
-            1567246191278229249,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1406433191213927091,
-            6862475404903510073,
-            17634440654877840803,
-            3462202399603232446,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17224506332177256266,
-            10474059004670149247,
-            16292409789875153552,
-            652085306198696608,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11938960711317115987,
-            6466557970741106724,
-            6545444134419679167,
-            1287423236162096259,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1156602484558683056,
-            4799880167680642338,
-            2789418409260094738,
-            3430067791919420920,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11187241970242392129,
-            4301297113815676834,
-            1528017460935522797,
-            2722970461184158057,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2564719589103130196,
-            14989870995522715992,
-            7714526246006213153,
-            759563863830634723,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8842364768259170743,
-            5513468814743019449,
-            12449773380996800355,
-            2700640980733646673,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15982704374577651957,
-            16293028716791962673,
-            13651545508552513599,
-            3020142804414283438,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3439646574975311557,
-            15261185794337340737,
-            12449714529868027917,
-            3125173813517547151,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4886284227725579651,
-            15368823094691008848,
-            3636386779135220646,
-            2828229047198583627,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14029731145974659569,
-            444293095918008285,
-            10558977810445972835,
-            510862003806127547,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9666984166686055490,
-            4879099932767562238,
-            7291508107445308532,
-            3472883487556646828,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18004788695111220343,
-            11041649173671828075,
-            15318197524158659682,
-            1621602696779475366,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9728052857513164907,
-            16711676532999802806,
-            3600064002799376901,
-            3482383522185776399,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9486991290778648626,
-            2146264170800296246,
-            7260117805459571550,
-            1738926985462143405,
-        ]),
-        PhantomData,
-    ),
-];
-const M16: [&[Fr]; 16] = [
-    &M160, &M161, &M162, &M163, &M164, &M165, &M166, &M167, &M168, &M169, &M1610, &M1611, &M1612,
-    &M1613, &M1614, &M1615,
-];
-const M170: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7509738900808394457,
-            12069945597891132194,
-            10254856263825316230,
-            2404466910474347626,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12526440623576539230,
-            2358740979443812395,
-            11720368859774090798,
-            1494451542118206405,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16420639056702341865,
-            2646451111666718719,
-            819858286296991194,
-            3438046260655166705,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9738221898387337851,
-            5876964912802416499,
-            17082072415804585671,
-            900400264228107221,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13829646699393378386,
-            10217455047162425717,
-            16970396318090937961,
-            2754228981780415950,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7618349683839929341,
-            5681141327317954968,
-            9047836140919975733,
-            1920798187563433135,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12531050359841681506,
-            10346862286770371000,
-            8577715530720347124,
-            1755519516734431036,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3924187614208867946,
-            14692253700742900370,
-            6315979145298192457,
-            1070097566035724784,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12211459429267208301,
-            9612697761750415215,
-            12014514047646918506,
-            24383763220916854,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2938561425673367549,
-            5426075483317603593,
-            12626364581799103136,
-            1375744798256025656,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3076785907084339349,
-            11206977815187135456,
-            14930864746351676680,
-            393460417561604418,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2023870183399385930,
-            7417944311076493718,
-            6321885268773679648,
-            3429575833759254447,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10423451169849763335,
-            13149133162710704116,
-            9936713453042023536,
-            611861871073824075,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15598852796351909147,
-            3742146709646237627,
-            12719881574593179183,
-            552878848494903721,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11350089164305328883,
-            247870536223780816,
-            6987882262269834435,
-            1115299825835811091,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10789170130284432779,
-            14743616202564676652,
-            16062579068868738043,
-            1865107826978419753,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2380309481624579334,
-            4035042112328468035,
-            14493486624468129989,
-            3218588467740361789,
-        ]),
-        PhantomData,
-    ),
-];
-const M171: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14807400213996732348,
-            7534035347307449447,
-            6742711082548952860,
-            2118634338427306092,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11354476531474854486,
-            2216585478894678769,
-            13699573482882870101,
-            474727327948784169,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17579703482086041071,
-            4054515737820078181,
-            8367008068201201396,
-            3297976900229721741,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11262470616381165184,
-            11204837662464931073,
-            6572297931890575234,
-            3328224859260540272,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15610552311003235461,
-            10246874061781850163,
-            6226004079197019143,
-            1062701079494378923,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3900621079299395907,
-            14481953762694302291,
-            4341818884970542082,
-            536686643340023924,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10816573859437028075,
-            17607076995599884525,
-            16962495162446908211,
-            1019880736066202934,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5929070287811410547,
-            10704122328502599639,
-            9505911334340031371,
-            2208803650326874391,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12293199341584691737,
-            2625384748888890812,
-            13173690703035470267,
-            2471747738617208156,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13774053891556886080,
-            16238124493905479305,
-            16042185686355407705,
-            20650291021688134,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4566964472551513845,
-            8444771415939375876,
-            9176910248492225152,
-            1573028871949939607,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17252496854601492194,
-            7235546994128423156,
-            3178610701637585641,
-            2544636372338538584,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7978197944596950905,
-            6038809781558333606,
-            12843910033832185121,
-            330599600528948322,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2296014507441863621,
-            12668327086240949606,
-            8750721081423330501,
-            838514709828606846,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            27555814124363502,
-            10318009717483177429,
-            1624360781534136750,
-            1245915659385688017,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2790227437692882070,
-            16645553804890423284,
-            8114414774407393120,
-            1770099491716432419,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15284846037601910008,
-            2991717099567632482,
-            10949933403236322367,
-            388067749002061029,
-        ]),
-        PhantomData,
-    ),
-];
-const M172: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13964800821219811613,
-            13680576422009058886,
-            630057608324866859,
-            2105291499492370835,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15589097792467161468,
-            7504354823158451161,
-            14914625657770881809,
-            57105540865651992,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13889666908979681857,
-            7054190412446412481,
-            6239960645768226807,
-            845149360811433802,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4432157138886104442,
-            12895784714874587687,
-            17377497959734109284,
-            223912210695303504,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5175529402647802970,
-            723359849614983338,
-            16666121707161020063,
-            2033648294862433964,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5821724438736777956,
-            685436145861954741,
-            13642556415347843073,
-            2021915149903458469,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13957962343285580748,
-            16496256519128053306,
-            4398912382192389774,
-            739536428037669220,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2493181841422874373,
-            1018042045694560456,
-            16818573071720412569,
-            505989025290504240,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5871429517044210721,
-            2064688156358063903,
-            17889595318130771484,
-            3276617569986714745,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7008189179575636351,
-            5145967411614259962,
-            13391010157329920414,
-            1845880606714460919,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16059689267286535043,
-            2373561691491476597,
-            1374508864016688690,
-            3164929310592265764,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13344206980686357995,
-            15728011427000594300,
-            14379367239479605398,
-            2432944614441982802,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9809075300875845266,
-            11689081098941538300,
-            10994079314695560492,
-            610378269861108681,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17362014145192004738,
-            13931279925789387884,
-            48391145387044930,
-            1849519341346994241,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16087527493944449361,
-            15527023435919412618,
-            5599640215726453758,
-            3196499801216563740,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7982642356105207973,
-            821811799925639919,
-            8278293623879349709,
-            1196485639855121502,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18398441398486093432,
-            5542890296370980848,
-            4957335883266183505,
-            2017939052127999810,
-        ]),
-        PhantomData,
-    ),
-];
-const M173: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16010028876030635193,
-            9087501518425118343,
-            4026739859347155729,
-            521731017745791360,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3709551897152772743,
-            8573782916827245319,
-            6329333252609738042,
-            1805739740063805305,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            687207140276431333,
-            16589919689763423549,
-            1059922725052276246,
-            1376615095236063489,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14687353179280904047,
-            6616762954261708839,
-            11474299755574978131,
-            3286917634702090093,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10381913453713941787,
-            5645186534663536758,
-            15178908779733154706,
-            3005242821077005428,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11231061630389292130,
-            17684784826574075911,
-            14021474905270658883,
-            2738637536021318540,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2101548612045777852,
-            13165071021796672682,
-            13969303769494332200,
-            465431521722087550,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12627956618044232624,
-            2801874377643343412,
-            1576066596177127103,
-            3274602336584323892,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6225437453588779965,
-            407437553382865227,
-            16954618845401874666,
-            1045793817117963548,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3905838709926075328,
-            17836381594783169858,
-            6480623063891440125,
-            2471265253256646554,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15038754335318118016,
-            7668391297727713841,
-            2997703173574975965,
-            353599090141397050,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            848207824570333753,
-            8346192674883169502,
-            872296680626866679,
-            2434600150100557312,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6468058177446414057,
-            1188120835775992099,
-            7807684407713431482,
-            893013849931537637,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            83256584803177604,
-            2894793353219969240,
-            4719683052505292865,
-            3171621228848214610,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13132995691066366921,
-            15043265454238499844,
-            13376874733221415278,
-            3443063200962195352,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15683595446113746256,
-            9810793972554542681,
-            13819231428112460486,
-            1131451055439547802,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14326745645999013955,
-            2161118525344797295,
-            13689095026591476886,
-            3355272533121223100,
-        ]),
-        PhantomData,
-    ),
-];
-const M174: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17843976675890027,
-            14185765876814369472,
-            11053577711694328101,
-            2901181040691151041,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5451401538655969978,
-            2512724275279679712,
-            13277699453798273241,
-            2319734704774893400,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8568288840707953112,
-            12874157887151620873,
-            1647037645778355253,
-            2019672896426152873,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1302828580049789692,
-            13135256606493102424,
-            3607196018651712422,
-            3200760477420918544,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5408762134256493962,
-            15858762532540425520,
-            3100249899224507420,
-            1510413430476367100,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2581706239662550543,
-            14656158284916353612,
-            7629240710669079391,
-            1694126692305388767,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10687953636647249010,
-            7156635084208722822,
-            5171623696838013857,
-            1796709939751468331,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3485883939158729432,
-            12219977895608559317,
-            16460563058712487401,
-            1038654997415624034,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11741769049383093341,
-            522670159545883376,
-            17361262395849570056,
-            3246898062367131450,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8461383714911602773,
-            1319966702942561220,
-            11345523828267054445,
-            3073467640639049459,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7487912544887597664,
-            17541287938305547005,
-            4937871233243656980,
-            939612671051691057,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3222888272088143062,
-            1895782901668590044,
-            11177665019210168729,
-            1987361676993665341,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6925037685367387456,
-            11155378893736896448,
-            7314775037864767153,
-            3115225580971443159,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10669598878086554286,
-            408835381691861362,
-            4259090258027398153,
-            1387977389814936349,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17325700501133266027,
-            5977865123814205535,
-            6483588378110736914,
-            1256627577931294774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5308616542901852478,
-            8319687604053112487,
-            7466490708168953504,
-            1191462051379667390,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15258461131939441318,
-            15926032616288769905,
-            11654770453614049185,
-            2893838333801116551,
-        ]),
-        PhantomData,
-    ),
-];
-const M175: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8962359712392566671,
-            1072354694751116696,
-            2734212620856239690,
-            1424102640040558043,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7692470503346450141,
-            14411860410591420126,
-            10387204528903585125,
-            2814003267505461187,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12723146121988361336,
-            11716840953220451942,
-            553238398265277532,
-            2983750834873205344,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7330718709409207439,
-            3557700432453817757,
-            8431781119821101437,
-            416347779367102409,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6611901098195682873,
-            2655917995767989871,
-            1380610843629193351,
-            2938682207064372411,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9282876479821600124,
-            3083415114178816601,
-            10332869023962208597,
-            1756761240276720543,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8788874004940227398,
-            5707825015248020040,
-            4877130811802381222,
-            1949253567918103542,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13484316708312523843,
-            2055797144194620388,
-            10983925627103598471,
-            842540793119127123,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6935566829497526136,
-            15498607467982954948,
-            402229006780735794,
-            617276151665585579,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6217110915319138630,
-            9654025534895722760,
-            12425837918640934845,
-            1153748923574499758,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1578360737027631051,
-            13082903814917251038,
-            467520225536711047,
-            1861594947822936734,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2098262889603630698,
-            10020020070583012348,
-            14177661136892945395,
-            1083542189940351656,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12013730779879120862,
-            3439248556838357059,
-            223869223268676331,
-            2637408278827781664,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3607523332114864690,
-            14197361922849794662,
-            9283215863370237383,
-            2482275728391977454,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16507001837364381428,
-            10581857435255895723,
-            7539913367276945883,
-            2340557980657872283,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12327113012592749566,
-            8715666101863324563,
-            9757461175502318469,
-            2488267197841418714,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1460010151925912752,
-            2384213651555405386,
-            14503307871043377754,
-            2324274181798648180,
-        ]),
-        PhantomData,
-    ),
-];
-const M176: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10347760676854439235,
-            9423596666695201885,
-            3792821104326166909,
-            3103433941109480837,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3866822330137625071,
-            8022830380136645071,
-            7982461166077168612,
-            2360230105859248939,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            676220163118150019,
-            3454407088188670207,
-            4157410928281005394,
-            2908244030803288931,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6983458139506046711,
-            10243075116618161217,
-            6680949405412642606,
-            116980217485235321,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17614266285882074822,
-            5431882119159526291,
-            7448659957624012871,
-            1155114482227980991,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1247142559013852740,
-            1309300242302575472,
-            15433719170365823378,
-            3020394794779709582,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11361335651400891115,
-            1294637196873418340,
-            9955577181979763679,
-            2675189871209154328,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10977236406903385385,
-            10594021753615162186,
-            8251677885967484054,
-            1864096334527787823,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15422712876678892717,
-            10396194424714863562,
-            18106115786699892978,
-            1030400420139710327,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            503410878047469575,
-            2130388194427293105,
-            15905540886757272550,
-            1849387590378309300,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7589049047562659405,
-            4561865657260017888,
-            1019973581292257215,
-            1993914727493285049,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2070275557029229389,
-            5725023986755635040,
-            86347900104806429,
-            2041497092747226555,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8940418412097076236,
-            17462672127518674547,
-            118579999394299114,
-            1491297900011161828,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5627688014206952607,
-            1039729733760915629,
-            9191504221280734671,
-            3029991658738635256,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1756536394263169921,
-            4433723607082883143,
-            14019853490036937884,
-            3333476159100153499,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9136216982971399084,
-            15750476237121128235,
-            14877529496835692328,
-            2137829009260270212,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17605281281330027059,
-            9379730562955820629,
-            14900692925636530714,
-            2020610043146507935,
-        ]),
-        PhantomData,
-    ),
-];
-const M177: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9026664941482825374,
-            18414524129333280965,
-            12321692502665862481,
-            893806419902097008,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5766796870576226195,
-            10196481397699992901,
-            13265705984618285483,
-            95225192188081105,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            77998273393970425,
-            13244494392591220447,
-            5318614247728457340,
-            3007819687177170195,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11966842923479330093,
-            14200711842538881994,
-            3755397196963366257,
-            1264186092053380053,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10865278202016329795,
-            16215550434920322987,
-            5927137158677819150,
-            2429534738600044700,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3921977463004021840,
-            12782407109900195428,
-            12856270695430821498,
-            1861853149849294524,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12322064840458219503,
-            14710861355477860679,
-            17691758336696360495,
-            112118032479858087,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7135995653216591889,
-            10477462739334609037,
-            6969923817973326752,
-            2652898509463914020,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14905238865152218376,
-            10161252595879536974,
-            2783829938722889252,
-            2813662273229635447,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1587704743769418190,
-            3645132598025143,
-            5286305716647097287,
-            895041990739608035,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16367223892110922498,
-            1068572572097885898,
-            4058921375782681716,
-            1670421860122405398,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6278876697434430980,
-            10886334998308536522,
-            9966923719587982137,
-            703788034541763152,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13032164272059537936,
-            2185078766438087277,
-            6219329879420100622,
-            2107868207613022138,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7927584824760918284,
-            10313955042148433258,
-            6764578205979954921,
-            2332276244458174630,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11263556619638076226,
-            3046612715006317116,
-            769672459264615064,
-            1654420507984077926,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8841303546719684758,
-            13435869029663672103,
-            15876659081628749287,
-            1778136710904236484,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4923899431077178639,
-            11884121401732156460,
-            6291340817170309277,
-            133322016652749415,
-        ]),
-        PhantomData,
-    ),
-];
-const M178: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6936385237647825858,
-            18107166577111658366,
-            13076626433606426219,
-            3479950506014382567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10069244307851556243,
-            17306872340813879620,
-            4185086126744576603,
-            865110790967243756,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16747612359658297311,
-            13326916226948418238,
-            10643664895958216629,
-            1647346693594708014,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14353649155813624692,
-            1998280816441859022,
-            233380261469126114,
-            2519261651124130887,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15921861100316329275,
-            5443162705851626949,
-            13492102092522131729,
-            2968759003173230845,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17172584813696408583,
-            5246962081056323678,
-            354631368914493090,
-            127535311712194351,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3077955639672811713,
-            16517849238746451135,
-            9387539106103207662,
-            2993904711670676197,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9103858159255085496,
-            3917105878946474193,
-            16965313261324720925,
-            2670790745741866394,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3090452401893733256,
-            15365839062145754588,
-            9134248131630840061,
-            221292505967616224,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1086460120496403160,
-            4341813442172995560,
-            4139697214306369394,
-            170231124191700589,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7446351306612265054,
-            3945152435151714874,
-            10844674602690165827,
-            1434664484758638645,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15415398055473201853,
-            2550430169977198182,
-            14806166377268041,
-            1522586962582797776,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1414622772957553336,
-            9151034114314737712,
-            16781384002195227571,
-            2038729171821771470,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            735782707447110373,
-            9025767879040652546,
-            13782757954636963883,
-            2260751459485321879,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2459683718322465777,
-            13331573119286808307,
-            16843299261997788058,
-            977773325282223445,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11445453669602923658,
-            11696623930369993950,
-            1288834478014066040,
-            237195362836164513,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17420022778552418361,
-            16680285034886269224,
-            13061180689690812427,
-            2240556688459592592,
-        ]),
-        PhantomData,
-    ),
-];
-const M179: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15875821579124406995,
-            12453489076962869415,
-            17465002163759762573,
-            1600346134009736086,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11621827004369338044,
-            13948170894468450271,
-            4928583601286112553,
-            1263653617900026916,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17044748799548428700,
-            2846829734917806065,
-            12008096946329124843,
-            3096169084911871118,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7012978838830659829,
-            10101248021927022496,
-            6984345776861894585,
-            1738306106796781666,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16816506629101878833,
-            15973004196791321535,
-            12432304965718081229,
-            3018804530684226657,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10089773557758962819,
-            3521011241794556170,
-            10308226920503182068,
-            2357586385298409667,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3704085176238728782,
-            7209873526633068095,
-            12836671520552953707,
-            721744231872214929,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3596618180945866974,
-            5816154767236556335,
-            17370363240140354945,
-            175160215217059235,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4395233414652625113,
-            3397630240734095791,
-            1302211788460409526,
-            2182670633411536630,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16159505730509249513,
-            9137937413006453142,
-            12866018356248007016,
-            2872045351087116551,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7935387218617809384,
-            1175485773752944678,
-            3261737905142588262,
-            2372695619630726665,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17081848182556059390,
-            14157348071864622463,
-            784823073130008305,
-            545616748446007333,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2730718074112100073,
-            15599474665231944547,
-            4560083346038477801,
-            3142800278320066971,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9286005813482969333,
-            12138655217715242172,
-            2923820848171736213,
-            1793819978281058326,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17020766983919734959,
-            12177256993410582899,
-            10042371542871892635,
-            217905672731894430,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14959631180949508848,
-            3859311209458709922,
-            17899241105136620431,
-            71448997456860394,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            768883188759846618,
-            16760340860024028594,
-            17578024815212441679,
-            171072089496945511,
-        ]),
-        PhantomData,
-    ),
-];
-const M1710: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7633460385350008620,
-            7457532700490967957,
-            12691704734336738979,
-            1801323825567327878,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17858924391723737700,
-            7664323467392187884,
-            10932973814355843041,
-            1182124475394014719,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9593481953637191258,
-            16168494364941113390,
-            6899379914523202998,
-            3464834198660800457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6535674497292884558,
-            2489306527971657901,
-            17199549772190735927,
-            2117055650968866684,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16070254790800893515,
-            2540823765269221077,
-            9425032438781431048,
-            2365451359286585434,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13941371723575963043,
-            848153262930690635,
-            12376459003765301694,
-            2727150437524627886,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18065251929504104414,
-            9635400424299646430,
-            6152836972074557541,
-            1652915864830095258,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12875384164229361055,
-            8597564774356298279,
-            6174927775405388392,
-            2226766613919929274,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2976440349050505135,
-            16346165685514323562,
-            14015242158946724824,
-            972681188531515026,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5743442899063161717,
-            10038068409697512486,
-            4862646552708007958,
-            912693309493385464,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            182972461519991479,
-            14154721396049742513,
-            9719901047061997779,
-            334205729307611084,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9596427006405164565,
-            2707284648923166036,
-            6107551390228616019,
-            110737785367456330,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16992073423271946205,
-            90043379227894642,
-            2167168527149170705,
-            3303119624230948539,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8034307502748651494,
-            5558453723173359750,
-            7862718698127933253,
-            615171644677911102,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            402087863760169228,
-            3966021593278210238,
-            15910198915552104377,
-            212435054055537400,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7644995486541743923,
-            794268749118476596,
-            810171232954639640,
-            2002284549906566624,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5391652143320011631,
-            16388082875492199782,
-            6906900865928947691,
-            2225176997435666318,
-        ]),
-        PhantomData,
-    ),
-];
-const M1711: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6615612638820873395,
-            9056704868409220780,
-            15915706108025586032,
-            3436015746305878708,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11501242712154217363,
-            7840236435155525976,
-            11791863932335038710,
-            2405073257554562907,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7856407156690144407,
-            4378483690053124150,
-            4514635171917486607,
-            1988677854816508127,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17065367653355206414,
-            7200669928306863359,
-            8790516271758749116,
-            2448721823043965818,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2583574271417776514,
-            2933412687510003267,
-            16989771216670625808,
-            436763212987324082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2938066513899616577,
-            17851169698066721101,
-            4769386807599986814,
-            1364625733906195492,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8007412796967911794,
-            11639762252375096921,
-            3322057659423835938,
-            1501125646792330230,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9837721272957311977,
-            12588830323281204095,
-            17784812155443818145,
-            204562986986527554,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9119437343637258311,
-            16462107744324697385,
-            3820678056255356375,
-            3167632936815350875,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12849116760763943850,
-            3889647511728940853,
-            17883304002945270426,
-            1524631748881090401,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13059157173922553124,
-            15044728523333253489,
-            15465990530055850735,
-            358081382955542336,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4690632255869936387,
-            8525743437637532923,
-            3263872084651379570,
-            309254068223287914,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            991918766911675345,
-            6141867280659368512,
-            4599419316126226466,
-            1758424713485051345,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15887059871093856286,
-            2263960537341929447,
-            5598604755633394171,
-            2392752694015829389,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18043283603917870434,
-            272891818736432273,
-            4658196223437547365,
-            977620760171861349,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12631922341946275993,
-            2947968222040827129,
-            9508206479063639055,
-            1873431514120848272,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13650917512287760485,
-            5328790496622801172,
-            6518804129449466569,
-            8504134155947345,
-        ]),
-        PhantomData,
-    ),
-];
-const M1712: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2649659702728107467,
-            3232901458470303920,
-            12511534368948009668,
-            1241368225692300359,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15755299186633804288,
-            2917332820773894156,
-            1511226655059427333,
-            747409675620470184,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            105761712681613005,
-            17816526250920291844,
-            544295857404183107,
-            2642895139709612028,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16953421924522683963,
-            1291810293852712100,
-            1998902006677438520,
-            2406102575315451906,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6655177411312810832,
-            10653788565690728712,
-            11986974848696294462,
-            812040757614082089,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12359601899047790794,
-            14367165742352087430,
-            6613574361567607425,
-            1468060796970753906,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15231814451478610492,
-            10355474343362287595,
-            10603696915066121892,
-            2899118628934245782,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2835055079183766050,
-            495680086730975676,
-            6945983420386758925,
-            1763146930292983016,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18210947955474551840,
-            781335297749264859,
-            10718806592632622312,
-            2048369493467173486,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16546944613087427244,
-            13987634605751346277,
-            13783135945522594499,
-            2578416927078855079,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14614976953236497450,
-            8732945683236579372,
-            13426977684760543319,
-            952660437210314058,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13007476806347058482,
-            14139966770642956915,
-            10852105621867399109,
-            2637620007592603871,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5641728755815800899,
-            17360776267626355404,
-            6817626480983311872,
-            3211817221577518112,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15049523860841391381,
-            14708581040058663349,
-            10006873112921397745,
-            2841076311671433712,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13972672365169899092,
-            13526459057372999162,
-            15270940059842145605,
-            1209515530621324734,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8628963848961887783,
-            3018179084120336180,
-            11101817400374533676,
-            457469574048935032,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10872864492767836488,
-            11932628797225625667,
-            13073010139388449492,
-            3284521547389870061,
-        ]),
-        PhantomData,
-    ),
-];
-const M1713: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5267429332875896006,
-            14867135497416155565,
-            5086769266749930113,
-            1042226901928687897,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18247227335634097433,
-            474521853984337917,
-            713667089821934461,
-            2213756995635184329,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16081619322741436737,
-            10631024353462510767,
-            14549487797819197155,
-            298938345281718202,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10775034993764489374,
-            5306319041592060323,
-            5435701358692967225,
-            778793944351153997,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17951678745593871492,
-            9202934462651653432,
-            17700409831747602353,
-            3298359140377327427,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1399093963863029778,
-            5899586140339494230,
-            13020995643097978740,
-            2245797357295412973,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8667488320816109989,
-            10552236399270224845,
-            8461307785513630920,
-            508870473527741771,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6070169264704825539,
-            10882589753186866923,
-            11143047449160810543,
-            1409287744068325523,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4113538951460422672,
-            10078519902153715131,
-            11881011583758568175,
-            563068905059552413,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3101567493377503320,
-            14977529734784733623,
-            812301440855523298,
-            2689538551937803025,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13239749424114050326,
-            17759007987006690025,
-            4937337990240959307,
-            1992672229878128338,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14336173385359959587,
-            8004426088079585609,
-            17470158712215000342,
-            598413814018631999,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15871081291595019233,
-            3250786716356085278,
-            14197793327973966137,
-            2813932348314119777,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13625017310986336401,
-            17437208814502214695,
-            355209598154975685,
-            659667618632478032,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2013511006925094207,
-            10657530933045637214,
-            3385415949226254094,
-            203523601795210693,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3499609984041046593,
-            2374688401928156675,
-            16684283854902159642,
-            2057096359769635802,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2443461910618352374,
-            6269857081373982372,
-            4639509796735145914,
-            2083414349463636830,
-        ]),
-        PhantomData,
-    ),
-];
-const M1714: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14594285462311234944,
-            12492618555970032832,
-            555236006455689258,
-            347986708946078783,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13128518504367242995,
-            3300093552429130709,
-            7243452593519281861,
-            1550936609573549272,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2603210753527982955,
-            5795638814789681342,
-            17753305085839439773,
-            1091656042176839689,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1113346919107563665,
-            13580729053184371900,
-            8571354354729191942,
-            3081384519052116400,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16970886703836246312,
-            525299127823396212,
-            8200136293223213650,
-            3162573394534936977,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12044383923620182704,
-            12295919938951891281,
-            10597385683367153948,
-            1454416126537651044,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5070228053042266585,
-            11046083473218976899,
-            6109777552664030261,
-            2760935961184879043,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15687190289879778338,
-            8029530657957415247,
-            60549230790984454,
-            745980403827653051,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16049795773175675487,
-            15442712114898997975,
-            8710969162866204675,
-            3022260518798613389,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2602244748179147840,
-            12054437712030590423,
-            389739757478682240,
-            1761783845783457748,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17254829770823406694,
-            3554498957345573070,
-            10791047047613079208,
-            2420061039059604510,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5438399694682711715,
-            11732099835563119654,
-            15140299445161846846,
-            780251933831929295,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12220914949098156544,
-            7699694750729925483,
-            11525064265111052527,
-            1797670346619075968,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15202975934408355065,
-            8877249188145941910,
-            17112419019701902794,
-            113312599954691964,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14693595225665541369,
-            12654516572107734604,
-            17150579715563190972,
-            2756492854226084998,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11140381225314805334,
-            16209680981407088528,
-            8809868681249972326,
-            50897235659864744,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17918411126684244204,
-            6804020656586005219,
-            7080315662417404476,
-            1258770460622671639,
-        ]),
-        PhantomData,
-    ),
-];
-const M1715: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13540243165774915580,
-            15000697673428297574,
-            5889795678216550192,
-            1977993247873331836,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18116953871452952834,
-            17457083163509491486,
-            496889682864327617,
-            711058521279718850,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3243308664475054167,
-            15289777880925740197,
-            1155278948630909566,
-            2319721917915470830,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4342084058504319685,
-            3118853335918304901,
-            9998374440750464138,
-            709903696817640352,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13570362236725958718,
-            3890881139233518264,
-            15036053735380673622,
-            194276396329184969,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6232035240665695997,
-            5571578581514383377,
-            10221731126616025105,
-            880790081633003255,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9011619203430402104,
-            1538863702727048689,
-            9710968253466315403,
-            616160849240552793,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16523178243092648786,
-            17157139575382610466,
-            8502512061948572837,
-            2183701971021814053,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10857642929883417690,
-            3405930310609558874,
-            16491679600590258853,
-            2394999453294064617,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3949797774992847935,
-            17692794606485151007,
-            3499152776643193341,
-            2325571245885003458,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8387382170590576218,
-            12283365764015826043,
-            9230616925723322073,
-            1378120973890269700,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16052774542393225574,
-            8717935984581704635,
-            304842697006563888,
-            1193114276462319396,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8319505937578891990,
-            5789897720286223609,
-            4689869323802707617,
-            875653041038615684,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4621878935620939964,
-            6595680224067471424,
-            16897714530540472163,
-            595392866128364174,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11951640647656098909,
-            15203761547003269339,
-            910362765669391162,
-            2886408541123117232,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1669245725096964037,
-            16060679373520682671,
-            1655479784573169857,
-            1994497712151752926,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7360517226362989523,
-            17343619345366324360,
-            6024333734285483456,
-            2821723149523435619,
-        ]),
-        PhantomData,
-    ),
-];
-const M1716: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17698487874043478000,
-            17281439531063754840,
-            3264521599287486818,
-            2801693953845797600,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12070856651221327561,
-            1655898648343210031,
-            1033073378086831488,
-            921061218578129325,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15302319645358033144,
-            9837135949921725262,
-            3519116490642379923,
-            1371758591112408879,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9745205670648960168,
-            7045412854255226590,
-            6237734888468324694,
-            77251606175476508,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16251530305430683299,
-            8612520369727238520,
-            10150407754486364377,
-            2310378823691270343,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5672050715943388815,
-            5687567264992290922,
-            6155708042949717157,
-            3070893190318525567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1493313338655070918,
-            10909484938693326922,
-            7932732416434537820,
-            916537552146907139,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17422073396276616151,
-            8801285255035288219,
-            5814356163501123830,
-            2252112913872993926,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17390365960104493575,
-            14132207025746951179,
-            16959336195370668555,
-            12345439176581840,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6628401876239822754,
-            7707050199661932096,
-            9042612777418957542,
-            933616108574576276,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7721559431698994344,
-            12827160095488363263,
-            16585796832877725327,
-            2609940529351334380,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4678140426469523192,
-            6956907972617044527,
-            7686546902162208017,
-            1149221528992648209,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1469606071066305528,
-            3267511786553962418,
-            15778693869724752967,
-            2124927647712987277,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11479696597180575534,
-            9318406702032679491,
-            3036658334407161954,
-            2512588466438590508,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            549698137815431305,
-            4082362364273566264,
-            2503661735787795408,
-            2436052050601137485,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7805011376795887547,
-            3418689757205023888,
-            14987513682143524609,
-            434132644199719809,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11406692831801011654,
-            12179727591941900242,
-            11953128205233775698,
-            233366187883780185,
-        ]),
-        PhantomData,
-    ),
-];
-const M17: [&[Fr]; 17] = [
-    &M170, &M171, &M172, &M173, &M174, &M175, &M176, &M177, &M178, &M179, &M1710, &M1711, &M1712,
-    &M1713, &M1714, &M1715, &M1716,
-];
-pub const M: [&[&[Fr]]; 16] = [
-    &M2, &M3, &M4, &M5, &M6, &M7, &M8, &M9, &M10, &M11, &M12, &M13, &M14, &M15, &M16, &M17,
-];
@@ -1,8 +1,39 @@
-pub mod c;
-pub mod m;
-pub mod p;
-pub mod s;
+use ark_bn254::Fr;
+use ark_ff::{BigInt, Fp};
+use core::marker::PhantomData;
+
+// c, m, p and s are generated by build.rs, their elements are read from a blob of Montgomery limbs
+const CONSTANTS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/constants.bin"));
+
+const fn load<const N: usize>(start: usize) -> [Fr; N] {
+    let mut elems = [Fp(BigInt([0; 4]), PhantomData); N];
+    let mut i = 0;
+    while i < N {
+        let mut limbs = [0u64; 4];
+        let mut j = 0;
+        while j < 4 {
+            let o = ((start + i) * 4 + j) * 8;
+            limbs[j] = u64::from_le_bytes([
+                CONSTANTS[o],
+                CONSTANTS[o + 1],
+                CONSTANTS[o + 2],
+                CONSTANTS[o + 3],
+                CONSTANTS[o + 4],
+                CONSTANTS[o + 5],
+                CONSTANTS[o + 6],
+                CONSTANTS[o + 7],
+            ]);
+            j += 1;
+        }
+        elems[i] = Fp(BigInt(limbs), PhantomData);
+        i += 1;
+    }
+    elems
+}
+
+include!(concat!(env!("OUT_DIR"), "/constants.rs"));
+
 pub const N_ROUNDS_PS: [usize; 16] = [
     56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68,
 ];
-pub const N_ROUNDS_F: usize = 8;
\ No newline at end of file
+pub const N_ROUNDS_F: usize = 8;
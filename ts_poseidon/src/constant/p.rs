@@ -1,16404 +0,0 @@
-use std::marker::PhantomData;
-
-use ark_bn254::{Fr, FrConfig};
-use ark_ff::{BigInt, Fp, MontBackend};
-
-const P20: [Fr; 2] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17920654652615044516,
-            5315228629426935734,
-            16921197825591404379,
-            2189197718402122891,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7470729755515319992,
-            17041816498400614106,
-            13375160378833856321,
-            560417305746632720,
-        ]),
-        PhantomData,
-    ),
-];
-const P21: [Fr; 2] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14492560017976159917,
-            4919046997046981090,
-            263614296871819263,
-            2759311356546844781,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14737248955252893158,
-            9988285311957681526,
-            15162306182917172733,
-            1523291054249726037,
-        ]),
-        PhantomData,
-    ),
-];
-const P2: [&[Fr]; 2] = [&P20, &P21];
-const P30: [Fr; 3] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17503398944334214103,
-            9230635622294457373,
-            15972181701675803672,
-            1035501513956951521,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10031185618283607160,
-            1021827904899329408,
-            2027898939202150942,
-            644829577581408112,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17693094766018701067,
-            580253372102123280,
-            17792480949843436565,
-            3287654480373894136,
-        ]),
-        PhantomData,
-    ),
-];
-const P31: [Fr; 3] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3555859163746403433,
-            10741605658797293728,
-            13576548464965265343,
-            569316227345355306,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            320434089056656007,
-            10023327187822129910,
-            15472697854952844802,
-            1014040493118211725,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17437799077791925149,
-            4150214198986295062,
-            2074485014963175651,
-            667476728929731378,
-        ]),
-        PhantomData,
-    ),
-];
-const P32: [Fr; 3] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2951080920043055536,
-            4426455047811528265,
-            8768180898619925469,
-            274491192378258711,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3685791004738251084,
-            2153559632285600265,
-            13752442926386106140,
-            1494341733206578977,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15563625038501543063,
-            7830459524777687006,
-            1326806468190506924,
-            533126919063978101,
-        ]),
-        PhantomData,
-    ),
-];
-const P3: [&[Fr]; 3] = [&P30, &P31, &P32];
-const P40: [Fr; 4] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14359397154288353683,
-            7568855942418732343,
-            6575810714121073298,
-            1603235339818113405,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15214950336973944180,
-            16382307398827477195,
-            5172974422404477613,
-            1952862625803295279,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9798659726556002024,
-            9452133592655803360,
-            16187278525923205487,
-            2824941746189379415,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11617303812994443602,
-            3353806891509787162,
-            7034867450677407706,
-            1008443194781512109,
-        ]),
-        PhantomData,
-    ),
-];
-const P41: [Fr; 4] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            108602273466007515,
-            16249964682517188766,
-            14716043256467990562,
-            2400229853407491193,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11869443304371811144,
-            6447579329610638243,
-            1487810193902133627,
-            777400932663234712,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4387324863556937783,
-            415067269744349629,
-            9626629578208316711,
-            1886467128293269372,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7488634466367291172,
-            17436125380026907493,
-            12408107060432007735,
-            2692229598964456770,
-        ]),
-        PhantomData,
-    ),
-];
-const P42: [Fr; 4] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6353895381692353302,
-            4091743744793837442,
-            9077326780205011538,
-            34544310704927253,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7467529846530837252,
-            17987194545333658484,
-            16809669604915425333,
-            1465490573352106904,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            495645310928671757,
-            16728429210067444156,
-            4966376946791527321,
-            3088737265466980447,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14530833427132186290,
-            2496980674800302495,
-            12859178895780614775,
-            564992054544566189,
-        ]),
-        PhantomData,
-    ),
-];
-const P43: [Fr; 4] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11312809233593050308,
-            3529573911206627621,
-            7956373469564173497,
-            1979278223697134082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12696974005093336884,
-            15107929469878133943,
-            3033500253002322987,
-            905497989055703561,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1557591219144612270,
-            1490815025439075161,
-            1680851718804270318,
-            3469135761295417028,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11477273314984994170,
-            16841946882811024707,
-            934535349923870999,
-            3169778321459185474,
-        ]),
-        PhantomData,
-    ),
-];
-const P4: [&[Fr]; 4] = [&P40, &P41, &P42, &P43];
-const P50: [Fr; 5] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8594639770776629194,
-            7546478767167658132,
-            13640771885987542429,
-            2983573406304057248,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14864921472999257330,
-            10892861928529450326,
-            11648978846226426016,
-            3163934955210011089,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14399003459489557996,
-            11285690681243646134,
-            12242410217976972115,
-            931614776896866705,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12746161036344824252,
-            1016004599934268090,
-            17677828695433748247,
-            165385115975159731,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15228384306117990204,
-            2005227978225115932,
-            16792294102796933231,
-            3324144365610765174,
-        ]),
-        PhantomData,
-    ),
-];
-const P51: [Fr; 5] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11346081397337899078,
-            3868235280280109022,
-            319557847744162712,
-            1209732933464865258,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14598279286082797534,
-            15115387464900059833,
-            15439311640635771198,
-            180533218157824379,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5970376624450036070,
-            8396879712538468562,
-            2161084001196485151,
-            2954211978152245277,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12524881748757500477,
-            6882787660049285800,
-            12949002790514937624,
-            1047557632802354102,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3312789843944937655,
-            2106269239516835736,
-            5896945132462174053,
-            1615773057545216670,
-        ]),
-        PhantomData,
-    ),
-];
-const P52: [Fr; 5] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13287419000174074501,
-            13394502194651237557,
-            8243443477577732863,
-            1186060625675204365,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5080036299088872525,
-            7690597247565580240,
-            18398125966743230220,
-            3048180496783173869,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2391570293650361288,
-            12125280641442275813,
-            7227712218554972780,
-            1887850978352642465,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1078256033943735660,
-            9053707931703294266,
-            16975834259473822844,
-            3085606925332545083,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6368428772211246042,
-            5309501187618331752,
-            10843249081000936050,
-            1104791854685336199,
-        ]),
-        PhantomData,
-    ),
-];
-const P53: [Fr; 5] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7030314969498925190,
-            1161088964787644504,
-            17064206938715911703,
-            794961854477645221,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6497555741244292281,
-            5715358885431888177,
-            2941586895274049957,
-            341346785301634264,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2271797083888221041,
-            168363507505516601,
-            490955584021795815,
-            3481015188370061568,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17248468958580555496,
-            4312139945403637013,
-            6274465151179455618,
-            96808470429626214,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8671715042247012747,
-            10153303999946449719,
-            8833586761621940749,
-            2619226205180117196,
-        ]),
-        PhantomData,
-    ),
-];
-const P54: [Fr; 5] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8202270750577778799,
-            1895384160460365701,
-            5193294109199544270,
-            203157780055891531,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7914613870010333275,
-            258839804087633097,
-            12824131703707013436,
-            2600797171269457782,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3355245006845500633,
-            15176633343424199750,
-            998001550524668419,
-            3262031059385971794,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7873911672576248546,
-            5559725584029926106,
-            11181748291380524176,
-            3100649686644848537,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6467058676823577192,
-            13473005957383544475,
-            2835356878045535352,
-            1333459717357385349,
-        ]),
-        PhantomData,
-    ),
-];
-const P5: [&[Fr]; 5] = [&P50, &P51, &P52, &P53, &P54];
-const P60: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4638859865247591341,
-            12701419573696611182,
-            10927713859705290760,
-            2891083094489377082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3980824913078429500,
-            1760815751953401794,
-            17256638775476981188,
-            1177858852436651827,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6831985936628972144,
-            15746220575332814170,
-            15646093599458736410,
-            2377908038573407368,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15462411484211726082,
-            5615972701161092275,
-            9081020826009140478,
-            2016033084852672525,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8629953403113228000,
-            13729689645910730622,
-            6695955518114875397,
-            1531248893423618279,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12654426035038958714,
-            1849120033928855495,
-            227996033912644514,
-            3397932724290515195,
-        ]),
-        PhantomData,
-    ),
-];
-const P61: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2214059413692101491,
-            17295904315807324112,
-            2778093539175007343,
-            2782376324993009899,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12926286863209903949,
-            17633709935856316602,
-            3056119807679422452,
-            1482166891208865817,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10250959322506578271,
-            4783976373144494709,
-            6616864573203282785,
-            3302174935591691502,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16969909073181616719,
-            8123698985215851944,
-            11390238397867938295,
-            1176262933588016015,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1070037370185089124,
-            10005168018873290045,
-            6522404430047049887,
-            2379461437179666154,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1592612691398733991,
-            12668376437373910009,
-            7621169397973797938,
-            2770012456541491932,
-        ]),
-        PhantomData,
-    ),
-];
-const P62: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18145278755416295485,
-            524548675471404666,
-            16525699892622690367,
-            1722835628533022303,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13226799009598705374,
-            16812856991532814203,
-            12188952626491178806,
-            938077361431167024,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16907308742516195742,
-            16290572457834609657,
-            2859749725485708914,
-            165823836058662935,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17588626359129184461,
-            11392318260099164381,
-            10992477126592072562,
-            303508747786638775,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11432042603978696900,
-            6529114126204320894,
-            3659588329068881380,
-            3041165003414680830,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18314676149899841281,
-            12160909205949168520,
-            8680656083135792864,
-            2507454774144237698,
-        ]),
-        PhantomData,
-    ),
-];
-const P63: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8919748998971519079,
-            10553111342707770578,
-            9953423120640136427,
-            1819635885589578006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            231465315311060920,
-            9207164109133673371,
-            14911799286175974285,
-            3339066014536579084,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5351372603436850164,
-            4651499976406588024,
-            16961170045377346449,
-            1958328003622946780,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4490937795606194372,
-            15593490710140421262,
-            10845252651183850426,
-            699303892638749860,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4450107465104067027,
-            12488828528691738604,
-            11414537075549897150,
-            486117643960329759,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2616899245349782652,
-            8299703428175564399,
-            6982717860783506647,
-            1655192459322389668,
-        ]),
-        PhantomData,
-    ),
-];
-const P64: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4984721526010472226,
-            17406661742393645519,
-            17548044302249509509,
-            106017418313204681,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18178700808647250058,
-            13029049888155328462,
-            9982814184475911238,
-            1417488941385645461,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5027873602523461682,
-            9715028510940983034,
-            18023875036368462722,
-            963787950347354699,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9265762818342258548,
-            17772898401194584261,
-            10314543749029260841,
-            3109607835898262129,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8772793959278315484,
-            16986055198192517802,
-            1154127522709452891,
-            2787571568212520623,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7990513366086733026,
-            8364149863671493256,
-            6379383478411825651,
-            2352875916052057344,
-        ]),
-        PhantomData,
-    ),
-];
-const P65: [Fr; 6] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14411489553823947513,
-            3865314241170707077,
-            13348642907516700585,
-            1047539638147959469,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11140931381555703592,
-            4657578291622659042,
-            10412047287715635423,
-            1244036415977889083,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11481047410417294055,
-            9754286433077739160,
-            3415993836097339733,
-            511503148580609281,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15977148961914451875,
-            149562105389204038,
-            819309410819214048,
-            1796403611671544386,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16753105202092556215,
-            14773309401670582026,
-            13491700911132362269,
-            389924218012085086,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8460845668965270242,
-            8900901389708312032,
-            2837650835100898185,
-            108674323149581241,
-        ]),
-        PhantomData,
-    ),
-];
-const P6: [&[Fr]; 6] = [&P60, &P61, &P62, &P63, &P64, &P65];
-const P70: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15153710674085495083,
-            12910643420133432704,
-            2797289784435140373,
-            336388720017723838,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4385677867354334499,
-            12341471553717617257,
-            14391431710729559424,
-            3328697438541004552,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2161019561843406564,
-            11206994408219165222,
-            11764902331683125596,
-            328954424921496286,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            674197942254386607,
-            14097587065215926844,
-            4318160446265391987,
-            1674568274962004470,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4282451801030559316,
-            5865105771310936352,
-            1775408935165962652,
-            537469374332784703,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2004590457123780347,
-            7811986720867518607,
-            16292216282682376644,
-            2331536779177578011,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2998445289397417385,
-            17712156962542790639,
-            10085254359185486294,
-            1779109788509514267,
-        ]),
-        PhantomData,
-    ),
-];
-const P71: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15231257745032047235,
-            3368074060636949517,
-            12940491953008342098,
-            615955003522796962,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            48148632786715243,
-            18431464375158743396,
-            1592811082707349743,
-            600476762552902863,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10119713904236159208,
-            16128847455408447236,
-            1414527365398015895,
-            1579121239824796332,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13840623478748232020,
-            4578151502337537665,
-            1277016999447259306,
-            3088617673591812261,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17916299694549870156,
-            5875196647213676376,
-            7599915946576801460,
-            1294613468671558196,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11179327247260827778,
-            1540662213037828566,
-            17882794804592817936,
-            3166674349662958922,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10408094329760163323,
-            8159856850244090292,
-            14077107789163070804,
-            1650131369148833587,
-        ]),
-        PhantomData,
-    ),
-];
-const P72: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15380619388071125957,
-            17306055439625527261,
-            18359409776286739288,
-            2712495107812141496,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3907640863868546162,
-            10654399525949857165,
-            14031351885438504618,
-            3008809557566876811,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15697840833365164325,
-            8214725677672736428,
-            11507507678244206274,
-            1705143759385234878,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14674667449974810306,
-            2126259111019870685,
-            6782657981131883858,
-            3334892547367291304,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2723016366662651699,
-            6148141334196748165,
-            8134672713188464615,
-            2903854752040990647,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8599536985702060607,
-            13897584009952008259,
-            247901461840571629,
-            197790672781091594,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3122896661894873580,
-            16955390296179881352,
-            13556826767417148485,
-            1698629195582144054,
-        ]),
-        PhantomData,
-    ),
-];
-const P73: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16334896021043627986,
-            7768473698819830,
-            2855322591557026521,
-            803707183128705570,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            147561999827500519,
-            136956217326125835,
-            7810050983273148907,
-            804569485701745884,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2439573115442134369,
-            17233120906054082887,
-            9803256441132701439,
-            3279875056466812271,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14760091201941357544,
-            5270947516513126802,
-            6753157831711221945,
-            1961361641493239182,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8891303412596928205,
-            6130218378245050416,
-            9534621550979916126,
-            2164391816787322083,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11963821888100119244,
-            5969321971429372683,
-            9475081582768952606,
-            1209690590904309251,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17482666845863458692,
-            17989655255627465774,
-            6209608917515387586,
-            1305443566657969284,
-        ]),
-        PhantomData,
-    ),
-];
-const P74: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13080547902885263562,
-            8819773114950398207,
-            2015848004488644183,
-            2091735685277275533,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8764049991575052490,
-            15495322634732281400,
-            7695212457489251995,
-            2603109745505158958,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17542398029128721455,
-            3583967988992392789,
-            17019172481971045044,
-            936966703311029502,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5437770543042780067,
-            12229665552694509509,
-            274439142179530976,
-            802450791008254979,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7169998306873123278,
-            6664262274390603639,
-            3485398491662948157,
-            795436355724878016,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14826295431099431561,
-            7523807333703188495,
-            14698298515752989679,
-            1221913342328579085,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18221933573614141502,
-            5716404629993651529,
-            13617186233465841964,
-            782966055757762314,
-        ]),
-        PhantomData,
-    ),
-];
-const P75: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1103037793894593011,
-            10432439615990921475,
-            408676366408273636,
-            690619635530153027,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10000944538194503775,
-            18134106642932511641,
-            6242151381837287037,
-            2039596176304467130,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5554714851454336653,
-            5508869280335920323,
-            8967797078783458616,
-            2844536118088757232,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7103714940508460251,
-            16863871145121911212,
-            15956292183011729667,
-            2871868618067098689,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5948151595012856920,
-            1968051240105464452,
-            3546493853080468322,
-            986453762090955364,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12481887678680705910,
-            6469841108853003801,
-            1434854331781044105,
-            1181685111365716181,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5566894276136002850,
-            16711473048070514292,
-            14951219888358848846,
-            3285990315903898505,
-        ]),
-        PhantomData,
-    ),
-];
-const P76: [Fr; 7] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15899329554679246838,
-            12880134623787619953,
-            15623908173414171158,
-            3339865870496991995,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14094758344072362094,
-            705953518988025684,
-            13929667212070188958,
-            1027502858882738124,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13599530045416567305,
-            13149734025074340798,
-            2512430923002104747,
-            2554624913862475984,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13164040839220820516,
-            3680430941489084565,
-            14115979278901456894,
-            3279567509991875954,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4664581734764004750,
-            4768336702772243604,
-            5601274573342547120,
-            1585816227164811306,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16292195574762746789,
-            8593821391361170100,
-            16707258230899011478,
-            2024121419683014180,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4256466183429219320,
-            4430894203285768053,
-            3574320435132279428,
-            2596171555937716147,
-        ]),
-        PhantomData,
-    ),
-];
-const P7: [&[Fr]; 7] = [&P70, &P71, &P72, &P73, &P74, &P75, &P76];
-const P80: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2255668496628234545,
-            15246293562682024538,
-            15862665822765969366,
-            1243456397736102236,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13150153513105372237,
-            8353532695332263298,
-            12922791837321562377,
-            1787318283719881678,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1907753596075236283,
-            4675833649020854958,
-            821286210423361614,
-            501156648408159892,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7712462845422142770,
-            16750470461285479910,
-            17622322766631627750,
-            2684726865731969597,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1046713881586034930,
-            584115082717853810,
-            5474279123278547388,
-            1291730301438850216,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11589522016757606341,
-            17920220717413652783,
-            17212203535032990164,
-            129090461184032184,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14584707314440282942,
-            8842983422052546904,
-            14582149441389647189,
-            1204450623216288245,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15574443586064832820,
-            8957933123603061238,
-            17587449389031360553,
-            485305034404913529,
-        ]),
-        PhantomData,
-    ),
-];
-const P81: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11839766509476138681,
-            15182680952613917293,
-            12636917596312885306,
-            2752711190835043578,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10167607035635461588,
-            13527190020985525659,
-            13066147691144872733,
-            668368369505274195,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6359364826521843977,
-            18273381513767229435,
-            5143500250241428861,
-            581146379975981429,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8720444048676128134,
-            5845274404406269738,
-            10414560489996590732,
-            2558598410689181807,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17728436641860480123,
-            7212986825566038320,
-            10000803292874539405,
-            2541649642257031858,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9074696173706206183,
-            8237078841007530465,
-            6932276671204682062,
-            2136465892299861235,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17492746855831167064,
-            1317791671142674159,
-            13352792724977621370,
-            2505250733936714115,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4843143708941259301,
-            4537889585126251660,
-            11723538523301943780,
-            3380673132416082305,
-        ]),
-        PhantomData,
-    ),
-];
-const P82: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7227750274011365707,
-            15575317956934748098,
-            5955028652175233335,
-            3326981543517226311,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8704530828342832302,
-            6931881478351176025,
-            17940108324845296438,
-            143748006627550802,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9508029292969757687,
-            6925541083997395,
-            13547440326522333138,
-            1510618863664213591,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4241730522548434746,
-            931049523223201186,
-            7106923963408607634,
-            2897359817486584591,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10964853001099444418,
-            8326962313019330793,
-            3663352944079992609,
-            2696860895287876797,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1299096712756595239,
-            3270663273521823215,
-            161607202187032604,
-            518266075153952088,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10673050058221728616,
-            8230949639656048973,
-            7630271898042223456,
-            353136406643659706,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15674411780234765393,
-            9467317953750732004,
-            5240647596303409358,
-            692521331618692501,
-        ]),
-        PhantomData,
-    ),
-];
-const P83: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7403740575183550932,
-            5492933594909241847,
-            8424978560738301913,
-            2501195922569655789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2781769506616939650,
-            17606294459338944552,
-            13264881800426267310,
-            361638685329618460,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2719252803958085493,
-            5298479758823644615,
-            2173381540668701158,
-            2510921845010909351,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            562161711292707437,
-            10673096346084547141,
-            1569422335936480251,
-            3102585234860335685,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13164838833631466115,
-            10934688378837019260,
-            1762851132890426136,
-            1505548584485939188,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7093921828640837161,
-            9677001548527964378,
-            13884722725623620794,
-            2420805552828631686,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15157955872562176159,
-            15471716419216822338,
-            3539520447112682555,
-            1913649006121566057,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10807405462738845322,
-            9792764702235956462,
-            6194402302801876863,
-            2813098305572718485,
-        ]),
-        PhantomData,
-    ),
-];
-const P84: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12686170943952318550,
-            12350881350819275227,
-            3761503622030959427,
-            2749243505546214433,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14578907422416516119,
-            8793569997732062571,
-            9588787762838091447,
-            1033642072808347272,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13043964233499109724,
-            16953969445246064346,
-            187303966390644549,
-            2076937173536895180,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6673382425099207521,
-            4985529521543925522,
-            10490832297832952418,
-            2170458346937657590,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11286686050086903481,
-            11674891093025274673,
-            11423435714598539753,
-            2606855175677862434,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14784772368613242934,
-            2954476795030552884,
-            3812511131843533166,
-            3325611657549831868,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1969764653049782159,
-            4771741454438705771,
-            4218001623600448279,
-            900711986818809709,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10889400927386739198,
-            8397451138720557326,
-            5868521321752648130,
-            1426139084138289387,
-        ]),
-        PhantomData,
-    ),
-];
-const P85: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17050837798655321153,
-            11961364015963024192,
-            8246338996987557854,
-            396170937774182291,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8338936753953358005,
-            7772415623093562352,
-            8694576942669623012,
-            1425280170338061818,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5774652922635861146,
-            3630335101928558785,
-            7652985127454986487,
-            2745186586264015852,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17767334479053517426,
-            6484653127739241827,
-            7983361356255110634,
-            2603342359598483796,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17750911984379963535,
-            15943774156433353941,
-            4670881870990778843,
-            3245338214078599972,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12973764886868809258,
-            6528637064697323742,
-            8943662862072818681,
-            2708433464960342380,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16889315909706211538,
-            5683207594897580388,
-            8717158872169620214,
-            78201288852192743,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3680448009733127616,
-            9901358101601655988,
-            3210228461940744128,
-            2010719779390554609,
-        ]),
-        PhantomData,
-    ),
-];
-const P86: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4037399471801784384,
-            9800963650356329005,
-            3279375485943748295,
-            2537229256314000185,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1190517206097703089,
-            6841041022084456778,
-            13105327494052241102,
-            468245534421450851,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12032109167058254451,
-            2402345622987943710,
-            14746130866815114216,
-            284131352260498107,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9025702616523648962,
-            9023533278131267191,
-            4492284118799300500,
-            1377014568458113793,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4380321684309960784,
-            7624372817776788925,
-            681518248948794672,
-            1049375298779078033,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6899433723752175923,
-            5513452161636946997,
-            16590135846341856676,
-            3029071659210224015,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11962403771338832143,
-            16549484459864450306,
-            12543220923072160183,
-            2417635054288840204,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12443477316151865822,
-            4500404988530038564,
-            8194296683202783316,
-            2685343323865686495,
-        ]),
-        PhantomData,
-    ),
-];
-const P87: [Fr; 8] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1673811273576141506,
-            7313553960346080301,
-            14145374863817065622,
-            1252394632090100430,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9589759023237951224,
-            9500193480257411195,
-            7344834232834022875,
-            1625892280416957459,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2656856172804143437,
-            7650731538137390918,
-            13605981583691642846,
-            2108893480206344815,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17278158871162876393,
-            13828813961332817271,
-            17548744786263686896,
-            843466215059603563,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7660939199934937547,
-            1732202579841339586,
-            3487852903305894219,
-            1017867665490565834,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5326217031862417487,
-            6321551930046848214,
-            10736285528690954784,
-            3394557340606086212,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5323763896463358802,
-            8932309047481977145,
-            8290517921716143899,
-            1797600609056103329,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10927461986292219443,
-            7489439583728009590,
-            16339268934721969353,
-            1317988356378711567,
-        ]),
-        PhantomData,
-    ),
-];
-const P8: [&[Fr]; 8] = [&P80, &P81, &P82, &P83, &P84, &P85, &P86, &P87];
-const P90: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4933524686769579967,
-            2042354632144177965,
-            5885273385733824968,
-            2068533996940005711,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4128647156931734691,
-            4515720135556872386,
-            4004229673326055921,
-            267046360623075206,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13412721572851870560,
-            7291095547651787027,
-            17848618095795387620,
-            2952445355463443918,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12435218884396880631,
-            7810840115505032422,
-            11448626481580436137,
-            2254982304636739081,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18314082893745527726,
-            9546022812322630522,
-            1170785774870634744,
-            3427530243843259186,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13797436487909942575,
-            195987059195652736,
-            261037837990892317,
-            247270267558031090,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2329079608293047573,
-            12446896201652454428,
-            13950107830398975168,
-            3172173861307247527,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5689367066705459491,
-            6763676980709382951,
-            13802176920720639626,
-            3079595323666820594,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5383524646359610840,
-            16491032476029644751,
-            15788509097421536261,
-            1645056866938666474,
-        ]),
-        PhantomData,
-    ),
-];
-const P91: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18113196693395655642,
-            16644408848458654383,
-            14252718983598861229,
-            247103141222296861,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2294730749927973251,
-            16381865704383540109,
-            14656889194299304668,
-            2952546068594507269,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10170745284690798596,
-            14573678258827719223,
-            3818988811810154438,
-            1424773175540593278,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12748858539642354654,
-            13904507554121245053,
-            10859034920397431197,
-            1950195438348417940,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15827978656390767131,
-            4601071187986915109,
-            12820113415621738451,
-            2947652288238980000,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9084955886795776167,
-            5866663764324750900,
-            7886989345712991698,
-            78474244137532351,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4143542016768673490,
-            14718801011354505051,
-            16176382018403808499,
-            281450616726487828,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6489192380613848125,
-            5314867913335984056,
-            8008053164919970356,
-            2519463932952125103,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16806467213878335965,
-            17364829106381776621,
-            15013410890516034831,
-            3447891578208814431,
-        ]),
-        PhantomData,
-    ),
-];
-const P92: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12362269017651976856,
-            5756950301455108942,
-            14445563773711718799,
-            3286399355255609280,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4351845721073412294,
-            550184285942416451,
-            4499720729623967097,
-            118381334918916112,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2269271779877774814,
-            16005432027001738535,
-            8976244746114482587,
-            1857715620232255219,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5134103974562650733,
-            11881587661972460579,
-            17676245703198852458,
-            756782688606939925,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7636212482950485198,
-            10120883273406055151,
-            12144995511762615980,
-            3124511740136512040,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9169562556138184289,
-            18182345450512857004,
-            11389530197066540490,
-            2503744037823250230,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18171826835416630146,
-            5754976036037672742,
-            16545112803274514318,
-            529890228873621922,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9212408021769295404,
-            14531113027879585007,
-            61533366297845563,
-            1570198230159523397,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17886111581268875439,
-            3372063695087575534,
-            6174356841195361901,
-            3317180437443668969,
-        ]),
-        PhantomData,
-    ),
-];
-const P93: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            626262873594184564,
-            8274756723172521684,
-            16049767424179592592,
-            3445206686663386779,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7522494153296030044,
-            9316986342781448264,
-            18308880275537828083,
-            2384948014551635159,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2922391585197492957,
-            9586581563982513822,
-            6906625783243853908,
-            2735276330697987077,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9383153993698484258,
-            18324942850557968938,
-            14260653507037973492,
-            2591589161710797058,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8485057315679178052,
-            14869927404774618432,
-            7088922966795423946,
-            2076158565426687819,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5021817268936456373,
-            17997422344808856660,
-            17686968394502919375,
-            2255279598635127114,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17230680548328372165,
-            9333872928530203350,
-            6208609263339791263,
-            429052884046508590,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15697821626813985700,
-            14702330958892368074,
-            2760461869164421449,
-            3457621082932981545,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17510031245695983208,
-            15261434506845297801,
-            13030524347181539389,
-            2760455353568678255,
-        ]),
-        PhantomData,
-    ),
-];
-const P94: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15550913694265800735,
-            3519035166657091920,
-            1123025546113934212,
-            1859106978731942962,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15222862277835066461,
-            7265033257385036943,
-            5869926155809869773,
-            1131662509614252687,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11095629642136980125,
-            2501493864386362120,
-            15383554798466537537,
-            3166576352576579201,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            524536033038912281,
-            6087802896075962408,
-            8188110763826329099,
-            2641310719095900171,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16599598587908098061,
-            4081704284991566611,
-            13409658986178303761,
-            129955875895482154,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2731549966593459889,
-            7698427216957818846,
-            18079994650634933150,
-            1756866150086367744,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3284296071258047122,
-            4922383664424193375,
-            5664745654422644852,
-            1589007021355035318,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17503294658344845026,
-            2486202394219564213,
-            1988561964974180962,
-            200195142581898865,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4828032774173238193,
-            11809794806850393404,
-            1674460225042687284,
-            1885274695046239071,
-        ]),
-        PhantomData,
-    ),
-];
-const P95: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17644477432127585723,
-            3587849649608642118,
-            12234412604592943610,
-            1139607490973993386,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17329676849940570820,
-            17747228259449783884,
-            11632689792996881935,
-            3339014592428034605,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2888682194311726623,
-            1892387629622565459,
-            15011220670207380266,
-            1133722496220083301,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            981230389339598353,
-            8398766481578159249,
-            10416323798714762072,
-            1029924983083575874,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6286267199469620271,
-            13851930397477549088,
-            1390904290710216132,
-            2736734430899338893,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11208722103163034060,
-            3146060058913486308,
-            17245578450864481540,
-            1180585237431442664,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2837761224945194603,
-            10158859478748390197,
-            13643997833866514293,
-            313385850024548596,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2744589714435362572,
-            7651260633205470064,
-            13944192115946470712,
-            2397797894773312174,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7907301858651967288,
-            16489732327206173449,
-            18162277366831588098,
-            172762461024764913,
-        ]),
-        PhantomData,
-    ),
-];
-const P96: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4385687108251512356,
-            14097107248487947707,
-            1015644022092061460,
-            2048769597575854371,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3214123956610193573,
-            5768662196827504317,
-            16292491422068517052,
-            671547244050900071,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16153009837533253710,
-            8614122393163302708,
-            6530688838841555102,
-            2645774672071012865,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3413561855219357288,
-            17412999207382975883,
-            7107807806636709417,
-            297639382848443981,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1532673709566784855,
-            6775961845798889482,
-            307375151344536176,
-            2591089279690523371,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2695478297914562494,
-            10996916826758289385,
-            11553736701214130143,
-            1128326268861812010,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7798818829941470896,
-            16004185217534499402,
-            698140981170743924,
-            790945944101771191,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16648703773538300345,
-            13864016322310808395,
-            385319856126182696,
-            2888181261660517303,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10053375692276923277,
-            5333899733172821702,
-            2737952752993898013,
-            3290353996963492722,
-        ]),
-        PhantomData,
-    ),
-];
-const P97: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14424019300931158690,
-            16084697900623158843,
-            15916118353013959179,
-            2375240145824534749,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1066379085842465151,
-            8873463863078944369,
-            5449083304763776725,
-            1920689777014504758,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13481942100072432214,
-            9487878346996861038,
-            6351162481586345775,
-            3020982814179747230,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            88571399205760108,
-            14882087633220323006,
-            15328936735930713903,
-            3189694475709392439,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1057095809737943350,
-            17465637758908990010,
-            16482282562142147859,
-            2995002021264193575,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13875312102422553251,
-            17611798788951797217,
-            5755226669885182189,
-            1770154842812715000,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5265918470408036037,
-            11237247096333474921,
-            6154712124681252880,
-            3016424122800394059,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3151521687522402707,
-            505378338586041360,
-            7087522579235780921,
-            2006701653059696263,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5407179575541816626,
-            2921571749900866988,
-            11762412948477349346,
-            780575834491010085,
-        ]),
-        PhantomData,
-    ),
-];
-const P98: [Fr; 9] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16979536170967274882,
-            1435178713448090716,
-            16878948847624853284,
-            1097807827477989969,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3240104027213343781,
-            1162724430538552735,
-            8946889664600069867,
-            830185524658758893,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3741251580084998732,
-            5358583198280670724,
-            14603989358598604666,
-            759296392310405315,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6336847298471274048,
-            15766544502573115897,
-            1513021425386529145,
-            1906070662169517372,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16558308320429772959,
-            12247459442966567104,
-            16207701548035287439,
-            1944065048757637555,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16216471152857727704,
-            17868235953364015679,
-            13797042252462772764,
-            1419825086708794110,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8646846918877436750,
-            9009179356549560802,
-            8837708939662684287,
-            1195814124982876120,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11223606104873246416,
-            14663722644145353560,
-            16259989512329292463,
-            842545634841140611,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9184220609454455242,
-            10857392992927519331,
-            8277385102868503217,
-            3481621428561531629,
-        ]),
-        PhantomData,
-    ),
-];
-const P9: [&[Fr]; 9] = [&P90, &P91, &P92, &P93, &P94, &P95, &P96, &P97, &P98];
-const P100: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16600631333450986702,
-            5908802470013039510,
-            15804220077681601167,
-            3110049343395948731,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16039900831789413020,
-            14641873734326325696,
-            799938800522183192,
-            2228821496907362723,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16347273876293268525,
-            5675053921330137440,
-            7012583322851918064,
-            325698983132069859,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2524799438478979578,
-            5912986438461839047,
-            6817212475494663872,
-            2158340029031644558,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4291676026459820793,
-            14199371901852242006,
-            11939317784459971697,
-            1253232526390345435,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9615622943864110238,
-            9959815854116739235,
-            12358796026261547940,
-            1536214469376312013,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11501937886530488417,
-            12448739554765353862,
-            6104092730192290630,
-            2510241312404292299,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4513751137397239697,
-            16062027337750640288,
-            3671314219378156170,
-            3355769232105768613,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16490346825386951129,
-            12342854996590145381,
-            7583177110689929544,
-            695291093793424918,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15909503236929054804,
-            6644196100094377988,
-            9126509287168834814,
-            1764409382412696691,
-        ]),
-        PhantomData,
-    ),
-];
-const P101: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15659925491892548621,
-            12755948412133458164,
-            14880799827126958747,
-            2307480687364299370,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16818386498501184170,
-            14558908755472650008,
-            5137980721482528422,
-            157139403255236724,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1692407465706202926,
-            4003990426736427066,
-            7102023888971803615,
-            2260814640053145990,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13249743286372386152,
-            11539764330231526741,
-            5002598296649596448,
-            2282698762739114295,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16868084630782559193,
-            830822795258434456,
-            17300557419656749359,
-            2218560924179655074,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3359466155373052427,
-            16754050639318264766,
-            1518520922675992572,
-            2320690150771592759,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14771810642508647820,
-            4591415265979705267,
-            7841407717239805137,
-            1538135630407528636,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13700301096915675982,
-            4916319365504986347,
-            9960274865960757520,
-            2147162132405833282,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            900668875277272346,
-            12192970583873299881,
-            5470597005191049618,
-            1813993391782649815,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14398092373947318490,
-            5263005895630831853,
-            13500078677919195049,
-            2684367865619132311,
-        ]),
-        PhantomData,
-    ),
-];
-const P102: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1438485312388679053,
-            16386052118196646600,
-            3771116474428455816,
-            3256267470853999235,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15133508520212216026,
-            8851152840850563097,
-            5015889667224740908,
-            561700196715874313,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1448943113011826850,
-            425180524093786216,
-            4803350327070979187,
-            1393042934293340169,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4215919315288514299,
-            16298421324699733847,
-            373899195444578699,
-            2234863193399933253,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14159863649704531795,
-            13048101998237930955,
-            2787602632829531660,
-            1778157225043470569,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16671077367580926358,
-            6889150626334918407,
-            14912166123741451060,
-            2422148959634654744,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3041591365696672590,
-            9325934217242953651,
-            6424778970698035254,
-            1767815063337918149,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1112358032790281538,
-            16550325961764816730,
-            5918410818350894372,
-            2501476129920825925,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16424021345052072139,
-            2433562606489982610,
-            3942995545724360174,
-            3015303703242628528,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10199584575209215170,
-            2913100769359655722,
-            10448167893170370491,
-            3402778906273669215,
-        ]),
-        PhantomData,
-    ),
-];
-const P103: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16626870962278965184,
-            9494304733774770814,
-            17762321678556609763,
-            1756882893492022226,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10039267817856194589,
-            12147813216355025987,
-            12463180806247831210,
-            2581126183792379133,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12879815959700924770,
-            14591084736048902235,
-            11304681650424324678,
-            192961278576639728,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17163360198755044361,
-            17804314996965897442,
-            11265206544196266023,
-            923437773505388739,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15724709264026465511,
-            7069960519730727272,
-            15397682006588141027,
-            1696582274484686829,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2215805733081049650,
-            13861183626395742378,
-            4706806147887335600,
-            1505831514110027750,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6914169440837275673,
-            13340315454404315296,
-            15410078360521869231,
-            1026075081673222327,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14303966948611880679,
-            6425855052803539721,
-            3498842968658723986,
-            2402226485459103382,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14754163483092451294,
-            8197287263179748557,
-            16962899912946446284,
-            3132647145800027127,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15307449413999464517,
-            7249445163840025375,
-            12622139921282816501,
-            2424117085250729854,
-        ]),
-        PhantomData,
-    ),
-];
-const P104: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5307370076484039596,
-            4544333001160408646,
-            9053125869235611141,
-            1249855709779837621,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8092182792423119878,
-            12726636896605913104,
-            18130818312428241600,
-            2147274469258780169,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3375296353006391745,
-            15452674213983173771,
-            15994018409807580273,
-            3111622860461405701,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14666850281708960235,
-            15177068129677801175,
-            12203025239264116885,
-            2906717384021419413,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15227779945945940945,
-            4695041678644857987,
-            580990562321068401,
-            1709059697080199028,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12501603686141509806,
-            16524189423895017627,
-            10439793054781864342,
-            277233189544582673,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14943714382039886664,
-            11475016197179154025,
-            2695446295187835498,
-            2333987982163789655,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15764096736508940755,
-            9891987066679100410,
-            491432862656293246,
-            1049796914393271213,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17742082129345246615,
-            13698335343699570309,
-            7325584718479779615,
-            3265303221039650200,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12595970098870825694,
-            3472674835279698532,
-            16937309926881812263,
-            3392235564266967310,
-        ]),
-        PhantomData,
-    ),
-];
-const P105: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15929882565618011808,
-            1822601549970038465,
-            18067326981728163318,
-            1079972762965766792,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9738768242902556826,
-            17933970746912159044,
-            7925140079253200695,
-            542827854784342615,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17904264577570664644,
-            13244810087525060995,
-            3174300040106354044,
-            683612256375272542,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11334647201313979927,
-            12208651099930967670,
-            13530087943280060487,
-            1535225147117129872,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5346939229199280962,
-            4483111215412294422,
-            14403867262222458710,
-            348664584181524239,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6342602737528197034,
-            8251552204873734373,
-            1629222197448635050,
-            2113875014263634746,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2499076370664924218,
-            6071931011826080368,
-            6177147749125174555,
-            853319919841402863,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3182324172228497886,
-            15215924521654055255,
-            10866922797160017317,
-            1710678184570434056,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7131241493568422680,
-            11738687884855495027,
-            7862579556764095033,
-            1094779825159913934,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9732937428566359180,
-            11155889910830706884,
-            10571918341247598864,
-            2460505422483521560,
-        ]),
-        PhantomData,
-    ),
-];
-const P106: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3833369197749728627,
-            9485933923094013546,
-            8447385306383990634,
-            810220513984362793,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2483404589029356989,
-            8442928084122698515,
-            1790290852852836012,
-            429045319057740844,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3052669750675373502,
-            3564322263425017413,
-            2092550227225319981,
-            2949984666739136940,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14545062265216004832,
-            14999795114730187138,
-            15355935221254673628,
-            1446819145545080298,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            575274473398823540,
-            5457375714462279905,
-            9311806911494650430,
-            2724497042990098660,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1132873820439860032,
-            13572846382686731686,
-            1207172660474924768,
-            437423925086895014,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13930895648363721640,
-            5134624371914685181,
-            14272358343978581868,
-            1399289576142255255,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11218359367742700516,
-            12796150475396744409,
-            14896111132602146,
-            1036828354351921576,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4420677455405496443,
-            3602224942637922827,
-            2925548975903661581,
-            1158891272704733007,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17389693974198954877,
-            11301755119281228167,
-            15928388681855541889,
-            712504678825415347,
-        ]),
-        PhantomData,
-    ),
-];
-const P107: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2273751205512557607,
-            9222476303506859568,
-            16981709986265242052,
-            1271762543469840297,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3200976068187882118,
-            14543707741733369909,
-            4295717206711367312,
-            1710619493783228939,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5654834842767969253,
-            13168593194078102118,
-            8012406665899459302,
-            2950108748932460237,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7705128183207579932,
-            10040903427133871996,
-            8079627053573995687,
-            1432768772386768032,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16380176026296973138,
-            2547506222137392664,
-            10966169460452460796,
-            2565406391231666436,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14291973682406681642,
-            655326119550472081,
-            9640541292532244491,
-            2201722960585022038,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12673989475592629101,
-            11078063673270075432,
-            8789277814688376523,
-            1767940614643177305,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7451263269697871704,
-            14314170984032725801,
-            7025363286856940131,
-            1058980506694945797,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7565400629644126513,
-            9535584495714578527,
-            2793925745022122112,
-            2651768691121172486,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14041673452143601743,
-            12912865221442128577,
-            1054352892639164126,
-            2687616118183125846,
-        ]),
-        PhantomData,
-    ),
-];
-const P108: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            364040156088901921,
-            8353877204904088811,
-            9907468355965489706,
-            3089282291392827577,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2088871475402179405,
-            5851711525731710113,
-            4424840458550966852,
-            2936482408719847345,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17439292151487823097,
-            14785468746314576237,
-            9614626477916872968,
-            884655687997528979,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13132755442959172451,
-            14422358784764405915,
-            5157316231756064118,
-            3009513641200956469,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4122781676183577670,
-            483590225301772922,
-            10130969624994889956,
-            2725668931943900560,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14843629936793513256,
-            8325102985106036083,
-            6171005859299154414,
-            221760222773599156,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15868905902497153375,
-            8351321902885440624,
-            16946632971004109020,
-            2682112893138865564,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3969141242635427904,
-            11298915942083509369,
-            2810084672006710364,
-            1164797963343409751,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12454652171875830325,
-            11606494926998679991,
-            2194003278625348266,
-            2157850502639660737,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17370494706466532235,
-            2400057000412418905,
-            10626199505662140577,
-            1745107245618745927,
-        ]),
-        PhantomData,
-    ),
-];
-const P109: [Fr; 10] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14689029426330664072,
-            7440797049353724228,
-            14358586592606020395,
-            2116260418670622391,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2825518351591537937,
-            14756629532309129112,
-            7917617502916511354,
-            1469835858626742999,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3598553428886443347,
-            13473544007048968172,
-            5538112703386538070,
-            1521087333217093096,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11585307795901213526,
-            4653533393234680729,
-            289593128524699259,
-            1358502844671370560,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8204676028028798352,
-            11898829324987694380,
-            8081169549409643649,
-            2923161074351506462,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2561132431574665863,
-            8794158753632882836,
-            6498524624246853483,
-            3449245997239059002,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5922875688931734441,
-            17965766606988905568,
-            10357381176917148413,
-            140899711444536269,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11086334821603993939,
-            12652071360715496770,
-            14249107999567106594,
-            220298121352550627,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9944125527803042690,
-            18294731887883773191,
-            5943238477222971118,
-            1714606814390866961,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6566051749200644282,
-            15891115232690258780,
-            1722180216420970875,
-            1884844059446839568,
-        ]),
-        PhantomData,
-    ),
-];
-const P10: [&[Fr]; 10] = [
-    &P100, &P101, &P102, &P103, &P104, &P105, &P106, &P107, &P108, &P109,
-];
-const P110: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13778150922616775651,
-            3640767472225694329,
-            6494653809889298636,
-            3092894481579731692,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1284055215708597096,
-            8211996906566705553,
-            4706387038554425590,
-            2767144077619399855,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5264389727081806953,
-            14432374115444296975,
-            14969302110724965175,
-            1759845011830342799,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8441861861540036651,
-            14214038335737387220,
-            3299207482819311198,
-            647719180841891252,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            706026564842849771,
-            16079630338745601124,
-            6006009940582851211,
-            618269161771316138,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7367781736626235769,
-            14538264337048599602,
-            4552197209713397220,
-            1677395739426456334,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7487922738913196560,
-            565945729546930246,
-            18019467407531338084,
-            2272647652250701178,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13570751100257078767,
-            13782766331066645198,
-            2877074860193371084,
-            1209210331445973651,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16331214146596845961,
-            10625471085223056274,
-            16340806867727568414,
-            3043377093796719476,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1524012556360664065,
-            10718110010642504794,
-            11105847496501652003,
-            354406097356463861,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7778908075091903559,
-            7807715302667550586,
-            16717310143257512587,
-            1076943207420961517,
-        ]),
-        PhantomData,
-    ),
-];
-const P111: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18205369188130108035,
-            12429775561846889765,
-            2022752181120256434,
-            3036364629608871273,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10740482847578303787,
-            4558338396605470092,
-            2456759438093588861,
-            2975047714211809102,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6528144363018223747,
-            566969880895938726,
-            338222843191129912,
-            2118499196804179022,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3236186305672967936,
-            18079457930704219723,
-            14426967215712872415,
-            2927830528054840516,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3025865207636967612,
-            2007759551844016233,
-            14600360077973126236,
-            283140378219974985,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16293488421272122192,
-            2028961221813953275,
-            867559670262153846,
-            3379074826672751615,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13708524800414203517,
-            12566166497179970578,
-            7268868159305201936,
-            2153508529291751509,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5507380353999011574,
-            14676578644211077289,
-            5835360203204565097,
-            1472003868328287936,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17150377777494616185,
-            3505947644532700601,
-            18074126858858078154,
-            122113158889456896,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10207034394735984017,
-            10290146965672788990,
-            6581238020253895383,
-            941393098573900869,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2896421176621460268,
-            14284651416030347330,
-            5981479802850099527,
-            681807844367648733,
-        ]),
-        PhantomData,
-    ),
-];
-const P112: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3342770821290973027,
-            4372971046333655103,
-            9001856182803580456,
-            2682440133780657002,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3084546940821034483,
-            17714616195176907699,
-            527843098850271981,
-            3109269880226834631,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12297166133296384528,
-            13209207844898889985,
-            8559939872392319353,
-            2587035064294395219,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4680329823010910118,
-            17605366765084028010,
-            1159369906488888772,
-            1864324828911448656,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            591292785193168651,
-            2681057358033518315,
-            4649007015284467578,
-            3066746614820034951,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8043410753143094255,
-            8508063227581767583,
-            2762449474332947797,
-            2372108245319373697,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14313222717060005842,
-            598112904187163366,
-            9663585251024125491,
-            3392633667986477158,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11754372361336294041,
-            16309796586105074919,
-            7393874270376117102,
-            1083978348505253817,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5427017322835993314,
-            10821000229832299527,
-            3366975027424934694,
-            337348456104028258,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12207427762926047274,
-            5773788340336187593,
-            1429598435590808745,
-            1486372342644866139,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8660302537769160799,
-            6033911176450695407,
-            1522382400027489317,
-            2668839766051868650,
-        ]),
-        PhantomData,
-    ),
-];
-const P113: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15879608713073651914,
-            9347597141258933304,
-            2814494829199330777,
-            2826776200726941747,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            451218551495399966,
-            15533158944776285540,
-            11437276148312089238,
-            906154298502050586,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4105522228638042400,
-            15643786212082660090,
-            13627912046411227158,
-            3158880145186459923,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10845186378611173191,
-            6506051622206290676,
-            17543781902156871325,
-            993930026363988295,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4881507926575957522,
-            2279965813016192587,
-            12624859360713276246,
-            989545039019640664,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17546121078518205369,
-            5029018355043845112,
-            7756848940541286521,
-            1075567384199683042,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11666116588348371510,
-            135404747386530846,
-            56425214155207356,
-            3453966113758905610,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1443418295197036652,
-            11010174110660660288,
-            1630778016022804814,
-            1631246361967709818,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10499706359814162200,
-            8324037871620403470,
-            2066387382903658299,
-            928621255990344473,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8251951937567447541,
-            969557628372704927,
-            10067301835736196576,
-            2696169627952907276,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13895235166310996959,
-            3679257316413282641,
-            18208903916260889608,
-            1066712931321909266,
-        ]),
-        PhantomData,
-    ),
-];
-const P114: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2726407223817287633,
-            148341805741163469,
-            7621960538905613065,
-            762244143708248819,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16040452767465934660,
-            46922841358698796,
-            14798984852683324004,
-            2765401092807458145,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1002763157189825840,
-            16246358507412319051,
-            8277777309638234302,
-            2617738150382507179,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3596658838718704528,
-            12648265134145417844,
-            3065609422774504706,
-            2271046455100807726,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4477885353747986983,
-            4179328537697763366,
-            4749623314396747082,
-            415790814976544736,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            814557945879666585,
-            18376631873280689247,
-            5943890978778505630,
-            2236344522973092852,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4319306564180023806,
-            16201808081764417221,
-            14786048892428551401,
-            3212861022034006175,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9050209984675798707,
-            17267781018647340647,
-            8319694552687536101,
-            2039545236371155337,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5721323269673952197,
-            13097858103567177378,
-            15521731031391911032,
-            634176744870262774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11143385068963961887,
-            16936792524174886703,
-            3872983518713875510,
-            2763156243960698839,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5741161571798932115,
-            7498270696747344467,
-            16246542886581707955,
-            241785925716670884,
-        ]),
-        PhantomData,
-    ),
-];
-const P115: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2786451271014113854,
-            12685523230279738176,
-            15694170434652168240,
-            2576163107179522534,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            124206595722284637,
-            18250049987296555005,
-            6176524557571867078,
-            507175247309682640,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17137985088959036276,
-            1500024122653663873,
-            15764984133532415465,
-            2545888588188388587,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2546677795250396151,
-            6823103027389961709,
-            11170343395385521512,
-            3107567517494520848,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12433393950760871286,
-            13315614564079549301,
-            14803865307752118176,
-            2007319044351976603,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            471374103607797513,
-            2532818897335987133,
-            9192618536253172169,
-            422346974840640048,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5707690423765505416,
-            16416166967910799453,
-            2621860789402892976,
-            2029394198621538867,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12742521340220561353,
-            1274645002810895300,
-            12440163406111966079,
-            404577226951603189,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9671304883484663817,
-            18344474392586695189,
-            18315595934778226958,
-            1762971503415872341,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            524480351999787213,
-            15459625273883031989,
-            15637251809130929098,
-            549883695909195896,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13736200804660225161,
-            17251557510496113632,
-            406683055590260893,
-            672147508530480478,
-        ]),
-        PhantomData,
-    ),
-];
-const P116: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9014186571906523507,
-            5744781597470249878,
-            14882317793545267269,
-            1034744415900853100,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8311709922034304817,
-            3254323971021112307,
-            16595897481258216133,
-            887251779797659601,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8224959319730856172,
-            16877494819918120227,
-            9848098884949745903,
-            2592462714583524279,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15493518183248393915,
-            9249338747511466403,
-            11392994751836488598,
-            2496669104178463840,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7644088261301434321,
-            11918054782408081868,
-            4111889726243660757,
-            1104154559543580529,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7183516951707678307,
-            12405966984899661782,
-            5901564904787881498,
-            3385047457933647038,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5145681699169267250,
-            5108005000368528631,
-            11583848694955663555,
-            2388378718257536513,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10352782887593087644,
-            8671804749629622513,
-            15084689680246685740,
-            2009261086556697298,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13938753143374558202,
-            8559910412866643081,
-            4882191323252019990,
-            35607973702532515,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7504017622008604380,
-            1643016132770332107,
-            12216094681816295518,
-            3270152493965048219,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            582885048807476938,
-            9327525372034474325,
-            13257967496828610231,
-            797211468489775220,
-        ]),
-        PhantomData,
-    ),
-];
-const P117: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10076932938606761704,
-            5830261657850587712,
-            7384782709812700069,
-            2249240416688714898,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16757977004579358830,
-            730871949101437629,
-            1202610153451796217,
-            101208502159045874,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8211274353195638164,
-            16761454067388904544,
-            12341051674460131668,
-            1148255458692871817,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9803561557149001242,
-            17923876853628087630,
-            11910540542416476995,
-            743708987662799502,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11927360756016889520,
-            8899165414204068639,
-            6028036464894123267,
-            995234496561745427,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16912176510572878939,
-            6172158920472288147,
-            1938745266076467348,
-            130945049703809929,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1275024233987208734,
-            6390273211350497047,
-            10583759739636046600,
-            2814386602327616585,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6410745341524066263,
-            10624443056162566444,
-            14822926249326633614,
-            2055329517504978553,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5702663901063749476,
-            15004185021133381506,
-            10246570044728773299,
-            617346366686455542,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10459901066448311029,
-            322267837339838243,
-            13154023071596195900,
-            2338420870691746989,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7337951836000292723,
-            4208634184827669217,
-            5173756994505497713,
-            1463459090452484335,
-        ]),
-        PhantomData,
-    ),
-];
-const P118: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6705619773844458074,
-            7525598502239548392,
-            3677242466006018170,
-            1011342857346480577,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9010763021211715097,
-            13799879048132135543,
-            436431130016179852,
-            2905662347907757040,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2311783284737554750,
-            286412436929367609,
-            11760122644234888152,
-            137011506266527394,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2333442949416543971,
-            17888475546049106765,
-            13070255541382874064,
-            2459669818097500335,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4316870540437571391,
-            5397317035491021798,
-            1382068336151790442,
-            1239250541083090968,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5245371041356039036,
-            16737595885186231462,
-            5578490892281078321,
-            233494122487228954,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10966521176171370143,
-            3898173843020252099,
-            14063029035337575070,
-            96422083103744456,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4361198172055879591,
-            14690212812103864041,
-            13936425405016892134,
-            1155728059923888834,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9547695679297700833,
-            13278542247186708111,
-            6083456351248183602,
-            2149110133851133010,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3407206300564668605,
-            14469485367546423508,
-            13898695464611397709,
-            1131917575627168526,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7758741537580618821,
-            4246594754046859131,
-            579813597125580079,
-            247597743664350959,
-        ]),
-        PhantomData,
-    ),
-];
-const P119: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5888561586645772112,
-            1749994041946282873,
-            10737487483307520669,
-            3442280115673048832,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            750944273955213744,
-            17690126991493418722,
-            8683827271105994184,
-            978162341447291750,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10777264153684004595,
-            5636312466964719934,
-            6130740280287965907,
-            2514170330981162277,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3071598520937202511,
-            4293914791151884534,
-            1142548339496251351,
-            3430550524667876070,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15575015301471109730,
-            16334224097613606952,
-            10463567803773980285,
-            2747859604681173006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13967211167543168419,
-            18325037378271364879,
-            2439408894763010466,
-            1941124235924481380,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2476594649394767926,
-            6966366285152016203,
-            12103791141816073897,
-            316879815178796353,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11635612499630258853,
-            10729867904150416883,
-            17531429750307661979,
-            2736856502004942642,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13299023985264068095,
-            13919338217313807054,
-            11609137240981613542,
-            2129689499646050551,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14220678064728222949,
-            17603997256967905044,
-            16944806155691492653,
-            1992223831509940976,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16595688189457726531,
-            10479849828892559900,
-            2931740422781966063,
-            3125915275827705194,
-        ]),
-        PhantomData,
-    ),
-];
-const P1110: [Fr; 11] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1845149794808336814,
-            2465548328461858331,
-            5404238722193328671,
-            460700021952314018,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15497246833334127142,
-            16490015520812566699,
-            9709987145859513629,
-            1627204304230147612,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3144592142795723978,
-            17318710361963913611,
-            2225681126050421642,
-            2554285107437323936,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9936000327333375414,
-            18250896577926739101,
-            182897610411041496,
-            3358698500720539584,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15009754935715801989,
-            15706080246891162045,
-            17235469738294353891,
-            1826069207642388543,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8730220125162976827,
-            17298465952714380998,
-            1838932064607733384,
-            2659880244787935767,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15689079531350061042,
-            10106095440350322497,
-            18440300773216610499,
-            3430588868284482574,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            727955415484068830,
-            6321306234976116788,
-            13440966700918512636,
-            614312820857357635,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7681314633454147170,
-            10228913871227982014,
-            15711361109923218586,
-            1792320645373339180,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16854440843031151513,
-            6297641151620471351,
-            10672967072061971266,
-            3470337577565754556,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10606503940812735678,
-            1386582281296537474,
-            1260723812480817072,
-            565785349089446493,
-        ]),
-        PhantomData,
-    ),
-];
-const P11: [&[Fr]; 11] = [
-    &P110, &P111, &P112, &P113, &P114, &P115, &P116, &P117, &P118, &P119, &P1110,
-];
-const P120: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8926519962877962019,
-            10875404266800807564,
-            12257704583426493663,
-            2156942940422774847,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10211075854505451016,
-            3134063351486756824,
-            3620274165689982734,
-            1050081712024282777,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7023577367677345307,
-            1464796324548573345,
-            12288254271664712404,
-            2322083773701203940,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6476937480135294962,
-            5016501974695983401,
-            10577714827428073861,
-            499965830632685370,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1074517051105912975,
-            9183979781450297889,
-            128902207658276943,
-            1043475360573077562,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10250825602904141724,
-            15306845742179415121,
-            10905276229323632933,
-            1616783401038313764,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7725927652348707556,
-            1486558872917662256,
-            10046764325899457212,
-            770953956327794558,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1041509175952377126,
-            12425310926239128829,
-            15685650372036742631,
-            243066140820629008,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6902581750176830353,
-            11955623366541655106,
-            16469152570346270705,
-            1103098877254217472,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10543953050545373186,
-            516012413509049347,
-            12146868137355568407,
-            2656185829240296561,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17904942112765844407,
-            2896315054962360889,
-            14645243397723341624,
-            1271874740980129137,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11170899018056645239,
-            5071110692090353448,
-            8125744412879966708,
-            2561680840730726495,
-        ]),
-        PhantomData,
-    ),
-];
-const P121: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2088226959803177400,
-            15914062042192652357,
-            16548027596567517620,
-            2522046924298666231,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6706972510142498106,
-            6874979396802953053,
-            15890746811896507233,
-            1728571930920346427,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1721114698830698260,
-            5926451652612864045,
-            5543060260154095767,
-            3116283953716213378,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13849211843491937104,
-            16680886791724758884,
-            2238036023418818517,
-            1236581867243646563,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            884561260695512721,
-            8420414613111777857,
-            11226069069879897995,
-            2120775211982212804,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10449297573798392952,
-            4060831809062076928,
-            7828128496982109850,
-            1861045038456998608,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2505033785971704134,
-            5024004017498889396,
-            15564867372108273834,
-            607790416488913622,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18370038479466891269,
-            12476820231771741689,
-            800285744533282168,
-            2674140733289069589,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1888420150513574239,
-            9464752707688986049,
-            14451455440908417152,
-            2670485188073597201,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14368034873906754791,
-            13478973821759936877,
-            11923324447914459108,
-            207314812265657333,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            35968064823129505,
-            11598564869392503389,
-            13942936517489851881,
-            131871311690904226,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1553646707798467967,
-            2658263183712083251,
-            4465286854461038273,
-            1773014527925462307,
-        ]),
-        PhantomData,
-    ),
-];
-const P122: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1219354398264404559,
-            10680776693907232385,
-            5421493332590563319,
-            1678429157596362959,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            831789729106235268,
-            15933511771091221506,
-            13392934532384776698,
-            2637110870582484792,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11006144382394181585,
-            9141957287159317144,
-            16818956649122382017,
-            3025916480901383747,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2661337270392743805,
-            11753682203679765520,
-            1607664993319027417,
-            3151842554832632402,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            250620821772054497,
-            16448190872581209422,
-            4053000827077340393,
-            1735064125275079472,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7184033019460365139,
-            6167783211019814179,
-            6438553022419150221,
-            512294623554065449,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            698754142648982972,
-            11931674306240838381,
-            415959096067644326,
-            1786639351624373521,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9669147336926088919,
-            7928338592788907516,
-            16051499533558666513,
-            1430229738171030401,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16095376351465524802,
-            6183576695269363133,
-            16011240045254203007,
-            1716057823138083257,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5807341230171278403,
-            15646604913138524952,
-            17625125044883217290,
-            1796999759707575402,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1925178471782602928,
-            4116769135865194775,
-            17925261039701374566,
-            2472758829515039938,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9506170957553207993,
-            6294155739582581754,
-            6260310753600580608,
-            2685055580974454716,
-        ]),
-        PhantomData,
-    ),
-];
-const P123: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11985384012277553429,
-            15544497655061059775,
-            14371791074491801285,
-            1841358365603582896,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2932317122061906098,
-            12770776534265969903,
-            4438004457357215091,
-            2469506883127283150,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10616194992268844640,
-            10121551173811658383,
-            11855793517880900533,
-            1679448082987522410,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2210480419097541722,
-            15793776886379227111,
-            468706240370584886,
-            1468116632469301628,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4721040688030451304,
-            14317967429182517472,
-            4890227828422065656,
-            211741341989026350,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13388425801854605957,
-            4000091223223588643,
-            7531354847592952104,
-            2169374397885524598,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12741530831624564177,
-            2023856745423213792,
-            9906439726826488823,
-            1211905404934404511,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15691305024560878117,
-            8659139861409797187,
-            15467677576525697430,
-            929682330465917557,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12706334179284103635,
-            18315586102713103876,
-            10355485787595866349,
-            1914374973349278860,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13064871580625272358,
-            18083063199381688832,
-            6928783785978421441,
-            2065770801688294757,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8216092652454348918,
-            7413227459766585665,
-            9728773024602063553,
-            1594520000011094446,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9740193399895034967,
-            17721330987071471176,
-            13959567810776368659,
-            2003251096530419298,
-        ]),
-        PhantomData,
-    ),
-];
-const P124: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2627052095341468352,
-            9949133321266115559,
-            3987402617304542932,
-            463962337783684460,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9823729304953236751,
-            10307090292456524260,
-            9533355014165609534,
-            1504124618483935483,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14759764595878080683,
-            14625737245213293860,
-            12488041958026189981,
-            1886903302807919624,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13645533810923716580,
-            2475330606041410825,
-            7344003629318221891,
-            1944603435810596785,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4135506221851814571,
-            17398058548207491515,
-            15374662325785292227,
-            2907175614941962646,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9573013289228132593,
-            13773370256170011548,
-            2668580291065781906,
-            928230373748968916,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3064833013446316211,
-            16250290857969887094,
-            8442642755728597500,
-            3364016535547452580,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1840917868660674919,
-            5454363973062761858,
-            10473395976185868166,
-            2963795856975973284,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11388685963142640589,
-            4281203094510733135,
-            14127219475283405279,
-            1362695275580417031,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12597734456824386419,
-            10615028177313171512,
-            7246002413442902133,
-            897795628874800545,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6872911949084063980,
-            1088358673305342969,
-            1694063018386534359,
-            699485489794845371,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4582094830754648810,
-            16731225853088404258,
-            12623846804966812047,
-            2843046110783878437,
-        ]),
-        PhantomData,
-    ),
-];
-const P125: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15144357222501008100,
-            6486323871926291612,
-            9892303096088029811,
-            2074833145338982337,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3005906502519846484,
-            2096616686802323924,
-            2916192248162509528,
-            608778197444819940,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4766083711011562838,
-            10085400471630877096,
-            17824250577363998142,
-            645389981542514151,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2671467440839802737,
-            11311498554057410140,
-            181276719429642348,
-            17252943707882989,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14284982511645218144,
-            14916824984436597599,
-            16737082285998377459,
-            2852140326904294204,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9980306815358821605,
-            5142085503320977313,
-            5132850588537174537,
-            1160403754803714684,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6246718142360179218,
-            12039076842711991072,
-            15390880643457802316,
-            2054670302090087100,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5702450666753278263,
-            5618393480584933396,
-            7977951175234313713,
-            1962444727200677434,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2722058755943483701,
-            8217782475842461629,
-            8724597740447530861,
-            741738041438604591,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18371636200600982811,
-            1511477635601868511,
-            7920517724782345159,
-            1900153214860692084,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            141283913468075914,
-            6957421386819596696,
-            4420400643910681227,
-            2274864255171343804,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9058020214799567503,
-            14570386341063068547,
-            15154865219617333435,
-            3396719349304655172,
-        ]),
-        PhantomData,
-    ),
-];
-const P126: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3218423773660448010,
-            7956594621426332205,
-            2148632453530702201,
-            1530864573643116125,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17000282143229463544,
-            5787432342537793280,
-            10399167133132724466,
-            2381991394917551777,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17593167999880389914,
-            11104382542762202653,
-            16391972533388287913,
-            3318837779151982007,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1456799205206013399,
-            5815393187195747606,
-            12674605239222436183,
-            1789728117608723164,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7130708715317982307,
-            2062194096529042431,
-            747115625145443187,
-            1683142849224246996,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5525717023420036985,
-            114121900910427986,
-            12115281013876886575,
-            706299686796290145,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16288516325161069129,
-            18029653381348933069,
-            6658896242421295337,
-            3375723742921065328,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7476067561130398241,
-            5218304655723711173,
-            10080114106413964041,
-            2631102710395691587,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12329122097007895451,
-            3802177579638109599,
-            3450060552354887677,
-            3093755607053228435,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2344259911831119062,
-            315663832826408393,
-            11082467693628305079,
-            1460800400699926588,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7299638386857114481,
-            557335000585121087,
-            1121039283230234754,
-            1906961206524320720,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2299382340071521535,
-            11485785902754212794,
-            2014433775686898950,
-            3051861327853266251,
-        ]),
-        PhantomData,
-    ),
-];
-const P127: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5185698740737854124,
-            11968858508103146087,
-            9417956892036942960,
-            1655977128131832418,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10187681231557336199,
-            3769625817604529013,
-            10005753696808263017,
-            2651960141496238584,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5578856426870219655,
-            3031934656088206907,
-            14498843401584258642,
-            1017493548365339888,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10270571263653081261,
-            16381849384949991080,
-            15486419868947477758,
-            1229338437858030353,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7516914888430187377,
-            17644224599081826860,
-            16378796252802019675,
-            779555711982945201,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18335260020447199187,
-            3849499540505451387,
-            8907419854050953046,
-            2533342089818891982,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1363621376125368990,
-            4928904358562031671,
-            6307026399774145870,
-            2967349068471815180,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14000571697791205428,
-            1414112690682000617,
-            3767254963844507388,
-            2966529418259053549,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12794037685810905554,
-            11556826694306403455,
-            5033677573899665378,
-            801346866429853185,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1158862161171100347,
-            9841432107505780533,
-            8015205281167814612,
-            3277941782886374540,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4804060562067165061,
-            7566760010955498604,
-            13234549045878802618,
-            2109743277654763471,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8079415079763469753,
-            6586662048613580982,
-            6512526457524868426,
-            3246223775061109355,
-        ]),
-        PhantomData,
-    ),
-];
-const P128: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17367126948970087817,
-            9872709257499425483,
-            11898105081410027154,
-            3462132673578254264,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7947352513450720335,
-            3702867981791267521,
-            7729239457118064594,
-            2488624033087771348,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3797926119831640428,
-            9181441138807999739,
-            2924381217885881898,
-            168624621039534781,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2586617486621331422,
-            6871681143502844869,
-            14803225627030639618,
-            2589435346394435731,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16349539594169744759,
-            5068675665921118714,
-            1170806238986855491,
-            3189292291545719933,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13268892164982601554,
-            17648096163099676779,
-            7769276488792314238,
-            966077625548306909,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15477537402076685240,
-            2932573176292319387,
-            1333376065544262677,
-            3092899749806778796,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14192305634038123798,
-            958883032169390832,
-            3080260105841554710,
-            1716086700493711103,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9373827918028748905,
-            124714558683695552,
-            10510697961221483690,
-            1838537354669674541,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8735978196179707586,
-            408577366810225967,
-            1294109135881925539,
-            256677781896296431,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2786491803356987105,
-            11774209934951696950,
-            13636662689363690135,
-            2702470648878689726,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14505229885646480394,
-            17526956586549497073,
-            6976179244749938042,
-            1064073364200953792,
-        ]),
-        PhantomData,
-    ),
-];
-const P129: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5187551440397379542,
-            2269348216233020354,
-            9054839282015136132,
-            2650825032934891481,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13477261755278820598,
-            9059362242565678876,
-            223221718874751359,
-            2222157957808627277,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17064229648322209537,
-            16563536749553151248,
-            7276970942655690738,
-            3455807301782500565,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5490624215264543436,
-            14498577245387417736,
-            1594479959839812869,
-            3185579803745000535,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15126043956847910284,
-            9961406312897433004,
-            2071972576796482927,
-            1471153805065539457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17998411548434333907,
-            9740896243722323756,
-            14768020089932258696,
-            680308225319370975,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5082585274963163673,
-            12910837555062777471,
-            9397846772162006503,
-            1234866529792383763,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12668986020848320036,
-            10151299861334938899,
-            15457550645100523787,
-            1536672787304489779,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3067388150576146614,
-            6098962293763723453,
-            17444134979602749068,
-            474037621579037257,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1225946989865066847,
-            7599243091825309964,
-            10682211631029457921,
-            2901456525487052937,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16477227027625928556,
-            10234183444510888640,
-            16302197653210024992,
-            2601666881665349923,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9497364474905636375,
-            3621000384356436049,
-            14862553687932005163,
-            2972984455672925943,
-        ]),
-        PhantomData,
-    ),
-];
-const P1210: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10542828005239870543,
-            8279106558741004438,
-            7680060132917404115,
-            3180784541657195736,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5229015740833137226,
-            8862467675453044206,
-            10997793763664840813,
-            2939260342235806863,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5159825137627397680,
-            10085047543464001732,
-            7966371269657825987,
-            2432282538569812383,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            773699362333381074,
-            11422520100292268512,
-            16873543213013091837,
-            808442070358765517,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17047071783938509588,
-            1536896997929623424,
-            12422175496064393558,
-            1619262121782576034,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10248356263563514896,
-            3696070586243001495,
-            9875443098916322368,
-            2402369179692025558,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            889851492424808443,
-            9185355815302689367,
-            16878650538395142149,
-            1740445388484127148,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6312092569015368547,
-            8589198019597353300,
-            5850655146672037029,
-            2881439734770402799,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4403032660371472735,
-            17592435039462999992,
-            117659774626017326,
-            717265359217887754,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            898589832394048325,
-            16258361843763993621,
-            9836840520142237520,
-            289325547887074226,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4183357093746581211,
-            12912063367874651378,
-            16783165612953652609,
-            2512611734170378296,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9664521886333048935,
-            13000689855006009673,
-            12566079250905647033,
-            2122647135525753294,
-        ]),
-        PhantomData,
-    ),
-];
-const P1211: [Fr; 12] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18086852604544126615,
-            14174304549218018876,
-            13408748386687468957,
-            525156577372591380,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2609216280309908204,
-            17918079001840978053,
-            809424430982623215,
-            265915891550881358,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8784532418533924277,
-            9858421984214242797,
-            13423314133326701405,
-            1828760918406339578,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2194067517705278607,
-            6273053095546850464,
-            14839859945304089962,
-            2679895327704644855,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7368913465801716739,
-            17424537334810777463,
-            18215295780135573987,
-            2150526941118176285,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16475491649379473392,
-            9275471973702617416,
-            8277255940864500326,
-            294997966484258805,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1693912442160178667,
-            16478729860737731345,
-            4702222053837093609,
-            1321899940392967178,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18104368897385257420,
-            10791792896634467522,
-            10798802960552583633,
-            357837344491748753,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10087871448774262489,
-            4059783985457163163,
-            7321681109983014834,
-            2036720569048471465,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14500630422508760024,
-            3703906075917451441,
-            13702092941000033886,
-            2858962892670873247,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2332528048682582089,
-            15550178899901806083,
-            4690876617263110629,
-            1480149068338668705,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1096159887917878188,
-            17145536182556181665,
-            9955666115312533638,
-            187370932828555777,
-        ]),
-        PhantomData,
-    ),
-];
-const P12: [&[Fr]; 12] = [
-    &P120, &P121, &P122, &P123, &P124, &P125, &P126, &P127, &P128, &P129, &P1210, &P1211,
-];
-const P130: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2742448559636872206,
-            7595408910944052159,
-            14934450654995516201,
-            3083309976703171066,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7835859415718817138,
-            2867009903201882509,
-            1512664531416569613,
-            168857625145619995,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10525829506883045819,
-            9267840416948928474,
-            15211462772186846742,
-            2815751377941484503,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2288825295799696870,
-            17189420472816044039,
-            14764817152458849651,
-            284004311544985928,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3654989584592453327,
-            3591563618656781165,
-            7145569080470388506,
-            525010352132305457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10388978399573703805,
-            5854651798545884208,
-            8708283691311750285,
-            3105274301494543207,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2031701126482703011,
-            594176166402520828,
-            6423468716314314414,
-            2714486154647511720,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16403947106044578476,
-            12920782446698178399,
-            8532938514917403162,
-            2672328346836219556,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5700670862411369497,
-            783551888664456754,
-            1198735207499355435,
-            506244156844832152,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4571425493396128195,
-            16229085202191103032,
-            13933185422604032449,
-            2503529425534445174,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4191454639622821551,
-            3707184790276644258,
-            2107735257622074564,
-            1801960960413029925,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3737239142252770717,
-            2698050440718189568,
-            5241699384055916194,
-            3205570845735385892,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9644838607791661055,
-            15481672930399379443,
-            12977873442744550265,
-            974080304939665666,
-        ]),
-        PhantomData,
-    ),
-];
-const P131: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3426007190223872498,
-            15309555839232697517,
-            12371107601806524899,
-            1165510373458662004,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15960827775428944739,
-            14829493855394742959,
-            9923814252259009563,
-            2512565094595892858,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8024545101328837185,
-            10574334790327989091,
-            11338762409878452753,
-            3033331706206354654,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12290583345247609364,
-            10400553426909459951,
-            6745880010374600457,
-            209685229032090352,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4092246761360233738,
-            16046683614462615730,
-            18034982118878551169,
-            464821540908435266,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12014108462322314463,
-            13071704280453197595,
-            5216729271680090343,
-            311695687065662733,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13271813088860065850,
-            9307430759574910930,
-            14647104630643420312,
-            1428676055196022013,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11979625606990202694,
-            16493469062950349229,
-            6421795927172273595,
-            2857971847241386567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15933218370124978446,
-            10639277163114515875,
-            12338748975234342102,
-            2019603703067620154,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3644095759203456977,
-            17444195221430539980,
-            18439466206138606020,
-            980201274735584445,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4567838642554075280,
-            5382224582887984259,
-            10155199579252463847,
-            1109483763439482968,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15763220555473972793,
-            15036005107095146256,
-            103994015230344907,
-            1880238486882552991,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10885095313971734539,
-            2410154386288751630,
-            10829455136059584806,
-            128518311325864614,
-        ]),
-        PhantomData,
-    ),
-];
-const P132: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14181680832149344186,
-            1555103600229278137,
-            12428295873403488971,
-            229226946326984815,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16308902037066020014,
-            1096030357452824201,
-            16855359038383689268,
-            1439081483685076290,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10572970591668628600,
-            2189242455478434979,
-            4853045764281993543,
-            2935157789273623067,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11312085942577685292,
-            4148891416856700977,
-            2323304996769164927,
-            2321146562487001631,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8671543828896906935,
-            18386918323081566085,
-            8498465785057109077,
-            3223454889256092075,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3644997833871068088,
-            4895843536592649026,
-            2311431611962656299,
-            815407875521638049,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6649595471778596306,
-            12382104365045283887,
-            4947149699422234715,
-            960672889870650187,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13253401315848605969,
-            3503072122897999996,
-            8532422771978098686,
-            188630119237986304,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11728757069991497300,
-            16224759260992079293,
-            12719264081224214069,
-            2057702772333028614,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6317962147252722564,
-            17389335082298275035,
-            10514723343666152414,
-            1839123903715470481,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2844991582509521772,
-            1771402637826649118,
-            4849064540134590636,
-            3473918021182658956,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9978786050550484699,
-            9788827251947522502,
-            12140928582751792241,
-            906546820654254439,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14214796062501107588,
-            16395099020819859176,
-            3406390431921786052,
-            1656991487944683692,
-        ]),
-        PhantomData,
-    ),
-];
-const P133: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14903753866014075473,
-            5960833768594880504,
-            9568518241557313869,
-            1531484901387058682,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10708951895419919221,
-            12764771954279832635,
-            16290209707776635633,
-            2368960084876002580,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2048565327499014256,
-            16445951297104533865,
-            18155443445546556087,
-            1284499622471646763,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3770732850529143238,
-            3664378581431904595,
-            12727118786715409409,
-            2820449970125531950,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12562654173085362261,
-            12913347435021089804,
-            7323081011339789179,
-            1175702382649767204,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13469892564004995537,
-            12168256784399252689,
-            18252106078169096206,
-            3046774524263202736,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9309826859037423429,
-            11874520444499591860,
-            507865007992670115,
-            786844712057281908,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14505350113486997949,
-            2763683655826564612,
-            2628675642312472408,
-            730550404693331125,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3702862325618600828,
-            11230400157244726965,
-            15657504162367558637,
-            2407326628013227908,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7872674234883201676,
-            15433638696908458243,
-            12408753230849080955,
-            2111547494657723250,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8690556844566217539,
-            10057984467656556887,
-            12605535741608194560,
-            379957914231876461,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11680160618331225957,
-            10669119532723499089,
-            3913681487012660346,
-            926870683348506216,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8191749454805012886,
-            3604126336258523305,
-            10276694774506154183,
-            2657442400538794356,
-        ]),
-        PhantomData,
-    ),
-];
-const P134: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4500667523834094669,
-            7281946579724174179,
-            17824919319267382895,
-            30769782997501522,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10939244706066864539,
-            6244788344167495567,
-            8584902185754602821,
-            1411414294409431410,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12478594439432589779,
-            9575437115204335567,
-            17145989365001893023,
-            59876537390073008,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3817746647242851528,
-            4513977848420571553,
-            4210217296916836323,
-            1161262374372568622,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16128664184853028412,
-            7795783000390916824,
-            2173836178824692863,
-            1026424452156513857,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13960455920379553239,
-            14967124104706452227,
-            9958267611256523737,
-            1045766844142152431,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12373454201987441978,
-            9418812055216361679,
-            6072006599895595795,
-            676912487353631865,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1910915110007357485,
-            3689192000490290557,
-            10213671386566673802,
-            3432106365762165044,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6620429809450446439,
-            10163595273706831743,
-            7321768093159424877,
-            248611319628885124,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11340621005982102789,
-            11409589296117074469,
-            4175003808604798536,
-            722781807116629615,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12773481119637449508,
-            1721737492287297324,
-            18153642434077250750,
-            1827625002917334746,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15844413862907774953,
-            1943855937717731623,
-            6983295971883125366,
-            2159800762122223001,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13045296223461506464,
-            13439355749863176663,
-            14327737666262217867,
-            1542567625401248164,
-        ]),
-        PhantomData,
-    ),
-];
-const P135: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1774079332271141914,
-            2431568189127927674,
-            4468916420914885772,
-            2237630400216455013,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8590562049626744379,
-            764732827188083550,
-            10832221981591045545,
-            3110022929333322332,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13817639568248974915,
-            8394154286709839496,
-            3379547519593487349,
-            721380771606448792,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6336463404514968157,
-            3309544127079230413,
-            3630260777191950240,
-            2885342629573537016,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10761497355715663848,
-            5062038346250508954,
-            10537228870573190617,
-            293394239970572100,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17590910253638928326,
-            16562604224801072563,
-            9914230555240315536,
-            3017150098586097012,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14961395569408733836,
-            6432923936875396960,
-            13365266575399516643,
-            740896389699022393,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11355446899485270805,
-            9731359248840702214,
-            16756168557271929531,
-            777185964723314774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16501422090726510116,
-            14548256186554267245,
-            10614053876467859992,
-            2173039367013135308,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1820663414658111797,
-            7483695139549494326,
-            11396198963241129948,
-            3347769766647772976,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1845691921138339867,
-            4579212550072499290,
-            17791635775848899987,
-            953714285573709678,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16689202439243232017,
-            97834751454024431,
-            10164588989912337765,
-            2390284961588507593,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9115315553591863112,
-            3645202663525452235,
-            3790395615530428977,
-            1795952183679314354,
-        ]),
-        PhantomData,
-    ),
-];
-const P136: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15998680308753619123,
-            4324394236339621231,
-            108066261842114206,
-            1967280461866098706,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9356403897846413404,
-            14688407217480390804,
-            11683086682229750840,
-            2123283970065515016,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11207946279363419916,
-            3766369487496333532,
-            2609163906504948183,
-            2111508443813005125,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13949688566331839183,
-            16293737335929746879,
-            9639586021432646275,
-            3051040809807362675,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5450055628299079014,
-            18111244020265301119,
-            16811103908622626591,
-            2165042673924800148,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            606379089442672745,
-            177016855069148780,
-            10625981853482603454,
-            872390555589735776,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10430064922103970138,
-            6856836042285527601,
-            5363479029757459786,
-            1249538713804774813,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6806067877698737130,
-            5503535567082473138,
-            15182512850038500350,
-            2860547326731507739,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1508724565172463759,
-            9006238609685207551,
-            17378993200357373401,
-            784977896898748140,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6514322631745204800,
-            4220658777313359928,
-            5509855772424002560,
-            1715912296565908109,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3832822742479338565,
-            14922826821002520810,
-            6969158947584928345,
-            247469703602841213,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3535717846526928361,
-            15147604865575656630,
-            7110971327859776018,
-            662824844870817084,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9004023851425446538,
-            2955818953433897945,
-            12184337387130887083,
-            3127292389595776850,
-        ]),
-        PhantomData,
-    ),
-];
-const P137: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9398534831868360996,
-            1637268933425861982,
-            16924682975611432461,
-            2558724962186311248,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5461781823888794045,
-            17683769480648707719,
-            14799901602136172700,
-            3082172792399285003,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1907480943774822375,
-            2834538152839456208,
-            6343766874395565105,
-            2025190295973020283,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7024822623041902328,
-            5839313940630542543,
-            8569735135254022837,
-            10847056766050573,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3578057500516136400,
-            11440960040614720331,
-            17643963254679520868,
-            684180735527152831,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6986006237745043385,
-            2436457365063193949,
-            5488623677965013041,
-            2472918075641659113,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9874916784762230753,
-            5769741198306353632,
-            1754001924220819461,
-            2839878496605690727,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5139022213572111469,
-            3856572639677121447,
-            12707781385888726094,
-            1496744641604829788,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15583441992700464015,
-            6194831116934123590,
-            8020322406219907184,
-            2671123123647201043,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5767668505171813851,
-            1082630983247794380,
-            17636111373741397208,
-            622525317409981300,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14993590345951104601,
-            5015273230808471352,
-            11299939293351014140,
-            1197026605172411822,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            743476796032907545,
-            4223566923913834429,
-            8730874795987631077,
-            1664539155085532720,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3640395552759905055,
-            5689204078141311707,
-            1515329661645754229,
-            1603388863831496085,
-        ]),
-        PhantomData,
-    ),
-];
-const P138: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5059453832089784690,
-            16355827326468214812,
-            8885167606740454514,
-            2273669884490535298,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2015723722748986357,
-            10837343465313030369,
-            295480848642911231,
-            1546801617908949255,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16629411263742949879,
-            14377761739244620700,
-            6624155207954847530,
-            286173836167279235,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4054503114581125808,
-            3003022135437599602,
-            8076237221483571165,
-            47047886840952519,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7138151680524495446,
-            15948477765905398997,
-            17547212518492057537,
-            62497884856832355,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9500418935267492103,
-            11254274753885831638,
-            10776155124173578824,
-            1519969241269786347,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12892909252971773776,
-            4674887119417886653,
-            3520695122872239377,
-            2589747508791534352,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10912686937941448937,
-            10517292480366424883,
-            17282100172710047137,
-            1495743221759090359,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9953066929423245159,
-            5785762722593642723,
-            6199119847623008158,
-            855819429361416322,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1171043641538396905,
-            15871080825225108259,
-            8450885549150935517,
-            2153398498268172765,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6224893199358832813,
-            2881820157186476693,
-            465238306469959299,
-            1047877233654522663,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17107238143847813020,
-            17724400080323437968,
-            17391988426149942226,
-            253681354213517910,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10526323098067628873,
-            18301082741852553229,
-            14461490287534577131,
-            298413837600383570,
-        ]),
-        PhantomData,
-    ),
-];
-const P139: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3667263075650855579,
-            16917631444650893344,
-            9091329822772443199,
-            2163629193943377244,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3436594626735686895,
-            4496709697364685424,
-            6736235271296482852,
-            1172646134577608254,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12756183399259134397,
-            4380027900598549519,
-            9700382888577805008,
-            2093340735966218790,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14875893777050030059,
-            896322776371551260,
-            4550559516061818648,
-            2655068124045914614,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15992370935283151684,
-            6892030662281177977,
-            6511833392194917842,
-            2387481817906829207,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8265170057888397561,
-            7524924426787547691,
-            1314128651124376663,
-            1308826970806316192,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8989350693894238498,
-            8952562461977230102,
-            8114369727758106284,
-            1700965443992273385,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6202835960675893838,
-            8434139360061396073,
-            16427050739387892650,
-            898406915695076142,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4400582615801613782,
-            10754920304376897060,
-            3810364408636121799,
-            1204680936940586677,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10057177105540081052,
-            11986092756132753708,
-            2571711197093486935,
-            1785326527464415650,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            927683319670618887,
-            5388192603307863016,
-            17000514125263491252,
-            2599128717288612859,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9306988522218380921,
-            5656785972625716758,
-            14464452505018762869,
-            1071732431749391432,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15694063821303128815,
-            14306279447789346876,
-            9869484836126726404,
-            2930590040775720715,
-        ]),
-        PhantomData,
-    ),
-];
-const P1310: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            369208238599038861,
-            17056362539322206247,
-            5416553114144680712,
-            2867820401817681487,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10489858746739100121,
-            14549611878760141581,
-            13636929664397014986,
-            1949131401612564820,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2637107671896633574,
-            780391955684797417,
-            7390303063365690855,
-            3211427067910539667,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7992191482735025069,
-            10624858007084789869,
-            14618191643772993378,
-            2932311749430939079,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10097398609941288567,
-            5790189315405475769,
-            26991138585514320,
-            2472803743530834449,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16321090836283093141,
-            4182724897676481406,
-            4825590271014529219,
-            370297295843125638,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7269932756653093654,
-            9001119366860972585,
-            10752838062315587847,
-            3388663873455103495,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7701014457366636783,
-            15211538072389679356,
-            8652977550835477788,
-            3215245584189674744,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11718229987287644900,
-            13078473985171414272,
-            10651069286684483624,
-            1539829803182376887,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12507392564535088990,
-            9136796747872326478,
-            17524276262192362498,
-            2803484482164787496,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9563716683255882954,
-            15655620739953317320,
-            14855761495495607575,
-            744892649762088097,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17506175079186736964,
-            3418332615134054573,
-            18047817703400899171,
-            2386087204742024263,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18249305634630328222,
-            1199927722913048710,
-            15503282992811749544,
-            2317970026809797221,
-        ]),
-        PhantomData,
-    ),
-];
-const P1311: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10851446933739997357,
-            6384797561070854624,
-            12964772522243333937,
-            51993587930106222,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10643160537357583224,
-            7895412466448410534,
-            15232295171682092167,
-            340569189446117336,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6215092811446279425,
-            12606529056755435724,
-            12114845562965344673,
-            3159605237151535714,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14893416520272391798,
-            1735169194367700595,
-            6547120192384290657,
-            2723021410732304535,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            197443063074529769,
-            6302513333215440733,
-            2284910326495715258,
-            245100063289133256,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17059897869869092889,
-            10661707200102056948,
-            13445273591652979962,
-            3202416012733458519,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            422244427734404299,
-            7964846740561973436,
-            10762937449194074051,
-            3129411724049314958,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1450338404344951078,
-            7980881091411408304,
-            7729910399798649462,
-            1767755089041022051,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1753695842743583963,
-            11037293438056206511,
-            318938632482746705,
-            2806540054688237796,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9907650012228457505,
-            10087086130044083277,
-            4396079595581038694,
-            2652302656424687116,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11203955649971234588,
-            18185091711827471906,
-            12022846011161622169,
-            1546238519035023932,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15982391268498098736,
-            16533796787237022462,
-            10736415344107724063,
-            2250285701382231766,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3605742157749319866,
-            1961784344102262203,
-            1772828255459381396,
-            792288063554601507,
-        ]),
-        PhantomData,
-    ),
-];
-const P1312: [Fr; 13] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18390167422166238926,
-            2215670558261605118,
-            2484516152997349151,
-            1650370772237204721,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17777832121764343782,
-            13049241426927936949,
-            6096127125075338436,
-            1467985047753405561,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18301276286541198725,
-            6793625436963542109,
-            2689742099821619779,
-            991929740594241931,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8388889899614054226,
-            161269189549062833,
-            13171521697502183253,
-            2370318914986603340,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14269987328799523739,
-            7516798748517426116,
-            12578038982548559837,
-            2051382818686593508,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5589503809516742254,
-            10331892534631538609,
-            2798065550033918659,
-            286320325745008555,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6055169902075503522,
-            11661718356165005086,
-            7916576395868523348,
-            2806534302561911221,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16223584262952071664,
-            6286066716330992296,
-            17559631773167560032,
-            112394175539105372,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7879696004032003021,
-            13543149694734082657,
-            13047060032146905478,
-            2497618417428110738,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2537637889021052324,
-            13496463246440788222,
-            1186215820756774060,
-            3237038930812310341,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3725982793099156061,
-            574495903313503422,
-            4587930546025744847,
-            511768956030751712,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            747669643305256533,
-            18232643824708622195,
-            9232929987880796016,
-            2496769073336880411,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3615607305983023314,
-            13648043367049551079,
-            7504712273419804265,
-            1307968981763298025,
-        ]),
-        PhantomData,
-    ),
-];
-const P13: [&[Fr]; 13] = [
-    &P130, &P131, &P132, &P133, &P134, &P135, &P136, &P137, &P138, &P139, &P1310, &P1311, &P1312,
-];
-const P140: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15723090063932111907,
-            8944865148737554906,
-            13166907120804715941,
-            1923503388791086008,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10933682637932035323,
-            880190230814486144,
-            10128588009204632224,
-            1939208722241232314,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12332418879709655807,
-            16771668416875443452,
-            17378130369497050513,
-            358380040659269684,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4778281299345991651,
-            13870995240888581319,
-            3254975961344351010,
-            3485176366056001667,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15614754943856091201,
-            13894369347832223670,
-            12118722008415878772,
-            1273788755015616591,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13814281266109513714,
-            10624678808050935435,
-            3616843849196778975,
-            2560520657602749485,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4656093393297505546,
-            10717419325828845242,
-            10058743980185016451,
-            2041270622567992011,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4979994028533070979,
-            18135399371201057854,
-            441020586221317272,
-            2954457774877338333,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5881883682816782937,
-            13061492540908623252,
-            7650919813432458955,
-            1321692255416678298,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7312998149594064718,
-            17088222903301231362,
-            2173334430797159536,
-            3306794342185438357,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2387541582429503124,
-            5266263931927337305,
-            17531429783914482283,
-            1414454911311989049,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2270640115907303426,
-            7751072691396126800,
-            4276106736843072462,
-            2893304196318931291,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4299005151830552514,
-            16454840449144049691,
-            1286547946679765696,
-            774948327424575000,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4861678554944664761,
-            14615617626564130601,
-            1810549819992516306,
-            1015514101768811014,
-        ]),
-        PhantomData,
-    ),
-];
-const P141: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7188800455254685422,
-            4898751691846246812,
-            11734113685820355056,
-            1239830117564842994,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16521297316251515981,
-            7971856085896758021,
-            10485203148662232587,
-            1313197821371327482,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14958180140755652337,
-            9039233964643406755,
-            13627521464002164880,
-            1205491093631811493,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14685129987492459308,
-            14024375578416108000,
-            2540706756129985442,
-            759957374686539219,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13689974213629217298,
-            1930034557567860805,
-            12073899009616396042,
-            90864235511721736,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9515795168986741782,
-            9996850674243176068,
-            9373162628885000332,
-            1602476846589898096,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13183409686057428173,
-            9019832501683030250,
-            5175587368233170829,
-            2045211630343619832,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4335265454130259933,
-            4011634339755833465,
-            7871326463805461229,
-            1322802669974200391,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            784470687352157797,
-            10836491417341673993,
-            5678584681768354972,
-            1435856591285482522,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8491985891162546909,
-            16628618011194925502,
-            1172243592857960982,
-            327120366360348380,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5598085209006940879,
-            17521045673663597403,
-            14547770006876637232,
-            2452138936333679064,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1927731366710816186,
-            14705746957898709956,
-            12370273730529445266,
-            2259813348442905906,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16411916004632151116,
-            8210562619074225098,
-            15484638909380679778,
-            2421499108088803340,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17029180136928546091,
-            7636784768819543245,
-            15344220734073850262,
-            1627287021667804040,
-        ]),
-        PhantomData,
-    ),
-];
-const P142: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16877989963390755027,
-            18029051336705681604,
-            1183199064106197808,
-            168261955860687247,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11456080797862073203,
-            16227616135975010119,
-            11712480239109290200,
-            1872910813677999002,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3782083787346648444,
-            17979808367327784205,
-            7654396111879110190,
-            2401622862472697184,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15999380043037325723,
-            4759103475821347778,
-            7287177313555974360,
-            1507419819005897553,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12107713079475702980,
-            18030506775156442409,
-            16230906771484268616,
-            618220311719202120,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12349747271515144159,
-            4401185966010868898,
-            17556110147668509058,
-            2565313119870834097,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17066625596968959666,
-            6330146921845723497,
-            3695188513038422285,
-            845749040266168453,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10345113007204892001,
-            14822693482435340129,
-            6509015351060320962,
-            2850541470153023362,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15021572975369090751,
-            11516724045465816574,
-            9407453154105079276,
-            3030206715880426162,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12599650868613531382,
-            2393070488624973138,
-            13171349697270310341,
-            866933250970299755,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9389007974021195631,
-            6599071318930392594,
-            2633989658878006884,
-            438000417220491845,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11182528028329828261,
-            3120559816312517197,
-            10483334522485789763,
-            2826770926219408266,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5174088538134909738,
-            8658163746492567411,
-            787420560091399381,
-            3037907375515739695,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2373050601979123384,
-            14595360152310869176,
-            10873870968016406344,
-            1707118585905961579,
-        ]),
-        PhantomData,
-    ),
-];
-const P143: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10799236445289359463,
-            4472415830677530340,
-            5490304961576141579,
-            522270901332626078,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            308069524607361097,
-            10822111890725931039,
-            13461135595339993946,
-            1650448209578909799,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15169489262213757905,
-            8016660931131519642,
-            7227882293686534906,
-            2765058859421942670,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12662943815999898526,
-            12629124851377456297,
-            16492499419607923935,
-            3043497714845444456,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17648876548759965453,
-            3572859318387204157,
-            375200875044612170,
-            987650258337151032,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14275020034184876513,
-            2555277348874437563,
-            5482774864701227913,
-            831145605448578036,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2521821001784331672,
-            17061530901250879912,
-            2468519455704053882,
-            562441442318684245,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7358709725316089533,
-            12323114033687725117,
-            12685436327011781027,
-            3255351315487859674,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6406168148102984933,
-            8427649150407254175,
-            18321559160034178033,
-            1257747937540554451,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18056219726686020892,
-            17125110250819414201,
-            8937772379703737915,
-            908811465030899104,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9845735254598491548,
-            1054856075690036730,
-            1687706101761016045,
-            1471526770470478949,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11669062549258972365,
-            3719137350050981929,
-            14651712604692109428,
-            2306626997645300086,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6523825632936902772,
-            880655892720801729,
-            13943709786395922856,
-            94495541197039849,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8836332856707426360,
-            16788812025978701754,
-            7450700735015211707,
-            2134050458715100337,
-        ]),
-        PhantomData,
-    ),
-];
-const P144: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            810403182351634932,
-            17677013873692002550,
-            14000751300024527970,
-            2534362870039073829,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17044365655645777888,
-            4747652947402056867,
-            669981441513011429,
-            3443262830459418458,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4233014336694650879,
-            11627578391963518279,
-            9119962865375635142,
-            3394362207574909525,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16678623852586166061,
-            1681739970870291288,
-            16894383671752523124,
-            1425311400217340921,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7337765998220057724,
-            6602557050241512366,
-            8147033271074110872,
-            1618190055483147766,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16872813281169832712,
-            2401513654767115847,
-            5532201839326564759,
-            3226407096965851711,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14379391105258255753,
-            16333824531692717420,
-            8377475295476076153,
-            2832778086445878187,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7614333199490682721,
-            3061193856049874883,
-            14516747163456970203,
-            2465809785626177720,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15162135366581708940,
-            16314064733533569914,
-            18396361481162865086,
-            2996337731069135485,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6370151260811716958,
-            11016331728699901755,
-            1024301344270049958,
-            3258579621837075866,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3821072319192353690,
-            5068794533718074696,
-            5360904204197753070,
-            3229661272196573931,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11202520516274792870,
-            7815161960040739925,
-            10133895587193258281,
-            2103807193616809110,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13952821802673997321,
-            3030298069176556805,
-            8099762295040820609,
-            585138105534338537,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5720069782092919424,
-            18020763128163236626,
-            6044503009502470061,
-            234129415457350431,
-        ]),
-        PhantomData,
-    ),
-];
-const P145: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4661647807454158322,
-            18299519382265699018,
-            12024999099561257234,
-            259453319635904373,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18152077205097492287,
-            10199798481958384141,
-            6264023505467557279,
-            2101688344734882546,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6982237803959390285,
-            6681730663980959693,
-            9969544848293035266,
-            1735150110371369405,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11004267240350467567,
-            13744755681795602458,
-            7414315102293279274,
-            528162324549890114,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8380114233417783945,
-            262929594507912705,
-            12172656208651053295,
-            875978655524333105,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8165660868239595588,
-            7415814005313346729,
-            17042715394652561120,
-            174166356071266501,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3693273388518119304,
-            11599621469324495643,
-            14646412749181823311,
-            3144307106899101294,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13961603576384150181,
-            3557137718943695369,
-            10228229912573016501,
-            3437607418815497483,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16074102131329222757,
-            13863316907460718667,
-            2182814014079972884,
-            1349909599583582757,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16739461597269554404,
-            3939990107202362618,
-            6440783766416576978,
-            3341476223412210195,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15186168256785801246,
-            12106334305196788742,
-            5275075166729763935,
-            2257400504898840588,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16005511940812789864,
-            4167195849594072529,
-            1079777391143097952,
-            1409639781373862626,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4104603930932813663,
-            6992763873638933446,
-            11271011956955572262,
-            2826023589767477539,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10055166402212987539,
-            16584427185855174094,
-            10339471068772117000,
-            2759462912179651586,
-        ]),
-        PhantomData,
-    ),
-];
-const P146: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17695380950040657318,
-            9817515497410599850,
-            978802562137073604,
-            1163364227073845424,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            722560653989447841,
-            7090994082285092976,
-            4032328704836476535,
-            358131491980549624,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9546172108378949033,
-            17969151389458758560,
-            7667998880349972104,
-            1456099385631196488,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4494960132615769710,
-            16959353108288761143,
-            1106598646046575004,
-            303752072814648465,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8408269489263658382,
-            15056587357495107142,
-            13837801343604496022,
-            1076689404332374685,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1986744789086384058,
-            3675334948123726870,
-            4851828163904186753,
-            2789004181321941474,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15149385418009941252,
-            76094960084776263,
-            7846750763099450021,
-            2619827118715896269,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3581575553762153098,
-            314650020807174411,
-            10600181600678569289,
-            2296118304831377051,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8222897373955707600,
-            13849988376108182823,
-            2612594575730097769,
-            658005016548114675,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10298670035951514326,
-            3020459108602770347,
-            6416169298966692731,
-            3249239243255135431,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11468980323131696397,
-            1752044304188741346,
-            16853204287526905042,
-            2530608258435687625,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14583388296158985938,
-            17859131027768105300,
-            13568234619461320610,
-            2035826097055672495,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16227959758034135971,
-            3909172918511542614,
-            1486258068580358824,
-            1748914876493738903,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4188483176445224802,
-            15613032110224712970,
-            8710094111659041745,
-            1064172651679491439,
-        ]),
-        PhantomData,
-    ),
-];
-const P147: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7503323828501680077,
-            991405922480170474,
-            14320640983950263873,
-            625806564775220823,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            585448441581847727,
-            14724343776748438892,
-            2682880806260044660,
-            594931367528063913,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12277323314800992394,
-            2505082872286376215,
-            17628253919203250500,
-            2845275061885595556,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8904673243158841215,
-            18047595622911813940,
-            2602989514205269276,
-            1618217759037385853,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14028454036909464236,
-            17095127244212495075,
-            11104696177352770475,
-            580052792731278360,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12852186484351838153,
-            9440683328280980398,
-            4347786324616546904,
-            2868241098698431951,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7169830041799052151,
-            13068273508484396196,
-            17996538122716167738,
-            851515150106004497,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11146013270280821604,
-            16975105149448508096,
-            16824114752425729198,
-            827026406705623082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15730905595729041128,
-            3489051169140362735,
-            1999225671283089743,
-            1719689201943125639,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15305554950178178393,
-            7120234352328743834,
-            9885239063968558942,
-            3351556988269745307,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14033965061516351335,
-            16792783860904546277,
-            12093571534636865875,
-            2856844357323241646,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17836780846859360473,
-            18061589944784906442,
-            10092764872473312060,
-            3156946510676164461,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17791370019000945457,
-            2969296727581160082,
-            2629061591958861645,
-            609288561381395091,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4709372821100776292,
-            13458077702523074692,
-            1957794026157399329,
-            3461839518726604869,
-        ]),
-        PhantomData,
-    ),
-];
-const P148: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17430530522113526419,
-            4302663412462855270,
-            4203788866906089075,
-            1336830507964808459,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14546275124826389487,
-            6835282421408244661,
-            5461959587195704697,
-            2710157181667038904,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6208894844583567820,
-            7008973778876947005,
-            5795629689275011032,
-            1062953983126968347,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4481408258761947657,
-            11998833446450421381,
-            15103210091127546610,
-            3278960341471856741,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            679218465687711711,
-            12178711615076203235,
-            4908620740928812909,
-            1240548480879932117,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8043003361597835126,
-            2132558736977349326,
-            10816819212720820233,
-            74277516005295173,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12094612114700285111,
-            3849184578590887077,
-            2465903376308603922,
-            259017406694684293,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3761756962485547275,
-            9289787127441413895,
-            12028070637564799410,
-            2269041336219130144,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5700320489154119500,
-            12140529925292038137,
-            15289059606996822311,
-            3121558305930155936,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6423850197202679526,
-            17105304565418153130,
-            13973880246985502744,
-            2929717097431778720,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16582134114718435010,
-            13571256937128601822,
-            3040388273456524329,
-            967870012051369050,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8239192969810349374,
-            3111837064993907907,
-            13140934267297860142,
-            3381193953392124168,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7661459842608848553,
-            2544329403683505384,
-            688350355095586069,
-            1584460482127925794,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6628894681451410150,
-            3353905421952369239,
-            2977918432971747343,
-            3409210501736983277,
-        ]),
-        PhantomData,
-    ),
-];
-const P149: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7452627679438664116,
-            10165987087490991726,
-            14123607720567944417,
-            2624641383555210522,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3648743092513906158,
-            553614711435487487,
-            1245111703456203188,
-            98200570854730659,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17578835816026170648,
-            189684801319585853,
-            15077910142747776204,
-            2285890554914633864,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5325182067398702015,
-            3317357193946367507,
-            5022301081444737366,
-            845518087034408910,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5251122442836388647,
-            3055937337527474090,
-            9366099507733012846,
-            290705086868136362,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3795047878294478350,
-            10616869923493810007,
-            3109126203148315841,
-            2206892685682214964,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6995867592715771472,
-            1747133455927260542,
-            12720992794465017930,
-            1240185305283661727,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10118154479157713336,
-            13192393657374947080,
-            11556936334040014659,
-            3022407531376905221,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11671235486310448990,
-            4544587984064037657,
-            9281277646108039111,
-            3027020527953932507,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7846825819753287970,
-            3475131788628922450,
-            10617516245737335520,
-            2942470105533424089,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8986031408167485949,
-            5755885119899664364,
-            3542552869174569377,
-            456138974351878874,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1880183493763926627,
-            3749144410428575289,
-            3173777224987608111,
-            631397035896845967,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4868272313862374090,
-            3079594380310095979,
-            9525343179355416933,
-            2521032640822451457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16903998633890615285,
-            618596772376813181,
-            5718853340428598391,
-            117070388468551145,
-        ]),
-        PhantomData,
-    ),
-];
-const P1410: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3869962819292722771,
-            1802440276688195700,
-            11778875007285867767,
-            1997269535327083322,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13331859566899504607,
-            11876174026417626096,
-            13260577143634331467,
-            15847818881054220,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8046843155122721816,
-            16737478848887919544,
-            12539214620550382521,
-            147956317243589377,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1758876487481382471,
-            7299010507988641836,
-            10760410799107560279,
-            2969116720814350758,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15551424407650855712,
-            4358900675937276607,
-            5514657436024593970,
-            2863475770766207331,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10393306609598471331,
-            15945083514881309259,
-            6805938100602550236,
-            3378525520917250492,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4357275787800340509,
-            7807523145543226999,
-            12233000055375989411,
-            2247204025803162390,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4924223384503200698,
-            9760190897128633645,
-            15062267019124916247,
-            1729421101892987169,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8999116794208845566,
-            15119896277635522672,
-            17160643887339143634,
-            213750211786381548,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17084662483246714735,
-            587777155104970936,
-            12804074932373625867,
-            2204627106836829876,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            358394984385897993,
-            109516425486672547,
-            13013057224364538488,
-            946022642760734137,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6141205302422554749,
-            6359168485194142874,
-            13805110220075678632,
-            735695482984192828,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5338304229237277138,
-            10037412366410802230,
-            10739378554278580148,
-            2343620871447695085,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16011163985516105721,
-            10239371991909490244,
-            8339912173266630009,
-            3421409106518903355,
-        ]),
-        PhantomData,
-    ),
-];
-const P1411: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4801551906735980375,
-            16866883861152508384,
-            5170009964177810193,
-            2201166284297156984,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10195433662408637854,
-            7096626581105300395,
-            4651647274352025048,
-            1666239760710569652,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15506212622132943543,
-            16085356000070774635,
-            15995955405453147226,
-            242228075730392472,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7755227928553350246,
-            7521023357927035390,
-            16736081839589480225,
-            1021693488605316166,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13857479752514444344,
-            7546897551788940405,
-            8713789564401241729,
-            1494052932652563258,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1455215038359873828,
-            14711425230235309743,
-            12569616473837105489,
-            1728131415636875136,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10581686933636877785,
-            15550071978262662430,
-            9745119309782107080,
-            2134537943043644729,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17448231860907173276,
-            1730838312938991598,
-            15502144568366787873,
-            2036958616982525844,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14539539770192177696,
-            3092898581282678563,
-            8855143805951412149,
-            1202152162342646594,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            840969084645941239,
-            5013073964496126841,
-            638155188262142493,
-            2125778981668575365,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            424950901943813539,
-            3459522757097953046,
-            547509867729105744,
-            806003791501452719,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13566073123791226706,
-            17435748039814807625,
-            8471387514828820042,
-            2138690253836028145,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1432332224060960037,
-            16797874106513225274,
-            1488331771960064083,
-            2673982237319637381,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1560494551868114445,
-            4771608964434660720,
-            698602962161454718,
-            2491816845663081076,
-        ]),
-        PhantomData,
-    ),
-];
-const P1412: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4725586929158465413,
-            6943208252376193008,
-            14705632255284541098,
-            622028241362837339,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1376846653662786051,
-            338154628389738440,
-            14860279285754932843,
-            310916727525186442,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8746834089582355174,
-            2261601070744259288,
-            532712723225688561,
-            2583278264628107040,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13931710153218027272,
-            12002090348656320826,
-            12124190627452247783,
-            2405088883216152816,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6226665818406485368,
-            8726122362058674538,
-            4220384599125267517,
-            3425644898172096317,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8845009206097043474,
-            11330585790033390575,
-            15403137598972497207,
-            2531049081418928796,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9211984431180380022,
-            3944742448104489095,
-            4025589912201621292,
-            2028212863837989345,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5740391777551451354,
-            10107189550008398837,
-            17836510090278073828,
-            92906524700776130,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            409411108109108974,
-            11448327848856180854,
-            1909979113213652631,
-            3263875898695963324,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9023228380822828834,
-            13542484026545915276,
-            16421692540744943461,
-            349112318753260181,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7973025014686673003,
-            18352881664547029828,
-            9025379691433319260,
-            3380685093493634234,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18361719314285510405,
-            17854909028617573410,
-            4658987897749270494,
-            2685173962131883880,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11353482889373052923,
-            3512217950267580025,
-            12027538252475768227,
-            3199702596670896478,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5308658959629227198,
-            15495960646288785563,
-            5866972680783628717,
-            1936495045384142845,
-        ]),
-        PhantomData,
-    ),
-];
-const P1413: [Fr; 14] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9963405283409516221,
-            2155890079759399611,
-            481425050737297965,
-            2434555641698259599,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14375826279206204585,
-            9044669235163950209,
-            10722412733762920790,
-            767509677959151371,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17268683802664376644,
-            9372644892626324164,
-            16998087821318591828,
-            2579073351894967157,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1315844883251684859,
-            15323460566807713587,
-            476556558195058224,
-            1535009429207541813,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6256691800420849028,
-            11673009776377492857,
-            1873304013890294415,
-            2816878091547897748,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6732923186274049033,
-            10661862073550644225,
-            1898158430949354742,
-            2769060391700747310,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9250462326030001327,
-            15334874172138150788,
-            16525891243874647934,
-            2910965291595539661,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8920871232428785453,
-            16312360705866971891,
-            18086155329174806371,
-            399166989086500680,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14854126602744796594,
-            13442569157864339800,
-            14436539058796385712,
-            71501158107964497,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1718515214768674047,
-            66164278945548903,
-            14644358966860642637,
-            2166836637263467680,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14658022237548347670,
-            16675968869677043970,
-            10238575888184068370,
-            2039032447943090128,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13490390594245511044,
-            17220198096778277798,
-            4122599690835407076,
-            931449745877676715,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7661754369773979619,
-            13760584415340431203,
-            7187615726176369602,
-            2317810062805698698,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15699972053633683902,
-            2054310493122205548,
-            4047204612903313017,
-            2801807019056275645,
-        ]),
-        PhantomData,
-    ),
-];
-const P14: [&[Fr]; 14] = [
-    &P140, &P141, &P142, &P143, &P144, &P145, &P146, &P147, &P148, &P149, &P1410, &P1411, &P1412,
-    &P1413,
-];
-const P150: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1209027639078680839,
-            7943003647025095098,
-            14671381253828602591,
-            2509706271394593274,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3652829575543829637,
-            9513734652801464550,
-            1636868871168827921,
-            1062883180952763502,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16020606660230038919,
-            12413193051581577477,
-            15117577581691374272,
-            511998744734398080,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2885751871694092810,
-            7101362806418700078,
-            12631890095981630285,
-            1871882541555417302,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            619780877473942841,
-            18259351881927424464,
-            6278457724892055957,
-            1904907482231099655,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9976545740920757065,
-            16290300655086134554,
-            9142896403508437255,
-            914690357486469581,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7495535340394849093,
-            8582314657785493806,
-            1164674597470495257,
-            1153730176581772405,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7319898284795931813,
-            3263373150664446899,
-            8278941946157131566,
-            3322026676165557546,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8900139355227902894,
-            15139739560317408147,
-            2694058150323904807,
-            2896580931168313230,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11279858271175060717,
-            13740568238565586685,
-            11960001476429926630,
-            1683847123407921618,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4659457397703090660,
-            12318233803032153412,
-            15796054036945096594,
-            1665136780773191352,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15587370015268150330,
-            4683002686578203557,
-            12566486019463082704,
-            179001389641845249,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17945545147034035436,
-            14760669496486800870,
-            13673343202080303152,
-            3171428765846075612,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13281187073042658055,
-            2728735656699231814,
-            15185528749917507661,
-            1612546382560062497,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13875307678641485714,
-            1175076963437259619,
-            14055895945229586541,
-            963754520062641302,
-        ]),
-        PhantomData,
-    ),
-];
-const P151: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12862032118729696899,
-            2747215576290068479,
-            4393731556297504968,
-            253361261830987464,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12880883498732988233,
-            7785712624861067364,
-            11286731144457068402,
-            1083435155388621561,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13170506341551783074,
-            15814333425587103386,
-            1020960588621448531,
-            3063734734580541949,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13610202700508034564,
-            17082657377151132932,
-            7520417872811360953,
-            2786398355791677933,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7712973749045333818,
-            3214280601532225578,
-            13570287298478931773,
-            122204174146015089,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7234900412350122597,
-            7786282227136965744,
-            11409819863088249152,
-            1210937018366241064,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7062000690587338046,
-            8572609614844490812,
-            183623871833287986,
-            2471003759180782999,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14441304730877541007,
-            4356115522923260068,
-            4974746186517968174,
-            2387608364521869422,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1125663490253703364,
-            16773372125335469645,
-            18083185553160842670,
-            396479074563295578,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12852792507516093944,
-            6731422139355970066,
-            10059728010405741949,
-            1313623805361647142,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5617368783802377948,
-            17822879651064888588,
-            14181576508627536390,
-            2492730708860346506,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9174910137372127350,
-            13770912959650108097,
-            4482941195244890062,
-            2965358820660026503,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7258104681556902234,
-            563113510912803706,
-            17608459399345726554,
-            1970686968271018837,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6204313349419604200,
-            14009611153397440358,
-            398170696301029750,
-            991302452105393291,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13127883039229475640,
-            1600230722679395539,
-            16598922355758623566,
-            3072297632050590023,
-        ]),
-        PhantomData,
-    ),
-];
-const P152: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2028693044808857407,
-            8811340826958813497,
-            14259320162252242524,
-            1403023281293934358,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11865513399457464320,
-            10826741143370420923,
-            12390428310379419180,
-            1436537713851053723,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15512417465703814933,
-            14381776061932873822,
-            10606861439825796394,
-            263801367193805924,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13292414864929689559,
-            14520766677848542862,
-            18326892507865175951,
-            3244801297450129445,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14616732938259411242,
-            6916015294661716395,
-            18442239022337791495,
-            575232749089911187,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6775041544008983052,
-            14062794358282226256,
-            7206841156073714436,
-            241964819683873342,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13679111275208568531,
-            17604271565864378577,
-            6008794107364720872,
-            1693762016668531691,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8662657825021972945,
-            6237647790042526361,
-            5385945608166133932,
-            2976117545610726272,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1829212886397440038,
-            2640046032800934978,
-            7519947526081269392,
-            1903712682198423901,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13920362807701920839,
-            8531072774318241940,
-            18349142858682594014,
-            2619015984397217635,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5292846481457723160,
-            11278490096698640551,
-            12105864857252592477,
-            709915931710630881,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5499708801251721865,
-            17765264554482440402,
-            6128023517408373187,
-            1761359987325357446,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12592220725140688242,
-            3600301173581895738,
-            16796083990936414223,
-            366608248110893502,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13123225787357014064,
-            5829845185841483175,
-            12457447874895341665,
-            2217403967835015512,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16082264776243096149,
-            14919846256056252303,
-            3222258050951796019,
-            3248198680533572465,
-        ]),
-        PhantomData,
-    ),
-];
-const P153: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4038875813352131222,
-            15922334383211324003,
-            13046474496552826196,
-            3466565772212543192,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12989341152619735947,
-            8727337172390409249,
-            12844413907170929899,
-            2151248318098783262,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18224873347888482158,
-            817870728262474187,
-            8316278131209966437,
-            105715013507390836,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9945454401352007616,
-            869112118057765225,
-            15492791402491930723,
-            3111933216391912163,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13516194151221631838,
-            17514892058946415272,
-            17390154511205344682,
-            951934470354935723,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16651620855566221285,
-            6327907707736279673,
-            14494191817875744684,
-            541019011540981451,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17434016466011679339,
-            12329014607113541205,
-            9419789610407665221,
-            3285047840505476724,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1912858791726535798,
-            17697587543758736976,
-            13938643361169589472,
-            2665376187503885430,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16936195004376036391,
-            8417641150056659997,
-            7887635484428260200,
-            1269212654055864061,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17804851691778363720,
-            2088354411910326864,
-            11777917866899673129,
-            1918172742834519352,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16369533154281965135,
-            13094782925700082213,
-            8047273848860966965,
-            2443101244615320470,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            896370215375780171,
-            16673229182216873651,
-            9242694807150435655,
-            1934957721812377863,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17459518596033772785,
-            774052091872670338,
-            10086545981580676993,
-            553366686059677346,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12172216101381004335,
-            12083136997908268827,
-            6504595569221782256,
-            2065440584073949285,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17663267791222677955,
-            698318757064390791,
-            11879229848293625465,
-            1942883724696786530,
-        ]),
-        PhantomData,
-    ),
-];
-const P154: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15651571925511999887,
-            15612953592165920630,
-            9859057064164881967,
-            387383520701510128,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4015836264764784377,
-            9812650278766568559,
-            16008979796000018539,
-            2471460192151046138,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6377165972162410382,
-            8237112377439691094,
-            12421197518526566946,
-            399347759611464699,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13522046860067025325,
-            7104623355949582410,
-            7697032200962313741,
-            1966011338569651633,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2153903159440965447,
-            16561931516824807821,
-            16292526681798210680,
-            983850025876874841,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12902447718460943979,
-            6044021650566447760,
-            17317339213501748448,
-            1771878703891467598,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7337566476499663369,
-            2392225003612352188,
-            2377001321109202743,
-            1643596621659766340,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10191203817372436941,
-            2853276686179159100,
-            3656824112643594100,
-            256889895285250994,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14190897633829282349,
-            2186245145844790199,
-            3820064869063191458,
-            910016369291303425,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17017974946761374912,
-            7437039427554810269,
-            4736296341315586228,
-            1411716041525846593,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14673662644179072311,
-            483733465093581376,
-            17798879769031292941,
-            312502178433944310,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8724412639358061554,
-            1898998185561418254,
-            9772241767690737066,
-            376775209031346592,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12335860816830014511,
-            627163695732772752,
-            1024092255548908181,
-            765311722472615687,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4017748594951754985,
-            12375521302264003641,
-            5201312567058048897,
-            2337261189612250012,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5597767681438214458,
-            501821892601604665,
-            742171391401350091,
-            2289476496431202246,
-        ]),
-        PhantomData,
-    ),
-];
-const P155: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3597594043782194828,
-            1335027886939281269,
-            7061279621972894734,
-            2133713012730339889,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17827067726927130844,
-            4886864720351680779,
-            14257023265542941303,
-            112607373559315267,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5526847133772322697,
-            85296038724028111,
-            13209591273399629062,
-            589272826845133867,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11191335701773636087,
-            6884716473669174679,
-            16298451317519606994,
-            527220135771936279,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1758487059436781171,
-            784765891033717393,
-            11112890329159088533,
-            2271325732556201676,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7282730456766294308,
-            12867490123077305387,
-            17560551572014130846,
-            2073858505959547957,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3175568934878957263,
-            5472851234520528618,
-            17377749699807646456,
-            842327024706680194,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10522781331086298356,
-            14152097393039968684,
-            17743977991892901798,
-            1654786767792112080,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2355708838366979559,
-            9020388964818506020,
-            3234609838275574546,
-            3464140581229031307,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7096572811388563305,
-            16284970226912620446,
-            15315687147586567302,
-            506519898471865066,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12495672507817490747,
-            10755573611902534689,
-            11093832682515391550,
-            3466811210541262315,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8338681350778023595,
-            4170424928400578671,
-            11793490419453497571,
-            2051156506806133728,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10407720226727743281,
-            10210931908801557273,
-            10371975523848189927,
-            1944616465708121187,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11426859213469843606,
-            1419552435619592363,
-            12077098162615154413,
-            2281806026399366407,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            41068399335891374,
-            18183288791303340230,
-            7410326237303521912,
-            3398290885031084748,
-        ]),
-        PhantomData,
-    ),
-];
-const P156: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7192105644864043385,
-            12240615975706685938,
-            1400482305914478409,
-            2165094519633287398,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8691196744818847005,
-            11624377276636808273,
-            11836608220395507055,
-            1574653971833565875,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8334200926663389002,
-            16226936220111410632,
-            2811951625693612924,
-            1148702850020237941,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8011118030474230276,
-            6057863946491892125,
-            2397778329621413974,
-            1809729240763886438,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3760599045838621071,
-            7138931521878423039,
-            13563879711685863310,
-            157670083514493293,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17681375210954143773,
-            3757996921778304309,
-            18143636524443703177,
-            903249584603903076,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14559201552861049554,
-            2905781719653598597,
-            4540989775634108365,
-            3483555765597897764,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4634454252727715463,
-            11243822733304380740,
-            1375541135679697839,
-            1310649805567586815,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9083147994154124733,
-            3199418359377910397,
-            2918648775811814909,
-            1127562082061465102,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12991674333465473903,
-            6193355281945199866,
-            11027140926587151762,
-            3328911955680480828,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            422967068770576393,
-            3813245194355690917,
-            9515540255866637370,
-            1204565407126916765,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15457209766372145556,
-            1939996808089053980,
-            13903138083763164923,
-            866220149165575256,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1472037921007454715,
-            3560406245083728590,
-            15055828864520434371,
-            1896377465269219003,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8717515598221788095,
-            4582184621092256425,
-            14280508143565587813,
-            30247863716281055,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3815472462593917824,
-            12028138431316790715,
-            6046177443673115887,
-            2395143980298044693,
-        ]),
-        PhantomData,
-    ),
-];
-const P157: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            159769155243491755,
-            1280168554713271751,
-            1656437357608858406,
-            608765644179995110,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13318865645252185277,
-            10904134550147254660,
-            6025985636485519947,
-            838650435105017155,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15724358800487251932,
-            16974678299193865,
-            17712738770487876133,
-            1279676686772016532,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14814958620524853294,
-            5383581582170957509,
-            4478203481807564539,
-            1774702255471337104,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13147286826118487555,
-            10611210116535683590,
-            11513362389890669382,
-            1585419952052705010,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15576349470005469089,
-            716905777070417836,
-            10841193453942594326,
-            690629413954148276,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16861127829034274472,
-            4668135922613807828,
-            17535251298683862077,
-            3116058299362706731,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17444688933818235053,
-            1935114794809736468,
-            3504351191035290176,
-            1759273849141367901,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10860072962468079202,
-            9486101756798279383,
-            15889132430797821077,
-            2414315430922105525,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10404958533359449356,
-            7656985955445615866,
-            12484885549362338027,
-            2498989743904130243,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12743657515726945177,
-            4905156051793583003,
-            18299399084581044080,
-            790878343311690070,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15190420874468001440,
-            2066803715341930194,
-            6805872927338381281,
-            3306244819108595798,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13158911435565062616,
-            6688259514690740458,
-            5888428217721193468,
-            1500026441558978969,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10554223304372836033,
-            7482477928102898804,
-            17466677328473675371,
-            1195526272281035852,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7852126341865936302,
-            13654551796435023142,
-            13618044613507787118,
-            1577545116849882262,
-        ]),
-        PhantomData,
-    ),
-];
-const P158: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10068726702754304580,
-            17392849312524388748,
-            16984321195449771432,
-            3293105960672582544,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11142020482704545374,
-            13539233532804934643,
-            15290104505673861526,
-            518037751528595892,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15880441622792939396,
-            3437250393750898485,
-            4317753674911645242,
-            1482486716766981306,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6048732561115493581,
-            17280288867089734883,
-            15626359959627203600,
-            1574466956399009020,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13120576795350122852,
-            7403509859223297000,
-            11256461726843702014,
-            2440544215634535779,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3517368091778918102,
-            2351561312564995503,
-            7012808125912062132,
-            3025613338918950714,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10965939011138296883,
-            5997306743445644197,
-            16573595266852718684,
-            15772738836325787,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3051138307583313265,
-            15856825600084625682,
-            7659263020294063385,
-            1365087757230097033,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15211639945394336848,
-            8424656490166645994,
-            14314479418096168835,
-            1650111245649497599,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18207894545291115047,
-            1112494097588387376,
-            10723535171625135634,
-            570816390323610953,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2747250197633684014,
-            9572827279569563468,
-            7991123450838839691,
-            2227116035959967079,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3500584295928113724,
-            3612624234473927292,
-            3959148183549157440,
-            1406353524412881799,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8014035823158838729,
-            18408959297822082597,
-            302186076348471139,
-            1336515576005892781,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15106545895397002536,
-            15178324777700930585,
-            15127342703947265075,
-            885526413409872745,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1961513475313767950,
-            15222141903722399604,
-            8666536374514613277,
-            1054463561497943419,
-        ]),
-        PhantomData,
-    ),
-];
-const P159: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7646753186603521672,
-            16780676232708815818,
-            9272988189560374340,
-            3329363870420649696,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4419385861227362424,
-            10775615379409488360,
-            15472791333889620383,
-            592731785187842048,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6611585856255062731,
-            3964469198677362023,
-            11101627061326772630,
-            2611815805978968133,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14578583563770549585,
-            765137407300396101,
-            5945463398405651820,
-            1865369574355185144,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3445099866950459994,
-            3747718388907060827,
-            14501582164086910577,
-            1711307164465273903,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1734487907882727970,
-            3852911951536231265,
-            12571171317247647147,
-            1138349452408960521,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17176938833526379236,
-            15566076402542688347,
-            6896067236172153773,
-            1060102158960775890,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13143419250281820471,
-            1729148384852183449,
-            2132075188511445057,
-            42779002178511262,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            903581595626380734,
-            10161335408273362820,
-            14201644398238491591,
-            622136808393950482,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2115519503282588702,
-            6277594557822976410,
-            10565279232092355945,
-            1495735584373031001,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1980327800793441386,
-            7630767723553407570,
-            4317666063926728524,
-            3189717140984989315,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14031327188787535859,
-            11444463211721138084,
-            7516482624953661571,
-            1189956659188482538,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8445430716198621441,
-            6916366067046271159,
-            11242534685512545175,
-            857144261375496920,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3060268186641577181,
-            5157883841488377010,
-            15220219578000831448,
-            687539613803263611,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12064083797564047533,
-            6944623546903612563,
-            9638808694677753722,
-            3287309077020008414,
-        ]),
-        PhantomData,
-    ),
-];
-const P1510: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14730809341988418982,
-            7790659251595032907,
-            667589261129073402,
-            395543669698437749,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11425953288957353659,
-            17032274609913340202,
-            7404790310691204059,
-            3095241343655934166,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18241633410116698153,
-            922392977875100988,
-            11915518378261317521,
-            872338960151717574,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17029041407765138999,
-            9740367686455999866,
-            1454715115021868489,
-            927213397062377188,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            461067655598071041,
-            2330755097223821275,
-            658081372360240304,
-            1487827427189821931,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1463619482967966851,
-            8145801515117189249,
-            99191082417720530,
-            386338840014022318,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6730587383974023750,
-            17422623841354302431,
-            5455675093373346866,
-            2922738208315140558,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7111056937053478036,
-            3181378549747454881,
-            542310744082766518,
-            2894501758102482966,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12463616773628729697,
-            3026973347021535239,
-            2685026690872003891,
-            1318453490652911087,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15612918215228954625,
-            10759194862411008657,
-            10117764582750265178,
-            2600923763976872983,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15941059104233005278,
-            5994571293979596137,
-            3552794474001444894,
-            227025588370257244,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14634060039494912094,
-            8232211118055970107,
-            8567431784673584611,
-            2096006229456610811,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17368220761791311643,
-            18338362314218558603,
-            10676464084904898013,
-            1008197056670525102,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1471257832224794326,
-            7136834109503307007,
-            2596020281787288576,
-            2511932989567847300,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13133260257415239272,
-            11328840450647088963,
-            10149657697379759224,
-            2386156263636622979,
-        ]),
-        PhantomData,
-    ),
-];
-const P1511: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18077257047542835989,
-            12312618281029102809,
-            15120149568996268518,
-            2894682246124604490,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            372704007498397594,
-            11538203781993411048,
-            16663093519209865759,
-            738291841532833765,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10255001621938213663,
-            11586039660133991637,
-            2128323760438952838,
-            825727127939494732,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4565171720871366598,
-            5165571301807196911,
-            15116022354645922126,
-            2391566663090610042,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15258802568460345431,
-            17087133120976951747,
-            10126299463953266509,
-            598921696214845002,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6149929112181700696,
-            5610645393019104932,
-            4384412905704168443,
-            682698031926192299,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16784246588367738277,
-            10243110891645081454,
-            9952285295670595163,
-            712207920931411972,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13871748151113644076,
-            8029857881043623832,
-            16501524931313411960,
-            158185753856514907,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8639139383974796491,
-            9928656188910168556,
-            11222397974560932412,
-            2378353511193673997,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8097596178762523447,
-            1121225143817495306,
-            13382371937375752846,
-            1653773719781076157,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13490530552255649665,
-            16026395691714246035,
-            6458398916501193940,
-            775797714854678775,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4612985164827663341,
-            15054327315957771167,
-            4313828128032529903,
-            1328540451846369159,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4729967701136562274,
-            1951678233593416081,
-            5011459939415631102,
-            2927509101503631171,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5244231534293978630,
-            7020051691063792016,
-            14204371124368137146,
-            1977226789795354195,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9514428949664695765,
-            15004954168786236268,
-            18427933396336683484,
-            2873751220814123489,
-        ]),
-        PhantomData,
-    ),
-];
-const P1512: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11591178741741020217,
-            13155081416555938315,
-            9260755981233574450,
-            1132335595701264985,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15397102779871795465,
-            5251894780075211150,
-            5731682786802631553,
-            1393648646263840131,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13106560462521038664,
-            712046405769723915,
-            17383698150646871687,
-            1124472132700316500,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3142587477279143170,
-            8937593775799166869,
-            12464502538778894807,
-            3201403079331116549,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15469776742302296568,
-            16217899760886954785,
-            5293378158792903620,
-            3080797866271255236,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5472638545001319097,
-            1474460898010179959,
-            10666044921845029806,
-            2357255727518804303,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12967755416956017227,
-            33191958920089642,
-            1772224618652466135,
-            1806061076347974444,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            381764431604131641,
-            441447628945966541,
-            15710377397996388807,
-            2147279129742595203,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11450614388801247248,
-            17150025582492368244,
-            14227612940573396749,
-            1262622886574970455,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17008547057487195430,
-            9079558480411384404,
-            16186172940513684185,
-            2156266795144696297,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4860177547658342751,
-            11039568317731908521,
-            9478410912646156836,
-            3265763975548743746,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14918443910179695353,
-            2426749042267527020,
-            15700664823644526302,
-            2752789604852117572,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            833135672502399323,
-            16678569731452802173,
-            13757515523519541263,
-            3463492832802293696,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16364800879196525474,
-            10479603961622111356,
-            8361921880511324967,
-            2435747635212745730,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10304396953837716765,
-            13946757798596520112,
-            11328020563076180271,
-            1393715299080966266,
-        ]),
-        PhantomData,
-    ),
-];
-const P1513: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1199110810029130532,
-            3207720045255705056,
-            17295791970435141395,
-            77463058666553274,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1006245577743201067,
-            3837408459612579716,
-            2164413081721146648,
-            865200047104309981,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15164789832268433075,
-            13812211172373346527,
-            2476970832774360515,
-            1207966534607204916,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2398021340063544892,
-            16112046757305490418,
-            15035815853574980677,
-            1102896737980188542,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15607642771685943974,
-            15680055738029861756,
-            7843918386286678192,
-            2839100214637048993,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3184446927379310374,
-            7961739070627196166,
-            3842724717644649534,
-            267332253550783335,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7744110258321032905,
-            10312362095821139115,
-            14732565323511138140,
-            2519002769972145988,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15107071182339945020,
-            17458346603675887211,
-            4134879304863025610,
-            2448747023755160397,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6449333534350004208,
-            13919793032397691672,
-            6392279869166073489,
-            2435190786741726953,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17263472912994224204,
-            5077646071163946323,
-            15097458408855521472,
-            22854800352043046,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9520356683448433050,
-            11335004783323882912,
-            7494485366667656808,
-            3445412676637498004,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7863158361558777963,
-            8989117119791789513,
-            5241578755249694661,
-            3225994061194294530,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5800316427222068108,
-            7791795205838074444,
-            17909289509707989383,
-            1073052083726004789,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6731566482440305018,
-            1993235293102165059,
-            2242556094212440138,
-            3239795706373304780,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12241923715111177730,
-            4141674100055350457,
-            3437667771467009338,
-            1235568977200402419,
-        ]),
-        PhantomData,
-    ),
-];
-const P1514: [Fr; 15] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12624092476274947332,
-            142610550012839915,
-            9781413665790854116,
-            2824611142818550409,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15038951756984161400,
-            9395595337946633698,
-            3178810557901008315,
-            134649487640879316,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3150859532086297643,
-            8587138192673632920,
-            16646021429647225418,
-            1644054454401482131,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14249945610076364829,
-            6537320511282521065,
-            12920712170806496468,
-            691254800776592018,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1848818131791075085,
-            5365315471675548559,
-            524145411833243443,
-            887322688202463439,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2352579080971998914,
-            16341214152650127931,
-            350762576459801680,
-            88795034320504116,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11686210141458755883,
-            5714845092252258664,
-            9644583992708716611,
-            3170652483526573083,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3996182869185710339,
-            7539896405732584406,
-            270100015158116444,
-            3187199967127486082,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12612072461168084493,
-            2890130172554196629,
-            8328642642037010730,
-            382542826329336550,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10665990871809633113,
-            4691932684977021378,
-            14227565049214143478,
-            1660607739303404642,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14869108544977930142,
-            14007304895965775353,
-            8543970718588242031,
-            1347535805944547162,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15178711531134752384,
-            3479819963284481924,
-            4450500813162327938,
-            3054859240175818652,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16985490356337740614,
-            8523648400120559829,
-            2398271374601035310,
-            492280473605774743,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1209532021018243276,
-            4720514939721550074,
-            347039921611980748,
-            3370581949849829297,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10213666822998547036,
-            14237256059924135461,
-            12444487806329170933,
-            355785303193369835,
-        ]),
-        PhantomData,
-    ),
-];
-const P15: [&[Fr]; 15] = [
-    &P150, &P151, &P152, &P153, &P154, &P155, &P156, &P157, &P158, &P159, &P1510, &P1511, &P1512,
-    &P1513, &P1514,
-];
-const P160: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18228707519148411994,
-            893778983143571551,
-            14930552804037119281,
-            2207320878121433072,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4170373381249223200,
-            14918468938716368267,
-            4699521744832910655,
-            1668347806360489698,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7171627046102698884,
-            9177670835972989545,
-            6298668391436170082,
-            229227183670285155,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6518282521321752901,
-            16673588054410315818,
-            16459043969462937703,
-            1856812788142725211,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11031426181697780654,
-            14880314183643666622,
-            12583658442483505407,
-            1540784730993104787,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13602287792445412293,
-            12964456824989056607,
-            896479515418159500,
-            2918032677155673503,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9516416840012147302,
-            13955965031829665251,
-            11984108965011643105,
-            115897457358047393,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16829678514777472029,
-            8686607680200211406,
-            7129316808781946167,
-            1071337322439917254,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15090253751831173794,
-            5135529552486900373,
-            18263870359896398470,
-            2576554447198508593,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7006375346322659768,
-            704989644685350515,
-            4190275179125195479,
-            2111570096400563295,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3200098328299728693,
-            15236965411313687916,
-            12990164193287016703,
-            3173418022884453339,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6118072434456609185,
-            9378412494592354368,
-            4287322874967592357,
-            3472992838291688584,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15657104260775032602,
-            6402920832573726893,
-            15696271464624476612,
-            488302758400925111,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            862694089000796846,
-            10088841390403389492,
-            5351145282024814289,
-            261888308715841223,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16846333865798602651,
-            1659304650788954493,
-            2091317161943898708,
-            2481318064346064804,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            636539979694115660,
-            17774604828799374256,
-            13161555532208485244,
-            562215354203683816,
-        ]),
-        PhantomData,
-    ),
-];
-const P161: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4921275766052334565,
-            16229398323246323055,
-            13618351548469487584,
-            3087389341159532332,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7836123197409952073,
-            8660154450623147090,
-            10212538441803203678,
-            1798816357723472654,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9982346828085208106,
-            15576475793640773705,
-            5817606196624925093,
-            44811992434977249,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17528602640445910642,
-            9865841948535390771,
-            3709921669612979998,
-            1487208077348304194,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5840598313893313870,
-            1281438997678041004,
-            818168328226587958,
-            615142182383378381,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15297679104764942374,
-            3887170283134930823,
-            3129619945319905632,
-            2525249692493976816,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8410237055171359176,
-            11471520943133167857,
-            12646612238135336087,
-            824066543104490475,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1509817577424297505,
-            14910806642679642462,
-            6978289457775610170,
-            2357771326411923831,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16752109824523381412,
-            16870128742636170259,
-            13598054619653903368,
-            2084932071217385860,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4886907159109829132,
-            3542278454123084458,
-            13033215385845956007,
-            3219526796179661497,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2390228182236880711,
-            16774201825072385848,
-            12702625861479704791,
-            2313310603721388280,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3188301671272207101,
-            4940550452318816665,
-            7701224294072203296,
-            1448320516642653169,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1437726235613373391,
-            9146793600841876584,
-            14043121782626897786,
-            1311342567165765380,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7172070116882050138,
-            1646759550448645614,
-            3888095895609486767,
-            2160726452932581668,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10155866487727236607,
-            16042342578133870660,
-            9159246129977163840,
-            352272560586335221,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13809972740272055194,
-            10644035886270155610,
-            14793151282655953605,
-            2635078347668973692,
-        ]),
-        PhantomData,
-    ),
-];
-const P162: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2228806465175379355,
-            15279047253652713954,
-            13490888947130133117,
-            262578630366754845,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7592242789043594837,
-            7439668571175923336,
-            11082974303370406241,
-            1268915834859710248,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14269936931924778073,
-            2814733588429089266,
-            2949704090207621553,
-            1997186771682971713,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14577591893629343665,
-            9446271014523797564,
-            3166937653314726876,
-            347976510320216420,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7551949661804357897,
-            16230435709690394097,
-            4300871878008038601,
-            2645686056439689135,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16273767675840525541,
-            9299484571557325127,
-            13377962008206740829,
-            3039148108337156854,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5186667056763273172,
-            11689497540419190827,
-            10298834487765699330,
-            2020595851965504025,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6641950523184730854,
-            8859871343738037649,
-            3330880494964367389,
-            3171171433907917968,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16851749464310230859,
-            16170208640366531928,
-            2695529106043202335,
-            3245849061150953595,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14474479822943383242,
-            11799919871271598489,
-            17319520233570648408,
-            1116444504615435021,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6581828633437875379,
-            4742745821608194220,
-            597485837285959963,
-            3443724725924613158,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16871361971609586118,
-            13346225879516583570,
-            8609685750854322159,
-            164738032594197970,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7088489097762560581,
-            17087892127916703592,
-            16509358942300641541,
-            3205861915899389987,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10044847942393715017,
-            13588836038663649778,
-            8210229923637279585,
-            2346451011903938831,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2142904953912970590,
-            53792180904308323,
-            615189994864416757,
-            789158768200747472,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5896032257862604174,
-            12174475013881047990,
-            11451089194495854944,
-            456782227440653437,
-        ]),
-        PhantomData,
-    ),
-];
-const P163: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2508337182886194110,
-            2018288619968497968,
-            14602918790034141603,
-            177365286484458819,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6173015255603590861,
-            3944887956410474412,
-            693542868198457798,
-            2120913510325240457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17139820684505051159,
-            16354519065834225156,
-            628041086108704955,
-            3046928533598703504,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3574729204436442259,
-            7410517993040490674,
-            6796708423809901468,
-            238514708516448640,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3676393517564580495,
-            13187845849339144994,
-            10155984692492945001,
-            1438244299940147586,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11840416427676356256,
-            11876779319936309279,
-            13697812140916283987,
-            3078138172102994008,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14676961596056194906,
-            1904547409715154260,
-            5586649212277017503,
-            1552795884449017187,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11702320044888887347,
-            5857409693072346729,
-            16921889177316544388,
-            203374274079161266,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7720591583688153574,
-            424280360677590903,
-            7157672401252609286,
-            1698589367026360298,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8855514532144027059,
-            1656148594200464461,
-            7982915482645314278,
-            2302361945685037583,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2026828813944163299,
-            17803280717294030046,
-            4104467011464294992,
-            1388075966439481294,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            19833142453279023,
-            13706075276968958613,
-            13960672848831660375,
-            2173411065319838663,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7515411797950089864,
-            11662730298597261330,
-            15036944384650343121,
-            2075882426465420695,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5902625677925331292,
-            14694171827582276649,
-            4625659120088844619,
-            1736167706623854290,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3744517036476524433,
-            5948088172255536794,
-            9224886238460470776,
-            965137585652507790,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13787327842432401806,
-            14857853848772771541,
-            6261491614041020947,
-            1962068258522073553,
-        ]),
-        PhantomData,
-    ),
-];
-const P164: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13175071890525137109,
-            621839090168750356,
-            11010335299661335211,
-            350297906279918860,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1054855872741565189,
-            14344065052743819009,
-            7644665233038232995,
-            1480538619758547121,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14031081723194643612,
-            11643656920435531803,
-            2913631366914798502,
-            2266496439414122974,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8755770396649014489,
-            4042334939250769844,
-            632235526140553983,
-            3057966823032046755,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5528512563236448412,
-            4792470923090775329,
-            164641637141462339,
-            713064098666359637,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6796917831450844203,
-            16706083160120130701,
-            4839908463006593821,
-            2499152607201987694,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5050011457345995574,
-            14771273302994103734,
-            14463327618085629155,
-            1515142482081936147,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14161595944231269146,
-            2754614592557556869,
-            6393564168552942692,
-            163080244342194705,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13018617073799779493,
-            12665704340258023308,
-            6923164746962582296,
-            1687675183351733443,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14638454956684134910,
-            9503269195162212520,
-            1960852126915162363,
-            188422992432768899,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7005931068126908146,
-            9942329856064224619,
-            272827525795599281,
-            2410870233505738872,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15493448088720199509,
-            17035358527566832756,
-            12190311450017890771,
-            418453951840273558,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17319741282748631849,
-            1728985150333588133,
-            11559498329112047599,
-            3454540492189482505,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2167766712139029073,
-            6645440638850025271,
-            7544690135800524989,
-            1635313877922470625,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18029528600617678594,
-            14868125824750705940,
-            2583606342360901387,
-            1954079111621182692,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17471595637880329597,
-            7036113133796617931,
-            2116821505667867534,
-            3064875561923845960,
-        ]),
-        PhantomData,
-    ),
-];
-const P165: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            41176725757284346,
-            2707010666871171418,
-            3860291731191217272,
-            3245852095503373677,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15353094612543569976,
-            290759380312767847,
-            3321123645156063825,
-            2714766860753853597,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4011269960032091626,
-            14597946245553470491,
-            13733876743244478169,
-            3211964449640394102,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1924522421927900091,
-            17621697835960531915,
-            4656063376363787066,
-            118789853086903155,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10849751047127327438,
-            1061255543909224573,
-            9581817002622792852,
-            3289990372900745632,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14080624017095978023,
-            13345363416555660584,
-            10739756024804611670,
-            2311001350702299127,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8851343329629878015,
-            9197774134487870637,
-            1824621990287481544,
-            1097753667028538068,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5720685050435574874,
-            17432310056919363981,
-            7836301019484023696,
-            1818897057359533341,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6999534489478998572,
-            7577003714923945382,
-            12340177513539299948,
-            1528850286987484069,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6072705344932626642,
-            16046262893336381717,
-            3664040864498186365,
-            2285475461893292947,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9293007001963212644,
-            10941588420082193528,
-            2101698963226128662,
-            1919628494616738444,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10900785488468503410,
-            8596458024480664112,
-            5040535884664800574,
-            3446445930611946556,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3207734158566529,
-            14958024220104891455,
-            1181917807122346754,
-            697440643798356405,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11655226006894806535,
-            12278353228409316972,
-            14005330841975066249,
-            2542040482221580093,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12668712361584385158,
-            2280781787415364745,
-            15553831749768597932,
-            1139003792264235002,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12903204783119804940,
-            7218559975818715540,
-            3664530609225296421,
-            6539601019006730,
-        ]),
-        PhantomData,
-    ),
-];
-const P166: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            134716800150417229,
-            8120178632213131186,
-            15590299010759346804,
-            1274000384574886462,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2568776408339181179,
-            7968368715325195151,
-            15431876871263083622,
-            3396169272447313903,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13682091755782389466,
-            10756853822830377242,
-            17152134021736361297,
-            1739436389097634596,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16852333994405945418,
-            4514644665214631580,
-            12996809796482958765,
-            3396830149576331948,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18071592895096536699,
-            17037612107729515930,
-            8846318590185190393,
-            639343214134654743,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5659501731624419101,
-            15546660494818485196,
-            17140899445759394181,
-            2396600952567631916,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            985403807774639523,
-            10613206774852386420,
-            6995158110874001091,
-            762668407548887006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13845491486612371119,
-            7127670872597276052,
-            5229197521479303698,
-            3461281115390518891,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1037792918301770110,
-            12433634807884033097,
-            155173524019754666,
-            595612413882367393,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1313279800990884092,
-            14925501451255680383,
-            17909558901235194953,
-            1090542806012339759,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15117426568039170655,
-            4204248051551379709,
-            848762879082338829,
-            3018284980259112826,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13190869564513254489,
-            13949911641228231436,
-            896483952518170166,
-            3292488904057142174,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5788277865279054765,
-            7196347394262920700,
-            12886869672305345159,
-            135747922816289377,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6715273599119462600,
-            10714551054751233617,
-            13015117084139746718,
-            2762701302483263773,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            256739994012477213,
-            5535709628879435250,
-            9107759892352927426,
-            3217731761535084977,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15641286933107889493,
-            2233493449728291902,
-            17275303083893847325,
-            1772651877087882169,
-        ]),
-        PhantomData,
-    ),
-];
-const P167: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7260096415536097576,
-            12888500082738983877,
-            12638880349802762827,
-            514490385951436483,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10381026234478706628,
-            6175110579738741312,
-            8478587387972434373,
-            1488700568954666505,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14466985427212982855,
-            5030324155462385511,
-            3443282145388739124,
-            3219820513920554530,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12473849748705752718,
-            16505565018799736906,
-            10110196230617152807,
-            1457383287617251112,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2733223904462545773,
-            8499943856566788703,
-            12607137476784887300,
-            2975004331905953651,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16326546206780030513,
-            9383517683477286937,
-            6333551827817982629,
-            2850692766035184753,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7018653953585679768,
-            10515432027709771056,
-            12153599969948385904,
-            2716424274508208930,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4463646561927066843,
-            9338761931309335916,
-            10259681675859909872,
-            1967381637603846527,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12188587241840686505,
-            17692925896497817963,
-            15690511106280136379,
-            608476445050788952,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            402172909514887624,
-            16308062391412466367,
-            10724281787979568315,
-            2471110244024554954,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4247005688330717267,
-            6237636784757846890,
-            14210575030618908531,
-            351746615813778429,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15535705064863815414,
-            7268265795810913777,
-            8609756744210694324,
-            164212348423223522,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11570399699426543776,
-            540864219332849371,
-            18315830544234446833,
-            1920886325925903114,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16532066207341618080,
-            4940036774123424840,
-            15456680643299817890,
-            1564382994425254783,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            416381927012463339,
-            14163302316475998984,
-            3269434761662136098,
-            1788728140011648484,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2929996078035427007,
-            17480360075014952608,
-            16116456335210848619,
-            3243899635508155516,
-        ]),
-        PhantomData,
-    ),
-];
-const P168: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1643938428803721675,
-            14449274104768805216,
-            9867387587888501478,
-            2975312728966379906,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8867594257803089458,
-            15715246462745390231,
-            5545118048350980971,
-            1300826033124265897,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8287800140744466214,
-            4599738287897078827,
-            1000762302531475723,
-            2858354464049738169,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12965448457393562482,
-            12995586384363044724,
-            230619978705113450,
-            1428155649840967081,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13720482536718760017,
-            16175003281062157247,
-            10034745562253710406,
-            525593601071465098,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13825073069455039906,
-            521140148518153424,
-            17452545460242660727,
-            732572334242961725,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6463395202465401386,
-            12130687415027503277,
-            11865556772562586607,
-            303131662703751949,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10426553814346004358,
-            9489017178148843787,
-            2482087588531284226,
-            3271107173069397430,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12053073535864535166,
-            733694455090465041,
-            13290337349146999718,
-            1034517361806975049,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16872638229350961495,
-            14532123321234254088,
-            8854167009321206050,
-            1521410862336122046,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4843475708789425463,
-            11681781111743235875,
-            6886017560232982690,
-            1044166154358408002,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9150490224162234393,
-            14031969583492508679,
-            1164811062598697589,
-            1744694280246748586,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17665675344788934008,
-            10177437360881679689,
-            2492920676123981694,
-            3084656626741540455,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10925163967987719980,
-            145382531922367668,
-            9895274609622278507,
-            1185805029908909560,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8844700398339376752,
-            15215479687656580736,
-            105896531910171754,
-            3195073702271319849,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3512352153548389527,
-            12073594333569648207,
-            7110671768237507347,
-            2067161126932879462,
-        ]),
-        PhantomData,
-    ),
-];
-const P169: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15877895169315012260,
-            10656968326840295223,
-            2026443393108993706,
-            3146152192368922550,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10127077710174662570,
-            14190844352578849095,
-            15256840788491456894,
-            2202172971252767223,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            946984513670992402,
-            17374092309780364623,
-            6814513163011732699,
-            1720063206312527610,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            840009190881276204,
-            16643950961315978666,
-            1124240205647195548,
-            1412734038113183909,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11348245850882617500,
-            14023370903916063072,
-            17708359906359972941,
-            2169268999688243944,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15621833862892549148,
-            12948142790381615702,
-            8475843404170170402,
-            2673829848693388233,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16272060642086523024,
-            4782360762911869415,
-            11418311263963694561,
-            3192874374599985021,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14837925792351552240,
-            8095351724957939620,
-            17417431734309200550,
-            2774758734434088041,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6640799849599870819,
-            15282376788939449885,
-            6730111506655719072,
-            1990977397594848498,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6890567192889924487,
-            3024040301481256927,
-            14671303055892788898,
-            3447743577365151297,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11204788466456765246,
-            13463454310471640309,
-            15495593177233049477,
-            490757388048332205,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6690429656155555859,
-            18208897163177459679,
-            573666716886917065,
-            1541254463948409610,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1986486669825401481,
-            11250889590726025040,
-            2599449047166785340,
-            2804482198823354899,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17955132330824264773,
-            7364914626158647557,
-            5087612654537578424,
-            1542101371706203625,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4813052517385859715,
-            10427637462379997939,
-            15422608953659764710,
-            344086459491822962,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2952680715433463232,
-            12675527579879095051,
-            1179070327593716863,
-            613037084760492504,
-        ]),
-        PhantomData,
-    ),
-];
-const P1610: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17391679336221390105,
-            9704038897386151226,
-            6984073548022465486,
-            3429791030651873539,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1665150535251332028,
-            12199706777976874370,
-            3957786038804391203,
-            2372578055803144761,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18381934667708137928,
-            6174007645764568753,
-            11913294914888152641,
-            992180169594733767,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12391327090585183122,
-            5974784565735925965,
-            16431512175707619570,
-            3327615744298437344,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17070015902249492385,
-            8266502601014549338,
-            13582243883335658091,
-            2595793483390139487,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4003433532842919855,
-            8526129819172033694,
-            4044811316429705939,
-            2818816976086282362,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12480397173725302410,
-            10226736344549500054,
-            16594509453091109451,
-            3385898283674660193,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8189831622078848234,
-            6365910233575049742,
-            18210977303034211676,
-            2674148672986584057,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2225674772884541179,
-            10936146085643400993,
-            6394460496659766366,
-            1370990731635190132,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11554246141855262250,
-            7593924949861643328,
-            16859389031930724668,
-            3414903759199337499,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11335424499625002844,
-            14263594735862076964,
-            15288164721948474947,
-            718746191236751221,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13582704720676605189,
-            6246724198321609124,
-            11061791058798959633,
-            400076959370539701,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14038168443176199114,
-            9567979876961367809,
-            9468122366860468481,
-            1261652953688709143,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9958540695474553662,
-            12455222254937563379,
-            16245888004122216596,
-            1014816437523155365,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6316443545234879318,
-            11587726685718814606,
-            16837218891878928143,
-            3124701867764120774,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12852381327883379651,
-            2898098921022489063,
-            3908807549435403569,
-            593390585438918587,
-        ]),
-        PhantomData,
-    ),
-];
-const P1611: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6971170420757276460,
-            14894601492976858023,
-            7281867598063372161,
-            1988175743271660904,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2093365688406875819,
-            8365193388148017315,
-            242711010954719110,
-            1347781498232190122,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            196113979213603449,
-            12757387363809737638,
-            1022632073048475915,
-            1512441414124332877,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10646292855558916340,
-            15138070531842039528,
-            17705080703738395755,
-            759878905246879297,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3433589071355498174,
-            3673168675126432336,
-            11193691630227760186,
-            1101695602351876311,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4436133359358814565,
-            10244296626347107400,
-            798555809649813034,
-            2192992954969866421,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12215176978771942552,
-            16332431286149567300,
-            5007704744340543566,
-            2753986137660940166,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13386597497117226707,
-            402220616959252845,
-            13393371168338491583,
-            1928327508285082264,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8610647318245438459,
-            16882984505338200051,
-            12730068785281184300,
-            1958328064756267622,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1758757251937335266,
-            6453252320071225549,
-            12633634394742700359,
-            1373234782707365819,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8607498342846713180,
-            7426330801154453976,
-            2985065467260580924,
-            688971363168834605,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7847751017592947017,
-            9157139973640066921,
-            1533379241717784787,
-            537322971400887864,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5954608780961710238,
-            6199675860080168300,
-            7989786876840876937,
-            1982245990067856278,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4220956051341157540,
-            11629917629964753935,
-            16262582266193284863,
-            2864341522881120325,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2795209833887499220,
-            9209200624257824515,
-            12054474129826880692,
-            2601803732950027626,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4799550365577618087,
-            3517313447621685440,
-            5934709172687792164,
-            1434737698904527354,
-        ]),
-        PhantomData,
-    ),
-];
-const P1612: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15507618132776500097,
-            15582307554375635108,
-            10888631311666312108,
-            2285483272235773866,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9654623609723098334,
-            1640052465815712114,
-            5601715511111003541,
-            2111597173009173800,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9764286271391420541,
-            7653792906732030600,
-            744143423720843249,
-            518696203084347610,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            774409405370702698,
-            16696242961178227189,
-            8699838183959189691,
-            2706810795939209180,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18041338765906128206,
-            4834026332137985834,
-            14311175738459977598,
-            1309910948347470937,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9907078197806471942,
-            17647829408353742305,
-            6315455072575623170,
-            3091714722183701722,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2531788169237618310,
-            532160276843484223,
-            6318350901107542738,
-            2452074646572436448,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12331493931856997663,
-            1103448770925753623,
-            18324172870369596813,
-            789332101606960490,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13978884667096269792,
-            14939648774839156060,
-            5589598124108537021,
-            2216034139782759288,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9214296959616312257,
-            3972655151218235789,
-            1559026104557873278,
-            2073496543322264016,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10955583474698161489,
-            8443438945415590350,
-            13383174856878672613,
-            2004612436604948507,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11620272716205502619,
-            13000056538711915241,
-            16843115347157530523,
-            1967527270315234921,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9152585446976473192,
-            4353937297286087589,
-            18160697243968587368,
-            2510053594903702277,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11902476503913688147,
-            10078270053894468935,
-            4295621757479099985,
-            2596494015183925719,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8504093679790844555,
-            9658197040515539295,
-            15543549889144918328,
-            2967267264176927188,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14251370760889061887,
-            16023806354825499211,
-            297433219463963069,
-            2353514978891753113,
-        ]),
-        PhantomData,
-    ),
-];
-const P1613: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17330377730219103851,
-            62825105892344904,
-            13402640527070502655,
-            1190726090356558228,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14800283269524953177,
-            10082645120887055897,
-            17800345483451917977,
-            2868645551809722785,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2486777825623160915,
-            1394592031485760226,
-            16738496673479689253,
-            308920806467211285,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14498076667140106990,
-            8188186427042591597,
-            14533682275445724204,
-            2928531624698795130,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17935437340455630589,
-            5052634660586478669,
-            13943186706052748282,
-            2145625833331333710,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3272850064116013732,
-            16724107792308272369,
-            6502758801454387524,
-            2856299500450017748,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3956855317089639584,
-            8274179327937972260,
-            18225910537665441569,
-            468875441846101347,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4208137163759321012,
-            1749644631529172608,
-            3526884925057695795,
-            1071209167130491873,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17326000515550131663,
-            15306191828814601098,
-            9681574763948712582,
-            2486224062537207780,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5437204756220425740,
-            18268517506050130282,
-            1388228256675278790,
-            2679129359893211606,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6481624475509347998,
-            2436639768874630722,
-            2648380794627377314,
-            3029491586459263785,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3411693631009989879,
-            6741980024458122878,
-            14654684836854647233,
-            1480574319948201117,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7348077453130309513,
-            2112018241267337533,
-            3594625027710945612,
-            1412774725747935871,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3051355938556369345,
-            15762365834327877068,
-            5971284262904098049,
-            3315649859881801971,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1994482173538303016,
-            5886320828491409429,
-            12342053734416218171,
-            2045872658909318806,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3945396113761577864,
-            12889351686294630593,
-            7582274619712198037,
-            1251116043008751449,
-        ]),
-        PhantomData,
-    ),
-];
-const P1614: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1986678329407437934,
-            2843700516576524578,
-            14264295306042794637,
-            1299707144116771915,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11961877867752878369,
-            798595448101299110,
-            843048762037184773,
-            3477376418381740727,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            135296841123209059,
-            15983005158928690857,
-            1735053863563537458,
-            2717397104069879734,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3824499983312323306,
-            10133959331001686185,
-            10318617768160290410,
-            721159728793539145,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2658020946945707732,
-            14537008749294608255,
-            18026206200435821562,
-            1277378593455582247,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18271732932632394090,
-            6444862774819977587,
-            3959599984233828835,
-            2262392467269974499,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7435061788750899646,
-            8141197695537620289,
-            10078865919373845284,
-            2586939837139331597,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3427610696191742876,
-            12924006899223488022,
-            5456466276174108285,
-            1642308644181881254,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16048769728349603321,
-            4657828146277820904,
-            2685739226731413467,
-            3433571335656279952,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4284792777763926520,
-            2805532075863879728,
-            2468085126388062572,
-            2114825548767753532,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11273047237766491033,
-            634468242866976142,
-            5458665589484767575,
-            3319749623920230107,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11033986882413102928,
-            18422922612953012685,
-            16968286078251491472,
-            377638970443882535,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2814859790252758666,
-            370984245914391742,
-            9375251085828043386,
-            1784966143709665027,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8489464422611637387,
-            14307829753452333802,
-            18001980027067361154,
-            2126380506376660389,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17866187039353499859,
-            7382105059197648179,
-            17062588814430395,
-            2297333955262487308,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11160340239060622310,
-            13937564437904433678,
-            11075329017844948341,
-            2621376062124367453,
-        ]),
-        PhantomData,
-    ),
-];
-const P1615: [Fr; 16] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9713225225379204389,
-            633906485844744993,
-            9651021979456474687,
-            1567246191278229249,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4643549998437840704,
-            6044442963095116789,
-            14434082650690203127,
-            2617707230764895232,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5155881033236154805,
-            6807730470261109461,
-            10117010568775900693,
-            1115553845848045616,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3938060713478064623,
-            16747740133464488110,
-            609122402273256328,
-            1229090253028251026,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4346493522023071934,
-            12522041453327384236,
-            8598269462007313595,
-            2361520146605932457,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10629564638959874913,
-            12389617649726713823,
-            9209794828339684667,
-            1762532445727206169,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            354857937267559371,
-            12849923938123276762,
-            182895039692045796,
-            2564266917987664137,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4989647098462667424,
-            16140804100117222094,
-            18278817120169879790,
-            1308460800323577343,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            883689560346870217,
-            10058019872547332924,
-            3102898346062845087,
-            1668609978799878509,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2127267963233095284,
-            14923566905876934219,
-            2136835583064152670,
-            1622315055157406531,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3505336617965720372,
-            14471921710961341316,
-            17704360232173911184,
-            1762404514804366073,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11212441667640144233,
-            12583153244417194026,
-            13642471778899645609,
-            2925979087401881404,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6669489724362194724,
-            14472944888395820437,
-            3270039914906244770,
-            2637276671139498626,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11559259774854830797,
-            10066141268875262737,
-            8153662804438789090,
-            2034457422237782259,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3758209238916101661,
-            13794295123829142750,
-            13309503488023838856,
-            3050200688369613981,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16941615856983085547,
-            16398991440305371640,
-            11549338928212048847,
-            1969424227573212983,
-        ]),
-        PhantomData,
-    ),
-];
-const P16: [&[Fr]; 16] = [
-    &P160, &P161, &P162, &P163, &P164, &P165, &P166, &P167, &P168, &P169, &P1610, &P1611, &P1612,
-    &P1613, &P1614, &P1615,
-];
-const P170: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7509738900808394457,
-            12069945597891132194,
-            10254856263825316230,
-            2404466910474347626,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14839969842941727020,
-            3699076835550962360,
-            16317410788400315438,
-            2624800851351688130,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9044928665680305029,
-            8335178137756948509,
-            14992973348452235759,
-            3082709711124415680,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11576976438761667033,
-            3522093266057543278,
-            6727708324083298752,
-            2674771730481736565,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11962478093425570297,
-            4031390788151559658,
-            14305486330873061785,
-            227961666770232229,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17582455635513274895,
-            1462502319833852855,
-            269807439670358495,
-            1005522044267211205,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1618079706599081212,
-            2470807830745611748,
-            9097893848012091752,
-            3131546059706648066,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7431166272942052654,
-            8631808762180049072,
-            4025142131036868942,
-            2164310132402402724,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15584064084380089228,
-            8991866970246075263,
-            2368380089365035707,
-            930421890511399193,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10224267832426096572,
-            4794311192991477953,
-            15207572632969467457,
-            2909058178084935714,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1392808828963676532,
-            734732538823744948,
-            239787668552856812,
-            2770302289265701857,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17079443947245437871,
-            5902415273222306451,
-            6691969172199959257,
-            162918220705039838,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1380038205487381144,
-            15737611528851613840,
-            1211795239737435357,
-            2264581246363491486,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14704905766538725599,
-            15379772266212519263,
-            17635732888947916957,
-            491981783172672025,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17436009689200615321,
-            17363937606610114496,
-            8552202571311161532,
-            1672717898376929421,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9305084200358666274,
-            6358535643542972185,
-            12470625622722821916,
-            2035323744757416256,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6982720348674753743,
-            11908313616986175472,
-            11937680929683727968,
-            3319282434472373779,
-        ]),
-        PhantomData,
-    ),
-];
-const P171: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14807400213996732348,
-            7534035347307449447,
-            6742711082548952860,
-            2118634338427306092,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7889925973662562470,
-            10589258495873724394,
-            2059136888440154258,
-            993182695446809816,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12952324154355995599,
-            15480269244507360540,
-            9040554502842994869,
-            651431352735010113,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15781606237055502218,
-            11765036246748980268,
-            14065081388257348113,
-            2948393066873085506,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12711780236422229769,
-            2140486043355613225,
-            3554190051800955885,
-            2439404959502910714,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            373469047459484313,
-            16448317693115236958,
-            17112394483700918438,
-            2365102628450392991,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14024760417881385291,
-            9154819430792085939,
-            6810510707195489982,
-            1249968967632567001,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4701100858612386269,
-            674697903658753069,
-            15382733928147827682,
-            3437919193666670173,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14307150356373111425,
-            11411295133085902466,
-            9971665326231424335,
-            2585501431630172286,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8333881465746197042,
-            13383300867078500038,
-            52578518074801250,
-            3107928622495978144,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11712249642598622373,
-            12623466631833841335,
-            6903478860356233723,
-            2553982562347880966,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14146713582170880399,
-            7225150022709819348,
-            17186776229633871642,
-            202103484890944619,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10149598129565998025,
-            10137029369403459935,
-            13917584482803319028,
-            1836503947375839972,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13021482481108526695,
-            16991588836450631512,
-            7061170065266748077,
-            1748688758750486661,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8623322611726696918,
-            14264405170033025960,
-            16113328608083941124,
-            1161490972316279025,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9915008619707478034,
-            2921098239708990894,
-            9987365499002097285,
-            3415315021241359477,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7021190620716496358,
-            6940685674041607364,
-            401641828445474716,
-            3018330016867495149,
-        ]),
-        PhantomData,
-    ),
-];
-const P172: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13964800821219811613,
-            13680576422009058886,
-            630057608324866859,
-            2105291499492370835,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1517990166824530722,
-            9502695971983917333,
-            11712396707201274263,
-            1959314763833727463,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1304600475184630990,
-            4964487223685470997,
-            16184229671505060676,
-            947813757691260960,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3295952754413714870,
-            5920987492568190358,
-            11012314204583036600,
-            117287430663913729,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15463412907269823180,
-            7827224898349033728,
-            13774093387605270786,
-            399805320118926185,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13392050759190466637,
-            9180173474693006464,
-            11793746573075814520,
-            2613301879341762961,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2087016253713341121,
-            17626980480792017734,
-            14584447295857143405,
-            3257994236675645593,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3314914634267738026,
-            15844727741926010374,
-            8177915080573186155,
-            2554306824989076355,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16197089250536823101,
-            16322236190315375853,
-            13826770270701149243,
-            462555407163454235,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12372201324242497769,
-            4121663475145641710,
-            3595418566687593531,
-            662228774783307270,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17037446239145954239,
-            574080089123313698,
-            6726090559867970957,
-            1777991269854005255,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6796687375351446776,
-            13678141023106461079,
-            11361742870049453858,
-            2454686250687219372,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2525274641139316156,
-            17230757615434056981,
-            11881006763869720175,
-            305304148023257383,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4845737711310515592,
-            14481917855867790777,
-            2220193344254678071,
-            697556132458142097,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5070421700669584629,
-            17320929542974180390,
-            11627862743651090246,
-            17435270452258979,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2382171658729529049,
-            2732167996721259460,
-            6502344230634981462,
-            3195404326231677011,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17295455168625396132,
-            6802023030930564593,
-            3437858858366099271,
-            3372614105912492272,
-        ]),
-        PhantomData,
-    ),
-];
-const P173: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16010028876030635193,
-            9087501518425118343,
-            4026739859347155729,
-            521731017745791360,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13848362820254979470,
-            16837746370636792679,
-            3476566100208496502,
-            1675006841605098834,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11007659449872684023,
-            2010594742803001653,
-            13492520286474194541,
-            2452344953198996834,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6584679230384498487,
-            12282716102553097715,
-            234204946249953665,
-            2392369800106732676,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7324390497136982354,
-            16914542277470689206,
-            1888389045341991236,
-            3147949422744511642,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12623753776440082821,
-            17623985219768959264,
-            9741797788977358526,
-            1684534214873233797,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9647414240876773410,
-            9843682869479810431,
-            44396876166388430,
-            855312842826354152,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            410607570193098839,
-            15205597861369128724,
-            623506548338942794,
-            2311991869357809230,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15933340101184819279,
-            12480133243133055189,
-            15199914986154823852,
-            1639113083757861653,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3568545219725348309,
-            2165270008700542518,
-            12298403542356798563,
-            3417606041834699453,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13927140297269183966,
-            1105934357138801690,
-            2497764963351933967,
-            1597054765991144403,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1686941915757906152,
-            18350332549060993779,
-            319921568379065566,
-            872139741370970516,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11022258638482326437,
-            10411513547865445921,
-            11734294720069781646,
-            1466050330853769571,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12679279620859827248,
-            9003443774844294382,
-            4243543190483965295,
-            1882922187746956346,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7221122634878125561,
-            15038476757623362925,
-            1990558311254672035,
-            2185378929822678361,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17747023498408260231,
-            14797616306457936740,
-            6705046718066113464,
-            947544151604883681,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1738441128016436423,
-            15732236130663956662,
-            15240030764990895356,
-            2233063330394666043,
-        ]),
-        PhantomData,
-    ),
-];
-const P174: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17843976675890027,
-            14185765876814369472,
-            11053577711694328101,
-            2901181040691151041,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11000123568642285209,
-            9275135437319780410,
-            16563414524873252180,
-            1876424171585835763,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17815305884448451928,
-            16520345017469748479,
-            14295102855154741617,
-            1223034860153869243,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2024022765913417466,
-            1044801332717595109,
-            13434199411250343507,
-            1587816088384739019,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7255407759688492617,
-            18145081833554086874,
-            2066538403045582890,
-            2857363753839233177,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15544238648328064108,
-            3352947900349551557,
-            8999767385239208530,
-            2139879678587293049,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16697712572579169790,
-            9600479373816527123,
-            599264019940092031,
-            1621469674943198168,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6368855468244976233,
-            7753028655728634807,
-            2445880129818066094,
-            3059547003006963332,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1951562975600219036,
-            16381918729960360311,
-            5943418200216529435,
-            3250143421427484497,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6174041034833213116,
-            4431941961979030727,
-            5201338787506341521,
-            3011252153032603403,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17722764715225861278,
-            15260132644775767923,
-            2791660413259442536,
-            3252472447838611636,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8820594490173867903,
-            2544896567185323603,
-            1934328153719319401,
-            3287583881263554573,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1177054811768785201,
-            10291135477204670364,
-            14892865120950892388,
-            889877021148539530,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15708339015724192361,
-            3524122216960797844,
-            1627017353419511116,
-            2462639138222598541,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6805436734637384466,
-            15385290954775923589,
-            8018638483806952485,
-            1919714822103985575,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4602595747505337477,
-            13424737707786167313,
-            7099213152228862812,
-            903697458905205005,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1989956808986774668,
-            13436065191169343522,
-            13075730424896906237,
-            3387165121015065182,
-        ]),
-        PhantomData,
-    ),
-];
-const P175: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8962359712392566671,
-            1072354694751116696,
-            2734212620856239690,
-            1424102640040558043,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4626161636374567801,
-            11275119740874061588,
-            3764954490600476805,
-            1800587939734281546,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6273540146263748136,
-            11176501547583811570,
-            1941881591619385440,
-            464277720884993689,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12479644953806553858,
-            12612025734320560092,
-            10446647948247826489,
-            2748995246376506142,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1463836335417976141,
-            15923978350753866445,
-            5656902808357187562,
-            3054465428636087729,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5840131981231744028,
-            7079315749756059367,
-            5199499646394002498,
-            2232582336613960354,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16282072031847210947,
-            14733264551999554269,
-            10913583202888358242,
-            1007844301355076319,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1805913478810007497,
-            17224216783246095475,
-            775731096478157860,
-            952874871357402053,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4370751142724343029,
-            1053715631851758334,
-            3906666959065218997,
-            529868985536028923,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1166959175192458158,
-            14836877774346883941,
-            7990518539768710920,
-            3393952043188560764,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6431901676438977348,
-            13512999974141401666,
-            18270840520356266162,
-            1080802793807967035,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11592290662902328661,
-            7537160493077422237,
-            11763088523801882124,
-            479107987604643983,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15595853493663340618,
-            16586695283425065376,
-            1355855925786198770,
-            261744237081623553,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10451897833501896714,
-            13276261245533116647,
-            4909391738665600108,
-            1507987196877501035,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12943041350048523014,
-            8123189618999994340,
-            7200782668201370426,
-            788422199420837287,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4014709780347298267,
-            17554571575787067177,
-            8336112823460154873,
-            3328761529427379197,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16166300426765734749,
-            17851490492828838475,
-            4623069798621512335,
-            3311495773429086710,
-        ]),
-        PhantomData,
-    ),
-];
-const P176: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10347760676854439235,
-            9423596666695201885,
-            3792821104326166909,
-            3103433941109480837,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8013355620313945458,
-            15039076219545040008,
-            9447967589658583124,
-            1870764764185932966,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1943027833500460896,
-            3727116634269695867,
-            5780414490654616764,
-            1265740108088538613,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1125904358573789497,
-            2810352537341655453,
-            7968232328209191981,
-            1583629325433440530,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12266366393099422638,
-            6022188658627846530,
-            12179618178746885798,
-            3281780952653621930,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9407868214547445841,
-            4536292329175995929,
-            5988221808763922839,
-            2345998286354594083,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7838603995253007806,
-            11696478158601121356,
-            2803956159653343199,
-            2415015125063431217,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10378611550746265919,
-            3964360925297150586,
-            11193823520667098832,
-            1951935592958218224,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10194858803066564311,
-            10517009154576730693,
-            12930142173871572620,
-            485190274517521248,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2355600460966232092,
-            582805660007453365,
-            14955141963294107566,
-            900128180051615042,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16786627915790796291,
-            12647811231592155497,
-            17378585799648130182,
-            1981512566810356249,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14877566636010411338,
-            6896659344629263467,
-            5343153181936683678,
-            804483812718013123,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13559768262511617813,
-            3320352155294232031,
-            8509227540646399757,
-            660090867418654136,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13067301164577168769,
-            11204587701925860174,
-            961690103851370486,
-            1304034411893351648,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10876166479201909948,
-            7461727282617574731,
-            1851667743619452426,
-            1593979844796782394,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15128277022064538491,
-            1219850770370894332,
-            2252480282406083529,
-            2591479565742211447,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14579969565761440049,
-            8856655206752436142,
-            18197934934525819562,
-            1695565211291755971,
-        ]),
-        PhantomData,
-    ),
-];
-const P177: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9026664941482825374,
-            18414524129333280965,
-            12321692502665862481,
-            893806419902097008,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17466642662253541978,
-            2770214342568519440,
-            15190185503975170967,
-            323438746246231864,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11576097076085798499,
-            1988421862493355313,
-            10045782483164856301,
-            2070829018681831814,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10980268152667297969,
-            3297418057992138618,
-            10657234895476722660,
-            2216553772247952467,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8088276566975628524,
-            10971709892139242048,
-            3923999263307406862,
-            2564938415074383485,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17817773569462166838,
-            9788641075816451470,
-            9054081227938224239,
-            3048807088135570476,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5735170974445936225,
-            14404636621799126262,
-            1278640384965962675,
-            1340030600645289155,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            969893979396305882,
-            3163547345182950111,
-            8295470680750033059,
-            2163543567695304417,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13230235457299097795,
-            17325525752994180968,
-            15366445848786366136,
-            1976533661457538941,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            635941424976778868,
-            2173328778831307218,
-            3377231779370285588,
-            2374366883737984523,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            32158181867109264,
-            791872821717677985,
-            13018856984544855220,
-            1736962805463576477,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6274559754328110294,
-            8444366734713806028,
-            15952455940647912770,
-            1823004718087374765,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7974535375779398565,
-            13839575862002835714,
-            4513363222260280088,
-            1516290463846359405,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1972466191478178479,
-            1412635525620274499,
-            10705846971482596976,
-            529561027890862559,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14500228286747787777,
-            3379925002878367984,
-            9614757847630325849,
-            936612746183522265,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2375380875698471711,
-            13768517083220460066,
-            9666019931483604147,
-            2601669289222744423,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14066403220966541801,
-            5679602830265856996,
-            8343050392311122183,
-            2509578034522602552,
-        ]),
-        PhantomData,
-    ),
-];
-const P178: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6936385237647825858,
-            18107166577111658366,
-            13076626433606426219,
-            3479950506014382567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2755907281910894550,
-            17281652330884541143,
-            4720025366153375916,
-            2612750097562040078,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9136537863827154587,
-            17430689975564113804,
-            16359001023247007379,
-            2179748487055308494,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            513405461947811110,
-            5919523666210903444,
-            13627555740733587854,
-            2626511570293254254,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5032258080060055993,
-            18363951822594884105,
-            9726603620508125990,
-            406633536084791837,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13984817341141386169,
-            2033718811561006850,
-            8127520767607139052,
-            1672596527695457982,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5770576659456729155,
-            11526600063286325272,
-            1514192421082667959,
-            1238757482211804738,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5888765082547527094,
-            9973597242445114692,
-            17280418443599378756,
-            326578307359486791,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4468710442075585931,
-            8388920030950015629,
-            7941455941348714045,
-            1507568189778792100,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3462001987480652444,
-            5886993412589644451,
-            3677918045448152612,
-            1015759300138990153,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9219325799002099331,
-            17717707407749103125,
-            13581392262382400716,
-            1593261331684225469,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16614812691950711435,
-            15955922747604505118,
-            12804572418201432378,
-            3268786199902459459,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4384912497537975927,
-            13304580954502876656,
-            4619813662469937794,
-            1093891562213982716,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14394947624696515344,
-            10006511813352043106,
-            14164232369234767357,
-            2994173229370402386,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            909099658113729714,
-            16206048315156274334,
-            4705881424496794533,
-            282469989147427365,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2894140568125404064,
-            10128481046517085394,
-            13494956291878345738,
-            2800658890500222566,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16556895707040047238,
-            2055469472600370058,
-            14777128565728064265,
-            2819248935769946734,
-        ]),
-        PhantomData,
-    ),
-];
-const P179: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15875821579124406995,
-            12453489076962869415,
-            17465002163759762573,
-            1600346134009736086,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6959212782681004270,
-            13319278642537594031,
-            1350495913017042508,
-            1597543634922882693,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            548563337745109827,
-            4204350922102022204,
-            15810762473345558471,
-            2561301427273453367,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8964236249458324126,
-            3624629578532116060,
-            12506324922211225781,
-            3286041530708960803,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            830419348260554501,
-            3638569842719542783,
-            11691660031050212861,
-            930745089723416112,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11442201486423675923,
-            10108479521906425653,
-            2074010385612641606,
-            2715862975347349681,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16527773466809894880,
-            2536130179138296691,
-            12794846860119985943,
-            1345645660727176548,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17699451475823717973,
-            7713904858334342456,
-            4156660243006107053,
-            2109510335180201039,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2081325290849203892,
-            16770564829010138210,
-            14812125220658379515,
-            2530837554624323773,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6255053700018129488,
-            7311746856514282143,
-            17876161613677281748,
-            1394384170867391506,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15287096087720452463,
-            3054791100034342470,
-            5083207795716323125,
-            268913957598360682,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8935338434958106537,
-            15911468662605631022,
-            5916465171048478502,
-            1429941875666837157,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2937679341223539349,
-            15969841354661582291,
-            2363320895340018409,
-            1663239038211243365,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9550630105250367825,
-            1936458685247070712,
-            15708272051085805080,
-            1686814998829805730,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9878217417502372930,
-            2624894630835871741,
-            1679859666504615070,
-            115840152237473524,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9988775145146832489,
-            11383871670667378381,
-            201472669841778552,
-            416545113871779409,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2853772221447383443,
-            5789844093629327545,
-            14189363176671025337,
-            461270533058420719,
-        ]),
-        PhantomData,
-    ),
-];
-const P1710: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7633460385350008620,
-            7457532700490967957,
-            12691704734336738979,
-            1801323825567327878,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13656161801943066033,
-            10610113893289117601,
-            17346081066583202456,
-            387365981133650498,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2381780593951295320,
-            7688470876321043514,
-            11628586761757786114,
-            1171701644330859144,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12418567258545914883,
-            17455407585432422465,
-            11567411611284562551,
-            567695243211547875,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5998513499927916553,
-            2405008713655185004,
-            4958237407209700139,
-            1339298568796648838,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6771911313487059656,
-            10295637062653411619,
-            14099460123244064264,
-            658785109862564417,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13953564122799649805,
-            8581826171094374743,
-            7439606078640615332,
-            2394655911090073928,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8657150337355072359,
-            2774596959137707006,
-            1546780166226808259,
-            2966852889250561608,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11575463731998238043,
-            9693458358264721274,
-            4588670754112362245,
-            2817918619445154169,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13358209094138364908,
-            12567858267713723450,
-            11090689090840144060,
-            1876804114896710658,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5172355003648654434,
-            17872031172682539504,
-            15391715667679176747,
-            3181049688903252586,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            134955336629903476,
-            11656377532395343335,
-            2770551372270288821,
-            3087561537575091856,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15643682867511928246,
-            3577151697679036076,
-            12439051012318772780,
-            2824347059306588599,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5860943614077363731,
-            13791430928083582665,
-            7016140361403836995,
-            2798191923920341403,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2832173654918604271,
-            18133207892759063192,
-            3312237751772064631,
-            1683795938766023342,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3789322970767860606,
-            18319261381805717037,
-            6286011458488120356,
-            909649801402515341,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2585059372523194350,
-            13242376769824658910,
-            15910384282538400753,
-            2947242133428091558,
-        ]),
-        PhantomData,
-    ),
-];
-const P1711: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6615612638820873395,
-            9056704868409220780,
-            15915706108025586032,
-            3436015746305878708,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2431884245711009531,
-            10546196763190583045,
-            9764223061780354942,
-            2579180337499022152,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13011674121713720654,
-            11409128962806046735,
-            3729213870894045044,
-            1860864274572032363,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13173498472925615951,
-            8502807162221913549,
-            6916157240241092818,
-            2622626961668854302,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7776427038220477248,
-            3633531585563256499,
-            4833934217869583072,
-            1928335291948737394,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18133079887828067242,
-            4418116731606489673,
-            10076855031894641079,
-            4597435025847222,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4006008098634086803,
-            14827147534055737377,
-            152029941510158958,
-            420754132396497882,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17569567228060386116,
-            5864425539591992391,
-            17425813953723299890,
-            641993299123793588,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2633024011121958055,
-            2579185281481318950,
-            5497532637315199080,
-            900334421846685850,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12711845562650414361,
-            34302326386165085,
-            7947205603753883572,
-            1472483877677656182,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10633210491634113943,
-            625768811089349325,
-            9781538884912115377,
-            2202285594593859404,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11234196656990647846,
-            8673899208491859047,
-            17459139080901457970,
-            2020576412404658411,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13600932497182210468,
-            9754118516712469410,
-            10613494279148777657,
-            3442142571146289903,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6780091002705189216,
-            7918134624021202247,
-            7268325783374859805,
-            5493642213947958,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13452858030855682549,
-            16029030628184333200,
-            1195986458192302059,
-            507204928980855885,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3393321420444301653,
-            8395600531594134029,
-            8333013686697461489,
-            538227706606520010,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            937059912533024235,
-            9690888492645165488,
-            4542682729090318713,
-            3220640743586812941,
-        ]),
-        PhantomData,
-    ),
-];
-const P1712: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2649659702728107467,
-            3232901458470303920,
-            12511534368948009668,
-            1241368225692300359,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5105067969038822769,
-            7953121350299673986,
-            10184359659379817459,
-            426025059795121819,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8859535130269481316,
-            367809128910206739,
-            554591572087736045,
-            2863496971899630698,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16856848245690434006,
-            2269145019427513335,
-            17722144863912695974,
-            3133645427207393951,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1027785133481098327,
-            784340224392528502,
-            2975860695267064997,
-            3226301231688703006,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6938632452375346776,
-            1814261822845456032,
-            13270758358806174016,
-            2285312015095445064,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3880065006785447963,
-            6627197266060341418,
-            12447491552570335498,
-            1474174178671987412,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1244137743194415065,
-            11646703732948594509,
-            6097448289474682445,
-            2708379625218838467,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8323927147422321206,
-            2654878922151738827,
-            16496764797212038163,
-            2813783976945344652,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8993629141313091787,
-            6693007755979938150,
-            7934733675057632924,
-            753879752411147729,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10081535254567000702,
-            5930983695684080586,
-            5270139621204503813,
-            1066491032627029144,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9341599760495136132,
-            1935511573790304171,
-            2960863532205886837,
-            292129717155181625,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11315302296407873554,
-            15709635090365605360,
-            14531755479708658598,
-            3233252968386858055,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4307260388490349569,
-            4592071735410961204,
-            13435929444533087090,
-            2486830166651696329,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2723871683970955196,
-            4831987080534846338,
-            13272320747392967343,
-            3317135762467205376,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12899851203774168065,
-            9070130244477154112,
-            3128636263459765597,
-            2303783574047706817,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6386656778067386241,
-            11886983395616408828,
-            8109130796753306486,
-            3249730569731311519,
-        ]),
-        PhantomData,
-    ),
-];
-const P1713: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5267429332875896006,
-            14867135497416155565,
-            5086769266749930113,
-            1042226901928687897,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10774444542709431662,
-            9818749597438468921,
-            5127682570139692674,
-            2553173140377812984,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7731041662147135928,
-            4400705183958763627,
-            3639953944290080397,
-            1615719330825215260,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1607771112220351026,
-            4137150397506259038,
-            12766204133190481971,
-            2026851915390429269,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5569673299588936181,
-            17070086508245082566,
-            17107277677574624584,
-            528621069388114395,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17343218861899177406,
-            1195643981684406783,
-            917783099593783580,
-            1707170097622205642,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16237035207396053217,
-            12286850841587675156,
-            5421689322541461495,
-            626048239902374802,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11853759203096152345,
-            2667192960082390079,
-            303894481577722432,
-            2264405722638249198,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5116894069956116025,
-            17029576996516041124,
-            11454453583119716943,
-            1066583923769250369,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5767886997519006709,
-            15855926616920121314,
-            139580794564962546,
-            1864165961257693319,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7028157979319793059,
-            5825354182405976864,
-            9436598836812835349,
-            349634644382001358,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8555009068359898127,
-            10213345510951749918,
-            6392350522537055657,
-            1384263736247158144,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14615575028705088916,
-            16303321306396205803,
-            2733896862237243994,
-            804650297904434788,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            124879720454744448,
-            2693814171264559741,
-            3097766244747938621,
-            486997570953776300,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11123418780170719062,
-            11622127421402136703,
-            8520399009011055567,
-            745674527577431567,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13584725809031042853,
-            11108042190180861237,
-            6073689097352333258,
-            1306468961064697533,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10032612690928578432,
-            13953517427093413955,
-            4726267514195158020,
-            929773745385296052,
-        ]),
-        PhantomData,
-    ),
-];
-const P1714: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14594285462311234944,
-            12492618555970032832,
-            555236006455689258,
-            347986708946078783,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            1498967175618588633,
-            1753971240203755906,
-            6995473892948942165,
-            2375341060930480897,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17916076813051963120,
-            6448763912092775135,
-            9605318605134121658,
-            2909383458806588183,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6021821593137518009,
-            12458085295149635308,
-            2522733895688495353,
-            2652682062343193987,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9175301361081025656,
-            4670855077271758565,
-            4517292019109093758,
-            62379707322188808,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            4443775264138213430,
-            5474122403502528976,
-            11944046653898823303,
-            2252548512425015947,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3270608626659137775,
-            17975258574436933724,
-            1905754292593147118,
-            1994395123915061483,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6478878266282644566,
-            18111373150782961737,
-            14705811054721586078,
-            1873602936131447106,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8614943597286618919,
-            16092401024181090491,
-            16727881476037424588,
-            1420239988641056075,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7446220912339254649,
-            4370338094680903471,
-            4559329528648509436,
-            1423797476724357997,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            9930642479621239835,
-            1254541965462462704,
-            1322034304716504264,
-            1308718795073409192,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5565215824223158672,
-            14409101903604906084,
-            15130728487120611239,
-            1684795253851495965,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8409031462439280305,
-            9723373474683077281,
-            3331869469984089126,
-            1449898214774599032,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3202367226750034567,
-            6525398806633034372,
-            17212231341160387447,
-            1547033446648010858,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8217891889472727424,
-            530039782118450239,
-            3308725892624813698,
-            1354917358323765492,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            10441401402278033314,
-            13099437763254255199,
-            10253628959270072165,
-            866648153067397958,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13941719754382529722,
-            10212198180199516103,
-            701736543572439418,
-            1808024067937623385,
-        ]),
-        PhantomData,
-    ),
-];
-const P1715: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13540243165774915580,
-            15000697673428297574,
-            5889795678216550192,
-            1977993247873331836,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8514865122578777359,
-            11187135751529313260,
-            18125963000648436840,
-            2793623617677124138,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2180393879168824101,
-            7456388043373131719,
-            12752894929295861625,
-            121207522400008865,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6296339300965357286,
-            4634869416625443192,
-            2043173845948469515,
-            2726387151421246054,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5742881152740896751,
-            9222200151752690082,
-            11566227277835243346,
-            55059289875586762,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13580044194958635170,
-            3527762365448131073,
-            16472230302710580337,
-            1122329453058323323,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5079978008868247237,
-            8267823657444096201,
-            16366931485579345161,
-            2313237729019360340,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7205601738051499569,
-            4294204003219402864,
-            2793766671882526945,
-            168901576641839669,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            13122848853027889325,
-            8525743643026015360,
-            12400469086771582472,
-            2910159512041939411,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2411113131123376893,
-            15974759002284540361,
-            2567619635877963299,
-            2893444702505434939,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            5759052826666373808,
-            8382738170069193700,
-            9193283897685073680,
-            2326601710330861488,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            8142559487360920477,
-            5364807471598984128,
-            6487224133458585518,
-            1238407975695058840,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7232679637743328258,
-            17866719085919608241,
-            12056738772566778029,
-            574858105411717408,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            18172025771802518174,
-            8267874743375213037,
-            10143756105240030597,
-            1552995901885373917,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            684224442052634562,
-            4924102562562367999,
-            7599851587030636830,
-            797298192927630199,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3668020074425815706,
-            12418262298207564049,
-            8733798279852554635,
-            2549509058778712152,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            6115441059826699168,
-            17451458140272177639,
-            15533300266152543550,
-            2248620419455318515,
-        ]),
-        PhantomData,
-    ),
-];
-const P1716: [Fr; 17] = [
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17698487874043478000,
-            17281439531063754840,
-            3264521599287486818,
-            2801693953845797600,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            12193667773612785894,
-            18156711527574989948,
-            5394241175084760744,
-            1476556337082553289,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2346964762923983553,
-            5279664614583970008,
-            11461610704475839586,
-            1213776329900792239,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2179172372913566532,
-            7978175508625583177,
-            9759311010082666089,
-            2834079348959646028,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2274740963945998014,
-            11009217611783325046,
-            1742951945964906342,
-            1540536146596559226,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3845331980572838056,
-            9559810165281361116,
-            5350809224815512174,
-            295552387549023387,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            2302603952570732801,
-            926743663573610245,
-            17300786435965419173,
-            1391621392717172063,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            11717241795277977713,
-            11722209869753109917,
-            12634201037852625998,
-            488239206138693468,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7364220427955297582,
-            15949103956959182025,
-            1386329242383406741,
-            864715061810273575,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            14526326456296638052,
-            6647530653073205283,
-            9059132033603137039,
-            118462531162390783,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16589535195651654364,
-            5681079861334536515,
-            7054672627130134204,
-            3305023740571434009,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            15157730715417377791,
-            15749033798420732868,
-            10112028850788900594,
-            2914400804926684950,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            17194511436558460154,
-            4033644067836230894,
-            2050002967338142952,
-            2883372637274934195,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            16964901153601299483,
-            17810322647678893157,
-            7614313442925815313,
-            799764107783416778,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7268429003979484979,
-            867088860709880223,
-            12860726007530284609,
-            2379085425071662442,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            7131990780095069180,
-            5567094444812400514,
-            10291681982083201597,
-            1955266561735905770,
-        ]),
-        PhantomData,
-    ),
-    Fp::<MontBackend<FrConfig, 4>, 4>(
-        BigInt::<4>([
-            3683357907947878753,
-            2328172226548238944,
-            1553090330160049959,
-            2921662493859341010,
-        ]),
-        PhantomData,
-    ),
-];
-const P17: [&[Fr]; 17] = [
-    &P170, &P171, &P172, &P173, &P174, &P175, &P176, &P177, &P178, &P179, &P1710, &P1711, &P1712,
-    &P1713, &P1714, &P1715, &P1716,
-];
-pub const P: [&[&[Fr]]; 16] = [
-    &P2, &P3, &P4, &P5, &P6, &P7, &P8, &P9, &P10, &P11, &P12, &P13, &P14, &P15, &P16, &P17,
-];
@@ -0,0 +1,101 @@
+use super::constant::{c::C, m::M, p::P, s::S, N_ROUNDS_F, N_ROUNDS_PS};
+use ark_bn254::Fr;
+use ark_ff::Field;
+use num_traits::identities::Zero;
+
+#[inline(always)]
+fn sigma(x: &mut Fr) {
+    let x2 = x.square();
+    *x *= x2.square();
+}
+
+#[inline(always)]
+fn full_round<const INPUT_LEN_PLUS_ONE: usize>(
+    state: &mut [Fr; INPUT_LEN_PLUS_ONE],
+    c: &[Fr],
+    m: &[&[Fr; INPUT_LEN_PLUS_ONE]; INPUT_LEN_PLUS_ONE],
+) {
+    for (x, c) in state.iter_mut().zip(c) {
+        sigma(x);
+        *x += c;
+    }
+    mix(state, m);
+}
+
+#[inline(always)]
+fn mix<const INPUT_LEN_PLUS_ONE: usize>(
+    state: &mut [Fr; INPUT_LEN_PLUS_ONE],
+    m: &[&[Fr; INPUT_LEN_PLUS_ONE]; INPUT_LEN_PLUS_ONE],
+) {
+    let mut y = [Fr::zero(); INPUT_LEN_PLUS_ONE];
+    for (x, row) in state.iter().zip(m) {
+        for (y, m) in y.iter_mut().zip(row.iter()) {
+            *y += *m * x;
+        }
+    }
+    *state = y;
+}
+
+// the rows of a matrix of the tables as arrays of the width, so that the rounds need no bounds checks
+fn rows<const INPUT_LEN_PLUS_ONE: usize>(
+    m: &'static [&'static [Fr]],
+) -> [&'static [Fr; INPUT_LEN_PLUS_ONE]; INPUT_LEN_PLUS_ONE] {
+    core::array::from_fn(|i| m[i][..INPUT_LEN_PLUS_ONE].try_into().unwrap())
+}
+
+// the same rounds as `permute` in lib.rs, with the constants of the width looked up once per call
+// and the squarings of sigma unrolled. only used for the widths of the trees and leaves, 3 to 5
+pub fn permute<const INPUT_LEN_PLUS_ONE: usize>(
+    initial_state: Fr,
+    input: &[Fr],
+) -> [Fr; INPUT_LEN_PLUS_ONE] {
+    let n_rounds_p = N_ROUNDS_PS[INPUT_LEN_PLUS_ONE - 2];
+    let m = rows::<INPUT_LEN_PLUS_ONE>(M[INPUT_LEN_PLUS_ONE - 2]);
+    let p = rows::<INPUT_LEN_PLUS_ONE>(P[INPUT_LEN_PLUS_ONE - 2]);
+    let (c_first, c_rest) = C[INPUT_LEN_PLUS_ONE - 2].split_at(INPUT_LEN_PLUS_ONE);
+    let (c_first_half, c_rest) = c_rest.split_at(N_ROUNDS_F / 2 * INPUT_LEN_PLUS_ONE);
+    let (c_partial, c_last_half) = c_rest.split_at(n_rounds_p);
+    let s = &S[INPUT_LEN_PLUS_ONE - 2][..(INPUT_LEN_PLUS_ONE * 2 - 1) * n_rounds_p];
+
+    let mut state = [Fr::zero(); INPUT_LEN_PLUS_ONE];
+    state[0] = initial_state;
+    state[1..].copy_from_slice(&input[..INPUT_LEN_PLUS_ONE - 1]);
+    for (x, c) in state.iter_mut().zip(c_first) {
+        *x += c;
+    }
+
+    let mut c_rounds = c_first_half.chunks_exact(INPUT_LEN_PLUS_ONE);
+    for c in c_rounds.by_ref().take(N_ROUNDS_F / 2 - 1) {
+        full_round(&mut state, c, &m);
+    }
+    full_round(&mut state, c_rounds.next().unwrap(), &p);
+
+    for (c, s) in c_partial
+        .iter()
+        .zip(s.chunks_exact(INPUT_LEN_PLUS_ONE * 2 - 1))
+    {
+        sigma(&mut state[0]);
+        state[0] += c;
+        let (s_row, s_col) = s.split_at(INPUT_LEN_PLUS_ONE);
+        let x0 = state[0];
+        let mut y0 = Fr::zero();
+        for (x, s) in state.iter().zip(s_row) {
+            y0 += *s * x;
+        }
+        for (x, s) in state[1..].iter_mut().zip(s_col) {
+            *x += x0 * s;
+        }
+        state[0] = y0;
+    }
+
+    for c in c_last_half
+        .chunks_exact(INPUT_LEN_PLUS_ONE)
+        .take(N_ROUNDS_F / 2 - 1)
+    {
+        full_round(&mut state, c, &m);
+    }
+    for x in state.iter_mut() {
+        sigma(x);
+    }
+    state
+}
@@ -1,31 +1,61 @@
+#![no_std]
+extern crate alloc;
+
 use self::constant::{c::C, m::M, p::P, N_ROUNDS_F, N_ROUNDS_PS};
 use self::ops::{ark, mix, mix_last, mix_s, sigma};
+use alloc::{format, string::String, vec, vec::Vec};
 use ark_bn254::Fr;
 use num_traits::identities::Zero;
 
 pub mod constant;
+mod fast;
 pub mod ops;
 
 pub fn poseidon<const INPUT_LEN_PLUS_ONE: usize>(input: &[Fr]) -> Fr {
+    let state = permute::<INPUT_LEN_PLUS_ONE>(Fr::zero(), input);
+    mix_last(&state, 0, &M)
+}
+
+// `poseidon` without the fast path, which it is checked against
+pub fn poseidon_generic<const INPUT_LEN_PLUS_ONE: usize>(input: &[Fr]) -> Fr {
+    let state = permute_generic::<INPUT_LEN_PLUS_ONE>(Fr::zero(), input);
+    mix_last(&state, 0, &M)
+}
+
+fn permute<const INPUT_LEN_PLUS_ONE: usize>(
+    initial_state: Fr,
+    input: &[Fr],
+) -> [Fr; INPUT_LEN_PLUS_ONE] {
+    match INPUT_LEN_PLUS_ONE {
+        3..=5 => fast::permute::<INPUT_LEN_PLUS_ONE>(initial_state, input),
+        _ => permute_generic::<INPUT_LEN_PLUS_ONE>(initial_state, input),
+    }
+}
+
+// the rounds of circomlib's PoseidonEx, up to the mix giving the outputs
+fn permute_generic<const INPUT_LEN_PLUS_ONE: usize>(
+    initial_state: Fr,
+    input: &[Fr],
+) -> [Fr; INPUT_LEN_PLUS_ONE] {
     let mut state = [Fr::zero(); INPUT_LEN_PLUS_ONE];
     state.iter_mut().enumerate().for_each(|(i, s)| {
         *s = match i {
-            0 => Fr::zero(),
+            0 => initial_state,
             _ => input[i - 1],
         }
     });
     state = ops::ark(state, 0);
 
     for i in 0..(N_ROUNDS_F / 2 - 1) {
-        for j in 0..INPUT_LEN_PLUS_ONE {
-            state[j] = sigma(state[j]);
+        for s in state.iter_mut() {
+            *s = sigma(*s);
         }
         state = ark(state, (i + 1) * INPUT_LEN_PLUS_ONE);
         state = mix(&state, &M);
     }
 
-    for j in 0..INPUT_LEN_PLUS_ONE {
-        state[j] = sigma(state[j]);
+    for s in state.iter_mut() {
+        *s = sigma(*s);
     }
     state = ark(state, 4 * INPUT_LEN_PLUS_ONE);
     state = mix(&state, &P);
@@ -35,8 +65,8 @@ pub fn poseidon<const INPUT_LEN_PLUS_ONE: usize>(input: &[Fr]) -> Fr {
         state = mix_s(&state, i);
     }
     for i in 0..(N_ROUNDS_F / 2 - 1) {
-        for j in 0..INPUT_LEN_PLUS_ONE {
-            state[j] = sigma(state[j]);
+        for s in state.iter_mut() {
+            *s = sigma(*s);
         }
         state = ark(
             state,
@@ -46,8 +76,107 @@ pub fn poseidon<const INPUT_LEN_PLUS_ONE: usize>(input: &[Fr]) -> Fr {
         );
         state = mix(&state, &M);
     }
-    for j in 0..INPUT_LEN_PLUS_ONE {
-        state[j] = sigma(state[j]);
+    for s in state.iter_mut() {
+        *s = sigma(*s);
     }
-    mix_last(&state, 0, &M)
+    state
+}
+
+fn check_width<const INPUT_LEN_PLUS_ONE: usize>(input_len: usize) -> Result<(), String> {
+    if INPUT_LEN_PLUS_ONE < 2 || INPUT_LEN_PLUS_ONE > M.len() + 1 {
+        return Err(format!(
+            "poseidon is not defined for a width of {}",
+            INPUT_LEN_PLUS_ONE
+        ));
+    }
+    if input_len != INPUT_LEN_PLUS_ONE - 1 {
+        return Err(format!(
+            "poseidon::<{}> takes {} inputs, not {}",
+            INPUT_LEN_PLUS_ONE,
+            INPUT_LEN_PLUS_ONE - 1,
+            input_len
+        ));
+    }
+    Ok(())
+}
+
+// like `poseidon`, but rejects an input that does not fill the width instead of panicking or ignoring the rest
+pub fn poseidon_checked<const INPUT_LEN_PLUS_ONE: usize>(input: &[Fr]) -> Result<Fr, String> {
+    check_width::<INPUT_LEN_PLUS_ONE>(input.len())?;
+    Ok(poseidon::<INPUT_LEN_PLUS_ONE>(input))
+}
+
+// circomlib's PoseidonEx(nInputs, nOuts), `poseidon` is the first output with an initial state of zero
+pub fn poseidon_ex<const INPUT_LEN_PLUS_ONE: usize>(
+    initial_state: Fr,
+    input: &[Fr],
+    n_outs: usize,
+) -> Result<Vec<Fr>, String> {
+    check_width::<INPUT_LEN_PLUS_ONE>(input.len())?;
+    if n_outs == 0 || n_outs > INPUT_LEN_PLUS_ONE {
+        return Err(format!(
+            "poseidon::<{}> gives 1 to {} outputs, not {}",
+            INPUT_LEN_PLUS_ONE, INPUT_LEN_PLUS_ONE, n_outs
+        ));
+    }
+    let state = permute::<INPUT_LEN_PLUS_ONE>(initial_state, input);
+    Ok((0..n_outs).map(|i| mix_last(&state, i, &M)).collect())
+}
+
+// picks the width from the input length, for 1 to 16 inputs
+pub fn poseidon_var(input: &[Fr]) -> Result<Fr, String> {
+    match input.len() {
+        1 => poseidon_checked::<2>(input),
+        2 => poseidon_checked::<3>(input),
+        3 => poseidon_checked::<4>(input),
+        4 => poseidon_checked::<5>(input),
+        5 => poseidon_checked::<6>(input),
+        6 => poseidon_checked::<7>(input),
+        7 => poseidon_checked::<8>(input),
+        8 => poseidon_checked::<9>(input),
+        9 => poseidon_checked::<10>(input),
+        10 => poseidon_checked::<11>(input),
+        11 => poseidon_checked::<12>(input),
+        12 => poseidon_checked::<13>(input),
+        13 => poseidon_checked::<14>(input),
+        14 => poseidon_checked::<15>(input),
+        15 => poseidon_checked::<16>(input),
+        16 => poseidon_checked::<17>(input),
+        len => Err(format!("poseidon takes 1 to 16 inputs, not {}", len)),
+    }
+}
+
+// iden3's SpongeHashX with a frame of INPUT_LEN_PLUS_ONE - 1 inputs: each full frame is hashed and the
+// next one starts with its hash, a last partial frame is hashed zero padded. the frame is at least 2 inputs
+pub fn poseidon_sponge<const INPUT_LEN_PLUS_ONE: usize>(input: &[Fr]) -> Result<Fr, String> {
+    check_width::<INPUT_LEN_PLUS_ONE>(INPUT_LEN_PLUS_ONE.wrapping_sub(1))?;
+    let frame_size = INPUT_LEN_PLUS_ONE - 1;
+    if frame_size < 2 {
+        return Err(format!(
+            "the sponge needs a frame of 2 inputs or more, not {}",
+            frame_size
+        ));
+    }
+    let mut frame = vec![Fr::zero(); frame_size];
+    let mut hash = None;
+    let mut dirty = false;
+    let mut k = 0;
+    for x in input {
+        dirty = true;
+        frame[k] = *x;
+        if k == frame_size - 1 {
+            let frame_hash = poseidon::<INPUT_LEN_PLUS_ONE>(&frame);
+            frame = vec![Fr::zero(); frame_size];
+            frame[0] = frame_hash;
+            hash = Some(frame_hash);
+            dirty = false;
+            k = 1;
+        } else {
+            k += 1;
+        }
+    }
+    if dirty {
+        hash = Some(poseidon::<INPUT_LEN_PLUS_ONE>(&frame));
+    }
+    hash.ok_or(String::from("the sponge takes at least 1 input"))
 }
@@ -20,8 +20,8 @@ pub fn mix<const INPUT_LEN_PLUS_ONE: usize>(
     m: &[&[&[Fr]]; 16],
 ) -> [Fr; INPUT_LEN_PLUS_ONE] {
     let mut y = [Fr::zero(); INPUT_LEN_PLUS_ONE];
-    for i in 0..INPUT_LEN_PLUS_ONE {
-        y[i] = mix_last(x, i, m);
+    for (i, yi) in y.iter_mut().enumerate() {
+        *yi = mix_last(x, i, m);
     }
     y
 }
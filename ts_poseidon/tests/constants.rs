@@ -0,0 +1,49 @@
+use ark_bn254::Fr;
+use std::str::FromStr;
+use ts_poseidon::constant::{c::C, m::M, p::P};
+use ts_poseidon::poseidon;
+
+// the constants used to be checked in as generated tables. they are summarized by the evaluation
+// at a fixed point of the polynomial whose coefficients are all their elements, width after width
+fn fingerprint<'a>(elems: impl Iterator<Item = &'a Fr>) -> String {
+    let x = Fr::from(0x9e37_79b9_7f4a_7c15u64);
+    elems
+        .fold(Fr::from(0u64), |acc, elem| acc * x + elem)
+        .to_string()
+}
+
+#[test]
+fn round_constants_match_previous_tables() {
+    assert_eq!(
+        fingerprint(C.iter().flat_map(|c| c.iter())),
+        "19703889775149269493140982566872932606268014357785455039443516516008118321460"
+    );
+}
+
+#[test]
+fn mds_matrices_match_previous_tables() {
+    assert_eq!(
+        fingerprint(M.iter().flat_map(|m| m.iter().flat_map(|row| row.iter()))),
+        "5127486465101791958346110851571832646075153967986554479212174881719592057496"
+    );
+}
+
+#[test]
+fn pre_sparse_matrices_match_previous_tables() {
+    assert_eq!(
+        fingerprint(P.iter().flat_map(|p| p.iter().flat_map(|row| row.iter()))),
+        "19257681861942233618657433008068341593662104182324843327806829455390932709644"
+    );
+}
+
+// there was no table of the sparse matrices, they are checked through circomlibjs' poseidon([1, 2])
+#[test]
+fn sparse_matrices_give_circomlib_hash() {
+    assert_eq!(
+        poseidon::<3>(&[Fr::from(1u64), Fr::from(2u64)]),
+        Fr::from_str(
+            "7853200120776062878684798364095072458815029376092732009249414926327459813530"
+        )
+        .unwrap()
+    );
+}
@@ -0,0 +1,188 @@
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use proptest::prelude::*;
+use std::str::FromStr;
+use ts_poseidon::{poseidon_checked, poseidon_ex, poseidon_sponge, poseidon_var};
+
+fn from_str(s: &str) -> Fr {
+    Fr::from_str(s).unwrap()
+}
+
+fn input(n: usize) -> Vec<Fr> {
+    (1..=n as u64).map(Fr::from).collect()
+}
+
+// the whole output state of the permutation of [0, 1, 2] and [0, 1, 2, 3, 4], from the test vectors
+// of the reference implementation (hadeshash, poseidonperm_x5_254_3 and poseidonperm_x5_254_5)
+#[test]
+fn poseidon_ex_gives_the_reference_permutation() {
+    assert_eq!(
+        poseidon_ex::<3>(Fr::from(0u64), &input(2), 3).unwrap(),
+        [
+            "7853200120776062878684798364095072458815029376092732009249414926327459813530",
+            "7142104613055408817911962100316808866448378443474503659992478482890339429929",
+            "6549537674122432311777789598043107870002137484850126429160507761192163713804",
+        ]
+        .map(from_str)
+    );
+    assert_eq!(
+        poseidon_ex::<5>(Fr::from(0u64), &input(4), 5).unwrap(),
+        [
+            "18821383157269793795438455681495246036402687001665670618754263018637548127333",
+            "7817711165059374331357136443537800893307845083525445872661165200086166013245",
+            "16733335996448830230979566039396561240864200624113062088822991822580465420551",
+            "6644334865470350789317807668685953492649391266180911382577082600917830417726",
+            "3372108894677221197912083238087960099443657816445944159266857514496320565191",
+        ]
+        .map(from_str)
+    );
+}
+
+// no published vectors start from a non-zero state. these were computed with the plain permutation
+// of the Poseidon paper from the Grain LFSR constants, which gives the reference vectors above
+#[test]
+fn poseidon_ex_with_an_initial_state() {
+    assert_eq!(
+        poseidon_ex::<3>(Fr::from(7u64), &input(2), 3).unwrap(),
+        [
+            "7540134072963094781551849189553249853827772934652778250146343743716337940736",
+            "9416154640693989499515053031030659859852016023578551600909268811390724747953",
+            "3980480193631752437841939048985062961895463609238460158469593834428459977044",
+        ]
+        .map(from_str)
+    );
+    assert_eq!(
+        poseidon_ex::<5>(Fr::from(11u64), &input(4), 5).unwrap(),
+        [
+            "8777542044315998629624711056644057292750779077152634150858788941759345169577",
+            "19589978478128271067547081570908223466570577920078860394801728126507903369510",
+            "16589908813267510716964514738634783143548186682644955909448279272457843152866",
+            "11619027427760942477319926615686332129360838810406171652908361404834540522691",
+            "9369612599299678264886722505023021705895756012330538518205227261257096807196",
+        ]
+        .map(from_str)
+    );
+    assert_eq!(
+        poseidon_ex::<7>(-Fr::from(1u64), &input(6), 4).unwrap(),
+        [
+            "19845684931220837529303330477042037426197895648462912998349373682748393780537",
+            "3889948434505545690409378983389276161957323530851675342411735847330528436818",
+            "20010289752709232435444287190831449071894242560719957306384020100062608343976",
+            "4951189700145991955924960972213516707021792770088963580530271875669378427831",
+        ]
+        .map(from_str)
+    );
+    assert_eq!(
+        poseidon_ex::<17>(Fr::from(12345678901234567890u64), &input(16), 17).unwrap(),
+        [
+            "14952921891849221738447326088983960911146126197994180690015531831174422425969",
+            "4764475777749514761248853378630418728888448707664475414168853422260910455387",
+            "17060819501595623266809642628876068596301718475346971369350736681094758263620",
+            "15592520618465457500912381761504505827391753205425480171841935422773649581881",
+            "8990016964957489215381763662023069756014861135288995774240897441383027051139",
+            "4547025379731952878247145451851740434375764910824892923927931065085937462821",
+            "5123802946215716140498541805145024472424952504230818446181947469445675851850",
+            "10744200122517389134293504800511748279186766049944896842986459080403219394143",
+            "19530788396718577094744153059698245080551541825211635983283730353120964526710",
+            "15309393825531894230272430561070990241030025647383018135334643276454640177650",
+            "4884793110575814497993015275219650362967643792108853660857661355283061532674",
+            "2386087741719947519455551777713817880637774656506874074608726087241319678941",
+            "11820931368944841871537797022139077952718643395321969538689044587657261315989",
+            "9474238199142411395521598565082514848199096292606894167219059066604585066828",
+            "9941430531601979118064195973355327912997791471073150178695477692524286894094",
+            "2250480568705215703851301937568527553369166286873675345366059027896655086187",
+            "19591211082656435727415482604973966687191160447859037833947117052239848309553",
+        ]
+        .map(from_str)
+    );
+}
+
+// a single zero padded frame is the plain hash, as in go-iden3-crypto's tests of Hash
+#[test]
+fn sponge_of_a_single_frame() {
+    let one_two = [Fr::from(1u64), Fr::from(2u64)];
+    let three_four = [Fr::from(3u64), Fr::from(4u64)];
+    assert_eq!(
+        poseidon_sponge::<6>(&one_two).unwrap(),
+        from_str("1018317224307729531995786483840663576608797660851238720571059489595066344487")
+    );
+    assert_eq!(
+        poseidon_sponge::<7>(&one_two).unwrap(),
+        from_str("15336558801450556532856248569924170992202208561737609669134139141992924267169")
+    );
+    assert_eq!(
+        poseidon_sponge::<6>(&three_four).unwrap(),
+        from_str("5811595552068139067952687508729883632420015185677766880877743348592482390548")
+    );
+    assert_eq!(
+        poseidon_sponge::<7>(&three_four).unwrap(),
+        from_str("12263118664590987767234828103155242843640892839966517009184493198782366909018")
+    );
+    assert_eq!(
+        poseidon_sponge::<15>(&input(9)).unwrap(),
+        from_str("5540388656744764564518487011617040650780060800286365721923524861648744699539")
+    );
+    assert_eq!(
+        poseidon_sponge::<3>(&one_two).unwrap(),
+        poseidon_var(&one_two).unwrap()
+    );
+}
+
+// the sponge as the hash of the first frame, then of each next frame of frame_size - 1 inputs
+// behind the previous hash, the last one zero padded
+fn sponge_reference(input: &[Fr], frame_size: usize) -> Fr {
+    let padded = |frame: &[Fr]| {
+        let mut frame = frame.to_vec();
+        frame.resize(frame_size, Fr::from(0u64));
+        poseidon_var(&frame).unwrap()
+    };
+    let (first, mut rest) = input.split_at(input.len().min(frame_size));
+    let mut hash = padded(first);
+    while !rest.is_empty() {
+        let (next, tail) = rest.split_at(rest.len().min(frame_size - 1));
+        hash = padded(&[&[hash], next].concat());
+        rest = tail;
+    }
+    hash
+}
+
+fn inputs(len: std::ops::Range<usize>) -> impl Strategy<Value = Vec<Fr>> {
+    prop::collection::vec(any::<[u8; 32]>(), len).prop_map(|inputs| {
+        inputs
+            .iter()
+            .map(|bytes| Fr::from_le_bytes_mod_order(bytes))
+            .collect()
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn sponge_matches_reference(input in inputs(1..40)) {
+        prop_assert_eq!(poseidon_sponge::<3>(&input).unwrap(), sponge_reference(&input, 2));
+        prop_assert_eq!(poseidon_sponge::<7>(&input).unwrap(), sponge_reference(&input, 6));
+        prop_assert_eq!(poseidon_sponge::<17>(&input).unwrap(), sponge_reference(&input, 16));
+    }
+}
+
+#[test]
+fn invalid_inputs_are_rejected() {
+    assert!(poseidon_checked::<3>(&input(1)).is_err());
+    assert!(poseidon_checked::<3>(&input(3)).is_err());
+    assert!(poseidon_checked::<1>(&[]).is_err());
+    assert!(poseidon_checked::<18>(&input(17)).is_err());
+
+    assert!(poseidon_ex::<3>(Fr::from(0u64), &input(3), 1).is_err());
+    assert!(poseidon_ex::<3>(Fr::from(0u64), &input(2), 0).is_err());
+    assert!(poseidon_ex::<3>(Fr::from(0u64), &input(2), 4).is_err());
+    assert!(poseidon_ex::<18>(Fr::from(0u64), &input(17), 1).is_err());
+
+    assert!(poseidon_var(&[]).is_err());
+    assert!(poseidon_var(&input(17)).is_err());
+
+    assert!(poseidon_sponge::<3>(&[]).is_err());
+    // a frame of a single input cannot chain the hashes
+    assert!(poseidon_sponge::<2>(&input(3)).is_err());
+    assert!(poseidon_sponge::<18>(&input(3)).is_err());
+}
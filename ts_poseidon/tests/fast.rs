@@ -0,0 +1,40 @@
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use proptest::prelude::*;
+use ts_poseidon::{poseidon, poseidon_generic};
+
+fn inputs(len: usize) -> impl Strategy<Value = Vec<Fr>> {
+    prop::collection::vec(any::<[u8; 32]>(), len).prop_map(|inputs| {
+        inputs
+            .iter()
+            .map(|bytes| Fr::from_le_bytes_mod_order(bytes))
+            .collect()
+    })
+}
+
+// the fast path of the widths 3 to 5 must give the same hashes as the generic rounds
+proptest! {
+    #[test]
+    fn fast_path_of_width_3_matches_generic(input in inputs(2)) {
+        prop_assert_eq!(poseidon::<3>(&input), poseidon_generic::<3>(&input));
+    }
+
+    #[test]
+    fn fast_path_of_width_4_matches_generic(input in inputs(3)) {
+        prop_assert_eq!(poseidon::<4>(&input), poseidon_generic::<4>(&input));
+    }
+
+    #[test]
+    fn fast_path_of_width_5_matches_generic(input in inputs(4)) {
+        prop_assert_eq!(poseidon::<5>(&input), poseidon_generic::<5>(&input));
+    }
+}
+
+#[test]
+fn fast_path_matches_generic_on_edge_inputs() {
+    for x in [Fr::from(0u64), Fr::from(1u64), -Fr::from(1u64)] {
+        assert_eq!(poseidon::<3>(&[x; 2]), poseidon_generic::<3>(&[x; 2]));
+        assert_eq!(poseidon::<4>(&[x; 3]), poseidon_generic::<4>(&[x; 3]));
+        assert_eq!(poseidon::<5>(&[x; 4]), poseidon_generic::<5>(&[x; 4]));
+    }
+}
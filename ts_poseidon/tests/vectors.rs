@@ -0,0 +1,135 @@
+use ark_bn254::Fr;
+use std::str::FromStr;
+use ts_poseidon::{poseidon, poseidon_generic, poseidon_var};
+
+fn from_str(s: &str) -> Fr {
+    Fr::from_str(s).unwrap()
+}
+
+fn input(n: usize) -> Vec<Fr> {
+    (1..=n as u64).map(Fr::from).collect()
+}
+
+fn padded(input: &[u64], len: usize) -> Vec<Fr> {
+    let mut input: Vec<Fr> = input.iter().copied().map(Fr::from).collect();
+    input.resize(len, Fr::from(0u64));
+    input
+}
+
+// the fast path, the generic rounds and the width picked from the input length all give the vector
+fn check<const INPUT_LEN_PLUS_ONE: usize>(input: &[Fr], expected: &str) {
+    let expected = from_str(expected);
+    let width = INPUT_LEN_PLUS_ONE;
+    assert_eq!(
+        poseidon::<INPUT_LEN_PLUS_ONE>(input),
+        expected,
+        "poseidon::<{}>",
+        width
+    );
+    assert_eq!(
+        poseidon_generic::<INPUT_LEN_PLUS_ONE>(input),
+        expected,
+        "poseidon_generic::<{}>",
+        width
+    );
+    assert_eq!(
+        poseidon_var(input).unwrap(),
+        expected,
+        "poseidon_var of width {}",
+        width
+    );
+}
+
+// circomlibjs' own test vectors (test/poseidon.js)
+#[test]
+fn circomlibjs_vectors() {
+    check::<2>(
+        &input(1),
+        "18586133768512220936620570745912940619677854269274689475585506675881198879027",
+    );
+    check::<3>(
+        &input(2),
+        "7853200120776062878684798364095072458815029376092732009249414926327459813530",
+    );
+    check::<3>(
+        &padded(&[3, 4], 2),
+        "14763215145315200506921711489642608356394854266165572616578112107564877678998",
+    );
+    check::<5>(
+        &input(4),
+        "18821383157269793795438455681495246036402687001665670618754263018637548127333",
+    );
+}
+
+// go-iden3-crypto's test vectors of Hash, zero padded to the width
+#[test]
+fn go_iden3_vectors() {
+    check::<6>(
+        &padded(&[1, 2], 5),
+        "1018317224307729531995786483840663576608797660851238720571059489595066344487",
+    );
+    check::<6>(
+        &padded(&[3, 4], 5),
+        "5811595552068139067952687508729883632420015185677766880877743348592482390548",
+    );
+    check::<7>(
+        &padded(&[1, 2], 6),
+        "15336558801450556532856248569924170992202208561737609669134139141992924267169",
+    );
+    check::<7>(
+        &padded(&[3, 4], 6),
+        "12263118664590987767234828103155242843640892839966517009184493198782366909018",
+    );
+    check::<15>(
+        &padded(&[1, 2, 3, 4, 5, 6, 7, 8, 9], 14),
+        "5540388656744764564518487011617040650780060800286365721923524861648744699539",
+    );
+}
+
+// no vectors of the widths 4, 8 to 14, 16 and 17 were at hand. these were computed with the plain
+// permutation of the Poseidon paper, from the round constants and MDS matrices of the Grain LFSR and
+// without any of circomlib's optimizations, which gives all the published vectors above. they pin the
+// optimized rounds of these widths to the paper, not to circomlibjs
+#[test]
+fn unpublished_widths_match_plain_permutation() {
+    check::<4>(
+        &input(3),
+        "6542985608222806190361240322586112750744169038454362455181422643027100751666",
+    );
+    check::<8>(
+        &input(7),
+        "12748163991115452309045839028154629052133952896122405799815156419278439301912",
+    );
+    check::<9>(
+        &input(8),
+        "18604317144381847857886385684060986177838410221561136253933256952257712543953",
+    );
+    check::<10>(
+        &input(9),
+        "13589767895268936107593642967621470491511464502761040466226072462545218539640",
+    );
+    check::<11>(
+        &input(10),
+        "3657500514307717306974218405144578736633140001277925127187636780142269815841",
+    );
+    check::<12>(
+        &input(11),
+        "3572015662710076994097916907865950486270383304442561406230608893458731714472",
+    );
+    check::<13>(
+        &input(12),
+        "2501997477381648492950318384533644783248002172679259592360114615426357826485",
+    );
+    check::<14>(
+        &input(13),
+        "7041832639553862712666971417715061873827921493498355005117622707743491651590",
+    );
+    check::<16>(
+        &input(15),
+        "4203130618016961831408770638653325366880478848856764494148034853759773445968",
+    );
+    check::<17>(
+        &input(16),
+        "9989051620750914585850546081941653841776809718687451684622678807385399211877",
+    );
+}
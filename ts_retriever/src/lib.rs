@@ -82,8 +82,8 @@ impl EventTracer {
 
             let filter = FilterBuilder::default()
                 .address(vec![contract_address])
-                .from_block(BlockNumber::Number(start_block_id.into()))
-                .to_block(BlockNumber::Number(end_block_id.into()))
+                .from_block(BlockNumber::Number(start_block_id))
+                .to_block(BlockNumber::Number(end_block_id))
                 .topics(Some(vec![event_signature]), None, None, None)
                 .build();
             self.logs = web3.eth().logs(filter).await.map_err(|e| e.to_string())?;
@@ -118,6 +118,7 @@ impl EventTracer {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn retrieve(
     abi_json_str: &str,
     api_link: &str,
@@ -206,11 +207,11 @@ pub async fn retrieve(
     while current_executed_block_info.is_some() {
         if slt(&current_executed_block_info, &current_committed_block_info)? {
             if let Some((_, executed_l2_block_id)) = &current_executed_block_info {
-                if queue.len() != 0 {
-                    if let Some((tx_id, l2_block_id)) = queue.get(0) {
-                        if executed_l2_block_id.clone() > l2_block_id.clone() {
+                if !queue.is_empty() {
+                    if let Some((tx_id, l2_block_id)) = queue.front() {
+                        if executed_l2_block_id > l2_block_id {
                             return Err("Loading block info failed - 1".to_string());
-                        } else if executed_l2_block_id.clone() == l2_block_id.clone() {
+                        } else if executed_l2_block_id == l2_block_id {
                             let semaphore_clone = semaphore.clone();
                             let tx_id = tx_id.clone();
                             futures.push_back(async move {
@@ -234,29 +235,24 @@ pub async fn retrieve(
             current_executed_block_info = executed_envent_tracer.pop().await?;
         } else {
             if let Some((committed_tx_id, committed_l2_block_id)) = &current_committed_block_info {
-                if queue.len() == 0 {
-                    queue.push_back((committed_tx_id.clone(), committed_l2_block_id.clone()));
+                if queue.is_empty() {
+                    queue.push_back((committed_tx_id.clone(), *committed_l2_block_id));
                 } else {
-                    match queue.get(queue.len() - 1) {
+                    match queue.back() {
                         None => {
                             unreachable!()
                         }
                         Some((_, l2_block_id)) => {
                             if committed_l2_block_id > l2_block_id {
-                                queue.push_back((
-                                    committed_tx_id.clone(),
-                                    committed_l2_block_id.clone(),
-                                ));
+                                queue.push_back((committed_tx_id.clone(), *committed_l2_block_id));
                             } else {
                                 let mut i = queue.len();
                                 let mut is_changed = false;
                                 while i > 0 {
                                     if let Some((_, l2_block_id)) = queue.get(i - 1) {
                                         if committed_l2_block_id == l2_block_id {
-                                            *queue.get_mut(i - 1).unwrap() = (
-                                                committed_tx_id.clone(),
-                                                committed_l2_block_id.clone(),
-                                            );
+                                            *queue.get_mut(i - 1).unwrap() =
+                                                (committed_tx_id.clone(), *committed_l2_block_id);
                                             is_changed = true;
                                             break;
                                         } else if committed_l2_block_id > l2_block_id {
@@ -356,7 +352,7 @@ pub async fn retrieve_consume_data(
         logs.extend(new_logs);
 
         if current_block > filter_batch_size.into() {
-            current_block = current_block - web3::types::U64::from(filter_batch_size + 1);
+            current_block -= web3::types::U64::from(filter_batch_size + 1);
         } else {
             break;
         }
@@ -394,6 +390,81 @@ pub async fn retrieve_consume_data(
     Ok(result)
 }
 
+// the sender is the first indexed topic of an L1Request event, the pub_data its bytes field
+pub fn decode_l1_request(log: &Log) -> Result<([u8; 20], Vec<u8>), String> {
+    let sender = log
+        .topics
+        .get(1)
+        .ok_or("Loading L1 request sender failed")?;
+    let mut sender_addr = [0u8; 20];
+    sender_addr.copy_from_slice(&sender.0[12..]);
+
+    let param_types = vec![
+        ParamType::Uint(64),
+        ParamType::Uint(8),
+        ParamType::Bytes,
+        ParamType::Uint(32),
+    ];
+    let tokens =
+        decode(&param_types, &log.data.0).map_err(|e| format!("Error decoding log: {:?}", e))?;
+    let pub_data = match &tokens[2] {
+        Token::Bytes(data) => data.clone(),
+        _ => return Err("Error decoding log: unexpected token type".to_string()),
+    };
+    Ok((sender_addr, pub_data))
+}
+
+// returns the (sender, pub_data) of every L1 request emitted in [start_block_id, end_block_id]
+pub async fn retrieve_l1_requests(
+    api_link: &str,
+    api_key: &str,
+    ts_contract_addr: &str,
+    start_block_id: u64,
+    end_block_id: u64,
+    filter_batch_size: usize,
+) -> Result<Vec<([u8; 20], Vec<u8>)>, String> {
+    let link = format!("{}{}", api_link, api_key);
+    let http = web3::transports::Http::new(&link).map_err(|e| e.to_string())?;
+    let web3 = web3::Web3::new(http);
+
+    let contract_address: H160 = ts_contract_addr
+        .parse()
+        .map_err(|_| "Loading contract address failed")?;
+
+    let l1_req_event_signature =
+        H256::from_slice(&keccak256("L1Request(address,uint64,uint8,bytes,uint32)"));
+
+    let mut requests = vec![];
+    let mut from_block = start_block_id;
+    while from_block <= end_block_id {
+        let to_block = std::cmp::min(from_block + filter_batch_size as u64, end_block_id);
+        let filter = FilterBuilder::default()
+            .address(vec![contract_address])
+            .topics(Some(vec![l1_req_event_signature]), None, None, None)
+            .from_block(BlockNumber::Number(from_block.into()))
+            .to_block(BlockNumber::Number(to_block.into()))
+            .build();
+        let logs = web3.eth().logs(filter).await.map_err(|e| e.to_string())?;
+        for log in logs {
+            requests.push(decode_l1_request(&log)?);
+        }
+        from_block = to_block + 1;
+    }
+    Ok(requests)
+}
+
+pub async fn get_latest_l1_block_id(api_link: &str, api_key: &str) -> Result<u64, String> {
+    let link = format!("{}{}", api_link, api_key);
+    let http = web3::transports::Http::new(&link).map_err(|e| e.to_string())?;
+    let web3 = web3::Web3::new(http);
+    Ok(web3
+        .eth()
+        .block_number()
+        .await
+        .map_err(|e| e.to_string())?
+        .as_u64())
+}
+
 pub async fn is_evacuation_mod(
     api_link: &str,
     api_key: &str,
@@ -500,7 +571,7 @@ pub async fn retrieve_last_excuted_block(
 
     let mut logs: Vec<Log> = Vec::new();
     let mut current_block = latest_block;
-    while logs.len() == 0 {
+    while logs.is_empty() {
         let from_block: BlockNumber = if current_block > filter_batch_size.into() {
             (current_block - web3::types::U64::from(filter_batch_size)).into()
         } else {
@@ -517,7 +588,7 @@ pub async fn retrieve_last_excuted_block(
         logs = web3.eth().logs(filter).await.map_err(|e| e.to_string())?;
 
         if current_block > filter_batch_size.into() {
-            current_block = current_block - web3::types::U64::from(filter_batch_size + 1);
+            current_block -= web3::types::U64::from(filter_batch_size + 1);
         } else {
             break;
         }
@@ -0,0 +1,63 @@
+use ethabi::{encode, Token};
+use ts_retriever::decode_l1_request;
+use web3::types::{Bytes, Log, H160, H256, U256};
+
+// an L1Request(address indexed sender, uint64 requestId, uint8 opType, bytes pubData, uint32 ...) log
+fn log(topics: Vec<H256>, data: Vec<u8>) -> Log {
+    Log {
+        address: H160::repeat_byte(0x11),
+        topics,
+        data: Bytes(data),
+        block_hash: None,
+        block_number: None,
+        transaction_hash: None,
+        transaction_index: None,
+        log_index: None,
+        transaction_log_index: None,
+        log_type: None,
+        removed: None,
+    }
+}
+
+fn data(pub_data: &[u8]) -> Vec<u8> {
+    encode(&[
+        Token::Uint(U256::from(7u64)),
+        Token::Uint(U256::from(1u64)),
+        Token::Bytes(pub_data.to_vec()),
+        Token::Uint(U256::from(3u64)),
+    ])
+}
+
+fn sender_topic(sender: [u8; 20]) -> H256 {
+    let mut topic = [0u8; 32];
+    topic[12..].copy_from_slice(&sender);
+    H256(topic)
+}
+
+#[test]
+fn l1_request_is_decoded() {
+    let sender = [0xab; 20];
+    // pub data longer than a word, as those of the registrations
+    let pub_data: Vec<u8> = (0..75u8).collect();
+    let log = log(
+        vec![H256::repeat_byte(0x01), sender_topic(sender)],
+        data(&pub_data),
+    );
+    assert_eq!(decode_l1_request(&log).unwrap(), (sender, pub_data));
+}
+
+#[test]
+fn malformed_l1_request_is_rejected() {
+    let pub_data = [1u8, 2, 3];
+    // no sender topic
+    let without_sender = log(vec![H256::repeat_byte(0x01)], data(&pub_data));
+    assert!(decode_l1_request(&without_sender).is_err());
+    // truncated data
+    let mut truncated = data(&pub_data);
+    truncated.truncate(100);
+    let truncated = log(
+        vec![H256::repeat_byte(0x01), sender_topic([0xab; 20])],
+        truncated,
+    );
+    assert!(decode_l1_request(&truncated).is_err());
+}
@@ -1,12 +1,27 @@
 use super::{
+    constants::Params,
     token::{Token, TokenTree},
     Array,
 };
+use alloc::{format, string::String, vec, vec::Vec};
 use ark_bn254::Fr;
 use num_traits::{One, Zero};
 use ts_merkle_tree::{MerkleLeaf, MerkleTree, MerkleTreeWithLeaves};
 use ts_poseidon::poseidon;
 
+#[derive(Clone, Copy, Debug)]
+pub struct BalanceChange {
+    pub acc_id: u64,
+    pub token_id: u64,
+    pub avl_amt: Fr,
+    pub locked_amt: Fr,
+}
+
+// the leaf of an account in the account tree, from the root of its token tree
+pub fn calc_account_digest(l2_addr: Fr, nonce: Fr, token_root: Fr) -> Fr {
+    poseidon::<5>(&[Fr::from(3u64), l2_addr, nonce, token_root])
+}
+
 pub struct Account<TokenTreeNodes: Array<Option<Fr>>, Tokens: Array<Token>> {
     pub l2_addr: Fr,
     pub nonce: Fr,
@@ -16,10 +31,17 @@ impl<TokenTreeNodes: Array<Option<Fr>> + Default, Tokens: Array<Token> + Default
     for Account<TokenTreeNodes, Tokens>
 {
     fn default() -> Self {
+        Self::new(super::constants::TOKEN_TREE_HEIGHT)
+    }
+}
+impl<TokenTreeNodes: Array<Option<Fr>> + Default, Tokens: Array<Token> + Default>
+    Account<TokenTreeNodes, Tokens>
+{
+    pub fn new(token_tree_height: usize) -> Self {
         Self {
             l2_addr: Fr::zero(),
             nonce: Fr::zero(),
-            tokens: TokenTree::default(),
+            tokens: TokenTree::new(token_tree_height),
         }
     }
 }
@@ -28,8 +50,8 @@ impl<TokenTreeNodes: Array<Option<Fr>> + Clone, Tokens: Array<Token> + Clone> Cl
 {
     fn clone(&self) -> Self {
         Self {
-            l2_addr: self.l2_addr.clone(),
-            nonce: self.nonce.clone(),
+            l2_addr: self.l2_addr,
+            nonce: self.nonce,
             tokens: self.tokens.clone(),
         }
     }
@@ -38,12 +60,14 @@ impl<TokenTreeNodes: Array<Option<Fr>>, Tokens: Array<Token>> MerkleLeaf<Fr>
     for Account<TokenTreeNodes, Tokens>
 {
     fn digest(&self) -> Result<Fr, String> {
-        Ok(poseidon::<5>(&[
-            Fr::from(3u64),
+        Ok(calc_account_digest(
             self.l2_addr,
             self.nonce,
             self.tokens.get_root()?,
-        ]))
+        ))
+    }
+    fn commit(&mut self) -> Result<(), String> {
+        self.tokens.commit()
     }
 }
 impl<TokenTreeNodes: Array<Option<Fr>>, Tokens: Array<Token>> Account<TokenTreeNodes, Tokens> {
@@ -57,26 +81,69 @@ impl<TokenTreeNodes: Array<Option<Fr>>, Tokens: Array<Token>> Account<TokenTreeN
     }
     pub fn income(&mut self, token_id: u64, amt: Fr) -> Result<(), String> {
         self.tokens
-            .update(token_id, |token| Ok(token.income(amt)?))?;
+            .update_deferred(token_id, |token| token.income(amt))?;
         Ok(())
     }
     pub fn outgo(&mut self, token_id: u64, amt: Fr) -> Result<(), String> {
         self.tokens
-            .update(token_id, |token| Ok(token.outgo(amt)?))?;
+            .update_deferred(token_id, |token| token.outgo(amt))?;
         Ok(())
     }
     pub fn lock(&mut self, token_id: u64, amt: Fr) -> Result<(), String> {
-        self.tokens.update(token_id, |token| Ok(token.lock(amt)?))?;
+        self.tokens
+            .update_deferred(token_id, |token| token.lock(amt))?;
         Ok(())
     }
     pub fn unlock(&mut self, token_id: u64, amt: Fr) -> Result<(), String> {
         self.tokens
-            .update(token_id, |token| Ok(token.unlock(amt)?))?;
+            .update_deferred(token_id, |token| token.unlock(amt))?;
         Ok(())
     }
     pub fn deduct(&mut self, token_id: u64, amt: Fr) -> Result<(), String> {
         self.tokens
-            .update(token_id, |token| Ok(token.deduct(amt)?))?;
+            .update_deferred(token_id, |token| token.deduct(amt))?;
+        Ok(())
+    }
+}
+
+// an account changed through `AccountTree::update_account`, with the changes of its balances
+pub struct AccountUpdate<TokenTreeNodes: Array<Option<Fr>>, Tokens: Array<Token>> {
+    account: Account<TokenTreeNodes, Tokens>,
+    // (token_id, avl_amt delta, locked_amt delta), negative deltas are field negations
+    balance_changes: Vec<(u64, Fr, Fr)>,
+}
+impl<TokenTreeNodes: Array<Option<Fr>>, Tokens: Array<Token>>
+    AccountUpdate<TokenTreeNodes, Tokens>
+{
+    pub fn set_l2_addr(&mut self, l2_addr: Fr) -> Result<(), String> {
+        self.account.set_l2_addr(l2_addr)
+    }
+    pub fn increase_nonce(&mut self) -> Result<(), String> {
+        self.account.increase_nonce()
+    }
+    pub fn income(&mut self, token_id: u64, amt: Fr) -> Result<(), String> {
+        self.account.income(token_id, amt)?;
+        self.balance_changes.push((token_id, amt, Fr::zero()));
+        Ok(())
+    }
+    pub fn outgo(&mut self, token_id: u64, amt: Fr) -> Result<(), String> {
+        self.account.outgo(token_id, amt)?;
+        self.balance_changes.push((token_id, -amt, Fr::zero()));
+        Ok(())
+    }
+    pub fn lock(&mut self, token_id: u64, amt: Fr) -> Result<(), String> {
+        self.account.lock(token_id, amt)?;
+        self.balance_changes.push((token_id, -amt, amt));
+        Ok(())
+    }
+    pub fn unlock(&mut self, token_id: u64, amt: Fr) -> Result<(), String> {
+        self.account.unlock(token_id, amt)?;
+        self.balance_changes.push((token_id, amt, -amt));
+        Ok(())
+    }
+    pub fn deduct(&mut self, token_id: u64, amt: Fr) -> Result<(), String> {
+        self.account.deduct(token_id, amt)?;
+        self.balance_changes.push((token_id, Fr::zero(), -amt));
         Ok(())
     }
 }
@@ -91,7 +158,11 @@ pub struct AccountTree<
     pub actual_level: usize,
     pub accounts: Accounts,
     pub default_proof: Vec<Fr>,
-    pub _phantom: std::marker::PhantomData<(TokenTreeNodes, Tokens)>,
+    pub token_tree_height: usize,
+    pub deferred: bool,
+    pub dirty_leaf_ids: Vec<usize>,
+    pub journal: Option<Vec<BalanceChange>>,
+    pub _phantom: core::marker::PhantomData<(TokenTreeNodes, Tokens)>,
 }
 impl<
         AccountTreeNodes: Array<Option<Fr>> + Default,
@@ -101,10 +172,21 @@ impl<
     > Default for AccountTree<AccountTreeNodes, Accounts, TokenTreeNodes, Tokens>
 {
     fn default() -> Self {
-        let default_leaf = Account::<TokenTreeNodes, Tokens>::default();
+        Self::new(&Params::default())
+    }
+}
+impl<
+        AccountTreeNodes: Array<Option<Fr>> + Default,
+        Accounts: Array<Account<TokenTreeNodes, Tokens>> + Default,
+        TokenTreeNodes: Array<Option<Fr>> + Default,
+        Tokens: Array<Token> + Default,
+    > AccountTree<AccountTreeNodes, Accounts, TokenTreeNodes, Tokens>
+{
+    pub fn new(params: &Params) -> Self {
+        let default_leaf = Account::<TokenTreeNodes, Tokens>::new(params.token_tree_height);
         let default_leaf_node = default_leaf.digest().unwrap();
         let mut default_proof = vec![default_leaf_node];
-        for i in 1..super::constants::ACCOUNT_TREE_HEIGHT {
+        for i in 1..params.account_tree_height {
             default_proof.push(Self::hash(default_proof[i - 1], default_proof[i - 1]).unwrap());
         }
         Self {
@@ -112,7 +194,11 @@ impl<
             actual_level: 0,
             accounts: Accounts::default(),
             default_proof,
-            _phantom: std::marker::PhantomData,
+            token_tree_height: params.token_tree_height,
+            deferred: false,
+            dirty_leaf_ids: vec![],
+            journal: None,
+            _phantom: core::marker::PhantomData,
         }
     }
 }
@@ -151,7 +237,20 @@ impl<
         Ok(self.default_proof[idx])
     }
     fn get_level(&self) -> Result<usize, String> {
-        Ok(super::constants::ACCOUNT_TREE_HEIGHT)
+        Ok(self.default_proof.len())
+    }
+    fn is_deferred(&self) -> Result<bool, String> {
+        Ok(self.deferred)
+    }
+    fn get_dirty_leaf_ids(&self) -> Result<&[usize], String> {
+        Ok(&self.dirty_leaf_ids)
+    }
+    fn push_dirty_leaf_id(&mut self, leaf_id: usize) -> Result<(), String> {
+        self.dirty_leaf_ids.push(leaf_id);
+        Ok(())
+    }
+    fn take_dirty_leaf_ids(&mut self) -> Result<Vec<usize>, String> {
+        Ok(core::mem::take(&mut self.dirty_leaf_ids))
     }
 }
 impl<
@@ -163,9 +262,47 @@ impl<
 {
     type Leaf = Account<TokenTreeNodes, Tokens>;
     fn leaf_at(&self, idx: usize) -> Result<Self::Leaf, String> {
-        self.accounts.get(idx)
+        let mut leaf = self.accounts.get(idx)?;
+        // an array gives the accounts it does not hold with the token tree of the default height
+        if leaf.tokens.default_proof.len() != self.token_tree_height {
+            leaf.tokens.default_proof =
+                TokenTree::<TokenTreeNodes, Tokens>::default_leaf_node(self.token_tree_height);
+        }
+        Ok(leaf)
     }
     fn leaf_set(&mut self, idx: usize, leaf: Self::Leaf) -> Result<(), String> {
         self.accounts.set(idx, &leaf)
     }
 }
+impl<
+        AccountTreeNodes: Array<Option<Fr>>,
+        Accounts: Array<Account<TokenTreeNodes, Tokens>>,
+        TokenTreeNodes: Array<Option<Fr>>,
+        Tokens: Array<Token>,
+    > AccountTree<AccountTreeNodes, Accounts, TokenTreeNodes, Tokens>
+{
+    // like `update_deferred`, and adds the balance changes to the journal when journaling
+    pub fn update_account(
+        &mut self,
+        acc_id: u64,
+        f: impl FnOnce(&mut AccountUpdate<TokenTreeNodes, Tokens>) -> Result<(), String>,
+    ) -> Result<(), String> {
+        let mut update = AccountUpdate {
+            account: self.leaf_at(acc_id as usize)?,
+            balance_changes: vec![],
+        };
+        f(&mut update)?;
+        self.update_leaf_deferred(acc_id as usize, update.account)?;
+        if let Some(journal) = self.journal.as_mut() {
+            for (token_id, avl_amt, locked_amt) in update.balance_changes {
+                journal.push(BalanceChange {
+                    acc_id,
+                    token_id,
+                    avl_amt,
+                    locked_amt,
+                });
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+use alloc::{collections::BTreeMap, format, string::String};
+use ark_bn254::Fr;
+use num_traits::Zero;
+use ts_tx::{Tx as RawTx, TxAucEnd};
+
+use super::{
+    mechanism::{_1fixed, calc_days, primary_market, secondary_market},
+    tsb_info::TSBInfo,
+    Account, Array, Map, State, Token, Tx, Value,
+};
+
+// the funds of a token held by all accounts, next to the flows that moved them in and out of L2.
+// negative amounts are field negations
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Ledger {
+    pub avl_amt: Fr,
+    pub locked_amt: Fr,
+    pub deposited_amt: Fr,
+    pub withdrawn_amt: Fr,
+    pub evacuated_amt: Fr,
+    pub fee_amt: Fr,
+    // principal lent, collateral of matched loans and redeemed bonds
+    pub to_loans_amt: Fr,
+    // principal borrowed, issued bonds and the base tokens of redeemed bonds
+    pub from_loans_amt: Fr,
+}
+impl Ledger {
+    pub fn expected_amt(&self) -> Fr {
+        self.deposited_amt + self.from_loans_amt
+            - self.withdrawn_amt
+            - self.evacuated_amt
+            - self.fee_amt
+            - self.to_loans_amt
+    }
+    // what the accounts hold beyond the expected amount
+    pub fn surplus_amt(&self) -> Fr {
+        self.avl_amt + self.locked_amt - self.expected_amt()
+    }
+}
+
+impl<
+        TsRoot: Value,
+        AccountTreeNodes: Array<Option<Fr>>,
+        Accounts: Array<Account<TokenTreeNodes, Tokens>>,
+        TokenTreeNodes: Array<Option<Fr>>,
+        Tokens: Array<Token>,
+        TSBInfos: Array<TSBInfo>,
+        BondTokenIds: Map<TSBInfo, u64>,
+        Txs: Array<Tx>,
+    >
+    State<TsRoot, AccountTreeNodes, Accounts, TokenTreeNodes, Tokens, TSBInfos, BondTokenIds, Txs>
+{
+    // applies the tx like `update`, and books its balance changes into the ledgers of its tokens.
+    // the flows are taken from the txs themselves: L1 requests, withdrawals and redemptions,
+    // and for matching txs the fee of the fee rate on the matched amount, the principal, the bonds
+    // issued at the auction's interest and the collateral of the auction end
+    pub fn update_and_audit(
+        &mut self,
+        tx_id: usize,
+        ledgers: &mut BTreeMap<u64, Ledger>,
+    ) -> Result<(), String> {
+        let raw_tx = self.txs.get(tx_id)?.raw_tx;
+        let order_tx_id = self.find_filled_order_tx_id(tx_id)?;
+        let order_before = match order_tx_id {
+            Some(order_tx_id) => Some(self.txs.get(order_tx_id)?),
+            None => None,
+        };
+        self.update(tx_id)?;
+        for change in self.take_balance_changes()? {
+            let ledger = ledgers.entry(change.token_id).or_default();
+            ledger.avl_amt += change.avl_amt;
+            ledger.locked_amt += change.locked_amt;
+        }
+        match raw_tx {
+            RawTx::TxDeposit(tx) => {
+                ledgers
+                    .entry(tx.deposit_token_id)
+                    .or_default()
+                    .deposited_amt += tx.deposit_amt;
+            }
+            RawTx::TxForcedWithdraw(tx) => {
+                ledgers
+                    .entry(tx.withdraw_token_id)
+                    .or_default()
+                    .withdrawn_amt += tx.withdraw_amt;
+            }
+            RawTx::TxWithdraw(tx) => {
+                ledgers
+                    .entry(tx.withdraw_token_id)
+                    .or_default()
+                    .withdrawn_amt += tx.withdraw_amt;
+                ledgers.entry(tx.tx_fee_token_id).or_default().fee_amt += tx.tx_fee_amt;
+            }
+            RawTx::TxUserCancel(tx) => {
+                ledgers.entry(tx.tx_fee_token_id).or_default().fee_amt += tx.tx_fee_amt;
+            }
+            RawTx::TxEvacuation(tx) => {
+                ledgers.entry(tx.token_id).or_default().evacuated_amt += tx.amount;
+            }
+            RawTx::TxRedeem(tx) => {
+                let base_token_id = self.tsb_infos.get(tx.token_id as usize)?.base_token_id as u64;
+                ledgers.entry(tx.token_id).or_default().to_loans_amt += tx.amount;
+                ledgers.entry(base_token_id).or_default().from_loans_amt += tx.amount;
+            }
+            _ => {}
+        }
+        if let (Some(order_tx_id), Some(order_before)) = (order_tx_id, order_before) {
+            let order = self.txs.get(order_tx_id)?;
+            self.audit_fill(tx_id, &order_before, &order, ledgers)?;
+        }
+        Ok(())
+    }
+    // books what the tx filled of the order, from the amounts matched since the order was last charged
+    fn audit_fill(
+        &self,
+        tx_id: usize,
+        order_before: &Tx,
+        order: &Tx,
+        ledgers: &mut BTreeMap<u64, Ledger>,
+    ) -> Result<(), String> {
+        let deducted_amt = order.cum_deducted_amt - order_before.ori_cum_deducted_amt;
+        let target_amt = order.cum_target_amt - order_before.ori_cum_target_amt;
+        match order.raw_tx {
+            RawTx::TxAucLend(lender) => {
+                let (matched_pir, end) = self.find_auction(tx_id)?;
+                let days = calc_days(end.matched_time, end.maturity);
+                let bond_token_id = self.find_bond_token_id(end.debt_token_id, end.maturity)?;
+                let fee_amt = primary_market::calc_fee(
+                    lender.fee_rate,
+                    deducted_amt,
+                    lender.default_matched_interest_rate + _1fixed(),
+                    days,
+                );
+                let ledger = ledgers.entry(lender.lending_token_id).or_default();
+                ledger.to_loans_amt += deducted_amt;
+                ledger.fee_amt +=
+                    charged_fee_amt(order_before, fee_amt, lender.primary_lend_min_fee_amt, None);
+                ledgers.entry(bond_token_id).or_default().from_loans_amt +=
+                    primary_market::calc_debt_amt(matched_pir, deducted_amt, days);
+            }
+            RawTx::TxAucBorrow(borrower) => {
+                let (matched_pir, end) = self.find_auction(tx_id)?;
+                let days = calc_days(end.matched_time, end.maturity);
+                let fee_amt =
+                    primary_market::calc_fee(borrower.fee_rate, target_amt, matched_pir, days);
+                let ledger = ledgers.entry(end.debt_token_id).or_default();
+                ledger.from_loans_amt += target_amt;
+                ledger.fee_amt += charged_fee_amt(
+                    order_before,
+                    fee_amt,
+                    borrower.primary_borrow_min_fee_amt,
+                    Some(target_amt),
+                );
+                ledgers
+                    .entry(end.collateral_token_id)
+                    .or_default()
+                    .to_loans_amt += end.collateral_amt;
+            }
+            raw_order @ (RawTx::TxSecLimitOrder(_) | RawTx::TxSecMarketOrder(_)) => {
+                let is_maker = matches!(
+                    self.txs.get(tx_id)?.raw_tx,
+                    RawTx::TxSecLimitExchange(_) | RawTx::TxSecMarketExchange(_)
+                );
+                let (sell_token_id, buy_token_id, fee_rate, min_fee_amt) = match raw_order {
+                    RawTx::TxSecLimitOrder(tx) if is_maker => (
+                        tx.sell_token_id,
+                        tx.buy_token_id,
+                        tx.fee1,
+                        tx.secondary_maker_min_fee_amt,
+                    ),
+                    RawTx::TxSecLimitOrder(tx) => (
+                        tx.sell_token_id,
+                        tx.buy_token_id,
+                        tx.fee0,
+                        tx.secondary_taker_min_fee_amt,
+                    ),
+                    RawTx::TxSecMarketOrder(tx) => (
+                        tx.sell_token_id,
+                        tx.buy_token_id,
+                        tx.fee0,
+                        tx.secondary_taker_min_fee_amt,
+                    ),
+                    _ => unreachable!(),
+                };
+                let matched_time = match self.find_closing_tx(tx_id)? {
+                    RawTx::TxSecLimitEnd(tx) => tx.matched_time,
+                    RawTx::TxSecMarketEnd(tx) => tx.matched_time,
+                    _ => return Err(format!("tx {} is not in an exchange", tx_id)),
+                };
+                // an order selling bonds pays its fee from the base tokens it receives
+                let sells_bonds = self.tsb_infos.get(buy_token_id as usize)? == TSBInfo::default();
+                let (bond_token_id, bond_amt, fee_token_id, received_amt) = if sells_bonds {
+                    (sell_token_id, deducted_amt, buy_token_id, Some(target_amt))
+                } else {
+                    (buy_token_id, target_amt, sell_token_id, None)
+                };
+                let maturity = self.tsb_infos.get(bond_token_id as usize)?.maturity;
+                let fee_amt = secondary_market::calc_fee(
+                    fee_rate,
+                    bond_amt,
+                    calc_days(matched_time, maturity),
+                );
+                ledgers.entry(fee_token_id).or_default().fee_amt +=
+                    charged_fee_amt(order_before, fee_amt, min_fee_amt, received_amt);
+            }
+            _ => return Err(format!("tx {} does not fill an order", tx_id)),
+        }
+        Ok(())
+    }
+    // the order a matching tx fills: the lender or maker of a match, the borrower or taker of an end
+    fn find_filled_order_tx_id(&self, tx_id: usize) -> Result<Option<usize>, String> {
+        match self.txs.get(tx_id)?.raw_tx {
+            RawTx::TxAucMatch(tx) => Ok(Some(tx_id - tx.lender_tx_offset as usize)),
+            RawTx::TxSecLimitExchange(tx) => Ok(Some(tx_id - tx.maker_tx_offset as usize)),
+            RawTx::TxSecMarketExchange(tx) => Ok(Some(tx_id - tx.maker_tx_offset as usize)),
+            RawTx::TxAucEnd(_) | RawTx::TxSecLimitEnd(_) | RawTx::TxSecMarketEnd(_) => {
+                match self.find_opening_tx(tx_id)? {
+                    (start_tx_id, RawTx::TxAucStart(tx)) => {
+                        Ok(Some(start_tx_id - tx.borrower_tx_offset as usize))
+                    }
+                    (start_tx_id, RawTx::TxSecLimitStart(tx)) => {
+                        Ok(Some(start_tx_id - tx.taker_tx_offset as usize))
+                    }
+                    (start_tx_id, RawTx::TxSecMarketOrder(_)) => Ok(Some(start_tx_id)),
+                    _ => Err(format!("tx {} ends no batch", tx_id)),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+    // the interest and the end of the auction the tx is in
+    fn find_auction(&self, tx_id: usize) -> Result<(Fr, TxAucEnd), String> {
+        let matched_pir = match self.find_opening_tx(tx_id)? {
+            (_, RawTx::TxAucStart(tx)) => tx.ori_matched_interest,
+            _ => return Err(format!("tx {} is not in an auction", tx_id)),
+        };
+        let end = match self.find_closing_tx(tx_id)? {
+            RawTx::TxAucEnd(tx) => tx,
+            _ => return Err(format!("tx {} is not in an auction", tx_id)),
+        };
+        Ok((matched_pir, end))
+    }
+    // the tx before the matches or exchanges of the batch, and the tx after them
+    fn find_opening_tx(&self, tx_id: usize) -> Result<(usize, RawTx), String> {
+        let mut tmp = tx_id;
+        loop {
+            tmp = tmp
+                .checked_sub(1)
+                .ok_or(format!("tx {} is not in a batch", tx_id))?;
+            match self.txs.get(tmp)?.raw_tx {
+                RawTx::TxAucMatch(_)
+                | RawTx::TxSecLimitExchange(_)
+                | RawTx::TxSecMarketExchange(_) => {}
+                raw_tx => return Ok((tmp, raw_tx)),
+            }
+        }
+    }
+    fn find_closing_tx(&self, tx_id: usize) -> Result<RawTx, String> {
+        let mut tmp = tx_id;
+        loop {
+            match self.txs.get(tmp)?.raw_tx {
+                RawTx::TxAucMatch(_)
+                | RawTx::TxSecLimitExchange(_)
+                | RawTx::TxSecMarketExchange(_) => tmp += 1,
+                raw_tx => return Ok(raw_tx),
+            }
+        }
+    }
+}
+
+// what an order is charged for a fill whose fee is `fee_amt`: the fees beyond the min fee it was
+// credited, at least the min fee, which is capped by what it received when paid from the income
+fn charged_fee_amt(
+    order_before: &Tx,
+    fee_amt: Fr,
+    min_fee_amt: Fr,
+    received_amt: Option<Fr>,
+) -> Fr {
+    let credit_amt = order_before.credit_amt;
+    let mut new_credit_amt = max(credit_amt, min_fee_amt);
+    if let Some(received_amt) = received_amt {
+        new_credit_amt = min(
+            new_credit_amt,
+            max(credit_amt, order_before.cum_fee_amt) + received_amt,
+        );
+    }
+    let charged_credit_amt = if new_credit_amt > credit_amt {
+        new_credit_amt - credit_amt
+    } else {
+        Fr::zero()
+    };
+    let cum_fee_amt = order_before.cum_fee_amt + fee_amt;
+    let charged_fee_amt = if cum_fee_amt > new_credit_amt {
+        min(cum_fee_amt - new_credit_amt, fee_amt)
+    } else {
+        Fr::zero()
+    };
+    charged_credit_amt + charged_fee_amt
+}
+fn max(l: Fr, r: Fr) -> Fr {
+    if l > r {
+        l
+    } else {
+        r
+    }
+}
+fn min(l: Fr, r: Fr) -> Fr {
+    if l < r {
+        l
+    } else {
+        r
+    }
+}
@@ -1,3 +1,20 @@
 pub const ACCOUNT_TREE_HEIGHT: usize = 32;
 pub const TOKEN_TREE_HEIGHT: usize = 16;
 pub const TX_COUNT_PER_BLOCK : usize = 256;
+
+// the sizes of a deployment, the constants above are the mainnet ones
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    pub account_tree_height: usize,
+    pub token_tree_height: usize,
+    pub tx_count_per_block: usize,
+}
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            account_tree_height: ACCOUNT_TREE_HEIGHT,
+            token_tree_height: TOKEN_TREE_HEIGHT,
+            tx_count_per_block: TX_COUNT_PER_BLOCK,
+        }
+    }
+}
@@ -1,24 +1,54 @@
+#![no_std]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 mod account;
+mod audit;
 pub mod constants;
+mod market;
 mod mechanism;
+mod order;
+#[cfg(feature = "std")]
+mod parallel;
+mod position;
 mod token;
 mod tsb_info;
 mod tx;
+mod validation;
 
-use self::mechanism::{calc_days, primary_market};
 pub use self::{
-    account::{Account, AccountTree},
+    account::{calc_account_digest, Account, AccountTree, AccountUpdate, BalanceChange},
+    audit::Ledger,
+    market::Trade,
     mechanism::secondary_market,
+    order::{Order, OrderType},
+    position::{BondPosition, LoanPosition},
     token::{Token, TokenTree},
     tsb_info::TSBInfo,
     tx::Tx,
 };
+use self::{
+    constants::Params,
+    mechanism::{calc_days, primary_market},
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use ark_bn254::Fr;
 use num_traits::Zero;
 use ts_merkle_tree::{MerkleTree, MerkleTreeWithLeaves};
 use ts_poseidon::poseidon;
 use ts_tx::Tx as RawTx;
 
+// the state root of the circuit, from the ts root and the root of the account tree
+pub fn calc_state_root(ts_root: Fr, acc_root: Fr) -> Fr {
+    poseidon::<4>(&[Fr::from(2u64), ts_root, acc_root])
+}
+
 pub trait Value: Sized {
     fn get(&self) -> Result<Fr, String>;
     fn set(&mut self, value: &Fr) -> Result<(), String>;
@@ -27,6 +57,10 @@ pub trait Array<Elem>: Sized {
     fn get(&self, index: usize) -> Result<Elem, String>;
     fn set(&mut self, index: usize, elem: &Elem) -> Result<(), String>;
 }
+pub trait Map<Key, Elem>: Sized {
+    fn get(&self, key: &Key) -> Result<Option<Elem>, String>;
+    fn set(&mut self, key: &Key, elem: &Elem) -> Result<(), String>;
+}
 pub struct State<
     TsRoot: Value,
     AccountTreeNodes: Array<Option<Fr>>,
@@ -34,11 +68,15 @@ pub struct State<
     TokenTreeNodes: Array<Option<Fr>>,
     Tokens: Array<Token>,
     TSBInfos: Array<tsb_info::TSBInfo>,
+    BondTokenIds: Map<tsb_info::TSBInfo, u64>,
     Txs: Array<Tx>,
 > {
+    pub params: Params,
     pub ts_root: TsRoot,
     pub accounts: AccountTree<AccountTreeNodes, Accounts, TokenTreeNodes, Tokens>,
     pub tsb_infos: TSBInfos,
+    // the bond tokens by their base token and maturity, filled as they are created
+    pub bond_token_ids: BondTokenIds,
     pub txs: Txs,
 }
 impl<
@@ -48,14 +86,44 @@ impl<
         TokenTreeNodes: Array<Option<Fr>> + Default,
         Tokens: Array<Token> + Default,
         TSBInfos: Array<tsb_info::TSBInfo> + Default,
+        BondTokenIds: Map<tsb_info::TSBInfo, u64> + Default,
         Txs: Array<Tx> + Default,
-    > Default for State<TsRoot, AccountTreeNodes, Accounts, TokenTreeNodes, Tokens, TSBInfos, Txs>
+    > Default
+    for State<
+        TsRoot,
+        AccountTreeNodes,
+        Accounts,
+        TokenTreeNodes,
+        Tokens,
+        TSBInfos,
+        BondTokenIds,
+        Txs,
+    >
 {
     fn default() -> Self {
+        Self::new(Params::default())
+    }
+}
+impl<
+        TsRoot: Value + Default,
+        AccountTreeNodes: Array<Option<Fr>> + Default,
+        Accounts: Array<Account<TokenTreeNodes, Tokens>> + Default,
+        TokenTreeNodes: Array<Option<Fr>> + Default,
+        Tokens: Array<Token> + Default,
+        TSBInfos: Array<tsb_info::TSBInfo> + Default,
+        BondTokenIds: Map<tsb_info::TSBInfo, u64> + Default,
+        Txs: Array<Tx> + Default,
+    >
+    State<TsRoot, AccountTreeNodes, Accounts, TokenTreeNodes, Tokens, TSBInfos, BondTokenIds, Txs>
+{
+    // an empty state whose trees have the heights of the deployment
+    pub fn new(params: Params) -> Self {
         Self {
+            params,
             ts_root: TsRoot::default(),
-            accounts: AccountTree::default(),
+            accounts: AccountTree::new(&params),
             tsb_infos: TSBInfos::default(),
+            bond_token_ids: BondTokenIds::default(),
             txs: Txs::default(),
         }
     }
@@ -67,52 +135,196 @@ impl<
         TokenTreeNodes: Array<Option<Fr>>,
         Tokens: Array<Token>,
         TSBInfos: Array<tsb_info::TSBInfo>,
+        BondTokenIds: Map<tsb_info::TSBInfo, u64>,
         Txs: Array<Tx>,
-    > State<TsRoot, AccountTreeNodes, Accounts, TokenTreeNodes, Tokens, TSBInfos, Txs>
+    >
+    State<TsRoot, AccountTreeNodes, Accounts, TokenTreeNodes, Tokens, TSBInfos, BondTokenIds, Txs>
 {
     pub fn get_root(&self) -> Result<Fr, String> {
-        Ok(poseidon::<4>(&[
-            Fr::from(2u64),
+        Ok(calc_state_root(
             self.ts_root.get()?,
             self.accounts.get_root()?,
-        ]))
+        ))
     }
     pub fn set_ts_root(&mut self, ts_root: Fr) -> Result<(), String> {
         self.ts_root.set(&ts_root)
     }
+    pub fn set_deferred(&mut self, deferred: bool) -> Result<(), String> {
+        if !deferred {
+            self.commit()?;
+        }
+        self.accounts.deferred = deferred;
+        Ok(())
+    }
+    pub fn commit(&mut self) -> Result<(), String> {
+        self.accounts.commit()
+    }
+    pub fn set_journaling(&mut self, journaling: bool) -> Result<(), String> {
+        self.accounts.journal = match journaling {
+            true => Some(vec![]),
+            false => None,
+        };
+        Ok(())
+    }
+    pub fn take_balance_changes(&mut self) -> Result<Vec<BalanceChange>, String> {
+        let journal = self
+            .accounts
+            .journal
+            .as_mut()
+            .ok_or("journaling is not enabled")?;
+        let mut balance_changes: Vec<BalanceChange> = vec![];
+        for change in journal.drain(..) {
+            match balance_changes
+                .iter_mut()
+                .find(|merged| merged.acc_id == change.acc_id && merged.token_id == change.token_id)
+            {
+                Some(merged) => {
+                    merged.avl_amt += change.avl_amt;
+                    merged.locked_amt += change.locked_amt;
+                }
+                None => balance_changes.push(change),
+            }
+        }
+        Ok(balance_changes)
+    }
+    pub fn for_each_tx(
+        &self,
+        end_block_id: usize,
+        mut f: impl FnMut(usize, Tx) -> Result<(), String>,
+    ) -> Result<(), String> {
+        for block_id in 1..end_block_id {
+            for offset in 0..self.params.tx_count_per_block {
+                let tx_id = (block_id - 1) * self.params.tx_count_per_block + offset;
+                let tx = self.txs.get(tx_id)?;
+                if let RawTx::TxNoop(_) = tx.raw_tx {
+                    break;
+                }
+                f(tx_id, tx)?;
+            }
+        }
+        Ok(())
+    }
+    pub fn get_open_orders(&self, end_block_id: usize) -> Result<Vec<Order>, String> {
+        let mut orders = vec![];
+        self.for_each_tx(end_block_id, |tx_id, tx| {
+            if let Some(order) = Order::from_tx(tx_id, &tx) {
+                if order.is_open() {
+                    orders.push(order);
+                }
+            }
+            Ok(())
+        })?;
+        Ok(orders)
+    }
+    pub fn get_bond_positions(
+        &self,
+        end_block_id: usize,
+        acc_id: usize,
+    ) -> Result<Vec<BondPosition>, String> {
+        let mut bond_token_ids = vec![];
+        self.for_each_tx(end_block_id, |_, tx| {
+            if let RawTx::TxCreateTsbBondToken(tx) = tx.raw_tx {
+                bond_token_ids.push(tx.bond_token_id);
+            }
+            Ok(())
+        })?;
+        bond_token_ids.sort_unstable();
+        bond_token_ids.dedup();
+        let account = self.accounts.leaf_at(acc_id)?;
+        let mut positions = vec![];
+        for bond_token_id in bond_token_ids {
+            let token = account.tokens.leaf_at(bond_token_id as usize)?;
+            if token.avl_amt.is_zero() && token.locked_amt.is_zero() {
+                continue;
+            }
+            let tsb_info = self.tsb_infos.get(bond_token_id as usize)?;
+            positions.push(BondPosition {
+                bond_token_id,
+                base_token_id: tsb_info.base_token_id as u64,
+                maturity: tsb_info.maturity,
+                avl_amt: token.avl_amt,
+                locked_amt: token.locked_amt,
+            });
+        }
+        Ok(positions)
+    }
+    pub fn get_loan_positions(
+        &self,
+        end_block_id: usize,
+        acc_id: usize,
+    ) -> Result<Vec<LoanPosition>, String> {
+        let mut positions: Vec<LoanPosition> = vec![];
+        self.for_each_tx(end_block_id, |_, tx| {
+            if let RawTx::TxAucEnd(tx) = tx.raw_tx {
+                if tx.borrow_account != Fr::from(acc_id as u64) {
+                    return Ok(());
+                }
+                match positions.iter_mut().find(|position| {
+                    position.collateral_token_id == tx.collateral_token_id
+                        && position.debt_token_id == tx.debt_token_id
+                        && position.maturity == tx.maturity
+                }) {
+                    Some(position) => {
+                        position.matched_collateral_amt += tx.collateral_amt;
+                        position.matched_debt_amt += tx.debt_amt;
+                    }
+                    None => positions.push(LoanPosition {
+                        collateral_token_id: tx.collateral_token_id,
+                        matched_collateral_amt: tx.collateral_amt,
+                        debt_token_id: tx.debt_token_id,
+                        matched_debt_amt: tx.debt_amt,
+                        maturity: tx.maturity,
+                    }),
+                }
+            }
+            Ok(())
+        })?;
+        Ok(positions)
+    }
+    pub fn find_bond_token_id(&self, base_token_id: u64, maturity: Fr) -> Result<u64, String> {
+        let tsb_info = TSBInfo {
+            base_token_id: base_token_id as usize,
+            maturity,
+        };
+        self.bond_token_ids.get(&tsb_info)?.ok_or(format!(
+            "no bond token of token {} matures at {}",
+            base_token_id, maturity
+        ))
+    }
     pub fn push_tx(&mut self, tx_id: usize, raw_tx: RawTx) -> Result<(), String> {
         self.txs.set(tx_id, &Tx::new(raw_tx))
     }
     pub fn update(&mut self, tx_id: usize) -> Result<(), String> {
+        self.validate(tx_id)?;
         let raw_tx = self.txs.get(tx_id)?.raw_tx;
         match raw_tx {
             RawTx::TxNoop(_) => {}
             RawTx::TxRegister(tx) => {
                 self.accounts
-                    .update(tx.account_id, |acc| Ok(acc.set_l2_addr(tx.hashed_pub_key)?))?;
+                    .update_account(tx.account_id, |acc| acc.set_l2_addr(tx.hashed_pub_key))?;
             }
             RawTx::TxDeposit(tx) => {
-                self.accounts.update(tx.account_id, |acc| {
-                    Ok(acc.income(tx.deposit_token_id, tx.deposit_amt)?)
+                self.accounts.update_account(tx.account_id, |acc| {
+                    acc.income(tx.deposit_token_id, tx.deposit_amt)
                 })?;
             }
             RawTx::TxForcedWithdraw(tx) => {
-                self.accounts.update(tx.account_id, |acc| {
-                    Ok(acc.outgo(tx.withdraw_token_id, tx.withdraw_amt)?)
+                self.accounts.update_account(tx.account_id, |acc| {
+                    acc.outgo(tx.withdraw_token_id, tx.withdraw_amt)
                 })?;
             }
             RawTx::TxTransfer(tx) => {
-                self.accounts.update(tx.sender_id, |acc| {
+                self.accounts.update_account(tx.sender_id, |acc| {
                     acc.outgo(tx.transfer_token_id, tx.transfer_amt)?;
                     acc.increase_nonce()?;
                     Ok(())
                 })?;
-                self.accounts.update(tx.receiver_id, |acc| {
-                    Ok(acc.income(tx.transfer_token_id, tx.transfer_amt)?)
+                self.accounts.update_account(tx.receiver_id, |acc| {
+                    acc.income(tx.transfer_token_id, tx.transfer_amt)
                 })?;
             }
             RawTx::TxWithdraw(tx) => {
-                self.accounts.update(tx.account_id, |acc| {
+                self.accounts.update_account(tx.account_id, |acc| {
                     acc.outgo(tx.withdraw_token_id, tx.withdraw_amt)?;
                     acc.outgo(tx.tx_fee_token_id, tx.tx_fee_amt)?;
                     acc.increase_nonce()?;
@@ -120,16 +332,15 @@ impl<
                 })?;
             }
             RawTx::TxCreateTsbBondToken(tx) => {
-                self.tsb_infos.set(
-                    tx.bond_token_id as usize,
-                    &TSBInfo {
-                        base_token_id: tx.base_token_id as usize,
-                        maturity: tx.maturity,
-                    },
-                )?;
+                let tsb_info = TSBInfo {
+                    base_token_id: tx.base_token_id as usize,
+                    maturity: tx.maturity,
+                };
+                self.tsb_infos.set(tx.bond_token_id as usize, &tsb_info)?;
+                self.bond_token_ids.set(&tsb_info, &tx.bond_token_id)?;
             }
             RawTx::TxAucLend(tx) => {
-                self.accounts.update(tx.lender_id, |acc| {
+                self.accounts.update_account(tx.lender_id, |acc| {
                     let days_from_matched = calc_days(tx.matched_time, tx.maturity_time);
                     let expected_fee_amt = primary_market::calc_fee(
                         tx.fee_rate,
@@ -151,7 +362,7 @@ impl<
                 })?;
             }
             RawTx::TxAucBorrow(tx) => {
-                self.accounts.update(tx.sender_id, |acc| {
+                self.accounts.update_account(tx.sender_id, |acc| {
                     let amt_to_be_lock = tx.collateral_amt;
                     acc.lock(tx.collateral_token_id, amt_to_be_lock)?;
                     let mut current_tx = self.txs.get(tx_id)?;
@@ -171,7 +382,7 @@ impl<
                         return Err("invalid tx type to cancel".to_string());
                     }
                 };
-                self.accounts.update(accound_id, |acc| {
+                self.accounts.update_account(accound_id, |acc| {
                     acc.unlock(token_id, order.locked_amt)?;
                     Ok(())
                 })?;
@@ -189,7 +400,7 @@ impl<
                         return Err(format!("invalid tx type to cancel: {:?}", order.raw_tx));
                     }
                 };
-                self.accounts.update(accound_id, |acc| {
+                self.accounts.update_account(accound_id, |acc| {
                     acc.unlock(token_id, order.locked_amt)?;
                     acc.outgo(tx.tx_fee_token_id, tx.tx_fee_amt)?;
                     Ok(())
@@ -212,7 +423,10 @@ impl<
                                     borrower_tx_id,
                                 );
                             }
-                            RawTx::TxAucMatch(_) => Ok(tmp -= 1),
+                            RawTx::TxAucMatch(_) => {
+                                tmp -= 1;
+                                Ok(())
+                            }
                             _ => Err("invalid tx type".to_string()),
                         }?
                     }
@@ -225,22 +439,15 @@ impl<
                             RawTx::TxAucEnd(tx) => {
                                 break (tx.debt_token_id, tx.matched_time, tx.maturity);
                             }
-                            RawTx::TxAucMatch(_) => Ok(tmp += 1),
+                            RawTx::TxAucMatch(_) => {
+                                tmp += 1;
+                                Ok(())
+                            }
                             _ => Err("invalid tx type".to_string()),
                         }?
                     }
                 };
-                let tsb_info = TSBInfo {
-                    base_token_id: debt_token_id as usize,
-                    maturity,
-                };
-                let mut bond_token_id: u64 = 0;
-                loop {
-                    if self.tsb_infos.get(bond_token_id as usize)? == tsb_info {
-                        break;
-                    }
-                    bond_token_id += 1u64;
-                }
+                let bond_token_id = self.find_bond_token_id(debt_token_id, maturity)?;
 
                 let lender_tx_id = tx_id - tx.lender_tx_offset as usize;
                 let mut lender = self.txs.get(lender_tx_id)?;
@@ -313,7 +520,7 @@ impl<
                 };
 
                 lender.locked_amt -= matched_amt + matched_fee_amt;
-                self.accounts.update(sender_id, |acc| {
+                self.accounts.update_account(sender_id, |acc| {
                     acc.deduct(lend_token_id, matched_amt + matched_fee_amt)?;
                     acc.income(bond_token_id, matched_tsb_amt)?;
                     Ok(())
@@ -322,7 +529,7 @@ impl<
                 lender.ori_cum_target_amt = lender.cum_target_amt;
 
                 if lender.cum_deducted_amt == signed_lend_amt {
-                    self.accounts.update(sender_id, |acc| {
+                    self.accounts.update_account(sender_id, |acc| {
                         acc.unlock(lend_token_id, lender.locked_amt)?;
                         Ok(())
                     })?;
@@ -346,7 +553,10 @@ impl<
                                     borrower_tx_id,
                                 );
                             }
-                            RawTx::TxAucMatch(_) => Ok(tmp -= 1),
+                            RawTx::TxAucMatch(_) => {
+                                tmp -= 1;
+                                Ok(())
+                            }
                             _ => Err("invalid tx type".to_string()),
                         }?
                     }
@@ -430,7 +640,7 @@ impl<
                 };
                 borrower.locked_amt -= matched_collateral_amt;
 
-                self.accounts.update(borrower_id, |acc| {
+                self.accounts.update_account(borrower_id, |acc| {
                     acc.deduct(tx.collateral_token_id, matched_collateral_amt)?;
                     acc.income(debt_token_id, matched_amt - matched_fee_amt)?;
                     Ok(())
@@ -439,7 +649,7 @@ impl<
                 borrower.ori_cum_target_amt = borrower.cum_target_amt;
 
                 if borrower.cum_deducted_amt == signed_collateral_amt {
-                    self.accounts.update(borrower_id, |acc| {
+                    self.accounts.update_account(borrower_id, |acc| {
                         acc.unlock(tx.collateral_token_id, borrower.locked_amt)?;
                         Ok(())
                     })?;
@@ -449,7 +659,7 @@ impl<
                 self.txs.set(borrower_tx_id, &borrower)?;
             }
             RawTx::TxSecLimitOrder(tx) => {
-                self.accounts.update(tx.sender_id, |acc| {
+                self.accounts.update_account(tx.sender_id, |acc| {
                     let mut tsb_info_leaf = self.tsb_infos.get(tx.buy_token_id as usize)?;
                     let side = tsb_info_leaf.base_token_id == TSBInfo::default().base_token_id;
                     if side {
@@ -509,7 +719,10 @@ impl<
                                 let taker_tx_id = tmp - tx.taker_tx_offset as usize;
                                 break (self.txs.get(taker_tx_id)?, taker_tx_id);
                             }
-                            RawTx::TxSecLimitExchange(_) => Ok(tmp -= 1),
+                            RawTx::TxSecLimitExchange(_) => {
+                                tmp -= 1;
+                                Ok(())
+                            }
                             _ => Err("invalid tx type".to_string()),
                         }?
                     }
@@ -522,7 +735,10 @@ impl<
                             RawTx::TxSecLimitEnd(tx) => {
                                 break tx.matched_time;
                             }
-                            RawTx::TxSecLimitExchange(_) => Ok(tmp += 1),
+                            RawTx::TxSecLimitExchange(_) => {
+                                tmp += 1;
+                                Ok(())
+                            }
                             _ => Err("invalid tx type".to_string()),
                         }?
                     }
@@ -663,7 +879,7 @@ impl<
                 };
                 maker.locked_amt -= matched_sell_amt + fee_from_sell_amt;
 
-                self.accounts.update(maker_acc_id, |acc| {
+                self.accounts.update_account(maker_acc_id, |acc| {
                     acc.deduct(sell_token_id, matched_sell_amt + fee_from_sell_amt)?;
                     acc.income(buy_token_id, matched_buy_amt - fee_from_buy_amt)?;
                     Ok(())
@@ -671,14 +887,13 @@ impl<
                 maker.ori_cum_deducted_amt = maker.cum_deducted_amt;
                 maker.ori_cum_target_amt = maker.cum_target_amt;
 
-                if {
-                    if maker_side {
-                        maker.cum_deducted_amt == signed_sell_amt
-                    } else {
-                        maker.cum_target_amt == signed_buy_amt
-                    }
-                } {
-                    self.accounts.update(maker_acc_id, |acc| {
+                let is_filled = if maker_side {
+                    maker.cum_deducted_amt == signed_sell_amt
+                } else {
+                    maker.cum_target_amt == signed_buy_amt
+                };
+                if is_filled {
+                    self.accounts.update_account(maker_acc_id, |acc| {
                         acc.unlock(sell_token_id, maker.locked_amt)?;
                         Ok(())
                     })?;
@@ -698,7 +913,10 @@ impl<
                                 let taker_tx_id = tmp - tx.taker_tx_offset as usize;
                                 break (self.txs.get(taker_tx_id)?, taker_tx_id);
                             }
-                            RawTx::TxSecLimitExchange(_) => Ok(tmp -= 1),
+                            RawTx::TxSecLimitExchange(_) => {
+                                tmp -= 1;
+                                Ok(())
+                            }
                             _ => Err("invalid tx type".to_string()),
                         }?
                     }
@@ -838,7 +1056,7 @@ impl<
                 };
 
                 taker.locked_amt -= matched_sell_amt + fee_from_sell_amt;
-                self.accounts.update(taker_acc_id, |acc| {
+                self.accounts.update_account(taker_acc_id, |acc| {
                     acc.deduct(sell_token_id, matched_sell_amt + fee_from_sell_amt)?;
                     acc.income(buy_token_id, matched_buy_amt - fee_from_buy_amt)?;
                     Ok(())
@@ -846,14 +1064,13 @@ impl<
                 taker.ori_cum_deducted_amt = taker.cum_deducted_amt;
                 taker.ori_cum_target_amt = taker.cum_target_amt;
 
-                if {
-                    if side {
-                        taker.cum_deducted_amt == signed_sell_amt
-                    } else {
-                        taker.cum_target_amt == signed_buy_amt
-                    }
-                } {
-                    self.accounts.update(taker_acc_id, |acc| {
+                let is_filled = if side {
+                    taker.cum_deducted_amt == signed_sell_amt
+                } else {
+                    taker.cum_target_amt == signed_buy_amt
+                };
+                if is_filled {
+                    self.accounts.update_account(taker_acc_id, |acc| {
                         acc.unlock(sell_token_id, taker.locked_amt)?;
                         Ok(())
                     })?;
@@ -865,7 +1082,7 @@ impl<
             RawTx::TxIncreaseEpoch(_) => {}
             RawTx::TxRedeem(tx) => {
                 let base_token_id = self.tsb_infos.get(tx.token_id as usize)?.base_token_id as u64;
-                self.accounts.update(tx.sender_id, |acc| {
+                self.accounts.update_account(tx.sender_id, |acc| {
                     acc.outgo(tx.token_id, tx.amount)?;
                     acc.income(base_token_id, tx.amount)?;
                     acc.increase_nonce()?;
@@ -883,7 +1100,10 @@ impl<
                             RawTx::TxSecMarketOrder(_) => {
                                 break (order, tmp);
                             }
-                            RawTx::TxSecMarketExchange(_) => Ok(tmp -= 1),
+                            RawTx::TxSecMarketExchange(_) => {
+                                tmp -= 1;
+                                Ok(())
+                            }
                             _ => Err("invalid tx type".to_string()),
                         }?
                     }
@@ -896,7 +1116,10 @@ impl<
                             RawTx::TxSecMarketEnd(tx) => {
                                 break tx.matched_time;
                             }
-                            RawTx::TxSecMarketExchange(_) => Ok(tmp += 1),
+                            RawTx::TxSecMarketExchange(_) => {
+                                tmp += 1;
+                                Ok(())
+                            }
                             _ => Err("invalid tx type".to_string()),
                         }?
                     }
@@ -1037,7 +1260,7 @@ impl<
                 };
                 maker.locked_amt -= matched_sell_amt + fee_from_sell_amt;
 
-                self.accounts.update(maker_acc_id, |acc| {
+                self.accounts.update_account(maker_acc_id, |acc| {
                     acc.deduct(sell_token_id, matched_sell_amt + fee_from_sell_amt)?;
                     acc.income(buy_token_id, matched_buy_amt - fee_from_buy_amt)?;
                     Ok(())
@@ -1045,14 +1268,13 @@ impl<
                 maker.ori_cum_deducted_amt = maker.cum_deducted_amt;
                 maker.ori_cum_target_amt = maker.cum_target_amt;
 
-                if {
-                    if maker_side {
-                        maker.cum_deducted_amt == signed_sell_amt
-                    } else {
-                        maker.cum_target_amt == signed_buy_amt
-                    }
-                } {
-                    self.accounts.update(maker_acc_id, |acc| {
+                let is_filled = if maker_side {
+                    maker.cum_deducted_amt == signed_sell_amt
+                } else {
+                    maker.cum_target_amt == signed_buy_amt
+                };
+                if is_filled {
+                    self.accounts.update_account(maker_acc_id, |acc| {
                         acc.unlock(sell_token_id, maker.locked_amt)?;
                         Ok(())
                     })?;
@@ -1071,7 +1293,10 @@ impl<
                             RawTx::TxSecMarketOrder(_) => {
                                 break (order, tmp);
                             }
-                            RawTx::TxSecMarketExchange(_) => Ok(tmp -= 1),
+                            RawTx::TxSecMarketExchange(_) => {
+                                tmp -= 1;
+                                Ok(())
+                            }
                             _ => Err("invalid tx type".to_string()),
                         }?
                     }
@@ -1206,7 +1431,7 @@ impl<
                     )
                 };
 
-                self.accounts.update(taker_acc_id, |acc| {
+                self.accounts.update_account(taker_acc_id, |acc| {
                     acc.outgo(sell_token_id, matched_sell_amt + fee_from_sell_amt)?;
                     acc.income(buy_token_id, matched_buy_amt - fee_from_buy_amt)?;
                     Ok(())
@@ -1217,7 +1442,7 @@ impl<
                 self.txs.set(taker_tx_id, &taker)?;
             }
             RawTx::TxEvacuation(tx) => {
-                self.accounts.update(tx.sender_id, |acc| {
+                self.accounts.update_account(tx.sender_id, |acc| {
                     acc.outgo(tx.token_id, tx.amount)?;
                     Ok(())
                 })?;
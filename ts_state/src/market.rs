@@ -0,0 +1,116 @@
+use alloc::{format, string::String};
+use ark_bn254::Fr;
+use ts_tx::Tx as RawTx;
+
+use super::{tsb_info::TSBInfo, Account, Array, Map, State, Token, Tx, Value};
+
+// what a matching tx filled: the principal lent for bonds in an auction,
+// or the base tokens exchanged for bonds against a limit order
+#[derive(Clone, Copy, Debug)]
+pub struct Trade {
+    pub is_primary: bool,
+    pub bond_token_id: u64,
+    pub base_token_id: u64,
+    pub maturity: Fr,
+    pub bond_amt: Fr,
+    pub base_amt: Fr,
+    pub matched_interest: Option<Fr>,
+}
+
+impl<
+        TsRoot: Value,
+        AccountTreeNodes: Array<Option<Fr>>,
+        Accounts: Array<Account<TokenTreeNodes, Tokens>>,
+        TokenTreeNodes: Array<Option<Fr>>,
+        Tokens: Array<Token>,
+        TSBInfos: Array<TSBInfo>,
+        BondTokenIds: Map<TSBInfo, u64>,
+        Txs: Array<Tx>,
+    >
+    State<TsRoot, AccountTreeNodes, Accounts, TokenTreeNodes, Tokens, TSBInfos, BondTokenIds, Txs>
+{
+    // applies the tx like `update`, and returns what it traded when it fills an order
+    pub fn update_and_trade(&mut self, tx_id: usize) -> Result<Option<Trade>, String> {
+        let order_tx_id = match self.txs.get(tx_id)?.raw_tx {
+            RawTx::TxAucMatch(tx) => Some(tx_id - tx.lender_tx_offset as usize),
+            RawTx::TxSecLimitExchange(tx) => Some(tx_id - tx.maker_tx_offset as usize),
+            RawTx::TxSecMarketExchange(tx) => Some(tx_id - tx.maker_tx_offset as usize),
+            _ => None,
+        };
+        let order_before = match order_tx_id {
+            Some(order_tx_id) => Some(self.txs.get(order_tx_id)?),
+            None => None,
+        };
+        self.update(tx_id)?;
+        let (order_tx_id, order_before) = match (order_tx_id, order_before) {
+            (Some(order_tx_id), Some(order_before)) => (order_tx_id, order_before),
+            _ => return Ok(None),
+        };
+        let order = self.txs.get(order_tx_id)?;
+        let deducted_amt = order.cum_deducted_amt - order_before.cum_deducted_amt;
+        let target_amt = order.cum_target_amt - order_before.cum_target_amt;
+        match order.raw_tx {
+            RawTx::TxAucLend(_) => {
+                let matched_interest = self.find_matched_interest(tx_id)?;
+                // the bond is the one of the auction end, as in `update`
+                let tsb_info = self.find_auction_tsb_info(tx_id)?;
+                let bond_token_id =
+                    self.find_bond_token_id(tsb_info.base_token_id as u64, tsb_info.maturity)?;
+                Ok(Some(Trade {
+                    is_primary: true,
+                    bond_token_id,
+                    base_token_id: tsb_info.base_token_id as u64,
+                    maturity: tsb_info.maturity,
+                    bond_amt: target_amt,
+                    base_amt: deducted_amt,
+                    matched_interest: Some(matched_interest),
+                }))
+            }
+            RawTx::TxSecLimitOrder(maker) => {
+                let sell_tsb_info = self.tsb_infos.get(maker.sell_token_id as usize)?;
+                let (bond_token_id, tsb_info, bond_amt, base_amt) =
+                    if sell_tsb_info != TSBInfo::default() {
+                        (maker.sell_token_id, sell_tsb_info, deducted_amt, target_amt)
+                    } else {
+                        let buy_tsb_info = self.tsb_infos.get(maker.buy_token_id as usize)?;
+                        (maker.buy_token_id, buy_tsb_info, target_amt, deducted_amt)
+                    };
+                Ok(Some(Trade {
+                    is_primary: false,
+                    bond_token_id,
+                    base_token_id: tsb_info.base_token_id as u64,
+                    maturity: tsb_info.maturity,
+                    bond_amt,
+                    base_amt,
+                    matched_interest: None,
+                }))
+            }
+            _ => Err(format!("tx {} does not fill an order", tx_id)),
+        }
+    }
+    fn find_auction_tsb_info(&self, tx_id: usize) -> Result<TSBInfo, String> {
+        let mut tmp = tx_id + 1;
+        loop {
+            match self.txs.get(tmp)?.raw_tx {
+                RawTx::TxAucEnd(tx) => {
+                    return Ok(TSBInfo {
+                        base_token_id: tx.debt_token_id as usize,
+                        maturity: tx.maturity,
+                    })
+                }
+                RawTx::TxAucMatch(_) => tmp += 1,
+                _ => return Err(format!("tx {} is not in an auction", tx_id)),
+            }
+        }
+    }
+    fn find_matched_interest(&self, tx_id: usize) -> Result<Fr, String> {
+        let mut tmp = tx_id - 1;
+        loop {
+            match self.txs.get(tmp)?.raw_tx {
+                RawTx::TxAucStart(tx) => return Ok(tx.ori_matched_interest),
+                RawTx::TxAucMatch(_) => tmp -= 1,
+                _ => return Err(format!("tx {} is not in an auction", tx_id)),
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use super::{super::Tx, _1f, _1fixed, _365f};
+use alloc::string::{String, ToString};
 use ark_bn254::Fr;
 use num_bigint::BigUint;
 use ts_tx::Tx as RawTx;
@@ -0,0 +1,92 @@
+use ark_bn254::Fr;
+use num_traits::Zero;
+use ts_tx::Tx as RawTx;
+
+use super::Tx;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderType {
+    AucLend,
+    AucBorrow,
+    SecLimit,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Order {
+    pub tx_id: usize,
+    pub order_type: OrderType,
+    pub acc_id: u64,
+    pub deducted_token_id: u64,
+    pub signed_deducted_amt: Fr,
+    pub target_token_id: Option<u64>,
+    pub signed_target_amt: Option<Fr>,
+    pub cum_deducted_amt: Fr,
+    pub cum_target_amt: Fr,
+    pub locked_amt: Fr,
+    // only the secondary limit orders expire, the auction orders stay open until matched or canceled
+    pub expired_time: Option<Fr>,
+}
+impl Order {
+    pub fn from_tx(tx_id: usize, tx: &Tx) -> Option<Self> {
+        let (order_type, acc_id, deducted_token_id, signed_deducted_amt, target, expired_time) =
+            match tx.raw_tx {
+                RawTx::TxAucLend(order) => (
+                    OrderType::AucLend,
+                    order.lender_id,
+                    order.lending_token_id,
+                    order.lending_amt,
+                    None,
+                    None,
+                ),
+                RawTx::TxAucBorrow(order) => (
+                    OrderType::AucBorrow,
+                    order.sender_id,
+                    order.collateral_token_id,
+                    order.collateral_amt,
+                    Some((None, order.borrowing_amt)),
+                    None,
+                ),
+                RawTx::TxSecLimitOrder(order) => (
+                    OrderType::SecLimit,
+                    order.sender_id,
+                    order.sell_token_id,
+                    order.sell_amt,
+                    Some((Some(order.buy_token_id), order.buy_amt)),
+                    Some(order.expired_time),
+                ),
+                _ => return None,
+            };
+        Some(Self {
+            tx_id,
+            order_type,
+            acc_id,
+            deducted_token_id,
+            signed_deducted_amt,
+            target_token_id: target.and_then(|(token_id, _)| token_id),
+            signed_target_amt: target.map(|(_, amt)| amt),
+            cum_deducted_amt: tx.cum_deducted_amt,
+            cum_target_amt: tx.cum_target_amt,
+            locked_amt: tx.locked_amt,
+            expired_time,
+        })
+    }
+    pub fn is_open(&self) -> bool {
+        !self.locked_amt.is_zero()
+    }
+    pub fn is_partially_matched(&self) -> bool {
+        !self.cum_deducted_amt.is_zero() || !self.cum_target_amt.is_zero()
+    }
+    pub fn is_expired(&self, current_time: Fr) -> bool {
+        match self.expired_time {
+            Some(expired_time) => expired_time < current_time,
+            None => false,
+        }
+    }
+    pub fn remaining_deducted_amt(&self) -> Fr {
+        self.signed_deducted_amt - self.cum_deducted_amt
+    }
+    pub fn remaining_target_amt(&self) -> Option<Fr> {
+        self.signed_target_amt
+            .map(|signed_target_amt| signed_target_amt - self.cum_target_amt)
+    }
+}
@@ -0,0 +1,374 @@
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use ark_bn254::Fr;
+use core::ops::Range;
+use std::thread;
+use ts_merkle_tree::{MerkleLeaf, MerkleTree, MerkleTreeWithLeaves};
+use ts_tx::Tx as RawTx;
+
+use super::{
+    tsb_info::TSBInfo, Account, AccountTree, Array, BalanceChange, Map, State, Token, TokenTree,
+    Tx, Value,
+};
+
+// a copy-on-write view of an array, the writes stay in memory until they are merged back
+struct Overlay<'b, Elem, Base> {
+    base: &'b Base,
+    changes: BTreeMap<usize, Elem>,
+}
+impl<'b, Elem, Base> Overlay<'b, Elem, Base> {
+    fn new(base: &'b Base) -> Self {
+        Self {
+            base,
+            changes: BTreeMap::new(),
+        }
+    }
+}
+impl<'b, Elem: Clone, Base> Clone for Overlay<'b, Elem, Base> {
+    fn clone(&self) -> Self {
+        Self {
+            base: self.base,
+            changes: self.changes.clone(),
+        }
+    }
+}
+impl<'b, Elem: Clone, Base: Array<Elem>> Array<Elem> for Overlay<'b, Elem, Base> {
+    fn get(&self, index: usize) -> Result<Elem, String> {
+        match self.changes.get(&index) {
+            Some(elem) => Ok(elem.clone()),
+            None => self.base.get(index),
+        }
+    }
+    fn set(&mut self, index: usize, elem: &Elem) -> Result<(), String> {
+        self.changes.insert(index, elem.clone());
+        Ok(())
+    }
+}
+
+// the bond tokens are only read by a group, the txs that create them are applied one by one
+struct SharedBondTokenIds<'b, Base>(&'b Base);
+impl<'b, Base: Map<TSBInfo, u64>> Map<TSBInfo, u64> for SharedBondTokenIds<'b, Base> {
+    fn get(&self, key: &TSBInfo) -> Result<Option<u64>, String> {
+        self.0.get(key)
+    }
+    fn set(&mut self, _: &TSBInfo, _: &u64) -> Result<(), String> {
+        Err("bond tokens cannot be created within a group".to_string())
+    }
+}
+
+type CopiedAccount<'b, TokenTreeNodes, Tokens> =
+    Account<Overlay<'b, Option<Fr>, TokenTreeNodes>, Overlay<'b, Token, Tokens>>;
+
+// the accounts touched by a group, any other account is out of its reach
+struct GroupAccounts<'b, TokenTreeNodes: Array<Option<Fr>>, Tokens: Array<Token>> {
+    leaves: BTreeMap<usize, CopiedAccount<'b, TokenTreeNodes, Tokens>>,
+}
+impl<'b, TokenTreeNodes: Array<Option<Fr>>, Tokens: Array<Token>>
+    Array<CopiedAccount<'b, TokenTreeNodes, Tokens>> for GroupAccounts<'b, TokenTreeNodes, Tokens>
+{
+    fn get(&self, index: usize) -> Result<CopiedAccount<'b, TokenTreeNodes, Tokens>, String> {
+        self.leaves
+            .get(&index)
+            .cloned()
+            .ok_or(format!("account {} is outside of the group", index))
+    }
+    fn set(
+        &mut self,
+        index: usize,
+        elem: &CopiedAccount<'b, TokenTreeNodes, Tokens>,
+    ) -> Result<(), String> {
+        self.leaves.insert(index, elem.clone());
+        Ok(())
+    }
+}
+
+struct CopiedRoot(Fr);
+impl Value for CopiedRoot {
+    fn get(&self) -> Result<Fr, String> {
+        Ok(self.0)
+    }
+    fn set(&mut self, value: &Fr) -> Result<(), String> {
+        self.0 = *value;
+        Ok(())
+    }
+}
+
+struct LeafUpdate {
+    acc_id: usize,
+    leaf_node: Fr,
+    l2_addr: Fr,
+    nonce: Fr,
+    token_level: usize,
+    token_nodes: BTreeMap<usize, Option<Fr>>,
+    tokens: BTreeMap<usize, Token>,
+}
+
+#[derive(Default)]
+struct GroupUpdate {
+    leaves: Vec<LeafUpdate>,
+    tsb_infos: BTreeMap<usize, TSBInfo>,
+    txs: BTreeMap<usize, Tx>,
+    balance_changes: Vec<(usize, Vec<BalanceChange>)>,
+}
+
+// the accounts updated by a tx that only depends on them, None for the txs that read other orders
+// (matching batches, cancels) or change the bond tokens, those are applied one by one
+fn touched_acc_ids(raw_tx: &RawTx) -> Option<Vec<u64>> {
+    match raw_tx {
+        RawTx::TxNoop(_)
+        | RawTx::TxIncreaseEpoch(_)
+        | RawTx::TxWithdrawFee(_)
+        | RawTx::TxSetAdminTsAddr(_) => Some(vec![]),
+        RawTx::TxRegister(tx) => Some(vec![tx.account_id]),
+        RawTx::TxDeposit(tx) => Some(vec![tx.account_id]),
+        RawTx::TxForcedWithdraw(tx) => Some(vec![tx.account_id]),
+        RawTx::TxTransfer(tx) => Some(vec![tx.sender_id, tx.receiver_id]),
+        RawTx::TxWithdraw(tx) => Some(vec![tx.account_id]),
+        RawTx::TxAucLend(tx) => Some(vec![tx.lender_id]),
+        RawTx::TxAucBorrow(tx) => Some(vec![tx.sender_id]),
+        RawTx::TxSecLimitOrder(tx) => Some(vec![tx.sender_id]),
+        RawTx::TxSecMarketOrder(tx) => Some(vec![tx.sender_id]),
+        RawTx::TxRedeem(tx) => Some(vec![tx.sender_id]),
+        RawTx::TxEvacuation(tx) => Some(vec![tx.sender_id]),
+        _ => None,
+    }
+}
+
+// splits consecutive txs into groups that share no account, each keeping the order of its txs
+fn group_txs(txs: &[(usize, Vec<u64>)]) -> Vec<(Vec<u64>, Vec<usize>)> {
+    let mut groups: Vec<(Vec<u64>, Vec<usize>)> = vec![];
+    let mut group_ids: BTreeMap<u64, usize> = BTreeMap::new();
+    for (tx_id, acc_ids) in txs {
+        let mut joined: Vec<usize> = acc_ids
+            .iter()
+            .filter_map(|acc_id| group_ids.get(acc_id).copied())
+            .collect();
+        joined.sort_unstable();
+        joined.dedup();
+        let group_id = match joined.first() {
+            Some(group_id) => *group_id,
+            None => {
+                groups.push((vec![], vec![]));
+                groups.len() - 1
+            }
+        };
+        for other_id in joined.into_iter().skip(1) {
+            let (other_acc_ids, other_tx_ids) = core::mem::take(&mut groups[other_id]);
+            for acc_id in other_acc_ids.iter() {
+                group_ids.insert(*acc_id, group_id);
+            }
+            groups[group_id].0.extend(other_acc_ids);
+            groups[group_id].1.extend(other_tx_ids);
+            groups[group_id].1.sort_unstable();
+        }
+        for acc_id in acc_ids {
+            if group_ids.insert(*acc_id, group_id).is_none() {
+                groups[group_id].0.push(*acc_id);
+            }
+        }
+        groups[group_id].1.push(*tx_id);
+    }
+    groups.retain(|(_, tx_ids)| !tx_ids.is_empty());
+    groups
+}
+
+impl<
+        TsRoot: Value + Sync,
+        AccountTreeNodes: Array<Option<Fr>> + Sync,
+        Accounts: Array<Account<TokenTreeNodes, Tokens>> + Sync,
+        TokenTreeNodes: Array<Option<Fr>> + Sync,
+        Tokens: Array<Token> + Sync,
+        TSBInfos: Array<TSBInfo> + Sync,
+        BondTokenIds: Map<TSBInfo, u64> + Sync,
+        Txs: Array<Tx> + Sync,
+    >
+    State<TsRoot, AccountTreeNodes, Accounts, TokenTreeNodes, Tokens, TSBInfos, BondTokenIds, Txs>
+{
+    // applies the txs like calling `update` on each of them in order, with the runs of txs that
+    // only update their own accounts spread over `threads` threads. returns the balance changes
+    // of every tx when journaling, in the order `take_balance_changes` would have given them
+    pub fn update_parallel(
+        &mut self,
+        tx_ids: Range<usize>,
+        threads: usize,
+    ) -> Result<Vec<(usize, Vec<BalanceChange>)>, String> {
+        let journaling = self.accounts.journal.is_some();
+        let mut balance_changes = vec![];
+        let mut pending: Vec<(usize, Vec<u64>)> = vec![];
+        for tx_id in tx_ids {
+            match touched_acc_ids(&self.txs.get(tx_id)?.raw_tx) {
+                Some(acc_ids) => pending.push((tx_id, acc_ids)),
+                None => {
+                    balance_changes.extend(self.update_groups(group_txs(&pending), threads)?);
+                    pending.clear();
+                    self.update(tx_id)?;
+                    if journaling {
+                        balance_changes.push((tx_id, self.take_balance_changes()?));
+                    }
+                }
+            }
+        }
+        balance_changes.extend(self.update_groups(group_txs(&pending), threads)?);
+        balance_changes.sort_by_key(|(tx_id, _)| *tx_id);
+        Ok(balance_changes)
+    }
+    fn update_groups(
+        &mut self,
+        groups: Vec<(Vec<u64>, Vec<usize>)>,
+        threads: usize,
+    ) -> Result<Vec<(usize, Vec<BalanceChange>)>, String> {
+        if groups.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut base_leaves = vec![];
+        for (acc_ids, _) in groups.iter() {
+            let mut leaves = BTreeMap::new();
+            for acc_id in acc_ids {
+                leaves.insert(*acc_id as usize, self.accounts.leaf_at(*acc_id as usize)?);
+            }
+            base_leaves.push(leaves);
+        }
+        let threads = threads.clamp(1, groups.len());
+        let state = &*self;
+        let updates = thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|thread_id| {
+                    let groups = &groups;
+                    let base_leaves = &base_leaves;
+                    scope.spawn(move || {
+                        let mut updates = vec![];
+                        for group_id in (thread_id..groups.len()).step_by(threads) {
+                            updates.push(
+                                state.update_group(&base_leaves[group_id], &groups[group_id].1)?,
+                            );
+                        }
+                        Ok::<_, String>(updates)
+                    })
+                })
+                .collect();
+            let mut updates = vec![];
+            for handle in handles {
+                updates.push(
+                    handle
+                        .join()
+                        .map_err(|_| "replay thread panicked".to_string())??,
+                );
+            }
+            Ok::<_, String>(updates)
+        })?;
+
+        let mut balance_changes = vec![];
+        for (thread_id, thread_updates) in updates.into_iter().enumerate() {
+            for (group_id, update) in (thread_id..groups.len())
+                .step_by(threads)
+                .zip(thread_updates)
+            {
+                for leaf_update in update.leaves {
+                    let mut leaf = base_leaves[group_id]
+                        .remove(&leaf_update.acc_id)
+                        .ok_or("unreachable")?;
+                    leaf.l2_addr = leaf_update.l2_addr;
+                    leaf.nonce = leaf_update.nonce;
+                    leaf.tokens.actual_level = leaf_update.token_level;
+                    for (idx, node) in leaf_update.token_nodes {
+                        leaf.tokens.nodes.set(idx, &node)?;
+                    }
+                    for (idx, token) in leaf_update.tokens {
+                        leaf.tokens.tokens.set(idx, &token)?;
+                    }
+                    self.accounts
+                        .update_leaf_node_deferred(leaf_update.acc_id, leaf_update.leaf_node)?;
+                    self.accounts.leaf_set(leaf_update.acc_id, leaf)?;
+                }
+                for (idx, tsb_info) in update.tsb_infos {
+                    self.tsb_infos.set(idx, &tsb_info)?;
+                }
+                for (idx, tx) in update.txs {
+                    self.txs.set(idx, &tx)?;
+                }
+                balance_changes.extend(update.balance_changes);
+            }
+        }
+        Ok(balance_changes)
+    }
+    // replays the txs of a group on a copy of the state that only holds its accounts
+    fn update_group(
+        &self,
+        base_leaves: &BTreeMap<usize, Account<TokenTreeNodes, Tokens>>,
+        tx_ids: &[usize],
+    ) -> Result<GroupUpdate, String> {
+        let mut leaves = BTreeMap::new();
+        for (acc_id, leaf) in base_leaves {
+            leaves.insert(
+                *acc_id,
+                Account {
+                    l2_addr: leaf.l2_addr,
+                    nonce: leaf.nonce,
+                    tokens: TokenTree {
+                        nodes: Overlay::new(&leaf.tokens.nodes),
+                        actual_level: leaf.tokens.actual_level,
+                        tokens: Overlay::new(&leaf.tokens.tokens),
+                        default_proof: leaf.tokens.default_proof.clone(),
+                        deferred: leaf.tokens.deferred,
+                        dirty_leaf_ids: leaf.tokens.dirty_leaf_ids.clone(),
+                    },
+                },
+            );
+        }
+        let mut copy = State {
+            params: self.params,
+            ts_root: CopiedRoot(self.ts_root.get()?),
+            accounts: AccountTree {
+                nodes: Overlay::new(&self.accounts.nodes),
+                actual_level: self.accounts.actual_level,
+                accounts: GroupAccounts { leaves },
+                default_proof: self.accounts.default_proof.clone(),
+                token_tree_height: self.accounts.token_tree_height,
+                deferred: true,
+                dirty_leaf_ids: vec![],
+                journal: self.accounts.journal.as_ref().map(|_| vec![]),
+                _phantom: core::marker::PhantomData,
+            },
+            tsb_infos: Overlay::new(&self.tsb_infos),
+            bond_token_ids: SharedBondTokenIds(&self.bond_token_ids),
+            txs: Overlay::new(&self.txs),
+        };
+
+        let mut update = GroupUpdate::default();
+        for tx_id in tx_ids {
+            copy.update(*tx_id)?;
+            if copy.accounts.journal.is_some() {
+                update
+                    .balance_changes
+                    .push((*tx_id, copy.take_balance_changes()?));
+            }
+        }
+        let mut acc_ids = copy.accounts.dirty_leaf_ids.clone();
+        acc_ids.sort_unstable();
+        acc_ids.dedup();
+        for acc_id in acc_ids {
+            // the left edge rebuilt when the tree grows is redone by the merge
+            let leaf = match copy.accounts.accounts.leaves.remove(&acc_id) {
+                Some(leaf) => leaf,
+                None => continue,
+            };
+            update.leaves.push(LeafUpdate {
+                acc_id,
+                leaf_node: leaf.digest()?,
+                l2_addr: leaf.l2_addr,
+                nonce: leaf.nonce,
+                token_level: leaf.tokens.get_actual_level()?,
+                token_nodes: leaf.tokens.nodes.changes,
+                tokens: leaf.tokens.tokens.changes,
+            });
+        }
+        update.tsb_infos = copy.tsb_infos.changes;
+        update.txs = copy.txs.changes;
+        Ok(update)
+    }
+}
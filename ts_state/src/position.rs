@@ -0,0 +1,38 @@
+use ark_bn254::Fr;
+
+#[derive(Clone, Copy, Debug)]
+pub struct BondPosition {
+    pub bond_token_id: u64,
+    pub base_token_id: u64,
+    pub maturity: Fr,
+    pub avl_amt: Fr,
+    pub locked_amt: Fr,
+}
+impl BondPosition {
+    pub fn is_matured(&self, current_time: Fr) -> bool {
+        self.maturity <= current_time
+    }
+    pub fn redeemable_amt(&self, current_time: Fr) -> Fr {
+        match self.is_matured(current_time) {
+            true => self.avl_amt,
+            false => Fr::from(0u64),
+        }
+    }
+}
+
+// the loans matched in auctions, summed per collateral token, debt token and maturity. the L2 txs
+// do not carry their repayments, liquidations and roll-overs, which happen on L1, so the amounts
+// are those at matching rather than what is still owed
+#[derive(Clone, Copy, Debug)]
+pub struct LoanPosition {
+    pub collateral_token_id: u64,
+    pub matched_collateral_amt: Fr,
+    pub debt_token_id: u64,
+    pub matched_debt_amt: Fr,
+    pub maturity: Fr,
+}
+impl LoanPosition {
+    pub fn is_matured(&self, current_time: Fr) -> bool {
+        self.maturity <= current_time
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::{format, string::String, vec, vec::Vec};
 use ark_bn254::Fr;
 use num_traits::Zero;
 
@@ -58,12 +59,14 @@ pub struct TokenTree<Nodes: Array<Option<Fr>>, Tokens: Array<Token>> {
     pub actual_level: usize,
     pub tokens: Tokens,
     pub default_proof: Vec<Fr>,
+    pub deferred: bool,
+    pub dirty_leaf_ids: Vec<usize>,
 }
 impl<Nodes: Array<Option<Fr>>, Tokens: Array<Token>> TokenTree<Nodes, Tokens> {
-    pub fn default_leaf_node() -> Vec<Fr> {
+    pub fn default_leaf_node(height: usize) -> Vec<Fr> {
         let default_leaf_node = Token::default().digest().unwrap();
         let mut default_proof = vec![default_leaf_node];
-        for i in 1..super::constants::TOKEN_TREE_HEIGHT {
+        for i in 1..height {
             default_proof.push(Self::hash(default_proof[i - 1], default_proof[i - 1]).unwrap());
         }
         default_proof
@@ -73,11 +76,18 @@ impl<Nodes: Array<Option<Fr>> + Default, Tokens: Array<Token> + Default> Default
     for TokenTree<Nodes, Tokens>
 {
     fn default() -> Self {
+        Self::new(super::constants::TOKEN_TREE_HEIGHT)
+    }
+}
+impl<Nodes: Array<Option<Fr>> + Default, Tokens: Array<Token> + Default> TokenTree<Nodes, Tokens> {
+    pub fn new(height: usize) -> Self {
         Self {
             nodes: Nodes::default(),
             actual_level: 0,
             tokens: Tokens::default(),
-            default_proof: Self::default_leaf_node(),
+            default_proof: Self::default_leaf_node(height),
+            deferred: false,
+            dirty_leaf_ids: vec![],
         }
     }
 }
@@ -90,6 +100,8 @@ impl<Nodes: Array<Option<Fr>> + Clone, Tokens: Array<Token> + Clone> Clone
             actual_level: self.actual_level,
             tokens: self.tokens.clone(),
             default_proof: self.default_proof.clone(),
+            deferred: self.deferred,
+            dirty_leaf_ids: self.dirty_leaf_ids.clone(),
         }
     }
 }
@@ -122,7 +134,20 @@ impl<Nodes: Array<Option<Fr>>, Tokens: Array<Token>> MerkleTree for TokenTree<No
         Ok(self.default_proof[idx])
     }
     fn get_level(&self) -> Result<usize, String> {
-        Ok(super::constants::TOKEN_TREE_HEIGHT)
+        Ok(self.default_proof.len())
+    }
+    fn is_deferred(&self) -> Result<bool, String> {
+        Ok(self.deferred)
+    }
+    fn get_dirty_leaf_ids(&self) -> Result<&[usize], String> {
+        Ok(&self.dirty_leaf_ids)
+    }
+    fn push_dirty_leaf_id(&mut self, leaf_id: usize) -> Result<(), String> {
+        self.dirty_leaf_ids.push(leaf_id);
+        Ok(())
+    }
+    fn take_dirty_leaf_ids(&mut self) -> Result<Vec<usize>, String> {
+        Ok(core::mem::take(&mut self.dirty_leaf_ids))
     }
 }
 impl<Nodes: Array<Option<Fr>>, Tokens: Array<Token>> MerkleTreeWithLeaves
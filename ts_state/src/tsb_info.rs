@@ -14,9 +14,9 @@ impl Default for TSBInfo {
         }
     }
 }
-impl std::cmp::PartialEq for TSBInfo {
+impl core::cmp::PartialEq for TSBInfo {
     fn eq(&self, other: &Self) -> bool {
         self.base_token_id == other.base_token_id && self.maturity == other.maturity
     }
 }
-impl std::cmp::Eq for TSBInfo {}
\ No newline at end of file
+impl core::cmp::Eq for TSBInfo {}
\ No newline at end of file
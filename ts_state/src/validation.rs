@@ -0,0 +1,318 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use ark_bn254::Fr;
+use num_traits::Zero;
+use ts_merkle_tree::MerkleTreeWithLeaves;
+use ts_tx::Tx as RawTx;
+
+use super::{tsb_info::TSBInfo, Account, Array, Map, State, Token, Tx, Value};
+
+impl<
+        TsRoot: Value,
+        AccountTreeNodes: Array<Option<Fr>>,
+        Accounts: Array<Account<TokenTreeNodes, Tokens>>,
+        TokenTreeNodes: Array<Option<Fr>>,
+        Tokens: Array<Token>,
+        TSBInfos: Array<TSBInfo>,
+        BondTokenIds: Map<TSBInfo, u64>,
+        Txs: Array<Tx>,
+    >
+    State<TsRoot, AccountTreeNodes, Accounts, TokenTreeNodes, Tokens, TSBInfos, BondTokenIds, Txs>
+{
+    // checks that the tx only refers to things that exist in the current state
+    pub fn validate(&self, tx_id: usize) -> Result<(), String> {
+        self.check_tx(tx_id, self.txs.get(tx_id)?.raw_tx)
+            .map_err(|reason| format!("invalid tx {}: {}", tx_id, reason))
+    }
+    fn check_tx(&self, tx_id: usize, raw_tx: RawTx) -> Result<(), String> {
+        match raw_tx {
+            RawTx::TxNoop(_) => {}
+            RawTx::TxRegister(tx) => {
+                self.check_acc_id(tx.account_id)?;
+            }
+            RawTx::TxDeposit(tx) => {
+                self.check_registered(tx.account_id)?;
+                self.check_token_id(tx.deposit_token_id)?;
+            }
+            RawTx::TxForcedWithdraw(tx) => {
+                self.check_registered(tx.account_id)?;
+                self.check_token_id(tx.withdraw_token_id)?;
+            }
+            RawTx::TxTransfer(tx) => {
+                self.check_registered(tx.sender_id)?;
+                self.check_registered(tx.receiver_id)?;
+                self.check_token_id(tx.transfer_token_id)?;
+            }
+            RawTx::TxWithdraw(tx) => {
+                self.check_registered(tx.account_id)?;
+                self.check_token_id(tx.withdraw_token_id)?;
+                self.check_token_id(tx.tx_fee_token_id)?;
+            }
+            RawTx::TxCreateTsbBondToken(tx) => {
+                self.check_token_id(tx.base_token_id)?;
+                self.check_token_id(tx.bond_token_id)?;
+                if self.tsb_infos.get(tx.bond_token_id as usize)? != TSBInfo::default() {
+                    return Err(format!("bond token {} already exists", tx.bond_token_id));
+                }
+            }
+            RawTx::TxAucLend(tx) => {
+                self.check_registered(tx.lender_id)?;
+                self.check_token_id(tx.lending_token_id)?;
+                check_maturity(tx.matched_time, tx.maturity_time)?;
+            }
+            RawTx::TxAucBorrow(tx) => {
+                self.check_registered(tx.sender_id)?;
+                self.check_token_id(tx.collateral_token_id)?;
+            }
+            RawTx::TxAucStart(tx) => {
+                self.check_offset(tx_id, tx.borrower_tx_offset, "auction borrow order", |tx| {
+                    matches!(tx, RawTx::TxAucBorrow(_))
+                })?;
+            }
+            RawTx::TxAucMatch(tx) => {
+                self.check_offset(tx_id, tx.lender_tx_offset, "auction lend order", |tx| {
+                    matches!(tx, RawTx::TxAucLend(_))
+                })?;
+                self.find_batch_start(
+                    tx_id,
+                    "auction start",
+                    |tx| matches!(tx, RawTx::TxAucStart(_)),
+                    |tx| matches!(tx, RawTx::TxAucMatch(_)),
+                )?;
+                match self.find_batch_end(
+                    tx_id,
+                    "auction end",
+                    |tx| matches!(tx, RawTx::TxAucEnd(_)),
+                    |tx| matches!(tx, RawTx::TxAucMatch(_)),
+                )? {
+                    RawTx::TxAucEnd(end) => {
+                        self.find_bond_token_id(end.debt_token_id, end.maturity)?;
+                    }
+                    _ => return Err("unreachable".to_string()),
+                }
+            }
+            RawTx::TxAucEnd(tx) => {
+                self.check_token_id(tx.collateral_token_id)?;
+                self.check_token_id(tx.debt_token_id)?;
+                check_maturity(tx.matched_time, tx.maturity)?;
+                self.find_batch_start(
+                    tx_id,
+                    "auction start",
+                    |tx| matches!(tx, RawTx::TxAucStart(_)),
+                    |tx| matches!(tx, RawTx::TxAucMatch(_)),
+                )?;
+            }
+            RawTx::TxSecLimitOrder(tx) => {
+                self.check_registered(tx.sender_id)?;
+                self.check_token_id(tx.sell_token_id)?;
+                self.check_token_id(tx.buy_token_id)?;
+                let maturity = self.check_bond_pair(tx.sell_token_id, tx.buy_token_id)?;
+                check_maturity(tx.matched_time, maturity)?;
+            }
+            RawTx::TxSecLimitStart(tx) => {
+                self.check_offset(tx_id, tx.taker_tx_offset, "limit order", |tx| {
+                    matches!(tx, RawTx::TxSecLimitOrder(_))
+                })?;
+            }
+            RawTx::TxSecLimitExchange(tx) => {
+                self.check_offset(tx_id, tx.maker_tx_offset, "limit order", |tx| {
+                    matches!(tx, RawTx::TxSecLimitOrder(_))
+                })?;
+                self.find_batch_start(
+                    tx_id,
+                    "limit order start",
+                    |tx| matches!(tx, RawTx::TxSecLimitStart(_)),
+                    |tx| matches!(tx, RawTx::TxSecLimitExchange(_)),
+                )?;
+                self.find_batch_end(
+                    tx_id,
+                    "limit order end",
+                    |tx| matches!(tx, RawTx::TxSecLimitEnd(_)),
+                    |tx| matches!(tx, RawTx::TxSecLimitExchange(_)),
+                )?;
+            }
+            RawTx::TxSecLimitEnd(_) => {
+                self.find_batch_start(
+                    tx_id,
+                    "limit order start",
+                    |tx| matches!(tx, RawTx::TxSecLimitStart(_)),
+                    |tx| matches!(tx, RawTx::TxSecLimitExchange(_)),
+                )?;
+            }
+            RawTx::TxSecMarketOrder(tx) => {
+                self.check_registered(tx.sender_id)?;
+                self.check_token_id(tx.sell_token_id)?;
+                self.check_token_id(tx.buy_token_id)?;
+                self.check_bond_pair(tx.sell_token_id, tx.buy_token_id)?;
+            }
+            RawTx::TxSecMarketExchange(tx) => {
+                self.check_offset(tx_id, tx.maker_tx_offset, "limit order", |tx| {
+                    matches!(tx, RawTx::TxSecLimitOrder(_))
+                })?;
+                self.find_batch_start(
+                    tx_id,
+                    "market order",
+                    |tx| matches!(tx, RawTx::TxSecMarketOrder(_)),
+                    |tx| matches!(tx, RawTx::TxSecMarketExchange(_)),
+                )?;
+                self.find_batch_end(
+                    tx_id,
+                    "market order end",
+                    |tx| matches!(tx, RawTx::TxSecMarketEnd(_)),
+                    |tx| matches!(tx, RawTx::TxSecMarketExchange(_)),
+                )?;
+            }
+            RawTx::TxSecMarketEnd(_) => {
+                self.find_batch_start(
+                    tx_id,
+                    "market order",
+                    |tx| matches!(tx, RawTx::TxSecMarketOrder(_)),
+                    |tx| matches!(tx, RawTx::TxSecMarketExchange(_)),
+                )?;
+            }
+            RawTx::TxAdminCancel(tx) => {
+                self.check_cancel(tx_id, tx.tx_id)?;
+            }
+            RawTx::TxUserCancel(tx) => {
+                self.check_cancel(tx_id, tx.tx_id)?;
+                self.check_token_id(tx.tx_fee_token_id)?;
+            }
+            RawTx::TxIncreaseEpoch(_) => {}
+            RawTx::TxRedeem(tx) => {
+                self.check_registered(tx.sender_id)?;
+                self.check_token_id(tx.token_id)?;
+                if self.tsb_infos.get(tx.token_id as usize)? == TSBInfo::default() {
+                    return Err(format!("token {} is not a bond token", tx.token_id));
+                }
+            }
+            RawTx::TxWithdrawFee(tx) => {
+                self.check_token_id(tx.token_id)?;
+            }
+            RawTx::TxEvacuation(tx) => {
+                self.check_registered(tx.sender_id)?;
+                self.check_token_id(tx.token_id)?;
+            }
+            RawTx::TxSetAdminTsAddr(_) => {}
+        }
+        Ok(())
+    }
+    fn check_acc_id(&self, acc_id: u64) -> Result<(), String> {
+        if acc_id >= 1 << self.params.account_tree_height {
+            return Err(format!(
+                "account id {} does not fit the account tree",
+                acc_id
+            ));
+        }
+        Ok(())
+    }
+    fn check_token_id(&self, token_id: u64) -> Result<(), String> {
+        if token_id >= 1 << self.params.token_tree_height {
+            return Err(format!("token id {} does not fit the token tree", token_id));
+        }
+        Ok(())
+    }
+    fn check_registered(&self, acc_id: u64) -> Result<(), String> {
+        self.check_acc_id(acc_id)?;
+        if self.accounts.leaf_at(acc_id as usize)?.l2_addr.is_zero() {
+            return Err(format!("account {} is not registered", acc_id));
+        }
+        Ok(())
+    }
+    // one side of a secondary market order is a bond token, returns its maturity
+    fn check_bond_pair(&self, sell_token_id: u64, buy_token_id: u64) -> Result<Fr, String> {
+        for token_id in [buy_token_id, sell_token_id] {
+            let tsb_info = self.tsb_infos.get(token_id as usize)?;
+            if tsb_info != TSBInfo::default() {
+                return Ok(tsb_info.maturity);
+            }
+        }
+        Err(format!(
+            "neither token {} nor token {} is a bond token",
+            sell_token_id, buy_token_id
+        ))
+    }
+    fn check_offset(
+        &self,
+        tx_id: usize,
+        offset: u64,
+        expected: &str,
+        is_expected: impl Fn(&RawTx) -> bool,
+    ) -> Result<usize, String> {
+        let target_tx_id = tx_id
+            .checked_sub(offset as usize)
+            .ok_or(format!("offset {} points before the first tx", offset))?;
+        if offset == 0 || !is_expected(&self.txs.get(target_tx_id)?.raw_tx) {
+            return Err(format!(
+                "offset {} does not point at the {}",
+                offset, expected
+            ));
+        }
+        Ok(target_tx_id)
+    }
+    fn check_cancel(&self, tx_id: usize, order_tx_id: u64) -> Result<(), String> {
+        if order_tx_id as usize >= tx_id {
+            return Err(format!("canceled tx {} is not a previous tx", order_tx_id));
+        }
+        match self.txs.get(order_tx_id as usize)?.raw_tx {
+            RawTx::TxAucLend(_)
+            | RawTx::TxAucBorrow(_)
+            | RawTx::TxSecLimitOrder(_)
+            | RawTx::TxSecMarketOrder(_) => Ok(()),
+            _ => Err(format!("canceled tx {} is not an order", order_tx_id)),
+        }
+    }
+    // walks back over the body of a matching batch to the tx that opens it
+    fn find_batch_start(
+        &self,
+        tx_id: usize,
+        expected: &str,
+        is_start: impl Fn(&RawTx) -> bool,
+        is_body: impl Fn(&RawTx) -> bool,
+    ) -> Result<RawTx, String> {
+        let mut tmp = tx_id;
+        loop {
+            tmp = tmp
+                .checked_sub(1)
+                .ok_or(format!("no {} before this tx", expected))?;
+            let raw_tx = self.txs.get(tmp)?.raw_tx;
+            if is_start(&raw_tx) {
+                return Ok(raw_tx);
+            }
+            if !is_body(&raw_tx) {
+                return Err(format!("tx {} is not the {}", tmp, expected));
+            }
+        }
+    }
+    // walks forward over the body of a matching batch to the tx that closes it
+    fn find_batch_end(
+        &self,
+        tx_id: usize,
+        expected: &str,
+        is_end: impl Fn(&RawTx) -> bool,
+        is_body: impl Fn(&RawTx) -> bool,
+    ) -> Result<RawTx, String> {
+        let mut tmp = tx_id;
+        loop {
+            tmp += 1;
+            let raw_tx = self.txs.get(tmp)?.raw_tx;
+            if is_end(&raw_tx) {
+                return Ok(raw_tx);
+            }
+            if !is_body(&raw_tx) {
+                return Err(format!("tx {} is not the {}", tmp, expected));
+            }
+        }
+    }
+}
+
+fn check_maturity(matched_time: Fr, maturity: Fr) -> Result<(), String> {
+    if maturity < matched_time {
+        return Err(format!(
+            "maturity {} is before the matched time {}",
+            maturity, matched_time
+        ));
+    }
+    Ok(())
+}
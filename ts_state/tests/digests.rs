@@ -0,0 +1,228 @@
+use ark_bn254::Fr;
+use std::collections::HashMap;
+use std::str::FromStr;
+use ts_merkle_tree::{verify_merkle_prf, MerkleLeaf, MerkleTree, MerkleTreeWithLeaves};
+use ts_poseidon::poseidon;
+use ts_state::{
+    calc_account_digest, calc_state_root, constants::Params, Account, Array, Map, State, TSBInfo,
+    Token, Tx, Value,
+};
+use ts_tx::*;
+
+#[derive(Clone, Default)]
+struct MemArray<T: Clone + Default>(HashMap<usize, T>);
+impl<T: Clone + Default> Array<T> for MemArray<T> {
+    fn get(&self, index: usize) -> Result<T, String> {
+        Ok(self.0.get(&index).cloned().unwrap_or_default())
+    }
+    fn set(&mut self, index: usize, elem: &T) -> Result<(), String> {
+        self.0.insert(index, elem.clone());
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct MemValue(Fr);
+impl Value for MemValue {
+    fn get(&self) -> Result<Fr, String> {
+        Ok(self.0)
+    }
+    fn set(&mut self, value: &Fr) -> Result<(), String> {
+        self.0 = *value;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct MemMap(HashMap<(usize, Fr), u64>);
+impl Map<TSBInfo, u64> for MemMap {
+    fn get(&self, key: &TSBInfo) -> Result<Option<u64>, String> {
+        Ok(self.0.get(&(key.base_token_id, key.maturity)).copied())
+    }
+    fn set(&mut self, key: &TSBInfo, elem: &u64) -> Result<(), String> {
+        self.0.insert((key.base_token_id, key.maturity), *elem);
+        Ok(())
+    }
+}
+
+type MemAccount = Account<MemArray<Option<Fr>>, MemArray<Token>>;
+type MemState = State<
+    MemValue,
+    MemArray<Option<Fr>>,
+    MemArray<MemAccount>,
+    MemArray<Option<Fr>>,
+    MemArray<Token>,
+    MemArray<TSBInfo>,
+    MemMap,
+    MemArray<Tx>,
+>;
+
+// the leaves and roots are those of the evacuation circuit: a token leaf is poseidon(2, avl, locked),
+// an account leaf poseidon(3, l2 address, nonce, token root) with 16 token levels, and the state root
+// poseidon(2, ts root, account root) with 32 account levels, all the nodes being poseidon(left, right).
+// the expected values were computed from these definitions with the plain Poseidon permutation of the
+// paper and dense trees, independently of this crate. no witness of the circuit was at hand, so none of
+// them come from one
+
+fn fr(s: &str) -> Fr {
+    Fr::from_str(s).unwrap()
+}
+
+fn token(avl_amt: u64, locked_amt: u64) -> Token {
+    Token {
+        avl_amt: Fr::from(avl_amt),
+        locked_amt: Fr::from(locked_amt),
+    }
+}
+
+fn account(l2_addr: u64, nonce: u64, tokens: &[(u64, Token)]) -> MemAccount {
+    let mut account = MemAccount {
+        l2_addr: Fr::from(l2_addr),
+        nonce: Fr::from(nonce),
+        ..Default::default()
+    };
+    for (token_id, token) in tokens {
+        account
+            .tokens
+            .update(*token_id, |leaf| {
+                *leaf = *token;
+                Ok(())
+            })
+            .unwrap();
+    }
+    account
+}
+
+#[test]
+fn token_digest() {
+    assert_eq!(
+        Token::default().digest().unwrap(),
+        fr("13234400070188801104792523922697988244748411503422448631147834118387475842488")
+    );
+    assert_eq!(
+        token(1000, 250).digest().unwrap(),
+        fr("16448808129091869562819762337227215558423673241659360378077711880111219474590")
+    );
+}
+
+#[test]
+fn account_digest() {
+    assert_eq!(
+        MemAccount::default().digest().unwrap(),
+        fr("16183193725301591698192037611634432755833427304481264069486357135144334538913")
+    );
+    assert_eq!(
+        account(0x1234, 3, &[(1, token(1000, 250)), (2, token(5, 0))])
+            .digest()
+            .unwrap(),
+        fr("7390869262422947131995448111593569299823302949887434791994593487451738970757")
+    );
+}
+
+#[test]
+fn state_root() {
+    let mut state = MemState::default();
+    assert_eq!(
+        state.get_root().unwrap(),
+        fr("11111136759937335548574249596774232166565402845047929052520827150573194067803")
+    );
+    state.set_ts_root(Fr::from(0xabcdu64)).unwrap();
+    for (acc_id, account) in [
+        (
+            2,
+            account(0x1234, 3, &[(1, token(1000, 250)), (2, token(5, 0))]),
+        ),
+        (5, account(0x5678, 0, &[(48, token(7, 0))])),
+    ] {
+        state.accounts.update_leaf(acc_id, account).unwrap();
+    }
+    assert_eq!(
+        state.get_root().unwrap(),
+        fr("20582062931668722598761031746001915548807481600816331565119867619150360339855")
+    );
+}
+
+// the root of a dense tree over all its leaves
+fn dense_root(mut nodes: Vec<Fr>) -> Fr {
+    while nodes.len() > 1 {
+        nodes = nodes
+            .chunks(2)
+            .map(|pair| poseidon::<3>(&[pair[0], pair[1]]))
+            .collect();
+    }
+    nodes[0]
+}
+
+#[test]
+fn state_root_with_deployment_heights() {
+    let params = Params {
+        account_tree_height: 3,
+        token_tree_height: 2,
+        tx_count_per_block: 8,
+    };
+    let mut state = MemState::new(params);
+    let default_token_root = dense_root(vec![Token::default().digest().unwrap(); 4]);
+    let default_leaf = calc_account_digest(Fr::from(0u64), Fr::from(0u64), default_token_root);
+    assert_eq!(state.accounts.default_proof.len(), 3);
+    assert_eq!(
+        state.get_root().unwrap(),
+        calc_state_root(Fr::from(0u64), dense_root(vec![default_leaf; 8]))
+    );
+
+    let raw_txs = [
+        ts_tx::Tx::TxRegister(TxRegister {
+            account_id: 1,
+            hashed_pub_key: Fr::from(0x7501u64),
+        }),
+        ts_tx::Tx::TxDeposit(TxDeposit {
+            account_id: 1,
+            deposit_token_id: 3,
+            deposit_amt: Fr::from(500u64),
+        }),
+        ts_tx::Tx::TxRegister(TxRegister {
+            account_id: 7,
+            hashed_pub_key: Fr::from(0x7507u64),
+        }),
+    ];
+    for (tx_id, raw_tx) in raw_txs.iter().enumerate() {
+        state.push_tx(tx_id, *raw_tx).unwrap();
+        state.update(tx_id).unwrap();
+    }
+    let mut tokens = vec![Token::default().digest().unwrap(); 4];
+    tokens[3] = token(500, 0).digest().unwrap();
+    let mut leaves = vec![default_leaf; 8];
+    leaves[1] = calc_account_digest(Fr::from(0x7501u64), Fr::from(0u64), dense_root(tokens));
+    leaves[7] = calc_account_digest(Fr::from(0x7507u64), Fr::from(0u64), default_token_root);
+    let acc_root = dense_root(leaves);
+    assert_eq!(
+        state.get_root().unwrap(),
+        calc_state_root(Fr::from(0u64), acc_root)
+    );
+
+    // the proofs have the heights of the deployment too
+    let acc_prf = state.accounts.verify_leaf_node(7).unwrap();
+    assert_eq!(acc_prf.proof.len(), 3);
+    assert_eq!(acc_prf.ori_root, acc_root);
+    verify_merkle_prf(|left, right| Ok(poseidon::<3>(&[left, right])), &acc_prf).unwrap();
+    let token_prf = state
+        .accounts
+        .leaf_at(1)
+        .unwrap()
+        .tokens
+        .verify_leaf_node(3)
+        .unwrap();
+    assert_eq!(token_prf.proof.len(), 2);
+
+    // and only the ids that fit them are accepted
+    state
+        .push_tx(
+            3,
+            ts_tx::Tx::TxDeposit(TxDeposit {
+                account_id: 1,
+                deposit_token_id: 4,
+                deposit_amt: Fr::from(500u64),
+            }),
+        )
+        .unwrap();
+    assert!(state.validate(3).is_err());
+}
@@ -0,0 +1,130 @@
+use ark_bn254::Fr;
+use ts_state::{Order, OrderType, Tx};
+use ts_tx::*;
+
+const T0: u64 = 1700000000;
+const DAY: u64 = 86400;
+
+fn tx(raw_tx: ts_tx::Tx, cum_deducted_amt: u64, cum_target_amt: u64, locked_amt: u64) -> Tx {
+    Tx {
+        raw_tx,
+        cum_deducted_amt: Fr::from(cum_deducted_amt),
+        cum_target_amt: Fr::from(cum_target_amt),
+        locked_amt: Fr::from(locked_amt),
+        ..Tx::default()
+    }
+}
+
+fn lend() -> ts_tx::Tx {
+    ts_tx::Tx::TxAucLend(TxAucLend {
+        lender_id: 1,
+        lending_token_id: 1,
+        lending_amt: Fr::from(1000u64),
+        fee_rate: Fr::from(1000000u64),
+        default_matched_interest_rate: Fr::from(5000000u64),
+        maturity_time: Fr::from(T0 + 180 * DAY),
+        matched_time: Fr::from(T0),
+        primary_lend_min_fee_amt: Fr::from(10u64),
+    })
+}
+
+fn borrow() -> ts_tx::Tx {
+    ts_tx::Tx::TxAucBorrow(TxAucBorrow {
+        sender_id: 2,
+        collateral_token_id: 2,
+        collateral_amt: Fr::from(500u64),
+        fee_rate: Fr::from(1000000u64),
+        borrowing_amt: Fr::from(600u64),
+        matched_time: Fr::from(T0),
+        primary_borrow_min_fee_amt: Fr::from(10u64),
+    })
+}
+
+fn limit() -> ts_tx::Tx {
+    ts_tx::Tx::TxSecLimitOrder(TxSecLimitOrder {
+        sender_id: 3,
+        sell_token_id: 48,
+        sell_amt: Fr::from(400u64),
+        fee0: Fr::from(1000000u64),
+        fee1: Fr::from(500000u64),
+        buy_token_id: 1,
+        buy_amt: Fr::from(380u64),
+        expired_time: Fr::from(T0 + 30 * DAY),
+        matched_time: Fr::from(T0),
+        secondary_taker_min_fee_amt: Fr::from(10u64),
+        secondary_maker_min_fee_amt: Fr::from(10u64),
+    })
+}
+
+#[test]
+fn orders_are_read_from_their_txs() {
+    let order = Order::from_tx(5, &tx(lend(), 0, 0, 1010)).unwrap();
+    assert_eq!(order.tx_id, 5);
+    assert_eq!(order.order_type, OrderType::AucLend);
+    assert_eq!((order.acc_id, order.deducted_token_id), (1, 1));
+    assert_eq!(order.target_token_id, None);
+    assert_eq!(order.remaining_target_amt(), None);
+
+    let order = Order::from_tx(6, &tx(borrow(), 0, 0, 500)).unwrap();
+    assert_eq!(order.order_type, OrderType::AucBorrow);
+    assert_eq!((order.acc_id, order.deducted_token_id), (2, 2));
+    // the borrowed token is only known once the order is matched
+    assert_eq!(order.target_token_id, None);
+    assert_eq!(order.remaining_target_amt(), Some(Fr::from(600u64)));
+
+    let order = Order::from_tx(7, &tx(limit(), 0, 0, 400)).unwrap();
+    assert_eq!(order.order_type, OrderType::SecLimit);
+    assert_eq!(order.target_token_id, Some(1));
+
+    let deposit = ts_tx::Tx::TxDeposit(TxDeposit {
+        account_id: 1,
+        deposit_token_id: 1,
+        deposit_amt: Fr::from(1u64),
+    });
+    assert!(Order::from_tx(8, &tx(deposit, 0, 0, 0)).is_none());
+}
+
+#[test]
+fn open_and_partially_matched_orders() {
+    for raw_tx in [lend(), borrow(), limit()] {
+        let unmatched = Order::from_tx(0, &tx(raw_tx, 0, 0, 400)).unwrap();
+        assert!(unmatched.is_open());
+        assert!(!unmatched.is_partially_matched());
+
+        let partially_matched = Order::from_tx(0, &tx(raw_tx, 100, 0, 300)).unwrap();
+        assert!(partially_matched.is_open());
+        assert!(partially_matched.is_partially_matched());
+        let partially_matched = Order::from_tx(0, &tx(raw_tx, 0, 90, 300)).unwrap();
+        assert!(partially_matched.is_partially_matched());
+
+        // filled or canceled, nothing is locked anymore
+        let closed = Order::from_tx(0, &tx(raw_tx, 100, 90, 0)).unwrap();
+        assert!(!closed.is_open());
+    }
+}
+
+#[test]
+fn remaining_amounts() {
+    let order = Order::from_tx(0, &tx(limit(), 150, 140, 250)).unwrap();
+    assert_eq!(order.remaining_deducted_amt(), Fr::from(250u64));
+    assert_eq!(order.remaining_target_amt(), Some(Fr::from(240u64)));
+
+    let order = Order::from_tx(0, &tx(borrow(), 0, 200, 500)).unwrap();
+    assert_eq!(order.remaining_deducted_amt(), Fr::from(500u64));
+    assert_eq!(order.remaining_target_amt(), Some(Fr::from(400u64)));
+
+    let order = Order::from_tx(0, &tx(lend(), 1000, 0, 10)).unwrap();
+    assert_eq!(order.remaining_deducted_amt(), Fr::from(0u64));
+}
+
+#[test]
+fn only_limit_orders_expire() {
+    let order = Order::from_tx(0, &tx(limit(), 0, 0, 400)).unwrap();
+    assert!(!order.is_expired(Fr::from(T0 + 30 * DAY)));
+    assert!(order.is_expired(Fr::from(T0 + 30 * DAY + 1)));
+    // past their maturity, the auction orders are still open until canceled
+    for raw_tx in [lend(), borrow()] {
+        let order = Order::from_tx(0, &tx(raw_tx, 0, 0, 400)).unwrap();
+        assert!(!order.is_expired(Fr::from(T0 + 365 * DAY)));
+    }
+}
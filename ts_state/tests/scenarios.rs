@@ -0,0 +1,586 @@
+use ark_bn254::Fr;
+use std::collections::{BTreeMap, HashMap};
+use ts_merkle_tree::MerkleTreeWithLeaves;
+use ts_state::{Account, Array, Map, State, TSBInfo, Token, Tx, Value};
+use ts_tx::*;
+
+#[derive(Clone, Default)]
+struct MemArray<T: Clone + Default>(HashMap<usize, T>);
+impl<T: Clone + Default> Array<T> for MemArray<T> {
+    fn get(&self, index: usize) -> Result<T, String> {
+        Ok(self.0.get(&index).cloned().unwrap_or_default())
+    }
+    fn set(&mut self, index: usize, elem: &T) -> Result<(), String> {
+        self.0.insert(index, elem.clone());
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct MemValue(Fr);
+impl Value for MemValue {
+    fn get(&self) -> Result<Fr, String> {
+        Ok(self.0)
+    }
+    fn set(&mut self, value: &Fr) -> Result<(), String> {
+        self.0 = *value;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct MemMap(HashMap<(usize, Fr), u64>);
+impl Map<TSBInfo, u64> for MemMap {
+    fn get(&self, key: &TSBInfo) -> Result<Option<u64>, String> {
+        Ok(self.0.get(&(key.base_token_id, key.maturity)).copied())
+    }
+    fn set(&mut self, key: &TSBInfo, elem: &u64) -> Result<(), String> {
+        self.0.insert((key.base_token_id, key.maturity), *elem);
+        Ok(())
+    }
+}
+
+type MemAccount = Account<MemArray<Option<Fr>>, MemArray<Token>>;
+type MemState = State<
+    MemValue,
+    MemArray<Option<Fr>>,
+    MemArray<MemAccount>,
+    MemArray<Option<Fr>>,
+    MemArray<Token>,
+    MemArray<TSBInfo>,
+    MemMap,
+    MemArray<Tx>,
+>;
+
+const T0: u64 = 1700000000;
+const DAY: u64 = 86400;
+const MATURITY: u64 = T0 + 180 * DAY;
+const BASE_TOKEN_ID: u64 = 1;
+const COLLATERAL_TOKEN_ID: u64 = 2;
+const BOND_TOKEN_ID: u64 = 48;
+
+// runs the txs one by one and keeps the state root after each of them
+struct Scenario {
+    name: &'static str,
+    state: MemState,
+    raw_txs: Vec<ts_tx::Tx>,
+    roots: Vec<Fr>,
+}
+impl Scenario {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            state: MemState::default(),
+            raw_txs: vec![],
+            roots: vec![],
+        }
+    }
+    fn apply(&mut self, raw_tx: ts_tx::Tx) -> usize {
+        self.apply_batch(vec![raw_tx])
+    }
+    // a matching batch is validated against its end tx, so the whole batch is pushed first
+    fn apply_batch(&mut self, raw_txs: Vec<ts_tx::Tx>) -> usize {
+        let start_tx_id = self.roots.len();
+        for (offset, raw_tx) in raw_txs.iter().enumerate() {
+            self.state.push_tx(start_tx_id + offset, *raw_tx).unwrap();
+        }
+        self.raw_txs.extend(raw_txs.iter().copied());
+        for tx_id in start_tx_id..start_tx_id + raw_txs.len() {
+            self.state.update(tx_id).unwrap();
+            self.roots.push(self.state.get_root().unwrap());
+        }
+        start_tx_id
+    }
+    fn register(&mut self, account_id: u64) {
+        self.apply(ts_tx::Tx::TxRegister(TxRegister {
+            account_id,
+            hashed_pub_key: Fr::from(0x7500 + account_id),
+        }));
+    }
+    fn deposit(&mut self, account_id: u64, deposit_token_id: u64, deposit_amt: u64) {
+        self.apply(ts_tx::Tx::TxDeposit(TxDeposit {
+            account_id,
+            deposit_token_id,
+            deposit_amt: Fr::from(deposit_amt),
+        }));
+    }
+    fn create_bond_token(&mut self) {
+        self.apply(ts_tx::Tx::TxCreateTsbBondToken(TxCreateTsbBondToken {
+            maturity: Fr::from(MATURITY),
+            base_token_id: BASE_TOKEN_ID,
+            bond_token_id: BOND_TOKEN_ID,
+        }));
+    }
+    fn balance(&self, acc_id: usize, token_id: usize) -> (Fr, Fr) {
+        let token = self
+            .state
+            .accounts
+            .leaf_at(acc_id)
+            .unwrap()
+            .tokens
+            .leaf_at(token_id)
+            .unwrap();
+        (token.avl_amt, token.locked_amt)
+    }
+    fn assert_balance(&self, acc_id: usize, token_id: usize, avl_amt: u64, locked_amt: u64) {
+        assert_eq!(
+            self.balance(acc_id, token_id),
+            (Fr::from(avl_amt), Fr::from(locked_amt)),
+            "{}: balance of account {} token {}",
+            self.name,
+            acc_id,
+            token_id
+        );
+    }
+    // replays all the txs as a single block, one by one and on several threads,
+    // which must give the same balance changes per tx and the same final root
+    fn assert_parallel_replay(&self) {
+        let mut serial = MemState::default();
+        let mut parallel = MemState::default();
+        for state in [&mut serial, &mut parallel] {
+            for (tx_id, raw_tx) in self.raw_txs.iter().enumerate() {
+                state.push_tx(tx_id, *raw_tx).unwrap();
+            }
+            state.set_deferred(true).unwrap();
+            state.set_journaling(true).unwrap();
+        }
+        let mut serial_changes = vec![];
+        for tx_id in 0..self.raw_txs.len() {
+            serial.update(tx_id).unwrap();
+            serial_changes.push((tx_id, serial.take_balance_changes().unwrap()));
+        }
+        let parallel_changes = parallel.update_parallel(0..self.raw_txs.len(), 4).unwrap();
+        serial.commit().unwrap();
+        parallel.commit().unwrap();
+        assert_eq!(
+            format!("{:?}", parallel_changes),
+            format!("{:?}", serial_changes),
+            "{}: balance changes",
+            self.name
+        );
+        assert_eq!(serial.get_root().unwrap(), *self.roots.last().unwrap());
+        assert_eq!(
+            parallel.get_root().unwrap(),
+            serial.get_root().unwrap(),
+            "{}: state root of the parallel replay",
+            self.name
+        );
+    }
+    // audits all the txs, whose fees and loans must account for every token the accounts hold
+    fn assert_audited(&self) {
+        let mut state = MemState::default();
+        for (tx_id, raw_tx) in self.raw_txs.iter().enumerate() {
+            state.push_tx(tx_id, *raw_tx).unwrap();
+        }
+        state.set_journaling(true).unwrap();
+        let mut ledgers = BTreeMap::new();
+        for tx_id in 0..self.raw_txs.len() {
+            state.update_and_audit(tx_id, &mut ledgers).unwrap();
+        }
+        for (token_id, ledger) in &ledgers {
+            assert_eq!(
+                ledger.surplus_amt(),
+                Fr::from(0u64),
+                "{}: surplus of token {}",
+                self.name,
+                token_id
+            );
+        }
+    }
+    // the roots are pinned in tests/golden/<name>.txt, one per tx. they were recorded from this
+    // crate, the circuit could not be run to check them. UPDATE_GOLDEN=1 records them again
+    fn assert_golden_roots(&self) {
+        let path = format!(
+            "{}/tests/golden/{}.txt",
+            env!("CARGO_MANIFEST_DIR"),
+            self.name
+        );
+        let roots: Vec<String> = self.roots.iter().map(|root| root.to_string()).collect();
+        if std::env::var("UPDATE_GOLDEN").is_ok() {
+            std::fs::write(&path, roots.join("\n") + "\n").unwrap();
+            return;
+        }
+        let golden = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("{}: golden file {}: {}", self.name, path, e));
+        let golden: Vec<&str> = golden.lines().collect();
+        assert_eq!(golden.len(), roots.len(), "{}: tx count", self.name);
+        for (tx_id, (root, golden)) in roots.iter().zip(golden).enumerate() {
+            assert_eq!(root, golden, "{}: state root after tx {}", self.name, tx_id);
+        }
+    }
+}
+
+#[test]
+fn transfer_and_withdraw() {
+    let mut s = Scenario::new("transfer_and_withdraw");
+    s.register(1);
+    s.register(2);
+    s.deposit(1, BASE_TOKEN_ID, 1000000);
+    s.apply(ts_tx::Tx::TxTransfer(TxTransfer {
+        sender_id: 1,
+        transfer_token_id: BASE_TOKEN_ID,
+        transfer_amt: Fr::from(300000u64),
+        receiver_id: 2,
+    }));
+    s.apply(ts_tx::Tx::TxWithdraw(TxWithdraw {
+        account_id: 2,
+        withdraw_token_id: BASE_TOKEN_ID,
+        withdraw_amt: Fr::from(100000u64),
+        tx_fee_token_id: BASE_TOKEN_ID,
+        tx_fee_amt: Fr::from(1000u64),
+    }));
+    s.apply(ts_tx::Tx::TxForcedWithdraw(TxForcedWithdraw {
+        account_id: 1,
+        withdraw_token_id: BASE_TOKEN_ID,
+        withdraw_amt: Fr::from(50000u64),
+    }));
+    s.assert_balance(1, BASE_TOKEN_ID as usize, 650000, 0);
+    s.assert_balance(2, BASE_TOKEN_ID as usize, 199000, 0);
+    assert_eq!(s.state.accounts.leaf_at(1).unwrap().nonce, Fr::from(1u64));
+    assert_eq!(s.state.accounts.leaf_at(2).unwrap().nonce, Fr::from(1u64));
+
+    s.apply(ts_tx::Tx::TxEvacuation(TxEvacuation {
+        sender_id: 2,
+        token_id: BASE_TOKEN_ID,
+        amount: Fr::from(199000u64),
+    }));
+    s.assert_balance(2, BASE_TOKEN_ID as usize, 0, 0);
+    s.assert_golden_roots();
+    s.assert_parallel_replay();
+    s.assert_audited();
+}
+
+#[test]
+fn auction_match_cancel_and_redeem() {
+    let mut s = Scenario::new("auction_match_cancel_and_redeem");
+    s.create_bond_token();
+    s.register(1);
+    s.register(2);
+    s.deposit(1, BASE_TOKEN_ID, 200000000);
+    s.deposit(2, COLLATERAL_TOKEN_ID, 100000000);
+
+    let lend_tx_id = s.apply(ts_tx::Tx::TxAucLend(TxAucLend {
+        lender_id: 1,
+        lending_token_id: BASE_TOKEN_ID,
+        lending_amt: Fr::from(100000000u64),
+        fee_rate: Fr::from(1000000u64),
+        default_matched_interest_rate: Fr::from(5000000u64),
+        maturity_time: Fr::from(MATURITY),
+        matched_time: Fr::from(T0),
+        primary_lend_min_fee_amt: Fr::from(10000u64),
+    }));
+    s.assert_balance(1, BASE_TOKEN_ID as usize, 99975480, 100024520);
+
+    s.apply(ts_tx::Tx::TxAucBorrow(TxAucBorrow {
+        sender_id: 2,
+        collateral_token_id: COLLATERAL_TOKEN_ID,
+        collateral_amt: Fr::from(50000000u64),
+        fee_rate: Fr::from(1000000u64),
+        borrowing_amt: Fr::from(60000000u64),
+        matched_time: Fr::from(T0),
+        primary_borrow_min_fee_amt: Fr::from(10000u64),
+    }));
+    s.assert_balance(2, COLLATERAL_TOKEN_ID as usize, 50000000, 50000000);
+
+    s.apply_batch(vec![
+        ts_tx::Tx::TxAucStart(TxAucStart {
+            borrower_tx_offset: 1,
+            ori_matched_interest: Fr::from(105000000u64),
+        }),
+        ts_tx::Tx::TxAucMatch(TxAucMatch {
+            lender_tx_offset: 3,
+        }),
+        ts_tx::Tx::TxAucEnd(TxAucEnd {
+            borrow_account: Fr::from(2u64),
+            collateral_token_id: COLLATERAL_TOKEN_ID,
+            collateral_amt: Fr::from(50000000u64),
+            debt_token_id: BASE_TOKEN_ID,
+            debt_amt: Fr::from(61471232u64),
+            matched_time: Fr::from(T0),
+            maturity: Fr::from(MATURITY),
+        }),
+    ]);
+    s.assert_balance(1, BASE_TOKEN_ID as usize, 99975480, 40009808);
+    s.assert_balance(1, BOND_TOKEN_ID as usize, 61471232, 0);
+    s.assert_balance(2, COLLATERAL_TOKEN_ID as usize, 50000000, 0);
+    s.assert_balance(2, BASE_TOKEN_ID as usize, 59985288, 0);
+    let bonds = s.state.get_bond_positions(2, 1).unwrap();
+    assert_eq!(bonds.len(), 1);
+    assert_eq!(
+        (bonds[0].bond_token_id, bonds[0].base_token_id),
+        (BOND_TOKEN_ID, BASE_TOKEN_ID)
+    );
+    assert_eq!(bonds[0].avl_amt, Fr::from(61471232u64));
+    assert_eq!(bonds[0].redeemable_amt(Fr::from(T0)), Fr::from(0u64));
+    assert_eq!(
+        bonds[0].redeemable_amt(Fr::from(MATURITY)),
+        Fr::from(61471232u64)
+    );
+    let loans = s.state.get_loan_positions(2, 2).unwrap();
+    assert_eq!(loans.len(), 1);
+    assert_eq!(
+        (loans[0].collateral_token_id, loans[0].debt_token_id),
+        (COLLATERAL_TOKEN_ID, BASE_TOKEN_ID)
+    );
+    assert_eq!(
+        (loans[0].matched_collateral_amt, loans[0].matched_debt_amt),
+        (Fr::from(50000000u64), Fr::from(61471232u64))
+    );
+    assert!(s.state.get_loan_positions(2, 1).unwrap().is_empty());
+
+    s.apply(ts_tx::Tx::TxUserCancel(TxUserCancel {
+        tx_id: lend_tx_id as u64,
+        tx_fee_token_id: BASE_TOKEN_ID,
+        tx_fee_amt: Fr::from(1000u64),
+    }));
+    s.assert_balance(1, BASE_TOKEN_ID as usize, 139984288, 0);
+
+    s.apply(ts_tx::Tx::TxRedeem(TxRedeem {
+        sender_id: 1,
+        token_id: BOND_TOKEN_ID,
+        amount: Fr::from(61471232u64),
+    }));
+    s.assert_balance(1, BOND_TOKEN_ID as usize, 0, 0);
+    s.assert_balance(1, BASE_TOKEN_ID as usize, 201455520, 0);
+    // the redeemed bonds are gone, the loan is still reported as matched
+    assert!(s.state.get_bond_positions(2, 1).unwrap().is_empty());
+    let loans = s.state.get_loan_positions(2, 2).unwrap();
+    assert_eq!(loans.len(), 1);
+    assert_eq!(loans[0].matched_debt_amt, Fr::from(61471232u64));
+    s.assert_golden_roots();
+    s.assert_parallel_replay();
+    s.assert_audited();
+}
+
+#[test]
+fn secondary_limit_and_market_exchange() {
+    let mut s = Scenario::new("secondary_limit_and_market_exchange");
+    s.create_bond_token();
+    s.register(1);
+    s.register(2);
+    s.deposit(1, BOND_TOKEN_ID, 100000000);
+    s.deposit(2, BASE_TOKEN_ID, 100000000);
+
+    let maker_tx_id = s.apply(ts_tx::Tx::TxSecLimitOrder(TxSecLimitOrder {
+        sender_id: 1,
+        sell_token_id: BOND_TOKEN_ID,
+        sell_amt: Fr::from(50000000u64),
+        fee0: Fr::from(1000000u64),
+        fee1: Fr::from(500000u64),
+        buy_token_id: BASE_TOKEN_ID,
+        buy_amt: Fr::from(48000000u64),
+        expired_time: Fr::from(T0 + 30 * DAY),
+        matched_time: Fr::from(T0),
+        secondary_taker_min_fee_amt: Fr::from(1000u64),
+        secondary_maker_min_fee_amt: Fr::from(1000u64),
+    }));
+    s.assert_balance(1, BOND_TOKEN_ID as usize, 50000000, 50000000);
+
+    s.apply(ts_tx::Tx::TxSecLimitOrder(TxSecLimitOrder {
+        sender_id: 2,
+        sell_token_id: BASE_TOKEN_ID,
+        sell_amt: Fr::from(30000000u64),
+        fee0: Fr::from(1000000u64),
+        fee1: Fr::from(500000u64),
+        buy_token_id: BOND_TOKEN_ID,
+        buy_amt: Fr::from(31000000u64),
+        expired_time: Fr::from(T0 + 30 * DAY),
+        matched_time: Fr::from(T0 + DAY),
+        secondary_taker_min_fee_amt: Fr::from(1000u64),
+        secondary_maker_min_fee_amt: Fr::from(1000u64),
+    }));
+    s.apply_batch(vec![
+        ts_tx::Tx::TxSecLimitStart(TxSecLimitStart { taker_tx_offset: 1 }),
+        ts_tx::Tx::TxSecLimitExchange(TxSecLimitExchange { maker_tx_offset: 3 }),
+        ts_tx::Tx::TxSecLimitEnd(TxSecLimitEnd {
+            matched_time: Fr::from(T0 + DAY),
+        }),
+    ]);
+    s.assert_balance(1, BOND_TOKEN_ID as usize, 50000000, 19000000);
+    s.assert_balance(1, BASE_TOKEN_ID as usize, 30303224, 0);
+    s.assert_balance(2, BOND_TOKEN_ID as usize, 31000000, 0);
+    s.assert_balance(2, BASE_TOKEN_ID as usize, 69468736, 0);
+
+    s.apply_batch(vec![
+        ts_tx::Tx::TxSecMarketOrder(TxSecMarketOrder {
+            sender_id: 2,
+            sell_token_id: BASE_TOKEN_ID,
+            sell_amt: Fr::from(10000000u64),
+            fee0: Fr::from(1000000u64),
+            buy_token_id: BOND_TOKEN_ID,
+            buy_amt: Fr::from(10000000u64),
+            expired_time: Fr::from(T0 + 30 * DAY),
+            secondary_taker_min_fee_amt: Fr::from(1000u64),
+        }),
+        ts_tx::Tx::TxSecMarketExchange(TxSecMarketExchange {
+            maker_tx_offset: (s.roots.len() + 1 - maker_tx_id) as u64,
+        }),
+        ts_tx::Tx::TxSecMarketEnd(TxSecMarketEnd {
+            matched_time: Fr::from(T0 + 2 * DAY),
+        }),
+    ]);
+    s.assert_balance(1, BOND_TOKEN_ID as usize, 50000000, 9000000);
+    s.assert_balance(2, BOND_TOKEN_ID as usize, 41000000, 0);
+    s.assert_balance(1, BASE_TOKEN_ID as usize, 40079691, 0);
+    s.assert_balance(2, BASE_TOKEN_ID as usize, 59619119, 0);
+
+    s.apply(ts_tx::Tx::TxAdminCancel(TxAdminCancel {
+        tx_id: maker_tx_id as u64,
+    }));
+    s.assert_balance(1, BOND_TOKEN_ID as usize, 59000000, 0);
+    s.assert_golden_roots();
+    s.assert_parallel_replay();
+    s.assert_audited();
+}
+
+#[test]
+fn parallel_replay_of_independent_accounts() {
+    let mut s = Scenario::new("parallel_replay_of_independent_accounts");
+    for account_id in 1..=12 {
+        s.register(account_id);
+        s.deposit(account_id, BASE_TOKEN_ID, 1000000 * account_id);
+    }
+    // pairs of accounts that only trade with each other, then a transfer joining two pairs
+    for account_id in (1..=12).step_by(2) {
+        s.apply(ts_tx::Tx::TxTransfer(TxTransfer {
+            sender_id: account_id,
+            transfer_token_id: BASE_TOKEN_ID,
+            transfer_amt: Fr::from(1000u64 * account_id),
+            receiver_id: account_id + 1,
+        }));
+    }
+    s.apply(ts_tx::Tx::TxTransfer(TxTransfer {
+        sender_id: 2,
+        transfer_token_id: BASE_TOKEN_ID,
+        transfer_amt: Fr::from(500u64),
+        receiver_id: 11,
+    }));
+    s.create_bond_token();
+    for account_id in (2..=12).step_by(2) {
+        s.apply(ts_tx::Tx::TxWithdraw(TxWithdraw {
+            account_id,
+            withdraw_token_id: BASE_TOKEN_ID,
+            withdraw_amt: Fr::from(2000u64),
+            tx_fee_token_id: BASE_TOKEN_ID,
+            tx_fee_amt: Fr::from(10u64),
+        }));
+    }
+    s.register(300);
+    s.deposit(300, COLLATERAL_TOKEN_ID, 5000);
+    s.assert_balance(2, BASE_TOKEN_ID as usize, 1998490, 0);
+    s.assert_balance(11, BASE_TOKEN_ID as usize, 10989500, 0);
+    s.assert_balance(12, BASE_TOKEN_ID as usize, 12008990, 0);
+    s.assert_golden_roots();
+    s.assert_parallel_replay();
+    s.assert_audited();
+}
+
+#[test]
+fn balance_changes_are_journaled_per_tx() {
+    let mut s = Scenario::new("balance_changes_are_journaled_per_tx");
+    assert!(s.state.take_balance_changes().is_err());
+    s.state.set_journaling(true).unwrap();
+    s.register(1);
+    s.register(2);
+    assert!(s.state.take_balance_changes().unwrap().is_empty());
+    s.deposit(1, BASE_TOKEN_ID, 1000000);
+    s.apply(ts_tx::Tx::TxWithdraw(TxWithdraw {
+        account_id: 1,
+        withdraw_token_id: BASE_TOKEN_ID,
+        withdraw_amt: Fr::from(100000u64),
+        tx_fee_token_id: BASE_TOKEN_ID,
+        tx_fee_amt: Fr::from(1000u64),
+    }));
+    // the deposit and the withdrawal with its fee are merged into one change
+    let changes: Vec<(u64, u64, Fr, Fr)> = s
+        .state
+        .take_balance_changes()
+        .unwrap()
+        .iter()
+        .map(|c| (c.acc_id, c.token_id, c.avl_amt, c.locked_amt))
+        .collect();
+    assert_eq!(
+        changes,
+        vec![(1, BASE_TOKEN_ID, Fr::from(899000u64), Fr::from(0u64))]
+    );
+
+    s.apply(ts_tx::Tx::TxTransfer(TxTransfer {
+        sender_id: 1,
+        transfer_token_id: BASE_TOKEN_ID,
+        transfer_amt: Fr::from(300000u64),
+        receiver_id: 2,
+    }));
+    let changes: Vec<(u64, u64, Fr, Fr)> = s
+        .state
+        .take_balance_changes()
+        .unwrap()
+        .iter()
+        .map(|c| (c.acc_id, c.token_id, c.avl_amt, c.locked_amt))
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            (1, BASE_TOKEN_ID, -Fr::from(300000u64), Fr::from(0u64)),
+            (2, BASE_TOKEN_ID, Fr::from(300000u64), Fr::from(0u64)),
+        ]
+    );
+    // the stored leaves do not carry the changes into later updates
+    s.apply(ts_tx::Tx::TxRegister(TxRegister {
+        account_id: 3,
+        hashed_pub_key: Fr::from(0x7503u64),
+    }));
+    assert!(s.state.take_balance_changes().unwrap().is_empty());
+
+    s.state.set_journaling(false).unwrap();
+    assert!(s.state.take_balance_changes().is_err());
+}
+
+#[test]
+fn loan_positions_sum_the_matched_loans() {
+    let mut state = MemState::default();
+    let auc_end = |borrow_account: u64, collateral_amt: u64, debt_amt: u64, maturity: u64| {
+        ts_tx::Tx::TxAucEnd(TxAucEnd {
+            borrow_account: Fr::from(borrow_account),
+            collateral_token_id: COLLATERAL_TOKEN_ID,
+            collateral_amt: Fr::from(collateral_amt),
+            debt_token_id: BASE_TOKEN_ID,
+            debt_amt: Fr::from(debt_amt),
+            matched_time: Fr::from(T0),
+            maturity: Fr::from(maturity),
+        })
+    };
+    for (tx_id, raw_tx) in [
+        auc_end(2, 100, 110, MATURITY),
+        auc_end(3, 50, 55, MATURITY),
+        auc_end(2, 40, 44, MATURITY),
+        auc_end(2, 10, 12, MATURITY + DAY),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        state.push_tx(tx_id, raw_tx).unwrap();
+    }
+    let loans: Vec<(Fr, Fr, Fr)> = state
+        .get_loan_positions(2, 2)
+        .unwrap()
+        .iter()
+        .map(|loan| {
+            (
+                loan.maturity,
+                loan.matched_collateral_amt,
+                loan.matched_debt_amt,
+            )
+        })
+        .collect();
+    assert_eq!(
+        loans,
+        vec![
+            (Fr::from(MATURITY), Fr::from(140u64), Fr::from(154u64)),
+            (Fr::from(MATURITY + DAY), Fr::from(10u64), Fr::from(12u64)),
+        ]
+    );
+    assert_eq!(state.get_loan_positions(2, 3).unwrap().len(), 1);
+    assert!(state.get_loan_positions(2, 4).unwrap().is_empty());
+    // no block is synced
+    assert!(state.get_loan_positions(1, 2).unwrap().is_empty());
+}
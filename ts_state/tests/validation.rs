@@ -0,0 +1,251 @@
+use ark_bn254::Fr;
+use std::collections::HashMap;
+use ts_state::{Account, Array, Map, State, TSBInfo, Token, Tx, Value};
+use ts_tx::*;
+
+#[derive(Clone, Default)]
+struct MemArray<T: Clone + Default>(HashMap<usize, T>);
+impl<T: Clone + Default> Array<T> for MemArray<T> {
+    fn get(&self, index: usize) -> Result<T, String> {
+        Ok(self.0.get(&index).cloned().unwrap_or_default())
+    }
+    fn set(&mut self, index: usize, elem: &T) -> Result<(), String> {
+        self.0.insert(index, elem.clone());
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct MemValue(Fr);
+impl Value for MemValue {
+    fn get(&self) -> Result<Fr, String> {
+        Ok(self.0)
+    }
+    fn set(&mut self, value: &Fr) -> Result<(), String> {
+        self.0 = *value;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct MemMap(HashMap<(usize, Fr), u64>);
+impl Map<TSBInfo, u64> for MemMap {
+    fn get(&self, key: &TSBInfo) -> Result<Option<u64>, String> {
+        Ok(self.0.get(&(key.base_token_id, key.maturity)).copied())
+    }
+    fn set(&mut self, key: &TSBInfo, elem: &u64) -> Result<(), String> {
+        self.0.insert((key.base_token_id, key.maturity), *elem);
+        Ok(())
+    }
+}
+
+type MemAccount = Account<MemArray<Option<Fr>>, MemArray<Token>>;
+type MemState = State<
+    MemValue,
+    MemArray<Option<Fr>>,
+    MemArray<MemAccount>,
+    MemArray<Option<Fr>>,
+    MemArray<Token>,
+    MemArray<TSBInfo>,
+    MemMap,
+    MemArray<Tx>,
+>;
+
+const T0: u64 = 1700000000;
+const MATURITY: u64 = T0 + 180 * 86400;
+const BASE_TOKEN_ID: u64 = 1;
+const COLLATERAL_TOKEN_ID: u64 = 2;
+const BOND_TOKEN_ID: u64 = 48;
+
+fn lend(maturity_time: u64, matched_time: u64) -> ts_tx::Tx {
+    ts_tx::Tx::TxAucLend(TxAucLend {
+        lender_id: 1,
+        lending_token_id: BASE_TOKEN_ID,
+        lending_amt: Fr::from(100000000u64),
+        fee_rate: Fr::from(1000000u64),
+        default_matched_interest_rate: Fr::from(5000000u64),
+        maturity_time: Fr::from(maturity_time),
+        matched_time: Fr::from(matched_time),
+        primary_lend_min_fee_amt: Fr::from(10000u64),
+    })
+}
+
+fn auction(lender_tx_offset: u64, maturity: u64) -> Vec<ts_tx::Tx> {
+    vec![
+        ts_tx::Tx::TxAucStart(TxAucStart {
+            borrower_tx_offset: 1,
+            ori_matched_interest: Fr::from(105000000u64),
+        }),
+        ts_tx::Tx::TxAucMatch(TxAucMatch { lender_tx_offset }),
+        ts_tx::Tx::TxAucEnd(TxAucEnd {
+            borrow_account: Fr::from(2u64),
+            collateral_token_id: COLLATERAL_TOKEN_ID,
+            collateral_amt: Fr::from(50000000u64),
+            debt_token_id: BASE_TOKEN_ID,
+            debt_amt: Fr::from(61471232u64),
+            matched_time: Fr::from(T0),
+            maturity: Fr::from(maturity),
+        }),
+    ]
+}
+
+// a bond token, two funded accounts and an auction lend order followed by a borrow order
+fn setup() -> MemState {
+    let mut state = MemState::default();
+    let raw_txs = [
+        ts_tx::Tx::TxCreateTsbBondToken(TxCreateTsbBondToken {
+            maturity: Fr::from(MATURITY),
+            base_token_id: BASE_TOKEN_ID,
+            bond_token_id: BOND_TOKEN_ID,
+        }),
+        ts_tx::Tx::TxRegister(TxRegister {
+            account_id: 1,
+            hashed_pub_key: Fr::from(0x7501u64),
+        }),
+        ts_tx::Tx::TxRegister(TxRegister {
+            account_id: 2,
+            hashed_pub_key: Fr::from(0x7502u64),
+        }),
+        ts_tx::Tx::TxDeposit(TxDeposit {
+            account_id: 1,
+            deposit_token_id: BASE_TOKEN_ID,
+            deposit_amt: Fr::from(200000000u64),
+        }),
+        ts_tx::Tx::TxDeposit(TxDeposit {
+            account_id: 2,
+            deposit_token_id: COLLATERAL_TOKEN_ID,
+            deposit_amt: Fr::from(100000000u64),
+        }),
+        lend(MATURITY, T0),
+        ts_tx::Tx::TxAucBorrow(TxAucBorrow {
+            sender_id: 2,
+            collateral_token_id: COLLATERAL_TOKEN_ID,
+            collateral_amt: Fr::from(50000000u64),
+            fee_rate: Fr::from(1000000u64),
+            borrowing_amt: Fr::from(60000000u64),
+            matched_time: Fr::from(T0),
+            primary_borrow_min_fee_amt: Fr::from(10000u64),
+        }),
+    ];
+    for (tx_id, raw_tx) in raw_txs.iter().enumerate() {
+        state.push_tx(tx_id, *raw_tx).unwrap();
+        state.update(tx_id).unwrap();
+    }
+    state
+}
+
+const NEXT_TX_ID: usize = 7;
+
+// pushes the txs after the setup and validates the one at `offset`
+fn validate(raw_txs: Vec<ts_tx::Tx>, offset: usize) -> Result<(), String> {
+    let mut state = setup();
+    for (i, raw_tx) in raw_txs.into_iter().enumerate() {
+        state.push_tx(NEXT_TX_ID + i, raw_tx).unwrap();
+    }
+    state.validate(NEXT_TX_ID + offset)
+}
+
+fn assert_rejected(result: Result<(), String>, reason: &str) {
+    let err = result.unwrap_err();
+    assert!(
+        err.contains(reason),
+        "expected \"{}\", got \"{}\"",
+        reason,
+        err
+    );
+}
+
+#[test]
+fn valid_auction_is_accepted() {
+    for offset in 0..3 {
+        validate(auction(3, MATURITY), offset).unwrap();
+    }
+}
+
+#[test]
+fn auction_match_with_bad_offset_is_rejected() {
+    // the offset points at the borrow order instead of the lend order
+    assert_rejected(
+        validate(auction(2, MATURITY), 1),
+        "offset 2 does not point at the auction lend order",
+    );
+    assert_rejected(
+        validate(auction(0, MATURITY), 1),
+        "offset 0 does not point at the auction lend order",
+    );
+    assert_rejected(
+        validate(auction(100, MATURITY), 1),
+        "offset 100 points before the first tx",
+    );
+}
+
+#[test]
+fn auction_match_without_bond_token_is_rejected() {
+    assert_rejected(
+        validate(auction(3, MATURITY + 1), 1),
+        &format!("no bond token of token {} matures at", BASE_TOKEN_ID),
+    );
+}
+
+#[test]
+fn redeem_of_non_bond_token_is_rejected() {
+    let redeem = |token_id| {
+        ts_tx::Tx::TxRedeem(TxRedeem {
+            sender_id: 1,
+            token_id,
+            amount: Fr::from(1000u64),
+        })
+    };
+    validate(vec![redeem(BOND_TOKEN_ID)], 0).unwrap();
+    assert_rejected(
+        validate(vec![redeem(BASE_TOKEN_ID)], 0),
+        &format!("token {} is not a bond token", BASE_TOKEN_ID),
+    );
+}
+
+#[test]
+fn unregistered_account_is_rejected() {
+    let deposit = ts_tx::Tx::TxDeposit(TxDeposit {
+        account_id: 3,
+        deposit_token_id: BASE_TOKEN_ID,
+        deposit_amt: Fr::from(1000u64),
+    });
+    assert_rejected(validate(vec![deposit], 0), "account 3 is not registered");
+    let transfer = ts_tx::Tx::TxTransfer(TxTransfer {
+        sender_id: 1,
+        transfer_token_id: BASE_TOKEN_ID,
+        transfer_amt: Fr::from(1000u64),
+        receiver_id: 3,
+    });
+    assert_rejected(validate(vec![transfer], 0), "account 3 is not registered");
+}
+
+#[test]
+fn out_of_range_token_id_is_rejected() {
+    let token_id = 1 << ts_state::constants::TOKEN_TREE_HEIGHT;
+    let deposit = ts_tx::Tx::TxDeposit(TxDeposit {
+        account_id: 1,
+        deposit_token_id: token_id,
+        deposit_amt: Fr::from(1000u64),
+    });
+    assert_rejected(
+        validate(vec![deposit], 0),
+        &format!("token id {} does not fit the token tree", token_id),
+    );
+}
+
+#[test]
+fn maturity_before_matched_time_is_rejected() {
+    assert_rejected(
+        validate(vec![lend(T0, T0 + 1)], 0),
+        &format!("maturity {} is before the matched time {}", T0, T0 + 1),
+    );
+    let mut batch = auction(3, MATURITY);
+    if let ts_tx::Tx::TxAucEnd(end) = &mut batch[2] {
+        end.maturity = Fr::from(T0 - 1);
+    }
+    assert_rejected(
+        validate(batch, 2),
+        &format!("maturity {} is before the matched time {}", T0 - 1, T0),
+    );
+}
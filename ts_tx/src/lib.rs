@@ -1,6 +1,9 @@
+#![no_std]
+extern crate alloc;
+
 pub mod parser;
 use ark_bn254::Fr;
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
@@ -37,6 +40,38 @@ impl Default for Tx {
         Tx::TxNoop(TxNoop {})
     }
 }
+impl Tx {
+    pub fn op_name(&self) -> &'static str {
+        match self {
+            Tx::TxNoop(_) => "noop",
+            Tx::TxRegister(_) => "register",
+            Tx::TxDeposit(_) => "deposit",
+            Tx::TxForcedWithdraw(_) => "forced_withdraw",
+            Tx::TxTransfer(_) => "transfer",
+            Tx::TxWithdraw(_) => "withdraw",
+            Tx::TxAucLend(_) => "auction_lend",
+            Tx::TxAucBorrow(_) => "auction_borrow",
+            Tx::TxAucStart(_) => "auction_start",
+            Tx::TxAucMatch(_) => "auction_match",
+            Tx::TxAucEnd(_) => "auction_end",
+            Tx::TxSecLimitOrder(_) => "second_limit_order",
+            Tx::TxSecLimitStart(_) => "second_limit_start",
+            Tx::TxSecLimitExchange(_) => "second_limit_exchange",
+            Tx::TxSecLimitEnd(_) => "second_limit_end",
+            Tx::TxSecMarketOrder(_) => "second_market_order",
+            Tx::TxSecMarketExchange(_) => "second_market_exchange",
+            Tx::TxSecMarketEnd(_) => "second_market_end",
+            Tx::TxAdminCancel(_) => "admin_cancel",
+            Tx::TxUserCancel(_) => "user_cancel",
+            Tx::TxIncreaseEpoch(_) => "increase_epoch",
+            Tx::TxCreateTsbBondToken(_) => "create_bond_token",
+            Tx::TxRedeem(_) => "redeem",
+            Tx::TxWithdrawFee(_) => "withdraw_fee",
+            Tx::TxEvacuation(_) => "evacuation",
+            Tx::TxSetAdminTsAddr(_) => "set_admin_ts_addr",
+        }
+    }
+}
 
 #[derive(Clone, Copy, Debug)]
 pub struct TxNoop {}
@@ -1,19 +1,23 @@
 use super::super::*;
 use ark_bn254::Fr;
 use num_bigint::BigUint;
-use std::collections::HashMap;
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+};
 
 fn f2usize(f: Fr) -> u64 {
     let bigint: BigUint = f.into();
     let bytes = bigint.to_bytes_be();
     let mut res = 0;
-    for i in 0..bytes.len() {
-        res = res * 256 + bytes[i] as u64;
+    for byte in bytes {
+        res = res * 256 + byte as u64;
     }
     res
 }
 
-pub fn to_tx(raw_tx: (&String, HashMap<String, Fr>)) -> Result<Tx, String> {
+pub fn to_tx(raw_tx: (&String, BTreeMap<String, Fr>)) -> Result<Tx, String> {
     let op_type = raw_tx.0;
     let params = raw_tx.1;
     match op_type.as_str() {
@@ -47,10 +51,10 @@ pub fn to_tx(raw_tx: (&String, HashMap<String, Fr>)) -> Result<Tx, String> {
     }
 }
 
-pub fn to_noop(_: HashMap<String, Fr>) -> Result<TxNoop, String> {
+pub fn to_noop(_: BTreeMap<String, Fr>) -> Result<TxNoop, String> {
     Ok(TxNoop {})
 }
-pub fn to_register(raw_tx: HashMap<String, Fr>) -> Result<TxRegister, String> {
+pub fn to_register(raw_tx: BTreeMap<String, Fr>) -> Result<TxRegister, String> {
     let account_id = f2usize(*raw_tx.get("account_id").ok_or("invalid op")?);
     let hashed_pub_key = *raw_tx.get("hashed_pub_key").ok_or("invalid op")?;
     Ok(TxRegister {
@@ -58,7 +62,7 @@ pub fn to_register(raw_tx: HashMap<String, Fr>) -> Result<TxRegister, String> {
         hashed_pub_key,
     })
 }
-pub fn to_deposit(raw_tx: HashMap<String, Fr>) -> Result<TxDeposit, String> {
+pub fn to_deposit(raw_tx: BTreeMap<String, Fr>) -> Result<TxDeposit, String> {
     let account_id = f2usize(*raw_tx.get("account_id").ok_or("invalid op")?);
     let deposit_token_id = f2usize(*raw_tx.get("deposit_token_id").ok_or("invalid op")?);
     let deposit_amt = *raw_tx.get("deposit_amt").ok_or("invalid op")?;
@@ -68,7 +72,7 @@ pub fn to_deposit(raw_tx: HashMap<String, Fr>) -> Result<TxDeposit, String> {
         deposit_amt,
     })
 }
-pub fn to_forced_withdraw(raw_tx: HashMap<String, Fr>) -> Result<TxForcedWithdraw, String> {
+pub fn to_forced_withdraw(raw_tx: BTreeMap<String, Fr>) -> Result<TxForcedWithdraw, String> {
     let account_id = f2usize(*raw_tx.get("account_id").ok_or("invalid op")?);
     let withdraw_token_id = f2usize(*raw_tx.get("withdraw_token_id").ok_or("invalid op")?);
     let withdraw_amt = *raw_tx.get("withdraw_amt").ok_or("invalid op")?;
@@ -78,7 +82,7 @@ pub fn to_forced_withdraw(raw_tx: HashMap<String, Fr>) -> Result<TxForcedWithdra
         withdraw_amt,
     })
 }
-pub fn to_transfer(raw_tx: HashMap<String, Fr>) -> Result<TxTransfer, String> {
+pub fn to_transfer(raw_tx: BTreeMap<String, Fr>) -> Result<TxTransfer, String> {
     let sender_id = f2usize(*raw_tx.get("sender_id").ok_or("invalid op")?);
     let transfer_token_id = f2usize(*raw_tx.get("transfer_token_id").ok_or("invalid op")?);
     let transfer_amt = *raw_tx.get("transfer_amt").ok_or("invalid op")?;
@@ -90,7 +94,7 @@ pub fn to_transfer(raw_tx: HashMap<String, Fr>) -> Result<TxTransfer, String> {
         receiver_id,
     })
 }
-pub fn to_withdraw(raw_tx: HashMap<String, Fr>) -> Result<TxWithdraw, String> {
+pub fn to_withdraw(raw_tx: BTreeMap<String, Fr>) -> Result<TxWithdraw, String> {
     let account_id = f2usize(*raw_tx.get("account_id").ok_or("invalid op")?);
     let withdraw_token_id = f2usize(*raw_tx.get("withdraw_token_id").ok_or("invalid op")?);
     let withdraw_amt = *raw_tx.get("withdraw_amt").ok_or("invalid op")?;
@@ -104,7 +108,7 @@ pub fn to_withdraw(raw_tx: HashMap<String, Fr>) -> Result<TxWithdraw, String> {
         tx_fee_amt,
     })
 }
-pub fn to_auc_lend(raw_tx: HashMap<String, Fr>) -> Result<TxAucLend, String> {
+pub fn to_auc_lend(raw_tx: BTreeMap<String, Fr>) -> Result<TxAucLend, String> {
     let lender_id = f2usize(*raw_tx.get("lender_id").ok_or("invalid op")?);
     let lending_token_id = f2usize(*raw_tx.get("lending_token_id").ok_or("invalid op")?);
     let lending_amt = *raw_tx.get("lending_amt").ok_or("invalid op")?;
@@ -126,7 +130,7 @@ pub fn to_auc_lend(raw_tx: HashMap<String, Fr>) -> Result<TxAucLend, String> {
         primary_lend_min_fee_amt,
     })
 }
-pub fn to_auc_borrow(raw_tx: HashMap<String, Fr>) -> Result<TxAucBorrow, String> {
+pub fn to_auc_borrow(raw_tx: BTreeMap<String, Fr>) -> Result<TxAucBorrow, String> {
     let sender_id = f2usize(*raw_tx.get("sender_id").ok_or("invalid op")?);
     let collateral_token_id = f2usize(*raw_tx.get("collateral_token_id").ok_or("invalid op")?);
     let collateral_amt = *raw_tx.get("collateral_amt").ok_or("invalid op")?;
@@ -144,7 +148,7 @@ pub fn to_auc_borrow(raw_tx: HashMap<String, Fr>) -> Result<TxAucBorrow, String>
         primary_borrow_min_fee_amt,
     })
 }
-pub fn to_auc_start(raw_tx: HashMap<String, Fr>) -> Result<TxAucStart, String> {
+pub fn to_auc_start(raw_tx: BTreeMap<String, Fr>) -> Result<TxAucStart, String> {
     let borrower_tx_offset = f2usize(*raw_tx.get("borrower_tx_offset").ok_or("invalid op")?);
     let ori_matched_interest = *raw_tx.get("ori_matched_interest").ok_or("invalid op")?;
     Ok(TxAucStart {
@@ -152,11 +156,11 @@ pub fn to_auc_start(raw_tx: HashMap<String, Fr>) -> Result<TxAucStart, String> {
         ori_matched_interest,
     })
 }
-pub fn to_auc_match(raw_tx: HashMap<String, Fr>) -> Result<TxAucMatch, String> {
+pub fn to_auc_match(raw_tx: BTreeMap<String, Fr>) -> Result<TxAucMatch, String> {
     let lender_tx_offset = f2usize(*raw_tx.get("lender_tx_offset").ok_or("invalid op")?);
     Ok(TxAucMatch { lender_tx_offset })
 }
-pub fn to_auc_end(raw_tx: HashMap<String, Fr>) -> Result<TxAucEnd, String> {
+pub fn to_auc_end(raw_tx: BTreeMap<String, Fr>) -> Result<TxAucEnd, String> {
     let borrow_account = *raw_tx.get("borrow_account").ok_or("invalid op")?;
     let collateral_token_id = f2usize(*raw_tx.get("collateral_token_id").ok_or("invalid op")?);
     let collateral_amt = *raw_tx.get("collateral_amt").ok_or("invalid op")?;
@@ -174,7 +178,7 @@ pub fn to_auc_end(raw_tx: HashMap<String, Fr>) -> Result<TxAucEnd, String> {
         maturity
     })
 }
-pub fn to_sec_limit_order(raw_tx: HashMap<String, Fr>) -> Result<TxSecLimitOrder, String> {
+pub fn to_sec_limit_order(raw_tx: BTreeMap<String, Fr>) -> Result<TxSecLimitOrder, String> {
     let sender_id = f2usize(*raw_tx.get("sender_id").ok_or("invalid op")?);
     let sell_token_id = f2usize(*raw_tx.get("sell_token_id").ok_or("invalid op")?);
     let sell_amt = *raw_tx.get("sell_amt").ok_or("invalid op")?;
@@ -204,19 +208,19 @@ pub fn to_sec_limit_order(raw_tx: HashMap<String, Fr>) -> Result<TxSecLimitOrder
         secondary_maker_min_fee_amt,
     })
 }
-pub fn to_sec_limit_start(raw_tx: HashMap<String, Fr>) -> Result<TxSecLimitStart, String> {
+pub fn to_sec_limit_start(raw_tx: BTreeMap<String, Fr>) -> Result<TxSecLimitStart, String> {
     let taker_tx_offset = f2usize(*raw_tx.get("taker_tx_offset").ok_or("invalid op")?);
     Ok(TxSecLimitStart { taker_tx_offset })
 }
-pub fn to_sec_limit_exchange(raw_tx: HashMap<String, Fr>) -> Result<TxSecLimitExchange, String> {
+pub fn to_sec_limit_exchange(raw_tx: BTreeMap<String, Fr>) -> Result<TxSecLimitExchange, String> {
     let maker_tx_offset = f2usize(*raw_tx.get("maker_tx_offset").ok_or("invalid op")?);
     Ok(TxSecLimitExchange { maker_tx_offset })
 }
-pub fn to_sec_limit_end(raw_tx: HashMap<String, Fr>) -> Result<TxSecLimitEnd, String> {
+pub fn to_sec_limit_end(raw_tx: BTreeMap<String, Fr>) -> Result<TxSecLimitEnd, String> {
     let matched_time = *raw_tx.get("matched_time").ok_or("invalid op")?;
     Ok(TxSecLimitEnd { matched_time })
 }
-pub fn to_sec_market_order(raw_tx: HashMap<String, Fr>) -> Result<TxSecMarketOrder, String> {
+pub fn to_sec_market_order(raw_tx: BTreeMap<String, Fr>) -> Result<TxSecMarketOrder, String> {
     let sender_id = f2usize(*raw_tx.get("sender_id").ok_or("invalid op")?);
     let sell_token_id = f2usize(*raw_tx.get("sell_token_id").ok_or("invalid op")?);
     let sell_amt = *raw_tx.get("sell_amt").ok_or("invalid op")?;
@@ -238,19 +242,19 @@ pub fn to_sec_market_order(raw_tx: HashMap<String, Fr>) -> Result<TxSecMarketOrd
         secondary_taker_min_fee_amt,
     })
 }
-pub fn to_sec_market_exchange(raw_tx: HashMap<String, Fr>) -> Result<TxSecMarketExchange, String> {
+pub fn to_sec_market_exchange(raw_tx: BTreeMap<String, Fr>) -> Result<TxSecMarketExchange, String> {
     let maker_tx_offset = f2usize(*raw_tx.get("maker_tx_offset").ok_or("invalid op")?);
     Ok(TxSecMarketExchange { maker_tx_offset })
 }
-pub fn to_sec_market_end(raw_tx: HashMap<String, Fr>) -> Result<TxSecMarketEnd, String> {
+pub fn to_sec_market_end(raw_tx: BTreeMap<String, Fr>) -> Result<TxSecMarketEnd, String> {
     let matched_time = *raw_tx.get("matched_time").ok_or("invalid op")?;
     Ok(TxSecMarketEnd { matched_time })
 }
-pub fn to_admin_cancel(raw_tx: HashMap<String, Fr>) -> Result<TxAdminCancel, String> {
+pub fn to_admin_cancel(raw_tx: BTreeMap<String, Fr>) -> Result<TxAdminCancel, String> {
     let tx_id = f2usize(*raw_tx.get("tx_id").ok_or("invalid op")?);
     Ok(TxAdminCancel { tx_id })
 }
-pub fn to_user_cancel(raw_tx: HashMap<String, Fr>) -> Result<TxUserCancel, String> {
+pub fn to_user_cancel(raw_tx: BTreeMap<String, Fr>) -> Result<TxUserCancel, String> {
     let tx_id = f2usize(*raw_tx.get("tx_id").ok_or("invalid op")?);
     let tx_fee_token_id = f2usize(*raw_tx.get("tx_fee_token_id").ok_or("invalid op")?);
     let tx_fee_amt = *raw_tx.get("tx_fee_amt").ok_or("invalid op")?;
@@ -260,11 +264,11 @@ pub fn to_user_cancel(raw_tx: HashMap<String, Fr>) -> Result<TxUserCancel, Strin
         tx_fee_amt,
     })
 }
-pub fn to_increase_epoch(_: HashMap<String, Fr>) -> Result<TxIncreaseEpoch, String> {
+pub fn to_increase_epoch(_: BTreeMap<String, Fr>) -> Result<TxIncreaseEpoch, String> {
     Ok(TxIncreaseEpoch {})
 }
 pub fn to_create_tsb_bond_token(
-    raw_tx: HashMap<String, Fr>,
+    raw_tx: BTreeMap<String, Fr>,
 ) -> Result<TxCreateTsbBondToken, String> {
     let maturity = *raw_tx.get("maturity").ok_or("invalid op")?;
     let base_token_id = f2usize(*raw_tx.get("base_token_id").ok_or("invalid op")?);
@@ -275,7 +279,7 @@ pub fn to_create_tsb_bond_token(
         bond_token_id,
     })
 }
-pub fn to_redeem(raw_tx: HashMap<String, Fr>) -> Result<TxRedeem, String> {
+pub fn to_redeem(raw_tx: BTreeMap<String, Fr>) -> Result<TxRedeem, String> {
     let sender_id = f2usize(*raw_tx.get("sender_id").ok_or("invalid op")?);
     let token_id = f2usize(*raw_tx.get("token_id").ok_or("invalid op")?);
     let amount = *raw_tx.get("amount").ok_or("invalid op")?;
@@ -285,12 +289,12 @@ pub fn to_redeem(raw_tx: HashMap<String, Fr>) -> Result<TxRedeem, String> {
         amount,
     })
 }
-pub fn to_withdraw_fee(raw_tx: HashMap<String, Fr>) -> Result<TxWithdrawFee, String> {
+pub fn to_withdraw_fee(raw_tx: BTreeMap<String, Fr>) -> Result<TxWithdrawFee, String> {
     let token_id = f2usize(*raw_tx.get("token_id").ok_or("invalid op")?);
     let amount = *raw_tx.get("amount").ok_or("invalid op")?;
     Ok(TxWithdrawFee { token_id, amount })
 }
-pub fn to_evacuation(raw_tx: HashMap<String, Fr>) -> Result<TxEvacuation, String> {
+pub fn to_evacuation(raw_tx: BTreeMap<String, Fr>) -> Result<TxEvacuation, String> {
     let sender_id = f2usize(*raw_tx.get("sender_id").ok_or("invalid op")?);
     let token_id = f2usize(*raw_tx.get("token_id").ok_or("invalid op")?);
     let amount = *raw_tx.get("amount").ok_or("invalid op")?;
@@ -300,6 +304,6 @@ pub fn to_evacuation(raw_tx: HashMap<String, Fr>) -> Result<TxEvacuation, String
         amount,
     })
 }
-pub fn to_set_admin_ts_add(_: HashMap<String, Fr>) -> Result<TxSetAdminTsAddr, String> {
+pub fn to_set_admin_ts_add(_: BTreeMap<String, Fr>) -> Result<TxSetAdminTsAddr, String> {
     Ok(TxSetAdminTsAddr {})
 }
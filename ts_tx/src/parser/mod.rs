@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 
 use ark_bn254::Fr;
 use ark_ff::PrimeField;
@@ -40,7 +40,7 @@ impl<'de> Deserialize<'de> for Schema {
         }
         #[derive(Clone, Deserialize, Serialize, Debug)]
         struct Helper {
-            base_type: HashMap<String, usize>,
+            base_type: BTreeMap<String, usize>,
             transaction: Vec<HelperTx>,
         }
         let sechma = Helper::deserialize(deserializer)?;
@@ -66,7 +66,7 @@ impl Schema {
         let op_type = data[0];
         let (op_type, params) = &self.ops[op_type as usize];
         let mut ptr = 0;
-        let mut map = HashMap::new();
+        let mut map = BTreeMap::new();
         for i in params {
             let key = &i.symbol;
             let len = &i.len;
@@ -91,7 +91,7 @@ fn float2fix(x: Fr) -> Fr {
     //total 40 bits, first 5 bits is exp, last 35 bits is mantissa
     let x_u64s = x_biguint.clone().to_u64_digits();
 
-    if x_u64s.len() == 0 {
+    if x_u64s.is_empty() {
         Fr::zero()
     } else {
         let exp = (x_u64s[0] >> 35) as u32;
@@ -0,0 +1,128 @@
+use ark_bn254::Fr;
+use serde::Deserialize;
+use std::str::FromStr;
+use ts_merkle_tree::{calc_root, MerkleLeaf};
+use ts_state::{calc_account_digest, calc_state_root, Token};
+use wasm_bindgen::prelude::*;
+
+// the field elements cross the boundary as decimal strings, like in the input files of `export`
+fn to_fr(s: &str) -> Result<Fr, String> {
+    Fr::from_str(s).map_err(|_| format!("invalid field element: {}", s))
+}
+fn to_frs(strs: &[String]) -> Result<Vec<Fr>, String> {
+    strs.iter().map(|s| to_fr(s)).collect()
+}
+fn hash(left: Fr, right: Fr) -> Result<Fr, String> {
+    Ok(ts_poseidon::poseidon::<3>(&[left, right]))
+}
+
+#[wasm_bindgen]
+pub fn poseidon(inputs: Vec<String>) -> Result<String, String> {
+    Ok(ts_poseidon::poseidon_var(&to_frs(&inputs)?)?.to_string())
+}
+
+#[wasm_bindgen(js_name = tokenDigest)]
+pub fn token_digest(avl_amt: &str, locked_amt: &str) -> Result<String, String> {
+    let token = Token {
+        avl_amt: to_fr(avl_amt)?,
+        locked_amt: to_fr(locked_amt)?,
+    };
+    Ok(token.digest()?.to_string())
+}
+
+#[wasm_bindgen(js_name = accountDigest)]
+pub fn account_digest(ts_addr: &str, nonce: &str, token_root: &str) -> Result<String, String> {
+    Ok(calc_account_digest(to_fr(ts_addr)?, to_fr(nonce)?, to_fr(token_root)?).to_string())
+}
+
+#[wasm_bindgen(js_name = stateRoot)]
+pub fn state_root(ts_root: &str, acc_root: &str) -> Result<String, String> {
+    Ok(calc_state_root(to_fr(ts_root)?, to_fr(acc_root)?).to_string())
+}
+
+// the root of the tree of a leaf node, from the siblings along its path
+#[wasm_bindgen(js_name = calcRoot)]
+pub fn calc_merkle_root(
+    leaf_id: usize,
+    leaf_node: &str,
+    proof: Vec<String>,
+) -> Result<String, String> {
+    Ok(calc_root(hash, leaf_id, to_fr(leaf_node)?, &to_frs(&proof)?)?.to_string())
+}
+
+#[derive(Deserialize)]
+struct EvacuInput {
+    #[serde(rename = "stateRoot")]
+    state_root: String,
+    #[serde(rename = "tsRoot")]
+    ts_root: String,
+    #[serde(rename = "accRoot")]
+    acc_root: String,
+    #[serde(rename = "accId")]
+    acc_id: String,
+    #[serde(rename = "nonce")]
+    nonce: String,
+    #[serde(rename = "tsAddr")]
+    ts_addr: String,
+    #[serde(rename = "tokenRoot")]
+    token_root: String,
+    #[serde(rename = "tokenId")]
+    token_id: String,
+    #[serde(rename = "avlAmt")]
+    avl_amt: String,
+    #[serde(rename = "lockedAmt")]
+    locked_amt: String,
+    #[serde(rename = "accMkPrf")]
+    acc_mk_prf: Vec<String>,
+    #[serde(rename = "tokenMkPrf")]
+    token_mk_prf: Vec<String>,
+}
+
+// checks an input file of `export` for a single token: the token leaf must be in the token tree,
+// the account leaf in the account tree, and both roots must give the state root
+#[wasm_bindgen(js_name = verifyEvacuInput)]
+pub fn verify_evacu_input(input: &str) -> Result<(), String> {
+    let input: EvacuInput = serde_json::from_str(input).map_err(|e| e.to_string())?;
+    let parse_id = |s: &str| {
+        s.parse::<usize>()
+            .map_err(|_| format!("invalid leaf id: {}", s))
+    };
+    let token = Token {
+        avl_amt: to_fr(&input.avl_amt)?,
+        locked_amt: to_fr(&input.locked_amt)?,
+    };
+    let token_root = to_fr(&input.token_root)?;
+    let token_leaf_id = parse_id(&input.token_id)?;
+    if calc_root(
+        hash,
+        token_leaf_id,
+        token.digest()?,
+        &to_frs(&input.token_mk_prf)?,
+    )? != token_root
+    {
+        return Err(format!(
+            "token {} is not in the token tree of the root",
+            input.token_id
+        ));
+    }
+    let acc_root = to_fr(&input.acc_root)?;
+    let account_leaf_node =
+        calc_account_digest(to_fr(&input.ts_addr)?, to_fr(&input.nonce)?, token_root);
+    let acc_leaf_id = parse_id(&input.acc_id)?;
+    if calc_root(
+        hash,
+        acc_leaf_id,
+        account_leaf_node,
+        &to_frs(&input.acc_mk_prf)?,
+    )? != acc_root
+    {
+        return Err(format!(
+            "account {} is not in the account tree of the root",
+            input.acc_id
+        ));
+    }
+    if calc_state_root(to_fr(&input.ts_root)?, acc_root) != to_fr(&input.state_root)? {
+        return Err("the roots do not give the state root".to_string());
+    }
+    Ok(())
+}
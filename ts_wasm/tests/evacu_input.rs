@@ -0,0 +1,67 @@
+use ts_wasm::{account_digest, poseidon, state_root, token_digest, verify_evacu_input};
+
+// the nodes of an empty tree along any path, from the leaves up, and its root
+fn empty_tree(leaf_node: String, height: usize) -> (Vec<String>, String) {
+    let mut nodes = vec![leaf_node];
+    for i in 0..height {
+        nodes.push(poseidon(vec![nodes[i].clone(), nodes[i].clone()]).unwrap());
+    }
+    let root = nodes.pop().unwrap();
+    (nodes, root)
+}
+
+// the input of `export` for an empty token of an empty account in an empty state
+fn empty_input() -> serde_json::Value {
+    let (token_mk_prf, token_root) = empty_tree(token_digest("0", "0").unwrap(), 16);
+    let (acc_mk_prf, acc_root) = empty_tree(account_digest("0", "0", &token_root).unwrap(), 32);
+    serde_json::json!({
+        "currentTime": "1700000000",
+        "stateRoot": state_root("0", &acc_root).unwrap(),
+        "tsRoot": "0",
+        "accRoot": acc_root,
+        "accId": "2",
+        "nonce": "0",
+        "tsAddr": "0",
+        "tokenRoot": token_root,
+        "tokenId": "1",
+        "avlAmt": "0",
+        "lockedAmt": "0",
+        "accMkPrf": acc_mk_prf,
+        "tokenMkPrf": token_mk_prf,
+    })
+}
+
+#[test]
+fn empty_state_root() {
+    // the root of an empty state, as in ts_state/tests/digests.rs
+    assert_eq!(
+        empty_input()["stateRoot"],
+        "11111136759937335548574249596774232166565402845047929052520827150573194067803"
+    );
+}
+
+#[test]
+fn verifies_export_input() {
+    assert!(verify_evacu_input(&empty_input().to_string()).is_ok());
+}
+
+#[test]
+fn rejects_tampered_input() {
+    for (key, value) in [
+        ("avlAmt", "1"),
+        ("nonce", "1"),
+        ("tsRoot", "1"),
+        ("stateRoot", "1"),
+    ] {
+        let mut input = empty_input();
+        input[key] = value.into();
+        assert!(verify_evacu_input(&input.to_string()).is_err(), "{}", key);
+    }
+    // in an empty tree every leaf has the same siblings, so only the proofs are changed
+    for key in ["tokenMkPrf", "accMkPrf"] {
+        let mut input = empty_input();
+        input[key][3] = "1".into();
+        assert!(verify_evacu_input(&input.to_string()).is_err(), "{}", key);
+    }
+    assert!(verify_evacu_input("{}").is_err());
+}